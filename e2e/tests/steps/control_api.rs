@@ -25,11 +25,13 @@ async fn when_interconnects_kind(
     left_member_id: String,
     right_member_id: String,
 ) {
-    let send_video = kind.contains("video").then_some(VideoSettings {
+    let send_video = kind.contains("video").then(|| VideoSettings {
         publish_policy: proto::PublishPolicy::Optional,
+        ..VideoSettings::default()
     });
-    let send_audio = kind.contains("audio").then_some(AudioSettings {
+    let send_audio = kind.contains("audio").then(|| AudioSettings {
         publish_policy: proto::PublishPolicy::Optional,
+        ..AudioSettings::default()
     });
 
     world
@@ -37,8 +39,8 @@ async fn when_interconnects_kind(
             left: PairedMember {
                 id: left_member_id,
                 recv: true,
-                send_video,
-                send_audio,
+                send_video: send_video.clone(),
+                send_audio: send_audio.clone(),
             },
             right: PairedMember {
                 id: right_member_id,
@@ -180,6 +180,8 @@ impl World {
                                 m.id(),
                             ),
                             force_relay: false,
+                            audio_filter: proto::MediaFilter::default(),
+                            video_filter: proto::MediaFilter::default(),
                         },
                     ),
                 );
@@ -200,6 +202,7 @@ impl World {
                     idle_timeout: None,
                     reconnect_timeout: None,
                     ping_interval: None,
+                    metadata: HashMap::new(),
                 })),
             )
             .await?;
@@ -224,6 +227,8 @@ impl World {
                                     self.room_id, builder.id,
                                 ),
                                 force_relay: false,
+                                audio_filter: proto::MediaFilter::default(),
+                                video_filter: proto::MediaFilter::default(),
                             },
                         );
                         (id, elem)
@@ -820,11 +825,17 @@ impl PairedMember {
                 proto::P2pMode::Always
             },
             force_relay: false,
-            audio_settings: self.send_audio.unwrap_or(proto::AudioSettings {
-                publish_policy: PublishPolicy::Disabled,
+            audio_settings: self.send_audio.clone().unwrap_or_else(|| {
+                proto::AudioSettings {
+                    publish_policy: PublishPolicy::Disabled,
+                    ..proto::AudioSettings::default()
+                }
             }),
-            video_settings: self.send_video.unwrap_or(proto::VideoSettings {
-                publish_policy: PublishPolicy::Disabled,
+            video_settings: self.send_video.clone().unwrap_or_else(|| {
+                proto::VideoSettings {
+                    publish_policy: PublishPolicy::Disabled,
+                    ..proto::VideoSettings::default()
+                }
             }),
         })
     }
@@ -841,6 +852,8 @@ impl PairedMember {
             id: format!("play-{}", publisher.id),
             src: format!("local://{room_id}/{}/publish", publisher.id),
             force_relay: false,
+            audio_filter: proto::MediaFilter::default(),
+            video_filter: proto::MediaFilter::default(),
         })
     }
 }
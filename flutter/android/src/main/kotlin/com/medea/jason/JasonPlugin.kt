@@ -1,17 +1,74 @@
 package com.medea.jason
 
+import android.Manifest
+import android.content.Context
+import android.content.pm.PackageManager
 import androidx.annotation.NonNull
 
 import io.flutter.embedding.engine.plugins.FlutterPlugin
+import io.flutter.plugin.common.MethodCall
+import io.flutter.plugin.common.MethodChannel
+
+/** Media permission state, mirroring the `PermissionState` enum of Rust side. */
+private enum class PermissionState(val value: Int) {
+    GRANTED(0),
+    PROMPT(2),
+}
+
+class JasonPlugin: FlutterPlugin, MethodChannel.MethodCallHandler {
+    /** [MethodChannel] of this plugin. */
+    private var channel: MethodChannel? = null
+
+    /** Application [Context] to check media permissions with. */
+    private var context: Context? = null
 
-class JasonPlugin: FlutterPlugin {
     override fun onAttachedToEngine(
         @NonNull flutterPluginBinding: FlutterPlugin.FlutterPluginBinding,
     ) {
+        context = flutterPluginBinding.applicationContext
+        channel = MethodChannel(
+            flutterPluginBinding.binaryMessenger,
+            "medea_jason",
+        ).also { it.setMethodCallHandler(this) }
     }
 
     override fun onDetachedFromEngine(
         @NonNull binding: FlutterPlugin.FlutterPluginBinding,
     ) {
+        channel?.setMethodCallHandler(null)
+        channel = null
+        context = null
+    }
+
+    override fun onMethodCall(
+        @NonNull call: MethodCall,
+        @NonNull result: MethodChannel.Result,
+    ) {
+        when (call.method) {
+            "permissionState" -> {
+                val kind = call.argument<Int>("kind")
+                result.success(permissionState(kind).value)
+            }
+            else -> result.notImplemented()
+        }
+    }
+
+    /**
+     * Returns the current [PermissionState] of the provided media permission
+     * kind.
+     *
+     * Android allows to distinguish denied permissions from the ones never
+     * requested only via an `Activity`, so both are reported as
+     * [PermissionState.PROMPT]. Display capture is prompted every time.
+     */
+    private fun permissionState(kind: Int?): PermissionState {
+        val permission = when (kind) {
+            0 -> Manifest.permission.RECORD_AUDIO
+            1 -> Manifest.permission.CAMERA
+            else -> return PermissionState.PROMPT
+        }
+        val granted = context?.checkSelfPermission(permission) ==
+            PackageManager.PERMISSION_GRANTED
+        return if (granted) PermissionState.GRANTED else PermissionState.PROMPT
     }
 }
@@ -1,6 +1,33 @@
+import AVFoundation
 import Flutter
 import UIKit
 
+/// Camera controls supported by this plugin, mirroring the `CameraControl`
+/// enum of Rust side.
+enum CameraControl: Int {
+  case zoom = 0
+  case torch = 1
+}
+
+/// Media permission state, mirroring the `PermissionState` enum of Rust side.
+enum PermissionState: Int {
+  case granted = 0
+  case denied = 1
+  case prompt = 2
+}
+
+/// Returns the `PermissionState` of capturing the provided `AVMediaType`.
+func authorizationState(of mediaType: AVMediaType) -> PermissionState {
+  switch AVCaptureDevice.authorizationStatus(for: mediaType) {
+  case .authorized:
+    return .granted
+  case .denied, .restricted:
+    return .denied
+  default:
+    return .prompt
+  }
+}
+
 public class MedeaJasonPlugin: NSObject, FlutterPlugin {
   public static func register(with registrar: FlutterPluginRegistrar) {
     let channel = FlutterMethodChannel(name: "medea_jason", binaryMessenger: registrar.messenger())
@@ -12,8 +39,84 @@ public class MedeaJasonPlugin: NSObject, FlutterPlugin {
     switch call.method {
     case "getPlatformVersion":
       result("iOS " + UIDevice.current.systemVersion)
+    case "cameraControls":
+      cameraControls(call, result: result)
+    case "setCameraControl":
+      setCameraControl(call, result: result)
+    case "permissionState":
+      permissionState(call, result: result)
     default:
       result(FlutterMethodNotImplemented)
     }
   }
+
+  /// Returns the current `PermissionState` of the provided media permission
+  /// kind.
+  ///
+  /// Display capture is prompted every time.
+  private func permissionState(_ call: FlutterMethodCall, result: @escaping FlutterResult) {
+    let args = call.arguments as? [String: Any]
+    switch args?["kind"] as? Int {
+    case 0:
+      result(authorizationState(of: .audio).rawValue)
+    case 1:
+      result(authorizationState(of: .video).rawValue)
+    default:
+      result(PermissionState.prompt.rawValue)
+    }
+  }
+
+  /// Returns `CameraControl`s supported by the camera with the provided
+  /// `deviceId`.
+  private func cameraControls(_ call: FlutterMethodCall, result: @escaping FlutterResult) {
+    let args = call.arguments as? [String: Any]
+    guard let deviceId = args?["deviceId"] as? String,
+          let device = AVCaptureDevice(uniqueID: deviceId)
+    else {
+      result([Int]())
+      return
+    }
+
+    var controls = [Int]()
+    if device.maxAvailableVideoZoomFactor > device.minAvailableVideoZoomFactor {
+      controls.append(CameraControl.zoom.rawValue)
+    }
+    if device.hasTorch {
+      controls.append(CameraControl.torch.rawValue)
+    }
+    result(controls)
+  }
+
+  /// Sets the provided value of the provided `CameraControl` for the camera
+  /// with the provided `deviceId`.
+  private func setCameraControl(_ call: FlutterMethodCall, result: @escaping FlutterResult) {
+    let args = call.arguments as? [String: Any]
+    guard let deviceId = args?["deviceId"] as? String,
+          let control = (args?["control"] as? Int).flatMap(CameraControl.init),
+          let device = AVCaptureDevice(uniqueID: deviceId)
+    else {
+      result(FlutterError(code: "CameraControlError", message: "Unsupported camera control", details: nil))
+      return
+    }
+
+    do {
+      try device.lockForConfiguration()
+      defer { device.unlockForConfiguration() }
+
+      switch control {
+      case .zoom:
+        let zoom = CGFloat(args?["value"] as? Double ?? 1)
+        device.videoZoomFactor = min(
+          max(zoom, device.minAvailableVideoZoomFactor),
+          device.maxAvailableVideoZoomFactor
+        )
+      case .torch:
+        let enabled = args?["value"] as? Bool ?? false
+        device.torchMode = enabled ? .on : .off
+      }
+      result(nil)
+    } catch {
+      result(FlutterError(code: "CameraControlError", message: error.localizedDescription, details: nil))
+    }
+  }
 }
@@ -1,5 +1,8 @@
 export 'src/interface/connection_handle.dart';
-export 'src/interface/enums.dart' show PeerConnectionState, LogLevel;
+export 'src/interface/device_capabilities.dart';
+export 'src/interface/diagnostics.dart';
+export 'src/interface/enums.dart'
+    show PeerConnectionState, LogLevel, PermissionKind;
 export 'src/interface/member_connection_state.dart'
     show MemberConnectionState, MemberConnectionStateP2P;
 export 'src/interface/exceptions.dart';
@@ -7,10 +10,14 @@ export 'src/interface/media_device_details.dart';
 export 'src/interface/media_manager.dart';
 export 'src/interface/media_track.dart';
 export 'src/interface/media_display_details.dart';
+export 'src/interface/network_state.dart';
+export 'src/interface/permission_state.dart';
 export 'src/interface/logging.dart';
 export 'src/interface/reconnect_handle.dart';
 export 'src/interface/room_close_reason.dart';
+export 'src/interface/room_member.dart';
 export 'src/interface/room_handle.dart';
+export 'src/interface/signalling_latency.dart';
 export 'src/native/jason.dart'
     if (dart.library.js_interop) 'src/web/jason.dart';
 export 'src/native/audio_track_constraints.dart'
@@ -0,0 +1,60 @@
+import '/src/util/rust_handles_storage.dart';
+import 'enums.dart' show FacingMode;
+
+/// Inclusive range of integer values supported by a media input device.
+class IntRange {
+  /// Minimum supported value.
+  final int min;
+
+  /// Maximum supported value.
+  final int max;
+
+  /// Creates a new [IntRange] with the provided [min] and [max] values.
+  const IntRange(this.min, this.max);
+}
+
+/// Inclusive range of floating point values supported by a media input
+/// device.
+class FloatRange {
+  /// Minimum supported value.
+  final double min;
+
+  /// Maximum supported value.
+  final double max;
+
+  /// Creates a new [FloatRange] with the provided [min] and [max] values.
+  const FloatRange(this.min, this.max);
+}
+
+/// Capabilities of a media input device, describing the values of
+/// [MediaTrackConstraints][1] it supports.
+///
+/// This struct is returned by the `MediaManagerHandle.deviceCapabilities()`
+/// method.
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#dom-mediatrackconstraints
+abstract class DeviceCapabilities implements SyncPlatformHandle {
+  /// Returns the supported width of the video in pixels.
+  IntRange? width();
+
+  /// Returns the supported height of the video in pixels.
+  IntRange? height();
+
+  /// Returns the supported frame rate of the video.
+  FloatRange? frameRate();
+
+  /// Returns the supported facing modes of the camera.
+  List<FacingMode> facingModes();
+
+  /// Returns the supported channel count of the audio.
+  IntRange? channelCount();
+
+  /// Returns the supported values of the echo cancellation toggle.
+  List<bool> echoCancellation();
+
+  /// Returns the supported values of the auto gain control toggle.
+  List<bool> autoGainControl();
+
+  /// Returns the supported values of the noise suppression toggle.
+  List<bool> noiseSuppression();
+}
@@ -0,0 +1,121 @@
+import 'enums.dart' show MediaKind;
+
+/// [RTCIceServer][1] to run pre-call diagnostics via.
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtciceserver
+class IceServer {
+  /// URLs of this [IceServer].
+  final List<String> urls;
+
+  /// Username to authenticate on this [IceServer] with.
+  final String? username;
+
+  /// Secret to authenticate on this [IceServer] with.
+  final String? credential;
+
+  /// Creates a new [IceServer] with the provided [urls] and optional
+  /// credentials.
+  const IceServer(this.urls, {this.username, this.credential});
+}
+
+/// Report of pre-call diagnostics.
+///
+/// This struct is returned by the `MediaManagerHandle.runDiagnostics()`
+/// method.
+class DiagnosticsReport {
+  /// Results of checking every available media input device.
+  final List<DeviceDiagnostics> devices;
+
+  /// Results of the local ICE gathering and connectivity check.
+  final IceDiagnostics ice;
+
+  /// Throughput estimated over a loopback connection, if it has been
+  /// established.
+  final ThroughputDiagnostics? throughput;
+
+  /// Creates a new [DiagnosticsReport] out of the provided results.
+  const DiagnosticsReport(this.devices, this.ice, this.throughput);
+}
+
+/// Result of checking a single media input device.
+class DeviceDiagnostics {
+  /// ID of the checked device.
+  final String deviceId;
+
+  /// Label of the checked device.
+  final String label;
+
+  /// Kind of the media produced by the checked device.
+  final MediaKind kind;
+
+  /// Description of the error occurred while acquiring media from the device,
+  /// if any.
+  final String? error;
+
+  /// Width (in pixels) of the video captured from the device.
+  final int? width;
+
+  /// Height (in pixels) of the video captured from the device.
+  final int? height;
+
+  /// Frame rate of the video captured from the device.
+  final double? frameRate;
+
+  /// Peak audio level (in `0..=100` range) captured from the device.
+  final int? audioLevel;
+
+  /// Creates a new [DeviceDiagnostics] out of the provided results.
+  const DeviceDiagnostics({
+    required this.deviceId,
+    required this.label,
+    required this.kind,
+    this.error,
+    this.width,
+    this.height,
+    this.frameRate,
+    this.audioLevel,
+  });
+}
+
+/// Results of the local ICE gathering and connectivity check.
+class IceDiagnostics {
+  /// Number of gathered host candidates.
+  final int hostCandidates;
+
+  /// Number of gathered server reflexive candidates.
+  final int srflxCandidates;
+
+  /// Number of gathered relay candidates.
+  final int relayCandidates;
+
+  /// Indicator whether the loopback connection has been established.
+  final bool connected;
+
+  /// Descriptions of the errors occurred while gathering candidates or
+  /// establishing the loopback connection.
+  final List<String> errors;
+
+  /// Creates a new [IceDiagnostics] out of the provided results.
+  const IceDiagnostics({
+    required this.hostCandidates,
+    required this.srflxCandidates,
+    required this.relayCandidates,
+    required this.connected,
+    required this.errors,
+  });
+}
+
+/// Throughput estimated over a loopback connection.
+class ThroughputDiagnostics {
+  /// Number of bytes sent during the measurement.
+  final int bytesSent;
+
+  /// Duration of the measurement in milliseconds.
+  final int durationMs;
+
+  /// Estimated bitrate in bits per second.
+  final int bitrateBps;
+
+  /// Creates a new [ThroughputDiagnostics] out of the provided results.
+  const ThroughputDiagnostics(this.bytesSent, this.durationMs, this.bitrateBps);
+}
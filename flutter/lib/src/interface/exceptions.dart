@@ -27,6 +27,19 @@ enum LocalMediaInitExceptionKind {
   /// [2]: https://tinyurl.com/rnxcavf
   /// [3]: https://w3.org/TR/screen-capture#dom-mediadevices-getdisplaymedia
   localTrackIsEnded,
+
+  /// Occurs if the [getUserMedia()][1] request failed, because a user denied
+  /// the media permission.
+  ///
+  /// [1]: https://tinyurl.com/w3-streams#dom-mediadevices-getusermedia
+  permissionDeniedByUser,
+
+  /// Occurs if the [getUserMedia()][1] request failed, because a system (for
+  /// example, OS privacy settings or a browser policy) denied the media
+  /// permission.
+  ///
+  /// [1]: https://tinyurl.com/w3-streams#dom-mediadevices-getusermedia
+  permissionDeniedBySystem,
 }
 
 /// Possible error kinds of a [RpcClientException].
@@ -81,6 +94,20 @@ abstract class InvalidOutputAudioDeviceIdException implements Exception {
   }
 }
 
+/// Exception thrown when no media input device with the requested ID exists.
+abstract class DeviceNotFoundException implements Exception {
+  /// Returns ID of the media input device which wasn't found.
+  String deviceId();
+
+  /// Returns stacktrace of this [DeviceNotFoundException].
+  String trace();
+
+  @override
+  String toString() {
+    return 'DeviceNotFoundException: `${deviceId()}`\n${trace()}';
+  }
+}
+
 /// Exception thrown when cannot interact with microphone volume.
 abstract class MicVolumeException implements Exception {
   /// Returns error that caused this [MicVolumeException].
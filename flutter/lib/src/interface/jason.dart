@@ -1,6 +1,7 @@
 import '../util/move_semantic.dart';
 import '/src/util/rust_handles_storage.dart';
 import 'media_manager.dart';
+import 'network_state.dart';
 import 'room_handle.dart';
 
 /// General library interface.
@@ -27,4 +28,50 @@ abstract class Jason implements SyncPlatformHandle {
   ///
   /// [ICE]: https://webrtcglossary.com/ice
   Future<void> networkChanged();
+
+  /// Makes all the connections with a media server created after this call
+  /// fall back to [Server-Sent Events] transport if a WebSocket connection
+  /// cannot be established (e.g. because of a restrictive proxy).
+  ///
+  /// [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+  void enableSseFallback();
+
+  /// Makes all the `Room`s initialized after this call share a single
+  /// connection with a media server, instead of creating a separate one for
+  /// each `Room`.
+  ///
+  /// Each `Room` is still joined and left independently, while the shared
+  /// connection is closed once all of them are left.
+  void shareRpcConnection();
+
+  /// Enables recording of RPC sessions of all the `Room`s initialized after
+  /// this call.
+  ///
+  /// Has no effect if the library is built without `rpc-recording` Cargo
+  /// feature.
+  void enableRpcRecording();
+
+  /// Exports the recorded RPC sessions as [JSON Lines], or returns `null` if
+  /// recording hasn't been enabled.
+  ///
+  /// [JSON Lines]: https://jsonlines.org
+  String? exportRpcRecording();
+
+  /// Enables monitoring of the network connectivity and the page lifecycle,
+  /// so [networkChanged] is called automatically once the network becomes
+  /// available again.
+  ///
+  /// Only supported on Web. Native platforms have no built-in connectivity
+  /// events, so an application should report them via [setNetworkOnline]
+  /// instead (for example, using a connectivity plugin of its choice).
+  void enableNetworkMonitor();
+
+  /// Notifies this [Jason] whether the device is connected to a network.
+  ///
+  /// Calls [networkChanged] automatically once the network becomes available
+  /// again.
+  void setNetworkOnline(bool online);
+
+  /// Sets callback, invoked with a [NetworkState] whenever it changes.
+  void onNetworkStateChange(void Function(NetworkState) f);
 }
@@ -1,8 +1,12 @@
 import '/src/util/rust_handles_storage.dart';
+import 'device_capabilities.dart';
+import 'diagnostics.dart';
+import 'enums.dart' show PermissionKind;
 import 'media_device_details.dart';
 import 'media_display_details.dart';
 import 'media_stream_settings.dart';
 import 'media_track.dart';
+import 'permission_state.dart';
 
 /// External handle to a `MediaManager`.
 ///
@@ -35,6 +39,24 @@ abstract class MediaManagerHandle implements SyncPlatformHandle {
   /// devices access failed.
   Future<List<MediaDeviceDetails>> enumerateDevices();
 
+  /// Returns [DeviceCapabilities] of the media input device with the provided
+  /// [deviceId], describing the resolutions, frame rates, facing modes, etc.
+  /// it supports.
+  ///
+  /// The device is never opened by this method: capabilities are read from an
+  /// already captured [LocalMediaTrack] of this device, if any.
+  ///
+  /// Throws a [StateError] if the underlying object has been disposed, e.g.
+  /// [free] was called on this [MediaManagerHandle], or on a [Jason] that
+  /// implicitly owns native object behind this [MediaManagerHandle].
+  ///
+  /// Throws a [EnumerateDevicesException] if a request of platform media
+  /// devices access failed.
+  ///
+  /// Throws a [DeviceNotFoundException] if there is no media input device with
+  /// the provided [deviceId].
+  Future<DeviceCapabilities> deviceCapabilities(String deviceId);
+
   /// Returns a list of [MediaDisplayDetails] objects representing available
   /// displays.
   ///
@@ -62,4 +84,54 @@ abstract class MediaManagerHandle implements SyncPlatformHandle {
 
   /// Subscribes onto the [MediaManagerHandle]'s `devicechange` event.
   void onDeviceChange(void Function() cb);
+
+  /// Returns the current [PermissionState] of the provided [PermissionKind],
+  /// allowing to check whether a user will be prompted before acquiring media
+  /// via [initLocalTracks].
+  ///
+  /// On native platforms the permissions are queried from the OS. Linux and
+  /// Windows have no media permissions, so always report them granted, while
+  /// Android reports both denied and never requested permissions as
+  /// [PermissionState.prompt].
+  ///
+  /// Throws a [StateError] if the underlying object has been disposed, e.g.
+  /// [free] was called on this [MediaManagerHandle], or on a [Jason] that
+  /// implicitly owns native object behind this [MediaManagerHandle].
+  ///
+  /// Throws an [InternalException] if the permission cannot be queried, for
+  /// example, if the provided [PermissionKind] is not supported by the
+  /// browser.
+  Future<PermissionState> permissionState(PermissionKind kind);
+
+  /// Subscribes onto the [PermissionState] changes of the provided
+  /// [PermissionKind].
+  ///
+  /// Replaces the callback previously set for the same [PermissionKind].
+  ///
+  /// Native platforms don't notify about media permissions changes, so they
+  /// are re-queried whenever the application is resumed there.
+  ///
+  /// Throws a [StateError] if the underlying object has been disposed, e.g.
+  /// [free] was called on this [MediaManagerHandle], or on a [Jason] that
+  /// implicitly owns native object behind this [MediaManagerHandle].
+  ///
+  /// Throws an [InternalException] if the permission cannot be queried, for
+  /// example, if the provided [PermissionKind] is not supported by the
+  /// browser.
+  Future<void> onPermissionChange(
+    PermissionKind kind,
+    void Function(PermissionState) f,
+  );
+
+  /// Runs pre-call diagnostics: checks every available media input device,
+  /// gathers local ICE candidates via the provided [iceServers] and estimates
+  /// throughput over a loopback connection.
+  ///
+  /// Throws a [StateError] if the underlying object has been disposed, e.g.
+  /// [free] was called on this [MediaManagerHandle], or on a [Jason] that
+  /// implicitly owns native object behind this [MediaManagerHandle].
+  ///
+  /// Throws an [InternalException] if a request of platform media devices
+  /// access failed.
+  Future<DiagnosticsReport> runDiagnostics(List<IceServer> iceServers);
 }
@@ -1,6 +1,8 @@
 import 'package:medea_flutter_webrtc/medea_flutter_webrtc.dart' as webrtc;
 
+import '../util/move_semantic.dart';
 import '../util/rust_handles_storage.dart';
+import 'device_video_track_constraints.dart';
 
 import 'enums.dart'
     show
@@ -8,9 +10,11 @@ import 'enums.dart'
         MediaKind,
         MediaSourceKind,
         MediaStreamTrackState,
+        MeteringMode,
         NoiseSuppressionLevel;
 
-export 'enums.dart' show MediaKind, MediaSourceKind, NoiseSuppressionLevel;
+export 'enums.dart'
+    show MediaKind, MediaSourceKind, MeteringMode, NoiseSuppressionLevel;
 
 typedef TrackMediaDirection = MediaDirection;
 
@@ -87,6 +91,100 @@ abstract class LocalMediaTrack implements MediaTrack {
   /// and Firefox are fine.
   bool isAudioProcessingAvailable();
 
+  /// Applies the provided [DeviceVideoTrackConstraints] to this live device
+  /// video [LocalMediaTrack], without re-acquiring its media source.
+  ///
+  /// Throws an [InternalException] if the constraints cannot be applied.
+  /// __Always__ throws on native platforms, so
+  /// [RoomHandle.setLocalMediaSettings] should be used there instead.
+  Future<void> applyConstraints(
+    @moveSemantics DeviceVideoTrackConstraints constraints,
+  );
+
+  /// Indicates whether the camera producing this [LocalMediaTrack] supports
+  /// zoom control via [LocalMediaTrack.setZoom].
+  ///
+  /// __NOTE__: Among native platforms only supported on iOS, so always returns
+  /// `false` on the other ones.
+  bool isZoomAvailable();
+
+  /// Sets the provided zoom level of the camera producing this
+  /// [LocalMediaTrack].
+  ///
+  /// Throws an [InternalException] if the camera doesn't support it, or on
+  /// unexpected platform error.
+  Future<void> setZoom(double zoom);
+
+  /// Indicates whether the camera producing this [LocalMediaTrack] supports
+  /// torch (fill light) control via [LocalMediaTrack.setTorchEnabled].
+  ///
+  /// __NOTE__: Among native platforms only supported on iOS, so always returns
+  /// `false` on the other ones.
+  bool isTorchAvailable();
+
+  /// Toggles the torch (fill light) of the camera producing this
+  /// [LocalMediaTrack].
+  ///
+  /// Throws an [InternalException] if the camera doesn't support it, or on
+  /// unexpected platform error.
+  Future<void> setTorchEnabled(bool enabled);
+
+  /// Indicates whether the camera producing this [LocalMediaTrack] supports
+  /// focus mode control via [LocalMediaTrack.setFocusMode].
+  ///
+  /// __NOTE__: Only supported on web, so always returns `false` on native
+  /// platforms.
+  bool isFocusModeAvailable();
+
+  /// Sets the provided focus [MeteringMode] of the camera producing this
+  /// [LocalMediaTrack].
+  ///
+  /// Throws an [InternalException] if the camera doesn't support it, or on
+  /// unexpected platform error.
+  Future<void> setFocusMode(MeteringMode mode);
+
+  /// Indicates whether the camera producing this [LocalMediaTrack] supports
+  /// focus distance control via [LocalMediaTrack.setFocusDistance].
+  ///
+  /// __NOTE__: Only supported on web, so always returns `false` on native
+  /// platforms.
+  bool isFocusDistanceAvailable();
+
+  /// Sets the provided focus distance (in meters) of the camera producing
+  /// this [LocalMediaTrack].
+  ///
+  /// Throws an [InternalException] if the camera doesn't support it, or on
+  /// unexpected platform error.
+  Future<void> setFocusDistance(double distance);
+
+  /// Indicates whether the camera producing this [LocalMediaTrack] supports
+  /// exposure compensation control via [LocalMediaTrack.setExposureCompensation].
+  ///
+  /// __NOTE__: Only supported on web, so always returns `false` on native
+  /// platforms.
+  bool isExposureCompensationAvailable();
+
+  /// Sets the provided exposure compensation (in exposure value stops) of
+  /// the camera producing this [LocalMediaTrack].
+  ///
+  /// Throws an [InternalException] if the camera doesn't support it, or on
+  /// unexpected platform error.
+  Future<void> setExposureCompensation(double compensation);
+
+  /// Indicates whether the camera producing this [LocalMediaTrack] supports
+  /// white balance mode control via [LocalMediaTrack.setWhiteBalanceMode].
+  ///
+  /// __NOTE__: Only supported on web, so always returns `false` on native
+  /// platforms.
+  bool isWhiteBalanceModeAvailable();
+
+  /// Sets the provided white balance [MeteringMode] of the camera producing
+  /// this [LocalMediaTrack].
+  ///
+  /// Throws an [InternalException] if the camera doesn't support it, or on
+  /// unexpected platform error.
+  Future<void> setWhiteBalanceMode(MeteringMode mode);
+
   /// Toggles noise suppression for this [LocalMediaTrack].
   ///
   /// Throws an [InternalException] on unexpected platform error. Not supported
@@ -0,0 +1,12 @@
+/// State of the network connectivity of a device, as seen by the library.
+enum NetworkState {
+  /// Device is connected to a network.
+  online,
+
+  /// Device is disconnected from any network.
+  offline,
+
+  /// Application is frozen (for example, a web page is frozen by a browser in
+  /// background), so its timers and network activity are suspended.
+  frozen,
+}
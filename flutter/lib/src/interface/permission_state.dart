@@ -0,0 +1,12 @@
+/// State of a media permission.
+enum PermissionState {
+  /// Permission is granted, so media can be captured without prompting a
+  /// user.
+  granted,
+
+  /// Permission is denied, so media capturing will fail.
+  denied,
+
+  /// User will be prompted for the permission on media capturing.
+  prompt,
+}
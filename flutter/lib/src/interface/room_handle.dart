@@ -4,6 +4,8 @@ import 'media_stream_settings.dart';
 import 'media_track.dart';
 import 'reconnect_handle.dart';
 import 'room_close_reason.dart';
+import 'room_member.dart';
+import 'signalling_latency.dart';
 
 /// External handle to a `Room`.
 abstract class RoomHandle implements SyncPlatformHandle {
@@ -23,6 +25,40 @@ abstract class RoomHandle implements SyncPlatformHandle {
   /// Throws `RpcClientException` if could not connect to media server.
   Future<void> join(String token);
 
+  /// Connects to a media server and joins the `Room` with the provided
+  /// [credential], so it never appears in the connection URL.
+  ///
+  /// The [url] has the following format:
+  /// `{{ Host URL }}/{{ Room ID }}/{{ Member ID }}`
+  /// (e.g. `wss://medea.com/MyConf1/Alice`).
+  ///
+  /// Additional HTTP [headers] are sent in a handshake request of the
+  /// WebSocket connection with a media server. Browsers don't allow setting
+  /// them, so an [UnsupportedError] is thrown on Web if any is provided.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed or if some
+  /// mandatory callback is not set. These callbacks are:
+  /// [RoomHandle.onConnectionLoss] and [RoomHandle.onFailedLocalMedia].
+  ///
+  /// Throws [FormatException] if the provided [url] string has bad format.
+  ///
+  /// Throws `RpcClientException` if could not connect to media server.
+  Future<void> joinWithCredential(
+    String url,
+    String credential, [
+    Map<String, String> headers = const {},
+  ]);
+
+  /// Leaves this `Room` gracefully, reporting the provided [reason] of leaving
+  /// to a media server.
+  ///
+  /// Waits for a media server to acknowledge the leaving (for 3 seconds at
+  /// most), stops all the local media tracks sent in this `Room` and resolves
+  /// with its final [RoomCloseReason] (also passed to the [onClose] callback).
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  Future<RoomCloseReason> leave([String? reason]);
+
   /// Updates this `Room`'s [MediaStreamSettings]. This affects all the
   /// `PeerConnection`s in this `Room`. If [MediaStreamSettings] are configured
   /// for some `Room`, then this `Room` can only send media tracks that
@@ -30,6 +66,11 @@ abstract class RoomHandle implements SyncPlatformHandle {
   /// media tracks in all sending peers, so that might cause a new
   /// [getUserMedia()][1] request to happen.
   ///
+  /// On web, resolution and frame rate changes are applied to live device and
+  /// display video tracks in place. Native platforms don't support changing
+  /// constraints of a live track yet, so the affected tracks are re-acquired
+  /// there.
+  ///
   /// Media obtaining/injection errors are additionally fired to
   /// [RoomHandle.onFailedLocalMedia()] callback.
   ///
@@ -181,6 +222,30 @@ abstract class RoomHandle implements SyncPlatformHandle {
   /// server didn't approve this state transition.
   Future<void> disableRemoteVideo([MediaSourceKind? kind]);
 
+  /// Returns all the [RoomMember]s currently present in this `Room`
+  /// (including the local one).
+  ///
+  /// Doesn't depend on any media being exchanged with these [RoomMember]s.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  List<RoomMember> members();
+
+  /// Sets callback, invoked when a new [RoomMember] joins this `Room`.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void onMemberJoined(void Function(RoomMember) f);
+
+  /// Sets callback, invoked with an ID of a [RoomMember] who left this `Room`.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void onMemberLeft(void Function(String) f);
+
+  /// Sets callback, invoked when metadata of some [RoomMember] of this `Room`
+  /// changes, providing an actual [RoomMember].
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void onMemberUpdated(void Function(RoomMember) f);
+
   /// Sets callback, invoked when a new `Connection` with some remote `Peer`
   /// is established.
   ///
@@ -216,4 +281,33 @@ abstract class RoomHandle implements SyncPlatformHandle {
   ///
   /// Throws [StateError] if the underlying [Pointer] has been freed.
   void onFailedLocalMedia(void Function(Object) f);
+
+  /// Sets callback, invoked with a name of an event received from a media
+  /// server, which this client doesn't recognize.
+  ///
+  /// Is a diagnostic hook only, since such events are ignored anyway.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void onUnknownEvent(void Function(String) f);
+
+  /// Sets callback, invoked with actual [SignallingLatency] statistics each
+  /// time a round-trip time of the signalling connection with a media server
+  /// is measured.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  void onSignallingLatency(void Function(SignallingLatency) f);
+
+  /// Returns the current [SignallingLatency] statistics of the signalling
+  /// connection with a media server, or `null` if no round-trip time has been
+  /// measured yet.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  SignallingLatency? signallingLatency();
+
+  /// Returns name of the transport used by the signalling connection with a
+  /// media server (`WebSocket` or `SSE`), or `null` if the connection hasn't
+  /// been established yet.
+  ///
+  /// Throws [StateError] if the underlying [Pointer] has been freed.
+  String? rpcTransportKind();
 }
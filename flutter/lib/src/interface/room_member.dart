@@ -0,0 +1,14 @@
+import '/src/util/rust_handles_storage.dart';
+
+/// `Member` present in a `Room`.
+///
+/// This struct is passed into the `RoomHandle.onMemberJoined()` and
+/// `RoomHandle.onMemberUpdated()` callbacks.
+abstract class RoomMember implements SyncPlatformHandle {
+  /// Returns an ID of this `Member`.
+  String id();
+
+  /// Returns opaque metadata of this `Member` (display name, avatar URL,
+  /// etc.).
+  Map<String, String> metadata();
+}
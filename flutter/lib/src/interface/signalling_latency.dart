@@ -0,0 +1,20 @@
+import '/src/util/rust_handles_storage.dart';
+
+/// Round-trip latency statistics of a signalling connection with a media
+/// server.
+///
+/// This struct is passed into the `RoomHandle.onSignallingLatency()` callback
+/// and returned by the `RoomHandle.signallingLatency()` method.
+abstract class SignallingLatency implements SyncPlatformHandle {
+  /// Returns round-trip time of the last measurement in milliseconds.
+  double rttMs();
+
+  /// Returns smoothed round-trip time in milliseconds.
+  double smoothedRttMs();
+
+  /// Returns round-trip time variation (jitter) in milliseconds.
+  double jitterMs();
+
+  /// Returns number of measurements these statistics are calculated from.
+  int samples();
+}
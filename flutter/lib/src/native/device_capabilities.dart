@@ -0,0 +1,61 @@
+import '../interface/device_capabilities.dart';
+import '../interface/enums.dart' show FacingMode;
+import 'ffi/frb/frb.dart' as frb;
+
+class NativeDeviceCapabilities implements DeviceCapabilities {
+  /// Rust `flutter_rust_bridge` API representation.
+  final frb.ApiDeviceCapabilities _caps;
+
+  /// Constructs a new [DeviceCapabilities] backed by the Rust struct behind the
+  /// provided [frb.ApiDeviceCapabilities].
+  NativeDeviceCapabilities(this._caps);
+
+  @override
+  IntRange? width() {
+    return _intRange(_caps.width);
+  }
+
+  @override
+  IntRange? height() {
+    return _intRange(_caps.height);
+  }
+
+  @override
+  FloatRange? frameRate() {
+    var range = _caps.frameRate;
+    return range == null ? null : FloatRange(range.min, range.max);
+  }
+
+  @override
+  List<FacingMode> facingModes() {
+    return _caps.facingModes;
+  }
+
+  @override
+  IntRange? channelCount() {
+    return _intRange(_caps.channelCount);
+  }
+
+  @override
+  List<bool> echoCancellation() {
+    return _caps.echoCancellation;
+  }
+
+  @override
+  List<bool> autoGainControl() {
+    return _caps.autoGainControl;
+  }
+
+  @override
+  List<bool> noiseSuppression() {
+    return _caps.noiseSuppression;
+  }
+
+  @override
+  void free() {}
+}
+
+/// Converts the provided [frb.ApiIntRange] into an [IntRange].
+IntRange? _intRange(frb.ApiIntRange? range) {
+  return range == null ? null : IntRange(range.min, range.max);
+}
@@ -10,6 +10,7 @@ class DeviceVideoTrackConstraints implements base.DeviceVideoTrackConstraints {
         facingMode: null,
         height: null,
         width: null,
+        frameRate: null,
       );
 
   @override
@@ -82,6 +83,22 @@ class DeviceVideoTrackConstraints implements base.DeviceVideoTrackConstraints {
     constraints.width = ConstrainU32.range(min, max);
   }
 
+  @override
+  void exactFrameRate(int frameRate) {
+    if (frameRate.isNegative || frameRate.bitLength > 32) {
+      throw ArgumentError.value(frameRate, 'frameRate', 'Expected `u32`');
+    }
+    constraints.frameRate = ConstrainU32.exact(frameRate);
+  }
+
+  @override
+  void idealFrameRate(int frameRate) {
+    if (frameRate.isNegative || frameRate.bitLength > 32) {
+      throw ArgumentError.value(frameRate, 'frameRate', 'Expected `u32`');
+    }
+    constraints.frameRate = ConstrainU32.ideal(frameRate);
+  }
+
   @override
   void free() {}
 }
@@ -24,6 +24,7 @@ void registerFunctions(DynamicLibrary dl) {
         _newInvalidOutputAudioDeviceIdException,
     newPanicException: _newPanicException,
     newMicVolumeException: _newMicVolumeException,
+    newDeviceNotFoundException: _newDeviceNotFoundException,
   );
 }
 
@@ -73,6 +74,18 @@ Object _newInvalidOutputAudioDeviceIdException(Pointer<Utf8> trace) {
   );
 }
 
+/// Creates a new [NativeDeviceNotFoundException] with the provided
+/// [deviceId] and [trace].
+Object _newDeviceNotFoundException(
+  Pointer<Utf8> deviceId,
+  Pointer<Utf8> trace,
+) {
+  return NativeDeviceNotFoundException(
+    deviceId.nativeStringToDartString(),
+    trace.nativeStringToDartString(),
+  );
+}
+
 /// Creates a new [MicVolumeException] with the provided error [cause] and
 /// [trace].
 Object _newMicVolumeException(Pointer<Handle> cause, Pointer<Utf8> trace) {
@@ -238,6 +251,28 @@ class NativeInvalidOutputAudioDeviceIdException
   }
 }
 
+/// Exception thrown when no media input device with the requested ID exists.
+class NativeDeviceNotFoundException extends DeviceNotFoundException {
+  /// ID of the media input device which wasn't found.
+  late final String _deviceId;
+
+  /// Native stacktrace.
+  late final String _nativeStackTrace;
+
+  /// Instantiates a new [NativeDeviceNotFoundException].
+  NativeDeviceNotFoundException(this._deviceId, this._nativeStackTrace);
+
+  @override
+  String deviceId() {
+    return _deviceId;
+  }
+
+  @override
+  String trace() {
+    return _nativeStackTrace;
+  }
+}
+
 /// Exception thrown when cannot interact with microphone volume.
 class NativeMicVolumeException extends MicVolumeException {
   /// Dart [Exception] or [Error] that caused this [NativeMicVolumeException].
@@ -22,6 +22,7 @@ Object Function(Pointer<Utf8>, Pointer<Handle>, bool)?
 _newMediaSettingsUpdateException;
 Object Function(Pointer<Utf8>)? _newInvalidOutputAudioDeviceIdException;
 Object Function(Pointer<Handle>, Pointer<Utf8>)? _newMicVolumeException;
+Object Function(Pointer<Utf8>, Pointer<Utf8>)? _newDeviceNotFoundException;
 Object Function()? _newPanicException;
 
 _ErrorSetterFnDart? _exception__new_state_error__set_error;
@@ -35,6 +36,7 @@ _ErrorSetterFnDart? _exception__new_media_settings_update_exception__set_error;
 _ErrorSetterFnDart?
 _exception__new_invalid_output_audio_device_id_exception__set_error;
 _ErrorSetterFnDart? _exception__new_mic_volume_exception__set_error;
+_ErrorSetterFnDart? _exception__new_device_not_found_exception__set_error;
 _ErrorSetterFnDart? _exception__new_panic_exception__set_error;
 
 void registerFunction(
@@ -57,6 +59,8 @@ void registerFunction(
   newInvalidOutputAudioDeviceIdException,
   required Object Function(Pointer<Handle>, Pointer<Utf8>)
   newMicVolumeException,
+  required Object Function(Pointer<Utf8>, Pointer<Utf8>)
+  newDeviceNotFoundException,
   required Object Function() newPanicException,
 }) {
   _newStateError = newStateError;
@@ -70,6 +74,7 @@ void registerFunction(
   _newInvalidOutputAudioDeviceIdException =
       newInvalidOutputAudioDeviceIdException;
   _newMicVolumeException = newMicVolumeException;
+  _newDeviceNotFoundException = newDeviceNotFoundException;
   _newPanicException = newPanicException;
 
   _exception__new_state_error__set_error = dl
@@ -112,6 +117,10 @@ void registerFunction(
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
         'exception__new_mic_volume_exception__set_error',
       );
+  _exception__new_device_not_found_exception__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'exception__new_device_not_found_exception__set_error',
+      );
   _exception__new_panic_exception__set_error = dl
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
         'exception__new_panic_exception__set_error',
@@ -163,6 +172,10 @@ void registerFunction(
   newMicVolumeException_native = Pointer.fromFunction(
     _newMicVolumeExceptionProxy,
   );
+  Pointer<NativeFunction<Handle Function(Pointer<Utf8>, Pointer<Utf8>)>>
+  newDeviceNotFoundException_native = Pointer.fromFunction(
+    _newDeviceNotFoundExceptionProxy,
+  );
   Pointer<NativeFunction<Handle Function()>> newPanicException_native =
       Pointer.fromFunction(_newPanicExceptionProxy);
 
@@ -179,6 +192,7 @@ void registerFunction(
       Pointer,
       Pointer,
       Pointer,
+      Pointer,
     ),
     void Function(
       Pointer,
@@ -192,6 +206,7 @@ void registerFunction(
       Pointer,
       Pointer,
       Pointer,
+      Pointer,
     )
   >('register_exception')(
     newStateError_native,
@@ -204,6 +219,7 @@ void registerFunction(
     newMediaSettingsUpdateException_native,
     newInvalidOutputAudioDeviceIdException_native,
     newMicVolumeException_native,
+    newDeviceNotFoundException_native,
     newPanicException_native,
   );
 }
@@ -323,6 +339,18 @@ Object _newMicVolumeExceptionProxy(Pointer<Handle> arg0, Pointer<Utf8> arg1) {
   }
 }
 
+Object _newDeviceNotFoundExceptionProxy(
+  Pointer<Utf8> arg0,
+  Pointer<Utf8> arg1,
+) {
+  try {
+    return _newDeviceNotFoundException!(arg0, arg1);
+  } catch (e) {
+    _exception__new_device_not_found_exception__set_error!(e);
+    return 0;
+  }
+}
+
 Object _newPanicExceptionProxy() {
   try {
     return _newPanicException!();
@@ -17,6 +17,11 @@ part 'api.freezed.dart';
 // These functions are ignored (category: IgnoreBecauseExplicitAttribute): `into_ptr`, `into_ptr`, `into_ptr`, `into_ptr`, `into_ptr`, `into_ptr`, `into_ptr`, `into_ptr`
 // These functions are ignored (category: IgnoreBecauseNotAllowedOwner): `from_ptr`
 
+/// Returns the [`ApiDeviceCapabilities`] from the provided [`ForeignClass`]
+/// address.
+ApiDeviceCapabilities deviceCapabilitiesFromRaw({required int ptr}) =>
+    RustLib.instance.api.crateApiDartApiDeviceCapabilitiesFromRaw(ptr: ptr);
+
 /// Returns the [`Vec<ApiMediaDeviceDetails>`] from the provided
 /// [`ForeignClass`] address.
 List<ApiMediaDeviceDetails> vecMediaDeviceDetailsFromRaw({required int ptr}) =>
@@ -125,6 +130,72 @@ class ApiDeviceAudioTrackConstraints {
           highPassFilter == other.highPassFilter;
 }
 
+/// Capabilities of a media input device, describing the values of
+/// [MediaTrackConstraints][1] it supports.
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#dom-mediatrackconstraints
+class ApiDeviceCapabilities {
+  /// Supported width of the video in pixels.
+  final ApiIntRange? width;
+
+  /// Supported height of the video in pixels.
+  final ApiIntRange? height;
+
+  /// Supported frame rate of the video.
+  final ApiFloatRange? frameRate;
+
+  /// Supported facing modes of the camera.
+  final List<FacingMode> facingModes;
+
+  /// Supported channel count of the audio.
+  final ApiIntRange? channelCount;
+
+  /// Supported values of the echo cancellation toggle.
+  final List<bool> echoCancellation;
+
+  /// Supported values of the auto gain control toggle.
+  final List<bool> autoGainControl;
+
+  /// Supported values of the noise suppression toggle.
+  final List<bool> noiseSuppression;
+
+  const ApiDeviceCapabilities({
+    this.width,
+    this.height,
+    this.frameRate,
+    required this.facingModes,
+    this.channelCount,
+    required this.echoCancellation,
+    required this.autoGainControl,
+    required this.noiseSuppression,
+  });
+
+  @override
+  int get hashCode =>
+      width.hashCode ^
+      height.hashCode ^
+      frameRate.hashCode ^
+      facingModes.hashCode ^
+      channelCount.hashCode ^
+      echoCancellation.hashCode ^
+      autoGainControl.hashCode ^
+      noiseSuppression.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiDeviceCapabilities &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          frameRate == other.frameRate &&
+          facingModes == other.facingModes &&
+          channelCount == other.channelCount &&
+          echoCancellation == other.echoCancellation &&
+          autoGainControl == other.autoGainControl &&
+          noiseSuppression == other.noiseSuppression;
+}
+
 /// Constraints applicable to video tracks that are sourced from some media
 /// device.
 class ApiDeviceVideoTrackConstraints {
@@ -141,11 +212,17 @@ class ApiDeviceVideoTrackConstraints {
   /// Width of the video in pixels.
   ConstrainU32? width;
 
+  /// [Frame rate][1] of the video.
+  ///
+  /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+  ConstrainU32? frameRate;
+
   ApiDeviceVideoTrackConstraints({
     this.deviceId,
     this.facingMode,
     this.height,
     this.width,
+    this.frameRate,
   });
 
   @override
@@ -153,7 +230,8 @@ class ApiDeviceVideoTrackConstraints {
       deviceId.hashCode ^
       facingMode.hashCode ^
       height.hashCode ^
-      width.hashCode;
+      width.hashCode ^
+      frameRate.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -163,7 +241,8 @@ class ApiDeviceVideoTrackConstraints {
           deviceId == other.deviceId &&
           facingMode == other.facingMode &&
           height == other.height &&
-          width == other.width;
+          width == other.width &&
+          frameRate == other.frameRate;
 }
 
 /// Constraints applicable to display audio tracks (system audio capture).
@@ -222,6 +301,51 @@ class ApiDisplayVideoTrackConstraints {
           frameRate == other.frameRate;
 }
 
+/// Inclusive range of floating point values supported by a media input
+/// device.
+class ApiFloatRange {
+  /// Minimum supported value.
+  final double min;
+
+  /// Maximum supported value.
+  final double max;
+
+  const ApiFloatRange({required this.min, required this.max});
+
+  @override
+  int get hashCode => min.hashCode ^ max.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiFloatRange &&
+          runtimeType == other.runtimeType &&
+          min == other.min &&
+          max == other.max;
+}
+
+/// Inclusive range of integer values supported by a media input device.
+class ApiIntRange {
+  /// Minimum supported value.
+  final int min;
+
+  /// Maximum supported value.
+  final int max;
+
+  const ApiIntRange({required this.min, required this.max});
+
+  @override
+  int get hashCode => min.hashCode ^ max.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiIntRange &&
+          runtimeType == other.runtimeType &&
+          min == other.min &&
+          max == other.max;
+}
+
 /// Representation of a [MediaDeviceInfo][0] ONLY for input devices.
 ///
 /// [0]: https://w3.org/TR/mediacapture-streams#device-info
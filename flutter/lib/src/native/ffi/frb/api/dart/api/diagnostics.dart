@@ -0,0 +1,225 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../../../frb_generated.dart';
+import '../../../media.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `from`, `from`, `from`, `from`, `from`
+
+/// [RTCIceServer][1] to run pre-call diagnostics via.
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtciceserver
+class ApiIceServer {
+  /// URLs of this [`ApiIceServer`].
+  final List<String> urls;
+
+  /// Username to authenticate on this [`ApiIceServer`] with.
+  final String? username;
+
+  /// Secret to authenticate on this [`ApiIceServer`] with.
+  final String? credential;
+
+  const ApiIceServer({required this.urls, this.username, this.credential});
+
+  @override
+  int get hashCode => urls.hashCode ^ username.hashCode ^ credential.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiIceServer &&
+          runtimeType == other.runtimeType &&
+          urls == other.urls &&
+          username == other.username &&
+          credential == other.credential;
+}
+
+/// Result of checking a single media input device.
+class DeviceDiagnostics {
+  /// ID of the checked device.
+  final String deviceId;
+
+  /// Label of the checked device.
+  final String label;
+
+  /// Kind of the media produced by the checked device.
+  final MediaKind kind;
+
+  /// Description of the error occurred while acquiring media from the
+  /// device, if any.
+  final String? error;
+
+  /// Width (in pixels) of the video captured from the device.
+  final int? width;
+
+  /// Height (in pixels) of the video captured from the device.
+  final int? height;
+
+  /// Frame rate of the video captured from the device.
+  final double? frameRate;
+
+  /// Peak audio level (in `0..=100` range) captured from the device.
+  final int? audioLevel;
+
+  const DeviceDiagnostics({
+    required this.deviceId,
+    required this.label,
+    required this.kind,
+    this.error,
+    this.width,
+    this.height,
+    this.frameRate,
+    this.audioLevel,
+  });
+
+  @override
+  int get hashCode =>
+      deviceId.hashCode ^
+      label.hashCode ^
+      kind.hashCode ^
+      error.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      frameRate.hashCode ^
+      audioLevel.hashCode;
+
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DeviceDiagnostics &&
+          runtimeType == other.runtimeType &&
+          deviceId == other.deviceId &&
+          label == other.label &&
+          kind == other.kind &&
+          error == other.error &&
+          width == other.width &&
+          height == other.height &&
+          frameRate == other.frameRate &&
+          audioLevel == other.audioLevel;
+}
+
+/// Report of pre-call diagnostics.
+class DiagnosticsReport {
+  /// Results of checking every available media input device.
+  final List<DeviceDiagnostics> devices;
+
+  /// Results of the local ICE gathering and connectivity check.
+  final IceDiagnostics ice;
+
+  /// Throughput estimated over a loopback connection, if it has been
+  /// established.
+  final ThroughputDiagnostics? throughput;
+
+  const DiagnosticsReport({
+    required this.devices,
+    required this.ice,
+    this.throughput,
+  });
+
+  /// Constructs a [`ForeignClass`] from the given raw pointer via
+  /// [`Box::from_raw()`].
+  ///
+  /// # Safety
+  ///
+  /// Same as for [`Box::from_raw()`].
+  static DiagnosticsReport fromPtr({required int ptr}) => RustLib.instance.api
+      .crateApiDartApiDiagnosticsDiagnosticsReportFromPtr(ptr: ptr);
+
+  @override
+  int get hashCode => devices.hashCode ^ ice.hashCode ^ throughput.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DiagnosticsReport &&
+          runtimeType == other.runtimeType &&
+          devices == other.devices &&
+          ice == other.ice &&
+          throughput == other.throughput;
+}
+
+/// Results of the local ICE gathering and connectivity check.
+class IceDiagnostics {
+  /// Number of gathered host candidates.
+  final int hostCandidates;
+
+  /// Number of gathered server reflexive candidates.
+  final int srflxCandidates;
+
+  /// Number of gathered relay candidates.
+  final int relayCandidates;
+
+  /// Indicator whether the loopback connection has been established.
+  final bool connected;
+
+  /// Descriptions of the errors occurred while gathering candidates or
+  /// establishing the loopback connection.
+  final List<String> errors;
+
+  const IceDiagnostics({
+    required this.hostCandidates,
+    required this.srflxCandidates,
+    required this.relayCandidates,
+    required this.connected,
+    required this.errors,
+  });
+
+  @override
+  int get hashCode =>
+      hostCandidates.hashCode ^
+      srflxCandidates.hashCode ^
+      relayCandidates.hashCode ^
+      connected.hashCode ^
+      errors.hashCode;
+
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IceDiagnostics &&
+          runtimeType == other.runtimeType &&
+          hostCandidates == other.hostCandidates &&
+          srflxCandidates == other.srflxCandidates &&
+          relayCandidates == other.relayCandidates &&
+          connected == other.connected &&
+          errors == other.errors;
+}
+
+/// Throughput estimated over a loopback connection.
+class ThroughputDiagnostics {
+  /// Number of bytes sent during the measurement.
+  final PlatformInt64 bytesSent;
+
+  /// Duration of the measurement in milliseconds.
+  final PlatformInt64 durationMs;
+
+  /// Estimated bitrate in bits per second.
+  final PlatformInt64 bitrateBps;
+
+  const ThroughputDiagnostics({
+    required this.bytesSent,
+    required this.durationMs,
+    required this.bitrateBps,
+  });
+
+  @override
+  int get hashCode =>
+      bytesSent.hashCode ^
+      durationMs.hashCode ^
+      bitrateBps.hashCode;
+
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ThroughputDiagnostics &&
+          runtimeType == other.runtimeType &&
+          bytesSent == other.bytesSent &&
+          durationMs == other.durationMs &&
+          bitrateBps == other.bitrateBps;
+}
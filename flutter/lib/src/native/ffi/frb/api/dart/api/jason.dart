@@ -19,6 +19,27 @@ abstract class Jason implements RustOpaqueInterface {
   /// Closes this [`Jason`].
   void jasonDispose();
 
+  /// Enables recording of RPC sessions of all the [`Room`]s initialized
+  /// after this call.
+  ///
+  /// Has no effect if this library is built without `rpc-recording` Cargo
+  /// feature.
+  void jasonEnableRpcRecording();
+
+  /// Makes all the connections with a media server created after this call
+  /// fall back to [Server-Sent Events] transport if a [WebSocket] connection
+  /// cannot be established.
+  ///
+  /// [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+  /// [WebSocket]: https://en.wikipedia.org/wiki/WebSocket
+  void jasonEnableSseFallback();
+
+  /// Exports the recorded RPC sessions as [JSON Lines], or returns [`None`]
+  /// if recording hasn't been enabled.
+  ///
+  /// [JSON Lines]: https://jsonlines.org
+  String? jasonExportRpcRecording();
+
   /// Creates a new [`Room`] and returns its [`RoomHandle`].
   RoomHandle jasonInitRoom();
 
@@ -34,6 +55,25 @@ abstract class Jason implements RustOpaqueInterface {
   /// [ICE]: https://webrtcglossary.com/ice
   Object jasonNetworkChanged();
 
+  /// Sets callback, invoked with a [`NetworkState`] whenever it changes.
+  ///
+  /// [`NetworkState`]: crate::api::NetworkState
+  void jasonOnNetworkStateChange({required Object cb});
+
+  /// Notifies [`Jason`] whether the device is connected to a network, as
+  /// reported by a connectivity plugin.
+  ///
+  /// Calls [`Jason::jason_network_changed()`] automatically once the network
+  /// becomes available again.
+  void jasonSetNetworkOnline({required bool online});
+
+  /// Makes all the [`Room`]s initialized after this call share a single
+  /// connection with a media server.
+  ///
+  /// Each [`Room`] is still joined and left independently, while the shared
+  /// connection is closed once all of them are left.
+  void jasonShareRpcConnection();
+
   /// Instantiates a new [`Jason`] interface to interact with this library.
   factory Jason() => RustLib.instance.api.crateApiDartApiJasonJasonNew();
 }
@@ -22,6 +22,15 @@ List<LocalMediaTrack> vecLocalTracksFromRaw({required int ptr}) => RustLib
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>
 abstract class LocalMediaTrack implements RustOpaqueInterface, ForeignClass {
+  /// Applies the provided [`ApiDeviceVideoTrackConstraints`] to this live
+  /// device video [`LocalMediaTrack`], without re-acquiring its media source.
+  ///
+  /// __NOTE__: Not supported by `medea_flutter_webrtc` yet, so always
+  /// completes with an error.
+  Object applyConstraints({
+    required ApiDeviceVideoTrackConstraints constraints,
+  });
+
   /// Frees the data behind the provided opaque local track.
   Object free();
 
@@ -68,6 +77,21 @@ abstract class LocalMediaTrack implements RustOpaqueInterface, ForeignClass {
   /// [`LocalMediaTrack`].
   Object isEchoCancellationEnabled();
 
+  /// Indicates whether the camera producing this [`LocalMediaTrack`]
+  /// supports exposure compensation control via
+  /// [`LocalMediaTrack::set_exposure_compensation()`].
+  bool isExposureCompensationAvailable();
+
+  /// Indicates whether the camera producing this [`LocalMediaTrack`]
+  /// supports focus distance control via
+  /// [`LocalMediaTrack::set_focus_distance()`].
+  bool isFocusDistanceAvailable();
+
+  /// Indicates whether the camera producing this [`LocalMediaTrack`]
+  /// supports focus mode control via
+  /// [`LocalMediaTrack::set_focus_mode()`].
+  bool isFocusModeAvailable();
+
   /// Indicates whether high-pass filter is enabled for this
   /// [`LocalMediaTrack`].
   ///
@@ -82,6 +106,25 @@ abstract class LocalMediaTrack implements RustOpaqueInterface, ForeignClass {
   /// [`LocalMediaTrack`].
   bool isOnAudioLevelAvailable();
 
+  /// Indicates whether the camera producing this [`LocalMediaTrack`]
+  /// supports torch control via
+  /// [`LocalMediaTrack::set_torch_enabled()`].
+  ///
+  /// __NOTE__: Only supported on iOS.
+  bool isTorchAvailable();
+
+  /// Indicates whether the camera producing this [`LocalMediaTrack`]
+  /// supports white balance mode control via
+  /// [`LocalMediaTrack::set_white_balance_mode()`].
+  bool isWhiteBalanceModeAvailable();
+
+  /// Indicates whether the camera producing this [`LocalMediaTrack`]
+  /// supports zoom control via
+  /// [`LocalMediaTrack::set_zoom()`].
+  ///
+  /// __NOTE__: Only supported on iOS.
+  bool isZoomAvailable();
+
   /// Returns a [`MediaKind::Audio`] if the provided [`LocalMediaTrack`]
   /// represents an audio track, or a [`MediaKind::Video`] if it represents a
   /// video track.
@@ -111,6 +154,18 @@ abstract class LocalMediaTrack implements RustOpaqueInterface, ForeignClass {
   /// Toggles acoustic echo cancellation for this [`LocalMediaTrack`].
   Object setEchoCancellationEnabled({required bool enabled});
 
+  /// Sets the provided exposure compensation (in exposure value stops) of the
+  /// camera producing this [`LocalMediaTrack`].
+  Object setExposureCompensation({required double compensation});
+
+  /// Sets the provided focus distance (in meters) of the camera producing
+  /// this [`LocalMediaTrack`].
+  Object setFocusDistance({required double distance});
+
+  /// Sets the provided focus [`MeteringMode`] of the camera producing this
+  /// [`LocalMediaTrack`].
+  Object setFocusMode({required MeteringMode mode});
+
   /// Toggles high-pass filter for this [`LocalMediaTrack`].
   ///
   /// __NOTE__: Only supported on desktop platforms.
@@ -124,6 +179,18 @@ abstract class LocalMediaTrack implements RustOpaqueInterface, ForeignClass {
   /// __NOTE__: Only supported on desktop platforms.
   Object setNoiseSuppressionLevel({required NoiseSuppressionLevel level});
 
+  /// Toggles the torch (fill light) of the camera producing this
+  /// [`LocalMediaTrack`].
+  Object setTorchEnabled({required bool enabled});
+
+  /// Sets the provided white balance [`MeteringMode`] of the camera producing
+  /// this [`LocalMediaTrack`].
+  Object setWhiteBalanceMode({required MeteringMode mode});
+
+  /// Sets the provided zoom level of the camera producing this
+  /// [`LocalMediaTrack`].
+  Object setZoom({required double zoom});
+
   /// Returns a [`media::MediaStreamTrackState::Live`] if this
   /// [`LocalMediaTrack`] is active, or a
   /// [`media::MediaStreamTrackState::Ended`] if it has ended.
@@ -6,13 +6,19 @@
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 import '../../../frb_generated.dart';
+import '../../../media.dart';
 import '../../../media/constraints.dart';
 import '../api.dart';
+import 'diagnostics.dart';
 
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `from`
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner< MediaManagerHandle>>
 abstract class MediaManagerHandle implements RustOpaqueInterface {
+  /// Returns [`ApiDeviceCapabilities`] of the media input device with the
+  /// provided `device_id`.
+  Object deviceCapabilities({required String deviceId});
+
   /// Returns a list of [`ApiMediaDeviceDetails`] objects representing
   /// available media input and devices, such as microphones, cameras, and
   /// so forth.
@@ -43,6 +49,23 @@ abstract class MediaManagerHandle implements RustOpaqueInterface {
   /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
   void onDeviceChange({required Object cb});
 
+  /// Subscribes onto the [`PermissionState`] changes of the provided
+  /// [`PermissionKind`].
+  ///
+  /// [`PermissionState`]: core::PermissionState
+  Object onPermissionChange({required PermissionKind kind, required Object cb});
+
+  /// Returns the current [`PermissionState`] of the provided
+  /// [`PermissionKind`].
+  ///
+  /// [`PermissionState`]: core::PermissionState
+  Object permissionState({required PermissionKind kind});
+
+  /// Runs pre-call diagnostics of media devices, network and connectivity
+  /// via the provided [`ApiIceServer`]s, resolving with a
+  /// [`DiagnosticsReport`].
+  Object runDiagnostics({required List<ApiIceServer> iceServers});
+
   /// Sets the microphone volume level in percents.
   Object setMicrophoneVolume({required PlatformInt64 level});
 
@@ -70,6 +70,45 @@ abstract class RoomHandle implements RustOpaqueInterface {
   /// (e.g. `wss://medea.com/MyConf1/Alice?token=777`).
   Object join({required String token});
 
+  /// Connects to a media server and joins the [`Room`] with the provided
+  /// `credential`, so it never appears in the connection URL.
+  ///
+  /// The `url` has the following format:
+  /// `{{ Host URL }}/{{ Room ID }}/{{ Member ID }}`
+  /// (e.g. `wss://medea.com/MyConf1/Alice`).
+  ///
+  /// Additional HTTP `headers` are sent in a handshake request of the
+  /// [WebSocket] connection with a media server.
+  ///
+  /// [WebSocket]: https://en.wikipedia.org/wiki/WebSocket
+  Object joinWithCredential({
+    required String url,
+    required String credential,
+    required Map<String, String> headers,
+  });
+
+  /// Leaves this [`Room`] gracefully, reporting the provided `reason` of
+  /// leaving to a media server.
+  ///
+  /// Waits for a media server to acknowledge the leaving (for 3 seconds at
+  /// most), stops all the local media tracks sent in this [`Room`] and
+  /// resolves with its final [`RoomCloseReason`].
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::leave()`] method errors.
+  Object leave({String? reason});
+
+  /// Returns all the `Member`s currently present in this [`Room`]
+  /// (including the local one).
+  ///
+  /// Doesn't depend on any media being exchanged with these `Member`s.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::members()`] method errors.
+  List<RoomMember> members();
+
   /// Mutes outbound audio in the provided [`Room`].
   Object muteAudio({MediaSourceKind? sourceKind});
 
@@ -124,6 +163,30 @@ abstract class RoomHandle implements RustOpaqueInterface {
   /// [`set_local_media_settings()`]: RoomHandle::set_local_media_settings
   void onLocalTrack({required Object cb});
 
+  /// Sets a callback to be invoked once a new `Member` joins the provided
+  /// [`Room`], providing a [`RoomMember`].
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::on_member_joined()`] method errors.
+  void onMemberJoined({required Object cb});
+
+  /// Sets a callback to be invoked with an ID of a `Member` who left the
+  /// provided [`Room`].
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::on_member_left()`] method errors.
+  void onMemberLeft({required Object cb});
+
+  /// Sets a callback to be invoked once metadata of some `Member` of the
+  /// provided [`Room`] changes, providing an actual [`RoomMember`].
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::on_member_updated()`] method errors.
+  void onMemberUpdated({required Object cb});
+
   /// Sets a callback to be invoked once a new [`Connection`] with some remote
   /// `Peer` is established.
   ///
@@ -134,6 +197,31 @@ abstract class RoomHandle implements RustOpaqueInterface {
   /// [`Connection`]: connection::Connection
   void onNewConnection({required Object cb});
 
+  /// Sets a callback to be invoked with actual [`SignallingLatency`]
+  /// statistics each time a round-trip time of the signalling connection
+  /// with a media server is measured.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::on_signalling_latency()`] method errors.
+  void onSignallingLatency({required Object cb});
+
+  /// Sets a callback to be invoked with a name of an event received from a
+  /// media server, which this client doesn't recognize.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::on_unknown_event()`] method errors.
+  void onUnknownEvent({required Object cb});
+
+  /// Returns name of the transport used by the signalling connection with a
+  /// media server (`WebSocket` or `SSE`), if it has been established.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::rpc_transport_kind()`] method errors.
+  String? rpcTransportKind();
+
   /// Updates this [`Room`]'s [`ApiMediaStreamSettings`].
   ///
   /// This affects all the [`PeerConnection`]s in this [`Room`]. If
@@ -143,6 +231,10 @@ abstract class RoomHandle implements RustOpaqueInterface {
   /// sending peers, so that might cause a new [getUserMedia()][1] request to
   /// happen.
   ///
+  /// __NOTE__: Changing constraints of a live track is not supported by
+  /// `medea_flutter_webrtc` yet, so even resolution or frame rate changes
+  /// re-acquire the affected media tracks.
+  ///
   /// Media obtaining/injection errors are additionally fired to a
   /// [`on_failed_local_media`] callback.
   ///
@@ -169,6 +261,14 @@ abstract class RoomHandle implements RustOpaqueInterface {
     required bool rollbackOnFail,
   });
 
+  /// Returns the current [`SignallingLatency`] statistics of the signalling
+  /// connection with a media server, if any has been measured yet.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::signalling_latency()`] method errors.
+  SignallingLatency? signallingLatency();
+
   /// Unmutes outbound audio in the provided [`Room`].
   Object unmuteAudio({MediaSourceKind? sourceKind});
 
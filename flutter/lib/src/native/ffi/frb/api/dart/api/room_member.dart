@@ -0,0 +1,41 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../../../frb_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`
+
+/// `Member` present in a [`Room`].
+class RoomMember {
+  /// ID of this `Member`.
+  final String id;
+
+  /// Opaque metadata of this `Member` (display name, avatar URL, etc.).
+  final Map<String, String> metadata;
+
+  const RoomMember({required this.id, required this.metadata});
+
+  /// Constructs a [`ForeignClass`] from the given raw pointer via
+  /// [`Box::from_raw()`].
+  ///
+  /// # Safety
+  ///
+  /// Same as for [`Box::from_raw()`].
+  static RoomMember fromPtr({required int ptr}) => RustLib.instance.api
+      .crateApiDartApiRoomMemberRoomMemberFromPtr(ptr: ptr);
+
+  @override
+  int get hashCode => id.hashCode ^ metadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomMember &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          metadata == other.metadata;
+}
@@ -0,0 +1,59 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import '../../../frb_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`
+
+/// Round-trip latency statistics of a signalling connection with a media
+/// server.
+class SignallingLatency {
+  /// Round-trip time of the last measurement in milliseconds.
+  final double rttMs;
+
+  /// Smoothed round-trip time in milliseconds.
+  final double smoothedRttMs;
+
+  /// Round-trip time variation (jitter) in milliseconds.
+  final double jitterMs;
+
+  /// Number of measurements these statistics are calculated from.
+  final int samples;
+
+  const SignallingLatency({
+    required this.rttMs,
+    required this.smoothedRttMs,
+    required this.jitterMs,
+    required this.samples,
+  });
+
+  /// Constructs a [`ForeignClass`] from the given raw pointer via
+  /// [`Box::from_raw()`].
+  ///
+  /// # Safety
+  ///
+  /// Same as for [`Box::from_raw()`].
+  static SignallingLatency fromPtr({required int ptr}) => RustLib.instance.api
+      .crateApiDartApiSignallingLatencySignallingLatencyFromPtr(ptr: ptr);
+
+  @override
+  int get hashCode =>
+      rttMs.hashCode ^
+      smoothedRttMs.hashCode ^
+      jitterMs.hashCode ^
+      samples.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SignallingLatency &&
+          runtimeType == other.runtimeType &&
+          rttMs == other.rttMs &&
+          smoothedRttMs == other.smoothedRttMs &&
+          jitterMs == other.jitterMs &&
+          samples == other.samples;
+}
@@ -107,4 +107,10 @@ enum RoomCloseKind {
   /// Usually this means that either `Member` or `Room` was deleted from the
   /// server.
   evicted,
+
+  /// Client API protocol version of the client is incompatible with the
+  /// server one.
+  ///
+  /// Most likely either the client or the server should be upgraded.
+  incompatibleProtocol,
 }
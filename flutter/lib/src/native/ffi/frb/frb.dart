@@ -1,10 +1,13 @@
 export 'api/dart/api.dart';
 export 'api/dart/api/connection_handle.dart';
+export 'api/dart/api/diagnostics.dart';
 export 'api/dart/api/jason.dart';
 export 'api/dart/api/local_media_track.dart';
 export 'api/dart/api/reconnect_handle.dart';
 export 'api/dart/api/remote_media_track.dart';
 export 'api/dart/api/room_close_reason.dart';
+export 'api/dart/api/room_member.dart';
+export 'api/dart/api/signalling_latency.dart';
 export 'api/dart/api/media_manager.dart';
 export 'api/dart/api/member_connection_state.dart';
 export 'api/dart/api/room.dart';
@@ -10,6 +10,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 import 'api/dart/api.dart';
 import 'api/dart/api/connection_handle.dart';
+import 'api/dart/api/diagnostics.dart';
 import 'api/dart/api/jason.dart';
 import 'api/dart/api/local_media_track.dart';
 import 'api/dart/api/media_manager.dart';
@@ -18,6 +19,8 @@ import 'api/dart/api/reconnect_handle.dart';
 import 'api/dart/api/remote_media_track.dart';
 import 'api/dart/api/room.dart';
 import 'api/dart/api/room_close_reason.dart';
+import 'api/dart/api/room_member.dart';
+import 'api/dart/api/signalling_latency.dart';
 import 'api/shared.dart';
 import 'frb_generated.dart';
 import 'media.dart';
@@ -150,6 +153,14 @@ abstract class RustLibApi extends BaseApi {
 
   void crateApiDartApiJasonJasonJasonDispose({required Jason that});
 
+  void crateApiDartApiJasonJasonJasonEnableRpcRecording({required Jason that});
+
+  void crateApiDartApiJasonJasonJasonEnableSseFallback({required Jason that});
+
+  String? crateApiDartApiJasonJasonJasonExportRpcRecording({
+    required Jason that,
+  });
+
   RoomHandle crateApiDartApiJasonJasonJasonInitRoom({required Jason that});
 
   MediaManagerHandle crateApiDartApiJasonJasonJasonMediaManager({
@@ -158,8 +169,25 @@ abstract class RustLibApi extends BaseApi {
 
   Object crateApiDartApiJasonJasonJasonNetworkChanged({required Jason that});
 
+  void crateApiDartApiJasonJasonJasonOnNetworkStateChange({
+    required Jason that,
+    required Object cb,
+  });
+
+  void crateApiDartApiJasonJasonJasonSetNetworkOnline({
+    required Jason that,
+    required bool online,
+  });
+
+  void crateApiDartApiJasonJasonJasonShareRpcConnection({required Jason that});
+
   Jason crateApiDartApiJasonJasonNew();
 
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackApplyConstraints({
+    required LocalMediaTrack that,
+    required ApiDeviceVideoTrackConstraints constraints,
+  });
+
   Object crateApiDartApiLocalMediaTrackLocalMediaTrackFree({
     required LocalMediaTrack that,
   });
@@ -189,6 +217,19 @@ abstract class RustLibApi extends BaseApi {
     required LocalMediaTrack that,
   });
 
+  bool
+  crateApiDartApiLocalMediaTrackLocalMediaTrackIsExposureCompensationAvailable({
+    required LocalMediaTrack that,
+  });
+
+  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsFocusDistanceAvailable({
+    required LocalMediaTrack that,
+  });
+
+  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsFocusModeAvailable({
+    required LocalMediaTrack that,
+  });
+
   Object crateApiDartApiLocalMediaTrackLocalMediaTrackIsHighPassFilterEnabled({
     required LocalMediaTrack that,
   });
@@ -202,6 +243,19 @@ abstract class RustLibApi extends BaseApi {
     required LocalMediaTrack that,
   });
 
+  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsTorchAvailable({
+    required LocalMediaTrack that,
+  });
+
+  bool
+  crateApiDartApiLocalMediaTrackLocalMediaTrackIsWhiteBalanceModeAvailable({
+    required LocalMediaTrack that,
+  });
+
+  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsZoomAvailable({
+    required LocalMediaTrack that,
+  });
+
   MediaKind crateApiDartApiLocalMediaTrackLocalMediaTrackKind({
     required LocalMediaTrack that,
   });
@@ -232,6 +286,21 @@ abstract class RustLibApi extends BaseApi {
     required bool enabled,
   });
 
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetExposureCompensation({
+    required LocalMediaTrack that,
+    required double compensation,
+  });
+
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetFocusDistance({
+    required LocalMediaTrack that,
+    required double distance,
+  });
+
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetFocusMode({
+    required LocalMediaTrack that,
+    required MeteringMode mode,
+  });
+
   Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetHighPassFilterEnabled({
     required LocalMediaTrack that,
     required bool enabled,
@@ -248,10 +317,30 @@ abstract class RustLibApi extends BaseApi {
     required NoiseSuppressionLevel level,
   });
 
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetTorchEnabled({
+    required LocalMediaTrack that,
+    required bool enabled,
+  });
+
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetWhiteBalanceMode({
+    required LocalMediaTrack that,
+    required MeteringMode mode,
+  });
+
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetZoom({
+    required LocalMediaTrack that,
+    required double zoom,
+  });
+
   Object crateApiDartApiLocalMediaTrackLocalMediaTrackState({
     required LocalMediaTrack that,
   });
 
+  Object crateApiDartApiMediaManagerMediaManagerHandleDeviceCapabilities({
+    required MediaManagerHandle that,
+    required String deviceId,
+  });
+
   Object crateApiDartApiMediaManagerMediaManagerHandleEnumerateDevices({
     required MediaManagerHandle that,
   });
@@ -279,6 +368,22 @@ abstract class RustLibApi extends BaseApi {
     required Object cb,
   });
 
+  Object crateApiDartApiMediaManagerMediaManagerHandleOnPermissionChange({
+    required MediaManagerHandle that,
+    required PermissionKind kind,
+    required Object cb,
+  });
+
+  Object crateApiDartApiMediaManagerMediaManagerHandlePermissionState({
+    required MediaManagerHandle that,
+    required PermissionKind kind,
+  });
+
+  Object crateApiDartApiMediaManagerMediaManagerHandleRunDiagnostics({
+    required MediaManagerHandle that,
+    required List<ApiIceServer> iceServers,
+  });
+
   Object crateApiDartApiMediaManagerMediaManagerHandleSetMicrophoneVolume({
     required MediaManagerHandle that,
     required PlatformInt64 level,
@@ -396,6 +501,22 @@ abstract class RustLibApi extends BaseApi {
     required String token,
   });
 
+  Object crateApiDartApiRoomRoomHandleJoinWithCredential({
+    required RoomHandle that,
+    required String url,
+    required String credential,
+    required Map<String, String> headers,
+  });
+
+  Object crateApiDartApiRoomRoomHandleLeave({
+    required RoomHandle that,
+    String? reason,
+  });
+
+  List<RoomMember> crateApiDartApiRoomRoomHandleMembers({
+    required RoomHandle that,
+  });
+
   Object crateApiDartApiRoomRoomHandleMuteAudio({
     required RoomHandle that,
     MediaSourceKind? sourceKind,
@@ -426,11 +547,40 @@ abstract class RustLibApi extends BaseApi {
     required Object cb,
   });
 
+  void crateApiDartApiRoomRoomHandleOnMemberJoined({
+    required RoomHandle that,
+    required Object cb,
+  });
+
+  void crateApiDartApiRoomRoomHandleOnMemberLeft({
+    required RoomHandle that,
+    required Object cb,
+  });
+
+  void crateApiDartApiRoomRoomHandleOnMemberUpdated({
+    required RoomHandle that,
+    required Object cb,
+  });
+
   void crateApiDartApiRoomRoomHandleOnNewConnection({
     required RoomHandle that,
     required Object cb,
   });
 
+  void crateApiDartApiRoomRoomHandleOnSignallingLatency({
+    required RoomHandle that,
+    required Object cb,
+  });
+
+  void crateApiDartApiRoomRoomHandleOnUnknownEvent({
+    required RoomHandle that,
+    required Object cb,
+  });
+
+  String? crateApiDartApiRoomRoomHandleRpcTransportKind({
+    required RoomHandle that,
+  });
+
   Object crateApiDartApiRoomRoomHandleSetLocalMediaSettings({
     required RoomHandle that,
     required ApiMediaStreamSettings settings,
@@ -438,6 +588,10 @@ abstract class RustLibApi extends BaseApi {
     required bool rollbackOnFail,
   });
 
+  SignallingLatency? crateApiDartApiRoomRoomHandleSignallingLatency({
+    required RoomHandle that,
+  });
+
   Object crateApiDartApiRoomRoomHandleUnmuteAudio({
     required RoomHandle that,
     MediaSourceKind? sourceKind,
@@ -448,6 +602,14 @@ abstract class RustLibApi extends BaseApi {
     MediaSourceKind? sourceKind,
   });
 
+  ApiDeviceCapabilities crateApiDartApiDeviceCapabilitiesFromRaw({
+    required int ptr,
+  });
+
+  DiagnosticsReport crateApiDartApiDiagnosticsDiagnosticsReportFromPtr({
+    required int ptr,
+  });
+
   void crateApiDartApiLogDartException({
     required String message,
     required String stackTrace,
@@ -464,12 +626,18 @@ abstract class RustLibApi extends BaseApi {
     required int ptr,
   });
 
+  RoomMember crateApiDartApiRoomMemberRoomMemberFromPtr({required int ptr});
+
   void crateApiDartApiSetDartOpaqueMessagePort({
     required PlatformInt64 dartHandlerPort,
   });
 
   Object crateApiDartApiSetLogLevel({required LogLevel level});
 
+  SignallingLatency crateApiDartApiSignallingLatencySignallingLatencyFromPtr({
+    required int ptr,
+  });
+
   List<LocalMediaTrack> crateApiDartApiLocalMediaTrackVecLocalTracksFromRaw({
     required int ptr,
   });
@@ -987,7 +1155,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "Jason_jason_dispose", argNames: ["that"]);
 
   @override
-  RoomHandle crateApiDartApiJasonJasonJasonInitRoom({required Jason that}) {
+  void crateApiDartApiJasonJasonJasonEnableRpcRecording({required Jason that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
@@ -999,27 +1167,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiDartApiJasonJasonJasonInitRoomConstMeta,
+        constMeta: kCrateApiDartApiJasonJasonJasonEnableRpcRecordingConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiJasonJasonJasonInitRoomConstMeta =>
+  TaskConstMeta
+  get kCrateApiDartApiJasonJasonJasonEnableRpcRecordingConstMeta =>
       const TaskConstMeta(
-        debugName: "Jason_jason_init_room",
+        debugName: "Jason_jason_enable_rpc_recording",
         argNames: ["that"],
       );
 
   @override
-  MediaManagerHandle crateApiDartApiJasonJasonJasonMediaManager({
-    required Jason that,
-  }) {
+  void crateApiDartApiJasonJasonJasonEnableSseFallback({required Jason that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
@@ -1031,25 +1197,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiDartApiJasonJasonJasonMediaManagerConstMeta,
+        constMeta: kCrateApiDartApiJasonJasonJasonEnableSseFallbackConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiJasonJasonJasonMediaManagerConstMeta =>
+  TaskConstMeta get kCrateApiDartApiJasonJasonJasonEnableSseFallbackConstMeta =>
       const TaskConstMeta(
-        debugName: "Jason_jason_media_manager",
+        debugName: "Jason_jason_enable_sse_fallback",
         argNames: ["that"],
       );
 
   @override
-  Object crateApiDartApiJasonJasonJasonNetworkChanged({required Jason that}) {
+  String? crateApiDartApiJasonJasonJasonExportRpcRecording({
+    required Jason that,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
@@ -1061,194 +1228,198 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiDartApiJasonJasonJasonNetworkChangedConstMeta,
+        constMeta: kCrateApiDartApiJasonJasonJasonExportRpcRecordingConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiJasonJasonJasonNetworkChangedConstMeta =>
+  TaskConstMeta
+  get kCrateApiDartApiJasonJasonJasonExportRpcRecordingConstMeta =>
       const TaskConstMeta(
-        debugName: "Jason_jason_network_changed",
+        debugName: "Jason_jason_export_rpc_recording",
         argNames: ["that"],
       );
 
   @override
-  Jason crateApiDartApiJasonJasonNew() {
+  RoomHandle crateApiDartApiJasonJasonJasonInitRoom({required Jason that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason(
+            that,
+            serializer,
+          );
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiDartApiJasonJasonNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiDartApiJasonJasonJasonInitRoomConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiJasonJasonNewConstMeta =>
-      const TaskConstMeta(debugName: "Jason_new", argNames: []);
+  TaskConstMeta get kCrateApiDartApiJasonJasonJasonInitRoomConstMeta =>
+      const TaskConstMeta(
+        debugName: "Jason_jason_init_room",
+        argNames: ["that"],
+      );
 
   @override
-  Object crateApiDartApiLocalMediaTrackLocalMediaTrackFree({
-    required LocalMediaTrack that,
+  MediaManagerHandle crateApiDartApiJasonJasonJasonMediaManager({
+    required Jason that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason(
             that,
             serializer,
           );
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiDartApiLocalMediaTrackLocalMediaTrackFreeConstMeta,
+        constMeta: kCrateApiDartApiJasonJasonJasonMediaManagerConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackFreeConstMeta =>
+  TaskConstMeta get kCrateApiDartApiJasonJasonJasonMediaManagerConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_free",
+        debugName: "Jason_jason_media_manager",
         argNames: ["that"],
       );
 
   @override
-  LocalMediaTrack crateApiDartApiLocalMediaTrackLocalMediaTrackFromPtr({
-    required int ptr,
-  }) {
+  Object crateApiDartApiJasonJasonJasonNetworkChanged({required Jason that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason(
+            that,
+            serializer,
+          );
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackFromPtrConstMeta,
-        argValues: [ptr],
+        constMeta: kCrateApiDartApiJasonJasonJasonNetworkChangedConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackFromPtrConstMeta =>
+  TaskConstMeta get kCrateApiDartApiJasonJasonJasonNetworkChangedConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_from_ptr",
-        argNames: ["ptr"],
+        debugName: "Jason_jason_network_changed",
+        argNames: ["that"],
       );
 
   @override
-  Object crateApiDartApiLocalMediaTrackLocalMediaTrackGetNoiseSuppressionLevel({
-    required LocalMediaTrack that,
+  void crateApiDartApiJasonJasonJasonOnNetworkStateChange({
+    required Jason that,
+    required Object cb,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason(
             that,
             serializer,
           );
+          sse_encode_DartOpaque(cb, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackGetNoiseSuppressionLevelConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiDartApiJasonJasonJasonOnNetworkStateChangeConstMeta,
+        argValues: [that, cb],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackGetNoiseSuppressionLevelConstMeta =>
+  get kCrateApiDartApiJasonJasonJasonOnNetworkStateChangeConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_get_noise_suppression_level",
-        argNames: ["that"],
+        debugName: "Jason_jason_on_network_state_change",
+        argNames: ["that", "cb"],
       );
 
   @override
-  Object crateApiDartApiLocalMediaTrackLocalMediaTrackGetTrack({
-    required LocalMediaTrack that,
+  void crateApiDartApiJasonJasonJasonSetNetworkOnline({
+    required Jason that,
+    required bool online,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason(
             that,
             serializer,
           );
+          sse_encode_bool(online, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackGetTrackConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiDartApiJasonJasonJasonSetNetworkOnlineConstMeta,
+        argValues: [that, online],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackGetTrackConstMeta =>
+  TaskConstMeta get kCrateApiDartApiJasonJasonJasonSetNetworkOnlineConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_get_track",
-        argNames: ["that"],
+        debugName: "Jason_jason_set_network_online",
+        argNames: ["that", "online"],
       );
 
   @override
-  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsAudioProcessingAvailable({
-    required LocalMediaTrack that,
-  }) {
+  void crateApiDartApiJasonJasonJasonShareRpcConnection({required Jason that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason(
             that,
             serializer,
           );
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsAudioProcessingAvailableConstMeta,
+        constMeta: kCrateApiDartApiJasonJasonJasonShareRpcConnectionConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
@@ -1256,49 +1427,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsAudioProcessingAvailableConstMeta =>
+  get kCrateApiDartApiJasonJasonJasonShareRpcConnectionConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_is_audio_processing_available",
+        debugName: "Jason_jason_share_rpc_connection",
         argNames: ["that"],
       );
 
   @override
-  Object crateApiDartApiLocalMediaTrackLocalMediaTrackIsAutoGainControlEnabled({
-    required LocalMediaTrack that,
-  }) {
+  Jason crateApiDartApiJasonJasonNew() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
-            that,
-            serializer,
-          );
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJason,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsAutoGainControlEnabledConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiDartApiJasonJasonNewConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsAutoGainControlEnabledConstMeta =>
-      const TaskConstMeta(
-        debugName: "LocalMediaTrack_is_auto_gain_control_enabled",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiDartApiJasonJasonNewConstMeta =>
+      const TaskConstMeta(debugName: "Jason_new", argNames: []);
 
   @override
-  Object
-  crateApiDartApiLocalMediaTrackLocalMediaTrackIsEchoCancellationEnabled({
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackApplyConstraints({
     required LocalMediaTrack that,
+    required ApiDeviceVideoTrackConstraints constraints,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -1308,6 +1469,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
+          sse_encode_box_autoadd_api_device_video_track_constraints(
+            constraints,
+            serializer,
+          );
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
         },
         codec: SseCodec(
@@ -1315,29 +1480,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsEchoCancellationEnabledConstMeta,
-        argValues: [that],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackApplyConstraintsConstMeta,
+        argValues: [that, constraints],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsEchoCancellationEnabledConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackApplyConstraintsConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_is_echo_cancellation_enabled",
-        argNames: ["that"],
+        debugName: "LocalMediaTrack_apply_constraints",
+        argNames: ["that", "constraints"],
       );
 
   @override
-  Object crateApiDartApiLocalMediaTrackLocalMediaTrackIsHighPassFilterEnabled({
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackFree({
     required LocalMediaTrack that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
@@ -1347,8 +1512,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsHighPassFilterEnabledConstMeta,
+        constMeta: kCrateApiDartApiLocalMediaTrackLocalMediaTrackFreeConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
@@ -1356,48 +1520,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsHighPassFilterEnabledConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackFreeConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_is_high_pass_filter_enabled",
+        debugName: "LocalMediaTrack_free",
         argNames: ["that"],
       );
 
   @override
-  Object
-  crateApiDartApiLocalMediaTrackLocalMediaTrackIsNoiseSuppressionEnabled({
-    required LocalMediaTrack that,
+  LocalMediaTrack crateApiDartApiLocalMediaTrackLocalMediaTrackFromPtr({
+    required int ptr,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
-            that,
-            serializer,
-          );
+          sse_encode_CastedPrimitive_usize(ptr, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsNoiseSuppressionEnabledConstMeta,
-        argValues: [that],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackFromPtrConstMeta,
+        argValues: [ptr],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsNoiseSuppressionEnabledConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackFromPtrConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_is_noise_suppression_enabled",
-        argNames: ["that"],
+        debugName: "LocalMediaTrack_from_ptr",
+        argNames: ["ptr"],
       );
 
   @override
-  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsOnAudioLevelAvailable({
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackGetNoiseSuppressionLevel({
     required LocalMediaTrack that,
   }) {
     return handler.executeSync(
@@ -1411,11 +1572,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsOnAudioLevelAvailableConstMeta,
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackGetNoiseSuppressionLevelConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
@@ -1423,14 +1584,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsOnAudioLevelAvailableConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackGetNoiseSuppressionLevelConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_is_on_audio_level_available",
+        debugName: "LocalMediaTrack_get_noise_suppression_level",
         argNames: ["that"],
       );
 
   @override
-  MediaKind crateApiDartApiLocalMediaTrackLocalMediaTrackKind({
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackGetTrack({
     required LocalMediaTrack that,
   }) {
     return handler.executeSync(
@@ -1444,10 +1605,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_media_kind,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiDartApiLocalMediaTrackLocalMediaTrackKindConstMeta,
+        constMeta:
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackGetTrackConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
@@ -1455,14 +1617,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackKindConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackGetTrackConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_kind",
+        debugName: "LocalMediaTrack_get_track",
         argNames: ["that"],
       );
 
   @override
-  MediaSourceKind crateApiDartApiLocalMediaTrackLocalMediaTrackMediaSourceKind({
+  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsAudioProcessingAvailable({
     required LocalMediaTrack that,
   }) {
     return handler.executeSync(
@@ -1476,11 +1638,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_media_source_kind,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackMediaSourceKindConstMeta,
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsAudioProcessingAvailableConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
@@ -1488,16 +1650,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackMediaSourceKindConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsAudioProcessingAvailableConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_media_source_kind",
+        debugName: "LocalMediaTrack_is_audio_processing_available",
         argNames: ["that"],
       );
 
   @override
-  void crateApiDartApiLocalMediaTrackLocalMediaTrackOnAudioLevelChanged({
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackIsAutoGainControlEnabled({
     required LocalMediaTrack that,
-    required Object f,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -1507,32 +1668,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_DartOpaque(f, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackOnAudioLevelChangedConstMeta,
-        argValues: [that, f],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsAutoGainControlEnabledConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackOnAudioLevelChangedConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsAutoGainControlEnabledConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_on_audio_level_changed",
-        argNames: ["that", "f"],
+        debugName: "LocalMediaTrack_is_auto_gain_control_enabled",
+        argNames: ["that"],
       );
 
   @override
-  void crateApiDartApiLocalMediaTrackLocalMediaTrackOnEnded({
+  Object
+  crateApiDartApiLocalMediaTrackLocalMediaTrackIsEchoCancellationEnabled({
     required LocalMediaTrack that,
-    required Object f,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -1542,33 +1702,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_DartOpaque(f, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackOnEndedConstMeta,
-        argValues: [that, f],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsEchoCancellationEnabledConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackOnEndedConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsEchoCancellationEnabledConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_on_ended",
-        argNames: ["that", "f"],
+        debugName: "LocalMediaTrack_is_echo_cancellation_enabled",
+        argNames: ["that"],
       );
 
   @override
-  Object
-  crateApiDartApiLocalMediaTrackLocalMediaTrackSetAutoGainControlEnabled({
+  bool
+  crateApiDartApiLocalMediaTrackLocalMediaTrackIsExposureCompensationAvailable({
     required LocalMediaTrack that,
-    required bool enabled,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -1578,33 +1736,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_bool(enabled, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetAutoGainControlEnabledConstMeta,
-        argValues: [that, enabled],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsExposureCompensationAvailableConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetAutoGainControlEnabledConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsExposureCompensationAvailableConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_set_auto_gain_control_enabled",
-        argNames: ["that", "enabled"],
+        debugName: "LocalMediaTrack_is_exposure_compensation_available",
+        argNames: ["that"],
       );
 
   @override
-  Object
-  crateApiDartApiLocalMediaTrackLocalMediaTrackSetEchoCancellationEnabled({
+  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsFocusDistanceAvailable({
     required LocalMediaTrack that,
-    required bool enabled,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -1614,32 +1769,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_bool(enabled, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetEchoCancellationEnabledConstMeta,
-        argValues: [that, enabled],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsFocusDistanceAvailableConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetEchoCancellationEnabledConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsFocusDistanceAvailableConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_set_echo_cancellation_enabled",
-        argNames: ["that", "enabled"],
+        debugName: "LocalMediaTrack_is_focus_distance_available",
+        argNames: ["that"],
       );
 
   @override
-  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetHighPassFilterEnabled({
+  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsFocusModeAvailable({
     required LocalMediaTrack that,
-    required bool enabled,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -1649,33 +1802,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_bool(enabled, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetHighPassFilterEnabledConstMeta,
-        argValues: [that, enabled],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsFocusModeAvailableConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetHighPassFilterEnabledConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsFocusModeAvailableConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_set_high_pass_filter_enabled",
-        argNames: ["that", "enabled"],
+        debugName: "LocalMediaTrack_is_focus_mode_available",
+        argNames: ["that"],
       );
 
   @override
-  Object
-  crateApiDartApiLocalMediaTrackLocalMediaTrackSetNoiseSuppressionEnabled({
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackIsHighPassFilterEnabled({
     required LocalMediaTrack that,
-    required bool enabled,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -1685,7 +1835,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_bool(enabled, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
         },
         codec: SseCodec(
@@ -1693,24 +1842,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetNoiseSuppressionEnabledConstMeta,
-        argValues: [that, enabled],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsHighPassFilterEnabledConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetNoiseSuppressionEnabledConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsHighPassFilterEnabledConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_set_noise_suppression_enabled",
-        argNames: ["that", "enabled"],
+        debugName: "LocalMediaTrack_is_high_pass_filter_enabled",
+        argNames: ["that"],
       );
 
   @override
-  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetNoiseSuppressionLevel({
+  Object
+  crateApiDartApiLocalMediaTrackLocalMediaTrackIsNoiseSuppressionEnabled({
     required LocalMediaTrack that,
-    required NoiseSuppressionLevel level,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -1720,7 +1869,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_noise_suppression_level(level, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
         },
         codec: SseCodec(
@@ -1728,22 +1876,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetNoiseSuppressionLevelConstMeta,
-        argValues: [that, level],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsNoiseSuppressionEnabledConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetNoiseSuppressionLevelConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsNoiseSuppressionEnabledConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_set_noise_suppression_level",
-        argNames: ["that", "level"],
+        debugName: "LocalMediaTrack_is_noise_suppression_enabled",
+        argNames: ["that"],
       );
 
   @override
-  Object crateApiDartApiLocalMediaTrackLocalMediaTrackState({
+  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsOnAudioLevelAvailable({
     required LocalMediaTrack that,
   }) {
     return handler.executeSync(
@@ -1757,10 +1905,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiDartApiLocalMediaTrackLocalMediaTrackStateConstMeta,
+        constMeta:
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsOnAudioLevelAvailableConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
@@ -1768,32 +1917,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   TaskConstMeta
-  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackStateConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsOnAudioLevelAvailableConstMeta =>
       const TaskConstMeta(
-        debugName: "LocalMediaTrack_state",
+        debugName: "LocalMediaTrack_is_on_audio_level_available",
         argNames: ["that"],
       );
 
   @override
-  Object crateApiDartApiMediaManagerMediaManagerHandleEnumerateDevices({
-    required MediaManagerHandle that,
+  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsTorchAvailable({
+    required LocalMediaTrack that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiMediaManagerMediaManagerHandleEnumerateDevicesConstMeta,
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsTorchAvailableConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
@@ -1801,32 +1950,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   TaskConstMeta
-  get kCrateApiDartApiMediaManagerMediaManagerHandleEnumerateDevicesConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsTorchAvailableConstMeta =>
       const TaskConstMeta(
-        debugName: "MediaManagerHandle_enumerate_devices",
+        debugName: "LocalMediaTrack_is_torch_available",
         argNames: ["that"],
       );
 
   @override
-  Object crateApiDartApiMediaManagerMediaManagerHandleEnumerateDisplays({
-    required MediaManagerHandle that,
+  bool
+  crateApiDartApiLocalMediaTrackLocalMediaTrackIsWhiteBalanceModeAvailable({
+    required LocalMediaTrack that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiMediaManagerMediaManagerHandleEnumerateDisplaysConstMeta,
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsWhiteBalanceModeAvailableConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
@@ -1834,67 +1984,64 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   TaskConstMeta
-  get kCrateApiDartApiMediaManagerMediaManagerHandleEnumerateDisplaysConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsWhiteBalanceModeAvailableConstMeta =>
       const TaskConstMeta(
-        debugName: "MediaManagerHandle_enumerate_displays",
+        debugName: "LocalMediaTrack_is_white_balance_mode_available",
         argNames: ["that"],
       );
 
   @override
-  Object crateApiDartApiMediaManagerMediaManagerHandleInitLocalTracks({
-    required MediaManagerHandle that,
-    required ApiMediaStreamSettings caps,
+  bool crateApiDartApiLocalMediaTrackLocalMediaTrackIsZoomAvailable({
+    required LocalMediaTrack that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
-          sse_encode_box_autoadd_api_media_stream_settings(caps, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiMediaManagerMediaManagerHandleInitLocalTracksConstMeta,
-        argValues: [that, caps],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsZoomAvailableConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiMediaManagerMediaManagerHandleInitLocalTracksConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackIsZoomAvailableConstMeta =>
       const TaskConstMeta(
-        debugName: "MediaManagerHandle_init_local_tracks",
-        argNames: ["that", "caps"],
+        debugName: "LocalMediaTrack_is_zoom_available",
+        argNames: ["that"],
       );
 
   @override
-  Object crateApiDartApiMediaManagerMediaManagerHandleMicrophoneVolume({
-    required MediaManagerHandle that,
+  MediaKind crateApiDartApiLocalMediaTrackLocalMediaTrackKind({
+    required LocalMediaTrack that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData: sse_decode_media_kind,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiDartApiMediaManagerMediaManagerHandleMicrophoneVolumeConstMeta,
+        constMeta: kCrateApiDartApiLocalMediaTrackLocalMediaTrackKindConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
@@ -1902,33 +2049,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   TaskConstMeta
-  get kCrateApiDartApiMediaManagerMediaManagerHandleMicrophoneVolumeConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackKindConstMeta =>
       const TaskConstMeta(
-        debugName: "MediaManagerHandle_microphone_volume",
+        debugName: "LocalMediaTrack_kind",
         argNames: ["that"],
       );
 
   @override
-  Object
-  crateApiDartApiMediaManagerMediaManagerHandleMicrophoneVolumeIsAvailable({
-    required MediaManagerHandle that,
+  MediaSourceKind crateApiDartApiLocalMediaTrackLocalMediaTrackMediaSourceKind({
+    required LocalMediaTrack that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData: sse_decode_media_source_kind,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiMediaManagerMediaManagerHandleMicrophoneVolumeIsAvailableConstMeta,
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackMediaSourceKindConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
@@ -1936,96 +2082,97 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   TaskConstMeta
-  get kCrateApiDartApiMediaManagerMediaManagerHandleMicrophoneVolumeIsAvailableConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackMediaSourceKindConstMeta =>
       const TaskConstMeta(
-        debugName: "MediaManagerHandle_microphone_volume_is_available",
+        debugName: "LocalMediaTrack_media_source_kind",
         argNames: ["that"],
       );
 
   @override
-  void crateApiDartApiMediaManagerMediaManagerHandleOnDeviceChange({
-    required MediaManagerHandle that,
-    required Object cb,
+  void crateApiDartApiLocalMediaTrackLocalMediaTrackOnAudioLevelChanged({
+    required LocalMediaTrack that,
+    required Object f,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
-          sse_encode_DartOpaque(cb, serializer);
+          sse_encode_DartOpaque(f, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_DartOpaque,
+          decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiMediaManagerMediaManagerHandleOnDeviceChangeConstMeta,
-        argValues: [that, cb],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackOnAudioLevelChangedConstMeta,
+        argValues: [that, f],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiMediaManagerMediaManagerHandleOnDeviceChangeConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackOnAudioLevelChangedConstMeta =>
       const TaskConstMeta(
-        debugName: "MediaManagerHandle_on_device_change",
-        argNames: ["that", "cb"],
+        debugName: "LocalMediaTrack_on_audio_level_changed",
+        argNames: ["that", "f"],
       );
 
   @override
-  Object crateApiDartApiMediaManagerMediaManagerHandleSetMicrophoneVolume({
-    required MediaManagerHandle that,
-    required PlatformInt64 level,
+  void crateApiDartApiLocalMediaTrackLocalMediaTrackOnEnded({
+    required LocalMediaTrack that,
+    required Object f,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
-          sse_encode_i_64(level, serializer);
+          sse_encode_DartOpaque(f, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiMediaManagerMediaManagerHandleSetMicrophoneVolumeConstMeta,
-        argValues: [that, level],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackOnEndedConstMeta,
+        argValues: [that, f],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiMediaManagerMediaManagerHandleSetMicrophoneVolumeConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackOnEndedConstMeta =>
       const TaskConstMeta(
-        debugName: "MediaManagerHandle_set_microphone_volume",
-        argNames: ["that", "level"],
+        debugName: "LocalMediaTrack_on_ended",
+        argNames: ["that", "f"],
       );
 
   @override
-  Object crateApiDartApiMediaManagerMediaManagerHandleSetOutputAudioId({
-    required MediaManagerHandle that,
-    required String deviceId,
+  Object
+  crateApiDartApiLocalMediaTrackLocalMediaTrackSetAutoGainControlEnabled({
+    required LocalMediaTrack that,
+    required bool enabled,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
-          sse_encode_String(deviceId, serializer);
+          sse_encode_bool(enabled, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
         },
         codec: SseCodec(
@@ -2033,71 +2180,70 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiMediaManagerMediaManagerHandleSetOutputAudioIdConstMeta,
-        argValues: [that, deviceId],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetAutoGainControlEnabledConstMeta,
+        argValues: [that, enabled],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiMediaManagerMediaManagerHandleSetOutputAudioIdConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetAutoGainControlEnabledConstMeta =>
       const TaskConstMeta(
-        debugName: "MediaManagerHandle_set_output_audio_id",
-        argNames: ["that", "deviceId"],
+        debugName: "LocalMediaTrack_set_auto_gain_control_enabled",
+        argNames: ["that", "enabled"],
       );
 
   @override
-  ReconnectHandle crateApiDartApiReconnectHandleReconnectHandleFromPtr({
-    required int ptr,
+  Object
+  crateApiDartApiLocalMediaTrackLocalMediaTrackSetEchoCancellationEnabled({
+    required LocalMediaTrack that,
+    required bool enabled,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+            that,
+            serializer,
+          );
+          sse_encode_bool(enabled, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiReconnectHandleReconnectHandleFromPtrConstMeta,
-        argValues: [ptr],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetEchoCancellationEnabledConstMeta,
+        argValues: [that, enabled],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiReconnectHandleReconnectHandleFromPtrConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetEchoCancellationEnabledConstMeta =>
       const TaskConstMeta(
-        debugName: "ReconnectHandle_from_ptr",
-        argNames: ["ptr"],
+        debugName: "LocalMediaTrack_set_echo_cancellation_enabled",
+        argNames: ["that", "enabled"],
       );
 
   @override
-  Object crateApiDartApiReconnectHandleReconnectHandleReconnectWithBackoff({
-    required ReconnectHandle that,
-    required int startingDelay,
-    required double multiplier,
-    required int maxDelay,
-    int? maxElapsedTimeMs,
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetExposureCompensation({
+    required LocalMediaTrack that,
+    required double compensation,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
-          sse_encode_u_32(startingDelay, serializer);
-          sse_encode_f_64(multiplier, serializer);
-          sse_encode_u_32(maxDelay, serializer);
-          sse_encode_opt_box_autoadd_u_32(maxElapsedTimeMs, serializer);
+          sse_encode_f_64(compensation, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
         },
         codec: SseCodec(
@@ -2105,46 +2251,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiReconnectHandleReconnectHandleReconnectWithBackoffConstMeta,
-        argValues: [
-          that,
-          startingDelay,
-          multiplier,
-          maxDelay,
-          maxElapsedTimeMs,
-        ],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetExposureCompensationConstMeta,
+        argValues: [that, compensation],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiReconnectHandleReconnectHandleReconnectWithBackoffConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetExposureCompensationConstMeta =>
       const TaskConstMeta(
-        debugName: "ReconnectHandle_reconnect_with_backoff",
-        argNames: [
-          "that",
-          "startingDelay",
-          "multiplier",
-          "maxDelay",
-          "maxElapsedTimeMs",
-        ],
+        debugName: "LocalMediaTrack_set_exposure_compensation",
+        argNames: ["that", "compensation"],
       );
 
   @override
-  Object crateApiDartApiReconnectHandleReconnectHandleReconnectWithDelay({
-    required ReconnectHandle that,
-    required int delayMs,
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetFocusDistance({
+    required LocalMediaTrack that,
+    required double distance,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
-          sse_encode_u_32(delayMs, serializer);
+          sse_encode_f_64(distance, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
         },
         codec: SseCodec(
@@ -2152,63 +2286,69 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiReconnectHandleReconnectHandleReconnectWithDelayConstMeta,
-        argValues: [that, delayMs],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetFocusDistanceConstMeta,
+        argValues: [that, distance],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiReconnectHandleReconnectHandleReconnectWithDelayConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetFocusDistanceConstMeta =>
       const TaskConstMeta(
-        debugName: "ReconnectHandle_reconnect_with_delay",
-        argNames: ["that", "delayMs"],
+        debugName: "LocalMediaTrack_set_focus_distance",
+        argNames: ["that", "distance"],
       );
 
   @override
-  RemoteMediaTrack crateApiDartApiRemoteMediaTrackRemoteMediaTrackFromPtr({
-    required int ptr,
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetFocusMode({
+    required LocalMediaTrack that,
+    required MeteringMode mode,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+            that,
+            serializer,
+          );
+          sse_encode_metering_mode(mode, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackFromPtrConstMeta,
-        argValues: [ptr],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetFocusModeConstMeta,
+        argValues: [that, mode],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackFromPtrConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetFocusModeConstMeta =>
       const TaskConstMeta(
-        debugName: "RemoteMediaTrack_from_ptr",
-        argNames: ["ptr"],
+        debugName: "LocalMediaTrack_set_focus_mode",
+        argNames: ["that", "mode"],
       );
 
   @override
-  Object crateApiDartApiRemoteMediaTrackRemoteMediaTrackGetTrack({
-    required RemoteMediaTrack that,
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetHighPassFilterEnabled({
+    required LocalMediaTrack that,
+    required bool enabled,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
+          sse_encode_bool(enabled, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
         },
         codec: SseCodec(
@@ -2216,297 +2356,1402 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackGetTrackConstMeta,
-        argValues: [that],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetHighPassFilterEnabledConstMeta,
+        argValues: [that, enabled],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackGetTrackConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetHighPassFilterEnabledConstMeta =>
       const TaskConstMeta(
-        debugName: "RemoteMediaTrack_get_track",
-        argNames: ["that"],
+        debugName: "LocalMediaTrack_set_high_pass_filter_enabled",
+        argNames: ["that", "enabled"],
       );
 
   @override
-  MediaKind crateApiDartApiRemoteMediaTrackRemoteMediaTrackKind({
-    required RemoteMediaTrack that,
+  Object
+  crateApiDartApiLocalMediaTrackLocalMediaTrackSetNoiseSuppressionEnabled({
+    required LocalMediaTrack that,
+    required bool enabled,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
+          sse_encode_bool(enabled, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_media_kind,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackKindConstMeta,
-        argValues: [that],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetNoiseSuppressionEnabledConstMeta,
+        argValues: [that, enabled],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackKindConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetNoiseSuppressionEnabledConstMeta =>
       const TaskConstMeta(
-        debugName: "RemoteMediaTrack_kind",
-        argNames: ["that"],
+        debugName: "LocalMediaTrack_set_noise_suppression_enabled",
+        argNames: ["that", "enabled"],
       );
 
   @override
-  MediaDirection crateApiDartApiRemoteMediaTrackRemoteMediaTrackMediaDirection({
-    required RemoteMediaTrack that,
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetNoiseSuppressionLevel({
+    required LocalMediaTrack that,
+    required NoiseSuppressionLevel level,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
+          sse_encode_noise_suppression_level(level, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_media_direction,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackMediaDirectionConstMeta,
-        argValues: [that],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetNoiseSuppressionLevelConstMeta,
+        argValues: [that, level],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackMediaDirectionConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetNoiseSuppressionLevelConstMeta =>
       const TaskConstMeta(
-        debugName: "RemoteMediaTrack_media_direction",
-        argNames: ["that"],
+        debugName: "LocalMediaTrack_set_noise_suppression_level",
+        argNames: ["that", "level"],
       );
 
   @override
-  MediaSourceKind
-  crateApiDartApiRemoteMediaTrackRemoteMediaTrackMediaSourceKind({
-    required RemoteMediaTrack that,
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetTorchEnabled({
+    required LocalMediaTrack that,
+    required bool enabled,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
+          sse_encode_bool(enabled, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_media_source_kind,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackMediaSourceKindConstMeta,
-        argValues: [that],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetTorchEnabledConstMeta,
+        argValues: [that, enabled],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackMediaSourceKindConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetTorchEnabledConstMeta =>
       const TaskConstMeta(
-        debugName: "RemoteMediaTrack_media_source_kind",
-        argNames: ["that"],
+        debugName: "LocalMediaTrack_set_torch_enabled",
+        argNames: ["that", "enabled"],
       );
 
   @override
-  bool crateApiDartApiRemoteMediaTrackRemoteMediaTrackMuted({
-    required RemoteMediaTrack that,
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetWhiteBalanceMode({
+    required LocalMediaTrack that,
+    required MeteringMode mode,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
+          sse_encode_metering_mode(mode, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackMutedConstMeta,
-        argValues: [that],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetWhiteBalanceModeConstMeta,
+        argValues: [that, mode],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackMutedConstMeta =>
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetWhiteBalanceModeConstMeta =>
       const TaskConstMeta(
-        debugName: "RemoteMediaTrack_muted",
-        argNames: ["that"],
+        debugName: "LocalMediaTrack_set_white_balance_mode",
+        argNames: ["that", "mode"],
       );
 
   @override
-  void crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnMediaDirectionChanged({
-    required RemoteMediaTrack that,
-    required Object f,
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackSetZoom({
+    required LocalMediaTrack that,
+    required double zoom,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
             that,
             serializer,
           );
-          sse_encode_DartOpaque(f, serializer);
+          sse_encode_f_64(zoom, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
         constMeta:
-            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnMediaDirectionChangedConstMeta,
-        argValues: [that, f],
+            kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetZoomConstMeta,
+        argValues: [that, zoom],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackSetZoomConstMeta =>
+      const TaskConstMeta(
+        debugName: "LocalMediaTrack_set_zoom",
+        argNames: ["that", "zoom"],
+      );
+
+  @override
+  Object crateApiDartApiLocalMediaTrackLocalMediaTrackState({
+    required LocalMediaTrack that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiLocalMediaTrackLocalMediaTrackStateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiLocalMediaTrackLocalMediaTrackStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "LocalMediaTrack_state",
+        argNames: ["that"],
+      );
+
+  @override
+  Object crateApiDartApiMediaManagerMediaManagerHandleDeviceCapabilities({
+    required MediaManagerHandle that,
+    required String deviceId,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_String(deviceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiMediaManagerMediaManagerHandleDeviceCapabilitiesConstMeta,
+        argValues: [that, deviceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiMediaManagerMediaManagerHandleDeviceCapabilitiesConstMeta =>
+      const TaskConstMeta(
+        debugName: "MediaManagerHandle_device_capabilities",
+        argNames: ["that", "deviceId"],
+      );
+
+  @override
+  Object crateApiDartApiMediaManagerMediaManagerHandleEnumerateDevices({
+    required MediaManagerHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiMediaManagerMediaManagerHandleEnumerateDevicesConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiMediaManagerMediaManagerHandleEnumerateDevicesConstMeta =>
+      const TaskConstMeta(
+        debugName: "MediaManagerHandle_enumerate_devices",
+        argNames: ["that"],
+      );
+
+  @override
+  Object crateApiDartApiMediaManagerMediaManagerHandleEnumerateDisplays({
+    required MediaManagerHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiMediaManagerMediaManagerHandleEnumerateDisplaysConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiMediaManagerMediaManagerHandleEnumerateDisplaysConstMeta =>
+      const TaskConstMeta(
+        debugName: "MediaManagerHandle_enumerate_displays",
+        argNames: ["that"],
+      );
+
+  @override
+  Object crateApiDartApiMediaManagerMediaManagerHandleInitLocalTracks({
+    required MediaManagerHandle that,
+    required ApiMediaStreamSettings caps,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_api_media_stream_settings(caps, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiMediaManagerMediaManagerHandleInitLocalTracksConstMeta,
+        argValues: [that, caps],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiMediaManagerMediaManagerHandleInitLocalTracksConstMeta =>
+      const TaskConstMeta(
+        debugName: "MediaManagerHandle_init_local_tracks",
+        argNames: ["that", "caps"],
+      );
+
+  @override
+  Object crateApiDartApiMediaManagerMediaManagerHandleMicrophoneVolume({
+    required MediaManagerHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiMediaManagerMediaManagerHandleMicrophoneVolumeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiMediaManagerMediaManagerHandleMicrophoneVolumeConstMeta =>
+      const TaskConstMeta(
+        debugName: "MediaManagerHandle_microphone_volume",
+        argNames: ["that"],
+      );
+
+  @override
+  Object
+  crateApiDartApiMediaManagerMediaManagerHandleMicrophoneVolumeIsAvailable({
+    required MediaManagerHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiMediaManagerMediaManagerHandleMicrophoneVolumeIsAvailableConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiMediaManagerMediaManagerHandleMicrophoneVolumeIsAvailableConstMeta =>
+      const TaskConstMeta(
+        debugName: "MediaManagerHandle_microphone_volume_is_available",
+        argNames: ["that"],
+      );
+
+  @override
+  void crateApiDartApiMediaManagerMediaManagerHandleOnDeviceChange({
+    required MediaManagerHandle that,
+    required Object cb,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(cb, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta:
+            kCrateApiDartApiMediaManagerMediaManagerHandleOnDeviceChangeConstMeta,
+        argValues: [that, cb],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiMediaManagerMediaManagerHandleOnDeviceChangeConstMeta =>
+      const TaskConstMeta(
+        debugName: "MediaManagerHandle_on_device_change",
+        argNames: ["that", "cb"],
+      );
+
+  @override
+  Object crateApiDartApiMediaManagerMediaManagerHandleOnPermissionChange({
+    required MediaManagerHandle that,
+    required PermissionKind kind,
+    required Object cb,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_permission_kind(kind, serializer);
+          sse_encode_DartOpaque(cb, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiMediaManagerMediaManagerHandleOnPermissionChangeConstMeta,
+        argValues: [that, kind, cb],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiMediaManagerMediaManagerHandleOnPermissionChangeConstMeta =>
+      const TaskConstMeta(
+        debugName: "MediaManagerHandle_on_permission_change",
+        argNames: ["that", "kind", "cb"],
+      );
+
+  @override
+  Object crateApiDartApiMediaManagerMediaManagerHandlePermissionState({
+    required MediaManagerHandle that,
+    required PermissionKind kind,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_permission_kind(kind, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiMediaManagerMediaManagerHandlePermissionStateConstMeta,
+        argValues: [that, kind],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiMediaManagerMediaManagerHandlePermissionStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "MediaManagerHandle_permission_state",
+        argNames: ["that", "kind"],
+      );
+
+  @override
+  Object crateApiDartApiMediaManagerMediaManagerHandleRunDiagnostics({
+    required MediaManagerHandle that,
+    required List<ApiIceServer> iceServers,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_list_api_ice_server(iceServers, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiMediaManagerMediaManagerHandleRunDiagnosticsConstMeta,
+        argValues: [that, iceServers],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiMediaManagerMediaManagerHandleRunDiagnosticsConstMeta =>
+      const TaskConstMeta(
+        debugName: "MediaManagerHandle_run_diagnostics",
+        argNames: ["that", "iceServers"],
+      );
+
+  @override
+  Object crateApiDartApiMediaManagerMediaManagerHandleSetMicrophoneVolume({
+    required MediaManagerHandle that,
+    required PlatformInt64 level,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_i_64(level, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiMediaManagerMediaManagerHandleSetMicrophoneVolumeConstMeta,
+        argValues: [that, level],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiMediaManagerMediaManagerHandleSetMicrophoneVolumeConstMeta =>
+      const TaskConstMeta(
+        debugName: "MediaManagerHandle_set_microphone_volume",
+        argNames: ["that", "level"],
+      );
+
+  @override
+  Object crateApiDartApiMediaManagerMediaManagerHandleSetOutputAudioId({
+    required MediaManagerHandle that,
+    required String deviceId,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaManagerHandle(
+            that,
+            serializer,
+          );
+          sse_encode_String(deviceId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiMediaManagerMediaManagerHandleSetOutputAudioIdConstMeta,
+        argValues: [that, deviceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiMediaManagerMediaManagerHandleSetOutputAudioIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "MediaManagerHandle_set_output_audio_id",
+        argNames: ["that", "deviceId"],
+      );
+
+  @override
+  ReconnectHandle crateApiDartApiReconnectHandleReconnectHandleFromPtr({
+    required int ptr,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiReconnectHandleReconnectHandleFromPtrConstMeta,
+        argValues: [ptr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiReconnectHandleReconnectHandleFromPtrConstMeta =>
+      const TaskConstMeta(
+        debugName: "ReconnectHandle_from_ptr",
+        argNames: ["ptr"],
+      );
+
+  @override
+  Object crateApiDartApiReconnectHandleReconnectHandleReconnectWithBackoff({
+    required ReconnectHandle that,
+    required int startingDelay,
+    required double multiplier,
+    required int maxDelay,
+    int? maxElapsedTimeMs,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle(
+            that,
+            serializer,
+          );
+          sse_encode_u_32(startingDelay, serializer);
+          sse_encode_f_64(multiplier, serializer);
+          sse_encode_u_32(maxDelay, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxElapsedTimeMs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiReconnectHandleReconnectHandleReconnectWithBackoffConstMeta,
+        argValues: [
+          that,
+          startingDelay,
+          multiplier,
+          maxDelay,
+          maxElapsedTimeMs,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiReconnectHandleReconnectHandleReconnectWithBackoffConstMeta =>
+      const TaskConstMeta(
+        debugName: "ReconnectHandle_reconnect_with_backoff",
+        argNames: [
+          "that",
+          "startingDelay",
+          "multiplier",
+          "maxDelay",
+          "maxElapsedTimeMs",
+        ],
+      );
+
+  @override
+  Object crateApiDartApiReconnectHandleReconnectHandleReconnectWithDelay({
+    required ReconnectHandle that,
+    required int delayMs,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReconnectHandle(
+            that,
+            serializer,
+          );
+          sse_encode_u_32(delayMs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiReconnectHandleReconnectHandleReconnectWithDelayConstMeta,
+        argValues: [that, delayMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiReconnectHandleReconnectHandleReconnectWithDelayConstMeta =>
+      const TaskConstMeta(
+        debugName: "ReconnectHandle_reconnect_with_delay",
+        argNames: ["that", "delayMs"],
+      );
+
+  @override
+  RemoteMediaTrack crateApiDartApiRemoteMediaTrackRemoteMediaTrackFromPtr({
+    required int ptr,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackFromPtrConstMeta,
+        argValues: [ptr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackFromPtrConstMeta =>
+      const TaskConstMeta(
+        debugName: "RemoteMediaTrack_from_ptr",
+        argNames: ["ptr"],
+      );
+
+  @override
+  Object crateApiDartApiRemoteMediaTrackRemoteMediaTrackGetTrack({
+    required RemoteMediaTrack that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackGetTrackConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackGetTrackConstMeta =>
+      const TaskConstMeta(
+        debugName: "RemoteMediaTrack_get_track",
+        argNames: ["that"],
+      );
+
+  @override
+  MediaKind crateApiDartApiRemoteMediaTrackRemoteMediaTrackKind({
+    required RemoteMediaTrack that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_media_kind,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackKindConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackKindConstMeta =>
+      const TaskConstMeta(
+        debugName: "RemoteMediaTrack_kind",
+        argNames: ["that"],
+      );
+
+  @override
+  MediaDirection crateApiDartApiRemoteMediaTrackRemoteMediaTrackMediaDirection({
+    required RemoteMediaTrack that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_media_direction,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackMediaDirectionConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackMediaDirectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "RemoteMediaTrack_media_direction",
+        argNames: ["that"],
+      );
+
+  @override
+  MediaSourceKind
+  crateApiDartApiRemoteMediaTrackRemoteMediaTrackMediaSourceKind({
+    required RemoteMediaTrack that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_media_source_kind,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackMediaSourceKindConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackMediaSourceKindConstMeta =>
+      const TaskConstMeta(
+        debugName: "RemoteMediaTrack_media_source_kind",
+        argNames: ["that"],
+      );
+
+  @override
+  bool crateApiDartApiRemoteMediaTrackRemoteMediaTrackMuted({
+    required RemoteMediaTrack that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackMutedConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackMutedConstMeta =>
+      const TaskConstMeta(
+        debugName: "RemoteMediaTrack_muted",
+        argNames: ["that"],
+      );
+
+  @override
+  void crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnMediaDirectionChanged({
+    required RemoteMediaTrack that,
+    required Object f,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(f, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnMediaDirectionChangedConstMeta,
+        argValues: [that, f],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnMediaDirectionChangedConstMeta =>
+      const TaskConstMeta(
+        debugName: "RemoteMediaTrack_on_media_direction_changed",
+        argNames: ["that", "f"],
+      );
+
+  @override
+  void crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnMuted({
+    required RemoteMediaTrack that,
+    required Object f,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(f, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnMutedConstMeta,
+        argValues: [that, f],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnMutedConstMeta =>
+      const TaskConstMeta(
+        debugName: "RemoteMediaTrack_on_muted",
+        argNames: ["that", "f"],
+      );
+
+  @override
+  void crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnStopped({
+    required RemoteMediaTrack that,
+    required Object f,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(f, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnStoppedConstMeta,
+        argValues: [that, f],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnStoppedConstMeta =>
+      const TaskConstMeta(
+        debugName: "RemoteMediaTrack_on_stopped",
+        argNames: ["that", "f"],
+      );
+
+  @override
+  void crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnUnmuted({
+    required RemoteMediaTrack that,
+    required Object f,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+            that,
+            serializer,
+          );
+          sse_encode_DartOpaque(f, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnUnmutedConstMeta,
+        argValues: [that, f],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnUnmutedConstMeta =>
+      const TaskConstMeta(
+        debugName: "RemoteMediaTrack_on_unmuted",
+        argNames: ["that", "f"],
+      );
+
+  @override
+  Object crateApiDartApiRoomRoomHandleDisableAudio({
+    required RoomHandle that,
+    MediaSourceKind? sourceKind,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleDisableAudioConstMeta,
+        argValues: [that, sourceKind],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleDisableAudioConstMeta =>
+      const TaskConstMeta(
+        debugName: "RoomHandle_disable_audio",
+        argNames: ["that", "sourceKind"],
+      );
+
+  @override
+  Object crateApiDartApiRoomRoomHandleDisableRemoteAudio({
+    required RoomHandle that,
+    MediaSourceKind? sourceKind,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleDisableRemoteAudioConstMeta,
+        argValues: [that, sourceKind],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleDisableRemoteAudioConstMeta =>
+      const TaskConstMeta(
+        debugName: "RoomHandle_disable_remote_audio",
+        argNames: ["that", "sourceKind"],
+      );
+
+  @override
+  Object crateApiDartApiRoomRoomHandleDisableRemoteVideo({
+    required RoomHandle that,
+    MediaSourceKind? sourceKind,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleDisableRemoteVideoConstMeta,
+        argValues: [that, sourceKind],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleDisableRemoteVideoConstMeta =>
+      const TaskConstMeta(
+        debugName: "RoomHandle_disable_remote_video",
+        argNames: ["that", "sourceKind"],
+      );
+
+  @override
+  Object crateApiDartApiRoomRoomHandleDisableVideo({
+    required RoomHandle that,
+    MediaSourceKind? sourceKind,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleDisableVideoConstMeta,
+        argValues: [that, sourceKind],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleDisableVideoConstMeta =>
+      const TaskConstMeta(
+        debugName: "RoomHandle_disable_video",
+        argNames: ["that", "sourceKind"],
+      );
+
+  @override
+  Object crateApiDartApiRoomRoomHandleEnableAudio({
+    required RoomHandle that,
+    MediaSourceKind? sourceKind,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleEnableAudioConstMeta,
+        argValues: [that, sourceKind],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleEnableAudioConstMeta =>
+      const TaskConstMeta(
+        debugName: "RoomHandle_enable_audio",
+        argNames: ["that", "sourceKind"],
+      );
+
+  @override
+  Object crateApiDartApiRoomRoomHandleEnableRemoteAudio({
+    required RoomHandle that,
+    MediaSourceKind? sourceKind,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_DartOpaque,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleEnableRemoteAudioConstMeta,
+        argValues: [that, sourceKind],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnMediaDirectionChangedConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleEnableRemoteAudioConstMeta =>
       const TaskConstMeta(
-        debugName: "RemoteMediaTrack_on_media_direction_changed",
-        argNames: ["that", "f"],
+        debugName: "RoomHandle_enable_remote_audio",
+        argNames: ["that", "sourceKind"],
       );
 
   @override
-  void crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnMuted({
-    required RemoteMediaTrack that,
-    required Object f,
+  Object crateApiDartApiRoomRoomHandleEnableRemoteVideo({
+    required RoomHandle that,
+    MediaSourceKind? sourceKind,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
             that,
             serializer,
           );
-          sse_encode_DartOpaque(f, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnMutedConstMeta,
-        argValues: [that, f],
+        constMeta: kCrateApiDartApiRoomRoomHandleEnableRemoteVideoConstMeta,
+        argValues: [that, sourceKind],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnMutedConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleEnableRemoteVideoConstMeta =>
       const TaskConstMeta(
-        debugName: "RemoteMediaTrack_on_muted",
-        argNames: ["that", "f"],
+        debugName: "RoomHandle_enable_remote_video",
+        argNames: ["that", "sourceKind"],
       );
 
   @override
-  void crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnStopped({
-    required RemoteMediaTrack that,
-    required Object f,
+  Object crateApiDartApiRoomRoomHandleEnableVideo({
+    required RoomHandle that,
+    MediaSourceKind? sourceKind,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
             that,
             serializer,
           );
-          sse_encode_DartOpaque(f, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnStoppedConstMeta,
-        argValues: [that, f],
+        constMeta: kCrateApiDartApiRoomRoomHandleEnableVideoConstMeta,
+        argValues: [that, sourceKind],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnStoppedConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleEnableVideoConstMeta =>
       const TaskConstMeta(
-        debugName: "RemoteMediaTrack_on_stopped",
-        argNames: ["that", "f"],
+        debugName: "RoomHandle_enable_video",
+        argNames: ["that", "sourceKind"],
       );
 
   @override
-  void crateApiDartApiRemoteMediaTrackRemoteMediaTrackOnUnmuted({
-    required RemoteMediaTrack that,
-    required Object f,
+  Object crateApiDartApiRoomRoomHandleJoin({
+    required RoomHandle that,
+    required String token,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemoteMediaTrack(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
             that,
             serializer,
           );
-          sse_encode_DartOpaque(f, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+          sse_encode_String(token, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnUnmutedConstMeta,
-        argValues: [that, f],
+        constMeta: kCrateApiDartApiRoomRoomHandleJoinConstMeta,
+        argValues: [that, token],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiDartApiRemoteMediaTrackRemoteMediaTrackOnUnmutedConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleJoinConstMeta =>
       const TaskConstMeta(
-        debugName: "RemoteMediaTrack_on_unmuted",
-        argNames: ["that", "f"],
+        debugName: "RoomHandle_join",
+        argNames: ["that", "token"],
       );
 
   @override
-  Object crateApiDartApiRoomRoomHandleDisableAudio({
+  Object crateApiDartApiRoomRoomHandleJoinWithCredential({
     required RoomHandle that,
-    MediaSourceKind? sourceKind,
+    required String url,
+    required String credential,
+    required Map<String, String> headers,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -2516,30 +3761,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+          sse_encode_String(url, serializer);
+          sse_encode_String(credential, serializer);
+          sse_encode_Map_String_String_None(headers, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleDisableAudioConstMeta,
-        argValues: [that, sourceKind],
+        constMeta: kCrateApiDartApiRoomRoomHandleJoinWithCredentialConstMeta,
+        argValues: [that, url, credential, headers],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleDisableAudioConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleJoinWithCredentialConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_disable_audio",
-        argNames: ["that", "sourceKind"],
+        debugName: "RoomHandle_join_with_credential",
+        argNames: ["that", "url", "credential", "headers"],
       );
 
   @override
-  Object crateApiDartApiRoomRoomHandleDisableRemoteAudio({
+  Object crateApiDartApiRoomRoomHandleLeave({
     required RoomHandle that,
-    MediaSourceKind? sourceKind,
+    String? reason,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -2549,30 +3796,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+          sse_encode_opt_String(reason, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleDisableRemoteAudioConstMeta,
-        argValues: [that, sourceKind],
+        constMeta: kCrateApiDartApiRoomRoomHandleLeaveConstMeta,
+        argValues: [that, reason],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleDisableRemoteAudioConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleLeaveConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_disable_remote_audio",
-        argNames: ["that", "sourceKind"],
+        debugName: "RoomHandle_leave",
+        argNames: ["that", "reason"],
       );
 
   @override
-  Object crateApiDartApiRoomRoomHandleDisableRemoteVideo({
+  List<RoomMember> crateApiDartApiRoomRoomHandleMembers({
     required RoomHandle that,
-    MediaSourceKind? sourceKind,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -2582,28 +3828,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_room_member,
+          decodeErrorData: sse_decode_DartOpaque,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleDisableRemoteVideoConstMeta,
-        argValues: [that, sourceKind],
+        constMeta: kCrateApiDartApiRoomRoomHandleMembersConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleDisableRemoteVideoConstMeta =>
-      const TaskConstMeta(
-        debugName: "RoomHandle_disable_remote_video",
-        argNames: ["that", "sourceKind"],
-      );
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleMembersConstMeta =>
+      const TaskConstMeta(debugName: "RoomHandle_members", argNames: ["that"]);
 
   @override
-  Object crateApiDartApiRoomRoomHandleDisableVideo({
+  Object crateApiDartApiRoomRoomHandleMuteAudio({
     required RoomHandle that,
     MediaSourceKind? sourceKind,
   }) {
@@ -2616,27 +3858,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleDisableVideoConstMeta,
+        constMeta: kCrateApiDartApiRoomRoomHandleMuteAudioConstMeta,
         argValues: [that, sourceKind],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleDisableVideoConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleMuteAudioConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_disable_video",
+        debugName: "RoomHandle_mute_audio",
         argNames: ["that", "sourceKind"],
       );
 
   @override
-  Object crateApiDartApiRoomRoomHandleEnableAudio({
+  Object crateApiDartApiRoomRoomHandleMuteVideo({
     required RoomHandle that,
     MediaSourceKind? sourceKind,
   }) {
@@ -2649,29 +3891,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_DartOpaque,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleEnableAudioConstMeta,
+        constMeta: kCrateApiDartApiRoomRoomHandleMuteVideoConstMeta,
         argValues: [that, sourceKind],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleEnableAudioConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleMuteVideoConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_enable_audio",
+        debugName: "RoomHandle_mute_video",
         argNames: ["that", "sourceKind"],
       );
 
   @override
-  Object crateApiDartApiRoomRoomHandleEnableRemoteAudio({
+  void crateApiDartApiRoomRoomHandleOnClose({
     required RoomHandle that,
-    MediaSourceKind? sourceKind,
+    required Object cb,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -2681,30 +3923,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+          sse_encode_DartOpaque(cb, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleEnableRemoteAudioConstMeta,
-        argValues: [that, sourceKind],
+        constMeta: kCrateApiDartApiRoomRoomHandleOnCloseConstMeta,
+        argValues: [that, cb],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleEnableRemoteAudioConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnCloseConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_enable_remote_audio",
-        argNames: ["that", "sourceKind"],
+        debugName: "RoomHandle_on_close",
+        argNames: ["that", "cb"],
       );
 
   @override
-  Object crateApiDartApiRoomRoomHandleEnableRemoteVideo({
+  void crateApiDartApiRoomRoomHandleOnConnectionLoss({
     required RoomHandle that,
-    MediaSourceKind? sourceKind,
+    required Object cb,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -2714,30 +3956,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+          sse_encode_DartOpaque(cb, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleEnableRemoteVideoConstMeta,
-        argValues: [that, sourceKind],
+        constMeta: kCrateApiDartApiRoomRoomHandleOnConnectionLossConstMeta,
+        argValues: [that, cb],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleEnableRemoteVideoConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnConnectionLossConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_enable_remote_video",
-        argNames: ["that", "sourceKind"],
+        debugName: "RoomHandle_on_connection_loss",
+        argNames: ["that", "cb"],
       );
 
   @override
-  Object crateApiDartApiRoomRoomHandleEnableVideo({
+  void crateApiDartApiRoomRoomHandleOnFailedLocalMedia({
     required RoomHandle that,
-    MediaSourceKind? sourceKind,
+    required Object cb,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -2747,30 +3989,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+          sse_encode_DartOpaque(cb, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleEnableVideoConstMeta,
-        argValues: [that, sourceKind],
+        constMeta: kCrateApiDartApiRoomRoomHandleOnFailedLocalMediaConstMeta,
+        argValues: [that, cb],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleEnableVideoConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnFailedLocalMediaConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_enable_video",
-        argNames: ["that", "sourceKind"],
+        debugName: "RoomHandle_on_failed_local_media",
+        argNames: ["that", "cb"],
       );
 
   @override
-  Object crateApiDartApiRoomRoomHandleJoin({
+  void crateApiDartApiRoomRoomHandleOnLocalTrack({
     required RoomHandle that,
-    required String token,
+    required Object cb,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -2780,30 +4022,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_String(token, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+          sse_encode_DartOpaque(cb, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleJoinConstMeta,
-        argValues: [that, token],
+        constMeta: kCrateApiDartApiRoomRoomHandleOnLocalTrackConstMeta,
+        argValues: [that, cb],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleJoinConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnLocalTrackConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_join",
-        argNames: ["that", "token"],
+        debugName: "RoomHandle_on_local_track",
+        argNames: ["that", "cb"],
       );
 
   @override
-  Object crateApiDartApiRoomRoomHandleMuteAudio({
+  void crateApiDartApiRoomRoomHandleOnMemberJoined({
     required RoomHandle that,
-    MediaSourceKind? sourceKind,
+    required Object cb,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -2813,30 +4055,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+          sse_encode_DartOpaque(cb, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleMuteAudioConstMeta,
-        argValues: [that, sourceKind],
+        constMeta: kCrateApiDartApiRoomRoomHandleOnMemberJoinedConstMeta,
+        argValues: [that, cb],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleMuteAudioConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnMemberJoinedConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_mute_audio",
-        argNames: ["that", "sourceKind"],
+        debugName: "RoomHandle_on_member_joined",
+        argNames: ["that", "cb"],
       );
 
   @override
-  Object crateApiDartApiRoomRoomHandleMuteVideo({
+  void crateApiDartApiRoomRoomHandleOnMemberLeft({
     required RoomHandle that,
-    MediaSourceKind? sourceKind,
+    required Object cb,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -2846,28 +4088,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+          sse_encode_DartOpaque(cb, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_DartOpaque,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_DartOpaque,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleMuteVideoConstMeta,
-        argValues: [that, sourceKind],
+        constMeta: kCrateApiDartApiRoomRoomHandleOnMemberLeftConstMeta,
+        argValues: [that, cb],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleMuteVideoConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnMemberLeftConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_mute_video",
-        argNames: ["that", "sourceKind"],
+        debugName: "RoomHandle_on_member_left",
+        argNames: ["that", "cb"],
       );
 
   @override
-  void crateApiDartApiRoomRoomHandleOnClose({
+  void crateApiDartApiRoomRoomHandleOnMemberUpdated({
     required RoomHandle that,
     required Object cb,
   }) {
@@ -2880,27 +4122,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_DartOpaque(cb, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_DartOpaque,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleOnCloseConstMeta,
+        constMeta: kCrateApiDartApiRoomRoomHandleOnMemberUpdatedConstMeta,
         argValues: [that, cb],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnCloseConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnMemberUpdatedConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_on_close",
+        debugName: "RoomHandle_on_member_updated",
         argNames: ["that", "cb"],
       );
 
   @override
-  void crateApiDartApiRoomRoomHandleOnConnectionLoss({
+  void crateApiDartApiRoomRoomHandleOnNewConnection({
     required RoomHandle that,
     required Object cb,
   }) {
@@ -2913,27 +4155,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_DartOpaque(cb, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_DartOpaque,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleOnConnectionLossConstMeta,
+        constMeta: kCrateApiDartApiRoomRoomHandleOnNewConnectionConstMeta,
         argValues: [that, cb],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnConnectionLossConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnNewConnectionConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_on_connection_loss",
+        debugName: "RoomHandle_on_new_connection",
         argNames: ["that", "cb"],
       );
 
   @override
-  void crateApiDartApiRoomRoomHandleOnFailedLocalMedia({
+  void crateApiDartApiRoomRoomHandleOnSignallingLatency({
     required RoomHandle that,
     required Object cb,
   }) {
@@ -2946,27 +4188,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_DartOpaque(cb, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_DartOpaque,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleOnFailedLocalMediaConstMeta,
+        constMeta: kCrateApiDartApiRoomRoomHandleOnSignallingLatencyConstMeta,
         argValues: [that, cb],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnFailedLocalMediaConstMeta =>
+  TaskConstMeta
+  get kCrateApiDartApiRoomRoomHandleOnSignallingLatencyConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_on_failed_local_media",
+        debugName: "RoomHandle_on_signalling_latency",
         argNames: ["that", "cb"],
       );
 
   @override
-  void crateApiDartApiRoomRoomHandleOnLocalTrack({
+  void crateApiDartApiRoomRoomHandleOnUnknownEvent({
     required RoomHandle that,
     required Object cb,
   }) {
@@ -2979,29 +4222,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_DartOpaque(cb, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_DartOpaque,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleOnLocalTrackConstMeta,
+        constMeta: kCrateApiDartApiRoomRoomHandleOnUnknownEventConstMeta,
         argValues: [that, cb],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnLocalTrackConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnUnknownEventConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_on_local_track",
+        debugName: "RoomHandle_on_unknown_event",
         argNames: ["that", "cb"],
       );
 
   @override
-  void crateApiDartApiRoomRoomHandleOnNewConnection({
+  String? crateApiDartApiRoomRoomHandleRpcTransportKind({
     required RoomHandle that,
-    required Object cb,
   }) {
     return handler.executeSync(
       SyncTask(
@@ -3011,24 +4253,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_DartOpaque(cb, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: sse_decode_DartOpaque,
         ),
-        constMeta: kCrateApiDartApiRoomRoomHandleOnNewConnectionConstMeta,
-        argValues: [that, cb],
+        constMeta: kCrateApiDartApiRoomRoomHandleRpcTransportKindConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDartApiRoomRoomHandleOnNewConnectionConstMeta =>
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleRpcTransportKindConstMeta =>
       const TaskConstMeta(
-        debugName: "RoomHandle_on_new_connection",
-        argNames: ["that", "cb"],
+        debugName: "RoomHandle_rpc_transport_kind",
+        argNames: ["that"],
       );
 
   @override
@@ -3052,7 +4293,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
           sse_encode_bool(stopFirst, serializer);
           sse_encode_bool(rollbackOnFail, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_DartOpaque,
@@ -3072,6 +4313,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "settings", "stopFirst", "rollbackOnFail"],
       );
 
+  @override
+  SignallingLatency? crateApiDartApiRoomRoomHandleSignallingLatency({
+    required RoomHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRoomHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_signalling_latency,
+          decodeErrorData: sse_decode_DartOpaque,
+        ),
+        constMeta: kCrateApiDartApiRoomRoomHandleSignallingLatencyConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomRoomHandleSignallingLatencyConstMeta =>
+      const TaskConstMeta(
+        debugName: "RoomHandle_signalling_latency",
+        argNames: ["that"],
+      );
+
   @override
   Object crateApiDartApiRoomRoomHandleUnmuteAudio({
     required RoomHandle that,
@@ -3086,7 +4358,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_DartOpaque,
@@ -3119,7 +4391,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_opt_box_autoadd_media_source_kind(sourceKind, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_DartOpaque,
@@ -3138,6 +4410,63 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "sourceKind"],
       );
 
+  @override
+  ApiDeviceCapabilities crateApiDartApiDeviceCapabilitiesFromRaw({
+    required int ptr,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_device_capabilities,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiDeviceCapabilitiesFromRawConstMeta,
+        argValues: [ptr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiDeviceCapabilitiesFromRawConstMeta =>
+      const TaskConstMeta(
+        debugName: "device_capabilities_from_raw",
+        argNames: ["ptr"],
+      );
+
+  @override
+  DiagnosticsReport crateApiDartApiDiagnosticsDiagnosticsReportFromPtr({
+    required int ptr,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_diagnostics_report,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiDiagnosticsDiagnosticsReportFromPtrConstMeta,
+        argValues: [ptr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiDiagnosticsDiagnosticsReportFromPtrConstMeta =>
+      const TaskConstMeta(
+        debugName: "diagnostics_report_from_ptr",
+        argNames: ["ptr"],
+      );
+
   @override
   void crateApiDartApiLogDartException({
     required String message,
@@ -3149,7 +4478,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(message, serializer);
           sse_encode_String(stackTrace, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -3178,7 +4507,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_CastedPrimitive_usize(ptr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_member_connection_state,
@@ -3206,7 +4535,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_DartOpaque(cb, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -3231,7 +4560,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_CastedPrimitive_usize(ptr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_room_close_reason,
@@ -3252,6 +4581,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["ptr"],
       );
 
+  @override
+  RoomMember crateApiDartApiRoomMemberRoomMemberFromPtr({required int ptr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_room_member,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDartApiRoomMemberRoomMemberFromPtrConstMeta,
+        argValues: [ptr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDartApiRoomMemberRoomMemberFromPtrConstMeta =>
+      const TaskConstMeta(debugName: "room_member_from_ptr", argNames: ["ptr"]);
+
   @override
   void crateApiDartApiSetDartOpaqueMessagePort({
     required PlatformInt64 dartHandlerPort,
@@ -3261,7 +4613,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(dartHandlerPort, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -3287,7 +4639,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_log_level(level, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_DartOpaque,
@@ -3303,6 +4655,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiDartApiSetLogLevelConstMeta =>
       const TaskConstMeta(debugName: "set_log_level", argNames: ["level"]);
 
+  @override
+  SignallingLatency crateApiDartApiSignallingLatencySignallingLatencyFromPtr({
+    required int ptr,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_signalling_latency,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiDartApiSignallingLatencySignallingLatencyFromPtrConstMeta,
+        argValues: [ptr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDartApiSignallingLatencySignallingLatencyFromPtrConstMeta =>
+      const TaskConstMeta(
+        debugName: "signalling_latency_from_ptr",
+        argNames: ["ptr"],
+      );
+
   @override
   List<LocalMediaTrack> crateApiDartApiLocalMediaTrackVecLocalTracksFromRaw({
     required int ptr,
@@ -3312,7 +4694,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_CastedPrimitive_usize(ptr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122)!;
         },
         codec: SseCodec(
           decodeSuccessData:
@@ -3343,7 +4725,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_CastedPrimitive_usize(ptr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_api_media_device_details,
@@ -3371,7 +4753,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_CastedPrimitive_usize(ptr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_api_media_display_details,
@@ -3586,6 +4968,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return decodeDartOpaque(raw, generalizedFrbRustBinding);
   }
 
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_string(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
   @protected
   ConnectionHandle
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle(
@@ -3694,19 +5086,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiDeviceCapabilities dco_decode_api_device_capabilities(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ApiDeviceCapabilities(
+      width: dco_decode_opt_box_autoadd_api_int_range(arr[0]),
+      height: dco_decode_opt_box_autoadd_api_int_range(arr[1]),
+      frameRate: dco_decode_opt_box_autoadd_api_float_range(arr[2]),
+      facingModes: dco_decode_list_facing_mode(arr[3]),
+      channelCount: dco_decode_opt_box_autoadd_api_int_range(arr[4]),
+      echoCancellation: dco_decode_list_bool(arr[5]),
+      autoGainControl: dco_decode_list_bool(arr[6]),
+      noiseSuppression: dco_decode_list_bool(arr[7]),
+    );
+  }
+
   @protected
   ApiDeviceVideoTrackConstraints dco_decode_api_device_video_track_constraints(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return ApiDeviceVideoTrackConstraints(
       deviceId: dco_decode_opt_String(arr[0]),
       facingMode: dco_decode_opt_box_autoadd_api_constrain_facing_mode(arr[1]),
       height: dco_decode_opt_box_autoadd_constrain_u_32(arr[2]),
       width: dco_decode_opt_box_autoadd_constrain_u_32(arr[3]),
+      frameRate: dco_decode_opt_box_autoadd_constrain_u_32(arr[4]),
     );
   }
 
@@ -3735,6 +5146,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiFloatRange dco_decode_api_float_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ApiFloatRange(
+      min: dco_decode_f_64(arr[0]),
+      max: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  ApiIceServer dco_decode_api_ice_server(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ApiIceServer(
+      urls: dco_decode_list_String(arr[0]),
+      username: dco_decode_opt_String(arr[1]),
+      credential: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  ApiIntRange dco_decode_api_int_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ApiIntRange(
+      min: dco_decode_u_32(arr[0]),
+      max: dco_decode_u_32(arr[1]),
+    );
+  }
+
   @protected
   ApiMediaDeviceDetails dco_decode_api_media_device_details(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3835,6 +5283,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_api_display_video_track_constraints(raw);
   }
 
+  @protected
+  ApiFloatRange dco_decode_box_autoadd_api_float_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_float_range(raw);
+  }
+
+  @protected
+  ApiIntRange dco_decode_box_autoadd_api_int_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_int_range(raw);
+  }
+
   @protected
   ApiMediaStreamSettings dco_decode_box_autoadd_api_media_stream_settings(
     dynamic raw,
@@ -3861,6 +5321,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_constrain_u_32(raw);
   }
 
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   MediaSourceKind dco_decode_box_autoadd_media_source_kind(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3883,6 +5355,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_noise_suppression_level(raw);
   }
 
+  @protected
+  SignallingLatency dco_decode_box_autoadd_signalling_latency(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_signalling_latency(raw);
+  }
+
+  @protected
+  ThroughputDiagnostics dco_decode_box_autoadd_throughput_diagnostics(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_throughput_diagnostics(raw);
+  }
+
   @protected
   int dco_decode_box_autoadd_u_16(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3926,6 +5412,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  DeviceDiagnostics dco_decode_device_diagnostics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return DeviceDiagnostics(
+      deviceId: dco_decode_String(arr[0]),
+      label: dco_decode_String(arr[1]),
+      kind: dco_decode_media_kind(arr[2]),
+      error: dco_decode_opt_String(arr[3]),
+      width: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      height: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      frameRate: dco_decode_opt_box_autoadd_f_64(arr[6]),
+      audioLevel: dco_decode_opt_box_autoadd_i_32(arr[7]),
+    );
+  }
+
+  @protected
+  DiagnosticsReport dco_decode_diagnostics_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DiagnosticsReport(
+      devices: dco_decode_list_device_diagnostics(arr[0]),
+      ice: dco_decode_ice_diagnostics(arr[1]),
+      throughput: dco_decode_opt_box_autoadd_throughput_diagnostics(arr[2]),
+    );
+  }
+
   @protected
   double dco_decode_f_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3950,6 +5467,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeI64(raw);
   }
 
+  @protected
+  IceDiagnostics dco_decode_ice_diagnostics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return IceDiagnostics(
+      hostCandidates: dco_decode_u_32(arr[0]),
+      srflxCandidates: dco_decode_u_32(arr[1]),
+      relayCandidates: dco_decode_u_32(arr[2]),
+      connected: dco_decode_bool(arr[3]),
+      errors: dco_decode_list_String(arr[4]),
+    );
+  }
+
   @protected
   PlatformInt64 dco_decode_isize(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3962,11 +5494,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(
-          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack,
-        )
-        .toList();
+    return (raw as List<dynamic>)
+        .map(
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack,
+        )
+        .toList();
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<ApiIceServer> dco_decode_list_api_ice_server(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_ice_server).toList();
   }
 
   @protected
@@ -3989,12 +5533,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         .toList();
   }
 
+  @protected
+  List<bool> dco_decode_list_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_bool).toList();
+  }
+
+  @protected
+  List<DeviceDiagnostics> dco_decode_list_device_diagnostics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_device_diagnostics).toList();
+  }
+
+  @protected
+  List<FacingMode> dco_decode_list_facing_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_facing_mode).toList();
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as Uint8List;
   }
 
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<RoomMember> dco_decode_list_room_member(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_room_member).toList();
+  }
+
   @protected
   LogLevel dco_decode_log_level(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4038,6 +5612,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  MeteringMode dco_decode_metering_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MeteringMode.values[raw as int];
+  }
+
   @protected
   NoiseSuppressionLevel dco_decode_noise_suppression_level(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4096,6 +5676,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         : dco_decode_box_autoadd_api_display_video_track_constraints(raw);
   }
 
+  @protected
+  ApiFloatRange? dco_decode_opt_box_autoadd_api_float_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_api_float_range(raw);
+  }
+
+  @protected
+  ApiIntRange? dco_decode_opt_box_autoadd_api_int_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_api_int_range(raw);
+  }
+
   @protected
   AudioDeviceKind? dco_decode_opt_box_autoadd_audio_device_kind(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4114,6 +5706,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_constrain_u_32(raw);
   }
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
   @protected
   MediaSourceKind? dco_decode_opt_box_autoadd_media_source_kind(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4140,6 +5744,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         : dco_decode_box_autoadd_noise_suppression_level(raw);
   }
 
+  @protected
+  SignallingLatency? dco_decode_opt_box_autoadd_signalling_latency(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_signalling_latency(raw);
+  }
+
+  @protected
+  ThroughputDiagnostics? dco_decode_opt_box_autoadd_throughput_diagnostics(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_throughput_diagnostics(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4158,6 +5780,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return PeerConnectionState.values[raw as int];
   }
 
+  @protected
+  PermissionKind dco_decode_permission_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PermissionKind.values[raw as int];
+  }
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
   @protected
   RoomCloseKind dco_decode_room_close_kind(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4176,6 +5814,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RoomMember dco_decode_room_member(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RoomMember(
+      id: dco_decode_String(arr[0]),
+      metadata: dco_decode_Map_String_String_None(arr[1]),
+    );
+  }
+
+  @protected
+  SignallingLatency dco_decode_signalling_latency(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SignallingLatency(
+      rttMs: dco_decode_f_64(arr[0]),
+      smoothedRttMs: dco_decode_f_64(arr[1]),
+      jitterMs: dco_decode_f_64(arr[2]),
+      samples: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  ThroughputDiagnostics dco_decode_throughput_diagnostics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ThroughputDiagnostics(
+      bytesSent: dco_decode_i_64(arr[0]),
+      durationMs: dco_decode_i_64(arr[1]),
+      bitrateBps: dco_decode_i_64(arr[2]),
+    );
+  }
+
   @protected
   int dco_decode_u_16(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4388,6 +6065,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return decodeDartOpaque(inner, generalizedFrbRustBinding);
   }
 
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
   @protected
   ConnectionHandle
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle(
@@ -4528,6 +6214,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiDeviceCapabilities sse_decode_api_device_capabilities(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_opt_box_autoadd_api_int_range(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_api_int_range(deserializer);
+    var var_frameRate = sse_decode_opt_box_autoadd_api_float_range(
+      deserializer,
+    );
+    var var_facingModes = sse_decode_list_facing_mode(deserializer);
+    var var_channelCount = sse_decode_opt_box_autoadd_api_int_range(
+      deserializer,
+    );
+    var var_echoCancellation = sse_decode_list_bool(deserializer);
+    var var_autoGainControl = sse_decode_list_bool(deserializer);
+    var var_noiseSuppression = sse_decode_list_bool(deserializer);
+    return ApiDeviceCapabilities(
+      width: var_width,
+      height: var_height,
+      frameRate: var_frameRate,
+      facingModes: var_facingModes,
+      channelCount: var_channelCount,
+      echoCancellation: var_echoCancellation,
+      autoGainControl: var_autoGainControl,
+      noiseSuppression: var_noiseSuppression,
+    );
+  }
+
   @protected
   ApiDeviceVideoTrackConstraints sse_decode_api_device_video_track_constraints(
     SseDeserializer deserializer,
@@ -4539,11 +6254,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
     var var_height = sse_decode_opt_box_autoadd_constrain_u_32(deserializer);
     var var_width = sse_decode_opt_box_autoadd_constrain_u_32(deserializer);
+    var var_frameRate = sse_decode_opt_box_autoadd_constrain_u_32(deserializer);
     return ApiDeviceVideoTrackConstraints(
       deviceId: var_deviceId,
       facingMode: var_facingMode,
       height: var_height,
       width: var_width,
+      frameRate: var_frameRate,
     );
   }
 
@@ -4570,6 +6287,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiFloatRange sse_decode_api_float_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_min = sse_decode_f_64(deserializer);
+    var var_max = sse_decode_f_64(deserializer);
+    return ApiFloatRange(min: var_min, max: var_max);
+  }
+
+  @protected
+  ApiIceServer sse_decode_api_ice_server(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_urls = sse_decode_list_String(deserializer);
+    var var_username = sse_decode_opt_String(deserializer);
+    var var_credential = sse_decode_opt_String(deserializer);
+    return ApiIceServer(
+      urls: var_urls,
+      username: var_username,
+      credential: var_credential,
+    );
+  }
+
+  @protected
+  ApiIntRange sse_decode_api_int_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_min = sse_decode_u_32(deserializer);
+    var var_max = sse_decode_u_32(deserializer);
+    return ApiIntRange(min: var_min, max: var_max);
+  }
+
   @protected
   ApiMediaDeviceDetails sse_decode_api_media_device_details(
     SseDeserializer deserializer,
@@ -4693,6 +6439,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_api_display_video_track_constraints(deserializer));
   }
 
+  @protected
+  ApiFloatRange sse_decode_box_autoadd_api_float_range(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_float_range(deserializer));
+  }
+
+  @protected
+  ApiIntRange sse_decode_box_autoadd_api_int_range(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_int_range(deserializer));
+  }
+
   @protected
   ApiMediaStreamSettings sse_decode_box_autoadd_api_media_stream_settings(
     SseDeserializer deserializer,
@@ -4725,6 +6487,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_constrain_u_32(deserializer));
   }
 
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
   @protected
   MediaSourceKind sse_decode_box_autoadd_media_source_kind(
     SseDeserializer deserializer,
@@ -4749,6 +6523,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_noise_suppression_level(deserializer));
   }
 
+  @protected
+  SignallingLatency sse_decode_box_autoadd_signalling_latency(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_signalling_latency(deserializer));
+  }
+
+  @protected
+  ThroughputDiagnostics sse_decode_box_autoadd_throughput_diagnostics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_throughput_diagnostics(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4799,6 +6589,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  DeviceDiagnostics sse_decode_device_diagnostics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_deviceId = sse_decode_String(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_kind = sse_decode_media_kind(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_frameRate = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_audioLevel = sse_decode_opt_box_autoadd_i_32(deserializer);
+    return DeviceDiagnostics(
+      deviceId: var_deviceId,
+      label: var_label,
+      kind: var_kind,
+      error: var_error,
+      width: var_width,
+      height: var_height,
+      frameRate: var_frameRate,
+      audioLevel: var_audioLevel,
+    );
+  }
+
+  @protected
+  DiagnosticsReport sse_decode_diagnostics_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_devices = sse_decode_list_device_diagnostics(deserializer);
+    var var_ice = sse_decode_ice_diagnostics(deserializer);
+    var var_throughput = sse_decode_opt_box_autoadd_throughput_diagnostics(
+      deserializer,
+    );
+    return DiagnosticsReport(
+      devices: var_devices,
+      ice: var_ice,
+      throughput: var_throughput,
+    );
+  }
+
   @protected
   double sse_decode_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4824,6 +6656,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getPlatformInt64();
   }
 
+  @protected
+  IceDiagnostics sse_decode_ice_diagnostics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hostCandidates = sse_decode_u_32(deserializer);
+    var var_srflxCandidates = sse_decode_u_32(deserializer);
+    var var_relayCandidates = sse_decode_u_32(deserializer);
+    var var_connected = sse_decode_bool(deserializer);
+    var var_errors = sse_decode_list_String(deserializer);
+    return IceDiagnostics(
+      hostCandidates: var_hostCandidates,
+      srflxCandidates: var_srflxCandidates,
+      relayCandidates: var_relayCandidates,
+      connected: var_connected,
+      errors: var_errors,
+    );
+  }
+
   @protected
   PlatformInt64 sse_decode_isize(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4849,6 +6698,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiIceServer> sse_decode_list_api_ice_server(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiIceServer>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_ice_server(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<ApiMediaDeviceDetails> sse_decode_list_api_media_device_details(
     SseDeserializer deserializer,
@@ -4864,24 +6739,88 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  List<ApiMediaDisplayDetails> sse_decode_list_api_media_display_details(
+  List<ApiMediaDisplayDetails> sse_decode_list_api_media_display_details(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiMediaDisplayDetails>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_media_display_details(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<bool> sse_decode_list_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <bool>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_bool(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DeviceDiagnostics> sse_decode_list_device_diagnostics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DeviceDiagnostics>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_device_diagnostics(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FacingMode> sse_decode_list_facing_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FacingMode>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_facing_mode(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApiMediaDisplayDetails>[];
+    var ans_ = <(String, String)>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_api_media_display_details(deserializer));
+      ans_.add(sse_decode_record_string_string(deserializer));
     }
     return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  List<RoomMember> sse_decode_list_room_member(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
     var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    var ans_ = <RoomMember>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_room_member(deserializer));
+    }
+    return ans_;
   }
 
   @protected
@@ -4935,6 +6874,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  MeteringMode sse_decode_metering_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MeteringMode.values[inner];
+  }
+
   @protected
   NoiseSuppressionLevel sse_decode_noise_suppression_level(
     SseDeserializer deserializer,
@@ -5032,6 +6978,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  ApiFloatRange? sse_decode_opt_box_autoadd_api_float_range(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_api_float_range(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ApiIntRange? sse_decode_opt_box_autoadd_api_int_range(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_api_int_range(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   AudioDeviceKind? sse_decode_opt_box_autoadd_audio_device_kind(
     SseDeserializer deserializer,
@@ -5071,6 +7043,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   MediaSourceKind? sse_decode_opt_box_autoadd_media_source_kind(
     SseDeserializer deserializer,
@@ -5110,6 +7104,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  SignallingLatency? sse_decode_opt_box_autoadd_signalling_latency(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_signalling_latency(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ThroughputDiagnostics? sse_decode_opt_box_autoadd_throughput_diagnostics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_throughput_diagnostics(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5141,6 +7161,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return PeerConnectionState.values[inner];
   }
 
+  @protected
+  PermissionKind sse_decode_permission_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PermissionKind.values[inner];
+  }
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
   @protected
   RoomCloseKind sse_decode_room_close_kind(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5159,6 +7196,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RoomMember sse_decode_room_member(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_metadata = sse_decode_Map_String_String_None(deserializer);
+    return RoomMember(id: var_id, metadata: var_metadata);
+  }
+
+  @protected
+  SignallingLatency sse_decode_signalling_latency(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rttMs = sse_decode_f_64(deserializer);
+    var var_smoothedRttMs = sse_decode_f_64(deserializer);
+    var var_jitterMs = sse_decode_f_64(deserializer);
+    var var_samples = sse_decode_u_32(deserializer);
+    return SignallingLatency(
+      rttMs: var_rttMs,
+      smoothedRttMs: var_smoothedRttMs,
+      jitterMs: var_jitterMs,
+      samples: var_samples,
+    );
+  }
+
+  @protected
+  ThroughputDiagnostics sse_decode_throughput_diagnostics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bytesSent = sse_decode_i_64(deserializer);
+    var var_durationMs = sse_decode_i_64(deserializer);
+    var var_bitrateBps = sse_decode_i_64(deserializer);
+    return ThroughputDiagnostics(
+      bytesSent: var_bytesSent,
+      durationMs: var_durationMs,
+      bitrateBps: var_bitrateBps,
+    );
+  }
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5391,6 +7468,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_string(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle(
@@ -5533,6 +7622,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_api_device_capabilities(
+    ApiDeviceCapabilities self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_api_int_range(self.width, serializer);
+    sse_encode_opt_box_autoadd_api_int_range(self.height, serializer);
+    sse_encode_opt_box_autoadd_api_float_range(self.frameRate, serializer);
+    sse_encode_list_facing_mode(self.facingModes, serializer);
+    sse_encode_opt_box_autoadd_api_int_range(self.channelCount, serializer);
+    sse_encode_list_bool(self.echoCancellation, serializer);
+    sse_encode_list_bool(self.autoGainControl, serializer);
+    sse_encode_list_bool(self.noiseSuppression, serializer);
+  }
+
   @protected
   void sse_encode_api_device_video_track_constraints(
     ApiDeviceVideoTrackConstraints self,
@@ -5546,6 +7651,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
     sse_encode_opt_box_autoadd_constrain_u_32(self.height, serializer);
     sse_encode_opt_box_autoadd_constrain_u_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_constrain_u_32(self.frameRate, serializer);
   }
 
   @protected
@@ -5568,6 +7674,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_constrain_u_32(self.frameRate, serializer);
   }
 
+  @protected
+  void sse_encode_api_float_range(
+    ApiFloatRange self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.min, serializer);
+    sse_encode_f_64(self.max, serializer);
+  }
+
+  @protected
+  void sse_encode_api_ice_server(ApiIceServer self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.urls, serializer);
+    sse_encode_opt_String(self.username, serializer);
+    sse_encode_opt_String(self.credential, serializer);
+  }
+
+  @protected
+  void sse_encode_api_int_range(ApiIntRange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.min, serializer);
+    sse_encode_u_32(self.max, serializer);
+  }
+
   @protected
   void sse_encode_api_media_device_details(
     ApiMediaDeviceDetails self,
@@ -5681,6 +7812,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_api_display_video_track_constraints(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_api_float_range(
+    ApiFloatRange self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_float_range(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_api_int_range(
+    ApiIntRange self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_int_range(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_api_media_stream_settings(
     ApiMediaStreamSettings self,
@@ -5717,6 +7866,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_constrain_u_32(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_media_source_kind(
     MediaSourceKind self,
@@ -5744,6 +7905,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_noise_suppression_level(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_signalling_latency(
+    SignallingLatency self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_signalling_latency(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_throughput_diagnostics(
+    ThroughputDiagnostics self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_throughput_diagnostics(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5789,6 +7968,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_device_diagnostics(
+    DeviceDiagnostics self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.deviceId, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_media_kind(self.kind, serializer);
+    sse_encode_opt_String(self.error, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.frameRate, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.audioLevel, serializer);
+  }
+
+  @protected
+  void sse_encode_diagnostics_report(
+    DiagnosticsReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_device_diagnostics(self.devices, serializer);
+    sse_encode_ice_diagnostics(self.ice, serializer);
+    sse_encode_opt_box_autoadd_throughput_diagnostics(
+      self.throughput,
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5804,69 +8013,160 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_ice_diagnostics(
+    IceDiagnostics self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.hostCandidates, serializer);
+    sse_encode_u_32(self.srflxCandidates, serializer);
+    sse_encode_u_32(self.relayCandidates, serializer);
+    sse_encode_bool(self.connected, serializer);
+    sse_encode_list_String(self.errors, serializer);
+  }
+
+  @protected
+  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+    List<LocalMediaTrack> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
+        item,
+        serializer,
+      );
+    }
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_api_ice_server(
+    List<ApiIceServer> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_ice_server(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_api_media_device_details(
+    List<ApiMediaDeviceDetails> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_media_device_details(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_api_media_display_details(
+    List<ApiMediaDisplayDetails> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_media_display_details(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_list_bool(List<bool> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_bool(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_list_device_diagnostics(
+    List<DeviceDiagnostics> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_device_diagnostics(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
-    List<LocalMediaTrack> self,
+  void sse_encode_list_facing_mode(
+    List<FacingMode> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLocalMediaTrack(
-        item,
-        serializer,
-      );
+      sse_encode_facing_mode(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_api_media_device_details(
-    List<ApiMediaDeviceDetails> self,
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_api_media_device_details(item, serializer);
-    }
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  void sse_encode_list_api_media_display_details(
-    List<ApiMediaDisplayDetails> self,
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_api_media_display_details(item, serializer);
+      sse_encode_record_string_string(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_list_room_member(
+    List<RoomMember> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    for (final item in self) {
+      sse_encode_room_member(item, serializer);
+    }
   }
 
   @protected
@@ -5921,6 +8221,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_metering_mode(MeteringMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_noise_suppression_level(
     NoiseSuppressionLevel self,
@@ -6017,6 +8323,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_api_float_range(
+    ApiFloatRange? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_api_float_range(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_api_int_range(
+    ApiIntRange? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_api_int_range(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_audio_device_kind(
     AudioDeviceKind? self,
@@ -6056,6 +8388,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_media_source_kind(
     MediaSourceKind? self,
@@ -6095,6 +8447,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_signalling_latency(
+    SignallingLatency? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_signalling_latency(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_throughput_diagnostics(
+    ThroughputDiagnostics? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_throughput_diagnostics(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6124,6 +8502,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_permission_kind(
+    PermissionKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_String(self.$2, serializer);
+  }
+
   @protected
   void sse_encode_room_close_kind(
     RoomCloseKind self,
@@ -6143,6 +8540,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.isClosedByServer, serializer);
   }
 
+  @protected
+  void sse_encode_room_member(RoomMember self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_Map_String_String_None(self.metadata, serializer);
+  }
+
+  @protected
+  void sse_encode_signalling_latency(
+    SignallingLatency self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.rttMs, serializer);
+    sse_encode_f_64(self.smoothedRttMs, serializer);
+    sse_encode_f_64(self.jitterMs, serializer);
+    sse_encode_u_32(self.samples, serializer);
+  }
+
+  @protected
+  void sse_encode_throughput_diagnostics(
+    ThroughputDiagnostics self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.bytesSent, serializer);
+    sse_encode_i_64(self.durationMs, serializer);
+    sse_encode_i_64(self.bitrateBps, serializer);
+  }
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6349,6 +8776,30 @@ class JasonImpl extends RustOpaque implements Jason {
   void jasonDispose() =>
       RustLib.instance.api.crateApiDartApiJasonJasonJasonDispose(that: this);
 
+  /// Enables recording of RPC sessions of all the [`Room`]s initialized
+  /// after this call.
+  ///
+  /// Has no effect if this library is built without `rpc-recording` Cargo
+  /// feature.
+  void jasonEnableRpcRecording() => RustLib.instance.api
+      .crateApiDartApiJasonJasonJasonEnableRpcRecording(that: this);
+
+  /// Makes all the connections with a media server created after this call
+  /// fall back to [Server-Sent Events] transport if a [WebSocket] connection
+  /// cannot be established.
+  ///
+  /// [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+  /// [WebSocket]: https://en.wikipedia.org/wiki/WebSocket
+  void jasonEnableSseFallback() => RustLib.instance.api
+      .crateApiDartApiJasonJasonJasonEnableSseFallback(that: this);
+
+  /// Exports the recorded RPC sessions as [JSON Lines], or returns [`None`]
+  /// if recording hasn't been enabled.
+  ///
+  /// [JSON Lines]: https://jsonlines.org
+  String? jasonExportRpcRecording() => RustLib.instance.api
+      .crateApiDartApiJasonJasonJasonExportRpcRecording(that: this);
+
   /// Creates a new [`Room`] and returns its [`RoomHandle`].
   RoomHandle jasonInitRoom() =>
       RustLib.instance.api.crateApiDartApiJasonJasonJasonInitRoom(that: this);
@@ -6366,6 +8817,31 @@ class JasonImpl extends RustOpaque implements Jason {
   /// [ICE]: https://webrtcglossary.com/ice
   Object jasonNetworkChanged() => RustLib.instance.api
       .crateApiDartApiJasonJasonJasonNetworkChanged(that: this);
+
+  /// Sets callback, invoked with a [`NetworkState`] whenever it changes.
+  ///
+  /// [`NetworkState`]: crate::api::NetworkState
+  void jasonOnNetworkStateChange({required Object cb}) => RustLib.instance.api
+      .crateApiDartApiJasonJasonJasonOnNetworkStateChange(that: this, cb: cb);
+
+  /// Notifies [`Jason`] whether the device is connected to a network, as
+  /// reported by a connectivity plugin.
+  ///
+  /// Calls [`Jason::jason_network_changed()`] automatically once the network
+  /// becomes available again.
+  void jasonSetNetworkOnline({required bool online}) =>
+      RustLib.instance.api.crateApiDartApiJasonJasonJasonSetNetworkOnline(
+        that: this,
+        online: online,
+      );
+
+  /// Makes all the [`Room`]s initialized after this call share a single
+  /// connection with a media server.
+  ///
+  /// Each [`Room`] is still joined and left independently, while the shared
+  /// connection is closed once all of them are left.
+  void jasonShareRpcConnection() => RustLib.instance.api
+      .crateApiDartApiJasonJasonJasonShareRpcConnection(that: this);
 }
 
 @sealed
@@ -6387,6 +8863,19 @@ class LocalMediaTrackImpl extends RustOpaque implements LocalMediaTrack {
         RustLib.instance.api.rust_arc_decrement_strong_count_LocalMediaTrackPtr,
   );
 
+  /// Applies the provided [`ApiDeviceVideoTrackConstraints`] to this live
+  /// device video [`LocalMediaTrack`], without re-acquiring its media source.
+  ///
+  /// __NOTE__: Not supported by `medea_flutter_webrtc` yet, so always
+  /// completes with an error.
+  Object applyConstraints({
+    required ApiDeviceVideoTrackConstraints constraints,
+  }) => RustLib.instance.api
+      .crateApiDartApiLocalMediaTrackLocalMediaTrackApplyConstraints(
+        that: this,
+        constraints: constraints,
+      );
+
   /// Frees the data behind the provided opaque local track.
   Object free() => RustLib.instance.api
       .crateApiDartApiLocalMediaTrackLocalMediaTrackFree(that: this);
@@ -6438,6 +8927,30 @@ class LocalMediaTrackImpl extends RustOpaque implements LocalMediaTrack {
         that: this,
       );
 
+  /// Indicates whether the camera producing this [`LocalMediaTrack`]
+  /// supports exposure compensation control via
+  /// [`LocalMediaTrack::set_exposure_compensation()`].
+  bool isExposureCompensationAvailable() => RustLib.instance.api
+      .crateApiDartApiLocalMediaTrackLocalMediaTrackIsExposureCompensationAvailable(
+        that: this,
+      );
+
+  /// Indicates whether the camera producing this [`LocalMediaTrack`]
+  /// supports focus distance control via
+  /// [`LocalMediaTrack::set_focus_distance()`].
+  bool isFocusDistanceAvailable() => RustLib.instance.api
+      .crateApiDartApiLocalMediaTrackLocalMediaTrackIsFocusDistanceAvailable(
+        that: this,
+      );
+
+  /// Indicates whether the camera producing this [`LocalMediaTrack`]
+  /// supports focus mode control via
+  /// [`LocalMediaTrack::set_focus_mode()`].
+  bool isFocusModeAvailable() => RustLib.instance.api
+      .crateApiDartApiLocalMediaTrackLocalMediaTrackIsFocusModeAvailable(
+        that: this,
+      );
+
   /// Indicates whether high-pass filter is enabled for this
   /// [`LocalMediaTrack`].
   ///
@@ -6461,6 +8974,28 @@ class LocalMediaTrackImpl extends RustOpaque implements LocalMediaTrack {
         that: this,
       );
 
+  /// Indicates whether the camera producing this [`LocalMediaTrack`]
+  /// supports torch control via
+  /// [`LocalMediaTrack::set_torch_enabled()`].
+  bool isTorchAvailable() => RustLib.instance.api
+      .crateApiDartApiLocalMediaTrackLocalMediaTrackIsTorchAvailable(
+        that: this,
+      );
+
+  /// Indicates whether the camera producing this [`LocalMediaTrack`]
+  /// supports white balance mode control via
+  /// [`LocalMediaTrack::set_white_balance_mode()`].
+  bool isWhiteBalanceModeAvailable() => RustLib.instance.api
+      .crateApiDartApiLocalMediaTrackLocalMediaTrackIsWhiteBalanceModeAvailable(
+        that: this,
+      );
+
+  /// Indicates whether the camera producing this [`LocalMediaTrack`]
+  /// supports zoom control via
+  /// [`LocalMediaTrack::set_zoom()`].
+  bool isZoomAvailable() => RustLib.instance.api
+      .crateApiDartApiLocalMediaTrackLocalMediaTrackIsZoomAvailable(that: this);
+
   /// Returns a [`MediaKind::Audio`] if the provided [`LocalMediaTrack`]
   /// represents an audio track, or a [`MediaKind::Video`] if it represents a
   /// video track.
@@ -6509,6 +9044,32 @@ class LocalMediaTrackImpl extends RustOpaque implements LocalMediaTrack {
         enabled: enabled,
       );
 
+  /// Sets the provided exposure compensation (in exposure value stops) of the
+  /// camera producing this [`LocalMediaTrack`].
+  Object setExposureCompensation({required double compensation}) => RustLib
+      .instance
+      .api
+      .crateApiDartApiLocalMediaTrackLocalMediaTrackSetExposureCompensation(
+        that: this,
+        compensation: compensation,
+      );
+
+  /// Sets the provided focus distance (in meters) of the camera producing
+  /// this [`LocalMediaTrack`].
+  Object setFocusDistance({required double distance}) => RustLib.instance.api
+      .crateApiDartApiLocalMediaTrackLocalMediaTrackSetFocusDistance(
+        that: this,
+        distance: distance,
+      );
+
+  /// Sets the provided focus [`MeteringMode`] of the camera producing this
+  /// [`LocalMediaTrack`].
+  Object setFocusMode({required MeteringMode mode}) => RustLib.instance.api
+      .crateApiDartApiLocalMediaTrackLocalMediaTrackSetFocusMode(
+        that: this,
+        mode: mode,
+      );
+
   /// Toggles high-pass filter for this [`LocalMediaTrack`].
   ///
   /// __NOTE__: Only supported on desktop platforms.
@@ -6540,6 +9101,32 @@ class LocalMediaTrackImpl extends RustOpaque implements LocalMediaTrack {
         level: level,
       );
 
+  /// Toggles the torch (fill light) of the camera producing this
+  /// [`LocalMediaTrack`].
+  Object setTorchEnabled({required bool enabled}) => RustLib.instance.api
+      .crateApiDartApiLocalMediaTrackLocalMediaTrackSetTorchEnabled(
+        that: this,
+        enabled: enabled,
+      );
+
+  /// Sets the provided white balance [`MeteringMode`] of the camera producing
+  /// this [`LocalMediaTrack`].
+  Object setWhiteBalanceMode({required MeteringMode mode}) => RustLib
+      .instance
+      .api
+      .crateApiDartApiLocalMediaTrackLocalMediaTrackSetWhiteBalanceMode(
+        that: this,
+        mode: mode,
+      );
+
+  /// Sets the provided zoom level of the camera producing this
+  /// [`LocalMediaTrack`].
+  Object setZoom({required double zoom}) =>
+      RustLib.instance.api.crateApiDartApiLocalMediaTrackLocalMediaTrackSetZoom(
+        that: this,
+        zoom: zoom,
+      );
+
   /// Returns a [`media::MediaStreamTrackState::Live`] if this
   /// [`LocalMediaTrack`] is active, or a
   /// [`media::MediaStreamTrackState::Ended`] if it has ended.
@@ -6570,6 +9157,14 @@ class MediaManagerHandleImpl extends RustOpaque implements MediaManagerHandle {
         .rust_arc_decrement_strong_count_MediaManagerHandlePtr,
   );
 
+  /// Returns [`ApiDeviceCapabilities`] of the media input device with the
+  /// provided `device_id`.
+  Object deviceCapabilities({required String deviceId}) => RustLib.instance.api
+      .crateApiDartApiMediaManagerMediaManagerHandleDeviceCapabilities(
+        that: this,
+        deviceId: deviceId,
+      );
+
   /// Returns a list of [`ApiMediaDeviceDetails`] objects representing
   /// available media input and devices, such as microphones, cameras, and
   /// so forth.
@@ -6622,6 +9217,41 @@ class MediaManagerHandleImpl extends RustOpaque implements MediaManagerHandle {
         cb: cb,
       );
 
+  /// Subscribes onto the [`PermissionState`] changes of the provided
+  /// [`PermissionKind`].
+  ///
+  /// [`PermissionState`]: core::PermissionState
+  Object onPermissionChange({
+    required PermissionKind kind,
+    required Object cb,
+  }) => RustLib.instance.api
+      .crateApiDartApiMediaManagerMediaManagerHandleOnPermissionChange(
+        that: this,
+        kind: kind,
+        cb: cb,
+      );
+
+  /// Returns the current [`PermissionState`] of the provided
+  /// [`PermissionKind`].
+  ///
+  /// [`PermissionState`]: core::PermissionState
+  Object permissionState({required PermissionKind kind}) => RustLib.instance.api
+      .crateApiDartApiMediaManagerMediaManagerHandlePermissionState(
+        that: this,
+        kind: kind,
+      );
+
+  /// Runs pre-call diagnostics of media devices, network and connectivity
+  /// via the provided [`ApiIceServer`]s, resolving with a
+  /// [`DiagnosticsReport`].
+  Object runDiagnostics({required List<ApiIceServer> iceServers}) => RustLib
+      .instance
+      .api
+      .crateApiDartApiMediaManagerMediaManagerHandleRunDiagnostics(
+        that: this,
+        iceServers: iceServers,
+      );
+
   /// Sets the microphone volume level in percents.
   Object setMicrophoneVolume({required PlatformInt64 level}) => RustLib
       .instance
@@ -6891,6 +9521,52 @@ class RoomHandleImpl extends RustOpaque implements RoomHandle {
   Object join({required String token}) => RustLib.instance.api
       .crateApiDartApiRoomRoomHandleJoin(that: this, token: token);
 
+  /// Connects to a media server and joins the [`Room`] with the provided
+  /// `credential`, so it never appears in the connection URL.
+  ///
+  /// The `url` has the following format:
+  /// `{{ Host URL }}/{{ Room ID }}/{{ Member ID }}`
+  /// (e.g. `wss://medea.com/MyConf1/Alice`).
+  ///
+  /// Additional HTTP `headers` are sent in a handshake request of the
+  /// [WebSocket] connection with a media server.
+  ///
+  /// [WebSocket]: https://en.wikipedia.org/wiki/WebSocket
+  Object joinWithCredential({
+    required String url,
+    required String credential,
+    required Map<String, String> headers,
+  }) => RustLib.instance.api.crateApiDartApiRoomRoomHandleJoinWithCredential(
+    that: this,
+    url: url,
+    credential: credential,
+    headers: headers,
+  );
+
+  /// Leaves this [`Room`] gracefully, reporting the provided `reason` of
+  /// leaving to a media server.
+  ///
+  /// Waits for a media server to acknowledge the leaving (for 3 seconds at
+  /// most), stops all the local media tracks sent in this [`Room`] and
+  /// resolves with its final [`RoomCloseReason`].
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::leave()`] method errors.
+  Object leave({String? reason}) => RustLib.instance.api
+      .crateApiDartApiRoomRoomHandleLeave(that: this, reason: reason);
+
+  /// Returns all the `Member`s currently present in this [`Room`]
+  /// (including the local one).
+  ///
+  /// Doesn't depend on any media being exchanged with these `Member`s.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::members()`] method errors.
+  List<RoomMember> members() =>
+      RustLib.instance.api.crateApiDartApiRoomRoomHandleMembers(that: this);
+
   /// Mutes outbound audio in the provided [`Room`].
   Object muteAudio({MediaSourceKind? sourceKind}) =>
       RustLib.instance.api.crateApiDartApiRoomRoomHandleMuteAudio(
@@ -6957,6 +9633,33 @@ class RoomHandleImpl extends RustOpaque implements RoomHandle {
   void onLocalTrack({required Object cb}) => RustLib.instance.api
       .crateApiDartApiRoomRoomHandleOnLocalTrack(that: this, cb: cb);
 
+  /// Sets a callback to be invoked once a new `Member` joins the provided
+  /// [`Room`], providing a [`RoomMember`].
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::on_member_joined()`] method errors.
+  void onMemberJoined({required Object cb}) => RustLib.instance.api
+      .crateApiDartApiRoomRoomHandleOnMemberJoined(that: this, cb: cb);
+
+  /// Sets a callback to be invoked with an ID of a `Member` who left the
+  /// provided [`Room`].
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::on_member_left()`] method errors.
+  void onMemberLeft({required Object cb}) => RustLib.instance.api
+      .crateApiDartApiRoomRoomHandleOnMemberLeft(that: this, cb: cb);
+
+  /// Sets a callback to be invoked once metadata of some `Member` of the
+  /// provided [`Room`] changes, providing an actual [`RoomMember`].
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::on_member_updated()`] method errors.
+  void onMemberUpdated({required Object cb}) => RustLib.instance.api
+      .crateApiDartApiRoomRoomHandleOnMemberUpdated(that: this, cb: cb);
+
   /// Sets a callback to be invoked once a new [`Connection`] with some remote
   /// `Peer` is established.
   ///
@@ -6968,6 +9671,34 @@ class RoomHandleImpl extends RustOpaque implements RoomHandle {
   void onNewConnection({required Object cb}) => RustLib.instance.api
       .crateApiDartApiRoomRoomHandleOnNewConnection(that: this, cb: cb);
 
+  /// Sets a callback to be invoked with actual [`SignallingLatency`]
+  /// statistics each time a round-trip time of the signalling connection
+  /// with a media server is measured.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::on_signalling_latency()`] method errors.
+  void onSignallingLatency({required Object cb}) => RustLib.instance.api
+      .crateApiDartApiRoomRoomHandleOnSignallingLatency(that: this, cb: cb);
+
+  /// Sets a callback to be invoked with a name of an event received from a
+  /// media server, which this client doesn't recognize.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::on_unknown_event()`] method errors.
+  void onUnknownEvent({required Object cb}) => RustLib.instance.api
+      .crateApiDartApiRoomRoomHandleOnUnknownEvent(that: this, cb: cb);
+
+  /// Returns name of the transport used by the signalling connection with a
+  /// media server (`WebSocket` or `SSE`), if it has been established.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::rpc_transport_kind()`] method errors.
+  String? rpcTransportKind() => RustLib.instance.api
+      .crateApiDartApiRoomRoomHandleRpcTransportKind(that: this);
+
   /// Updates this [`Room`]'s [`ApiMediaStreamSettings`].
   ///
   /// This affects all the [`PeerConnection`]s in this [`Room`]. If
@@ -7008,6 +9739,15 @@ class RoomHandleImpl extends RustOpaque implements RoomHandle {
     rollbackOnFail: rollbackOnFail,
   );
 
+  /// Returns the current [`SignallingLatency`] statistics of the signalling
+  /// connection with a media server, if any has been measured yet.
+  ///
+  /// # Errors
+  ///
+  /// If the [`core::RoomHandleImpl::signalling_latency()`] method errors.
+  SignallingLatency? signallingLatency() => RustLib.instance.api
+      .crateApiDartApiRoomRoomHandleSignallingLatency(that: this);
+
   /// Unmutes outbound audio in the provided [`Room`].
   Object unmuteAudio({MediaSourceKind? sourceKind}) =>
       RustLib.instance.api.crateApiDartApiRoomRoomHandleUnmuteAudio(
@@ -11,6 +11,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
 import 'api/dart/api.dart';
 import 'api/dart/api/connection_handle.dart';
+import 'api/dart/api/diagnostics.dart';
 import 'api/dart/api/jason.dart';
 import 'api/dart/api/local_media_track.dart';
 import 'api/dart/api/media_manager.dart';
@@ -19,6 +20,8 @@ import 'api/dart/api/reconnect_handle.dart';
 import 'api/dart/api/remote_media_track.dart';
 import 'api/dart/api/room.dart';
 import 'api/dart/api/room_close_reason.dart';
+import 'api/dart/api/room_member.dart';
+import 'api/dart/api/signalling_latency.dart';
 import 'api/shared.dart';
 import 'frb_generated.dart';
 import 'media.dart';
@@ -151,6 +154,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Object dco_decode_DartOpaque(dynamic raw);
 
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
+
   @protected
   ConnectionHandle
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle(
@@ -207,6 +213,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  ApiDeviceCapabilities dco_decode_api_device_capabilities(dynamic raw);
+
   @protected
   ApiDeviceVideoTrackConstraints dco_decode_api_device_video_track_constraints(
     dynamic raw,
@@ -220,6 +229,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ApiDisplayVideoTrackConstraints
   dco_decode_api_display_video_track_constraints(dynamic raw);
 
+  @protected
+  ApiFloatRange dco_decode_api_float_range(dynamic raw);
+
+  @protected
+  ApiIceServer dco_decode_api_ice_server(dynamic raw);
+
+  @protected
+  ApiIntRange dco_decode_api_int_range(dynamic raw);
+
   @protected
   ApiMediaDeviceDetails dco_decode_api_media_device_details(dynamic raw);
 
@@ -256,6 +274,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ApiDisplayVideoTrackConstraints
   dco_decode_box_autoadd_api_display_video_track_constraints(dynamic raw);
 
+  @protected
+  ApiFloatRange dco_decode_box_autoadd_api_float_range(dynamic raw);
+
+  @protected
+  ApiIntRange dco_decode_box_autoadd_api_int_range(dynamic raw);
+
   @protected
   ApiMediaStreamSettings dco_decode_box_autoadd_api_media_stream_settings(
     dynamic raw,
@@ -270,6 +294,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ConstrainU32 dco_decode_box_autoadd_constrain_u_32(dynamic raw);
 
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw);
+
   @protected
   MediaSourceKind dco_decode_box_autoadd_media_source_kind(dynamic raw);
 
@@ -283,6 +313,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  SignallingLatency dco_decode_box_autoadd_signalling_latency(dynamic raw);
+
+  @protected
+  ThroughputDiagnostics dco_decode_box_autoadd_throughput_diagnostics(
+    dynamic raw,
+  );
+
   @protected
   int dco_decode_box_autoadd_u_16(dynamic raw);
 
@@ -295,6 +333,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ConstrainU32 dco_decode_constrain_u_32(dynamic raw);
 
+  @protected
+  DeviceDiagnostics dco_decode_device_diagnostics(dynamic raw);
+
+  @protected
+  DiagnosticsReport dco_decode_diagnostics_report(dynamic raw);
+
   @protected
   double dco_decode_f_64(dynamic raw);
 
@@ -307,6 +351,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  IceDiagnostics dco_decode_ice_diagnostics(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_isize(dynamic raw);
 
@@ -316,6 +363,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<ApiIceServer> dco_decode_list_api_ice_server(dynamic raw);
+
   @protected
   List<ApiMediaDeviceDetails> dco_decode_list_api_media_device_details(
     dynamic raw,
@@ -326,9 +379,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  List<bool> dco_decode_list_bool(dynamic raw);
+
+  @protected
+  List<DeviceDiagnostics> dco_decode_list_device_diagnostics(dynamic raw);
+
+  @protected
+  List<FacingMode> dco_decode_list_facing_mode(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
+
+  @protected
+  List<RoomMember> dco_decode_list_room_member(dynamic raw);
+
   @protected
   LogLevel dco_decode_log_level(dynamic raw);
 
@@ -347,6 +415,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   MemberConnectionState dco_decode_member_connection_state(dynamic raw);
 
+  @protected
+  MeteringMode dco_decode_metering_mode(dynamic raw);
+
   @protected
   NoiseSuppressionLevel dco_decode_noise_suppression_level(dynamic raw);
 
@@ -374,6 +445,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ApiDisplayVideoTrackConstraints?
   dco_decode_opt_box_autoadd_api_display_video_track_constraints(dynamic raw);
 
+  @protected
+  ApiFloatRange? dco_decode_opt_box_autoadd_api_float_range(dynamic raw);
+
+  @protected
+  ApiIntRange? dco_decode_opt_box_autoadd_api_int_range(dynamic raw);
+
   @protected
   AudioDeviceKind? dco_decode_opt_box_autoadd_audio_device_kind(dynamic raw);
 
@@ -383,6 +460,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ConstrainU32? dco_decode_opt_box_autoadd_constrain_u_32(dynamic raw);
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
   @protected
   MediaSourceKind? dco_decode_opt_box_autoadd_media_source_kind(dynamic raw);
 
@@ -396,6 +479,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  SignallingLatency? dco_decode_opt_box_autoadd_signalling_latency(dynamic raw);
+
+  @protected
+  ThroughputDiagnostics? dco_decode_opt_box_autoadd_throughput_diagnostics(
+    dynamic raw,
+  );
+
   @protected
   int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
 
@@ -405,12 +496,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PeerConnectionState dco_decode_peer_connection_state(dynamic raw);
 
+  @protected
+  PermissionKind dco_decode_permission_kind(dynamic raw);
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw);
+
   @protected
   RoomCloseKind dco_decode_room_close_kind(dynamic raw);
 
   @protected
   RoomCloseReason dco_decode_room_close_reason(dynamic raw);
 
+  @protected
+  RoomMember dco_decode_room_member(dynamic raw);
+
+  @protected
+  SignallingLatency dco_decode_signalling_latency(dynamic raw);
+
+  @protected
+  ThroughputDiagnostics dco_decode_throughput_diagnostics(dynamic raw);
+
   @protected
   int dco_decode_u_16(dynamic raw);
 
@@ -516,6 +622,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Object sse_decode_DartOpaque(SseDeserializer deserializer);
 
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ConnectionHandle
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle(
@@ -571,6 +682,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiDeviceCapabilities sse_decode_api_device_capabilities(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiDeviceVideoTrackConstraints sse_decode_api_device_video_track_constraints(
     SseDeserializer deserializer,
@@ -584,6 +700,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ApiDisplayVideoTrackConstraints
   sse_decode_api_display_video_track_constraints(SseDeserializer deserializer);
 
+  @protected
+  ApiFloatRange sse_decode_api_float_range(SseDeserializer deserializer);
+
+  @protected
+  ApiIceServer sse_decode_api_ice_server(SseDeserializer deserializer);
+
+  @protected
+  ApiIntRange sse_decode_api_int_range(SseDeserializer deserializer);
+
   @protected
   ApiMediaDeviceDetails sse_decode_api_media_device_details(
     SseDeserializer deserializer,
@@ -634,6 +759,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiFloatRange sse_decode_box_autoadd_api_float_range(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiIntRange sse_decode_box_autoadd_api_int_range(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiMediaStreamSettings sse_decode_box_autoadd_api_media_stream_settings(
     SseDeserializer deserializer,
@@ -654,6 +789,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
   @protected
   MediaSourceKind sse_decode_box_autoadd_media_source_kind(
     SseDeserializer deserializer,
@@ -669,6 +810,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  SignallingLatency sse_decode_box_autoadd_signalling_latency(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ThroughputDiagnostics sse_decode_box_autoadd_throughput_diagnostics(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
 
@@ -681,6 +832,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ConstrainU32 sse_decode_constrain_u_32(SseDeserializer deserializer);
 
+  @protected
+  DeviceDiagnostics sse_decode_device_diagnostics(SseDeserializer deserializer);
+
+  @protected
+  DiagnosticsReport sse_decode_diagnostics_report(SseDeserializer deserializer);
+
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
@@ -693,6 +850,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
+  @protected
+  IceDiagnostics sse_decode_ice_diagnostics(SseDeserializer deserializer);
+
   @protected
   PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
 
@@ -702,6 +862,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<ApiIceServer> sse_decode_list_api_ice_server(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<ApiMediaDeviceDetails> sse_decode_list_api_media_device_details(
     SseDeserializer deserializer,
@@ -712,9 +880,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<bool> sse_decode_list_bool(SseDeserializer deserializer);
+
+  @protected
+  List<DeviceDiagnostics> sse_decode_list_device_diagnostics(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FacingMode> sse_decode_list_facing_mode(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RoomMember> sse_decode_list_room_member(SseDeserializer deserializer);
+
   @protected
   LogLevel sse_decode_log_level(SseDeserializer deserializer);
 
@@ -735,6 +922,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  MeteringMode sse_decode_metering_mode(SseDeserializer deserializer);
+
   @protected
   NoiseSuppressionLevel sse_decode_noise_suppression_level(
     SseDeserializer deserializer,
@@ -772,6 +962,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiFloatRange? sse_decode_opt_box_autoadd_api_float_range(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiIntRange? sse_decode_opt_box_autoadd_api_int_range(
+    SseDeserializer deserializer,
+  );
+
   @protected
   AudioDeviceKind? sse_decode_opt_box_autoadd_audio_device_kind(
     SseDeserializer deserializer,
@@ -787,6 +987,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
   @protected
   MediaSourceKind? sse_decode_opt_box_autoadd_media_source_kind(
     SseDeserializer deserializer,
@@ -802,6 +1008,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  SignallingLatency? sse_decode_opt_box_autoadd_signalling_latency(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ThroughputDiagnostics? sse_decode_opt_box_autoadd_throughput_diagnostics(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
 
@@ -813,12 +1029,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  PermissionKind sse_decode_permission_kind(SseDeserializer deserializer);
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RoomCloseKind sse_decode_room_close_kind(SseDeserializer deserializer);
 
   @protected
   RoomCloseReason sse_decode_room_close_reason(SseDeserializer deserializer);
 
+  @protected
+  RoomMember sse_decode_room_member(SseDeserializer deserializer);
+
+  @protected
+  SignallingLatency sse_decode_signalling_latency(SseDeserializer deserializer);
+
+  @protected
+  ThroughputDiagnostics sse_decode_throughput_diagnostics(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer);
 
@@ -938,6 +1173,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_DartOpaque(Object self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConnectionHandle(
@@ -1002,6 +1243,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_api_device_capabilities(
+    ApiDeviceCapabilities self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_api_device_video_track_constraints(
     ApiDeviceVideoTrackConstraints self,
@@ -1020,6 +1267,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_api_float_range(ApiFloatRange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_api_ice_server(ApiIceServer self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_api_int_range(ApiIntRange self, SseSerializer serializer);
+
   @protected
   void sse_encode_api_media_device_details(
     ApiMediaDeviceDetails self,
@@ -1077,6 +1333,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_api_float_range(
+    ApiFloatRange self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_api_int_range(
+    ApiIntRange self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_api_media_stream_settings(
     ApiMediaStreamSettings self,
@@ -1101,6 +1369,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_media_source_kind(
     MediaSourceKind self,
@@ -1119,6 +1393,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_signalling_latency(
+    SignallingLatency self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_throughput_diagnostics(
+    ThroughputDiagnostics self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
 
@@ -1134,6 +1420,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_constrain_u_32(ConstrainU32 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_device_diagnostics(
+    DeviceDiagnostics self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_diagnostics_report(
+    DiagnosticsReport self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
@@ -1146,6 +1444,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_ice_diagnostics(
+    IceDiagnostics self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
 
@@ -1156,6 +1460,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_api_ice_server(
+    List<ApiIceServer> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_api_media_device_details(
     List<ApiMediaDeviceDetails> self,
@@ -1168,12 +1481,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_bool(List<bool> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_device_diagnostics(
+    List<DeviceDiagnostics> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_facing_mode(
+    List<FacingMode> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_room_member(
+    List<RoomMember> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_log_level(LogLevel self, SseSerializer serializer);
 
@@ -1204,6 +1544,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_metering_mode(MeteringMode self, SseSerializer serializer);
+
   @protected
   void sse_encode_noise_suppression_level(
     NoiseSuppressionLevel self,
@@ -1243,6 +1586,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_api_float_range(
+    ApiFloatRange? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_api_int_range(
+    ApiIntRange? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_audio_device_kind(
     AudioDeviceKind? self,
@@ -1261,6 +1616,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_media_source_kind(
     MediaSourceKind? self,
@@ -1279,6 +1640,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_signalling_latency(
+    SignallingLatency? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_throughput_diagnostics(
+    ThroughputDiagnostics? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
 
@@ -1291,6 +1664,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_permission_kind(
+    PermissionKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_room_close_kind(RoomCloseKind self, SseSerializer serializer);
 
@@ -1300,6 +1685,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_room_member(RoomMember self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_signalling_latency(
+    SignallingLatency self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_throughput_diagnostics(
+    ThroughputDiagnostics self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer);
 
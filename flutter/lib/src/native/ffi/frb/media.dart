@@ -58,3 +58,17 @@ enum MediaKind {
   /// Video track.
   video,
 }
+
+/// Kind of a media permission, requested by a user agent before capturing
+/// media.
+enum PermissionKind {
+  /// Permission to capture audio from a microphone.
+  microphone,
+
+  /// Permission to capture video from a camera.
+  camera,
+
+  /// Permission to capture contents of a display or its portion (such as a
+  /// single window).
+  screen,
+}
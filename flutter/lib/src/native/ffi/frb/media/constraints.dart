@@ -55,6 +55,25 @@ enum FacingMode {
   right,
 }
 
+/// Mode of an automatic camera adjustment (focus, white balance, etc.).
+///
+/// Representation of a [MeteringMode][1].
+///
+/// [1]: https://w3.org/TR/image-capture#meteringmode-section
+enum MeteringMode {
+  /// No adjustment is available, or it's disabled.
+  none,
+
+  /// Adjustment is locked to a value set manually.
+  manual,
+
+  /// Adjustment is performed once, and then locked.
+  singleShot,
+
+  /// Adjustment is performed continuously.
+  continuous,
+}
+
 /// Audio processing noise suppression aggressiveness.
 enum NoiseSuppressionLevel {
   /// Minimal noise suppression.
@@ -7,6 +7,7 @@ import 'package:medea_flutter_webrtc/medea_flutter_webrtc.dart';
 import '../interface/enums.dart' as base;
 import '../interface/jason.dart' as base;
 import '../interface/media_manager.dart';
+import '../interface/network_state.dart';
 import '../interface/room_handle.dart';
 import '../util/move_semantic.dart';
 import '../util/rust_opaque.dart' as util;
@@ -178,6 +179,46 @@ class Jason implements base.Jason {
     await (opaque.inner.jasonNetworkChanged() as Future);
   }
 
+  @override
+  void enableSseFallback() {
+    opaque.inner.jasonEnableSseFallback();
+  }
+
+  @override
+  void shareRpcConnection() {
+    opaque.inner.jasonShareRpcConnection();
+  }
+
+  @override
+  void enableRpcRecording() {
+    opaque.inner.jasonEnableRpcRecording();
+  }
+
+  @override
+  String? exportRpcRecording() {
+    return opaque.inner.jasonExportRpcRecording();
+  }
+
+  @override
+  void enableNetworkMonitor() {
+    throw UnsupportedError(
+      'Network monitoring is not supported on native platforms, use '
+      '`setNetworkOnline()` instead',
+    );
+  }
+
+  @override
+  void setNetworkOnline(bool online) {
+    opaque.inner.jasonSetNetworkOnline(online: online);
+  }
+
+  @override
+  void onNetworkStateChange(void Function(NetworkState) f) {
+    opaque.inner.jasonOnNetworkStateChange(
+      cb: (int i) => f(NetworkState.values[i]),
+    );
+  }
+
   @override
   @moveSemantics
   void free() {
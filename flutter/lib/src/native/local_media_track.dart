@@ -1,10 +1,12 @@
 import 'package:medea_flutter_webrtc/medea_flutter_webrtc.dart' as webrtc;
 
+import '../interface/device_video_track_constraints.dart' as base;
 import '../interface/enums.dart';
 import '../interface/media_track.dart';
 import '../util/move_semantic.dart';
 import '../util/rust_opaque.dart';
 import '/src/util/rust_handles_storage.dart';
+import 'device_video_track_constraints.dart';
 import 'ffi/frb/frb.dart' as frb;
 
 class NativeLocalMediaTrack implements LocalMediaTrack {
@@ -84,6 +86,76 @@ class NativeLocalMediaTrack implements LocalMediaTrack {
     await (opaque.inner.setHighPassFilterEnabled(enabled: enabled) as Future);
   }
 
+  @override
+  Future<void> applyConstraints(
+    @moveSemantics base.DeviceVideoTrackConstraints constraints,
+  ) async {
+    await (opaque.inner.applyConstraints(
+          constraints: (constraints as DeviceVideoTrackConstraints).constraints,
+        )
+        as Future);
+  }
+
+  @override
+  bool isZoomAvailable() {
+    return opaque.inner.isZoomAvailable();
+  }
+
+  @override
+  Future<void> setZoom(double zoom) async {
+    await (opaque.inner.setZoom(zoom: zoom) as Future);
+  }
+
+  @override
+  bool isTorchAvailable() {
+    return opaque.inner.isTorchAvailable();
+  }
+
+  @override
+  Future<void> setTorchEnabled(bool enabled) async {
+    await (opaque.inner.setTorchEnabled(enabled: enabled) as Future);
+  }
+
+  @override
+  bool isFocusModeAvailable() {
+    return opaque.inner.isFocusModeAvailable();
+  }
+
+  @override
+  Future<void> setFocusMode(MeteringMode mode) async {
+    await (opaque.inner.setFocusMode(mode: mode) as Future);
+  }
+
+  @override
+  bool isFocusDistanceAvailable() {
+    return opaque.inner.isFocusDistanceAvailable();
+  }
+
+  @override
+  Future<void> setFocusDistance(double distance) async {
+    await (opaque.inner.setFocusDistance(distance: distance) as Future);
+  }
+
+  @override
+  bool isExposureCompensationAvailable() {
+    return opaque.inner.isExposureCompensationAvailable();
+  }
+
+  @override
+  Future<void> setExposureCompensation(double compensation) async {
+    await (opaque.inner.setExposureCompensation(compensation: compensation) as Future);
+  }
+
+  @override
+  bool isWhiteBalanceModeAvailable() {
+    return opaque.inner.isWhiteBalanceModeAvailable();
+  }
+
+  @override
+  Future<void> setWhiteBalanceMode(MeteringMode mode) async {
+    await (opaque.inner.setWhiteBalanceMode(mode: mode) as Future);
+  }
+
   @override
   Future<void> setNoiseSuppressionEnabled(bool enabled) async {
     await (opaque.inner.setNoiseSuppressionEnabled(enabled: enabled) as Future);
@@ -7,6 +7,7 @@ import '../util/move_semantic.dart';
 import '../util/rust_opaque.dart';
 import '/src/util/rust_handles_storage.dart';
 import 'ffi/frb/frb.dart' as frb;
+import 'device_capabilities.dart';
 import 'local_media_track.dart';
 import 'media_device_details.dart';
 import 'media_display_details.dart';
@@ -50,6 +51,18 @@ class NativeMediaManagerHandle implements MediaManagerHandle {
         .toList();
   }
 
+  @override
+  Future<DeviceCapabilities> deviceCapabilities(String deviceId) async {
+    Pointer caps;
+    caps =
+        await (opaque.inner.deviceCapabilities(deviceId: deviceId) as Future)
+            as Pointer;
+
+    return NativeDeviceCapabilities(
+      frb.deviceCapabilitiesFromRaw(ptr: caps.address),
+    );
+  }
+
   @override
   Future<List<MediaDisplayDetails>> enumerateDisplays() async {
     if (!(Platform.isLinux || Platform.isWindows || Platform.isMacOS)) {
@@ -92,6 +105,46 @@ class NativeMediaManagerHandle implements MediaManagerHandle {
     opaque.inner.onDeviceChange(cb: cb);
   }
 
+  @override
+  Future<PermissionState> permissionState(PermissionKind kind) async {
+    var state = await (opaque.inner.permissionState(kind: kind) as Future);
+    return PermissionState.values[state as int];
+  }
+
+  @override
+  Future<void> onPermissionChange(
+    PermissionKind kind,
+    void Function(PermissionState) f,
+  ) async {
+    await (opaque.inner.onPermissionChange(
+          kind: kind,
+          cb: (int i) => f(PermissionState.values[i]),
+        )
+        as Future);
+  }
+
+  @override
+  Future<DiagnosticsReport> runDiagnostics(List<IceServer> iceServers) async {
+    var report =
+        await (opaque.inner.runDiagnostics(
+                  iceServers: iceServers
+                      .map(
+                        (s) => frb.ApiIceServer(
+                          urls: s.urls,
+                          username: s.username,
+                          credential: s.credential,
+                        ),
+                      )
+                      .toList(),
+                )
+                as Future)
+            as Pointer;
+
+    return _diagnosticsReport(
+      frb.DiagnosticsReport.fromPtr(ptr: report.address),
+    );
+  }
+
   @moveSemantics
   @override
   void free() {
@@ -102,3 +155,38 @@ class NativeMediaManagerHandle implements MediaManagerHandle {
     }
   }
 }
+
+/// Converts the provided [frb.DiagnosticsReport] into a [DiagnosticsReport].
+DiagnosticsReport _diagnosticsReport(frb.DiagnosticsReport report) {
+  var throughput = report.throughput;
+  return DiagnosticsReport(
+    report.devices
+        .map(
+          (d) => DeviceDiagnostics(
+            deviceId: d.deviceId,
+            label: d.label,
+            kind: d.kind,
+            error: d.error,
+            width: d.width,
+            height: d.height,
+            frameRate: d.frameRate,
+            audioLevel: d.audioLevel,
+          ),
+        )
+        .toList(),
+    IceDiagnostics(
+      hostCandidates: report.ice.hostCandidates,
+      srflxCandidates: report.ice.srflxCandidates,
+      relayCandidates: report.ice.relayCandidates,
+      connected: report.ice.connected,
+      errors: report.ice.errors,
+    ),
+    throughput == null
+        ? null
+        : ThroughputDiagnostics(
+            throughput.bytesSent,
+            throughput.durationMs,
+            throughput.bitrateBps,
+          ),
+  );
+}
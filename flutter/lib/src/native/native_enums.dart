@@ -3,9 +3,9 @@ export '../native/ffi/frb/api/shared.dart'
     show PeerConnectionState, RoomCloseKind, LogLevel;
 export '../native/ffi/frb/media/track.dart' show MediaSourceKind;
 export '../native/ffi/frb/media.dart'
-    show MediaKind, MediaDeviceKind, AudioDeviceKind;
+    show MediaKind, MediaDeviceKind, AudioDeviceKind, PermissionKind;
 export '../native/ffi/frb/media/constraints.dart'
-    show FacingMode, NoiseSuppressionLevel;
+    show FacingMode, MeteringMode, NoiseSuppressionLevel;
 
 export 'package:medea_flutter_webrtc/medea_flutter_webrtc.dart'
     show MediaStreamTrackState;
@@ -14,6 +14,7 @@ import 'object.dart' as object;
 import 'peer_connection.dart' as peer_connection;
 import 'send_encoding_parameters.dart' as send_encoding_parameters;
 import 'send_parameters.dart' as parameters;
+import 'sse_transport.dart' as sse_transport;
 import 'transceiver.dart' as transceiver;
 import 'transport.dart' as transport;
 
@@ -28,6 +29,7 @@ void registerFunctions(DynamicLibrary dl) {
   constraints.registerFunctions(dl);
   media_devices.registerFunctions(dl);
   transport.registerFunctions(dl);
+  sse_transport.registerFunctions(dl);
   media_device_info.registerFunctions(dl);
   media_display_info.registerFunctions(dl);
   ice_candidate.registerFunctions(dl);
@@ -1,10 +1,13 @@
 import 'dart:ffi';
 
 import 'package:ffi/ffi.dart';
+import 'package:flutter/services.dart';
+import 'package:flutter/widgets.dart';
 import 'package:medea_flutter_webrtc/medea_flutter_webrtc.dart' as webrtc;
 
 import 'package:medea_jason/src/native/ffi/native_string.dart';
 import 'media_devices.g.dart' as bridge;
+import 'media_track.dart' show fetchCameraControls;
 
 /// Option to mock `getUserMedia()` request.
 const bool mockable = bool.fromEnvironment('MOCKABLE', defaultValue: false);
@@ -24,6 +27,8 @@ void registerFunctions(DynamicLibrary dl) {
       microphoneVolume: _microphoneVolume,
       onDeviceChange: _onDeviceChange,
       getMediaExceptionKind: _getMediaExceptionKind,
+      permissionState: _permissionState,
+      onPermissionChange: _onPermissionChange,
     );
   } else {
     bridge.registerFunction(
@@ -38,6 +43,8 @@ void registerFunctions(DynamicLibrary dl) {
       microphoneVolume: _microphoneVolume,
       onDeviceChange: _onDeviceChange,
       getMediaExceptionKind: _getMediaExceptionKind,
+      permissionState: _permissionState,
+      onPermissionChange: _onPermissionChange,
     );
   }
 }
@@ -70,12 +77,17 @@ class MockMediaDevices {
   }
 }
 
-/// Requests media input access and returns the created [webrtc.MediaStreamTrack]s.
+/// Requests media input access and returns the created
+/// [webrtc.MediaStreamTrack]s along with fetching their camera controls.
 Future<List<webrtc.MediaStreamTrack>> Function() _getUserMedia(
   Object constraints,
 ) {
   constraints as webrtc.DeviceConstraints;
-  return () => webrtc.getUserMedia(constraints);
+  return () async {
+    var tracks = await webrtc.getUserMedia(constraints);
+    await fetchCameraControls(tracks);
+    return tracks;
+  };
 }
 
 /// Returns all the available media devices.
@@ -125,8 +137,59 @@ void _onDeviceChange(Object cb) {
   webrtc.onDeviceChange(() => cb(null));
 }
 
-/// Returns the kind of the `GetMediaException`.
+/// Returns the kind of the `GetMediaException`, or `-1` if the provided
+/// [exception] is not a `GetMediaException`.
 int _getMediaExceptionKind(Object exception) {
-  exception as webrtc.GetMediaException;
+  if (exception is! webrtc.GetMediaException) {
+    return -1;
+  }
   return exception.kind().index;
 }
+
+/// [MethodChannel] of the `medea_jason` Flutter plugin providing media
+/// permissions, as `medea_flutter_webrtc` lacks them.
+const _channel = MethodChannel('medea_jason');
+
+/// [AppLifecycleListener]s re-querying media permissions, by their kinds.
+final Map<int, AppLifecycleListener> _permissionListeners = {};
+
+/// Queries the current state of the provided media permission kind.
+Future<int> _queryPermission(int kind) async {
+  try {
+    var state = await _channel.invokeMethod<int>('permissionState', {
+      'kind': kind,
+    });
+    return state!;
+  } on MissingPluginException {
+    // Platform has no media permissions, so they're always granted.
+    return 0;
+  }
+}
+
+/// Returns the current state of the provided media permission kind.
+Future<int> Function() _permissionState(int kind) {
+  return () => _queryPermission(kind);
+}
+
+/// Subscribes onto the state changes of the provided media permission kind,
+/// replacing the previous subscription.
+///
+/// Native platforms don't notify about media permissions changes, so they're
+/// re-queried whenever the application is resumed, as they can be changed in
+/// the system settings only.
+void _onPermissionChange(int kind, Object cb) {
+  cb as Function;
+  _permissionListeners.remove(kind)?.dispose();
+
+  int? last;
+  _queryPermission(kind).then((state) => last ??= state);
+  _permissionListeners[kind] = AppLifecycleListener(
+    onResume: () async {
+      var state = await _queryPermission(kind);
+      if (state != last) {
+        last = state;
+        cb(state);
+      }
+    },
+  );
+}
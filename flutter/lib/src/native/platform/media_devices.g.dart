@@ -17,6 +17,8 @@ Object Function()? _microphoneVolume;
 Object Function(int)? _setMicrophoneVolume;
 void Function(Object)? _onDeviceChange;
 int Function(Object)? _getMediaExceptionKind;
+Object Function(int)? _permissionState;
+void Function(int, Object)? _onPermissionChange;
 
 _ErrorSetterFnDart? _media_devices__enumerate_devices__set_error;
 _ErrorSetterFnDart? _media_devices__enumerate_displays__set_error;
@@ -28,6 +30,8 @@ _ErrorSetterFnDart? _media_devices__microphone_volume__set_error;
 _ErrorSetterFnDart? _media_devices__set_microphone_volume__set_error;
 _ErrorSetterFnDart? _media_devices__on_device_change__set_error;
 _ErrorSetterFnDart? _media_devices__get_media_exception_kind__set_error;
+_ErrorSetterFnDart? _media_devices__permission_state__set_error;
+_ErrorSetterFnDart? _media_devices__on_permission_change__set_error;
 
 void registerFunction(
   DynamicLibrary dl, {
@@ -41,6 +45,8 @@ void registerFunction(
   required Object Function(int) setMicrophoneVolume,
   required void Function(Object) onDeviceChange,
   required int Function(Object) getMediaExceptionKind,
+  required Object Function(int) permissionState,
+  required void Function(int, Object) onPermissionChange,
 }) {
   _enumerateDevices = enumerateDevices;
   _enumerateDisplays = enumerateDisplays;
@@ -52,6 +58,8 @@ void registerFunction(
   _setMicrophoneVolume = setMicrophoneVolume;
   _onDeviceChange = onDeviceChange;
   _getMediaExceptionKind = getMediaExceptionKind;
+  _permissionState = permissionState;
+  _onPermissionChange = onPermissionChange;
 
   _media_devices__enumerate_devices__set_error = dl
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
@@ -93,6 +101,14 @@ void registerFunction(
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
         'media_devices__get_media_exception_kind__set_error',
       );
+  _media_devices__permission_state__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'media_devices__permission_state__set_error',
+      );
+  _media_devices__on_permission_change__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'media_devices__on_permission_change__set_error',
+      );
 
   Pointer<NativeFunction<Handle Function()>> enumerateDevices_native =
       Pointer.fromFunction(_enumerateDevicesProxy);
@@ -116,6 +132,10 @@ void registerFunction(
       Pointer.fromFunction(_onDeviceChangeProxy);
   Pointer<NativeFunction<Int64 Function(Handle)>> getMediaExceptionKind_native =
       Pointer.fromFunction(_getMediaExceptionKindProxy, 0);
+  Pointer<NativeFunction<Handle Function(Int64)>> permissionState_native =
+      Pointer.fromFunction(_permissionStateProxy);
+  Pointer<NativeFunction<Void Function(Int64, Handle)>>
+  onPermissionChange_native = Pointer.fromFunction(_onPermissionChangeProxy);
 
   dl.lookupFunction<
     Void Function(
@@ -129,6 +149,8 @@ void registerFunction(
       Pointer,
       Pointer,
       Pointer,
+      Pointer,
+      Pointer,
     ),
     void Function(
       Pointer,
@@ -141,6 +163,8 @@ void registerFunction(
       Pointer,
       Pointer,
       Pointer,
+      Pointer,
+      Pointer,
     )
   >('register_media_devices')(
     enumerateDevices_native,
@@ -153,6 +177,8 @@ void registerFunction(
     setMicrophoneVolume_native,
     onDeviceChange_native,
     getMediaExceptionKind_native,
+    permissionState_native,
+    onPermissionChange_native,
   );
 }
 
@@ -245,3 +271,21 @@ int _getMediaExceptionKindProxy(Object arg0) {
     return 0;
   }
 }
+
+Object _permissionStateProxy(int arg0) {
+  try {
+    return _permissionState!(arg0);
+  } catch (e) {
+    _media_devices__permission_state__set_error!(e);
+    return 0;
+  }
+}
+
+void _onPermissionChangeProxy(int arg0, Object arg1) {
+  try {
+    return _onPermissionChange!(arg0, arg1);
+  } catch (e) {
+    _media_devices__on_permission_change__set_error!(e);
+    return;
+  }
+}
@@ -1,6 +1,7 @@
 import 'dart:ffi';
 
 import 'package:ffi/ffi.dart';
+import 'package:flutter/services.dart';
 import 'package:medea_flutter_webrtc/medea_flutter_webrtc.dart';
 
 import 'package:medea_jason/src/native/ffi/foreign_value.dart';
@@ -36,9 +37,46 @@ void registerFunctions(DynamicLibrary dl) {
     isAutoGainControlEnabled: _isAutoGainControlEnabled,
     isEchoCancellationEnabled: _isEchoCancellationEnabled,
     isHighPassFilterEnabled: _isHighPassFilterEnabled,
+    isCameraControlAvailable: _isCameraControlAvailable,
+    setCameraControl: _setCameraControl,
   );
 }
 
+/// [MethodChannel] of the `medea_jason` Flutter plugin providing camera
+/// controls, as `medea_flutter_webrtc` lacks them.
+const _channel = MethodChannel('medea_jason');
+
+/// Camera controls supported by the cameras of the obtained video
+/// [MediaStreamTrack]s, by their device IDs.
+final Map<String, Set<int>> _cameraControls = {};
+
+/// Fetches camera controls supported by the cameras of the provided video
+/// [MediaStreamTrack]s.
+///
+/// Must be awaited before the [MediaStreamTrack]s are passed to Rust, as it
+/// checks the camera controls availability synchronously.
+Future<void> fetchCameraControls(List<MediaStreamTrack> tracks) async {
+  for (var track in tracks) {
+    if (track.kind() != MediaKind.video) {
+      continue;
+    }
+    var deviceId = track.deviceId();
+    if (_cameraControls.containsKey(deviceId)) {
+      continue;
+    }
+
+    List<int>? controls;
+    try {
+      controls = await _channel.invokeListMethod<int>('cameraControls', {
+        'deviceId': deviceId,
+      });
+    } on MissingPluginException {
+      // Camera controls are not implemented on this platform.
+    }
+    _cameraControls[deviceId] = (controls ?? []).toSet();
+  }
+}
+
 /// Returns ID of the provided [MediaStreamTrack].
 Pointer<Utf8> _id(Object track) {
   track as MediaStreamTrack;
@@ -226,3 +264,28 @@ Future<bool> Function() _isHighPassFilterEnabled(Object track) {
     return await track.isHighPassFilterEnabled();
   };
 }
+
+/// Indicates whether the camera of the provided [MediaStreamTrack] supports the
+/// provided camera control.
+bool _isCameraControlAvailable(Object track, int control) {
+  track as MediaStreamTrack;
+
+  return _cameraControls[track.deviceId()]?.contains(control) ?? false;
+}
+
+/// Sets the provided value of the provided camera control for the camera of
+/// the provided [MediaStreamTrack].
+Future<void> Function() _setCameraControl(
+  Object track,
+  int control,
+  ForeignValue value,
+) {
+  track as MediaStreamTrack;
+  var args = {
+    'deviceId': track.deviceId(),
+    'control': control,
+    'value': value.toDart(),
+  };
+
+  return () => _channel.invokeMethod<void>('setCameraControl', args);
+}
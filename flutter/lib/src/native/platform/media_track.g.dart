@@ -33,6 +33,8 @@ Object Function(Object)? _getNoiseSuppressionLevel;
 Object Function(Object)? _isAutoGainControlEnabled;
 Object Function(Object)? _isEchoCancellationEnabled;
 Object Function(Object)? _isHighPassFilterEnabled;
+bool Function(Object, int)? _isCameraControlAvailable;
+Object Function(Object, int, ForeignValue)? _setCameraControl;
 
 _ErrorSetterFnDart? _media_stream_track__id__set_error;
 _ErrorSetterFnDart? _media_stream_track__device_id__set_error;
@@ -68,6 +70,9 @@ _media_stream_track__is_auto_gain_control_enabled__set_error;
 _ErrorSetterFnDart?
 _media_stream_track__is_echo_cancellation_enabled__set_error;
 _ErrorSetterFnDart? _media_stream_track__is_high_pass_filter_enabled__set_error;
+_ErrorSetterFnDart?
+_media_stream_track__is_camera_control_available__set_error;
+_ErrorSetterFnDart? _media_stream_track__set_camera_control__set_error;
 
 void registerFunction(
   DynamicLibrary dl, {
@@ -97,6 +102,8 @@ void registerFunction(
   required Object Function(Object) isAutoGainControlEnabled,
   required Object Function(Object) isEchoCancellationEnabled,
   required Object Function(Object) isHighPassFilterEnabled,
+  required bool Function(Object, int) isCameraControlAvailable,
+  required Object Function(Object, int, ForeignValue) setCameraControl,
 }) {
   _id = id;
   _deviceId = deviceId;
@@ -124,6 +131,8 @@ void registerFunction(
   _isAutoGainControlEnabled = isAutoGainControlEnabled;
   _isEchoCancellationEnabled = isEchoCancellationEnabled;
   _isHighPassFilterEnabled = isHighPassFilterEnabled;
+  _isCameraControlAvailable = isCameraControlAvailable;
+  _setCameraControl = setCameraControl;
 
   _media_stream_track__id__set_error = dl
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
@@ -229,6 +238,14 @@ void registerFunction(
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
         'media_stream_track__is_high_pass_filter_enabled__set_error',
       );
+  _media_stream_track__is_camera_control_available__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'media_stream_track__is_camera_control_available__set_error',
+      );
+  _media_stream_track__set_camera_control__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'media_stream_track__set_camera_control__set_error',
+      );
 
   Pointer<NativeFunction<Pointer<Utf8> Function(Handle)>> id_native =
       Pointer.fromFunction(_idProxy);
@@ -308,6 +325,13 @@ void registerFunction(
   isHighPassFilterEnabled_native = Pointer.fromFunction(
     _isHighPassFilterEnabledProxy,
   );
+  Pointer<NativeFunction<Bool Function(Handle, Int64)>>
+  isCameraControlAvailable_native = Pointer.fromFunction(
+    _isCameraControlAvailableProxy,
+    false,
+  );
+  Pointer<NativeFunction<Handle Function(Handle, Int64, ForeignValue)>>
+  setCameraControl_native = Pointer.fromFunction(_setCameraControlProxy);
 
   dl.lookupFunction<
     Void Function(
@@ -337,6 +361,8 @@ void registerFunction(
       Pointer,
       Pointer,
       Pointer,
+      Pointer,
+      Pointer,
     ),
     void Function(
       Pointer,
@@ -365,6 +391,8 @@ void registerFunction(
       Pointer,
       Pointer,
       Pointer,
+      Pointer,
+      Pointer,
     )
   >('register_media_stream_track')(
     id_native,
@@ -393,6 +421,8 @@ void registerFunction(
     isAutoGainControlEnabled_native,
     isEchoCancellationEnabled_native,
     isHighPassFilterEnabled_native,
+    isCameraControlAvailable_native,
+    setCameraControl_native,
   );
 }
 
@@ -629,3 +659,21 @@ Object _isHighPassFilterEnabledProxy(Object arg0) {
     return 0;
   }
 }
+
+bool _isCameraControlAvailableProxy(Object arg0, int arg1) {
+  try {
+    return _isCameraControlAvailable!(arg0, arg1);
+  } catch (e) {
+    _media_stream_track__is_camera_control_available__set_error!(e);
+    return false;
+  }
+}
+
+Object _setCameraControlProxy(Object arg0, int arg1, ForeignValue arg2) {
+  try {
+    return _setCameraControl!(arg0, arg1, arg2);
+  } catch (e) {
+    _media_stream_track__set_camera_control__set_error!(e);
+    return 0;
+  }
+}
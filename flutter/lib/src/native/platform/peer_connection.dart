@@ -25,6 +25,7 @@ void registerFunctions(DynamicLibrary dl) {
     onIceConnectionStateChange: _onIceConnectionStateChange,
     onIceGatheringStateChange: _onIceGatheringStateChange,
     newPeer: _newPeer,
+    setConfiguration: _setConfiguration,
     addTransceiver: _addTransceiver,
     createOffer: _createOffer,
     createAnswer: _createAnswer,
@@ -61,6 +62,22 @@ Future<PeerConnection> Function() _newPeer(
   );
 }
 
+/// Replaces `iceServers` of the provided [PeerConnection]'s configuration with
+/// the provided [List].
+Future<void> Function() _setConfiguration(
+  Object conn,
+  Object iceServers,
+  bool isForceRelayed,
+) {
+  conn as PeerConnection;
+  var servers = iceServers as List<dynamic>;
+  var iceType = isForceRelayed ? IceTransportType.relay : IceTransportType.all;
+  return () => conn.setConfiguration(
+    iceType,
+    servers.map((e) => e as IceServer).toList(),
+  );
+}
+
 /// Sets the provided [f] to the [PeerConnection.onTrack] callback.
 void _onTrack(Object conn, Object f) {
   conn as PeerConnection;
@@ -21,6 +21,7 @@ Object Function(Object, Pointer<Utf8>)? _getTransceiverByMid;
 Object Function(Object, Object)? _addIceCandidate;
 void Function(Object, Object)? _onIceConnectionStateChange;
 Object Function(Object, bool)? _newPeer;
+Object Function(Object, Object, bool)? _setConfiguration;
 Object Function(Object, int, Object)? _addTransceiver;
 Object Function(Object)? _createOffer;
 Object Function(Object)? _createAnswer;
@@ -42,6 +43,7 @@ _ErrorSetterFnDart? _peer_connection__get_transceiver_by_mid__set_error;
 _ErrorSetterFnDart? _peer_connection__add_ice_candidate__set_error;
 _ErrorSetterFnDart? _peer_connection__on_ice_connection_state_change__set_error;
 _ErrorSetterFnDart? _peer_connection__new_peer__set_error;
+_ErrorSetterFnDart? _peer_connection__set_configuration__set_error;
 _ErrorSetterFnDart? _peer_connection__add_transceiver__set_error;
 _ErrorSetterFnDart? _peer_connection__create_offer__set_error;
 _ErrorSetterFnDart? _peer_connection__create_answer__set_error;
@@ -65,6 +67,7 @@ void registerFunction(
   required Object Function(Object, Object) addIceCandidate,
   required void Function(Object, Object) onIceConnectionStateChange,
   required Object Function(Object, bool) newPeer,
+  required Object Function(Object, Object, bool) setConfiguration,
   required Object Function(Object, int, Object) addTransceiver,
   required Object Function(Object) createOffer,
   required Object Function(Object) createAnswer,
@@ -88,6 +91,7 @@ void registerFunction(
   _addIceCandidate = addIceCandidate;
   _onIceConnectionStateChange = onIceConnectionStateChange;
   _newPeer = newPeer;
+  _setConfiguration = setConfiguration;
   _addTransceiver = addTransceiver;
   _createOffer = createOffer;
   _createAnswer = createAnswer;
@@ -151,6 +155,10 @@ void registerFunction(
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
         'peer_connection__new_peer__set_error',
       );
+  _peer_connection__set_configuration__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'peer_connection__set_configuration__set_error',
+      );
   _peer_connection__add_transceiver__set_error = dl
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
         'peer_connection__add_transceiver__set_error',
@@ -210,6 +218,8 @@ void registerFunction(
   );
   Pointer<NativeFunction<Handle Function(Handle, Bool)>> newPeer_native =
       Pointer.fromFunction(_newPeerProxy);
+  Pointer<NativeFunction<Handle Function(Handle, Handle, Bool)>>
+  setConfiguration_native = Pointer.fromFunction(_setConfigurationProxy);
   Pointer<NativeFunction<Handle Function(Handle, Int64, Handle)>>
   addTransceiver_native = Pointer.fromFunction(_addTransceiverProxy);
   Pointer<NativeFunction<Handle Function(Handle)>> createOffer_native =
@@ -247,6 +257,7 @@ void registerFunction(
       Pointer,
       Pointer,
       Pointer,
+      Pointer,
     ),
     void Function(
       Pointer,
@@ -269,6 +280,7 @@ void registerFunction(
       Pointer,
       Pointer,
       Pointer,
+      Pointer,
     )
   >('register_peer_connection')(
     iceConnectionState_native,
@@ -285,6 +297,7 @@ void registerFunction(
     addIceCandidate_native,
     onIceConnectionStateChange_native,
     newPeer_native,
+    setConfiguration_native,
     addTransceiver_native,
     createOffer_native,
     createAnswer_native,
@@ -420,6 +433,15 @@ Object _newPeerProxy(Object arg0, bool arg1) {
   }
 }
 
+Object _setConfigurationProxy(Object arg0, Object arg1, bool arg2) {
+  try {
+    return _setConfiguration!(arg0, arg1, arg2);
+  } catch (e) {
+    _peer_connection__set_configuration__set_error!(e);
+    return 0;
+  }
+}
+
 Object _addTransceiverProxy(Object arg0, int arg1, Object arg2) {
   try {
     return _addTransceiver!(arg0, arg1, arg2);
@@ -0,0 +1,181 @@
+import 'dart:async';
+import 'dart:convert';
+import 'dart:ffi';
+import 'dart:io';
+
+import 'package:ffi/ffi.dart';
+
+import 'package:medea_jason/src/native/ffi/native_string.dart';
+import 'sse_transport.g.dart' as bridge;
+import 'transport.dart' show parseHeaders;
+
+/// Registers functions allowing Rust to operate [SseConnection]s.
+void registerFunctions(DynamicLibrary dl) {
+  bridge.registerFunction(dl, connect: _connect, send: _send, close: _close);
+}
+
+/// Connection with a server receiving messages as [Server-Sent Events][1] and
+/// sending them via separate HTTP `POST` requests.
+///
+/// [1]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+class SseConnection {
+  /// [HttpClient] performing all the requests of this [SseConnection].
+  final HttpClient _client;
+
+  /// URL of the [Server-Sent Events][1] stream.
+  ///
+  /// [1]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+  final Uri _url;
+
+  /// ID of the session assigned by the server.
+  final String _sessionId;
+
+  /// Subscription to the [Server-Sent Events][1] stream.
+  ///
+  /// [1]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+  final StreamSubscription _sub;
+
+  /// Callback notifying Rust about this [SseConnection] being lost.
+  final void Function() _onLost;
+
+  /// Last scheduled `POST` request, used to send messages in order.
+  Future<void> _queue = Future.value();
+
+  SseConnection(
+    this._client,
+    this._url,
+    this._sessionId,
+    this._sub,
+    this._onLost,
+  );
+
+  /// Sends the provided [message] to the server, after all the previously
+  /// sent ones.
+  ///
+  /// Considers this [SseConnection] lost if the server doesn't accept it.
+  void send(String message) {
+    _queue = _queue.then((_) => _post(_sessionId, message)).catchError((_) {
+      _sub.cancel();
+      _onLost();
+    });
+  }
+
+  /// Closes this [SseConnection] notifying the server with the provided
+  /// [reason].
+  void close(String reason) {
+    _sub.cancel();
+    _queue = _queue
+        .then((_) => _post('$_sessionId/close', reason))
+        .catchError((_) {})
+        .whenComplete(() => _client.close(force: true));
+  }
+
+  /// Sends an HTTP `POST` request with the provided [body] to the provided
+  /// [path] relative to the [_url].
+  Future<void> _post(String path, String body) async {
+    var base = _url.path.endsWith('/') ? _url.path : '${_url.path}/';
+    var req = await _client.postUrl(_url.replace(path: '$base$path'));
+    req.headers.contentType = ContentType.text;
+    req.write(body);
+    var resp = await req.close();
+    await resp.drain();
+    if (resp.statusCode >= 300) {
+      throw HttpException('Unexpected status ${resp.statusCode}');
+    }
+  }
+}
+
+/// Opens a [Server-Sent Events][1] stream from the provided [addr] sending the
+/// provided newline-separated [headers], and returns an [SseConnection] once
+/// the server assigns a session ID to it.
+///
+/// Passes data of unnamed events to the given [onMessage] callback, and data
+/// of a `close` event (or an empty string, if the stream is lost) to the given
+/// [onClose] callback.
+///
+/// [1]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+Future<SseConnection> Function() _connect(
+  Pointer<Utf8> addr,
+  Pointer<Utf8> headers,
+  Object onMessage,
+  Object onClose,
+) {
+  onMessage as Function;
+  onClose as Function;
+  return () async {
+    var url = Uri.parse(addr.nativeStringToDartString());
+    var client = HttpClient();
+    var req = await client.getUrl(url);
+    req.headers.set(HttpHeaders.acceptHeader, 'text/event-stream');
+    parseHeaders(headers).forEach(req.headers.set);
+    var resp = await req.close();
+    if (resp.statusCode != HttpStatus.ok) {
+      client.close(force: true);
+      throw HttpException('Unexpected status ${resp.statusCode}', uri: url);
+    }
+
+    var session = Completer<String>();
+    var closed = false;
+    String? event;
+    var data = <String>[];
+
+    void finish() {
+      if (!session.isCompleted) {
+        session.completeError(HttpException('SSE stream ended', uri: url));
+      } else if (!closed) {
+        closed = true;
+        onClose('');
+      }
+    }
+
+    var sub = resp
+        .transform(utf8.decoder)
+        .transform(const LineSplitter())
+        .listen(
+          (line) {
+            if (line.isEmpty) {
+              var payload = data.join('\n');
+              switch (event) {
+                case 'session':
+                  session.complete(payload);
+                case 'close':
+                  closed = true;
+                  onClose(payload);
+                case null || 'message':
+                  onMessage(payload);
+              }
+              event = null;
+              data.clear();
+            } else if (line.startsWith('event:')) {
+              event = line.substring(6).trim();
+            } else if (line.startsWith('data:')) {
+              var value = line.substring(5);
+              data.add(value.startsWith(' ') ? value.substring(1) : value);
+            }
+          },
+          onDone: finish,
+          onError: (_) => finish(),
+          cancelOnError: true,
+        );
+
+    try {
+      return SseConnection(client, url, await session.future, sub, finish);
+    } catch (_) {
+      await sub.cancel();
+      client.close(force: true);
+      rethrow;
+    }
+  };
+}
+
+/// Sends the provided [message] via the provided [SseConnection].
+void _send(Object conn, Pointer<Utf8> message) {
+  conn as SseConnection;
+  conn.send(message.nativeStringToDartString());
+}
+
+/// Closes the provided [SseConnection] with the provided [closeMsg].
+void _close(Object conn, Pointer<Utf8> closeMsg) {
+  conn as SseConnection;
+  conn.close(closeMsg.nativeStringToDartString());
+}
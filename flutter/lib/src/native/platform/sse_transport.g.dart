@@ -0,0 +1,89 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+
+import 'package:medea_jason/src/native/ffi/foreign_value.dart';
+
+typedef _ErrorSetterFnC = Void Function(Handle);
+typedef _ErrorSetterFnDart = void Function(Object);
+
+Object Function(Pointer<Utf8>, Pointer<Utf8>, Object, Object)? _connect;
+void Function(Object, Pointer<Utf8>)? _send;
+void Function(Object, Pointer<Utf8>)? _close;
+
+_ErrorSetterFnDart? _sse_transport__connect__set_error;
+_ErrorSetterFnDart? _sse_transport__send__set_error;
+_ErrorSetterFnDart? _sse_transport__close__set_error;
+
+void registerFunction(
+  DynamicLibrary dl, {
+  required Object Function(Pointer<Utf8>, Pointer<Utf8>, Object, Object)
+  connect,
+  required void Function(Object, Pointer<Utf8>) send,
+  required void Function(Object, Pointer<Utf8>) close,
+}) {
+  _connect = connect;
+  _send = send;
+  _close = close;
+
+  _sse_transport__connect__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'sse_transport__connect__set_error',
+      );
+  _sse_transport__send__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'sse_transport__send__set_error',
+      );
+  _sse_transport__close__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'sse_transport__close__set_error',
+      );
+
+  Pointer<
+    NativeFunction<
+      Handle Function(Pointer<Utf8>, Pointer<Utf8>, Handle, Handle)
+    >
+  >
+  connect_native = Pointer.fromFunction(_connectProxy);
+  Pointer<NativeFunction<Void Function(Handle, Pointer<Utf8>)>> send_native =
+      Pointer.fromFunction(_sendProxy);
+  Pointer<NativeFunction<Void Function(Handle, Pointer<Utf8>)>> close_native =
+      Pointer.fromFunction(_closeProxy);
+
+  dl.lookupFunction<
+    Void Function(Pointer, Pointer, Pointer),
+    void Function(Pointer, Pointer, Pointer)
+  >('register_sse_transport')(connect_native, send_native, close_native);
+}
+
+Object _connectProxy(
+  Pointer<Utf8> arg0,
+  Pointer<Utf8> arg1,
+  Object arg2,
+  Object arg3,
+) {
+  try {
+    return _connect!(arg0, arg1, arg2, arg3);
+  } catch (e) {
+    _sse_transport__connect__set_error!(e);
+    return 0;
+  }
+}
+
+void _sendProxy(Object arg0, Pointer<Utf8> arg1) {
+  try {
+    return _send!(arg0, arg1);
+  } catch (e) {
+    _sse_transport__send__set_error!(e);
+    return;
+  }
+}
+
+void _closeProxy(Object arg0, Pointer<Utf8> arg1) {
+  try {
+    return _close!(arg0, arg1);
+  } catch (e) {
+    _sse_transport__close__set_error!(e);
+    return;
+  }
+}
@@ -2,6 +2,7 @@ import 'dart:async';
 import 'dart:collection';
 import 'dart:ffi';
 import 'dart:io';
+import 'dart:typed_data';
 
 import 'package:ffi/ffi.dart';
 
@@ -17,7 +18,11 @@ void registerFunctions(DynamicLibrary dl) {
     bridge.registerFunction(
       dl,
       connect: MockWebSocket.connect,
+      protocol: _protocol,
       send: _send,
+      sendBinary: _sendBinary,
+      binaryMessageLen: _binaryMessageLen,
+      copyBinaryMessage: _copyBinaryMessage,
       close: _close,
       closeCode: _closeCode,
       closeReason: _closeReason,
@@ -26,7 +31,11 @@ void registerFunctions(DynamicLibrary dl) {
     bridge.registerFunction(
       dl,
       connect: _connect,
+      protocol: _protocol,
       send: _send,
+      sendBinary: _sendBinary,
+      binaryMessageLen: _binaryMessageLen,
+      copyBinaryMessage: _copyBinaryMessage,
       close: _close,
       closeCode: _closeCode,
       closeReason: _closeReason,
@@ -62,24 +71,36 @@ class MockWebSocket {
   /// Stored [WebSocket]s for outside access.
   static final _allWebSocket = HashMap<String, WsEntry>();
 
-  /// Connects to the provided [addr] and returns a [WebSocket] for it.
+  /// Connects to the provided [addr] offering the provided comma-separated
+  /// [protocols] and sending the provided newline-separated [headers], and
+  /// returns a [WebSocket] for it.
   ///
   /// Subscribes to the created [WebSocket] messages with the specified
-  /// [onMessage] and [onClose] callbacks.
+  /// [onMessage], [onBinaryMessage] and [onClose] callbacks.
   static Future<WebSocket> Function() connect(
     Pointer<Utf8> addr,
+    Pointer<Utf8> protocols,
+    Pointer<Utf8> headers,
     Object onMessage,
+    Object onBinaryMessage,
     Object onClose,
   ) {
     onMessage as Function;
+    onBinaryMessage as Function;
     onClose as void Function(CloseFrame);
 
     return () async {
-      var ws = await WebSocket.connect(addr.nativeStringToDartString());
+      var ws = await WebSocket.connect(
+        addr.nativeStringToDartString(),
+        protocols: _splitProtocols(protocols),
+        headers: parseHeaders(headers),
+      );
       var sub = ws.listen(
         (msg) {
           if (msg is String) {
             onMessage(msg);
+          } else if (msg is List<int>) {
+            onBinaryMessage(Uint8List.fromList(msg));
           }
         },
         onDone: () {
@@ -111,23 +132,35 @@ class MockWebSocket {
   }
 }
 
-/// Connects to the provided [addr] and returns [WebSocket] for it.
+/// Connects to the provided [addr] offering the provided comma-separated
+/// [protocols] and sending the provided newline-separated [headers], and
+/// returns [WebSocket] for it.
 ///
-/// Subscribes to the created [WebSocket] messages with the given [onMessage]
-/// and [onClose] callbacks.
+/// Subscribes to the created [WebSocket] messages with the given [onMessage],
+/// [onBinaryMessage] and [onClose] callbacks.
 Future<WebSocket> Function() _connect(
   Pointer<Utf8> addr,
+  Pointer<Utf8> protocols,
+  Pointer<Utf8> headers,
   Object onMessage,
+  Object onBinaryMessage,
   Object onClose,
 ) {
   onMessage as Function;
+  onBinaryMessage as Function;
   onClose as Function;
   return () async {
-    var ws = await WebSocket.connect(addr.nativeStringToDartString());
+    var ws = await WebSocket.connect(
+      addr.nativeStringToDartString(),
+      protocols: _splitProtocols(protocols),
+      headers: parseHeaders(headers),
+    );
     ws.listen(
       (msg) {
         if (msg is String) {
           onMessage(msg);
+        } else if (msg is List<int>) {
+          onBinaryMessage(Uint8List.fromList(msg));
         }
       },
       onDone: () {
@@ -139,12 +172,59 @@ Future<WebSocket> Function() _connect(
   };
 }
 
+/// Splits the provided comma-separated [protocols] into a [List].
+List<String> _splitProtocols(Pointer<Utf8> protocols) {
+  return protocols
+      .nativeStringToDartString()
+      .split(',')
+      .where((p) => p.isNotEmpty)
+      .toList();
+}
+
+/// Parses the provided newline-separated `Name: value` [headers] into a [Map].
+Map<String, dynamic> parseHeaders(Pointer<Utf8> headers) {
+  var parsed = <String, dynamic>{};
+  for (var line in headers.nativeStringToDartString().split('\n')) {
+    var idx = line.indexOf(':');
+    if (idx > 0) {
+      parsed[line.substring(0, idx).trim()] = line.substring(idx + 1).trim();
+    }
+  }
+  return parsed;
+}
+
+/// Returns the protocol selected by the server for the provided [WebSocket].
+Pointer<Utf8> _protocol(Object ws) {
+  ws as WebSocket;
+  return (ws.protocol ?? '').toNativeUtf8();
+}
+
 /// Sends the provided [message] to the provided [WebSocket].
 void _send(Object ws, Pointer<Utf8> message) {
   ws as WebSocket;
   ws.add(message.nativeStringToDartString());
 }
 
+/// Sends the provided binary [message] of the provided [len] to the provided
+/// [WebSocket].
+void _sendBinary(Object ws, Pointer message, int len) {
+  ws as WebSocket;
+  ws.add(Uint8List.fromList(message.cast<Uint8>().asTypedList(len)));
+}
+
+/// Returns the length of the provided binary [message].
+int _binaryMessageLen(Object message) {
+  message as Uint8List;
+  return message.length;
+}
+
+/// Copies the provided binary [message] into the provided [dst] buffer of the
+/// provided [len].
+void _copyBinaryMessage(Object message, Pointer dst, int len) {
+  message as Uint8List;
+  dst.cast<Uint8>().asTypedList(len).setAll(0, message.take(len));
+}
+
 /// Closes the provided [WebSocket] connection with the provided
 /// [closeCode] and [closeMsg].
 void _close(Object ws, int closeCode, Pointer<Utf8> closeMsg) {
@@ -7,28 +7,60 @@ import 'package:medea_jason/src/native/ffi/foreign_value.dart';
 typedef _ErrorSetterFnC = Void Function(Handle);
 typedef _ErrorSetterFnDart = void Function(Object);
 
-Object Function(Pointer<Utf8>, Object, Object)? _connect;
+Object Function(
+  Pointer<Utf8>,
+  Pointer<Utf8>,
+  Pointer<Utf8>,
+  Object,
+  Object,
+  Object,
+)?
+_connect;
+Pointer<Utf8> Function(Object)? _protocol;
 void Function(Object, Pointer<Utf8>)? _send;
+void Function(Object, Pointer, int)? _sendBinary;
+int Function(Object)? _binaryMessageLen;
+void Function(Object, Pointer, int)? _copyBinaryMessage;
 void Function(Object, int, Pointer<Utf8>)? _close;
 int Function(Object)? _closeCode;
 Pointer<Utf8> Function(Object)? _closeReason;
 
 _ErrorSetterFnDart? _transport__connect__set_error;
+_ErrorSetterFnDart? _transport__protocol__set_error;
 _ErrorSetterFnDart? _transport__send__set_error;
+_ErrorSetterFnDart? _transport__send_binary__set_error;
+_ErrorSetterFnDart? _transport__binary_message_len__set_error;
+_ErrorSetterFnDart? _transport__copy_binary_message__set_error;
 _ErrorSetterFnDart? _transport__close__set_error;
 _ErrorSetterFnDart? _transport__close_code__set_error;
 _ErrorSetterFnDart? _transport__close_reason__set_error;
 
 void registerFunction(
   DynamicLibrary dl, {
-  required Object Function(Pointer<Utf8>, Object, Object) connect,
+  required Object Function(
+    Pointer<Utf8>,
+    Pointer<Utf8>,
+    Pointer<Utf8>,
+    Object,
+    Object,
+    Object,
+  )
+  connect,
+  required Pointer<Utf8> Function(Object) protocol,
   required void Function(Object, Pointer<Utf8>) send,
+  required void Function(Object, Pointer, int) sendBinary,
+  required int Function(Object) binaryMessageLen,
+  required void Function(Object, Pointer, int) copyBinaryMessage,
   required void Function(Object, int, Pointer<Utf8>) close,
   required int Function(Object) closeCode,
   required Pointer<Utf8> Function(Object) closeReason,
 }) {
   _connect = connect;
+  _protocol = protocol;
   _send = send;
+  _sendBinary = sendBinary;
+  _binaryMessageLen = binaryMessageLen;
+  _copyBinaryMessage = copyBinaryMessage;
   _close = close;
   _closeCode = closeCode;
   _closeReason = closeReason;
@@ -37,10 +69,26 @@ void registerFunction(
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
         'transport__connect__set_error',
       );
+  _transport__protocol__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'transport__protocol__set_error',
+      );
   _transport__send__set_error = dl
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
         'transport__send__set_error',
       );
+  _transport__send_binary__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'transport__send_binary__set_error',
+      );
+  _transport__binary_message_len__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'transport__binary_message_len__set_error',
+      );
+  _transport__copy_binary_message__set_error = dl
+      .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
+        'transport__copy_binary_message__set_error',
+      );
   _transport__close__set_error = dl
       .lookupFunction<_ErrorSetterFnC, _ErrorSetterFnDart>(
         'transport__close__set_error',
@@ -54,10 +102,29 @@ void registerFunction(
         'transport__close_reason__set_error',
       );
 
-  Pointer<NativeFunction<Handle Function(Pointer<Utf8>, Handle, Handle)>>
+  Pointer<
+    NativeFunction<
+      Handle Function(
+        Pointer<Utf8>,
+        Pointer<Utf8>,
+        Pointer<Utf8>,
+        Handle,
+        Handle,
+        Handle,
+      )
+    >
+  >
   connect_native = Pointer.fromFunction(_connectProxy);
+  Pointer<NativeFunction<Pointer<Utf8> Function(Handle)>> protocol_native =
+      Pointer.fromFunction(_protocolProxy);
   Pointer<NativeFunction<Void Function(Handle, Pointer<Utf8>)>> send_native =
       Pointer.fromFunction(_sendProxy);
+  Pointer<NativeFunction<Void Function(Handle, Pointer, Uint32)>>
+  sendBinary_native = Pointer.fromFunction(_sendBinaryProxy);
+  Pointer<NativeFunction<Uint32 Function(Handle)>> binaryMessageLen_native =
+      Pointer.fromFunction(_binaryMessageLenProxy, 0);
+  Pointer<NativeFunction<Void Function(Handle, Pointer, Uint32)>>
+  copyBinaryMessage_native = Pointer.fromFunction(_copyBinaryMessageProxy);
   Pointer<NativeFunction<Void Function(Handle, Int32, Pointer<Utf8>)>>
   close_native = Pointer.fromFunction(_closeProxy);
   Pointer<NativeFunction<Int32 Function(Handle)>> closeCode_native =
@@ -66,26 +133,66 @@ void registerFunction(
       Pointer.fromFunction(_closeReasonProxy);
 
   dl.lookupFunction<
-    Void Function(Pointer, Pointer, Pointer, Pointer, Pointer),
-    void Function(Pointer, Pointer, Pointer, Pointer, Pointer)
+    Void Function(
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+    ),
+    void Function(
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+      Pointer,
+    )
   >('register_transport')(
     connect_native,
+    protocol_native,
     send_native,
+    sendBinary_native,
+    binaryMessageLen_native,
+    copyBinaryMessage_native,
     close_native,
     closeCode_native,
     closeReason_native,
   );
 }
 
-Object _connectProxy(Pointer<Utf8> arg0, Object arg1, Object arg2) {
+Object _connectProxy(
+  Pointer<Utf8> arg0,
+  Pointer<Utf8> arg1,
+  Pointer<Utf8> arg2,
+  Object arg3,
+  Object arg4,
+  Object arg5,
+) {
   try {
-    return _connect!(arg0, arg1, arg2);
+    return _connect!(arg0, arg1, arg2, arg3, arg4, arg5);
   } catch (e) {
     _transport__connect__set_error!(e);
     return 0;
   }
 }
 
+Pointer<Utf8> _protocolProxy(Object arg0) {
+  try {
+    return _protocol!(arg0);
+  } catch (e) {
+    _transport__protocol__set_error!(e);
+    return Pointer.fromAddress(0);
+  }
+}
+
 void _sendProxy(Object arg0, Pointer<Utf8> arg1) {
   try {
     return _send!(arg0, arg1);
@@ -95,6 +202,33 @@ void _sendProxy(Object arg0, Pointer<Utf8> arg1) {
   }
 }
 
+void _sendBinaryProxy(Object arg0, Pointer arg1, int arg2) {
+  try {
+    return _sendBinary!(arg0, arg1, arg2);
+  } catch (e) {
+    _transport__send_binary__set_error!(e);
+    return;
+  }
+}
+
+int _binaryMessageLenProxy(Object arg0) {
+  try {
+    return _binaryMessageLen!(arg0);
+  } catch (e) {
+    _transport__binary_message_len__set_error!(e);
+    return 0;
+  }
+}
+
+void _copyBinaryMessageProxy(Object arg0, Pointer arg1, int arg2) {
+  try {
+    return _copyBinaryMessage!(arg0, arg1, arg2);
+  } catch (e) {
+    _transport__copy_binary_message__set_error!(e);
+    return;
+  }
+}
+
 void _closeProxy(Object arg0, int arg1, Pointer<Utf8> arg2) {
   try {
     return _close!(arg0, arg1, arg2);
@@ -1,9 +1,13 @@
+import 'dart:ffi';
+
 import '../interface/connection_handle.dart';
 import '../interface/media_stream_settings.dart' as base_settings;
 import '../interface/media_track.dart';
 import '../interface/reconnect_handle.dart';
 import '../interface/room_close_reason.dart';
 import '../interface/room_handle.dart';
+import '../interface/room_member.dart';
+import '../interface/signalling_latency.dart';
 import '../util/move_semantic.dart';
 import '../util/rust_opaque.dart';
 import '/src/util/rust_handles_storage.dart';
@@ -13,6 +17,8 @@ import 'local_media_track.dart';
 import 'media_stream_settings.dart';
 import 'reconnect_handle.dart';
 import 'room_close_reason.dart';
+import 'room_member.dart';
+import 'signalling_latency.dart';
 
 class NativeRoomHandle implements RoomHandle {
   /// `flutter_rust_bridge` Rust opaque type backing this object.
@@ -30,6 +36,28 @@ class NativeRoomHandle implements RoomHandle {
     await (opaque.inner.join(token: token) as Future);
   }
 
+  @override
+  Future<void> joinWithCredential(
+    String url,
+    String credential, [
+    Map<String, String> headers = const {},
+  ]) async {
+    await (opaque.inner.joinWithCredential(
+          url: url,
+          credential: credential,
+          headers: headers,
+        )
+        as Future);
+  }
+
+  @override
+  Future<RoomCloseReason> leave([String? reason]) async {
+    var ptr = await (opaque.inner.leave(reason: reason) as Future) as Pointer;
+    return NativeRoomCloseReason(
+      frb.RoomCloseReason.fromPtr(ptr: ptr.address),
+    );
+  }
+
   @override
   Future<void> setLocalMediaSettings(
     base_settings.MediaStreamSettings settings,
@@ -104,6 +132,38 @@ class NativeRoomHandle implements RoomHandle {
     await (opaque.inner.disableRemoteVideo(sourceKind: kind) as Future);
   }
 
+  @override
+  List<RoomMember> members() {
+    return opaque.inner.members().map((m) => NativeRoomMember(m)).toList();
+  }
+
+  @override
+  void onMemberJoined(void Function(RoomMember) f) {
+    opaque.inner.onMemberJoined(
+      cb: (t) {
+        f(NativeRoomMember(frb.RoomMember.fromPtr(ptr: t.address)));
+      },
+    );
+  }
+
+  @override
+  void onMemberLeft(void Function(String) f) {
+    opaque.inner.onMemberLeft(
+      cb: (id) {
+        f(id);
+      },
+    );
+  }
+
+  @override
+  void onMemberUpdated(void Function(RoomMember) f) {
+    opaque.inner.onMemberUpdated(
+      cb: (t) {
+        f(NativeRoomMember(frb.RoomMember.fromPtr(ptr: t.address)));
+      },
+    );
+  }
+
   @override
   void onNewConnection(void Function(ConnectionHandle) f) {
     opaque.inner.onNewConnection(
@@ -149,6 +209,39 @@ class NativeRoomHandle implements RoomHandle {
     );
   }
 
+  @override
+  void onUnknownEvent(void Function(String) f) {
+    opaque.inner.onUnknownEvent(
+      cb: (event) {
+        f(event);
+      },
+    );
+  }
+
+  @override
+  void onSignallingLatency(void Function(SignallingLatency) f) {
+    opaque.inner.onSignallingLatency(
+      cb: (t) {
+        f(
+          NativeSignallingLatency(
+            frb.SignallingLatency.fromPtr(ptr: t.address),
+          ),
+        );
+      },
+    );
+  }
+
+  @override
+  SignallingLatency? signallingLatency() {
+    var latency = opaque.inner.signallingLatency();
+    return latency == null ? null : NativeSignallingLatency(latency);
+  }
+
+  @override
+  String? rpcTransportKind() {
+    return opaque.inner.rpcTransportKind();
+  }
+
   @moveSemantics
   @override
   void free() {
@@ -0,0 +1,24 @@
+import '../interface/room_member.dart';
+import 'ffi/frb/frb.dart' as frb;
+
+class NativeRoomMember implements RoomMember {
+  /// Rust `flutter_rust_bridge` api representation.
+  final frb.RoomMember _member;
+
+  /// Constructs a new [RoomMember] backed by the Rust struct behind the
+  /// provided [frb.RoomMember].
+  NativeRoomMember(this._member);
+
+  @override
+  String id() {
+    return _member.id;
+  }
+
+  @override
+  Map<String, String> metadata() {
+    return _member.metadata;
+  }
+
+  @override
+  void free() {}
+}
@@ -0,0 +1,34 @@
+import '../interface/signalling_latency.dart';
+import 'ffi/frb/frb.dart' as frb;
+
+class NativeSignallingLatency implements SignallingLatency {
+  /// Rust `flutter_rust_bridge` api representation.
+  final frb.SignallingLatency _latency;
+
+  /// Constructs a new [SignallingLatency] backed by the Rust struct behind the
+  /// provided [frb.SignallingLatency].
+  NativeSignallingLatency(this._latency);
+
+  @override
+  double rttMs() {
+    return _latency.rttMs;
+  }
+
+  @override
+  double smoothedRttMs() {
+    return _latency.smoothedRttMs;
+  }
+
+  @override
+  double jitterMs() {
+    return _latency.jitterMs;
+  }
+
+  @override
+  int samples() {
+    return _latency.samples;
+  }
+
+  @override
+  void free() {}
+}
@@ -0,0 +1,87 @@
+import 'dart:js_interop';
+
+import '../interface/device_capabilities.dart';
+import '../interface/enums.dart' show FacingMode;
+import '../util/move_semantic.dart';
+import 'exceptions.dart';
+import 'jason_wasm.dart' as wasm;
+
+class WebDeviceCapabilities implements DeviceCapabilities {
+  late wasm.DeviceCapabilities obj;
+
+  WebDeviceCapabilities(this.obj);
+
+  @override
+  IntRange? width() {
+    return fallibleFunction(() => _intRange(obj.width()));
+  }
+
+  @override
+  IntRange? height() {
+    return fallibleFunction(() => _intRange(obj.height()));
+  }
+
+  @override
+  FloatRange? frameRate() {
+    return fallibleFunction(() {
+      var range = obj.frame_rate();
+      if (range == null) {
+        return null;
+      }
+      var res = FloatRange(range.min, range.max);
+      range.free();
+      return res;
+    });
+  }
+
+  @override
+  List<FacingMode> facingModes() {
+    return fallibleFunction(
+      () => obj.facing_modes().toDart
+          .map((mode) => FacingMode.values[mode.toDartInt])
+          .toList(),
+    );
+  }
+
+  @override
+  IntRange? channelCount() {
+    return fallibleFunction(() => _intRange(obj.channel_count()));
+  }
+
+  @override
+  List<bool> echoCancellation() {
+    return fallibleFunction(
+      () => obj.echo_cancellation().toDart.map((v) => v.toDart).toList(),
+    );
+  }
+
+  @override
+  List<bool> autoGainControl() {
+    return fallibleFunction(
+      () => obj.auto_gain_control().toDart.map((v) => v.toDart).toList(),
+    );
+  }
+
+  @override
+  List<bool> noiseSuppression() {
+    return fallibleFunction(
+      () => obj.noise_suppression().toDart.map((v) => v.toDart).toList(),
+    );
+  }
+
+  @moveSemantics
+  @override
+  void free() {
+    obj.free();
+  }
+}
+
+/// Converts the provided [wasm.IntRange] into an [IntRange], freeing it.
+IntRange? _intRange(wasm.IntRange? range) {
+  if (range == null) {
+    return null;
+  }
+  var res = IntRange(range.min, range.max);
+  range.free();
+  return res;
+}
@@ -55,6 +55,8 @@ dynamic convertException(dynamic e) {
     var message = e.message();
     e.free();
     return StateError(message);
+  } else if (name == 'DeviceNotFoundException') {
+    return WebDeviceNotFoundException(e as wasm.DeviceNotFoundException);
   } else if (name == 'EnumerateDevicesException') {
     return WebEnumerateDevicesException(e as wasm.EnumerateDevicesException);
   } else if (name == 'InternalException') {
@@ -120,6 +122,30 @@ class WebEnumerateDevicesException extends EnumerateDevicesException {
   }
 }
 
+/// Exception thrown when no media input device with the requested ID exists.
+class WebDeviceNotFoundException extends DeviceNotFoundException {
+  late String _deviceId;
+  late String _trace;
+
+  WebDeviceNotFoundException(wasm.DeviceNotFoundException e) {
+    _deviceId = e.device_id();
+    _trace = e.trace();
+    e.free();
+  }
+
+  /// Returns ID of the media input device which wasn't found.
+  @override
+  String deviceId() {
+    return _deviceId;
+  }
+
+  /// Returns stacktrace of this [DeviceNotFoundException].
+  @override
+  String trace() {
+    return _trace;
+  }
+}
+
 /// Jason's internal exception.
 ///
 /// This is either a programmatic error or some unexpected platform component
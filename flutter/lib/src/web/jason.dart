@@ -3,6 +3,7 @@ import 'dart:js_interop';
 import '../interface/enums.dart' as base;
 import '../interface/jason.dart' as base;
 import '../interface/media_manager.dart';
+import '../interface/network_state.dart';
 import '../interface/room_handle.dart';
 import '../util/move_semantic.dart';
 import 'exceptions.dart';
@@ -50,6 +51,43 @@ class Jason extends base.Jason {
     await fallibleFuture(obj.network_changed().toDart);
   }
 
+  @override
+  void enableSseFallback() {
+    fallibleFunction(() => obj.enable_sse_fallback());
+  }
+
+  @override
+  void shareRpcConnection() {
+    fallibleFunction(() => obj.share_rpc_connection());
+  }
+
+  @override
+  void enableRpcRecording() {
+    fallibleFunction(() => obj.enable_rpc_recording());
+  }
+
+  @override
+  String? exportRpcRecording() {
+    return fallibleFunction(() => obj.export_rpc_recording());
+  }
+
+  @override
+  void enableNetworkMonitor() {
+    fallibleFunction(() => obj.enable_network_monitor());
+  }
+
+  @override
+  void setNetworkOnline(bool online) {
+    fallibleFunction(() => obj.set_network_online(online));
+  }
+
+  @override
+  void onNetworkStateChange(void Function(NetworkState) f) {
+    void fn(JSAny? i) => f(NetworkState.values[(i as JSNumber).toDartInt]);
+
+    fallibleFunction(() => obj.on_network_state_change(fn.toJS));
+  }
+
   @override
   @moveSemantics
   void free() {
@@ -126,6 +126,79 @@ extension type EnumerateDevicesException._(JSObject _) implements JSObject {
   external String trace();
 }
 
+@JS()
+extension type DeviceCapabilities._(JSObject _) implements JSObject {
+  external void free();
+  external IntRange? width();
+  external IntRange? height();
+  external FloatRange? frame_rate();
+  external JSArray<JSNumber> facing_modes();
+  external IntRange? channel_count();
+  external JSArray<JSBoolean> echo_cancellation();
+  external JSArray<JSBoolean> auto_gain_control();
+  external JSArray<JSBoolean> noise_suppression();
+}
+
+@JS()
+extension type DeviceDiagnostics._(JSObject _) implements JSObject {
+  external void free();
+  external String device_id();
+  external String label();
+  external num kind();
+  external String? error();
+  external int? width();
+  external int? height();
+  external double? frame_rate();
+  external int? audio_level();
+}
+
+@JS()
+extension type DiagnosticsReport._(JSObject _) implements JSObject {
+  external void free();
+  external JSArray<JSAny> devices();
+  external IceDiagnostics ice();
+  external ThroughputDiagnostics? throughput();
+}
+
+@JS()
+extension type DeviceNotFoundException._(JSObject _) implements JSObject {
+  external void free();
+  external String device_id();
+  external String trace();
+}
+
+@JS()
+extension type FloatRange._(JSObject _) implements JSObject {
+  external void free();
+  external double get min;
+  external double get max;
+}
+
+@JS()
+extension type IceDiagnostics._(JSObject _) implements JSObject {
+  external void free();
+  external int host_candidates();
+  external int srflx_candidates();
+  external int relay_candidates();
+  external bool connected();
+  external JSArray<JSString> errors();
+}
+
+@JS()
+extension type IceServer._(JSObject _) implements JSObject {
+  external factory IceServer(JSArray<JSString> urls);
+  external void free();
+  external void username(String username);
+  external void credential(String credential);
+}
+
+@JS()
+extension type IntRange._(JSObject _) implements JSObject {
+  external void free();
+  external int get min;
+  external int get max;
+}
+
 @JS()
 extension type FormatException._(JSObject _) implements JSObject {
   external void free();
@@ -157,6 +230,13 @@ extension type Jason._(JSObject _) implements JSObject {
   external MediaManagerHandle media_manager();
   external void close_room(RoomHandle room_to_delete);
   external JSPromise<JSAny?> network_changed();
+  external void enable_sse_fallback();
+  external void share_rpc_connection();
+  external void enable_rpc_recording();
+  external String? export_rpc_recording();
+  external void enable_network_monitor();
+  external void set_network_online(bool online);
+  external void on_network_state_change(JSFunction cb);
   external void dispose();
 }
 
@@ -180,12 +260,27 @@ extension type LocalMediaTrack._(JSObject _) implements JSObject {
   external bool is_on_audio_level_available();
   external void on_audio_level_changed(JSFunction cb);
   external bool is_audio_processing_available();
+  external JSPromise<JSAny?> apply_constraints(
+    DeviceVideoTrackConstraints constraints,
+  );
   external JSPromise<JSAny?> set_noise_suppression_enabled(bool enabled);
   external JSPromise<JSAny?> set_echo_cancellation_enabled(bool enabled);
   external JSPromise<JSAny?> set_auto_gain_control_enabled(bool enabled);
   external JSPromise<JSBoolean> is_noise_suppression_enabled();
   external JSPromise<JSBoolean> is_auto_gain_control_enabled();
   external JSPromise<JSBoolean> is_echo_cancellation_enabled();
+  external bool is_zoom_available();
+  external JSPromise<JSAny?> set_zoom(double zoom);
+  external bool is_torch_available();
+  external JSPromise<JSAny?> set_torch_enabled(bool enabled);
+  external bool is_focus_mode_available();
+  external JSPromise<JSAny?> set_focus_mode(num mode);
+  external bool is_focus_distance_available();
+  external JSPromise<JSAny?> set_focus_distance(double distance);
+  external bool is_exposure_compensation_available();
+  external JSPromise<JSAny?> set_exposure_compensation(double compensation);
+  external bool is_white_balance_mode_available();
+  external JSPromise<JSAny?> set_white_balance_mode(num mode);
 }
 
 @JS()
@@ -193,6 +288,12 @@ extension type MediaManagerHandle._(JSObject _) implements JSObject {
   external void on_device_change(JSFunction cb);
   external void free();
   external JSPromise<JSArray<JSAny>> enumerate_devices();
+  external JSPromise<JSNumber> permission_state(num kind);
+  external JSPromise<JSAny?> on_permission_change(num kind, JSFunction cb);
+  external JSPromise<DeviceCapabilities> device_capabilities(String deviceId);
+  external JSPromise<DiagnosticsReport> run_diagnostics(
+    JSArray<IceServer> ice_servers,
+  );
   external JSPromise<JSArray<JSAny>> init_local_tracks(
     MediaStreamSettings caps,
   );
@@ -260,15 +361,44 @@ extension type RoomCloseReason._(JSObject _) implements JSObject {
   external bool is_closed_by_server();
 }
 
+@JS()
+extension type SignallingLatency._(JSObject _) implements JSObject {
+  external void free();
+  external double rtt_ms();
+  external double smoothed_rtt_ms();
+  external double jitter_ms();
+  external int samples();
+}
+
+@JS()
+extension type RoomMember._(JSObject _) implements JSObject {
+  external void free();
+  external String id();
+  external JSObject metadata();
+}
+
 @JS()
 extension type RoomHandle._(JSObject _) implements JSObject {
   external void free();
   external void on_new_connection(JSFunction cb);
   external void on_close(JSFunction cb);
+  external void on_member_joined(JSFunction cb);
+  external void on_member_left(JSFunction cb);
+  external void on_member_updated(JSFunction cb);
+  external JSArray<JSAny> members();
   external void on_local_track(JSFunction cb);
   external void on_failed_local_media(JSFunction cb);
   external void on_connection_loss(JSFunction cb);
+  external void on_unknown_event(JSFunction cb);
+  external void on_signalling_latency(JSFunction cb);
+  external SignallingLatency? signalling_latency();
+  external String? rpc_transport_kind();
   external JSPromise<JSAny?> join(String token);
+  external JSPromise<JSAny?> join_with_credential(
+    String url,
+    String credential,
+  );
+  external JSPromise<JSAny?> leave(String? reason);
   external JSPromise<JSAny?> set_local_media_settings(
     MediaStreamSettings settings,
     bool stop_first,
@@ -303,3 +433,11 @@ extension type StateError._(JSObject _) implements JSObject {
   external String message();
   external String trace();
 }
+
+@JS()
+extension type ThroughputDiagnostics._(JSObject _) implements JSObject {
+  external void free();
+  external double bytes_sent();
+  external double duration_ms();
+  external double bitrate_bps();
+}
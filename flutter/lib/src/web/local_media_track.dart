@@ -5,9 +5,11 @@ import 'dart:js_interop';
 import 'package:medea_flutter_webrtc/medea_flutter_webrtc.dart' as webrtc;
 import 'package:medea_flutter_webrtc/src/platform/web/media_stream_track.dart';
 
+import '../interface/device_video_track_constraints.dart' as base;
 import '../interface/enums.dart';
 import '../interface/media_track.dart';
 import '../util/move_semantic.dart';
+import 'device_video_track_constraints.dart';
 import 'exceptions.dart';
 import 'jason_wasm.dart' as wasm;
 
@@ -76,6 +78,77 @@ class WebLocalMediaTrack implements LocalMediaTrack {
     await fallibleFuture(obj.set_echo_cancellation_enabled(enabled).toDart);
   }
 
+  @override
+  Future<void> applyConstraints(
+    @moveSemantics base.DeviceVideoTrackConstraints constraints,
+  ) async {
+    await fallibleFuture(
+      obj
+          .apply_constraints((constraints as DeviceVideoTrackConstraints).obj)
+          .toDart,
+    );
+  }
+
+  @override
+  bool isZoomAvailable() {
+    return fallibleFunction(() => obj.is_zoom_available());
+  }
+
+  @override
+  Future<void> setZoom(double zoom) async {
+    await fallibleFuture(obj.set_zoom(zoom).toDart);
+  }
+
+  @override
+  bool isTorchAvailable() {
+    return fallibleFunction(() => obj.is_torch_available());
+  }
+
+  @override
+  Future<void> setTorchEnabled(bool enabled) async {
+    await fallibleFuture(obj.set_torch_enabled(enabled).toDart);
+  }
+
+  @override
+  bool isFocusModeAvailable() {
+    return fallibleFunction(() => obj.is_focus_mode_available());
+  }
+
+  @override
+  Future<void> setFocusMode(MeteringMode mode) async {
+    await fallibleFuture(obj.set_focus_mode(mode.index).toDart);
+  }
+
+  @override
+  bool isFocusDistanceAvailable() {
+    return fallibleFunction(() => obj.is_focus_distance_available());
+  }
+
+  @override
+  Future<void> setFocusDistance(double distance) async {
+    await fallibleFuture(obj.set_focus_distance(distance).toDart);
+  }
+
+  @override
+  bool isExposureCompensationAvailable() {
+    return fallibleFunction(() => obj.is_exposure_compensation_available());
+  }
+
+  @override
+  Future<void> setExposureCompensation(double compensation) async {
+    await fallibleFuture(obj.set_exposure_compensation(compensation).toDart);
+  }
+
+  @override
+  bool isWhiteBalanceModeAvailable() {
+    return fallibleFunction(() => obj.is_white_balance_mode_available());
+  }
+
+  @override
+  Future<void> setWhiteBalanceMode(MeteringMode mode) async {
+    await fallibleFuture(obj.set_white_balance_mode(mode.index).toDart);
+  }
+
   @override
   Future<void> setNoiseSuppressionEnabled(bool enabled) async {
     await fallibleFuture(obj.set_noise_suppression_enabled(enabled).toDart);
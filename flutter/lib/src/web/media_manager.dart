@@ -7,12 +7,17 @@ import 'package:medea_flutter_webrtc/src/platform/web/video_renderer.dart'
 
 import 'dart:js_interop';
 
+import '../interface/device_capabilities.dart';
+import '../interface/diagnostics.dart';
+import '../interface/enums.dart' show MediaKind, PermissionKind;
 import '../interface/media_device_details.dart';
 import '../interface/media_display_details.dart';
 import '../interface/media_manager.dart';
 import '../interface/media_stream_settings.dart' as base_settings;
 import '../interface/media_track.dart';
+import '../interface/permission_state.dart';
 import '../util/move_semantic.dart';
+import 'device_capabilities.dart';
 import 'exceptions.dart';
 import 'jason_wasm.dart' as wasm;
 import 'local_media_track.dart';
@@ -45,6 +50,12 @@ class WebMediaManagerHandle implements MediaManagerHandle {
         .toList();
   }
 
+  @override
+  Future<DeviceCapabilities> deviceCapabilities(String deviceId) async {
+    final caps = await fallibleFuture(obj.device_capabilities(deviceId).toDart);
+    return WebDeviceCapabilities(caps);
+  }
+
   @override
   Future<List<MediaDisplayDetails>> enumerateDisplays() async {
     throw UnsupportedError('enumerateDisplays() is not implemented for Web');
@@ -67,6 +78,44 @@ class WebMediaManagerHandle implements MediaManagerHandle {
     obj.on_device_change(cb.toJS);
   }
 
+  @override
+  Future<PermissionState> permissionState(PermissionKind kind) async {
+    final state = await fallibleFuture(obj.permission_state(kind.index).toDart);
+    return PermissionState.values[(state as JSNumber).toDartInt];
+  }
+
+  @override
+  Future<void> onPermissionChange(
+    PermissionKind kind,
+    void Function(PermissionState) f,
+  ) async {
+    void fn(JSAny? i) => f(PermissionState.values[(i as JSNumber).toDartInt]);
+    await fallibleFuture(obj.on_permission_change(kind.index, fn.toJS).toDart);
+  }
+
+  @override
+  Future<DiagnosticsReport> runDiagnostics(List<IceServer> iceServers) async {
+    var servers = iceServers.map((s) {
+      var server = wasm.IceServer(s.urls.map((u) => u.toJS).toList().toJS);
+      if (s.username != null) {
+        server.username(s.username!);
+      }
+      if (s.credential != null) {
+        server.credential(s.credential!);
+      }
+      return server;
+    }).toList();
+
+    var report = await fallibleFuture(
+      obj.run_diagnostics(servers.toJS).toDart,
+    );
+    try {
+      return fallibleFunction(() => _diagnosticsReport(report));
+    } finally {
+      report.free();
+    }
+  }
+
   @override
   Future<bool> microphoneVolumeIsAvailable() async {
     return false;
@@ -84,3 +133,47 @@ class WebMediaManagerHandle implements MediaManagerHandle {
     throw UnimplementedError();
   }
 }
+
+/// Converts the provided [wasm.DiagnosticsReport] into a [DiagnosticsReport].
+DiagnosticsReport _diagnosticsReport(wasm.DiagnosticsReport report) {
+  var devices = report.devices().toDart.map((d) {
+    var device = d as wasm.DeviceDiagnostics;
+    try {
+      return DeviceDiagnostics(
+        deviceId: device.device_id(),
+        label: device.label(),
+        kind: MediaKind.values[device.kind().toInt()],
+        error: device.error(),
+        width: device.width(),
+        height: device.height(),
+        frameRate: device.frame_rate(),
+        audioLevel: device.audio_level(),
+      );
+    } finally {
+      device.free();
+    }
+  }).toList();
+
+  var ice = report.ice();
+  var iceDiagnostics = IceDiagnostics(
+    hostCandidates: ice.host_candidates(),
+    srflxCandidates: ice.srflx_candidates(),
+    relayCandidates: ice.relay_candidates(),
+    connected: ice.connected(),
+    errors: ice.errors().toDart.map((e) => e.toDart).toList(),
+  );
+  ice.free();
+
+  var throughput = report.throughput();
+  ThroughputDiagnostics? throughputDiagnostics;
+  if (throughput != null) {
+    throughputDiagnostics = ThroughputDiagnostics(
+      throughput.bytes_sent().toInt(),
+      throughput.duration_ms().toInt(),
+      throughput.bitrate_bps().toInt(),
+    );
+    throughput.free();
+  }
+
+  return DiagnosticsReport(devices, iceDiagnostics, throughputDiagnostics);
+}
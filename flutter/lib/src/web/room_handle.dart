@@ -6,6 +6,8 @@ import '../interface/media_track.dart';
 import '../interface/reconnect_handle.dart';
 import '../interface/room_close_reason.dart';
 import '../interface/room_handle.dart';
+import '../interface/room_member.dart';
+import '../interface/signalling_latency.dart';
 import '../util/move_semantic.dart';
 import 'connection_handle.dart';
 import 'exceptions.dart';
@@ -14,6 +16,8 @@ import 'local_media_track.dart';
 import 'media_stream_settings.dart';
 import 'reconnect_handle.dart';
 import 'room_close_reason.dart';
+import 'room_member.dart';
+import 'signalling_latency.dart';
 
 class WebRoomHandle implements RoomHandle {
   late wasm.RoomHandle obj;
@@ -25,6 +29,24 @@ class WebRoomHandle implements RoomHandle {
     await fallibleFuture(obj.join(token).toDart);
   }
 
+  @override
+  Future<void> joinWithCredential(
+    String url,
+    String credential, [
+    Map<String, String> headers = const {},
+  ]) async {
+    if (headers.isNotEmpty) {
+      throw UnsupportedError('Handshake headers are not supported on web');
+    }
+    await fallibleFuture(obj.join_with_credential(url, credential).toDart);
+  }
+
+  @override
+  Future<RoomCloseReason> leave([String? reason]) async {
+    var closeReason = await fallibleFuture(obj.leave(reason).toDart);
+    return WebRoomCloseReason(closeReason as wasm.RoomCloseReason);
+  }
+
   @override
   Future<void> setLocalMediaSettings(
     base_settings.MediaStreamSettings settings,
@@ -102,6 +124,33 @@ class WebRoomHandle implements RoomHandle {
     await fallibleFuture(obj.disable_remote_video(kind?.index).toDart);
   }
 
+  @override
+  List<RoomMember> members() {
+    return fallibleFunction(
+      () => obj.members().toDart
+          .map((m) => WebRoomMember(m as wasm.RoomMember))
+          .toList(),
+    );
+  }
+
+  @override
+  void onMemberJoined(void Function(RoomMember) f) {
+    void fn(JSAny? member) => f(WebRoomMember(member as wasm.RoomMember));
+    fallibleFunction(() => obj.on_member_joined(fn.toJS));
+  }
+
+  @override
+  void onMemberLeft(void Function(String) f) {
+    void fn(JSString id) => f(id.toDart);
+    fallibleFunction(() => obj.on_member_left(fn.toJS));
+  }
+
+  @override
+  void onMemberUpdated(void Function(RoomMember) f) {
+    void fn(JSAny? member) => f(WebRoomMember(member as wasm.RoomMember));
+    fallibleFunction(() => obj.on_member_updated(fn.toJS));
+  }
+
   @override
   void onNewConnection(void Function(ConnectionHandle) f) {
     void fn(JSAny? handle) =>
@@ -137,6 +186,32 @@ class WebRoomHandle implements RoomHandle {
     fallibleFunction(() => obj.on_failed_local_media(fn.toJS));
   }
 
+  @override
+  void onUnknownEvent(void Function(String) f) {
+    void fn(JSString event) => f(event.toDart);
+    fallibleFunction(() => obj.on_unknown_event(fn.toJS));
+  }
+
+  @override
+  void onSignallingLatency(void Function(SignallingLatency) f) {
+    void fn(JSAny? latency) =>
+        f(WebSignallingLatency(latency as wasm.SignallingLatency));
+    fallibleFunction(() => obj.on_signalling_latency(fn.toJS));
+  }
+
+  @override
+  SignallingLatency? signallingLatency() {
+    return fallibleFunction(() {
+      var latency = obj.signalling_latency();
+      return latency == null ? null : WebSignallingLatency(latency);
+    });
+  }
+
+  @override
+  String? rpcTransportKind() {
+    return fallibleFunction(() => obj.rpc_transport_kind());
+  }
+
   @moveSemantics
   @override
   void free() {
@@ -0,0 +1,35 @@
+import 'dart:js_interop';
+import 'dart:js_interop_unsafe';
+
+import '../interface/room_member.dart';
+import '../util/move_semantic.dart';
+import 'exceptions.dart';
+import 'jason_wasm.dart' as wasm;
+
+class WebRoomMember implements RoomMember {
+  late wasm.RoomMember obj;
+
+  WebRoomMember(this.obj);
+
+  @override
+  String id() {
+    return fallibleFunction(() => obj.id());
+  }
+
+  @override
+  Map<String, String> metadata() {
+    return fallibleFunction(() {
+      var metadata = <String, String>{};
+      void put(JSString value, JSString key) =>
+          metadata[key.toDart] = value.toDart;
+      obj.metadata().callMethod('forEach'.toJS, put.toJS);
+      return metadata;
+    });
+  }
+
+  @moveSemantics
+  @override
+  void free() {
+    obj.free();
+  }
+}
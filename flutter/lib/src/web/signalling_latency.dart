@@ -0,0 +1,36 @@
+import '../interface/signalling_latency.dart';
+import '../util/move_semantic.dart';
+import 'exceptions.dart';
+import 'jason_wasm.dart' as wasm;
+
+class WebSignallingLatency implements SignallingLatency {
+  late wasm.SignallingLatency obj;
+
+  WebSignallingLatency(this.obj);
+
+  @override
+  double rttMs() {
+    return fallibleFunction(() => obj.rtt_ms());
+  }
+
+  @override
+  double smoothedRttMs() {
+    return fallibleFunction(() => obj.smoothed_rtt_ms());
+  }
+
+  @override
+  double jitterMs() {
+    return fallibleFunction(() => obj.jitter_ms());
+  }
+
+  @override
+  int samples() {
+    return fallibleFunction(() => obj.samples());
+  }
+
+  @moveSemantics
+  @override
+  void free() {
+    obj.free();
+  }
+}
@@ -105,6 +105,21 @@ enum MediaSourceKind {
   display,
 }
 
+/// Mode of an automatic camera adjustment (focus, white balance, etc.).
+enum MeteringMode {
+  /// No adjustment is available, or it's disabled.
+  none,
+
+  /// Adjustment is locked to a value set manually.
+  manual,
+
+  /// Adjustment is performed once, and then locked.
+  singleShot,
+
+  /// Adjustment is performed continuously.
+  continuous,
+}
+
 /// Audio processing noise suppression aggressiveness.
 enum NoiseSuppressionLevel {
   /// Minimal noise suppression.
@@ -170,6 +185,20 @@ enum PeerConnectionState {
   closed,
 }
 
+/// Kind of a media permission, requested by a user agent before capturing
+/// media.
+enum PermissionKind {
+  /// Permission to capture audio from a microphone.
+  microphone,
+
+  /// Permission to capture video from a camera.
+  camera,
+
+  /// Permission to capture contents of a display or its portion (such as a
+  /// single window).
+  screen,
+}
+
 /// The reason of why a `Room` was closed.
 enum RoomCloseKind {
   /// Unexpected client error.
@@ -195,6 +224,12 @@ enum RoomCloseKind {
   /// Usually this means that either `Member` or `Room` was deleted from the
   /// server.
   evicted,
+
+  /// Client API protocol version of the client is incompatible with the
+  /// server one.
+  ///
+  /// Most likely either the client or the server should be upgraded.
+  incompatibleProtocol,
 }
 
 /// Global log level for the whole library and all its subsystems.
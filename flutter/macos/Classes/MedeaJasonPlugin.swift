@@ -1,6 +1,26 @@
+import AVFoundation
 import Cocoa
 import FlutterMacOS
 
+/// Media permission state, mirroring the `PermissionState` enum of Rust side.
+enum PermissionState: Int {
+  case granted = 0
+  case denied = 1
+  case prompt = 2
+}
+
+/// Returns the `PermissionState` of capturing the provided `AVMediaType`.
+func authorizationState(of mediaType: AVMediaType) -> PermissionState {
+  switch AVCaptureDevice.authorizationStatus(for: mediaType) {
+  case .authorized:
+    return .granted
+  case .denied, .restricted:
+    return .denied
+  default:
+    return .prompt
+  }
+}
+
 public class MedeaJasonPlugin: NSObject, FlutterPlugin {
   public static func register(with registrar: FlutterPluginRegistrar) {
     let channel = FlutterMethodChannel(name: "medea_jason", binaryMessenger: registrar.messenger)
@@ -12,8 +32,25 @@ public class MedeaJasonPlugin: NSObject, FlutterPlugin {
     switch call.method {
     case "getPlatformVersion":
       result("macOS " + ProcessInfo.processInfo.operatingSystemVersionString)
+    case "permissionState":
+      permissionState(call, result: result)
     default:
       result(FlutterMethodNotImplemented)
     }
   }
+
+  /// Returns the current `PermissionState` of the provided media permission
+  /// kind.
+  private func permissionState(_ call: FlutterMethodCall, result: @escaping FlutterResult) {
+    let args = call.arguments as? [String: Any]
+    switch args?["kind"] as? Int {
+    case 0:
+      result(authorizationState(of: .audio).rawValue)
+    case 1:
+      result(authorizationState(of: .video).rawValue)
+    default:
+      let granted = CGPreflightScreenCaptureAccess()
+      result((granted ? PermissionState.granted : .prompt).rawValue)
+    }
+  }
 }
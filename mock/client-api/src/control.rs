@@ -0,0 +1,376 @@
+//! In-memory [Control API] implementation, exposed via [gRPC].
+//!
+//! [Control API]: https://tinyurl.com/yxsqplq7
+//! [gRPC]: https://grpc.io
+
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::Path,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use actix::Arbiter;
+use async_trait::async_trait;
+use derive_more::{Display, Error as StdError, From};
+use medea_control_api_proto::{
+    self as proto, ControlApi, Element, Elements, Endpoint, Fid, Member, Ping,
+    Pong, Room, RootElement,
+    control::Request,
+    grpc::{ProtobufError, api::control_api_server::ControlApiServer},
+    member::{self, Credentials, PublicUrl, Sid, Sids},
+    room::{self, PipelineSpec},
+};
+use tonic::transport::Server;
+use tracing as log;
+
+use crate::{Cli, room::Rooms};
+
+/// Shared in-memory storage of [`room::Spec`]s.
+#[derive(Clone, Debug, Default)]
+pub struct Specs(Arc<Mutex<HashMap<room::Id, room::Spec>>>);
+
+impl Specs {
+    /// Loads all the [`RootElement`]s (in YAML format) from the files of the
+    /// provided directory.
+    ///
+    /// # Errors
+    ///
+    /// If the directory or any of its files cannot be read or parsed.
+    pub fn load_dir(&self, dir: &Path) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if !path.is_file() {
+                continue;
+            }
+
+            let RootElement::Room(room) =
+                serde_yaml::from_str(&fs::read_to_string(&path)?)
+                    .map_err(io::Error::other)?;
+            log::info!("Loaded static `Room` spec: {}", room.id);
+            drop(self.lock().insert(room.id, room.spec));
+        }
+        Ok(())
+    }
+
+    /// Returns the [`room::Spec`] of the provided [`room::Id`], if any.
+    #[must_use]
+    pub fn room(&self, room_id: &room::Id) -> Option<room::Spec> {
+        self.lock().get(room_id).cloned()
+    }
+
+    /// Locks the underlying storage of these [`Specs`].
+    fn lock(&self) -> MutexGuard<'_, HashMap<room::Id, room::Spec>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// [`ControlApi`] implementation storing all the [`Element`]s in memory.
+///
+/// Removing a [`Member`] (or a whole [`Room`]) evicts its client from the
+/// [`Rooms`]. All other changes take effect on the `Peer`s created afterwards.
+#[derive(Clone, Debug)]
+pub struct InMemoryControlApi {
+    /// Storage of [`room::Spec`]s.
+    specs: Specs,
+
+    /// Signalling runtime state of [`Room`]s.
+    rooms: Rooms,
+
+    /// [`PublicUrl`] to return in [`Sid`]s.
+    public_url: PublicUrl,
+}
+
+impl InMemoryControlApi {
+    /// Creates a new [`InMemoryControlApi`] over the provided [`Specs`].
+    #[must_use]
+    pub const fn new(
+        specs: Specs,
+        rooms: Rooms,
+        public_url: PublicUrl,
+    ) -> Self {
+        Self { specs, rooms, public_url }
+    }
+
+    /// Builds a [`Sid`] of the provided [`Member`].
+    fn sid(
+        &self,
+        room_id: &room::Id,
+        member_id: &member::Id,
+        spec: &member::Spec,
+    ) -> Sid {
+        Sid {
+            public_url: self.public_url.clone(),
+            room_id: room_id.clone(),
+            member_id: member_id.clone(),
+            creds: match &spec.credentials {
+                Some(Credentials::Plain(plain)) => Some(plain.clone()),
+                Some(Credentials::Hash(_)) | None => None,
+            },
+        }
+    }
+
+    /// Builds [`Sids`] of all the [`Member`]s in the provided [`room::Spec`].
+    fn room_sids(&self, room_id: &room::Id, spec: &room::Spec) -> Sids {
+        spec.pipeline
+            .iter()
+            .map(|(id, PipelineSpec::Member(m))| {
+                (id.clone(), self.sid(room_id, id, m))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl ControlApi for InMemoryControlApi {
+    type Error = Error;
+
+    async fn create(&self, request: Request) -> Result<Sids, Self::Error> {
+        let mut specs = self.specs.lock();
+        match request {
+            Request::Room { id, spec } => {
+                if specs.contains_key(&id) {
+                    return Err(Error::AlreadyExists(Fid::Room { id }));
+                }
+                let sids = self.room_sids(&id, &spec);
+                drop(specs.insert(id, spec));
+                drop(specs);
+                Ok(sids)
+            }
+            Request::Member { id, room_id, spec } => {
+                let room = specs.get_mut(&room_id).ok_or_else(|| {
+                    Error::NotFound(Fid::Room { id: room_id.clone() })
+                })?;
+                if room.pipeline.contains_key(&id) {
+                    return Err(Error::AlreadyExists(Fid::Member {
+                        id,
+                        room_id,
+                    }));
+                }
+                let sid = self.sid(&room_id, &id, &spec);
+                drop(room.pipeline.insert(id.clone(), (*spec).into()));
+                drop(specs);
+                Ok(HashMap::from([(id, sid)]))
+            }
+            Request::Endpoint { id, room_id, member_id, spec } => {
+                let PipelineSpec::Member(member) = specs
+                    .get_mut(&room_id)
+                    .and_then(|r| r.pipeline.get_mut(&member_id))
+                    .ok_or_else(|| {
+                        Error::NotFound(Fid::Member {
+                            id: member_id.clone(),
+                            room_id: room_id.clone(),
+                        })
+                    })?;
+                if member.pipeline.contains_key(&id) {
+                    return Err(Error::AlreadyExists(Fid::Endpoint {
+                        id,
+                        room_id,
+                        member_id,
+                    }));
+                }
+                drop(member.pipeline.insert(id, spec));
+                drop(specs);
+                Ok(Sids::new())
+            }
+        }
+    }
+
+    async fn apply(&self, request: Request) -> Result<Sids, Self::Error> {
+        let mut specs = self.specs.lock();
+        match request {
+            Request::Room { id, spec } => {
+                let sids = self.room_sids(&id, &spec);
+                let removed = specs
+                    .insert(id.clone(), spec.clone())
+                    .into_iter()
+                    .flat_map(|old| old.pipeline.into_keys())
+                    .filter(|m| !spec.pipeline.contains_key(m))
+                    .collect::<Vec<_>>();
+                drop(specs);
+                for member_id in removed {
+                    self.rooms.evict(&id, Some(&member_id));
+                }
+                Ok(sids)
+            }
+            Request::Member { id, room_id, spec } => {
+                let room = specs.get_mut(&room_id).ok_or_else(|| {
+                    Error::NotFound(Fid::Room { id: room_id.clone() })
+                })?;
+                let sid = self.sid(&room_id, &id, &spec);
+                drop(room.pipeline.insert(id.clone(), (*spec).into()));
+                drop(specs);
+                Ok(HashMap::from([(id, sid)]))
+            }
+            Request::Endpoint { id, room_id, member_id, spec } => {
+                let PipelineSpec::Member(member) = specs
+                    .get_mut(&room_id)
+                    .and_then(|r| r.pipeline.get_mut(&member_id))
+                    .ok_or_else(|| {
+                        Error::NotFound(Fid::Member {
+                            id: member_id.clone(),
+                            room_id: room_id.clone(),
+                        })
+                    })?;
+                drop(member.pipeline.insert(id, spec));
+                drop(specs);
+                Ok(Sids::new())
+            }
+        }
+    }
+
+    async fn delete(&self, fids: &[Fid]) -> Result<(), Self::Error> {
+        if fids.is_empty() {
+            return Err(Error::NoFids);
+        }
+
+        for fid in fids {
+            let mut specs = self.specs.lock();
+            match fid {
+                Fid::Room { id } => {
+                    drop(specs.remove(id));
+                    drop(specs);
+                    self.rooms.evict(id, None);
+                }
+                Fid::Member { id, room_id } => {
+                    if let Some(room) = specs.get_mut(room_id) {
+                        drop(room.pipeline.remove(id));
+                    }
+                    drop(specs);
+                    self.rooms.evict(room_id, Some(id));
+                }
+                Fid::Endpoint { id, room_id, member_id } => {
+                    if let Some(PipelineSpec::Member(member)) = specs
+                        .get_mut(room_id)
+                        .and_then(|r| r.pipeline.get_mut(member_id))
+                    {
+                        drop(member.pipeline.remove(id));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn get(&self, fids: &[Fid]) -> Result<Elements, Self::Error> {
+        let specs = self.specs.lock().clone();
+
+        if fids.is_empty() {
+            return Ok(specs
+                .into_iter()
+                .map(|(id, spec)| {
+                    (Fid::Room { id: id.clone() }, Room { id, spec }.into())
+                })
+                .collect());
+        }
+
+        Ok(fids
+            .iter()
+            .filter_map(|fid| {
+                let el = match fid {
+                    Fid::Room { id } => specs.get(id).map(|spec| {
+                        Element::from(Room {
+                            id: id.clone(),
+                            spec: spec.clone(),
+                        })
+                    }),
+                    Fid::Member { id, room_id } => specs
+                        .get(room_id)
+                        .and_then(|r| r.pipeline.get(id))
+                        .map(|PipelineSpec::Member(spec)| {
+                            Element::from(Box::new(Member {
+                                id: id.clone(),
+                                spec: spec.clone(),
+                            }))
+                        }),
+                    Fid::Endpoint { id, room_id, member_id } => specs
+                        .get(room_id)
+                        .and_then(|r| r.pipeline.get(member_id))
+                        .and_then(|PipelineSpec::Member(m)| m.pipeline.get(id))
+                        .map(|spec| {
+                            Element::from(Endpoint {
+                                id: id.clone(),
+                                spec: spec.clone(),
+                            })
+                        }),
+                };
+                el.map(|el| (fid.clone(), el))
+            })
+            .collect())
+    }
+
+    async fn healthz(&self, ping: Ping) -> Result<Pong, Self::Error> {
+        Ok(Pong(ping.0))
+    }
+}
+
+/// Possible errors of [`InMemoryControlApi`].
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    /// Error of converting a [gRPC] request.
+    ///
+    /// [gRPC]: https://grpc.io
+    #[display("Invalid request: {_0}")]
+    Protobuf(ProtobufError),
+
+    /// [`Element`] doesn't exist.
+    #[display("Element `{_0}` doesn't exist")]
+    #[from(ignore)]
+    NotFound(#[error(not(source))] Fid),
+
+    /// [`Element`] already exists.
+    #[display("Element `{_0}` already exists")]
+    #[from(ignore)]
+    AlreadyExists(#[error(not(source))] Fid),
+
+    /// No [`Fid`]s were specified.
+    #[display("No FIDs specified")]
+    NoFids,
+}
+
+impl Error {
+    /// Returns the code of this [`Error`].
+    const fn code(&self) -> u32 {
+        match self {
+            Self::Protobuf(_) => 1000,
+            Self::NotFound(_) => 1001,
+            Self::AlreadyExists(_) => 1002,
+            Self::NoFids => 1003,
+        }
+    }
+}
+
+impl From<Error> for proto::grpc::api::Error {
+    fn from(err: Error) -> Self {
+        Self {
+            code: err.code(),
+            text: err.to_string(),
+            doc: String::new(),
+            element: match &err {
+                Error::NotFound(fid) | Error::AlreadyExists(fid) => {
+                    fid.to_string()
+                }
+                Error::Protobuf(_) | Error::NoFids => String::new(),
+            },
+        }
+    }
+}
+
+/// Runs [gRPC] server of the provided [`InMemoryControlApi`].
+///
+/// # Panics
+///
+/// If cannot bind and run gRPC server.
+pub fn run(opts: &Cli, api: InMemoryControlApi) {
+    let addr = opts.grpc_addr;
+    let service = ControlApiServer::new(api);
+
+    _ = Arbiter::current().spawn(async move {
+        #[expect(clippy::expect_used, reason = "startup")]
+        Server::builder()
+            .add_service(service)
+            .serve(addr)
+            .await
+            .expect("failed to run gRPC Control API server");
+    });
+}
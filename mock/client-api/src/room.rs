@@ -0,0 +1,1801 @@
+//! Signalling runtime state of P2P mesh [`Room`]s.
+
+use std::{
+    collections::{HashMap, HashSet},
+    iter,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use actix::{Message, Recipient};
+use actix_web::rt;
+use medea_client_api_proto::{
+    self as proto, AudioSettings, Capabilities, CloseReason, Command,
+    CommandHandler, ConnectionMode, Credential, Direction, Event, IceCandidate,
+    IceServer, Incrementable as _, MediaDirection, MediaSourceKind, MediaType,
+    MemberId, NegotiationRole, PeerId, PeerMetrics, PeerUpdate,
+    ProtocolVersion, RoomId, RpcSettings, ServerMsg, Track, TrackId,
+    TrackPatchCommand, TrackPatchEvent, VideoSettings, state,
+};
+use medea_control_api_proto::{
+    endpoint::{
+        self,
+        web_rtc_publish::{P2pMode, Policy},
+    },
+    member::{self, Credentials},
+    room::{self, PipelineSpec},
+    turn::SharedSecret,
+};
+use tracing as log;
+
+use crate::{Cli, control::Specs};
+
+/// Message to be delivered to a client via its [`Connection`].
+#[derive(Clone, Debug, Message)]
+#[rtype(result = "()")]
+pub enum Outgoing {
+    /// [`ServerMsg`] to be sent to the client.
+    Msg(ServerMsg),
+
+    /// Connection with the client should be closed with the provided
+    /// [`CloseReason`].
+    Close(CloseReason),
+}
+
+/// Connection with a client.
+pub type Connection = Recipient<Outgoing>;
+
+/// Settings of [`Rooms`].
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// Default interval of sending [`ServerMsg::Ping`]s to clients.
+    pub ping_interval: Duration,
+
+    /// Default timeout of considering a client as lost when it doesn't send
+    /// any `Pong`s.
+    pub idle_timeout: Duration,
+
+    /// Default timeout of keeping a [`Member`] in a [`Room`] after its
+    /// [`Connection`] has been lost.
+    pub reconnect_timeout: Duration,
+
+    /// URLs of [`IceServer`]s to be used by `Peer`s.
+    pub ice_urls: Vec<String>,
+
+    /// [`SharedSecret`] to mint ephemeral TURN credentials with, if any.
+    pub turn_secret: Option<SharedSecret>,
+
+    /// Lifetime of ephemeral TURN credentials.
+    pub turn_ttl: Duration,
+}
+
+impl From<&Cli> for Settings {
+    fn from(opts: &Cli) -> Self {
+        Self {
+            ping_interval: opts.ping_interval,
+            idle_timeout: opts.idle_timeout,
+            reconnect_timeout: opts.reconnect_timeout,
+            ice_urls: opts.ice_servers.clone(),
+            turn_secret: opts.turn_secret.clone().map(SharedSecret::from),
+            turn_ttl: opts.turn_ttl,
+        }
+    }
+}
+
+impl Settings {
+    /// Returns [`RpcSettings`] for the provided [`member::Spec`], falling back
+    /// to the default ones.
+    #[must_use]
+    pub fn rpc(&self, spec: Option<&member::Spec>) -> RpcSettings {
+        let millis =
+            |d: Duration| u32::try_from(d.as_millis()).unwrap_or(u32::MAX);
+
+        RpcSettings {
+            idle_timeout_ms: millis(
+                spec.and_then(|s| s.idle_timeout).unwrap_or(self.idle_timeout),
+            ),
+            ping_interval_ms: millis(
+                spec.and_then(|s| s.ping_interval)
+                    .unwrap_or(self.ping_interval),
+            ),
+            latency_probes: true,
+        }
+    }
+
+    /// Returns [`IceServer`]s to be used by `Peer`s of the provided
+    /// [`Member`], minting fresh ephemeral TURN credentials for it, if
+    /// configured.
+    #[must_use]
+    pub fn ice_servers(&self, member_id: &MemberId) -> Vec<IceServer> {
+        if self.ice_urls.is_empty() {
+            return Vec::new();
+        }
+        vec![self.turn_secret.as_ref().map_or_else(
+            || IceServer {
+                urls: self.ice_urls.clone(),
+                username: None,
+                credential: None,
+            },
+            |secret| {
+                secret
+                    .credentials(&member_id.0, self.turn_ttl)
+                    .into_ice_server(self.ice_urls.clone())
+            },
+        )]
+    }
+}
+
+/// Registry of all the active [`Room`]s.
+#[derive(Clone, Debug)]
+pub struct Rooms {
+    /// Active [`Room`]s.
+    active: Arc<Mutex<HashMap<RoomId, Room>>>,
+
+    /// Storage of [`room::Spec`]s describing topologies of [`Room`]s.
+    specs: Specs,
+
+    /// [`Settings`] of these [`Rooms`].
+    settings: Arc<Settings>,
+}
+
+impl Rooms {
+    /// Creates new [`Rooms`] with the provided [`Specs`] and [`Settings`].
+    #[must_use]
+    pub fn new(specs: Specs, settings: Settings) -> Self {
+        Self { active: Arc::default(), specs, settings: Arc::new(settings) }
+    }
+
+    /// Returns [`Settings`] of these [`Rooms`].
+    #[must_use]
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Authorizes the provided [`Credential`] of a [`Member`] and joins it to
+    /// the [`Room`], creating `Peer`s with all the other connected
+    /// [`Member`]s it exchanges media with.
+    ///
+    /// Returns generation of the [`Connection`], or [`None`] if the
+    /// [`Member`] has been rejected.
+    pub fn join(
+        &self,
+        room_id: &RoomId,
+        member_id: &MemberId,
+        credential: &Credential,
+        conn: Connection,
+    ) -> Option<u64> {
+        let room_spec = self.specs.room(&room_id.clone().into());
+        let spec = room_spec
+            .as_ref()
+            .and_then(|r| r.pipeline.get(&member_id.clone().into()))
+            .map(|PipelineSpec::Member(m)| m)
+            .filter(|m| authorize(m, credential));
+        let (Some(room_spec), Some(spec)) = (room_spec.as_ref(), spec) else {
+            log::info!("`Member({room_id}/{member_id})` is rejected");
+            conn.do_send(Outgoing::Msg(ServerMsg::Event {
+                room_id: room_id.clone(),
+                event: Event::RoomLeft { close_reason: CloseReason::Rejected },
+            }));
+            return None;
+        };
+
+        conn.do_send(Outgoing::Msg(ServerMsg::RpcSettings(
+            self.settings.rpc(Some(spec)),
+        )));
+
+        let mut rooms = self.lock();
+        let room = rooms
+            .entry(room_id.clone())
+            .or_insert_with(|| Room::new(room_id.clone()));
+        let generation =
+            room.join(member_id, spec, conn, room_spec, &self.settings);
+        drop(rooms);
+
+        log::info!("`Member({room_id}/{member_id})` joined");
+        Some(generation)
+    }
+
+    /// Handles the provided [`Command`] of a [`Member`].
+    pub fn command(
+        &self,
+        room_id: &RoomId,
+        member_id: &MemberId,
+        command: Command,
+    ) {
+        let mut rooms = self.lock();
+        let Some(room) = rooms
+            .get_mut(room_id)
+            .filter(|r| r.members.contains_key(member_id))
+        else {
+            log::warn!(
+                "`Command` from unknown `Member({room_id}/{member_id})`",
+            );
+            return;
+        };
+        command.dispatch_with(&mut MemberCommands { room, member_id });
+        drop(rooms);
+    }
+
+    /// Re-mints ephemeral TURN credentials for all the existing `Peer`s,
+    /// notifying their [`Member`]s with [`Event::IceServersUpdated`].
+    pub fn refresh_ice_servers(&self) {
+        let mut rooms = self.lock();
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for room in rooms.values_mut() {
+            room.refresh_ice_servers(&self.settings);
+        }
+        drop(rooms);
+    }
+
+    /// Removes a [`Member`] from the [`Room`] immediately, acknowledging it
+    /// with an [`Event::RoomLeft`].
+    pub fn leave(
+        &self,
+        room_id: &RoomId,
+        member_id: &MemberId,
+        reason: Option<&str>,
+    ) {
+        log::info!(
+            "`Member({room_id}/{member_id})` left: {}",
+            reason.unwrap_or("no reason"),
+        );
+        self.remove_members(room_id, Some(CloseReason::Finished), |id, _| {
+            id == member_id
+        });
+    }
+
+    /// Marks the [`Connection`] of the provided generation as lost, and
+    /// removes the [`Member`] from the [`Room`] if it doesn't reconnect in
+    /// time.
+    pub fn disconnect(
+        &self,
+        room_id: &RoomId,
+        member_id: &MemberId,
+        generation: u64,
+    ) {
+        let mut rooms = self.lock();
+        let Some(member) = rooms
+            .get_mut(room_id)
+            .and_then(|r| r.members.get_mut(member_id))
+            .filter(|m| m.generation == generation)
+        else {
+            return;
+        };
+        member.connection = None;
+        let timeout = member.reconnect_timeout;
+        drop(rooms);
+
+        log::info!("`Member({room_id}/{member_id})` lost its connection");
+
+        let this = self.clone();
+        let (room_id, member_id) = (room_id.clone(), member_id.clone());
+        drop(rt::spawn(async move {
+            rt::time::sleep(timeout).await;
+            this.remove_members(&room_id, None, |id, m| {
+                *id == member_id
+                    && m.generation == generation
+                    && m.connection.is_none()
+            });
+        }));
+    }
+
+    /// Evicts the provided [`Member`] (or all the [`Member`]s, if [`None`])
+    /// from the [`Room`].
+    pub fn evict(&self, room_id: &room::Id, member_id: Option<&member::Id>) {
+        let room_id = RoomId(room_id.to_string());
+        self.remove_members(&room_id, Some(CloseReason::Evicted), |id, _| {
+            member_id.is_none_or(|m| id.0 == m.to_string())
+        });
+    }
+
+    /// Removes all the [`Member`]s matching the provided predicate from the
+    /// [`Room`], notifying them with [`Event::RoomLeft`] if the
+    /// [`CloseReason`] is provided.
+    fn remove_members(
+        &self,
+        room_id: &RoomId,
+        close_reason: Option<CloseReason>,
+        filter: impl Fn(&MemberId, &Member) -> bool,
+    ) {
+        let mut rooms = self.lock();
+        let Some(room) = rooms.get_mut(room_id) else {
+            return;
+        };
+        let ids = room
+            .members
+            .iter()
+            .filter(|(id, m)| filter(id, m))
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+        for id in ids {
+            if let Some(close_reason) = close_reason {
+                room.send(&id, Event::RoomLeft { close_reason });
+            }
+            room.remove_member(&id);
+        }
+        if room.members.is_empty() {
+            drop(rooms.remove(room_id));
+        }
+    }
+
+    /// Locks the underlying [`Room`]s.
+    fn lock(&self) -> MutexGuard<'_, HashMap<RoomId, Room>> {
+        self.active.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Checks whether the provided [`Credential`] matches the [`Credentials`] of
+/// the provided [`member::Spec`].
+fn authorize(spec: &member::Spec, credential: &Credential) -> bool {
+    match &spec.credentials {
+        None => true,
+        Some(Credentials::Plain(plain)) => {
+            Credential::from(plain.expose_str()) == *credential
+        }
+        Some(Credentials::Hash(_)) => {
+            log::warn!("Hashed `Credentials` are not supported");
+            false
+        }
+    }
+}
+
+/// Participant of a [`Room`].
+#[derive(Debug)]
+struct Member {
+    /// Metadata of this [`Member`].
+    metadata: HashMap<String, String>,
+
+    /// [`Connection`] with the client of this [`Member`], if any.
+    connection: Option<Connection>,
+
+    /// Generation of the [`Connection`], incremented on each reconnect.
+    generation: u64,
+
+    /// Timeout of keeping this [`Member`] after its [`Connection`] has been
+    /// lost.
+    reconnect_timeout: Duration,
+}
+
+/// `Peer` of a [`Member`], paired with the one of its partner [`Member`].
+#[derive(Debug)]
+struct Peer {
+    /// ID of the [`Member`] owning this [`Peer`].
+    member_id: MemberId,
+
+    /// ID of the partner [`Member`].
+    partner_member_id: MemberId,
+
+    /// ID of the partner [`Peer`].
+    partner_peer_id: PeerId,
+
+    /// Indicator whether [`Event::PeerCreated`] has been sent for this
+    /// [`Peer`] already.
+    is_known: bool,
+
+    /// Server-side state of this [`Peer`].
+    state: state::Peer,
+}
+
+/// Single space where [`Member`]s exchange media via P2P mesh `Peer`s.
+#[derive(Debug)]
+struct Room {
+    /// ID of this [`Room`].
+    id: RoomId,
+
+    /// [`Member`]s present in this [`Room`].
+    members: HashMap<MemberId, Member>,
+
+    /// `Peer`s of all the [`Member`]s in this [`Room`].
+    peers: HashMap<PeerId, Peer>,
+
+    /// Last allocated [`PeerId`].
+    last_peer_id: PeerId,
+
+    /// Last allocated [`TrackId`].
+    last_track_id: TrackId,
+}
+
+impl Room {
+    /// Creates a new empty [`Room`].
+    fn new(id: RoomId) -> Self {
+        Self {
+            id,
+            members: HashMap::new(),
+            peers: HashMap::new(),
+            last_peer_id: PeerId::default(),
+            last_track_id: TrackId::default(),
+        }
+    }
+
+    /// Joins the provided [`Member`] to this [`Room`] via the provided
+    /// [`Connection`], returning its generation.
+    fn join(
+        &mut self,
+        member_id: &MemberId,
+        spec: &member::Spec,
+        conn: Connection,
+        room_spec: &room::Spec,
+        settings: &Settings,
+    ) -> u64 {
+        let is_reconnect = self.members.contains_key(member_id);
+        let member =
+            self.members.entry(member_id.clone()).or_insert_with(|| Member {
+                metadata: HashMap::new(),
+                connection: None,
+                generation: 0,
+                reconnect_timeout: settings.reconnect_timeout,
+            });
+        member.metadata.clone_from(&spec.metadata);
+        member.reconnect_timeout =
+            spec.reconnect_timeout.unwrap_or(settings.reconnect_timeout);
+        member.generation += 1;
+        let generation = member.generation;
+        if let Some(old) = member.connection.replace(conn)
+            && Some(&old) != member.connection.as_ref()
+        {
+            old.do_send(Outgoing::Close(CloseReason::Reconnected));
+        }
+
+        self.send(
+            member_id,
+            Event::RoomJoined {
+                member_id: member_id.clone(),
+                is_reconnect,
+                members: self.roster(),
+            },
+        );
+        if !is_reconnect {
+            let joined = self.roster_member(member_id);
+            self.broadcast(member_id, || Event::MemberJoined {
+                member: joined.clone(),
+            });
+        }
+
+        let partners = self
+            .members
+            .iter()
+            .filter(|(id, m)| {
+                *id != member_id
+                    && m.connection.is_some()
+                    && !self.peers.values().any(|p| {
+                        p.member_id == *member_id && p.partner_member_id == **id
+                    })
+            })
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+        for partner_id in partners {
+            self.create_peers(member_id, &partner_id, room_spec, settings);
+        }
+
+        generation
+    }
+
+    /// Creates a pair of `Peer`s between the provided [`Member`]s, with the
+    /// first one being an offerer.
+    fn create_peers(
+        &mut self,
+        offerer: &MemberId,
+        answerer: &MemberId,
+        room_spec: &room::Spec,
+        settings: &Settings,
+    ) {
+        let room_id = room::Id::from(self.id.clone());
+        let (sent, sent_relay) =
+            tracks_between(room_spec, &room_id, offerer, answerer);
+        let (received, received_relay) =
+            tracks_between(room_spec, &room_id, answerer, offerer);
+        if sent.is_empty() && received.is_empty() {
+            return;
+        }
+        let force_relay = sent_relay || received_relay;
+
+        let offerer_peer_id = self.next_peer_id();
+        let answerer_peer_id = self.next_peer_id();
+        let mut offerer_state = new_peer_state(
+            offerer_peer_id,
+            force_relay,
+            settings.ice_servers(offerer),
+        );
+        let mut answerer_state = new_peer_state(
+            answerer_peer_id,
+            force_relay,
+            settings.ice_servers(answerer),
+        );
+        offerer_state.negotiation_role = Some(NegotiationRole::Offerer);
+
+        for media_type in sent {
+            let id = self.next_track_id();
+            add_track(
+                id,
+                media_type,
+                (offerer, &mut offerer_state),
+                (answerer, &mut answerer_state),
+            );
+        }
+        for media_type in received {
+            let id = self.next_track_id();
+            add_track(
+                id,
+                media_type,
+                (answerer, &mut answerer_state),
+                (offerer, &mut offerer_state),
+            );
+        }
+
+        drop(self.peers.insert(
+            offerer_peer_id,
+            Peer {
+                member_id: offerer.clone(),
+                partner_member_id: answerer.clone(),
+                partner_peer_id: answerer_peer_id,
+                is_known: true,
+                state: offerer_state,
+            },
+        ));
+        drop(self.peers.insert(
+            answerer_peer_id,
+            Peer {
+                member_id: answerer.clone(),
+                partner_member_id: offerer.clone(),
+                partner_peer_id: offerer_peer_id,
+                is_known: false,
+                state: answerer_state,
+            },
+        ));
+
+        if let Some(peer) = self.peers.get(&offerer_peer_id) {
+            self.send(offerer, peer_created(&peer.state));
+        }
+    }
+
+    /// Replaces [`IceServer`]s of all the `Peer`s in this [`Room`] with the
+    /// freshly minted ones, notifying their [`Member`]s.
+    fn refresh_ice_servers(&mut self, settings: &Settings) {
+        let mut updated = Vec::new();
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for (id, peer) in &mut self.peers {
+            peer.state.ice_servers = settings.ice_servers(&peer.member_id);
+            if peer.is_known {
+                updated.push((
+                    peer.member_id.clone(),
+                    Event::IceServersUpdated {
+                        peer_id: *id,
+                        ice_servers: peer.state.ice_servers.clone(),
+                    },
+                ));
+            }
+        }
+        for (member_id, event) in updated {
+            self.send(&member_id, event);
+        }
+    }
+
+    /// Removes the provided [`Member`] along with all its `Peer`s from this
+    /// [`Room`], notifying the other [`Member`]s.
+    fn remove_member(&mut self, member_id: &MemberId) {
+        let Some(member) = self.members.remove(member_id) else {
+            return;
+        };
+        drop(member);
+
+        let mut removed = HashMap::<MemberId, Vec<PeerId>>::new();
+        self.peers.retain(|id, p| {
+            if p.member_id == *member_id {
+                false
+            } else if p.partner_member_id == *member_id {
+                removed.entry(p.member_id.clone()).or_default().push(*id);
+                false
+            } else {
+                true
+            }
+        });
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for (partner_id, peer_ids) in removed {
+            self.send(&partner_id, Event::PeersRemoved { peer_ids });
+        }
+
+        self.broadcast(member_id, || Event::MemberLeft {
+            member_id: member_id.clone(),
+        });
+    }
+
+    /// Returns the roster of all the [`Member`]s present in this [`Room`].
+    fn roster(&self) -> Vec<proto::Member> {
+        let mut roster = self
+            .members
+            .keys()
+            .map(|id| self.roster_member(id))
+            .collect::<Vec<_>>();
+        roster.sort_unstable_by(|a, b| a.id.0.cmp(&b.id.0));
+        roster
+    }
+
+    /// Returns the roster entry of the provided [`Member`].
+    fn roster_member(&self, id: &MemberId) -> proto::Member {
+        proto::Member {
+            id: id.clone(),
+            metadata: self
+                .members
+                .get(id)
+                .map(|m| m.metadata.clone())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Sends the provided [`Event`] to the provided [`Member`], if it's
+    /// connected.
+    fn send(&self, member_id: &MemberId, event: Event) {
+        if let Some(conn) =
+            self.members.get(member_id).and_then(|m| m.connection.as_ref())
+        {
+            conn.do_send(Outgoing::Msg(ServerMsg::Event {
+                room_id: self.id.clone(),
+                event,
+            }));
+        }
+    }
+
+    /// Sends the [`Event`]s produced by the provided function to all the
+    /// [`Member`]s, except the provided one.
+    fn broadcast(&self, except: &MemberId, event: impl Fn() -> Event) {
+        for id in self.members.keys().filter(|id| *id != except) {
+            self.send(id, event());
+        }
+    }
+
+    /// Allocates a new [`PeerId`].
+    fn next_peer_id(&mut self) -> PeerId {
+        self.last_peer_id = self.last_peer_id.incr();
+        self.last_peer_id
+    }
+
+    /// Allocates a new [`TrackId`].
+    fn next_track_id(&mut self) -> TrackId {
+        self.last_track_id = self.last_track_id.incr();
+        self.last_track_id
+    }
+}
+
+/// [`CommandHandler`] of the [`Command`]s sent by a [`Member`] of a [`Room`].
+struct MemberCommands<'a> {
+    /// [`Room`] the [`Member`] participates in.
+    room: &'a mut Room,
+
+    /// ID of the [`Member`] who sent the [`Command`].
+    member_id: &'a MemberId,
+}
+
+impl MemberCommands<'_> {
+    /// Returns the [`Peer`] of the [`Member`] with the provided [`PeerId`].
+    fn own_peer(&mut self, peer_id: PeerId) -> Option<&mut Peer> {
+        let peer = self
+            .room
+            .peers
+            .get_mut(&peer_id)
+            .filter(|p| p.member_id == *self.member_id);
+        if peer.is_none() {
+            log::warn!(
+                "`Member({}/{})` doesn't own `Peer({peer_id})`",
+                self.room.id,
+                self.member_id,
+            );
+        }
+        peer
+    }
+
+    /// Returns the partner [`Peer`] of the provided [`Peer`].
+    fn partner_peer(&mut self, peer_id: PeerId) -> Option<&mut Peer> {
+        let partner_id = self.room.peers.get(&peer_id)?.partner_peer_id;
+        self.room.peers.get_mut(&partner_id)
+    }
+}
+
+impl CommandHandler for MemberCommands<'_> {
+    type Output = ();
+
+    /// Does nothing, as joining is handled by [`Rooms::join()`].
+    fn on_join_room(
+        &mut self,
+        _: MemberId,
+        _: Credential,
+        _: Capabilities,
+        _: ProtocolVersion,
+    ) -> Self::Output {
+    }
+
+    /// Does nothing, as leaving is handled by [`Rooms::leave()`].
+    fn on_leave_room(
+        &mut self,
+        _: MemberId,
+        _: Option<String>,
+    ) -> Self::Output {
+    }
+
+    /// Stores the provided SDP offer and passes it to the partner `Peer`,
+    /// creating it on the client side if it wasn't created before.
+    fn on_make_sdp_offer(
+        &mut self,
+        peer_id: PeerId,
+        sdp_offer: String,
+        mids: HashMap<TrackId, String>,
+        _: HashMap<TrackId, bool>,
+    ) -> Self::Output {
+        let Some(peer) = self.own_peer(peer_id) else {
+            return;
+        };
+        peer.state.local_sdp = Some(sdp_offer.clone());
+        set_mids(&mut peer.state, &mids);
+        self.room.send(
+            self.member_id,
+            Event::LocalDescriptionApplied {
+                peer_id,
+                sdp_offer: sdp_offer.clone(),
+            },
+        );
+
+        let Some(partner) = self.partner_peer(peer_id) else {
+            return;
+        };
+        let role = NegotiationRole::Answerer(sdp_offer.clone());
+        partner.state.remote_sdp = Some(sdp_offer);
+        partner.state.negotiation_role = Some(role.clone());
+        set_mids(&mut partner.state, &mids);
+
+        let events = if partner.is_known {
+            vec![Event::PeerUpdated {
+                peer_id: partner.state.id,
+                updates: Vec::new(),
+                negotiation_role: Some(role),
+            }]
+        } else {
+            partner.is_known = true;
+            let mut candidates = partner
+                .state
+                .ice_candidates
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>();
+            candidates.sort_unstable_by(|a, b| a.candidate.cmp(&b.candidate));
+            iter::once(peer_created(&partner.state))
+                .chain(candidates.into_iter().map(|candidate| {
+                    Event::IceCandidateDiscovered {
+                        peer_id: partner.state.id,
+                        candidate,
+                    }
+                }))
+                .collect()
+        };
+        let partner_member_id = partner.member_id.clone();
+        for event in events {
+            self.room.send(&partner_member_id, event);
+        }
+    }
+
+    /// Stores the provided SDP answer and passes it to the partner `Peer`.
+    fn on_make_sdp_answer(
+        &mut self,
+        peer_id: PeerId,
+        sdp_answer: String,
+        _: HashMap<TrackId, bool>,
+    ) -> Self::Output {
+        let Some(peer) = self.own_peer(peer_id) else {
+            return;
+        };
+        peer.state.local_sdp = Some(sdp_answer.clone());
+        peer.state.negotiation_role = None;
+        self.room.send(
+            self.member_id,
+            Event::LocalDescriptionApplied {
+                peer_id,
+                sdp_offer: sdp_answer.clone(),
+            },
+        );
+
+        let Some(partner) = self.partner_peer(peer_id) else {
+            return;
+        };
+        partner.state.remote_sdp = Some(sdp_answer.clone());
+        partner.state.negotiation_role = None;
+        let (partner_peer_id, partner_member_id) =
+            (partner.state.id, partner.member_id.clone());
+        self.room.send(
+            &partner_member_id,
+            Event::SdpAnswerMade { peer_id: partner_peer_id, sdp_answer },
+        );
+    }
+
+    /// Passes the provided [`IceCandidate`] to the partner `Peer`, or stores
+    /// it until the partner `Peer` is created on the client side.
+    fn on_set_ice_candidate(
+        &mut self,
+        peer_id: PeerId,
+        candidate: IceCandidate,
+    ) -> Self::Output {
+        if self.own_peer(peer_id).is_none() {
+            return;
+        }
+        let Some(partner) = self.partner_peer(peer_id) else {
+            return;
+        };
+        _ = partner.state.ice_candidates.insert(candidate.clone());
+        if partner.is_known {
+            let (partner_peer_id, partner_member_id) =
+                (partner.state.id, partner.member_id.clone());
+            self.room.send(
+                &partner_member_id,
+                Event::IceCandidateDiscovered {
+                    peer_id: partner_peer_id,
+                    candidate,
+                },
+            );
+        }
+    }
+
+    /// Logs the provided [`PeerMetrics`].
+    fn on_add_peer_connection_metrics(
+        &mut self,
+        peer_id: PeerId,
+        metrics: PeerMetrics,
+    ) -> Self::Output {
+        log::debug!("`Peer({}/{peer_id})` metrics: {metrics:?}", self.room.id,);
+    }
+
+    /// Applies the provided [`TrackPatchCommand`]s to both `Peer`s of the
+    /// pair, and notifies their [`Member`]s.
+    ///
+    /// Requests renegotiation from the requesting `Peer` if any
+    /// [`MediaDirection`] has been changed.
+    fn on_update_tracks(
+        &mut self,
+        peer_id: PeerId,
+        tracks_patches: Vec<TrackPatchCommand>,
+    ) -> Self::Output {
+        let Some(peer) = self.own_peer(peer_id) else {
+            return;
+        };
+
+        let mut patches = Vec::new();
+        let mut is_changed = false;
+        for patch in tracks_patches {
+            let (current, is_sender) = if let Some(s) =
+                peer.state.senders.get(&patch.id)
+            {
+                (s.media_direction, true)
+            } else if let Some(r) = peer.state.receivers.get(&patch.id) {
+                (r.media_direction, false)
+            } else {
+                log::warn!("`Peer({peer_id})` has no `Track({})`", patch.id);
+                continue;
+            };
+
+            let (mut send, mut recv) =
+                (current.is_send_enabled(), current.is_recv_enabled());
+            if let Some(enabled) = patch.enabled {
+                if is_sender {
+                    send = enabled;
+                } else {
+                    recv = enabled;
+                }
+            }
+            let direction = match (send, recv) {
+                (true, true) => MediaDirection::SendRecv,
+                (true, false) => MediaDirection::SendOnly,
+                (false, true) => MediaDirection::RecvOnly,
+                (false, false) => MediaDirection::Inactive,
+            };
+            is_changed |= direction != current;
+
+            patches.push(TrackPatchEvent {
+                media_direction: Some(direction),
+                muted: patch.muted,
+                ..TrackPatchEvent::new(patch.id)
+            });
+        }
+        for patch in &patches {
+            patch_track(&mut peer.state, patch);
+        }
+        let renegotiate = is_changed
+            && peer.state.negotiation_role.is_none()
+            && peer.state.local_sdp.is_some()
+            && peer.state.remote_sdp.is_some();
+        let negotiation_role = renegotiate.then_some(NegotiationRole::Offerer);
+        if renegotiate {
+            peer.state.negotiation_role.clone_from(&negotiation_role);
+        }
+
+        if let Some(partner) = self.partner_peer(peer_id) {
+            for patch in &patches {
+                patch_track(&mut partner.state, patch);
+            }
+            if partner.is_known {
+                let (partner_peer_id, partner_member_id) =
+                    (partner.state.id, partner.member_id.clone());
+                self.room.send(
+                    &partner_member_id,
+                    Event::PeerUpdated {
+                        peer_id: partner_peer_id,
+                        updates: patches
+                            .iter()
+                            .cloned()
+                            .map(PeerUpdate::Updated)
+                            .collect(),
+                        negotiation_role: None,
+                    },
+                );
+            }
+        }
+
+        self.room.send(
+            self.member_id,
+            Event::PeerUpdated {
+                peer_id,
+                updates: patches.into_iter().map(PeerUpdate::Updated).collect(),
+                negotiation_role,
+            },
+        );
+    }
+
+    /// Sends the actual server-side state of the [`Room`] to the [`Member`].
+    fn on_synchronize_me(&mut self, _: state::Room) -> Self::Output {
+        let state = state::Room {
+            peers: self
+                .room
+                .peers
+                .iter()
+                .filter(|(_, p)| p.member_id == *self.member_id && p.is_known)
+                .map(|(id, p)| (*id, p.state.clone()))
+                .collect(),
+            members: self
+                .room
+                .roster()
+                .into_iter()
+                .map(|m| (m.id.clone(), m))
+                .collect(),
+        };
+        self.room.send(self.member_id, Event::StateSynchronized { state });
+    }
+}
+
+/// Returns [`MediaType`]s of the `Track`s sent from one [`Member`] to another
+/// one according to the provided [`room::Spec`], along with an indicator
+/// whether media should be relayed via TURN forcibly.
+fn tracks_between(
+    spec: &room::Spec,
+    room_id: &room::Id,
+    from: &MemberId,
+    to: &MemberId,
+) -> (Vec<MediaType>, bool) {
+    let member = |id: &MemberId| {
+        spec.pipeline
+            .get(&member::Id::from(id.clone()))
+            .map(|PipelineSpec::Member(m)| m)
+    };
+    let (Some(sender), Some(receiver)) = (member(from), member(to)) else {
+        return (Vec::new(), false);
+    };
+    let sender_id = member::Id::from(from.clone());
+
+    let mut tracks = Vec::new();
+    let mut force_relay = false;
+    #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+    for play in receiver.pipeline.values() {
+        let endpoint::Spec::WebRtcPlayEndpoint(play) = play else {
+            continue;
+        };
+        if play.src.room_id != *room_id || play.src.member_id != sender_id {
+            continue;
+        }
+        let publish_id: &endpoint::Id = play.src.endpoint_id.as_ref();
+        let Some(endpoint::Spec::WebRtcPublishEndpoint(publish)) =
+            sender.pipeline.get(publish_id)
+        else {
+            continue;
+        };
+        if publish.p2p == P2pMode::Never {
+            log::warn!(
+                "`WebRtcPublishEndpoint({room_id}/{from}/{publish_id})` \
+                 requires a media server, skipping",
+            );
+            continue;
+        }
+        force_relay |= publish.force_relay || play.force_relay;
+
+        let audio = publish.audio_settings.publish_policy;
+        let video = publish.video_settings.publish_policy;
+        let mut media_types = Vec::new();
+        if audio != Policy::Disabled {
+            media_types.push(MediaType::Audio(AudioSettings {
+                required: audio == Policy::Required,
+                source_kind: MediaSourceKind::Device,
+            }));
+        }
+        if video != Policy::Disabled {
+            media_types.push(MediaType::Video(VideoSettings {
+                required: video == Policy::Required,
+                source_kind: MediaSourceKind::Device,
+                encoding_parameters: Vec::new(),
+            }));
+            media_types.push(MediaType::Video(VideoSettings {
+                required: false,
+                source_kind: MediaSourceKind::Display,
+                encoding_parameters: Vec::new(),
+            }));
+        }
+        tracks.extend(media_types.into_iter().filter(|t| play.is_playable(t)));
+    }
+    (tracks, force_relay)
+}
+
+/// Creates a new [`state::Peer`] without any `Track`s.
+fn new_peer_state(
+    id: PeerId,
+    force_relay: bool,
+    ice_servers: Vec<IceServer>,
+) -> state::Peer {
+    state::Peer {
+        id,
+        connection_mode: ConnectionMode::Mesh,
+        senders: HashMap::new(),
+        receivers: HashMap::new(),
+        force_relay,
+        ice_servers,
+        negotiation_role: None,
+        local_sdp: None,
+        remote_sdp: None,
+        restart_ice: false,
+        ice_candidates: HashSet::new(),
+        stats_scrape_interval_ms: 0,
+    }
+}
+
+/// Builds [`Event::PeerCreated`] for the provided [`state::Peer`].
+fn peer_created(peer: &state::Peer) -> Event {
+    let mut tracks = peer
+        .senders
+        .values()
+        .map(|s| Track {
+            id: s.id,
+            direction: Direction::Send {
+                receivers: s.receivers.clone(),
+                mid: s.mid.clone(),
+            },
+            media_direction: s.media_direction,
+            muted: s.muted,
+            media_type: s.media_type.clone(),
+        })
+        .chain(peer.receivers.values().map(|r| Track {
+            id: r.id,
+            direction: Direction::Recv {
+                sender: r.sender_id.clone(),
+                mid: r.mid.clone(),
+            },
+            media_direction: r.media_direction,
+            muted: r.muted,
+            media_type: r.media_type.clone(),
+        }))
+        .collect::<Vec<_>>();
+    tracks.sort_unstable_by_key(|t| t.id.0);
+
+    Event::PeerCreated {
+        peer_id: peer.id,
+        negotiation_role: peer
+            .negotiation_role
+            .clone()
+            .unwrap_or(NegotiationRole::Offerer),
+        connection_mode: peer.connection_mode,
+        tracks,
+        ice_servers: peer.ice_servers.clone(),
+        force_relay: peer.force_relay,
+        stats_scrape_interval_ms: peer.stats_scrape_interval_ms,
+    }
+}
+
+/// Adds a new `Track` sent from one [`state::Peer`] to another one.
+fn add_track(
+    id: TrackId,
+    media_type: MediaType,
+    (sender_id, sender): (&MemberId, &mut state::Peer),
+    (receiver_id, receiver): (&MemberId, &mut state::Peer),
+) {
+    drop(sender.senders.insert(
+        id,
+        state::Sender {
+            id,
+            connection_mode: ConnectionMode::Mesh,
+            mid: None,
+            media_type: media_type.clone(),
+            receivers: vec![receiver_id.clone()],
+            muted: false,
+            media_direction: MediaDirection::SendRecv,
+        },
+    ));
+    drop(receiver.receivers.insert(
+        id,
+        state::Receiver {
+            id,
+            connection_mode: ConnectionMode::Mesh,
+            mid: None,
+            media_type,
+            sender_id: sender_id.clone(),
+            muted: false,
+            media_direction: MediaDirection::SendRecv,
+        },
+    ));
+}
+
+/// Sets the provided `mid`s to the `Track`s of the provided [`state::Peer`].
+fn set_mids(peer: &mut state::Peer, mids: &HashMap<TrackId, String>) {
+    #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+    for (id, mid) in mids {
+        if let Some(s) = peer.senders.get_mut(id) {
+            s.mid = Some(mid.clone());
+        }
+        if let Some(r) = peer.receivers.get_mut(id) {
+            r.mid = Some(mid.clone());
+        }
+    }
+}
+
+/// Applies the provided [`TrackPatchEvent`] to the `Track` of the provided
+/// [`state::Peer`].
+fn patch_track(peer: &mut state::Peer, patch: &TrackPatchEvent) {
+    if let Some(s) = peer.senders.get_mut(&patch.id) {
+        if let Some(direction) = patch.media_direction {
+            s.media_direction = direction;
+        }
+        if let Some(muted) = patch.muted {
+            s.muted = muted;
+        }
+    }
+    if let Some(r) = peer.receivers.get_mut(&patch.id) {
+        if let Some(direction) = patch.media_direction {
+            r.media_direction = direction;
+        }
+        if let Some(muted) = patch.muted {
+            r.muted = muted;
+        }
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use actix::{Actor, Context, Handler};
+    use futures::{StreamExt as _, channel::mpsc};
+    use medea_control_api_proto::RootElement;
+
+    use super::*;
+
+    // language=YAML
+    const SPEC: &str = r#"
+kind: Room
+id: test-room
+spec:
+  pipeline:
+    alice:
+      kind: Member
+      spec:
+        metadata:
+          display_name: Alice
+        pipeline:
+          publish:
+            kind: WebRtcPublishEndpoint
+            spec:
+              p2p: Always
+          play-bob:
+            kind: WebRtcPlayEndpoint
+            spec:
+              src: "local://test-room/bob/publish"
+    bob:
+      kind: Member
+      spec:
+        pipeline:
+          publish:
+            kind: WebRtcPublishEndpoint
+            spec:
+              p2p: Always
+              audio_settings:
+                publish_policy: Disabled
+          play-alice:
+            kind: WebRtcPlayEndpoint
+            spec:
+              src: "local://test-room/alice/publish"
+    carol:
+      kind: Member
+      spec:
+        pipeline:
+          play-alice:
+            kind: WebRtcPlayEndpoint
+            spec:
+              src: "local://test-room/alice/publish"
+    dave:
+      kind: Member
+      spec:
+        pipeline:
+          publish:
+            kind: WebRtcPublishEndpoint
+            spec:
+              p2p: Never
+          play-alice:
+            kind: WebRtcPlayEndpoint
+            spec:
+              src: "local://test-room/alice/publish"
+              force_relay: true
+"#;
+
+    /// Client receiving all the [`Outgoing`] messages of a [`Room`].
+    struct Client(mpsc::UnboundedSender<Outgoing>);
+
+    impl Actor for Client {
+        type Context = Context<Self>;
+    }
+
+    impl Handler<Outgoing> for Client {
+        type Result = ();
+
+        fn handle(&mut self, msg: Outgoing, _: &mut Self::Context) {
+            drop(self.0.unbounded_send(msg));
+        }
+    }
+
+    /// Test environment of a single [`Room`].
+    struct TestRoom {
+        /// [`Room`] being tested.
+        room: Room,
+
+        /// [`room::Spec`] of the tested [`Room`].
+        spec: room::Spec,
+
+        /// [`Settings`] the [`Member`]s are joined with.
+        settings: Settings,
+    }
+
+    impl TestRoom {
+        /// Creates a new empty [`TestRoom`] described by the [`SPEC`].
+        fn new() -> Self {
+            let RootElement::Room(room) =
+                serde_yaml::from_str(SPEC).expect("invalid `SPEC`");
+            Self {
+                room: Room::new(RoomId(room.id.to_string())),
+                spec: room.spec,
+                settings: Settings {
+                    ping_interval: Duration::from_secs(3),
+                    idle_timeout: Duration::from_secs(10),
+                    reconnect_timeout: Duration::from_secs(10),
+                    ice_urls: Vec::new(),
+                    turn_secret: None,
+                    turn_ttl: Duration::from_hours(1),
+                },
+            }
+        }
+
+        /// Joins the provided [`Member`] to the tested [`Room`], returning
+        /// the receiver of the messages sent to it.
+        fn join(&mut self, id: &str) -> mpsc::UnboundedReceiver<Outgoing> {
+            let (tx, rx) = mpsc::unbounded();
+            let conn = Client(tx).start().recipient();
+            let member_id = MemberId::from(id);
+            let PipelineSpec::Member(spec) = self
+                .spec
+                .pipeline
+                .get(&member::Id::from(member_id.clone()))
+                .expect("unknown `Member`")
+                .clone();
+            _ = self.room.join(
+                &member_id,
+                &spec,
+                conn,
+                &self.spec,
+                &self.settings,
+            );
+            rx
+        }
+
+        /// Dispatches the provided [`Command`] of the provided [`Member`].
+        fn command(&mut self, id: &str, command: Command) {
+            command.dispatch_with(&mut MemberCommands {
+                room: &mut self.room,
+                member_id: &MemberId::from(id),
+            });
+        }
+    }
+
+    /// Returns the next [`Event`] received by a [`Client`].
+    async fn next_event(rx: &mut mpsc::UnboundedReceiver<Outgoing>) -> Event {
+        let msg = rt::time::timeout(Duration::from_secs(1), rx.next())
+            .await
+            .expect("no `Outgoing` message received")
+            .expect("`Client` is gone");
+        let Outgoing::Msg(ServerMsg::Event { event, .. }) = msg else {
+            panic!("unexpected `Outgoing` message: {msg:?}");
+        };
+        event
+    }
+
+    /// Asserts that no more messages are received by a [`Client`].
+    async fn assert_no_more(rx: &mut mpsc::UnboundedReceiver<Outgoing>) {
+        let msg =
+            rt::time::timeout(Duration::from_millis(100), rx.next()).await;
+        assert!(msg.is_err(), "unexpected `Outgoing` message: {msg:?}");
+    }
+
+    /// Returns an [`IceCandidate`] with the provided `candidate-attribute`.
+    fn candidate(candidate: &str) -> IceCandidate {
+        IceCandidate {
+            candidate: candidate.into(),
+            sdp_m_line_index: Some(0),
+            sdp_mid: Some("0".into()),
+        }
+    }
+
+    #[actix_web::test]
+    async fn joined_member_receives_roster() {
+        let mut room = TestRoom::new();
+
+        let mut alice = room.join("alice");
+        let Event::RoomJoined { member_id, is_reconnect, members } =
+            next_event(&mut alice).await
+        else {
+            panic!("expected `Event::RoomJoined`");
+        };
+        assert_eq!(member_id, MemberId::from("alice"), "wrong `MemberId`");
+        assert!(!is_reconnect, "first join is not a reconnect");
+        assert_eq!(
+            members,
+            [proto::Member {
+                id: "alice".into(),
+                metadata: HashMap::from([(
+                    "display_name".into(),
+                    "Alice".into(),
+                )]),
+            }],
+            "wrong roster",
+        );
+
+        let mut carol = room.join("carol");
+        let Event::RoomJoined { members: roster, .. } =
+            next_event(&mut carol).await
+        else {
+            panic!("expected `Event::RoomJoined`");
+        };
+        let ids = roster.into_iter().map(|m| m.id.0).collect::<Vec<_>>();
+        assert_eq!(ids, ["alice", "carol"], "wrong roster");
+
+        assert_eq!(
+            next_event(&mut alice).await,
+            Event::MemberJoined {
+                member: proto::Member {
+                    id: "carol".into(),
+                    metadata: HashMap::new(),
+                },
+            },
+            "`MemberJoined` is not broadcast",
+        );
+    }
+
+    #[actix_web::test]
+    async fn last_joined_member_is_offerer() {
+        let mut room = TestRoom::new();
+        let mut alice = room.join("alice");
+        drop(next_event(&mut alice).await);
+
+        let mut bob = room.join("bob");
+        drop(next_event(&mut bob).await);
+        let Event::PeerCreated { peer_id, negotiation_role, tracks, .. } =
+            next_event(&mut bob).await
+        else {
+            panic!("expected `Event::PeerCreated`");
+        };
+        assert_eq!(peer_id, PeerId(1), "wrong `PeerId`");
+        assert_eq!(
+            negotiation_role,
+            NegotiationRole::Offerer,
+            "wrong `NegotiationRole`",
+        );
+        let (sent, received): (Vec<_>, Vec<_>) = tracks
+            .iter()
+            .partition(|t| matches!(t.direction, Direction::Send { .. }));
+        assert_eq!(sent.len(), 2, "`bob` sends device and display video");
+        assert_eq!(received.len(), 3, "`bob` receives audio and videos");
+
+        assert!(
+            matches!(next_event(&mut alice).await, Event::MemberJoined { .. }),
+            "expected `Event::MemberJoined`",
+        );
+        assert_no_more(&mut alice).await;
+    }
+
+    #[actix_web::test]
+    async fn no_peers_without_media_exchange() {
+        let mut room = TestRoom::new();
+        let mut bob = room.join("bob");
+        drop(next_event(&mut bob).await);
+
+        let mut carol = room.join("carol");
+        drop(next_event(&mut carol).await);
+        assert_no_more(&mut carol).await;
+        assert!(room.room.peers.is_empty(), "no `Peer`s should be created");
+    }
+
+    #[actix_web::test]
+    async fn negotiation() {
+        let mut room = TestRoom::new();
+        let mut alice = room.join("alice");
+        drop(next_event(&mut alice).await);
+        let mut bob = room.join("bob");
+        drop(next_event(&mut bob).await);
+        drop(next_event(&mut bob).await);
+        drop(next_event(&mut alice).await);
+
+        // Candidates are buffered until the partner `Peer` is created.
+        room.command(
+            "bob",
+            Command::SetIceCandidate {
+                peer_id: PeerId(1),
+                candidate: candidate("bob-1"),
+            },
+        );
+        assert_no_more(&mut alice).await;
+
+        let mids = room.room.peers[&PeerId(1)]
+            .state
+            .senders
+            .keys()
+            .chain(room.room.peers[&PeerId(1)].state.receivers.keys())
+            .map(|id| (*id, id.to_string()))
+            .collect::<HashMap<_, _>>();
+        room.command(
+            "bob",
+            Command::MakeSdpOffer {
+                peer_id: PeerId(1),
+                sdp_offer: "offer".into(),
+                mids: mids.clone(),
+                transceivers_statuses: HashMap::new(),
+            },
+        );
+        assert_eq!(
+            next_event(&mut bob).await,
+            Event::LocalDescriptionApplied {
+                peer_id: PeerId(1),
+                sdp_offer: "offer".into(),
+            },
+            "offer is not acknowledged",
+        );
+        let Event::PeerCreated { peer_id, negotiation_role, tracks, .. } =
+            next_event(&mut alice).await
+        else {
+            panic!("expected `Event::PeerCreated`");
+        };
+        assert_eq!(peer_id, PeerId(2), "wrong `PeerId`");
+        assert_eq!(
+            negotiation_role,
+            NegotiationRole::Answerer("offer".into()),
+            "wrong `NegotiationRole`",
+        );
+        assert!(
+            tracks.iter().all(|t| match &t.direction {
+                Direction::Send { mid, .. } | Direction::Recv { mid, .. } => {
+                    mid.as_ref() == mids.get(&t.id)
+                }
+            }),
+            "`mid`s are not propagated",
+        );
+        assert_eq!(
+            next_event(&mut alice).await,
+            Event::IceCandidateDiscovered {
+                peer_id: PeerId(2),
+                candidate: candidate("bob-1"),
+            },
+            "buffered candidate is not sent",
+        );
+
+        room.command(
+            "alice",
+            Command::MakeSdpAnswer {
+                peer_id: PeerId(2),
+                sdp_answer: "answer".into(),
+                transceivers_statuses: HashMap::new(),
+            },
+        );
+        assert_eq!(
+            next_event(&mut alice).await,
+            Event::LocalDescriptionApplied {
+                peer_id: PeerId(2),
+                sdp_offer: "answer".into(),
+            },
+            "answer is not acknowledged",
+        );
+        assert_eq!(
+            next_event(&mut bob).await,
+            Event::SdpAnswerMade {
+                peer_id: PeerId(1),
+                sdp_answer: "answer".into(),
+            },
+            "answer is not passed",
+        );
+
+        room.command(
+            "alice",
+            Command::SetIceCandidate {
+                peer_id: PeerId(2),
+                candidate: candidate("alice-1"),
+            },
+        );
+        assert_eq!(
+            next_event(&mut bob).await,
+            Event::IceCandidateDiscovered {
+                peer_id: PeerId(1),
+                candidate: candidate("alice-1"),
+            },
+            "candidate is not passed",
+        );
+
+        let peer = &room.room.peers[&PeerId(1)].state;
+        assert_eq!(peer.local_sdp.as_deref(), Some("offer"), "wrong local SDP");
+        assert_eq!(
+            peer.remote_sdp.as_deref(),
+            Some("answer"),
+            "wrong remote SDP",
+        );
+        assert_eq!(peer.negotiation_role, None, "negotiation is finished");
+    }
+
+    #[actix_web::test]
+    async fn commands_for_foreign_peers_are_ignored() {
+        let mut room = TestRoom::new();
+        let mut alice = room.join("alice");
+        drop(next_event(&mut alice).await);
+        let mut bob = room.join("bob");
+        drop(next_event(&mut bob).await);
+        drop(next_event(&mut bob).await);
+        drop(next_event(&mut alice).await);
+
+        room.command(
+            "alice",
+            Command::MakeSdpOffer {
+                peer_id: PeerId(1),
+                sdp_offer: "offer".into(),
+                mids: HashMap::new(),
+                transceivers_statuses: HashMap::new(),
+            },
+        );
+        assert_no_more(&mut alice).await;
+        assert_no_more(&mut bob).await;
+        assert_eq!(
+            room.room.peers[&PeerId(1)].state.local_sdp,
+            None,
+            "foreign `Peer` is changed",
+        );
+    }
+
+    #[actix_web::test]
+    async fn disabling_track_requests_renegotiation() {
+        let mut room = TestRoom::new();
+        let mut alice = room.join("alice");
+        drop(next_event(&mut alice).await);
+        let mut bob = room.join("bob");
+        drop(next_event(&mut bob).await);
+        drop(next_event(&mut bob).await);
+        drop(next_event(&mut alice).await);
+
+        room.command(
+            "bob",
+            Command::MakeSdpOffer {
+                peer_id: PeerId(1),
+                sdp_offer: "offer".into(),
+                mids: HashMap::new(),
+                transceivers_statuses: HashMap::new(),
+            },
+        );
+        room.command(
+            "alice",
+            Command::MakeSdpAnswer {
+                peer_id: PeerId(2),
+                sdp_answer: "answer".into(),
+                transceivers_statuses: HashMap::new(),
+            },
+        );
+        for _ in 0..2 {
+            drop(next_event(&mut bob).await);
+        }
+        for _ in 0..2 {
+            drop(next_event(&mut alice).await);
+        }
+
+        let track_id = *room.room.peers[&PeerId(1)]
+            .state
+            .senders
+            .keys()
+            .next()
+            .expect("`bob` has no senders");
+        room.command(
+            "bob",
+            Command::UpdateTracks {
+                peer_id: PeerId(1),
+                tracks_patches: vec![TrackPatchCommand {
+                    id: track_id,
+                    enabled: Some(false),
+                    muted: None,
+                }],
+            },
+        );
+
+        let patch = TrackPatchEvent {
+            media_direction: Some(MediaDirection::RecvOnly),
+            muted: None,
+            ..TrackPatchEvent::new(track_id)
+        };
+        assert_eq!(
+            next_event(&mut alice).await,
+            Event::PeerUpdated {
+                peer_id: PeerId(2),
+                updates: vec![PeerUpdate::Updated(patch.clone())],
+                negotiation_role: None,
+            },
+            "partner is not notified",
+        );
+        assert_eq!(
+            next_event(&mut bob).await,
+            Event::PeerUpdated {
+                peer_id: PeerId(1),
+                updates: vec![PeerUpdate::Updated(patch)],
+                negotiation_role: Some(NegotiationRole::Offerer),
+            },
+            "renegotiation is not requested",
+        );
+        assert_eq!(
+            room.room.peers[&PeerId(2)].state.receivers[&track_id]
+                .media_direction,
+            MediaDirection::RecvOnly,
+            "partner `Peer` is not patched",
+        );
+    }
+
+    #[actix_web::test]
+    async fn synchronize_me_returns_known_peers_and_roster() {
+        let mut room = TestRoom::new();
+        let mut alice = room.join("alice");
+        drop(next_event(&mut alice).await);
+        let mut bob = room.join("bob");
+        drop(next_event(&mut bob).await);
+        drop(next_event(&mut bob).await);
+        drop(next_event(&mut alice).await);
+
+        room.command(
+            "alice",
+            Command::SynchronizeMe {
+                state: state::Room {
+                    peers: HashMap::new(),
+                    members: HashMap::new(),
+                },
+            },
+        );
+        let Event::StateSynchronized { state } = next_event(&mut alice).await
+        else {
+            panic!("expected `Event::StateSynchronized`");
+        };
+        assert!(state.peers.is_empty(), "`alice` doesn't know its `Peer` yet");
+        assert_eq!(state.members.len(), 2, "wrong roster");
+
+        room.command(
+            "bob",
+            Command::SynchronizeMe {
+                state: state::Room {
+                    peers: HashMap::new(),
+                    members: HashMap::new(),
+                },
+            },
+        );
+        let Event::StateSynchronized { state: bob_state } =
+            next_event(&mut bob).await
+        else {
+            panic!("expected `Event::StateSynchronized`");
+        };
+        assert_eq!(
+            bob_state.peers.keys().copied().collect::<Vec<_>>(),
+            [PeerId(1)],
+            "wrong `Peer`s",
+        );
+    }
+
+    #[actix_web::test]
+    async fn rejoin_is_reconnect() {
+        let mut room = TestRoom::new();
+        let mut alice = room.join("alice");
+        drop(next_event(&mut alice).await);
+        let mut carol = room.join("carol");
+        drop(next_event(&mut carol).await);
+        drop(next_event(&mut carol).await);
+        drop(next_event(&mut alice).await);
+
+        let mut alice_new = room.join("alice");
+        let msg = rt::time::timeout(Duration::from_secs(1), alice.next())
+            .await
+            .expect("old connection is not closed");
+        assert!(
+            matches!(msg, Some(Outgoing::Close(CloseReason::Reconnected))),
+            "wrong `CloseReason`: {msg:?}",
+        );
+        let Event::RoomJoined { is_reconnect, .. } =
+            next_event(&mut alice_new).await
+        else {
+            panic!("expected `Event::RoomJoined`");
+        };
+        assert!(is_reconnect, "rejoin is a reconnect");
+        assert_no_more(&mut carol).await;
+    }
+
+    #[actix_web::test]
+    async fn removed_member_is_cleaned_up() {
+        let mut room = TestRoom::new();
+        let mut alice = room.join("alice");
+        drop(next_event(&mut alice).await);
+        let mut bob = room.join("bob");
+        drop(next_event(&mut bob).await);
+        drop(next_event(&mut bob).await);
+        drop(next_event(&mut alice).await);
+
+        room.room.remove_member(&"alice".into());
+
+        assert_eq!(
+            next_event(&mut bob).await,
+            Event::PeersRemoved { peer_ids: vec![PeerId(1)] },
+            "partner `Peer` is not removed",
+        );
+        assert_eq!(
+            next_event(&mut bob).await,
+            Event::MemberLeft { member_id: "alice".into() },
+            "`MemberLeft` is not broadcast",
+        );
+        assert!(room.room.peers.is_empty(), "`Peer`s are not removed");
+        assert_eq!(room.room.roster().len(), 1, "`Member` is not removed");
+    }
+
+    #[test]
+    fn tracks_between_follows_spec() {
+        let TestRoom { spec, .. } = TestRoom::new();
+        let room_id = room::Id::from("test-room".to_owned());
+        let between = |from: &str, to: &str| {
+            tracks_between(&spec, &room_id, &from.into(), &to.into())
+        };
+
+        assert_eq!(
+            between("alice", "carol"),
+            (
+                vec![
+                    MediaType::Audio(AudioSettings {
+                        required: false,
+                        source_kind: MediaSourceKind::Device,
+                    }),
+                    MediaType::Video(VideoSettings {
+                        required: false,
+                        source_kind: MediaSourceKind::Device,
+                        encoding_parameters: Vec::new(),
+                    }),
+                    MediaType::Video(VideoSettings {
+                        required: false,
+                        source_kind: MediaSourceKind::Display,
+                        encoding_parameters: Vec::new(),
+                    }),
+                ],
+                false,
+            ),
+            "audio, device and display video are sent",
+        );
+        assert!(
+            between("bob", "alice")
+                .0
+                .iter()
+                .all(|t| matches!(t, MediaType::Video(_))),
+            "`bob` doesn't publish audio",
+        );
+        assert!(between("alice", "dave").1, "relay is forced by `dave`");
+        assert!(
+            between("dave", "alice").0.is_empty(),
+            "`alice` doesn't play `dave`",
+        );
+        assert!(
+            between("carol", "alice").0.is_empty(),
+            "`carol` doesn't publish",
+        );
+    }
+}
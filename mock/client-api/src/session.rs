@@ -0,0 +1,195 @@
+//! Transport-agnostic session of a [Client API] client.
+//!
+//! [Client API]: https://tinyurl.com/266y74tf
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use actix::{Actor, AsyncContext, Handler, SpawnHandle, dev::ToEnvelope};
+use medea_client_api_proto::{
+    ClientMsg, CloseReason, Command, MemberId, ProtocolVersion, RoomId,
+    RpcSettings, ServerMsg,
+};
+use tracing as log;
+
+use crate::room::{Outgoing, Rooms};
+
+/// State of a [`Session`], shared by all its transports.
+#[derive(Debug)]
+pub struct State {
+    /// [`Rooms`] this [`Session`] interacts with.
+    rooms: Rooms,
+
+    /// `Room`s joined via this [`Session`], along with the IDs of the joined
+    /// `Member`s and generations of their connections.
+    joined: HashMap<RoomId, (MemberId, u64)>,
+
+    /// Current [`RpcSettings`] of this [`Session`].
+    settings: RpcSettings,
+
+    /// [`ServerMsg::Ping`]s counter.
+    last_ping_num: u32,
+
+    /// [`Instant`] when the last message has been received from the client.
+    last_activity: Instant,
+
+    /// Handle of the heartbeat task.
+    heartbeat: Option<SpawnHandle>,
+}
+
+impl State {
+    /// Creates a new [`State`] with the default [`RpcSettings`].
+    #[must_use]
+    pub fn new(rooms: Rooms) -> Self {
+        let settings = rooms.settings().rpc(None);
+        Self {
+            rooms,
+            joined: HashMap::new(),
+            settings,
+            last_ping_num: 0,
+            last_activity: Instant::now(),
+            heartbeat: None,
+        }
+    }
+
+    /// Marks the client as being active right now.
+    pub fn touch(&mut self) {
+        self.last_activity = Instant::now();
+    }
+}
+
+/// Session of a [Client API] client over some transport.
+///
+/// [Client API]: https://tinyurl.com/266y74tf
+pub trait Session:
+    Actor<Context: AsyncContext<Self> + ToEnvelope<Self, Outgoing>>
+    + Handler<Outgoing>
+{
+    /// Returns the [`State`] of this [`Session`].
+    fn state(&mut self) -> &mut State;
+
+    /// Sends the provided [`ServerMsg`] to the client.
+    fn send(&mut self, ctx: &mut Self::Context, msg: &ServerMsg);
+
+    /// Closes this [`Session`] with the provided [`CloseReason`].
+    fn close(&mut self, ctx: &mut Self::Context, reason: CloseReason);
+
+    /// Sends the default [`RpcSettings`] and starts heartbeat.
+    fn start(&mut self, ctx: &mut Self::Context) {
+        let settings = self.state().settings;
+        self.send(ctx, &ServerMsg::RpcSettings(settings));
+        self.start_heartbeat(ctx);
+    }
+
+    /// Marks connections of all the joined `Member`s as lost.
+    fn stop(&mut self) {
+        let state = self.state();
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for (room_id, (member_id, generation)) in state.joined.drain() {
+            state.rooms.disconnect(&room_id, &member_id, generation);
+        }
+    }
+
+    /// (Re)starts sending [`ServerMsg::Ping`]s and checking the client for
+    /// being idle, according to the current [`RpcSettings`].
+    fn start_heartbeat(&mut self, ctx: &mut Self::Context) {
+        if let Some(handle) = self.state().heartbeat.take() {
+            _ = ctx.cancel_future(handle);
+        }
+
+        let interval = Duration::from_millis(
+            self.state().settings.ping_interval_ms.into(),
+        );
+        let handle = ctx.run_interval(interval, |this, ctx| {
+            let state = this.state();
+            let idle_timeout =
+                Duration::from_millis(state.settings.idle_timeout_ms.into());
+            if state.last_activity.elapsed() > idle_timeout {
+                log::info!("Client is idle, closing its connection");
+                this.close(ctx, CloseReason::Idle);
+                return;
+            }
+
+            state.last_ping_num += 1;
+            let num = state.last_ping_num;
+            this.send(ctx, &ServerMsg::Ping(num));
+        });
+        self.state().heartbeat = Some(handle);
+    }
+
+    /// Handles the provided [`ClientMsg`].
+    fn handle_msg(&mut self, msg: ClientMsg, ctx: &mut Self::Context) {
+        let (room_id, command) = match msg {
+            ClientMsg::Command { room_id, command } => (room_id, command),
+            ClientMsg::Ping(num) => {
+                self.send(ctx, &ServerMsg::Pong(num));
+                return;
+            }
+            ClientMsg::Pong(_) => return,
+        };
+
+        match command {
+            Command::JoinRoom {
+                member_id,
+                credential,
+                capabilities: _,
+                protocol_version,
+            } => {
+                if !protocol_version
+                    .is_compatible_with(ProtocolVersion::CURRENT)
+                {
+                    log::info!(
+                        "`Member({member_id})` speaks incompatible protocol \
+                         version {protocol_version}",
+                    );
+                    self.close(ctx, CloseReason::IncompatibleProtocol);
+                    return;
+                }
+
+                let conn = ctx.address().recipient();
+                let state = self.state();
+                if let Some(generation) =
+                    state.rooms.join(&room_id, &member_id, &credential, conn)
+                {
+                    drop(state.joined.insert(room_id, (member_id, generation)));
+                }
+            }
+            Command::LeaveRoom { member_id: _, reason } => {
+                let state = self.state();
+                if let Some((member_id, _)) = state.joined.remove(&room_id) {
+                    state.rooms.leave(&room_id, &member_id, reason.as_deref());
+                }
+            }
+            command @ (Command::MakeSdpOffer { .. }
+            | Command::MakeSdpAnswer { .. }
+            | Command::SetIceCandidate { .. }
+            | Command::AddPeerConnectionMetrics { .. }
+            | Command::UpdateTracks { .. }
+            | Command::SynchronizeMe { .. }) => {
+                let state = self.state();
+                if let Some((member_id, _)) = state.joined.get(&room_id) {
+                    state.rooms.command(&room_id, member_id, command);
+                } else {
+                    log::warn!("`Command` for not joined `Room({room_id})`");
+                }
+            }
+        }
+    }
+
+    /// Handles the provided [`Outgoing`] message of a `Room`.
+    fn handle_outgoing(&mut self, msg: Outgoing, ctx: &mut Self::Context) {
+        match msg {
+            Outgoing::Msg(ServerMsg::RpcSettings(settings)) => {
+                if settings != self.state().settings {
+                    self.state().settings = settings;
+                    self.start_heartbeat(ctx);
+                    self.send(ctx, &ServerMsg::RpcSettings(settings));
+                }
+            }
+            Outgoing::Msg(msg) => self.send(ctx, &msg),
+            Outgoing::Close(reason) => self.close(ctx, reason),
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! [Server-Sent Events] fallback transport of [Client API], for clients whose
+//! networks block [WebSocket] connections.
+//!
+//! [`ServerMsg`]s are streamed to a client as [Server-Sent Events], while
+//! [`ClientMsg`]s are received via separate HTTP `POST` requests.
+//!
+//! [Client API]: https://tinyurl.com/266y74tf
+//! [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+//! [WebSocket]: https://en.wikipedia.org/wiki/WebSocket
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use actix::{Actor, ActorContext as _, Addr, Context, Handler, Message};
+use actix_web::{
+    HttpResponse,
+    http::header,
+    web::{Bytes, Data, Path},
+};
+use futures::{StreamExt as _, channel::mpsc};
+use medea_client_api_proto::{
+    ClientMsg, CloseDescription, CloseReason, ServerMsg,
+};
+use rand::{RngExt as _, distr::Alphanumeric};
+use tracing as log;
+
+use crate::{
+    room::{Outgoing, Rooms},
+    session::{Session, State},
+};
+
+/// Registry of all the active [`Connection`]s by their IDs.
+#[derive(Clone, Debug, Default)]
+pub struct Connections(Arc<Mutex<HashMap<String, Addr<Connection>>>>);
+
+impl Connections {
+    /// Locks the underlying [`HashMap`] of [`Connection`]s.
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, Addr<Connection>>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns [`Addr`] of the [`Connection`] with the provided ID, if any.
+    fn get(&self, id: &str) -> Option<Addr<Connection>> {
+        self.lock().get(id).cloned()
+    }
+}
+
+/// Starts a new [`Connection`] streaming [`ServerMsg`]s as [Server-Sent
+/// Events].
+///
+/// The first event of the stream is a `session` event carrying the ID of the
+/// started [`Connection`], which should be used by the client to send its
+/// [`ClientMsg`]s.
+///
+/// [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+pub fn connect(rooms: &Rooms, sessions: &Connections) -> HttpResponse {
+    let id = rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect::<String>();
+    let (tx, rx) = mpsc::unbounded();
+
+    let session = Connection {
+        state: State::new(rooms.clone()),
+        id: id.clone(),
+        sessions: sessions.clone(),
+        tx,
+    };
+    drop(sessions.lock().insert(id, session.start()));
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .insert_header((header::ACCESS_CONTROL_ALLOW_ORIGIN, "*"))
+        .streaming(rx.map(Ok::<_, actix_web::Error>))
+}
+
+/// Passes [`ClientMsg`] from the HTTP request body to the [`Connection`] with
+/// the ID from the request path.
+pub async fn send(
+    id: Path<String>,
+    body: String,
+    sessions: Data<Connections>,
+) -> HttpResponse {
+    let Some(session) = sessions.get(&id) else {
+        return HttpResponse::NotFound()
+            .insert_header((header::ACCESS_CONTROL_ALLOW_ORIGIN, "*"))
+            .finish();
+    };
+    match serde_json::from_str::<ClientMsg>(&body) {
+        Ok(msg) => {
+            session.do_send(Incoming(msg));
+            HttpResponse::NoContent()
+        }
+        Err(e) => {
+            log::warn!("Failed to parse `ClientMsg`: {e}");
+            HttpResponse::BadRequest()
+        }
+    }
+    .insert_header((header::ACCESS_CONTROL_ALLOW_ORIGIN, "*"))
+    .finish()
+}
+
+/// Closes the [`Connection`] with the ID from the request path on the client's
+/// request.
+pub async fn close(
+    id: Path<String>,
+    sessions: Data<Connections>,
+) -> HttpResponse {
+    if let Some(session) = sessions.get(&id) {
+        session.do_send(Disconnect);
+    }
+    HttpResponse::NoContent()
+        .insert_header((header::ACCESS_CONTROL_ALLOW_ORIGIN, "*"))
+        .finish()
+}
+
+/// [Server-Sent Events] connection with a [Client API] client.
+///
+/// [Client API]: https://tinyurl.com/266y74tf
+/// [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+#[derive(Debug)]
+pub struct Connection {
+    /// [`State`] of this [`Connection`].
+    state: State,
+
+    /// ID of this [`Connection`].
+    id: String,
+
+    /// [`Connections`] registry this [`Connection`] is registered in.
+    sessions: Connections,
+
+    /// Sender of the [Server-Sent Events] stream.
+    ///
+    /// [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+    tx: mpsc::UnboundedSender<Bytes>,
+}
+
+impl Connection {
+    /// Sends a [Server-Sent Event] with the provided `event` name (if any)
+    /// and `data`, stopping this [`Connection`] if the client is gone.
+    ///
+    /// [Server-Sent Event]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+    fn send_event(
+        &self,
+        ctx: &mut Context<Self>,
+        event: Option<&str>,
+        data: &str,
+    ) {
+        let event = event.map(|e| format!("event: {e}\n")).unwrap_or_default();
+        let frame = Bytes::from(format!("{event}data: {data}\n\n"));
+        if self.tx.unbounded_send(frame).is_err() {
+            ctx.stop();
+        }
+    }
+}
+
+impl Session for Connection {
+    fn state(&mut self) -> &mut State {
+        &mut self.state
+    }
+
+    fn send(&mut self, ctx: &mut Self::Context, msg: &ServerMsg) {
+        match serde_json::to_string(msg) {
+            Ok(json) => self.send_event(ctx, None, &json),
+            Err(e) => log::error!("Failed to serialize `ServerMsg`: {e}"),
+        }
+    }
+
+    /// Sends a `close` event with the provided [`CloseReason`] and ends the
+    /// stream.
+    fn close(&mut self, ctx: &mut Self::Context, reason: CloseReason) {
+        match serde_json::to_string(&CloseDescription::new(reason)) {
+            Ok(json) => self.send_event(ctx, Some("close"), &json),
+            Err(e) => log::error!("Failed to serialize close reason: {e}"),
+        }
+        self.tx.close_channel();
+        ctx.stop();
+    }
+}
+
+impl Actor for Connection {
+    type Context = Context<Self>;
+
+    /// Sends the ID of this [`Connection`], the default [`RpcSettings`] and
+    /// starts heartbeat.
+    ///
+    /// [`RpcSettings`]: medea_client_api_proto::RpcSettings
+    fn started(&mut self, ctx: &mut Self::Context) {
+        let id = self.id.clone();
+        self.send_event(ctx, Some("session"), &id);
+        self.start(ctx);
+    }
+
+    /// Unregisters this [`Connection`] and marks connections of all the joined
+    /// `Member`s as lost.
+    fn stopped(&mut self, _: &mut Self::Context) {
+        drop(self.sessions.lock().remove(&self.id));
+        self.stop();
+    }
+}
+
+impl Handler<Outgoing> for Connection {
+    type Result = ();
+
+    fn handle(&mut self, msg: Outgoing, ctx: &mut Self::Context) {
+        self.handle_outgoing(msg, ctx);
+    }
+}
+
+/// [`ClientMsg`] received from a client via HTTP `POST` request.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+struct Incoming(ClientMsg);
+
+impl Handler<Incoming> for Connection {
+    type Result = ();
+
+    fn handle(&mut self, Incoming(msg): Incoming, ctx: &mut Self::Context) {
+        self.state.touch();
+        self.handle_msg(msg, ctx);
+    }
+}
+
+/// Request of a client to close its [`Connection`].
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+struct Disconnect;
+
+impl Handler<Disconnect> for Connection {
+    type Result = ();
+
+    fn handle(&mut self, _: Disconnect, ctx: &mut Self::Context) {
+        ctx.stop();
+    }
+}
@@ -0,0 +1,236 @@
+//! [WebSocket] server of [Client API].
+//!
+//! Also serves [Server-Sent Events] fallback transport (see [`sse`] module) on
+//! the same URL for clients not requesting a [WebSocket] upgrade.
+//!
+//! [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+//!
+//! [Client API]: https://tinyurl.com/266y74tf
+//! [WebSocket]: https://en.wikipedia.org/wiki/WebSocket
+
+use actix::{Actor, ActorContext as _, Handler, StreamHandler};
+use actix_web::{
+    App, Error, HttpRequest, HttpResponse, HttpServer,
+    http::header,
+    middleware,
+    web::{self, Data, Payload},
+};
+use actix_web_actors::ws;
+use medea_client_api_proto::{
+    ClientMsg, CloseDescription, CloseReason, ServerMsg, WireFormat,
+};
+use tracing as log;
+
+use crate::{
+    Cli,
+    room::{Outgoing, Rooms},
+    session::{Session, State},
+    sse::{self, Connections},
+};
+
+/// Runs [WebSocket] server of [Client API].
+///
+/// # Panics
+///
+/// If cannot bind and run HTTP server.
+///
+/// [Client API]: https://tinyurl.com/266y74tf
+/// [WebSocket]: https://en.wikipedia.org/wiki/WebSocket
+pub async fn run(opts: &Cli, rooms: Rooms) {
+    let rooms = Data::new(rooms);
+    let sse_sessions = Data::new(Connections::default());
+
+    log::info!("Client API server starting on {}", opts.addr);
+
+    #[expect(clippy::expect_used, reason = "startup")]
+    HttpServer::new(move || {
+        App::new()
+            .app_data(rooms.clone())
+            .app_data(sse_sessions.clone())
+            .wrap(middleware::Logger::default())
+            .service(web::resource("/ws").route(web::get().to(connect)))
+            .service(
+                web::resource("/ws/{session}").route(web::post().to(sse::send)),
+            )
+            .service(
+                web::resource("/ws/{session}/close")
+                    .route(web::post().to(sse::close)),
+            )
+    })
+    .bind(opts.addr)
+    .expect("failed to bind Client API server")
+    .run()
+    .await
+    .expect("failed to run Client API server");
+}
+
+/// Handles HTTP upgrade request trying to perform handshake and establish
+/// [WebSocket] connection.
+///
+/// Negotiates a [`WireFormat`] via the first [subprotocol][1] requested by the
+/// client and known by this server, falling back to [`WireFormat::Json`] if
+/// there is none.
+///
+/// Starts a [Server-Sent Events] session instead, if the request is not a
+/// [WebSocket] upgrade one.
+///
+/// # Errors
+///
+/// Errors if handshake fails for any underlying reason.
+///
+/// [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+/// [WebSocket]: https://en.wikipedia.org/wiki/WebSocket
+/// [1]: https://tools.ietf.org/html/rfc6455#section-1.9
+pub async fn connect(
+    request: HttpRequest,
+    rooms: Data<Rooms>,
+    sse_sessions: Data<Connections>,
+    payload: Payload,
+) -> Result<HttpResponse, Error> {
+    let is_upgrade = request
+        .headers()
+        .get(header::UPGRADE)
+        .and_then(|h| h.to_str().ok())
+        .is_some_and(|h| h.eq_ignore_ascii_case("websocket"));
+    if !is_upgrade {
+        return Ok(sse::connect(&rooms, &sse_sessions));
+    }
+
+    let negotiated = request
+        .headers()
+        .get(header::SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|protocols| {
+            protocols.split(',').map(str::trim).find_map(|p| {
+                WireFormat::ALL.into_iter().find(|f| f.subprotocol() == p)
+            })
+        });
+    let session =
+        WsSession::new(rooms.get_ref().clone(), negotiated.unwrap_or_default());
+
+    let protocols = negotiated.map(|f| [f.subprotocol()]);
+    let builder = ws::WsResponseBuilder::new(session, &request, payload);
+    match &protocols {
+        Some(protocols) => builder.protocols(protocols).start(),
+        None => builder.start(),
+    }
+}
+
+/// [WebSocket] connection with a [Client API] client.
+///
+/// [Client API]: https://tinyurl.com/266y74tf
+/// [WebSocket]: https://en.wikipedia.org/wiki/WebSocket
+#[derive(Debug)]
+struct WsSession {
+    /// [`State`] of this [`WsSession`].
+    state: State,
+
+    /// [`WireFormat`] negotiated with the client.
+    wire_format: WireFormat,
+}
+
+impl WsSession {
+    /// Creates a new [`WsSession`] with the default [`RpcSettings`] and the
+    /// provided negotiated [`WireFormat`].
+    ///
+    /// [`RpcSettings`]: medea_client_api_proto::RpcSettings
+    fn new(rooms: Rooms, wire_format: WireFormat) -> Self {
+        Self { state: State::new(rooms), wire_format }
+    }
+}
+
+impl Session for WsSession {
+    fn state(&mut self) -> &mut State {
+        &mut self.state
+    }
+
+    /// Sends the provided [`ServerMsg`] to the client in the negotiated
+    /// [`WireFormat`].
+    fn send(&mut self, ctx: &mut Self::Context, msg: &ServerMsg) {
+        match self.wire_format {
+            WireFormat::Json => match serde_json::to_string(msg) {
+                Ok(json) => ctx.text(json),
+                Err(e) => log::error!("Failed to serialize `ServerMsg`: {e}"),
+            },
+            WireFormat::Cbor => {
+                let mut buf = Vec::new();
+                match ciborium::into_writer(msg, &mut buf) {
+                    Ok(()) => ctx.binary(buf),
+                    Err(e) => {
+                        log::error!("Failed to serialize `ServerMsg`: {e}");
+                    }
+                }
+            }
+        }
+    }
+
+    fn close(&mut self, ctx: &mut Self::Context, reason: CloseReason) {
+        ctx.close(Some(ws::CloseReason {
+            code: ws::CloseCode::Normal,
+            description: serde_json::to_string(&CloseDescription::new(reason))
+                .ok(),
+        }));
+        ctx.stop();
+    }
+}
+
+impl Actor for WsSession {
+    type Context = ws::WebsocketContext<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        self.start(ctx);
+    }
+
+    fn stopped(&mut self, _: &mut Self::Context) {
+        self.stop();
+    }
+}
+
+impl Handler<Outgoing> for WsSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: Outgoing, ctx: &mut Self::Context) {
+        self.handle_outgoing(msg, ctx);
+    }
+}
+
+impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for WsSession {
+    fn handle(
+        &mut self,
+        item: Result<ws::Message, ws::ProtocolError>,
+        ctx: &mut Self::Context,
+    ) {
+        let msg = match item {
+            Ok(msg) => msg,
+            Err(e) => {
+                log::warn!("WebSocket protocol error: {e}");
+                ctx.stop();
+                return;
+            }
+        };
+        self.state.touch();
+
+        match msg {
+            ws::Message::Text(text) => {
+                match serde_json::from_str::<ClientMsg>(&text) {
+                    Ok(client_msg) => self.handle_msg(client_msg, ctx),
+                    Err(e) => log::warn!("Failed to parse `ClientMsg`: {e}"),
+                }
+            }
+            ws::Message::Binary(bytes) => {
+                match ciborium::from_reader::<ClientMsg, _>(&*bytes) {
+                    Ok(client_msg) => self.handle_msg(client_msg, ctx),
+                    Err(e) => log::warn!("Failed to parse `ClientMsg`: {e}"),
+                }
+            }
+            ws::Message::Ping(ping) => ctx.pong(&ping),
+            ws::Message::Close(reason) => {
+                ctx.close(reason);
+                ctx.stop();
+            }
+            ws::Message::Pong(_)
+            | ws::Message::Continuation(_)
+            | ws::Message::Nop => {}
+        }
+    }
+}
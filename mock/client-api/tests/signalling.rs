@@ -0,0 +1,242 @@
+//! Signalling of a P2P call between two `Member`s via [`Rooms`].
+
+#![forbid(non_ascii_idents, unsafe_code)]
+
+use std::{collections::HashMap, path::Path, time::Duration};
+
+use actix::{Actor, Context, Handler};
+use actix_web::rt;
+use futures::{StreamExt as _, channel::mpsc};
+use medea_client_api_mock::{
+    control::Specs,
+    room::{Outgoing, Rooms, Settings},
+};
+use medea_client_api_proto::{
+    CloseReason, Command, Credential, Event, IceCandidate, MemberId,
+    NegotiationRole, RoomId, ServerMsg,
+};
+
+/// Client receiving all the [`Outgoing`] messages of a `Member`.
+struct Client(mpsc::UnboundedSender<Outgoing>);
+
+impl Actor for Client {
+    type Context = Context<Self>;
+}
+
+impl Handler<Outgoing> for Client {
+    type Result = ();
+
+    fn handle(&mut self, msg: Outgoing, _: &mut Self::Context) {
+        drop(self.0.unbounded_send(msg));
+    }
+}
+
+/// Creates new [`Rooms`] with the `Room` specs of the `tests/specs`
+/// directory.
+fn rooms() -> Rooms {
+    let specs = Specs::default();
+    specs
+        .load_dir(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/specs"))
+        .expect("failed to load specs");
+    Rooms::new(
+        specs,
+        Settings {
+            ping_interval: Duration::from_secs(3),
+            idle_timeout: Duration::from_secs(10),
+            reconnect_timeout: Duration::from_secs(10),
+            ice_urls: Vec::new(),
+            turn_secret: None,
+            turn_ttl: Duration::from_hours(1),
+        },
+    )
+}
+
+/// Joins the provided `Member` to the `call` `Room` with the provided
+/// [`Credential`], returning the receiver of the messages sent to it.
+fn join(
+    rooms: &Rooms,
+    member_id: &str,
+    credential: &str,
+) -> (Option<u64>, mpsc::UnboundedReceiver<Outgoing>) {
+    let (tx, rx) = mpsc::unbounded();
+    let generation = rooms.join(
+        &RoomId::from("call"),
+        &MemberId::from(member_id),
+        &Credential::from(credential),
+        Client(tx).start().recipient(),
+    );
+    (generation, rx)
+}
+
+/// Returns the next [`Event`] received by a [`Client`], skipping
+/// [`ServerMsg::RpcSettings`].
+async fn next_event(rx: &mut mpsc::UnboundedReceiver<Outgoing>) -> Event {
+    loop {
+        let msg = rt::time::timeout(Duration::from_secs(1), rx.next())
+            .await
+            .expect("no `Outgoing` message received")
+            .expect("`Client` is gone");
+        match msg {
+            Outgoing::Msg(ServerMsg::Event { event, .. }) => return event,
+            Outgoing::Msg(ServerMsg::RpcSettings(_)) => {}
+            msg => panic!("unexpected `Outgoing` message: {msg:?}"),
+        }
+    }
+}
+
+#[actix_web::test]
+async fn rejects_invalid_credential() {
+    let rooms = rooms();
+
+    let (generation, mut caller) = join(&rooms, "caller", "wrong");
+    assert_eq!(generation, None, "`Member` is not rejected");
+    assert_eq!(
+        next_event(&mut caller).await,
+        Event::RoomLeft { close_reason: CloseReason::Rejected },
+        "`Member` is not notified",
+    );
+}
+
+#[actix_web::test]
+async fn join_negotiate_and_leave() {
+    let rooms = rooms();
+    let room_id = RoomId::from("call");
+    let (caller_id, responder_id) =
+        (MemberId::from("caller"), MemberId::from("responder"));
+
+    let (generation, mut caller) = join(&rooms, "caller", "test");
+    assert!(generation.is_some(), "`caller` is rejected");
+    assert!(
+        matches!(
+            next_event(&mut caller).await,
+            Event::RoomJoined { is_reconnect: false, .. },
+        ),
+        "expected `Event::RoomJoined`",
+    );
+
+    let (generation, mut responder) = join(&rooms, "responder", "test");
+    assert!(generation.is_some(), "`responder` is rejected");
+    let Event::RoomJoined { members, .. } = next_event(&mut responder).await
+    else {
+        panic!("expected `Event::RoomJoined`");
+    };
+    assert_eq!(members.len(), 2, "wrong roster");
+    assert!(
+        matches!(next_event(&mut caller).await, Event::MemberJoined { .. }),
+        "expected `Event::MemberJoined`",
+    );
+
+    let Event::PeerCreated {
+        peer_id: responder_peer,
+        negotiation_role: NegotiationRole::Offerer,
+        tracks,
+        ..
+    } = next_event(&mut responder).await
+    else {
+        panic!("expected offerer `Event::PeerCreated`");
+    };
+    assert!(!tracks.is_empty(), "`responder` receives no `Track`s");
+
+    rooms.command(
+        &room_id,
+        &responder_id,
+        Command::MakeSdpOffer {
+            peer_id: responder_peer,
+            sdp_offer: "offer".into(),
+            mids: HashMap::new(),
+            transceivers_statuses: HashMap::new(),
+        },
+    );
+    assert!(
+        matches!(
+            next_event(&mut responder).await,
+            Event::LocalDescriptionApplied { .. },
+        ),
+        "expected `Event::LocalDescriptionApplied`",
+    );
+    let Event::PeerCreated { peer_id: caller_peer, negotiation_role, .. } =
+        next_event(&mut caller).await
+    else {
+        panic!("expected answerer `Event::PeerCreated`");
+    };
+    assert_eq!(
+        negotiation_role,
+        NegotiationRole::Answerer("offer".into()),
+        "wrong `NegotiationRole`",
+    );
+
+    rooms.command(
+        &room_id,
+        &caller_id,
+        Command::MakeSdpAnswer {
+            peer_id: caller_peer,
+            sdp_answer: "answer".into(),
+            transceivers_statuses: HashMap::new(),
+        },
+    );
+    assert!(
+        matches!(
+            next_event(&mut caller).await,
+            Event::LocalDescriptionApplied { .. },
+        ),
+        "expected `Event::LocalDescriptionApplied`",
+    );
+    assert_eq!(
+        next_event(&mut responder).await,
+        Event::SdpAnswerMade {
+            peer_id: responder_peer,
+            sdp_answer: "answer".into(),
+        },
+        "answer is not passed",
+    );
+
+    let candidate = IceCandidate {
+        candidate: "candidate".into(),
+        sdp_m_line_index: Some(0),
+        sdp_mid: Some("0".into()),
+    };
+    rooms.command(
+        &room_id,
+        &caller_id,
+        Command::SetIceCandidate {
+            peer_id: caller_peer,
+            candidate: candidate.clone(),
+        },
+    );
+    assert_eq!(
+        next_event(&mut responder).await,
+        Event::IceCandidateDiscovered { peer_id: responder_peer, candidate },
+        "candidate is not passed",
+    );
+
+    rooms.leave(&room_id, &caller_id, Some("bye"));
+    assert_eq!(
+        next_event(&mut caller).await,
+        Event::RoomLeft { close_reason: CloseReason::Finished },
+        "leaving is not acknowledged",
+    );
+    assert_eq!(
+        next_event(&mut responder).await,
+        Event::PeersRemoved { peer_ids: vec![responder_peer] },
+        "partner `Peer` is not removed",
+    );
+    assert_eq!(
+        next_event(&mut responder).await,
+        Event::MemberLeft { member_id: caller_id.clone() },
+        "`MemberLeft` is not broadcast",
+    );
+
+    // Commands of the left `Member` are ignored.
+    rooms.command(
+        &room_id,
+        &caller_id,
+        Command::MakeSdpAnswer {
+            peer_id: caller_peer,
+            sdp_answer: "answer".into(),
+            transceivers_statuses: HashMap::new(),
+        },
+    );
+    let msg =
+        rt::time::timeout(Duration::from_millis(100), responder.next()).await;
+    assert!(msg.is_err(), "unexpected `Outgoing` message: {msg:?}");
+}
@@ -1,5 +1,7 @@
 //! `Endpoint` related methods and entities.
 
+use std::collections::HashMap;
+
 use medea_control_api_proto::grpc::api as proto;
 use serde::{Deserialize, Serialize};
 
@@ -83,12 +85,21 @@ impl From<PublishPolicy> for proto::web_rtc_publish_endpoint::PublishPolicy {
 }
 
 /// Settings for the audio media type of the [`WebRtcPublishEndpoint`].
-#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AudioSettings {
     /// Publishing policy of the audio media type in the
     /// [`WebRtcPublishEndpoint`].
     #[serde(default)]
     pub publish_policy: PublishPolicy,
+
+    /// Codecs to publish the audio media type with, in the order of
+    /// preference.
+    #[serde(default)]
+    pub preferred_codecs: Vec<Codec>,
+
+    /// Maximum bitrate to publish the audio media type with.
+    #[serde(default)]
+    pub max_bitrate: Option<u32>,
 }
 
 impl From<proto::web_rtc_publish_endpoint::AudioSettings> for AudioSettings {
@@ -100,6 +111,12 @@ impl From<proto::web_rtc_publish_endpoint::AudioSettings> for AudioSettings {
                 )
                 .unwrap_or_default()
                 .into(),
+            preferred_codecs: proto
+                .preferred_codecs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            max_bitrate: proto.max_bitrate,
         }
     }
 }
@@ -107,23 +124,63 @@ impl From<proto::web_rtc_publish_endpoint::AudioSettings> for AudioSettings {
 impl From<AudioSettings> for proto::web_rtc_publish_endpoint::AudioSettings {
     fn from(from: AudioSettings) -> Self {
         use proto::web_rtc_publish_endpoint::PublishPolicy;
-        Self { publish_policy: PublishPolicy::from(from.publish_policy).into() }
+        Self {
+            publish_policy: PublishPolicy::from(from.publish_policy).into(),
+            preferred_codecs: from
+                .preferred_codecs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            max_bitrate: from.max_bitrate,
+        }
     }
 }
 
 /// Settings for the video media type of the [`WebRtcPublishEndpoint`].
-#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct VideoSettings {
     /// Publishing policy of the video media type in the
     /// [`WebRtcPublishEndpoint`].
     #[serde(default)]
     pub publish_policy: PublishPolicy,
+
+    /// Codecs to publish the video media type with, in the order of
+    /// preference.
+    #[serde(default)]
+    pub preferred_codecs: Vec<Codec>,
+
+    /// Maximum total bitrate to publish the video media type with.
+    #[serde(default)]
+    pub max_bitrate: Option<u32>,
+
+    /// Maximum resolution to publish the video media type with.
+    #[serde(default)]
+    pub max_resolution: Option<Resolution>,
+
+    /// Maximum frame rate to publish the video media type with.
+    #[serde(default)]
+    pub max_frame_rate: Option<u32>,
+
+    /// Simulcast and/or SVC layers to publish the video media type with.
+    #[serde(default)]
+    pub encodings: Vec<Encoding>,
 }
 
 impl From<VideoSettings> for proto::web_rtc_publish_endpoint::VideoSettings {
     fn from(from: VideoSettings) -> Self {
         use proto::web_rtc_publish_endpoint::PublishPolicy;
-        Self { publish_policy: PublishPolicy::from(from.publish_policy).into() }
+        Self {
+            publish_policy: PublishPolicy::from(from.publish_policy).into(),
+            preferred_codecs: from
+                .preferred_codecs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            max_bitrate: from.max_bitrate,
+            max_resolution: from.max_resolution.map(Into::into),
+            max_frame_rate: from.max_frame_rate,
+            encodings: from.encodings.into_iter().map(Into::into).collect(),
+        }
     }
 }
 
@@ -136,6 +193,117 @@ impl From<proto::web_rtc_publish_endpoint::VideoSettings> for VideoSettings {
                 )
                 .unwrap_or_default()
                 .into(),
+            preferred_codecs: proto
+                .preferred_codecs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            max_bitrate: proto.max_bitrate,
+            max_resolution: proto.max_resolution.map(Into::into),
+            max_frame_rate: proto.max_frame_rate,
+            encodings: proto.encodings.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Codec to publish a media type with in the [`WebRtcPublishEndpoint`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Codec {
+    /// MIME `type/subtype` of this [`Codec`].
+    pub mime_type: String,
+
+    /// Codec-specific parameters of this [`Codec`].
+    #[serde(default)]
+    pub parameters: HashMap<String, String>,
+}
+
+impl From<Codec> for proto::web_rtc_publish_endpoint::Codec {
+    fn from(from: Codec) -> Self {
+        Self { mime_type: from.mime_type, parameters: from.parameters }
+    }
+}
+
+impl From<proto::web_rtc_publish_endpoint::Codec> for Codec {
+    fn from(proto: proto::web_rtc_publish_endpoint::Codec) -> Self {
+        Self { mime_type: proto.mime_type, parameters: proto.parameters }
+    }
+}
+
+/// Resolution of a video published in the [`WebRtcPublishEndpoint`].
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct Resolution {
+    /// Width of the video.
+    pub width: u32,
+
+    /// Height of the video.
+    pub height: u32,
+}
+
+impl From<Resolution> for proto::web_rtc_publish_endpoint::Resolution {
+    fn from(from: Resolution) -> Self {
+        Self { width: from.width, height: from.height }
+    }
+}
+
+impl From<proto::web_rtc_publish_endpoint::Resolution> for Resolution {
+    fn from(proto: proto::web_rtc_publish_endpoint::Resolution) -> Self {
+        Self { width: proto.width, height: proto.height }
+    }
+}
+
+/// Single encoding (simulcast layer) of a video published in the
+/// [`WebRtcPublishEndpoint`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Encoding {
+    /// RTP stream ID (RID) of this [`Encoding`].
+    pub rid: String,
+
+    /// Concrete codec to be used for this [`Encoding`].
+    #[serde(default)]
+    pub codec: Option<Codec>,
+
+    /// Maximum bitrate to publish this [`Encoding`] with.
+    #[serde(default)]
+    pub max_bitrate: Option<u32>,
+
+    /// Factor for scaling down video's resolution before publishing this
+    /// [`Encoding`].
+    #[serde(default)]
+    pub scale_resolution_down_by: Option<u32>,
+
+    /// SVC scalability mode of this [`Encoding`] (`L3T3`, for example).
+    #[serde(default)]
+    pub scalability_mode: Option<String>,
+}
+
+impl From<Encoding> for proto::web_rtc_publish_endpoint::Encoding {
+    fn from(from: Encoding) -> Self {
+        use proto::web_rtc_publish_endpoint::ScalabilityMode;
+        Self {
+            rid: from.rid,
+            codec: from.codec.map(Into::into),
+            max_bitrate: from.max_bitrate,
+            scale_resolution_down_by: from.scale_resolution_down_by,
+            scalability_mode: from
+                .scalability_mode
+                .and_then(|m| ScalabilityMode::from_str_name(&m))
+                .map(Into::into),
+        }
+    }
+}
+
+impl From<proto::web_rtc_publish_endpoint::Encoding> for Encoding {
+    fn from(proto: proto::web_rtc_publish_endpoint::Encoding) -> Self {
+        use proto::web_rtc_publish_endpoint::ScalabilityMode;
+        Self {
+            rid: proto.rid,
+            codec: proto.codec.map(Into::into),
+            max_bitrate: proto.max_bitrate,
+            scale_resolution_down_by: proto.scale_resolution_down_by,
+            scalability_mode: proto
+                .scalability_mode
+                .and_then(|m| ScalabilityMode::try_from(m).ok())
+                .map(|m| m.as_str_name().to_owned()),
         }
     }
 }
@@ -219,6 +387,14 @@ pub struct WebRtcPlayEndpoint {
     /// Option to relay all media through a TURN server forcibly.
     #[serde(default)]
     pub force_relay: bool,
+
+    /// Filter of the audio media type played by this [`WebRtcPlayEndpoint`].
+    #[serde(default)]
+    pub audio_filter: MediaFilter,
+
+    /// Filter of the video media type played by this [`WebRtcPlayEndpoint`].
+    #[serde(default)]
+    pub video_filter: MediaFilter,
 }
 
 impl WebRtcPlayEndpoint {
@@ -232,13 +408,102 @@ impl WebRtcPlayEndpoint {
             force_relay: self.force_relay,
             on_start: String::new(),
             on_stop: String::new(),
+            audio_filter: Some(self.audio_filter.into()),
+            video_filter: Some(self.video_filter.into()),
         }
     }
 }
 
 impl From<proto::WebRtcPlayEndpoint> for WebRtcPlayEndpoint {
     fn from(proto: proto::WebRtcPlayEndpoint) -> Self {
-        Self { id: proto.id, src: proto.src, force_relay: proto.force_relay }
+        Self {
+            id: proto.id,
+            src: proto.src,
+            force_relay: proto.force_relay,
+            audio_filter: proto
+                .audio_filter
+                .map(Into::into)
+                .unwrap_or_default(),
+            video_filter: proto
+                .video_filter
+                .map(Into::into)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Filter of a media type played by the [`WebRtcPlayEndpoint`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct MediaFilter {
+    /// Indicator whether the media type is played at all.
+    ///
+    /// If [`None`], then the media type is played.
+    #[serde(default)]
+    pub enabled: Option<bool>,
+
+    /// Kinds of media sources to play the media type from.
+    ///
+    /// If empty, then media of any source kind is played.
+    #[serde(default)]
+    pub source_kinds: Vec<MediaSourceKind>,
+}
+
+impl From<MediaFilter> for proto::web_rtc_play_endpoint::MediaFilter {
+    fn from(from: MediaFilter) -> Self {
+        use proto::web_rtc_play_endpoint::MediaSourceKind as Kind;
+        Self {
+            enabled: from.enabled,
+            source_kinds: from
+                .source_kinds
+                .into_iter()
+                .map(|k| Kind::from(k).into())
+                .collect(),
+        }
+    }
+}
+
+impl From<proto::web_rtc_play_endpoint::MediaFilter> for MediaFilter {
+    fn from(proto: proto::web_rtc_play_endpoint::MediaFilter) -> Self {
+        use proto::web_rtc_play_endpoint::MediaSourceKind as Kind;
+        Self {
+            enabled: proto.enabled,
+            source_kinds: proto
+                .source_kinds
+                .into_iter()
+                .filter_map(|k| Kind::try_from(k).ok())
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+/// Kind of a media source played by the [`WebRtcPlayEndpoint`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum MediaSourceKind {
+    /// Media is sourced by some media device (webcam or microphone).
+    Device,
+
+    /// Media is obtained with screen-capture.
+    Display,
+}
+
+impl From<MediaSourceKind> for proto::web_rtc_play_endpoint::MediaSourceKind {
+    fn from(from: MediaSourceKind) -> Self {
+        match from {
+            MediaSourceKind::Device => Self::Device,
+            MediaSourceKind::Display => Self::Display,
+        }
+    }
+}
+
+impl From<proto::web_rtc_play_endpoint::MediaSourceKind> for MediaSourceKind {
+    fn from(proto: proto::web_rtc_play_endpoint::MediaSourceKind) -> Self {
+        use proto::web_rtc_play_endpoint::MediaSourceKind as Kind;
+
+        match proto {
+            Kind::Device => Self::Device,
+            Kind::Display => Self::Display,
+        }
     }
 }
 
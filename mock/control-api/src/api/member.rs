@@ -47,6 +47,11 @@ pub struct Member {
     /// Interval of sending pings from Medea to this [`Member`] via Client API.
     #[serde(default, with = "humantime_serde")]
     pub ping_interval: Option<Duration>,
+
+    /// Opaque metadata of this [`Member`] exposed to other `Member`s via
+    /// Client API.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, String>,
 }
 
 impl Member {
@@ -78,6 +83,7 @@ impl Member {
                 .reconnect_timeout
                 .map(|d| d.try_into().unwrap()),
             ping_interval: self.ping_interval.map(|d| d.try_into().unwrap()),
+            metadata: self.metadata,
         }
     }
 
@@ -111,6 +117,7 @@ impl From<proto::Member> for Member {
             ping_interval: proto
                 .ping_interval
                 .map(|dur| dur.try_into().unwrap()),
+            metadata: proto.metadata,
         }
     }
 }
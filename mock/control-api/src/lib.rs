@@ -166,7 +166,8 @@ pub mod proto {
     pub use crate::api::{
         CreateResponse, Element, ErrorResponse, Response, SingleGetResponse,
         endpoint::{
-            AudioSettings, Endpoint, P2pMode, PublishPolicy, VideoSettings,
+            AudioSettings, Codec, Encoding, Endpoint, MediaFilter,
+            MediaSourceKind, P2pMode, PublishPolicy, Resolution, VideoSettings,
             WebRtcPlayEndpoint, WebRtcPublishEndpoint,
         },
         member::{Credentials, Member},
@@ -309,6 +309,105 @@ impl_incrementable!(PeerId);
 #[cfg(feature = "server")]
 impl_incrementable!(TrackId);
 
+/// Format of [`ClientMsg`]s and [`ServerMsg`]s on the wire, negotiated via
+/// [WebSocket subprotocol][1].
+///
+/// If no subprotocol is negotiated, then [`WireFormat::Json`] is used.
+///
+/// [1]: https://tools.ietf.org/html/rfc6455#section-1.9
+#[derive(Clone, Copy, Debug, Default, Display, Eq, Hash, PartialEq)]
+pub enum WireFormat {
+    /// [JSON] text frames.
+    ///
+    /// [JSON]: https://json.org
+    #[default]
+    #[display("{}", Self::JSON_SUBPROTOCOL)]
+    Json,
+
+    /// [CBOR] binary frames.
+    ///
+    /// [CBOR]: https://cbor.io
+    #[display("{}", Self::CBOR_SUBPROTOCOL)]
+    Cbor,
+}
+
+impl WireFormat {
+    /// All the supported [`WireFormat`]s in the order of preference.
+    pub const ALL: [Self; 2] = [Self::Cbor, Self::Json];
+
+    /// WebSocket subprotocol of the [`WireFormat::Json`].
+    pub const JSON_SUBPROTOCOL: &'static str = "medea-json";
+
+    /// WebSocket subprotocol of the [`WireFormat::Cbor`].
+    pub const CBOR_SUBPROTOCOL: &'static str = "medea-cbor";
+
+    /// Returns WebSocket subprotocol of this [`WireFormat`].
+    #[must_use]
+    pub const fn subprotocol(self) -> &'static str {
+        match self {
+            Self::Json => Self::JSON_SUBPROTOCOL,
+            Self::Cbor => Self::CBOR_SUBPROTOCOL,
+        }
+    }
+
+    /// Parses a [`WireFormat`] from the provided negotiated WebSocket
+    /// subprotocol, falling back to the [`WireFormat::Json`] if it's empty or
+    /// unknown.
+    #[must_use]
+    pub fn from_subprotocol(subprotocol: &str) -> Self {
+        match subprotocol {
+            Self::CBOR_SUBPROTOCOL => Self::Cbor,
+            _ => Self::Json,
+        }
+    }
+
+    /// Indicates whether this [`WireFormat`] uses binary frames.
+    #[must_use]
+    pub const fn is_binary(self) -> bool {
+        matches!(self, Self::Cbor)
+    }
+}
+
+/// Version of Client API protocol.
+///
+/// Versions are compatible as long as their [`ProtocolVersion::major`] parts
+/// are equal, so [`ProtocolVersion::minor`] bumps are allowed to introduce new
+/// [`Event`]s and fields only.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Display, Eq, Hash, PartialEq, Serialize,
+)]
+#[display("{major}.{minor}")]
+pub struct ProtocolVersion {
+    /// Major part of this [`ProtocolVersion`], bumped on breaking changes.
+    pub major: u16,
+
+    /// Minor part of this [`ProtocolVersion`], bumped on backward compatible
+    /// changes.
+    pub minor: u16,
+}
+
+impl ProtocolVersion {
+    /// [`ProtocolVersion`] implemented by this crate.
+    pub const CURRENT: Self = Self { major: 1, minor: 2 };
+
+    /// [`ProtocolVersion`] assumed for Web Clients not reporting any, since
+    /// they were released before versioning has been introduced.
+    pub const LEGACY: Self = Self { major: 1, minor: 0 };
+
+    /// Indicates whether this [`ProtocolVersion`] is compatible with the
+    /// provided one.
+    #[must_use]
+    pub const fn is_compatible_with(self, other: Self) -> bool {
+        self.major == other.major
+    }
+}
+
+impl Default for ProtocolVersion {
+    fn default() -> Self {
+        Self::LEGACY
+    }
+}
+
 /// Message sent by Media Server to Web Client.
 #[cfg_attr(
     any(
@@ -318,14 +417,20 @@ impl_incrementable!(TrackId);
     expect(variant_size_differences, reason = "`Event` is the most common")
 )]
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(feature = "client", derive(Deserialize))]
-#[cfg_attr(feature = "server", derive(Serialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Deserialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Serialize))]
 #[serde(tag = "msg", content = "data")]
 pub enum ServerMsg {
     /// `ping` message that Media Server is expected to send to Web Client
     /// periodically for probing its aliveness.
     Ping(u32),
 
+    /// `pong` message that Media Server answers with to Web Client in response
+    /// to received [`ClientMsg::Ping`].
+    ///
+    /// Since [`ProtocolVersion`] `1.1`.
+    Pong(u32),
+
     /// Media Server notifies Web Client about happened facts and it reacts on
     /// them to reach the proper state.
     Event {
@@ -350,13 +455,19 @@ pub enum ServerMsg {
     expect(variant_size_differences, reason = "`Command` is the most common")
 )]
 #[derive(Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "client", derive(Serialize))]
-#[cfg_attr(feature = "server", derive(Deserialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Serialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Deserialize))]
 pub enum ClientMsg {
     /// `pong` message that Web Client answers with to Media Server in response
     /// to received [`ServerMsg::Ping`].
     Pong(u32),
 
+    /// `ping` message that Web Client may send to Media Server for measuring
+    /// round-trip time of the signalling connection.
+    ///
+    /// Since [`ProtocolVersion`] `1.1`.
+    Ping(u32),
+
     /// Request of Web Client to change its state on Media Server.
     Command {
         /// ID of the `Room` that this [`Command`] is associated with.
@@ -380,12 +491,20 @@ pub struct RpcSettings {
     ///
     /// Unit: millisecond.
     pub ping_interval_ms: u32,
+
+    /// Indicator whether Media Server answers [`ClientMsg::Ping`]s with
+    /// [`ServerMsg::Pong`]s, so Web Client may measure round-trip time of the
+    /// signalling connection.
+    ///
+    /// Since [`ProtocolVersion`] `1.1`.
+    #[serde(default)]
+    pub latency_probes: bool,
 }
 
 /// Possible commands sent by Web Client to Media Server.
 #[dispatchable]
-#[cfg_attr(feature = "client", derive(Serialize))]
-#[cfg_attr(feature = "server", derive(Deserialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Serialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 #[serde(tag = "command", content = "data")]
 pub enum Command {
@@ -400,12 +519,28 @@ pub enum Command {
         /// [`Capabilities`] reported by Web Client (e.g. available codecs,
         /// platform).
         capabilities: Capabilities,
+
+        /// [`ProtocolVersion`] spoken by Web Client.
+        ///
+        /// Media Server closes the connection with a
+        /// [`CloseReason::IncompatibleProtocol`] if it cannot speak it.
+        #[serde(default)]
+        protocol_version: ProtocolVersion,
     },
 
     /// Request to leave a `Room`.
+    ///
+    /// Since [`ProtocolVersion`] `1.2`, Media Server acknowledges it with an
+    /// [`Event::RoomLeft`] before closing the connection.
     LeaveRoom {
         /// ID of the `Member` who leaves the `Room`.
         member_id: MemberId,
+
+        /// Human-readable reason of leaving the `Room`, if any.
+        ///
+        /// Since [`ProtocolVersion`] `1.2`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
     },
 
     /// Web Client sends SDP Offer.
@@ -478,8 +613,8 @@ pub enum Command {
 }
 
 /// Web Client's `PeerConnection` metrics.
-#[cfg_attr(feature = "client", derive(Serialize))]
-#[cfg_attr(feature = "server", derive(Deserialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Serialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub enum PeerMetrics {
     /// `PeerConnection`'s ICE connection state.
@@ -496,8 +631,8 @@ pub enum PeerMetrics {
 }
 
 /// Possible errors related to a `PeerConnection`.
-#[cfg_attr(feature = "client", derive(Serialize))]
-#[cfg_attr(feature = "server", derive(Deserialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Serialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Deserialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PeerConnectionError {
     /// Error occurred with ICE candidate from a `PeerConnection`.
@@ -507,8 +642,8 @@ pub enum PeerConnectionError {
 /// Error occurred with an [ICE] candidate from a `PeerConnection`.
 ///
 /// [ICE]: https://webrtcglossary.com/ice
-#[cfg_attr(feature = "client", derive(Serialize))]
-#[cfg_attr(feature = "server", derive(Deserialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Serialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Deserialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct IceCandidateError {
     /// Local IP address used to communicate with a [STUN]/[TURN] server.
@@ -552,8 +687,8 @@ pub struct IceCandidateError {
 }
 
 /// `PeerConnection`'s ICE connection state.
-#[cfg_attr(feature = "client", derive(Serialize))]
-#[cfg_attr(feature = "server", derive(Deserialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Serialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum IceConnectionState {
     /// ICE agent is gathering addresses or is waiting to be given remote
@@ -598,8 +733,8 @@ pub enum IceConnectionState {
 }
 
 /// `PeerConnection`'s connection state.
-#[cfg_attr(feature = "client", derive(Serialize))]
-#[cfg_attr(feature = "server", derive(Deserialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Serialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum PeerConnectionState {
     /// At least one of the connection's ICE transports are in the
@@ -680,6 +815,10 @@ pub enum CloseReason {
 
     /// Client was evicted on the server side.
     Evicted,
+
+    /// [`ProtocolVersion`] of a client is incompatible with the one of a
+    /// server.
+    IncompatibleProtocol,
 }
 
 /// Description which is sent in [Close] WebSocket frame from Media Server to
@@ -696,8 +835,8 @@ pub struct CloseDescription {
 
 /// Possible WebSocket messages sent from Media Server to Web Client.
 #[dispatchable(self: &Self, async_trait(?Send))]
-#[cfg_attr(feature = "client", derive(Deserialize))]
-#[cfg_attr(feature = "server", derive(Serialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Deserialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Serialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[serde(tag = "event", content = "data")]
 pub enum Event {
@@ -712,6 +851,11 @@ pub enum Event {
         ///
         /// Server expects [`Command::SynchronizeMe`] if this is `true`.
         is_reconnect: bool,
+
+        /// Roster of all the [`Member`]s currently present in the `Room`
+        /// (including the joined one).
+        #[serde(default)]
+        members: Vec<Member>,
     },
 
     /// Media Server notifies Web Client that a `Member` left a `Room`.
@@ -820,6 +964,23 @@ pub enum Event {
         negotiation_role: Option<NegotiationRole>,
     },
 
+    /// Media Server notifies Web Client about necessity to update
+    /// [`IceServer`]s of an existing `Peer` (for example, when time-limited
+    /// TURN credentials are going to expire).
+    ///
+    /// New [`IceServer`]s are applied to the [RTCPeerConnection][0] without
+    /// any (re)negotiation, and are used on the next ICE gathering (ICE
+    /// restart, for example).
+    ///
+    /// [0]: https://w3.org/TR/webrtc#rtcpeerconnection-interface
+    IceServersUpdated {
+        /// ID of the `Peer` to update [`IceServer`]s of.
+        peer_id: PeerId,
+
+        /// New [`IceServer`]s replacing the current ones.
+        ice_servers: Vec<IceServer>,
+    },
+
     /// Media Server notifies about connection quality score update.
     ConnectionQualityUpdated {
         /// Partner [`MemberId`] of the `Peer`.
@@ -834,6 +995,51 @@ pub enum Event {
         /// Proper state that should be assumed by Web Client.
         state: state::Room,
     },
+
+    /// Media Server notifies Web Client that a new [`Member`] joined the
+    /// `Room`.
+    MemberJoined {
+        /// [`Member`] who joined the `Room`.
+        member: Member,
+    },
+
+    /// Media Server notifies Web Client that a [`Member`] left the `Room`.
+    MemberLeft {
+        /// ID of the [`Member`] who left the `Room`.
+        member_id: MemberId,
+    },
+
+    /// Media Server notifies Web Client that metadata of a [`Member`] present
+    /// in the `Room` has been changed.
+    MemberUpdated {
+        /// [`Member`] with its actual metadata.
+        member: Member,
+    },
+
+    /// [`Event`] unknown to Web Client, most likely because Media Server
+    /// speaks a newer [`ProtocolVersion`].
+    ///
+    /// Is never sent by Media Server, but is produced by Web Client instead of
+    /// failing to parse an unrecognized [`Event`].
+    #[serde(skip)]
+    Unknown {
+        /// Name of the unrecognized [`Event`].
+        event: String,
+    },
+}
+
+/// `Member` present in a `Room`.
+///
+/// Doesn't depend on any media being exchanged with this `Member`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Member {
+    /// ID of this [`Member`].
+    pub id: MemberId,
+
+    /// Opaque metadata of this [`Member`] (display name, avatar URL, etc.),
+    /// as specified via Control API.
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
 }
 
 /// `Peer`'s negotiation role.
@@ -871,8 +1077,8 @@ pub enum ConnectionMode {
 }
 
 /// [`Track`] update which should be applied to the `Peer`.
-#[cfg_attr(feature = "client", derive(Deserialize))]
-#[cfg_attr(feature = "server", derive(Serialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Deserialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Serialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PeerUpdate {
     /// New [`Track`] should be added to the `Peer`.
@@ -917,8 +1123,8 @@ pub struct IceCandidate {
 }
 
 /// Track with a [`Direction`].
-#[cfg_attr(feature = "client", derive(Deserialize))]
-#[cfg_attr(feature = "server", derive(Serialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Deserialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Serialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Track {
     /// ID of this [`Track`].
@@ -947,8 +1153,8 @@ impl Track {
 
 /// Patch of a [`Track`] which Web Client can request with a
 /// [`Command::UpdateTracks`].
-#[cfg_attr(feature = "client", derive(Serialize))]
-#[cfg_attr(feature = "server", derive(Deserialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Serialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct TrackPatchCommand {
     /// ID of the [`Track`] this patch is intended for.
@@ -966,8 +1172,8 @@ pub struct TrackPatchCommand {
 
 /// Patch of a [`Track`] which Media Server can send with an
 /// [`Event::PeerUpdated`].
-#[cfg_attr(feature = "client", derive(Deserialize))]
-#[cfg_attr(feature = "server", derive(Serialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Deserialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Serialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TrackPatchEvent {
     /// ID of the [`Track`] which should be patched.
@@ -1113,8 +1319,8 @@ pub struct IceServer {
 }
 
 /// Possible directions of a [`Track`].
-#[cfg_attr(feature = "client", derive(Deserialize))]
-#[cfg_attr(feature = "server", derive(Serialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Deserialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Serialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 // TODO: Use different struct without `mids` in `PeerUpdated` event.
 pub enum Direction {
@@ -1362,8 +1568,8 @@ pub struct EncodingParameters {
 }
 
 /// Client capabilities (e.g. available codecs, platform).
-#[cfg_attr(feature = "client", derive(Serialize))]
-#[cfg_attr(feature = "server", derive(Deserialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Serialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Deserialize))]
 #[derive(Clone, Debug, Eq, Default, PartialEq)]
 pub struct Capabilities {
     /// [`Codec`] capabilities for sending audio.
@@ -1411,8 +1617,8 @@ pub struct Codec {
 }
 
 /// Estimated connection quality.
-#[cfg_attr(feature = "client", derive(Deserialize))]
-#[cfg_attr(feature = "server", derive(Serialize))]
+#[cfg_attr(any(feature = "client", feature = "recording"), derive(Deserialize))]
+#[cfg_attr(any(feature = "server", feature = "recording"), derive(Serialize))]
 #[derive(Clone, Copy, Debug, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum ConnectionQualityScore {
     /// Nearly all users dissatisfied.
@@ -6,7 +6,7 @@ use std::collections::{HashMap, HashSet};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    ConnectionMode, IceCandidate, IceServer, MediaDirection, MediaType,
+    ConnectionMode, IceCandidate, IceServer, MediaDirection, MediaType, Member,
     MemberId, NegotiationRole, PeerId, TrackId,
 };
 
@@ -15,6 +15,10 @@ use crate::{
 pub struct Room {
     /// All [`Peer`]s of this [`Room`].
     pub peers: HashMap<PeerId, Peer>,
+
+    /// All [`Member`]s present in this [`Room`].
+    #[serde(default)]
+    pub members: HashMap<MemberId, Member>,
 }
 
 /// State of a `Peer` element.
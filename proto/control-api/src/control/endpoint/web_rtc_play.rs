@@ -47,6 +47,115 @@ pub struct Spec {
     /// [TURN]: https://webrtc.org/getting-started/turn-server
     #[cfg_attr(feature = "serde", serde(default))]
     pub force_relay: bool,
+
+    /// [`MediaFilter`] of the audio media type played by this [`WebRtcPlay`]
+    /// [`Element`].
+    ///
+    /// [`Element`]: crate::Element
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub audio_filter: MediaFilter,
+
+    /// [`MediaFilter`] of the video media type played by this [`WebRtcPlay`]
+    /// [`Element`].
+    ///
+    /// [`Element`]: crate::Element
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub video_filter: MediaFilter,
+}
+
+#[cfg(feature = "client-api")]
+impl Spec {
+    /// Indicates whether a `Track` of the provided
+    /// [`medea_client_api_proto::MediaType`] passes the [`MediaFilter`]s of
+    /// this [`WebRtcPlay`] [`Element`].
+    ///
+    /// Media server advertises only the passing `Track`s to a client in
+    /// [`Event::PeerCreated`].
+    ///
+    /// [`Element`]: crate::Element
+    /// [`Event::PeerCreated`]: medea_client_api_proto::Event::PeerCreated
+    #[must_use]
+    pub fn is_playable(
+        &self,
+        media_type: &medea_client_api_proto::MediaType,
+    ) -> bool {
+        use medea_client_api_proto::MediaType;
+
+        match media_type {
+            MediaType::Audio(audio) => {
+                self.audio_filter.is_passing(audio.source_kind.into())
+            }
+            MediaType::Video(video) => {
+                self.video_filter.is_passing(video.source_kind.into())
+            }
+        }
+    }
+}
+
+/// Filter of a media type played by a [`WebRtcPlay`] media [`Element`].
+///
+/// [`Element`]: crate::Element
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct MediaFilter {
+    /// Indicator whether the media type is played at all.
+    #[cfg_attr(feature = "serde", serde(default = "MediaFilter::enabled"))]
+    pub enabled: bool,
+
+    /// [`MediaSourceKind`]s to play the media type from.
+    ///
+    /// If empty, then media of any [`MediaSourceKind`] is played.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub source_kinds: Vec<MediaSourceKind>,
+}
+
+impl MediaFilter {
+    /// Indicates whether media of the provided [`MediaSourceKind`] passes this
+    /// [`MediaFilter`].
+    #[must_use]
+    pub fn is_passing(&self, source_kind: MediaSourceKind) -> bool {
+        self.enabled
+            && (self.source_kinds.is_empty()
+                || self.source_kinds.contains(&source_kind))
+    }
+
+    /// Returns the default value of the [`MediaFilter::enabled`] field.
+    #[cfg(feature = "serde")]
+    const fn enabled() -> bool {
+        true
+    }
+}
+
+impl Default for MediaFilter {
+    fn default() -> Self {
+        Self { enabled: true, source_kinds: Vec::new() }
+    }
+}
+
+/// Possible kinds of media sources played by a [`WebRtcPlay`] media
+/// [`Element`].
+///
+/// [`Element`]: crate::Element
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum MediaSourceKind {
+    /// Media is sourced by some media device (webcam or microphone).
+    Device = 0,
+
+    /// Media is obtained with screen-capture.
+    Display = 1,
+}
+
+#[cfg(feature = "client-api")]
+impl From<medea_client_api_proto::MediaSourceKind> for MediaSourceKind {
+    fn from(kind: medea_client_api_proto::MediaSourceKind) -> Self {
+        use medea_client_api_proto::MediaSourceKind as Kind;
+
+        match kind {
+            Kind::Device => Self::Device,
+            Kind::Display => Self::Display,
+        }
+    }
 }
 
 /// ID of a [`WebRtcPlay`] media [`Element`].
@@ -2,6 +2,8 @@
 //!
 //! [`Endpoint`]: crate::Endpoint
 
+use std::collections::HashMap;
+
 use derive_more::with_trait::{AsRef, Display, From, Into};
 use ref_cast::RefCast;
 #[cfg(feature = "serde")]
@@ -30,7 +32,7 @@ pub struct WebRtcPublish {
 /// Spec of a [`WebRtcPublish`] media [`Element`].
 ///
 /// [`Element`]: crate::Element
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Spec {
     /// Peer-to-peer mode of this [`WebRtcPublish`] [`Element`].
@@ -114,23 +116,206 @@ pub enum P2pMode {
 /// Audio media type settings of a [`WebRtcPublish`] media [`Element`].
 ///
 /// [`Element`]: crate::Element
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct AudioSettings {
     /// [`Policy`] to publish the audio media type with.
     #[cfg_attr(feature = "serde", serde(default))]
     pub publish_policy: Policy,
+
+    /// [`Codec`]s to publish the audio media type with, in the order of
+    /// preference.
+    ///
+    /// If empty, then any [`Codec`] negotiated with a client may be used.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub preferred_codecs: Vec<Codec>,
+
+    /// Maximum bitrate (in bits per second) to publish the audio media type
+    /// with.
+    ///
+    /// If [`None`], then bitrate is not limited by a media server.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_bitrate: Option<u32>,
 }
 
 /// Video media type settings of a [`WebRtcPublish`] media [`Element`].
 ///
 /// [`Element`]: crate::Element
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct VideoSettings {
     /// [`Policy`] to publish the video media type with.
     #[cfg_attr(feature = "serde", serde(default))]
     pub publish_policy: Policy,
+
+    /// [`Codec`]s to publish the video media type with, in the order of
+    /// preference.
+    ///
+    /// If empty, then any [`Codec`] negotiated with a client may be used.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub preferred_codecs: Vec<Codec>,
+
+    /// Maximum total bitrate (in bits per second) to publish the video media
+    /// type with.
+    ///
+    /// If [`None`], then bitrate is not limited by a media server.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_bitrate: Option<u32>,
+
+    /// Maximum [`Resolution`] to publish the video media type with.
+    ///
+    /// If [`None`], then resolution is not limited by a media server.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_resolution: Option<Resolution>,
+
+    /// Maximum frame rate (in frames per second) to publish the video media
+    /// type with.
+    ///
+    /// If [`None`], then frame rate is not limited by a media server.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_frame_rate: Option<u32>,
+
+    /// [`Encoding`]s to publish the video media type with.
+    ///
+    /// Multiple [`Encoding`]s define [simulcast] layers, while a single
+    /// [`Encoding`] with a [`ScalabilityMode`] defines [SVC] layers.
+    ///
+    /// If empty, then a single encoding chosen by a client is published.
+    ///
+    /// [simulcast]: https://webrtcglossary.com/simulcast
+    /// [SVC]: https://webrtcglossary.com/svc
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub encodings: Vec<Encoding>,
+}
+
+/// Codec to publish a media type with in a [`WebRtcPublish`] media
+/// [`Element`].
+///
+/// [`Element`]: crate::Element
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Codec {
+    /// [MIME] `type/subtype` of this [`Codec`] (`video/VP9`, for example).
+    ///
+    /// [MIME]: https://en.wikipedia.org/wiki/Media_type
+    pub mime_type: String,
+
+    /// [`Codec`]-specific parameters that must be signaled to the remote party
+    /// (`profile-id`, for example).
+    ///
+    /// Corresponds to `a=fmtp` parameters in [SDP].
+    ///
+    /// [SDP]: https://en.wikipedia.org/wiki/Session_Description_Protocol
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub parameters: HashMap<String, String>,
+}
+
+/// Resolution of a video published in a [`WebRtcPublish`] media [`Element`].
+///
+/// [`Element`]: crate::Element
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Resolution {
+    /// Width (in pixels) of the video.
+    pub width: u32,
+
+    /// Height (in pixels) of the video.
+    pub height: u32,
+}
+
+/// Single encoding ([simulcast] layer) of a video published in a
+/// [`WebRtcPublish`] media [`Element`].
+///
+/// [`Element`]: crate::Element
+/// [simulcast]: https://webrtcglossary.com/simulcast
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Encoding {
+    /// [RTP stream ID (RID)][RID] of this [`Encoding`].
+    ///
+    /// [RID]: https://webrtcglossary.com/rid
+    pub rid: String,
+
+    /// Concrete [`Codec`] to be used for this [`Encoding`].
+    ///
+    /// If [`None`], then any negotiated [`Codec`] can be used.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub codec: Option<Codec>,
+
+    /// Maximum bitrate (in bits per second) to publish this [`Encoding`] with.
+    ///
+    /// If [`None`], then bitrate is not limited by a media server.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_bitrate: Option<u32>,
+
+    /// Factor for scaling down video's resolution in each dimension before
+    /// publishing this [`Encoding`].
+    ///
+    /// Must be greater than or equal to `1`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub scale_resolution_down_by: Option<u8>,
+
+    /// [SVC (Scalable Video Coding)][SVC] scalability mode of this
+    /// [`Encoding`].
+    ///
+    /// If [`None`], then [SVC] is not used.
+    ///
+    /// [SVC]: https://webrtcglossary.com/svc
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub scalability_mode: Option<ScalabilityMode>,
+}
+
+/// [Scalability mode][0] of [SVC (Scalable Video Coding)][SVC].
+///
+/// [SVC]: https://webrtcglossary.com/svc
+/// [0]: https://w3.org/TR/webrtc-svc#scalabilitymodes*
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum ScalabilityMode {
+    /// [L1T1](https://w3.org/TR/webrtc-svc#L1T1*) mode.
+    L1T1 = 0,
+
+    /// [L1T2](https://w3.org/TR/webrtc-svc#L1T2*) mode.
+    L1T2 = 1,
+
+    /// [L1T3](https://w3.org/TR/webrtc-svc#L1T3*) mode.
+    L1T3 = 2,
+
+    /// [L2T1](https://w3.org/TR/webrtc-svc#L2T1*) mode.
+    L2T1 = 3,
+
+    /// [L2T2](https://w3.org/TR/webrtc-svc#L2T2*) mode.
+    L2T2 = 4,
+
+    /// [L2T3](https://w3.org/TR/webrtc-svc#L2T3*) mode.
+    L2T3 = 5,
+
+    /// [L3T1](https://w3.org/TR/webrtc-svc#L3T1*) mode.
+    L3T1 = 6,
+
+    /// [L3T2](https://w3.org/TR/webrtc-svc#L3T2*) mode.
+    L3T2 = 7,
+
+    /// [L3T3](https://w3.org/TR/webrtc-svc#L3T3*) mode.
+    L3T3 = 8,
+
+    /// [S2T1](https://w3.org/TR/webrtc-svc#S2T1*) mode.
+    S2T1 = 9,
+
+    /// [S2T2](https://w3.org/TR/webrtc-svc#S2T2*) mode.
+    S2T2 = 10,
+
+    /// [S2T3](https://w3.org/TR/webrtc-svc#S2T3*) mode.
+    S2T3 = 11,
+
+    /// [S3T1](https://w3.org/TR/webrtc-svc#S3T1*) mode.
+    S3T1 = 12,
+
+    /// [S3T2](https://w3.org/TR/webrtc-svc#S3T2*) mode.
+    S3T2 = 13,
+
+    /// [S3T3](https://w3.org/TR/webrtc-svc#S3T3*) mode.
+    S3T3 = 14,
 }
 
 /// Policy of how a video or an audio media type can be published in a
@@ -90,6 +90,16 @@ pub struct Spec {
     /// [Client API]: https://tinyurl.com/266y74tf
     #[cfg_attr(feature = "serde", serde(default, with = "humantime_serde"))]
     pub ping_interval: Option<Duration>,
+
+    /// Opaque metadata of this [`Member`] (display name, avatar URL, etc.).
+    ///
+    /// Exposed to other [`Member`]s of the same [`Room`] via [Client API], so
+    /// they could be identified without any side channel.
+    ///
+    /// [Client API]: https://tinyurl.com/266y74tf
+    /// [`Room`]: crate::Room
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub metadata: HashMap<String, String>,
 }
 
 /// ID of a [`Member`] media [`Element`].
@@ -157,8 +167,7 @@ impl Sid {
     //       https://github.com/iqlusioninc/crates/issues/1182
     #[must_use]
     pub fn to_uri_string(&self) -> String {
-        let mut sid =
-            format!("{}/{}/{}", self.public_url, self.room_id, self.member_id);
+        let mut sid = self.to_uri_string_without_creds();
         if let Some(plain) = &self.creds {
             #[expect(clippy::expect_used, reason = "never fails")]
             write!(sid, "?token={}", plain.expose_str())
@@ -166,6 +175,16 @@ impl Sid {
         }
         sid
     }
+
+    /// Renders the [URI] string of this [`Sid`] omitting its
+    /// [`PlainCredentials`], so the ones may be passed to a client side
+    /// separately and don't leak into proxy and access logs.
+    ///
+    /// [URI]: https://en.wikipedia.org/wiki/Uniform_Resource_Identifier
+    #[must_use]
+    pub fn to_uri_string_without_creds(&self) -> String {
+        format!("{}/{}/{}", self.public_url, self.room_id, self.member_id)
+    }
 }
 
 impl FromStr for Sid {
@@ -288,13 +288,20 @@ pub struct Ping(pub u32);
 )]
 pub struct Pong(pub u32);
 
-#[cfg(all(feature = "serde", test))]
+#[cfg(test)]
+#[cfg(feature = "serde")]
+#[expect(clippy::too_many_lines, reason = "still easy to follow")]
 mod serialization {
+    use std::collections::HashMap;
+
     use super::{
         Room, RootElement,
         endpoint::{
-            web_rtc_play::{self, LocalSrcUri},
-            web_rtc_publish::{self, AudioSettings, P2pMode, VideoSettings},
+            web_rtc_play::{self, LocalSrcUri, MediaFilter, MediaSourceKind},
+            web_rtc_publish::{
+                self, AudioSettings, Codec, Encoding, P2pMode, Policy,
+                Resolution, ScalabilityMode, VideoSettings,
+            },
         },
         member::{self, Credentials},
         room,
@@ -311,6 +318,8 @@ spec:
       spec:
         credentials:
           plain: test
+        metadata:
+          display_name: Caller
         pipeline:
           publish:
             kind: WebRtcPublishEndpoint
@@ -326,6 +335,20 @@ spec:
             kind: WebRtcPublishEndpoint
             spec:
               p2p: Always
+              video_settings:
+                publish_policy: Required
+                preferred_codecs:
+                  - mime_type: video/VP9
+                    parameters:
+                      profile-id: "0"
+                max_resolution:
+                  width: 1280
+                  height: 720
+                max_frame_rate: 30
+                encodings:
+                  - rid: q
+                    max_bitrate: 1500000
+                    scalability_mode: L3T3
     responder:
       kind: Member
       spec:
@@ -340,6 +363,10 @@ spec:
             kind: WebRtcPlayEndpoint
             spec:
               src: "local://test-call/some-member/publish"
+              audio_filter:
+                enabled: false
+              video_filter:
+                source_kinds: [Display]
     "#;
 
     #[test]
@@ -354,7 +381,7 @@ spec:
                         (
                             "caller".into(),
                             member::Spec {
-                                pipeline: [(
+                                pipeline: HashMap::from([(
                                     "publish".into(),
                                     web_rtc_publish::Spec {
                                         p2p: P2pMode::Always,
@@ -365,9 +392,7 @@ spec:
                                         ),
                                     }
                                     .into(),
-                                )]
-                                .into_iter()
-                                .collect(),
+                                )]),
                                 credentials: Some(Credentials::Plain(
                                     "test".into(),
                                 )),
@@ -376,26 +401,51 @@ spec:
                                 idle_timeout: None,
                                 reconnect_timeout: None,
                                 ping_interval: None,
+                                metadata: HashMap::from([(
+                                    "display_name".into(),
+                                    "Caller".into(),
+                                )]),
                             }
                             .into(),
                         ),
                         (
                             "some-member".into(),
                             member::Spec {
-                                pipeline: [(
+                                pipeline: HashMap::from([(
                                     "publish".into(),
                                     web_rtc_publish::Spec {
                                         p2p: P2pMode::Always,
                                         force_relay: false,
                                         audio_settings: AudioSettings::default(
                                         ),
-                                        video_settings: VideoSettings::default(
-                                        ),
+                                        video_settings: VideoSettings {
+                                            publish_policy: Policy::Required,
+                                            preferred_codecs: vec![Codec {
+                                                mime_type: "video/VP9".into(),
+                                                parameters: HashMap::from([(
+                                                    "profile-id".into(),
+                                                    "0".into(),
+                                                )],),
+                                            }],
+                                            max_bitrate: None,
+                                            max_resolution: Some(Resolution {
+                                                width: 1280,
+                                                height: 720,
+                                            }),
+                                            max_frame_rate: Some(30),
+                                            encodings: vec![Encoding {
+                                                rid: "q".into(),
+                                                codec: None,
+                                                max_bitrate: Some(1_500_000),
+                                                scale_resolution_down_by: None,
+                                                scalability_mode: Some(
+                                                    ScalabilityMode::L3T3,
+                                                ),
+                                            }],
+                                        },
                                     }
                                     .into(),
-                                )]
-                                .into_iter()
-                                .collect(),
+                                )]),
                                 credentials: Some(Credentials::Plain(
                                     "test".into()
                                 )),
@@ -404,6 +454,7 @@ spec:
                                 idle_timeout: None,
                                 reconnect_timeout: None,
                                 ping_interval: None,
+                                metadata: HashMap::new(),
                             }
                             .into(),
                         ),
@@ -420,6 +471,10 @@ spec:
                                                 endpoint_id: "publish".into(),
                                             },
                                             force_relay: false,
+                                            audio_filter: MediaFilter::default(
+                                            ),
+                                            video_filter: MediaFilter::default(
+                                            ),
                                         }
                                         .into(),
                                     ),
@@ -432,6 +487,16 @@ spec:
                                                 endpoint_id: "publish".into(),
                                             },
                                             force_relay: false,
+                                            audio_filter: MediaFilter {
+                                                enabled: false,
+                                                source_kinds: vec![],
+                                            },
+                                            video_filter: MediaFilter {
+                                                enabled: true,
+                                                source_kinds: vec![
+                                                    MediaSourceKind::Display,
+                                                ],
+                                            },
                                         }
                                         .into(),
                                     )
@@ -446,6 +511,7 @@ spec:
                                 idle_timeout: None,
                                 reconnect_timeout: None,
                                 ping_interval: None,
+                                metadata: HashMap::new(),
                             }
                             .into(),
                         ),
@@ -215,6 +215,13 @@ pub struct Member {
         ::prost::alloc::string::String,
         member::Element,
     >,
+    /// Opaque metadata of this `Member` (display name, avatar URL, etc.),
+    /// exposed to other `Member`s of the `Room` via Client API.
+    #[prost(map = "string, string", tag = "10")]
+    pub metadata: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
     /// Credentials to authenticate this `Member` in Client API with.
     ///
     /// Plain and hashed credentials are supported. If no credentials provided,
@@ -230,14 +237,14 @@ pub struct Member {
 /// Nested message and enum types in `Member`.
 pub mod member {
     /// Elements which Member's pipeline can contain.
-    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Element {
         #[prost(oneof = "element::El", tags = "1, 2")]
         pub el: ::core::option::Option<element::El>,
     }
     /// Nested message and enum types in `Element`.
     pub mod element {
-        #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
         pub enum El {
             #[prost(message, tag = "1")]
             WebrtcPlay(super::super::WebRtcPlayEndpoint),
@@ -266,7 +273,7 @@ pub mod member {
 }
 /// Media element receiving media data from a client via WebRTC (allows to
 /// publish media data).
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WebRtcPublishEndpoint {
     /// ID of this `WebRtcPublishEndpoint`.
     #[prost(string, tag = "1")]
@@ -293,18 +300,189 @@ pub struct WebRtcPublishEndpoint {
 /// Nested message and enum types in `WebRtcPublishEndpoint`.
 pub mod web_rtc_publish_endpoint {
     /// Audio media type settings of a `WebRtcPublishEndpoint`.
-    #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct AudioSettings {
         /// Policy to publish the audio media type with.
         #[prost(enumeration = "PublishPolicy", tag = "1")]
         pub publish_policy: i32,
+        /// Codecs to publish the audio media type with, in the order of preference.
+        ///
+        /// If empty, then any codec negotiated with a client may be used.
+        #[prost(message, repeated, tag = "2")]
+        pub preferred_codecs: ::prost::alloc::vec::Vec<Codec>,
+        /// Maximum bitrate (in bits per second) to publish the audio media type
+        /// with.
+        ///
+        /// If not set, then bitrate is not limited by a media server.
+        #[prost(uint32, optional, tag = "3")]
+        pub max_bitrate: ::core::option::Option<u32>,
     }
     /// Video media type settings of `WebRtcPublishEndpoint`.
-    #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct VideoSettings {
         /// Policy to publish the video media type with.
         #[prost(enumeration = "PublishPolicy", tag = "1")]
         pub publish_policy: i32,
+        /// Codecs to publish the video media type with, in the order of preference.
+        ///
+        /// If empty, then any codec negotiated with a client may be used.
+        #[prost(message, repeated, tag = "2")]
+        pub preferred_codecs: ::prost::alloc::vec::Vec<Codec>,
+        /// Maximum total bitrate (in bits per second) to publish the video media
+        /// type with.
+        ///
+        /// If not set, then bitrate is not limited by a media server.
+        #[prost(uint32, optional, tag = "3")]
+        pub max_bitrate: ::core::option::Option<u32>,
+        /// Maximum resolution to publish the video media type with.
+        ///
+        /// If not set, then resolution is not limited by a media server.
+        #[prost(message, optional, tag = "4")]
+        pub max_resolution: ::core::option::Option<Resolution>,
+        /// Maximum frame rate (in frames per second) to publish the video media
+        /// type with.
+        ///
+        /// If not set, then frame rate is not limited by a media server.
+        #[prost(uint32, optional, tag = "5")]
+        pub max_frame_rate: ::core::option::Option<u32>,
+        /// Encodings (simulcast and/or SVC layers) to publish the video media type
+        /// with.
+        ///
+        /// Multiple encodings define simulcast layers, while a single encoding
+        /// with a `scalability_mode` defines SVC layers.
+        /// If empty, then a single encoding chosen by a client is published.
+        #[prost(message, repeated, tag = "6")]
+        pub encodings: ::prost::alloc::vec::Vec<Encoding>,
+    }
+    /// Codec to publish a media type with.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Codec {
+        /// MIME `type/subtype` of this `Codec` (`video/VP9`, for example).
+        #[prost(string, tag = "1")]
+        pub mime_type: ::prost::alloc::string::String,
+        /// Codec-specific parameters that must be signaled to the remote party
+        /// (`profile-id`, for example).
+        #[prost(map = "string, string", tag = "2")]
+        pub parameters: ::std::collections::HashMap<
+            ::prost::alloc::string::String,
+            ::prost::alloc::string::String,
+        >,
+    }
+    /// Resolution of a published video.
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+    pub struct Resolution {
+        /// Width (in pixels) of the video.
+        #[prost(uint32, tag = "1")]
+        pub width: u32,
+        /// Height (in pixels) of the video.
+        #[prost(uint32, tag = "2")]
+        pub height: u32,
+    }
+    /// Single encoding (simulcast layer) of a published video.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Encoding {
+        /// RTP stream ID (RID) of this `Encoding`.
+        #[prost(string, tag = "1")]
+        pub rid: ::prost::alloc::string::String,
+        /// Concrete codec to be used for this `Encoding`.
+        ///
+        /// If not set, then any negotiated codec can be used.
+        #[prost(message, optional, tag = "2")]
+        pub codec: ::core::option::Option<Codec>,
+        /// Maximum bitrate (in bits per second) to publish this `Encoding` with.
+        ///
+        /// If not set, then bitrate is not limited by a media server.
+        #[prost(uint32, optional, tag = "3")]
+        pub max_bitrate: ::core::option::Option<u32>,
+        /// Factor for scaling down video's resolution in each dimension before
+        /// publishing this `Encoding`.
+        ///
+        /// Must be greater than or equal to `1`, and less than or equal to `255`.
+        #[prost(uint32, optional, tag = "4")]
+        pub scale_resolution_down_by: ::core::option::Option<u32>,
+        /// SVC (Scalable Video Coding) scalability mode of this `Encoding`.
+        ///
+        /// If not set, then SVC is not used.
+        #[prost(enumeration = "ScalabilityMode", optional, tag = "5")]
+        pub scalability_mode: ::core::option::Option<i32>,
+    }
+    /// Scalability mode of SVC (Scalable Video Coding).
+    ///
+    /// See <https://w3.org/TR/webrtc-svc#scalabilitymodes\*> for details.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum ScalabilityMode {
+        L1t1 = 0,
+        L1t2 = 1,
+        L1t3 = 2,
+        L2t1 = 3,
+        L2t2 = 4,
+        L2t3 = 5,
+        L3t1 = 6,
+        L3t2 = 7,
+        L3t3 = 8,
+        S2t1 = 9,
+        S2t2 = 10,
+        S2t3 = 11,
+        S3t1 = 12,
+        S3t2 = 13,
+        S3t3 = 14,
+    }
+    impl ScalabilityMode {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::L1t1 => "L1T1",
+                Self::L1t2 => "L1T2",
+                Self::L1t3 => "L1T3",
+                Self::L2t1 => "L2T1",
+                Self::L2t2 => "L2T2",
+                Self::L2t3 => "L2T3",
+                Self::L3t1 => "L3T1",
+                Self::L3t2 => "L3T2",
+                Self::L3t3 => "L3T3",
+                Self::S2t1 => "S2T1",
+                Self::S2t2 => "S2T2",
+                Self::S2t3 => "S2T3",
+                Self::S3t1 => "S3T1",
+                Self::S3t2 => "S3T2",
+                Self::S3t3 => "S3T3",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "L1T1" => Some(Self::L1t1),
+                "L1T2" => Some(Self::L1t2),
+                "L1T3" => Some(Self::L1t3),
+                "L2T1" => Some(Self::L2t1),
+                "L2T2" => Some(Self::L2t2),
+                "L2T3" => Some(Self::L2t3),
+                "L3T1" => Some(Self::L3t1),
+                "L3T2" => Some(Self::L3t2),
+                "L3T3" => Some(Self::L3t3),
+                "S2T1" => Some(Self::S2t1),
+                "S2T2" => Some(Self::S2t2),
+                "S2T3" => Some(Self::S2t3),
+                "S3T1" => Some(Self::S3t1),
+                "S3T2" => Some(Self::S3t2),
+                "S3T3" => Some(Self::S3t3),
+                _ => None,
+            }
+        }
     }
     /// Policy of how a video or an audio media type can be published in a
     /// `WebRtcPublishEndpoint`.
@@ -427,6 +605,72 @@ pub struct WebRtcPlayEndpoint {
     /// Indicator whether to relay all media data through a TURN server forcibly.
     #[prost(bool, tag = "5")]
     pub force_relay: bool,
+    /// Filter of the audio media type played by this `WebRtcPlayEndpoint`.
+    ///
+    /// If not set, then all the audio published by the source is played.
+    #[prost(message, optional, tag = "6")]
+    pub audio_filter: ::core::option::Option<web_rtc_play_endpoint::MediaFilter>,
+    /// Filter of the video media type played by this `WebRtcPlayEndpoint`.
+    ///
+    /// If not set, then all the video published by the source is played.
+    #[prost(message, optional, tag = "7")]
+    pub video_filter: ::core::option::Option<web_rtc_play_endpoint::MediaFilter>,
+}
+/// Nested message and enum types in `WebRtcPlayEndpoint`.
+pub mod web_rtc_play_endpoint {
+    /// Filter of a media type played by a `WebRtcPlayEndpoint`.
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+    pub struct MediaFilter {
+        /// Indicator whether the media type is played at all.
+        ///
+        /// If not set, then the media type is played.
+        #[prost(bool, optional, tag = "1")]
+        pub enabled: ::core::option::Option<bool>,
+        /// Kinds of media sources to play the media type from.
+        ///
+        /// If empty, then media of any source kind is played.
+        #[prost(enumeration = "MediaSourceKind", repeated, tag = "2")]
+        pub source_kinds: ::prost::alloc::vec::Vec<i32>,
+    }
+    /// Possible kinds of media sources.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum MediaSourceKind {
+        /// Media is sourced by some media device (webcam or microphone).
+        Device = 0,
+        /// Media is obtained with screen-capture.
+        Display = 1,
+    }
+    impl MediaSourceKind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Device => "DEVICE",
+                Self::Display => "DISPLAY",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "DEVICE" => Some(Self::Device),
+                "DISPLAY" => Some(Self::Display),
+                _ => None,
+            }
+        }
+    }
 }
 /// Ping message received by a media server periodically for probing its
 /// healthiness.
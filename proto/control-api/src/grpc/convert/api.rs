@@ -11,9 +11,10 @@ use crate::{
     control::{ParseFidError, Request},
     endpoint::{
         self, web_rtc_play,
-        web_rtc_play::WebRtcPlay,
+        web_rtc_play::{MediaFilter, MediaSourceKind, WebRtcPlay},
         web_rtc_publish::{
-            self, AudioSettings, P2pMode, Policy, VideoSettings, WebRtcPublish,
+            self, AudioSettings, Codec, Encoding, P2pMode, Policy, Resolution,
+            ScalabilityMode, VideoSettings, WebRtcPublish,
         },
     },
     grpc::{CallbackUrlParseError, api as proto, convert::ProtobufError},
@@ -369,6 +370,7 @@ impl TryFrom<proto::Member> for Member {
                 idle_timeout,
                 reconnect_timeout,
                 ping_interval,
+                metadata: member.metadata,
             },
         })
     }
@@ -413,6 +415,7 @@ impl From<Member> for proto::Member {
                 })
                 .collect(),
             credentials: member.spec.credentials.map(Into::into),
+            metadata: member.spec.metadata,
         }
     }
 }
@@ -563,6 +566,16 @@ impl TryFrom<proto::WebRtcPlayEndpoint> for WebRtcPlay {
             spec: web_rtc_play::Spec {
                 src: val.src.parse()?,
                 force_relay: val.force_relay,
+                audio_filter: val
+                    .audio_filter
+                    .map(MediaFilter::try_from)
+                    .transpose()?
+                    .unwrap_or_default(),
+                video_filter: val
+                    .video_filter
+                    .map(MediaFilter::try_from)
+                    .transpose()?
+                    .unwrap_or_default(),
             },
         })
     }
@@ -576,6 +589,66 @@ impl From<WebRtcPlay> for proto::WebRtcPlayEndpoint {
             on_start: String::new(),
             on_stop: String::new(),
             force_relay: play.spec.force_relay,
+            audio_filter: Some(play.spec.audio_filter.into()),
+            video_filter: Some(play.spec.video_filter.into()),
+        }
+    }
+}
+
+impl TryFrom<proto::web_rtc_play_endpoint::MediaFilter> for MediaFilter {
+    type Error = ProtobufError;
+
+    fn try_from(
+        val: proto::web_rtc_play_endpoint::MediaFilter,
+    ) -> Result<Self, Self::Error> {
+        use proto::web_rtc_play_endpoint::MediaSourceKind as Proto;
+
+        #[expect(clippy::map_err_ignore, reason = "not useful")]
+        let source_kinds = val
+            .source_kinds
+            .into_iter()
+            .map(|k| {
+                Proto::try_from(k)
+                    .map(MediaSourceKind::from)
+                    .map_err(|_| ProtobufError::InvalidMediaSourceKind(k))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { enabled: val.enabled.unwrap_or(true), source_kinds })
+    }
+}
+
+impl From<MediaFilter> for proto::web_rtc_play_endpoint::MediaFilter {
+    fn from(filter: MediaFilter) -> Self {
+        use proto::web_rtc_play_endpoint::MediaSourceKind as Proto;
+
+        Self {
+            enabled: Some(filter.enabled),
+            source_kinds: filter
+                .source_kinds
+                .into_iter()
+                .map(|k| Proto::from(k).into())
+                .collect(),
+        }
+    }
+}
+
+impl From<proto::web_rtc_play_endpoint::MediaSourceKind> for MediaSourceKind {
+    fn from(val: proto::web_rtc_play_endpoint::MediaSourceKind) -> Self {
+        use proto::web_rtc_play_endpoint::MediaSourceKind as Proto;
+
+        match val {
+            Proto::Device => Self::Device,
+            Proto::Display => Self::Display,
+        }
+    }
+}
+
+impl From<MediaSourceKind> for proto::web_rtc_play_endpoint::MediaSourceKind {
+    fn from(val: MediaSourceKind) -> Self {
+        match val {
+            MediaSourceKind::Device => Self::Device,
+            MediaSourceKind::Display => Self::Display,
         }
     }
 }
@@ -598,7 +671,8 @@ impl TryFrom<proto::WebRtcPublishEndpoint> for WebRtcPublish {
                     .unwrap_or_default(),
                 video_settings: val
                     .video_settings
-                    .map(VideoSettings::from)
+                    .map(VideoSettings::try_from)
+                    .transpose()?
                     .unwrap_or_default(),
                 force_relay: val.force_relay,
             },
@@ -630,6 +704,12 @@ impl From<proto::web_rtc_publish_endpoint::AudioSettings> for AudioSettings {
             publish_policy: PublishPolicy::try_from(val.publish_policy)
                 .unwrap_or_default()
                 .into(),
+            preferred_codecs: val
+                .preferred_codecs
+                .into_iter()
+                .map(Codec::from)
+                .collect(),
+            max_bitrate: val.max_bitrate,
         }
     }
 }
@@ -640,19 +720,42 @@ impl From<AudioSettings> for proto::web_rtc_publish_endpoint::AudioSettings {
 
         Self {
             publish_policy: PublishPolicy::from(settings.publish_policy).into(),
+            preferred_codecs: settings
+                .preferred_codecs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            max_bitrate: settings.max_bitrate,
         }
     }
 }
 
-impl From<proto::web_rtc_publish_endpoint::VideoSettings> for VideoSettings {
-    fn from(val: proto::web_rtc_publish_endpoint::VideoSettings) -> Self {
+impl TryFrom<proto::web_rtc_publish_endpoint::VideoSettings> for VideoSettings {
+    type Error = ProtobufError;
+
+    fn try_from(
+        val: proto::web_rtc_publish_endpoint::VideoSettings,
+    ) -> Result<Self, Self::Error> {
         use proto::web_rtc_publish_endpoint::PublishPolicy;
 
-        Self {
+        Ok(Self {
             publish_policy: PublishPolicy::try_from(val.publish_policy)
                 .unwrap_or_default()
                 .into(),
-        }
+            preferred_codecs: val
+                .preferred_codecs
+                .into_iter()
+                .map(Codec::from)
+                .collect(),
+            max_bitrate: val.max_bitrate,
+            max_resolution: val.max_resolution.map(Resolution::from),
+            max_frame_rate: val.max_frame_rate,
+            encodings: val
+                .encodings
+                .into_iter()
+                .map(Encoding::try_from)
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
@@ -662,6 +765,144 @@ impl From<VideoSettings> for proto::web_rtc_publish_endpoint::VideoSettings {
 
         Self {
             publish_policy: PublishPolicy::from(settings.publish_policy).into(),
+            preferred_codecs: settings
+                .preferred_codecs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            max_bitrate: settings.max_bitrate,
+            max_resolution: settings.max_resolution.map(Into::into),
+            max_frame_rate: settings.max_frame_rate,
+            encodings: settings.encodings.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<proto::web_rtc_publish_endpoint::Codec> for Codec {
+    fn from(val: proto::web_rtc_publish_endpoint::Codec) -> Self {
+        Self { mime_type: val.mime_type, parameters: val.parameters }
+    }
+}
+
+impl From<Codec> for proto::web_rtc_publish_endpoint::Codec {
+    fn from(codec: Codec) -> Self {
+        Self { mime_type: codec.mime_type, parameters: codec.parameters }
+    }
+}
+
+impl From<proto::web_rtc_publish_endpoint::Resolution> for Resolution {
+    fn from(val: proto::web_rtc_publish_endpoint::Resolution) -> Self {
+        Self { width: val.width, height: val.height }
+    }
+}
+
+impl From<Resolution> for proto::web_rtc_publish_endpoint::Resolution {
+    fn from(resolution: Resolution) -> Self {
+        Self { width: resolution.width, height: resolution.height }
+    }
+}
+
+impl TryFrom<proto::web_rtc_publish_endpoint::Encoding> for Encoding {
+    type Error = ProtobufError;
+
+    fn try_from(
+        val: proto::web_rtc_publish_endpoint::Encoding,
+    ) -> Result<Self, Self::Error> {
+        use proto::web_rtc_publish_endpoint::ScalabilityMode as Proto;
+
+        let scale_resolution_down_by = val
+            .scale_resolution_down_by
+            .map(|v| {
+                u8::try_from(v)
+                    .ok()
+                    .filter(|v| *v >= 1)
+                    .ok_or(ProtobufError::InvalidScaleResolutionDownBy(v))
+            })
+            .transpose()?;
+        #[expect(clippy::map_err_ignore, reason = "not useful")]
+        let scalability_mode = val
+            .scalability_mode
+            .map(|m| {
+                Proto::try_from(m)
+                    .map(ScalabilityMode::from)
+                    .map_err(|_| ProtobufError::InvalidScalabilityMode(m))
+            })
+            .transpose()?;
+
+        Ok(Self {
+            rid: val.rid,
+            codec: val.codec.map(Codec::from),
+            max_bitrate: val.max_bitrate,
+            scale_resolution_down_by,
+            scalability_mode,
+        })
+    }
+}
+
+impl From<Encoding> for proto::web_rtc_publish_endpoint::Encoding {
+    fn from(encoding: Encoding) -> Self {
+        use proto::web_rtc_publish_endpoint::ScalabilityMode as Proto;
+
+        Self {
+            rid: encoding.rid,
+            codec: encoding.codec.map(Into::into),
+            max_bitrate: encoding.max_bitrate,
+            scale_resolution_down_by: encoding
+                .scale_resolution_down_by
+                .map(u32::from),
+            scalability_mode: encoding
+                .scalability_mode
+                .map(|m| Proto::from(m).into()),
+        }
+    }
+}
+
+impl From<proto::web_rtc_publish_endpoint::ScalabilityMode>
+    for ScalabilityMode
+{
+    fn from(val: proto::web_rtc_publish_endpoint::ScalabilityMode) -> Self {
+        use proto::web_rtc_publish_endpoint::ScalabilityMode as Proto;
+
+        match val {
+            Proto::L1t1 => Self::L1T1,
+            Proto::L1t2 => Self::L1T2,
+            Proto::L1t3 => Self::L1T3,
+            Proto::L2t1 => Self::L2T1,
+            Proto::L2t2 => Self::L2T2,
+            Proto::L2t3 => Self::L2T3,
+            Proto::L3t1 => Self::L3T1,
+            Proto::L3t2 => Self::L3T2,
+            Proto::L3t3 => Self::L3T3,
+            Proto::S2t1 => Self::S2T1,
+            Proto::S2t2 => Self::S2T2,
+            Proto::S2t3 => Self::S2T3,
+            Proto::S3t1 => Self::S3T1,
+            Proto::S3t2 => Self::S3T2,
+            Proto::S3t3 => Self::S3T3,
+        }
+    }
+}
+
+impl From<ScalabilityMode>
+    for proto::web_rtc_publish_endpoint::ScalabilityMode
+{
+    fn from(val: ScalabilityMode) -> Self {
+        match val {
+            ScalabilityMode::L1T1 => Self::L1t1,
+            ScalabilityMode::L1T2 => Self::L1t2,
+            ScalabilityMode::L1T3 => Self::L1t3,
+            ScalabilityMode::L2T1 => Self::L2t1,
+            ScalabilityMode::L2T2 => Self::L2t2,
+            ScalabilityMode::L2T3 => Self::L2t3,
+            ScalabilityMode::L3T1 => Self::L3t1,
+            ScalabilityMode::L3T2 => Self::L3t2,
+            ScalabilityMode::L3T3 => Self::L3t3,
+            ScalabilityMode::S2T1 => Self::S2t1,
+            ScalabilityMode::S2T2 => Self::S2t2,
+            ScalabilityMode::S2T3 => Self::S2t3,
+            ScalabilityMode::S3T1 => Self::S3t1,
+            ScalabilityMode::S3T2 => Self::S3t2,
+            ScalabilityMode::S3T3 => Self::S3t3,
         }
     }
 }
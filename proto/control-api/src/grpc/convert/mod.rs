@@ -63,6 +63,28 @@ pub enum ProtobufError {
     #[from(ignore)]
     InvalidDuration(Box<str>, &'static str),
 
+    /// [`Encoding`] specifies `scale_resolution_down_by` value less than `1`
+    /// or not fitting into [`u8`].
+    ///
+    /// [`Encoding`]: crate::endpoint::web_rtc_publish::Encoding
+    #[display("Invalid `scale_resolution_down_by` value: {_0}")]
+    #[from(ignore)]
+    InvalidScaleResolutionDownBy(#[error(not(source))] u32),
+
+    /// [`Encoding`] specifies unknown `scalability_mode` value.
+    ///
+    /// [`Encoding`]: crate::endpoint::web_rtc_publish::Encoding
+    #[display("Invalid `scalability_mode` value: {_0}")]
+    #[from(ignore)]
+    InvalidScalabilityMode(#[error(not(source))] i32),
+
+    /// [`MediaFilter`] specifies unknown `source_kinds` value.
+    ///
+    /// [`MediaFilter`]: crate::endpoint::web_rtc_play::MediaFilter
+    #[display("Invalid media source kind value: {_0}")]
+    #[from(ignore)]
+    InvalidMediaSourceKind(#[error(not(source))] i32),
+
     /// Error of parsing a [`Fid`].
     ///
     /// [`Fid`]: crate::Fid
@@ -89,6 +111,9 @@ impl From<ProtobufError> for tonic::Status {
             | ProtobufError::NoElementForId(_)
             | ProtobufError::CallbackUrlParseErr(_)
             | ProtobufError::InvalidDuration(_, _)
+            | ProtobufError::InvalidScaleResolutionDownBy(_)
+            | ProtobufError::InvalidScalabilityMode(_)
+            | ProtobufError::InvalidMediaSourceKind(_)
             | ProtobufError::ParseFidErr(_)
             | ProtobufError::TimeParseErr(_) => {
                 Self::invalid_argument(err.to_string())
@@ -153,6 +153,8 @@ pub mod control;
 pub mod direct;
 #[cfg(feature = "grpc")]
 pub mod grpc;
+#[cfg(feature = "turn")]
+pub mod turn;
 
 #[doc(inline)]
 pub use self::{
@@ -0,0 +1,154 @@
+//! Ephemeral [TURN] credentials definitions.
+//!
+//! Implements a [TURN REST API] scheme of time-limited credentials, as
+//! supported by [coturn] with its `use-auth-secret` option.
+//!
+//! [coturn]: https://github.com/coturn/coturn
+//! [TURN]: https://webrtcglossary.com/turn
+//! [TURN REST API]: https://tools.ietf.org/html/draft-uberti-behave-turn-rest-00
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::{Engine as _, prelude::BASE64_STANDARD};
+use derive_more::with_trait::{AsRef, From};
+use hmac::{Hmac, Mac as _};
+use secrecy::{ExposeSecret as _, SecretString};
+use sha1::Sha1;
+
+/// Secret shared between a [TURN] server and a media server, used for minting
+/// [`EphemeralCredentials`].
+///
+/// [TURN]: https://webrtcglossary.com/turn
+#[derive(AsRef, Clone, Debug)]
+pub struct SharedSecret(SecretString);
+
+impl SharedSecret {
+    /// Mints new [`EphemeralCredentials`] for the provided `user`, valid for
+    /// the provided `ttl` from now.
+    #[must_use]
+    pub fn credentials(
+        &self,
+        user: &str,
+        ttl: Duration,
+    ) -> EphemeralCredentials {
+        self.credentials_until(user, SystemTime::now() + ttl)
+    }
+
+    /// Mints new [`EphemeralCredentials`] for the provided `user`, valid until
+    /// the provided `expires_at` moment.
+    ///
+    /// # Panics
+    ///
+    /// Never, since HMAC accepts keys of any size.
+    ///
+    /// ```
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// # use medea_control_api_proto::turn::SharedSecret;
+    /// #
+    /// let creds = SharedSecret::from("north").credentials_until(
+    ///     "alice",
+    ///     UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+    /// );
+    ///
+    /// assert_eq!(creds.username, "1700000000:alice");
+    /// assert_eq!(creds.password.expose_str(), "Cd/49soE35ICqcJF/bCTn8Z4OyE=");
+    /// ```
+    #[must_use]
+    pub fn credentials_until(
+        &self,
+        user: &str,
+        expires_at: SystemTime,
+    ) -> EphemeralCredentials {
+        let timestamp =
+            expires_at.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let username = if user.is_empty() {
+            timestamp.to_string()
+        } else {
+            format!("{timestamp}:{user}")
+        };
+
+        #[expect(clippy::expect_used, reason = "HMAC accepts keys of any size")]
+        let mut mac =
+            Hmac::<Sha1>::new_from_slice(self.0.expose_secret().as_bytes())
+                .expect("HMAC accepts keys of any size");
+        mac.update(username.as_bytes());
+        let password =
+            BASE64_STANDARD.encode(mac.finalize().into_bytes()).into();
+
+        EphemeralCredentials {
+            username,
+            password,
+            expires_at: UNIX_EPOCH + Duration::from_secs(timestamp),
+        }
+    }
+}
+
+impl<T> From<T> for SharedSecret
+where
+    T: Into<String>,
+{
+    fn from(value: T) -> Self {
+        Self(value.into().into())
+    }
+}
+
+/// Time-limited credentials to authenticate on a [TURN] server with.
+///
+/// [TURN]: https://webrtcglossary.com/turn
+#[derive(Clone, Debug)]
+pub struct EphemeralCredentials {
+    /// Username in a `<expiration-timestamp>:<user>` format.
+    pub username: String,
+
+    /// Password being a Base64-encoded HMAC-SHA1 of the
+    /// [`EphemeralCredentials::username`].
+    pub password: Password,
+
+    /// Moment when these [`EphemeralCredentials`] expire.
+    pub expires_at: SystemTime,
+}
+
+impl EphemeralCredentials {
+    /// Indicates whether these [`EphemeralCredentials`] should be refreshed
+    /// at the provided moment, considering the provided `margin` before their
+    /// actual expiration.
+    #[must_use]
+    pub fn expires_within(&self, now: SystemTime, margin: Duration) -> bool {
+        now + margin >= self.expires_at
+    }
+
+    /// Converts these [`EphemeralCredentials`] into a Client API
+    /// [`IceServer`] with the provided `urls`.
+    ///
+    /// [`IceServer`]: medea_client_api_proto::IceServer
+    #[cfg(feature = "client-api")]
+    #[must_use]
+    pub fn into_ice_server(
+        self,
+        urls: Vec<String>,
+    ) -> medea_client_api_proto::IceServer {
+        medea_client_api_proto::IceServer {
+            urls,
+            username: Some(self.username),
+            credential: Some(self.password.0.into()),
+        }
+    }
+}
+
+/// Password of [`EphemeralCredentials`].
+#[derive(Clone, Debug, From)]
+pub struct Password(SecretString);
+
+impl Password {
+    /// Provides access to the underlying secret [`str`].
+    #[must_use]
+    pub fn expose_str(&self) -> &str {
+        self.0.expose_secret()
+    }
+}
+
+impl From<String> for Password {
+    fn from(value: String) -> Self {
+        Self(value.into())
+    }
+}
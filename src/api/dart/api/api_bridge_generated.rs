@@ -332,6 +332,72 @@ fn wire__crate__api__dart__api__jason__Jason_jason_dispose_impl(
                      let output_ok = Result::<_,()>::Ok({ crate::api::dart::api::jason::Jason::jason_dispose(api_that); })?;   Ok(output_ok)
                 })()) })
 }
+fn wire__crate__api__dart__api__jason__Jason_jason_enable_rpc_recording_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "Jason_jason_enable_rpc_recording", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Jason>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::jason::Jason::jason_enable_rpc_recording(&*api_that_guard))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__jason__Jason_jason_enable_sse_fallback_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "Jason_jason_enable_sse_fallback", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Jason>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::jason::Jason::jason_enable_sse_fallback(&*api_that_guard))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__jason__Jason_jason_export_rpc_recording_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "Jason_jason_export_rpc_recording", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Jason>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::jason::Jason::jason_export_rpc_recording(&*api_that_guard))?;   Ok(output_ok)
+                })()) })
+}
 fn wire__crate__api__dart__api__jason__Jason_jason_init_room_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -398,6 +464,74 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
  let output_ok = Result::<_,()>::Ok(crate::api::dart::api::jason::Jason::jason_network_changed(&*api_that_guard))?;   Ok(output_ok)
                 })()) })
 }
+fn wire__crate__api__dart__api__jason__Jason_jason_on_network_state_change_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "Jason_jason_on_network_state_change", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Jason>>>::sse_decode(&mut deserializer);
+let api_cb = <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::jason::Jason::jason_on_network_state_change(&*api_that_guard, api_cb))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__jason__Jason_jason_set_network_online_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "Jason_jason_set_network_online", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Jason>>>::sse_decode(&mut deserializer);
+let api_online = <bool>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::jason::Jason::jason_set_network_online(&*api_that_guard, api_online))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__jason__Jason_jason_share_rpc_connection_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "Jason_jason_share_rpc_connection", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Jason>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::jason::Jason::jason_share_rpc_connection(&*api_that_guard))?;   Ok(output_ok)
+                })()) })
+}
 fn wire__crate__api__dart__api__jason__Jason_new_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -411,6 +545,29 @@ fn wire__crate__api__dart__api__jason__Jason_new_impl(
                      let output_ok = Result::<_,()>::Ok(crate::api::dart::api::jason::Jason::new())?;   Ok(output_ok)
                 })()) })
 }
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_apply_constraints_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_apply_constraints", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);
+let api_constraints = <crate::api::dart::api::ApiDeviceVideoTrackConstraints>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::apply_constraints(&*api_that_guard, api_constraints))?;   Ok(output_ok)
+                })()) })
+}
 fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_free_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -547,6 +704,72 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
  let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::is_echo_cancellation_enabled(&*api_that_guard))?;   Ok(output_ok)
                 })()) })
 }
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_exposure_compensation_available_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_is_exposure_compensation_available", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::is_exposure_compensation_available(&*api_that_guard))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_focus_distance_available_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_is_focus_distance_available", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::is_focus_distance_available(&*api_that_guard))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_focus_mode_available_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_is_focus_mode_available", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::is_focus_mode_available(&*api_that_guard))?;   Ok(output_ok)
+                })()) })
+}
 fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_high_pass_filter_enabled_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -613,6 +836,72 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
  let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::is_on_audio_level_available(&*api_that_guard))?;   Ok(output_ok)
                 })()) })
 }
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_torch_available_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_is_torch_available", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::is_torch_available(&*api_that_guard))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_white_balance_mode_available_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_is_white_balance_mode_available", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::is_white_balance_mode_available(&*api_that_guard))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_zoom_available_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_is_zoom_available", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::is_zoom_available(&*api_that_guard))?;   Ok(output_ok)
+                })()) })
+}
 fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_kind_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -749,6 +1038,75 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
  let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::set_echo_cancellation_enabled(&*api_that_guard, api_enabled))?;   Ok(output_ok)
                 })()) })
 }
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_exposure_compensation_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_set_exposure_compensation", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);
+let api_compensation = <f64>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::set_exposure_compensation(&*api_that_guard, api_compensation))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_focus_distance_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_set_focus_distance", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);
+let api_distance = <f64>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::set_focus_distance(&*api_that_guard, api_distance))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_focus_mode_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_set_focus_mode", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);
+let api_mode = <crate::media::constraints::MeteringMode>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::set_focus_mode(&*api_that_guard, api_mode))?;   Ok(output_ok)
+                })()) })
+}
 fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_high_pass_filter_enabled_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -792,19 +1150,177 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
             }
         }
         let api_that_guard = api_that_guard.unwrap();
- let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::set_noise_suppression_enabled(&*api_that_guard, api_enabled))?;   Ok(output_ok)
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::set_noise_suppression_enabled(&*api_that_guard, api_enabled))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_noise_suppression_level_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_set_noise_suppression_level", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);
+let api_level = <crate::media::constraints::NoiseSuppressionLevel>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::set_noise_suppression_level(&*api_that_guard, api_level))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_torch_enabled_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_set_torch_enabled", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);
+let api_enabled = <bool>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::set_torch_enabled(&*api_that_guard, api_enabled))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_white_balance_mode_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_set_white_balance_mode", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);
+let api_mode = <crate::media::constraints::MeteringMode>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::set_white_balance_mode(&*api_that_guard, api_mode))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_zoom_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_set_zoom", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);
+let api_zoom = <f64>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::set_zoom(&*api_that_guard, api_zoom))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_state_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_state", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::state(&*api_that_guard))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_device_capabilities_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_device_capabilities", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaManagerHandle>>>::sse_decode(&mut deserializer);
+let api_device_id = <String>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::device_capabilities(&*api_that_guard, api_device_id))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_enumerate_devices_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_enumerate_devices", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaManagerHandle>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::enumerate_devices(&*api_that_guard))?;   Ok(output_ok)
                 })()) })
 }
-fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_noise_suppression_level_impl(
+fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_enumerate_displays_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_set_noise_suppression_level", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_enumerate_displays", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
             let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
             let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);
-let api_level = <crate::media::constraints::NoiseSuppressionLevel>::sse_decode(&mut deserializer);deserializer.end();
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaManagerHandle>>>::sse_decode(&mut deserializer);deserializer.end();
                 transform_result_sse::<_, ()>((move || {
                     let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
@@ -815,18 +1331,19 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
             }
         }
         let api_that_guard = api_that_guard.unwrap();
- let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::set_noise_suppression_level(&*api_that_guard, api_level))?;   Ok(output_ok)
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::enumerate_displays(&*api_that_guard))?;   Ok(output_ok)
                 })()) })
 }
-fn wire__crate__api__dart__api__local_media_track__LocalMediaTrack_state_impl(
+fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_init_local_tracks_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "LocalMediaTrack_state", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_init_local_tracks", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
             let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
             let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LocalMediaTrack>>>::sse_decode(&mut deserializer);deserializer.end();
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaManagerHandle>>>::sse_decode(&mut deserializer);
+let api_caps = <crate::api::dart::api::ApiMediaStreamSettings>::sse_decode(&mut deserializer);deserializer.end();
                 transform_result_sse::<_, ()>((move || {
                     let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
@@ -837,15 +1354,15 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
             }
         }
         let api_that_guard = api_that_guard.unwrap();
- let output_ok = Result::<_,()>::Ok(crate::api::dart::api::local_media_track::LocalMediaTrack::state(&*api_that_guard))?;   Ok(output_ok)
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::init_local_tracks(&*api_that_guard, api_caps))?;   Ok(output_ok)
                 })()) })
 }
-fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_enumerate_devices_impl(
+fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_microphone_volume_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_enumerate_devices", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_microphone_volume", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
             let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
             let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaManagerHandle>>>::sse_decode(&mut deserializer);deserializer.end();
@@ -859,15 +1376,15 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
             }
         }
         let api_that_guard = api_that_guard.unwrap();
- let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::enumerate_devices(&*api_that_guard))?;   Ok(output_ok)
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::microphone_volume(&*api_that_guard))?;   Ok(output_ok)
                 })()) })
 }
-fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_enumerate_displays_impl(
+fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_microphone_volume_is_available_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_enumerate_displays", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_microphone_volume_is_available", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
             let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
             let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaManagerHandle>>>::sse_decode(&mut deserializer);deserializer.end();
@@ -881,20 +1398,20 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
             }
         }
         let api_that_guard = api_that_guard.unwrap();
- let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::enumerate_displays(&*api_that_guard))?;   Ok(output_ok)
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::microphone_volume_is_available(&*api_that_guard))?;   Ok(output_ok)
                 })()) })
 }
-fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_init_local_tracks_impl(
+fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_on_device_change_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_init_local_tracks", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_on_device_change", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
             let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
             let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaManagerHandle>>>::sse_decode(&mut deserializer);
-let api_caps = <crate::api::dart::api::ApiMediaStreamSettings>::sse_decode(&mut deserializer);deserializer.end();
-                transform_result_sse::<_, ()>((move || {
+let api_cb = <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, flutter_rust_bridge::DartOpaque>((move || {
                     let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
@@ -904,18 +1421,20 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
             }
         }
         let api_that_guard = api_that_guard.unwrap();
- let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::init_local_tracks(&*api_that_guard, api_caps))?;   Ok(output_ok)
+ let output_ok = crate::api::dart::api::media_manager::MediaManagerHandle::on_device_change(&*api_that_guard, api_cb)?;   Ok(output_ok)
                 })()) })
 }
-fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_microphone_volume_impl(
+fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_on_permission_change_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_microphone_volume", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_on_permission_change", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
             let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
             let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaManagerHandle>>>::sse_decode(&mut deserializer);deserializer.end();
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaManagerHandle>>>::sse_decode(&mut deserializer);
+let api_kind = <crate::media::PermissionKind>::sse_decode(&mut deserializer);
+let api_cb = <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer);deserializer.end();
                 transform_result_sse::<_, ()>((move || {
                     let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
@@ -926,18 +1445,19 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
             }
         }
         let api_that_guard = api_that_guard.unwrap();
- let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::microphone_volume(&*api_that_guard))?;   Ok(output_ok)
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::on_permission_change(&*api_that_guard, api_kind, api_cb))?;   Ok(output_ok)
                 })()) })
 }
-fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_microphone_volume_is_available_impl(
+fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_permission_state_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_microphone_volume_is_available", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_permission_state", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
             let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
             let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaManagerHandle>>>::sse_decode(&mut deserializer);deserializer.end();
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaManagerHandle>>>::sse_decode(&mut deserializer);
+let api_kind = <crate::media::PermissionKind>::sse_decode(&mut deserializer);deserializer.end();
                 transform_result_sse::<_, ()>((move || {
                     let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
@@ -948,20 +1468,20 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
             }
         }
         let api_that_guard = api_that_guard.unwrap();
- let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::microphone_volume_is_available(&*api_that_guard))?;   Ok(output_ok)
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::permission_state(&*api_that_guard, api_kind))?;   Ok(output_ok)
                 })()) })
 }
-fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_on_device_change_impl(
+fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_run_diagnostics_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_on_device_change", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaManagerHandle_run_diagnostics", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
             let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
             let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaManagerHandle>>>::sse_decode(&mut deserializer);
-let api_cb = <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer);deserializer.end();
-                transform_result_sse::<_, flutter_rust_bridge::DartOpaque>((move || {
+let api_ice_servers = <Vec<crate::api::dart::api::diagnostics::ApiIceServer>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
                     let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
@@ -971,7 +1491,7 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
             }
         }
         let api_that_guard = api_that_guard.unwrap();
- let output_ok = crate::api::dart::api::media_manager::MediaManagerHandle::on_device_change(&*api_that_guard, api_cb)?;   Ok(output_ok)
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::media_manager::MediaManagerHandle::run_diagnostics(&*api_that_guard, api_ice_servers))?;   Ok(output_ok)
                 })()) })
 }
 fn wire__crate__api__dart__api__media_manager__MediaManagerHandle_set_microphone_volume_impl(
@@ -1504,6 +2024,76 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
  let output_ok = Result::<_,()>::Ok(crate::api::dart::api::room::RoomHandle::join(&*api_that_guard, api_token))?;   Ok(output_ok)
                 })()) })
 }
+fn wire__crate__api__dart__api__room__RoomHandle_join_with_credential_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "RoomHandle_join_with_credential", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RoomHandle>>>::sse_decode(&mut deserializer);
+let api_url = <String>::sse_decode(&mut deserializer);
+let api_credential = <String>::sse_decode(&mut deserializer);
+let api_headers = <std::collections::HashMap<String, String>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::room::RoomHandle::join_with_credential(&*api_that_guard, api_url, api_credential, api_headers))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__room__RoomHandle_leave_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "RoomHandle_leave", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RoomHandle>>>::sse_decode(&mut deserializer);
+let api_reason = <Option<String>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::room::RoomHandle::leave(&*api_that_guard, api_reason))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__room__RoomHandle_members_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "RoomHandle_members", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RoomHandle>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, flutter_rust_bridge::DartOpaque>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = crate::api::dart::api::room::RoomHandle::members(&*api_that_guard)?;   Ok(output_ok)
+                })()) })
+}
 fn wire__crate__api__dart__api__room__RoomHandle_mute_audio_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1642,6 +2232,75 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
  let output_ok = crate::api::dart::api::room::RoomHandle::on_local_track(&*api_that_guard, api_cb)?;   Ok(output_ok)
                 })()) })
 }
+fn wire__crate__api__dart__api__room__RoomHandle_on_member_joined_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "RoomHandle_on_member_joined", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RoomHandle>>>::sse_decode(&mut deserializer);
+let api_cb = <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, flutter_rust_bridge::DartOpaque>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = crate::api::dart::api::room::RoomHandle::on_member_joined(&*api_that_guard, api_cb)?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__room__RoomHandle_on_member_left_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "RoomHandle_on_member_left", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RoomHandle>>>::sse_decode(&mut deserializer);
+let api_cb = <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, flutter_rust_bridge::DartOpaque>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = crate::api::dart::api::room::RoomHandle::on_member_left(&*api_that_guard, api_cb)?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__room__RoomHandle_on_member_updated_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "RoomHandle_on_member_updated", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RoomHandle>>>::sse_decode(&mut deserializer);
+let api_cb = <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, flutter_rust_bridge::DartOpaque>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = crate::api::dart::api::room::RoomHandle::on_member_updated(&*api_that_guard, api_cb)?;   Ok(output_ok)
+                })()) })
+}
 fn wire__crate__api__dart__api__room__RoomHandle_on_new_connection_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1662,22 +2321,112 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
             }
         }
         let api_that_guard = api_that_guard.unwrap();
- let output_ok = crate::api::dart::api::room::RoomHandle::on_new_connection(&*api_that_guard, api_cb)?;   Ok(output_ok)
+ let output_ok = crate::api::dart::api::room::RoomHandle::on_new_connection(&*api_that_guard, api_cb)?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__room__RoomHandle_on_signalling_latency_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "RoomHandle_on_signalling_latency", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RoomHandle>>>::sse_decode(&mut deserializer);
+let api_cb = <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, flutter_rust_bridge::DartOpaque>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = crate::api::dart::api::room::RoomHandle::on_signalling_latency(&*api_that_guard, api_cb)?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__room__RoomHandle_on_unknown_event_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "RoomHandle_on_unknown_event", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RoomHandle>>>::sse_decode(&mut deserializer);
+let api_cb = <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, flutter_rust_bridge::DartOpaque>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = crate::api::dart::api::room::RoomHandle::on_unknown_event(&*api_that_guard, api_cb)?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__room__RoomHandle_rpc_transport_kind_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "RoomHandle_rpc_transport_kind", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RoomHandle>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, flutter_rust_bridge::DartOpaque>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = crate::api::dart::api::room::RoomHandle::rpc_transport_kind(&*api_that_guard)?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__room__RoomHandle_set_local_media_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "RoomHandle_set_local_media_settings", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RoomHandle>>>::sse_decode(&mut deserializer);
+let api_settings = <crate::api::dart::api::ApiMediaStreamSettings>::sse_decode(&mut deserializer);
+let api_stop_first = <bool>::sse_decode(&mut deserializer);
+let api_rollback_on_fail = <bool>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
+        for i in decode_indices_ {
+            match i {
+                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                _ => unreachable!(),
+            }
+        }
+        let api_that_guard = api_that_guard.unwrap();
+ let output_ok = Result::<_,()>::Ok(crate::api::dart::api::room::RoomHandle::set_local_media_settings(&*api_that_guard, api_settings, api_stop_first, api_rollback_on_fail))?;   Ok(output_ok)
                 })()) })
 }
-fn wire__crate__api__dart__api__room__RoomHandle_set_local_media_settings_impl(
+fn wire__crate__api__dart__api__room__RoomHandle_signalling_latency_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "RoomHandle_set_local_media_settings", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "RoomHandle_signalling_latency", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
             let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
             let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RoomHandle>>>::sse_decode(&mut deserializer);
-let api_settings = <crate::api::dart::api::ApiMediaStreamSettings>::sse_decode(&mut deserializer);
-let api_stop_first = <bool>::sse_decode(&mut deserializer);
-let api_rollback_on_fail = <bool>::sse_decode(&mut deserializer);deserializer.end();
-                transform_result_sse::<_, ()>((move || {
+            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RoomHandle>>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, flutter_rust_bridge::DartOpaque>((move || {
                     let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
@@ -1687,7 +2436,7 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
             }
         }
         let api_that_guard = api_that_guard.unwrap();
- let output_ok = Result::<_,()>::Ok(crate::api::dart::api::room::RoomHandle::set_local_media_settings(&*api_that_guard, api_settings, api_stop_first, api_rollback_on_fail))?;   Ok(output_ok)
+ let output_ok = crate::api::dart::api::room::RoomHandle::signalling_latency(&*api_that_guard)?;   Ok(output_ok)
                 })()) })
 }
 fn wire__crate__api__dart__api__room__RoomHandle_unmute_audio_impl(
@@ -1736,6 +2485,32 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
  let output_ok = Result::<_,()>::Ok(crate::api::dart::api::room::RoomHandle::unmute_video(&*api_that_guard, api_source_kind))?;   Ok(output_ok)
                 })()) })
 }
+fn wire__crate__api__dart__api__device_capabilities_from_raw_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "device_capabilities_from_raw", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ptr = <usize>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::dart::api::device_capabilities_from_raw(api_ptr))?;   Ok(output_ok)
+                })()) })
+}
+fn wire__crate__api__dart__api__diagnostics__diagnostics_report_from_ptr_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "diagnostics_report_from_ptr", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ptr = <usize>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::dart::api::diagnostics::DiagnosticsReport::from_ptr(api_ptr))?;   Ok(output_ok)
+                })()) })
+}
 fn wire__crate__api__dart__api__log_dart_exception_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1789,6 +2564,19 @@ fn wire__crate__api__dart__api__room_close_reason__room_close_reason_from_ptr_im
                      let output_ok = Result::<_,()>::Ok(crate::api::dart::api::room_close_reason::RoomCloseReason::from_ptr(api_ptr))?;   Ok(output_ok)
                 })()) })
 }
+fn wire__crate__api__dart__api__room_member__room_member_from_ptr_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "room_member_from_ptr", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ptr = <usize>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::dart::api::room_member::RoomMember::from_ptr(api_ptr))?;   Ok(output_ok)
+                })()) })
+}
 fn wire__crate__api__dart__api__set_dart_opaque_message_port_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1815,6 +2603,19 @@ fn wire__crate__api__dart__api__set_log_level_impl(
                      let output_ok = Result::<_,()>::Ok(crate::api::dart::api::set_log_level(api_level))?;   Ok(output_ok)
                 })()) })
 }
+fn wire__crate__api__dart__api__signalling_latency__signalling_latency_from_ptr_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "signalling_latency_from_ptr", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ptr = <usize>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::dart::api::signalling_latency::SignallingLatency::from_ptr(api_ptr))?;   Ok(output_ok)
+                })()) })
+}
 fn wire__crate__api__dart__api__local_media_track__vec_local_tracks_from_raw_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1987,6 +2788,16 @@ impl SseDecode for flutter_rust_bridge::DartOpaque {
     }
 }
 
+impl SseDecode for std::collections::HashMap<String, String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
 impl SseDecode
     for RustOpaqueMoi<
         flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
@@ -2169,6 +2980,47 @@ impl SseDecode for crate::api::dart::api::ApiDeviceAudioTrackConstraints {
     }
 }
 
+impl SseDecode for crate::api::dart::api::ApiDeviceCapabilities {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut var_width =
+            <Option<crate::api::dart::api::ApiIntRange>>::sse_decode(
+                deserializer,
+            );
+        let mut var_height =
+            <Option<crate::api::dart::api::ApiIntRange>>::sse_decode(
+                deserializer,
+            );
+        let mut var_frameRate =
+            <Option<crate::api::dart::api::ApiFloatRange>>::sse_decode(
+                deserializer,
+            );
+        let mut var_facingModes =
+            <Vec<crate::media::constraints::FacingMode>>::sse_decode(
+                deserializer,
+            );
+        let mut var_channelCount =
+            <Option<crate::api::dart::api::ApiIntRange>>::sse_decode(
+                deserializer,
+            );
+        let mut var_echoCancellation = <Vec<bool>>::sse_decode(deserializer);
+        let mut var_autoGainControl = <Vec<bool>>::sse_decode(deserializer);
+        let mut var_noiseSuppression = <Vec<bool>>::sse_decode(deserializer);
+        return crate::api::dart::api::ApiDeviceCapabilities {
+            width: var_width,
+            height: var_height,
+            frame_rate: var_frameRate,
+            facing_modes: var_facingModes,
+            channel_count: var_channelCount,
+            echo_cancellation: var_echoCancellation,
+            auto_gain_control: var_autoGainControl,
+            noise_suppression: var_noiseSuppression,
+        };
+    }
+}
+
 impl SseDecode for crate::api::dart::api::ApiDeviceVideoTrackConstraints {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(
@@ -2186,11 +3038,16 @@ impl SseDecode for crate::api::dart::api::ApiDeviceVideoTrackConstraints {
             <Option<crate::media::constraints::ConstrainU32>>::sse_decode(
                 deserializer,
             );
+        let mut var_frameRate =
+            <Option<crate::media::constraints::ConstrainU32>>::sse_decode(
+                deserializer,
+            );
         return crate::api::dart::api::ApiDeviceVideoTrackConstraints {
             device_id: var_deviceId,
             facing_mode: var_facingMode,
             height: var_height,
             width: var_width,
+            frame_rate: var_frameRate,
         };
     }
 }
@@ -2231,6 +3088,50 @@ impl SseDecode for crate::api::dart::api::ApiDisplayVideoTrackConstraints {
     }
 }
 
+impl SseDecode for crate::api::dart::api::ApiFloatRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut var_min = <f64>::sse_decode(deserializer);
+        let mut var_max = <f64>::sse_decode(deserializer);
+        return crate::api::dart::api::ApiFloatRange {
+            min: var_min,
+            max: var_max,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dart::api::ApiIntRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut var_min = <u32>::sse_decode(deserializer);
+        let mut var_max = <u32>::sse_decode(deserializer);
+        return crate::api::dart::api::ApiIntRange {
+            min: var_min,
+            max: var_max,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dart::api::diagnostics::ApiIceServer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut var_urls = <Vec<String>>::sse_decode(deserializer);
+        let mut var_username = <Option<String>>::sse_decode(deserializer);
+        let mut var_credential = <Option<String>>::sse_decode(deserializer);
+        return crate::api::dart::api::diagnostics::ApiIceServer {
+            urls: var_urls,
+            username: var_username,
+            credential: var_credential,
+        };
+    }
+}
+
 impl SseDecode for crate::api::dart::api::ApiMediaDeviceDetails {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(
@@ -2396,6 +3297,55 @@ impl SseDecode for f64 {
     }
 }
 
+impl SseDecode for crate::api::dart::api::diagnostics::DeviceDiagnostics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut var_deviceId = <String>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::media::MediaKind>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_width = <Option<u32>>::sse_decode(deserializer);
+        let mut var_height = <Option<u32>>::sse_decode(deserializer);
+        let mut var_frameRate = <Option<f64>>::sse_decode(deserializer);
+        let mut var_audioLevel = <Option<i32>>::sse_decode(deserializer);
+        return crate::api::dart::api::diagnostics::DeviceDiagnostics {
+            device_id: var_deviceId,
+            label: var_label,
+            kind: var_kind,
+            error: var_error,
+            width: var_width,
+            height: var_height,
+            frame_rate: var_frameRate,
+            audio_level: var_audioLevel,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dart::api::diagnostics::DiagnosticsReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut var_devices = <Vec<
+            crate::api::dart::api::diagnostics::DeviceDiagnostics,
+        >>::sse_decode(deserializer);
+        let mut var_ice =
+            <crate::api::dart::api::diagnostics::IceDiagnostics>::sse_decode(
+                deserializer,
+            );
+        let mut var_throughput = <Option<
+            crate::api::dart::api::diagnostics::ThroughputDiagnostics,
+        >>::sse_decode(deserializer);
+        return crate::api::dart::api::diagnostics::DiagnosticsReport {
+            devices: var_devices,
+            ice: var_ice,
+            throughput: var_throughput,
+        };
+    }
+}
+
 impl SseDecode for crate::media::constraints::FacingMode {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(
@@ -2439,6 +3389,72 @@ impl SseDecode for isize {
     }
 }
 
+impl SseDecode for crate::api::dart::api::diagnostics::IceDiagnostics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut var_hostCandidates = <u32>::sse_decode(deserializer);
+        let mut var_srflxCandidates = <u32>::sse_decode(deserializer);
+        let mut var_relayCandidates = <u32>::sse_decode(deserializer);
+        let mut var_connected = <bool>::sse_decode(deserializer);
+        let mut var_errors = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::dart::api::diagnostics::IceDiagnostics {
+            host_candidates: var_hostCandidates,
+            srflx_candidates: var_srflxCandidates,
+            relay_candidates: var_relayCandidates,
+            connected: var_connected,
+            errors: var_errors,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dart::api::diagnostics::ApiIceServer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::dart::api::diagnostics::ApiIceServer>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<bool>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<LocalMediaTrack> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(
@@ -2453,7 +3469,73 @@ impl SseDecode for Vec<LocalMediaTrack> {
     }
 }
 
-impl SseDecode for Vec<crate::api::dart::api::ApiMediaDeviceDetails> {
+impl SseDecode for Vec<crate::api::dart::api::ApiMediaDeviceDetails> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::dart::api::ApiMediaDeviceDetails>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dart::api::ApiMediaDisplayDetails> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::dart::api::ApiMediaDisplayDetails>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::media::constraints::FacingMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::media::constraints::FacingMode>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dart::api::diagnostics::DeviceDiagnostics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::dart::api::diagnostics::DeviceDiagnostics>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(
         deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
@@ -2461,17 +3543,13 @@ impl SseDecode for Vec<crate::api::dart::api::ApiMediaDeviceDetails> {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::dart::api::ApiMediaDeviceDetails>::sse_decode(
-                    deserializer,
-                ),
-            );
+            ans_.push(<u8>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::dart::api::ApiMediaDisplayDetails> {
+impl SseDecode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(
         deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
@@ -2479,17 +3557,13 @@ impl SseDecode for Vec<crate::api::dart::api::ApiMediaDisplayDetails> {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::dart::api::ApiMediaDisplayDetails>::sse_decode(
-                    deserializer,
-                ),
-            );
+            ans_.push(<(String, String)>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for Vec<crate::api::dart::api::room_member::RoomMember> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(
         deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
@@ -2497,7 +3571,11 @@ impl SseDecode for Vec<u8> {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+            ans_.push(
+                <crate::api::dart::api::room_member::RoomMember>::sse_decode(
+                    deserializer,
+                ),
+            );
         }
         return ans_;
     }
@@ -2601,6 +3679,22 @@ impl SseDecode
     }
 }
 
+impl SseDecode for crate::media::constraints::MeteringMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::media::constraints::MeteringMode::None,
+            1 => crate::media::constraints::MeteringMode::Manual,
+            2 => crate::media::constraints::MeteringMode::SingleShot,
+            3 => crate::media::constraints::MeteringMode::Continuous,
+            _ => unreachable!("Invalid variant for MeteringMode: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::media::constraints::NoiseSuppressionLevel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(
@@ -2710,6 +3804,36 @@ impl SseDecode
     }
 }
 
+impl SseDecode for Option<crate::api::dart::api::ApiFloatRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::dart::api::ApiFloatRange>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::dart::api::ApiIntRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::dart::api::ApiIntRange>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::media::AudioDeviceKind> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(
@@ -2808,6 +3932,62 @@ impl SseDecode for Option<crate::media::constraints::NoiseSuppressionLevel> {
     }
 }
 
+impl SseDecode
+    for Option<crate::api::dart::api::signalling_latency::SignallingLatency>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::dart::api::signalling_latency::SignallingLatency>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode
+    for Option<crate::api::dart::api::diagnostics::ThroughputDiagnostics>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::dart::api::diagnostics::ThroughputDiagnostics>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(
@@ -2855,6 +4035,32 @@ impl SseDecode for crate::api::shared::PeerConnectionState {
     }
 }
 
+impl SseDecode for crate::media::PermissionKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::media::PermissionKind::Microphone,
+            1 => crate::media::PermissionKind::Camera,
+            2 => crate::media::PermissionKind::Screen,
+            _ => unreachable!("Invalid variant for PermissionKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for (String, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <String>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
 impl SseDecode for crate::api::shared::RoomCloseKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(
@@ -2868,6 +4074,7 @@ impl SseDecode for crate::api::shared::RoomCloseKind {
             3 => crate::api::shared::RoomCloseKind::Idle,
             4 => crate::api::shared::RoomCloseKind::Rejected,
             5 => crate::api::shared::RoomCloseKind::Evicted,
+            6 => crate::api::shared::RoomCloseKind::IncompatibleProtocol,
             _ => unreachable!("Invalid variant for RoomCloseKind: {}", inner),
         };
     }
@@ -2888,6 +4095,59 @@ impl SseDecode for crate::api::dart::api::room_close_reason::RoomCloseReason {
     }
 }
 
+impl SseDecode for crate::api::dart::api::room_member::RoomMember {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_metadata =
+            <std::collections::HashMap<String, String>>::sse_decode(
+                deserializer,
+            );
+        return crate::api::dart::api::room_member::RoomMember {
+            id: var_id,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode
+    for crate::api::dart::api::signalling_latency::SignallingLatency
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut var_rttMs = <f64>::sse_decode(deserializer);
+        let mut var_smoothedRttMs = <f64>::sse_decode(deserializer);
+        let mut var_jitterMs = <f64>::sse_decode(deserializer);
+        let mut var_samples = <u32>::sse_decode(deserializer);
+        return crate::api::dart::api::signalling_latency::SignallingLatency {
+            rtt_ms: var_rttMs,
+            smoothed_rtt_ms: var_smoothedRttMs,
+            jitter_ms: var_jitterMs,
+            samples: var_samples,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dart::api::diagnostics::ThroughputDiagnostics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(
+        deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer,
+    ) -> Self {
+        let mut var_bytesSent = <i64>::sse_decode(deserializer);
+        let mut var_durationMs = <i64>::sse_decode(deserializer);
+        let mut var_bitrateBps = <i64>::sse_decode(deserializer);
+        return crate::api::dart::api::diagnostics::ThroughputDiagnostics {
+            bytes_sent: var_bytesSent,
+            duration_ms: var_durationMs,
+            bitrate_bps: var_bitrateBps,
+        };
+    }
+}
+
 impl SseDecode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(
@@ -2966,79 +4226,116 @@ fn pde_ffi_dispatcher_sync_impl(
 11 => wire__crate__api__dart__api__connection_handle__ConnectionHandle_on_state_change_impl(ptr, rust_vec_len, data_len),
 12 => wire__crate__api__dart__api__jason__Jason_jason_close_room_impl(ptr, rust_vec_len, data_len),
 13 => wire__crate__api__dart__api__jason__Jason_jason_dispose_impl(ptr, rust_vec_len, data_len),
-14 => wire__crate__api__dart__api__jason__Jason_jason_init_room_impl(ptr, rust_vec_len, data_len),
-15 => wire__crate__api__dart__api__jason__Jason_jason_media_manager_impl(ptr, rust_vec_len, data_len),
-16 => wire__crate__api__dart__api__jason__Jason_jason_network_changed_impl(ptr, rust_vec_len, data_len),
-17 => wire__crate__api__dart__api__jason__Jason_new_impl(ptr, rust_vec_len, data_len),
-18 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_free_impl(ptr, rust_vec_len, data_len),
-19 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_from_ptr_impl(ptr, rust_vec_len, data_len),
-20 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_get_noise_suppression_level_impl(ptr, rust_vec_len, data_len),
-21 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_get_track_impl(ptr, rust_vec_len, data_len),
-22 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_audio_processing_available_impl(ptr, rust_vec_len, data_len),
-23 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_auto_gain_control_enabled_impl(ptr, rust_vec_len, data_len),
-24 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_echo_cancellation_enabled_impl(ptr, rust_vec_len, data_len),
-25 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_high_pass_filter_enabled_impl(ptr, rust_vec_len, data_len),
-26 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_noise_suppression_enabled_impl(ptr, rust_vec_len, data_len),
-27 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_on_audio_level_available_impl(ptr, rust_vec_len, data_len),
-28 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_kind_impl(ptr, rust_vec_len, data_len),
-29 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_media_source_kind_impl(ptr, rust_vec_len, data_len),
-30 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_on_audio_level_changed_impl(ptr, rust_vec_len, data_len),
-31 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_on_ended_impl(ptr, rust_vec_len, data_len),
-32 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_auto_gain_control_enabled_impl(ptr, rust_vec_len, data_len),
-33 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_echo_cancellation_enabled_impl(ptr, rust_vec_len, data_len),
-34 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_high_pass_filter_enabled_impl(ptr, rust_vec_len, data_len),
-35 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_noise_suppression_enabled_impl(ptr, rust_vec_len, data_len),
-36 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_noise_suppression_level_impl(ptr, rust_vec_len, data_len),
-37 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_state_impl(ptr, rust_vec_len, data_len),
-38 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_enumerate_devices_impl(ptr, rust_vec_len, data_len),
-39 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_enumerate_displays_impl(ptr, rust_vec_len, data_len),
-40 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_init_local_tracks_impl(ptr, rust_vec_len, data_len),
-41 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_microphone_volume_impl(ptr, rust_vec_len, data_len),
-42 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_microphone_volume_is_available_impl(ptr, rust_vec_len, data_len),
-43 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_on_device_change_impl(ptr, rust_vec_len, data_len),
-44 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_set_microphone_volume_impl(ptr, rust_vec_len, data_len),
-45 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_set_output_audio_id_impl(ptr, rust_vec_len, data_len),
-46 => wire__crate__api__dart__api__reconnect_handle__ReconnectHandle_from_ptr_impl(ptr, rust_vec_len, data_len),
-47 => wire__crate__api__dart__api__reconnect_handle__ReconnectHandle_reconnect_with_backoff_impl(ptr, rust_vec_len, data_len),
-48 => wire__crate__api__dart__api__reconnect_handle__ReconnectHandle_reconnect_with_delay_impl(ptr, rust_vec_len, data_len),
-49 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_from_ptr_impl(ptr, rust_vec_len, data_len),
-50 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_get_track_impl(ptr, rust_vec_len, data_len),
-51 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_kind_impl(ptr, rust_vec_len, data_len),
-52 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_media_direction_impl(ptr, rust_vec_len, data_len),
-53 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_media_source_kind_impl(ptr, rust_vec_len, data_len),
-54 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_muted_impl(ptr, rust_vec_len, data_len),
-55 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_on_media_direction_changed_impl(ptr, rust_vec_len, data_len),
-56 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_on_muted_impl(ptr, rust_vec_len, data_len),
-57 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_on_stopped_impl(ptr, rust_vec_len, data_len),
-58 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_on_unmuted_impl(ptr, rust_vec_len, data_len),
-59 => wire__crate__api__dart__api__room__RoomHandle_disable_audio_impl(ptr, rust_vec_len, data_len),
-60 => wire__crate__api__dart__api__room__RoomHandle_disable_remote_audio_impl(ptr, rust_vec_len, data_len),
-61 => wire__crate__api__dart__api__room__RoomHandle_disable_remote_video_impl(ptr, rust_vec_len, data_len),
-62 => wire__crate__api__dart__api__room__RoomHandle_disable_video_impl(ptr, rust_vec_len, data_len),
-63 => wire__crate__api__dart__api__room__RoomHandle_enable_audio_impl(ptr, rust_vec_len, data_len),
-64 => wire__crate__api__dart__api__room__RoomHandle_enable_remote_audio_impl(ptr, rust_vec_len, data_len),
-65 => wire__crate__api__dart__api__room__RoomHandle_enable_remote_video_impl(ptr, rust_vec_len, data_len),
-66 => wire__crate__api__dart__api__room__RoomHandle_enable_video_impl(ptr, rust_vec_len, data_len),
-67 => wire__crate__api__dart__api__room__RoomHandle_join_impl(ptr, rust_vec_len, data_len),
-68 => wire__crate__api__dart__api__room__RoomHandle_mute_audio_impl(ptr, rust_vec_len, data_len),
-69 => wire__crate__api__dart__api__room__RoomHandle_mute_video_impl(ptr, rust_vec_len, data_len),
-70 => wire__crate__api__dart__api__room__RoomHandle_on_close_impl(ptr, rust_vec_len, data_len),
-71 => wire__crate__api__dart__api__room__RoomHandle_on_connection_loss_impl(ptr, rust_vec_len, data_len),
-72 => wire__crate__api__dart__api__room__RoomHandle_on_failed_local_media_impl(ptr, rust_vec_len, data_len),
-73 => wire__crate__api__dart__api__room__RoomHandle_on_local_track_impl(ptr, rust_vec_len, data_len),
-74 => wire__crate__api__dart__api__room__RoomHandle_on_new_connection_impl(ptr, rust_vec_len, data_len),
-75 => wire__crate__api__dart__api__room__RoomHandle_set_local_media_settings_impl(ptr, rust_vec_len, data_len),
-76 => wire__crate__api__dart__api__room__RoomHandle_unmute_audio_impl(ptr, rust_vec_len, data_len),
-77 => wire__crate__api__dart__api__room__RoomHandle_unmute_video_impl(ptr, rust_vec_len, data_len),
-79 => wire__crate__api__dart__api__log_dart_exception_impl(ptr, rust_vec_len, data_len),
-80 => wire__crate__api__dart__api__member_connection_state__member_connection_state_from_ptr_impl(ptr, rust_vec_len, data_len),
-81 => wire__crate__api__dart__api__on_panic_impl(ptr, rust_vec_len, data_len),
-82 => wire__crate__api__dart__api__room_close_reason__room_close_reason_from_ptr_impl(ptr, rust_vec_len, data_len),
-83 => wire__crate__api__dart__api__set_dart_opaque_message_port_impl(ptr, rust_vec_len, data_len),
-84 => wire__crate__api__dart__api__set_log_level_impl(ptr, rust_vec_len, data_len),
-85 => wire__crate__api__dart__api__local_media_track__vec_local_tracks_from_raw_impl(ptr, rust_vec_len, data_len),
-86 => wire__crate__api__dart__api__vec_media_device_details_from_raw_impl(ptr, rust_vec_len, data_len),
-87 => wire__crate__api__dart__api__vec_media_display_details_from_raw_impl(ptr, rust_vec_len, data_len),
+14 => wire__crate__api__dart__api__jason__Jason_jason_enable_rpc_recording_impl(ptr, rust_vec_len, data_len),
+15 => wire__crate__api__dart__api__jason__Jason_jason_enable_sse_fallback_impl(ptr, rust_vec_len, data_len),
+16 => wire__crate__api__dart__api__jason__Jason_jason_export_rpc_recording_impl(ptr, rust_vec_len, data_len),
+17 => wire__crate__api__dart__api__jason__Jason_jason_init_room_impl(ptr, rust_vec_len, data_len),
+18 => wire__crate__api__dart__api__jason__Jason_jason_media_manager_impl(ptr, rust_vec_len, data_len),
+19 => wire__crate__api__dart__api__jason__Jason_jason_network_changed_impl(ptr, rust_vec_len, data_len),
+20 => wire__crate__api__dart__api__jason__Jason_jason_on_network_state_change_impl(ptr, rust_vec_len, data_len),
+21 => wire__crate__api__dart__api__jason__Jason_jason_set_network_online_impl(ptr, rust_vec_len, data_len),
+22 => wire__crate__api__dart__api__jason__Jason_jason_share_rpc_connection_impl(ptr, rust_vec_len, data_len),
+23 => wire__crate__api__dart__api__jason__Jason_new_impl(ptr, rust_vec_len, data_len),
+24 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_apply_constraints_impl(ptr, rust_vec_len, data_len),
+25 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_free_impl(ptr, rust_vec_len, data_len),
+26 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_from_ptr_impl(ptr, rust_vec_len, data_len),
+27 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_get_noise_suppression_level_impl(ptr, rust_vec_len, data_len),
+28 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_get_track_impl(ptr, rust_vec_len, data_len),
+29 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_audio_processing_available_impl(ptr, rust_vec_len, data_len),
+30 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_auto_gain_control_enabled_impl(ptr, rust_vec_len, data_len),
+31 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_echo_cancellation_enabled_impl(ptr, rust_vec_len, data_len),
+32 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_exposure_compensation_available_impl(ptr, rust_vec_len, data_len),
+33 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_focus_distance_available_impl(ptr, rust_vec_len, data_len),
+34 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_focus_mode_available_impl(ptr, rust_vec_len, data_len),
+35 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_high_pass_filter_enabled_impl(ptr, rust_vec_len, data_len),
+36 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_noise_suppression_enabled_impl(ptr, rust_vec_len, data_len),
+37 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_on_audio_level_available_impl(ptr, rust_vec_len, data_len),
+38 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_torch_available_impl(ptr, rust_vec_len, data_len),
+39 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_white_balance_mode_available_impl(ptr, rust_vec_len, data_len),
+40 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_is_zoom_available_impl(ptr, rust_vec_len, data_len),
+41 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_kind_impl(ptr, rust_vec_len, data_len),
+42 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_media_source_kind_impl(ptr, rust_vec_len, data_len),
+43 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_on_audio_level_changed_impl(ptr, rust_vec_len, data_len),
+44 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_on_ended_impl(ptr, rust_vec_len, data_len),
+45 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_auto_gain_control_enabled_impl(ptr, rust_vec_len, data_len),
+46 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_echo_cancellation_enabled_impl(ptr, rust_vec_len, data_len),
+47 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_exposure_compensation_impl(ptr, rust_vec_len, data_len),
+48 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_focus_distance_impl(ptr, rust_vec_len, data_len),
+49 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_focus_mode_impl(ptr, rust_vec_len, data_len),
+50 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_high_pass_filter_enabled_impl(ptr, rust_vec_len, data_len),
+51 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_noise_suppression_enabled_impl(ptr, rust_vec_len, data_len),
+52 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_noise_suppression_level_impl(ptr, rust_vec_len, data_len),
+53 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_torch_enabled_impl(ptr, rust_vec_len, data_len),
+54 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_white_balance_mode_impl(ptr, rust_vec_len, data_len),
+55 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_set_zoom_impl(ptr, rust_vec_len, data_len),
+56 => wire__crate__api__dart__api__local_media_track__LocalMediaTrack_state_impl(ptr, rust_vec_len, data_len),
+57 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_device_capabilities_impl(ptr, rust_vec_len, data_len),
+58 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_enumerate_devices_impl(ptr, rust_vec_len, data_len),
+59 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_enumerate_displays_impl(ptr, rust_vec_len, data_len),
+60 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_init_local_tracks_impl(ptr, rust_vec_len, data_len),
+61 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_microphone_volume_impl(ptr, rust_vec_len, data_len),
+62 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_microphone_volume_is_available_impl(ptr, rust_vec_len, data_len),
+63 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_on_device_change_impl(ptr, rust_vec_len, data_len),
+64 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_on_permission_change_impl(ptr, rust_vec_len, data_len),
+65 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_permission_state_impl(ptr, rust_vec_len, data_len),
+66 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_run_diagnostics_impl(ptr, rust_vec_len, data_len),
+67 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_set_microphone_volume_impl(ptr, rust_vec_len, data_len),
+68 => wire__crate__api__dart__api__media_manager__MediaManagerHandle_set_output_audio_id_impl(ptr, rust_vec_len, data_len),
+69 => wire__crate__api__dart__api__reconnect_handle__ReconnectHandle_from_ptr_impl(ptr, rust_vec_len, data_len),
+70 => wire__crate__api__dart__api__reconnect_handle__ReconnectHandle_reconnect_with_backoff_impl(ptr, rust_vec_len, data_len),
+71 => wire__crate__api__dart__api__reconnect_handle__ReconnectHandle_reconnect_with_delay_impl(ptr, rust_vec_len, data_len),
+72 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_from_ptr_impl(ptr, rust_vec_len, data_len),
+73 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_get_track_impl(ptr, rust_vec_len, data_len),
+74 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_kind_impl(ptr, rust_vec_len, data_len),
+75 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_media_direction_impl(ptr, rust_vec_len, data_len),
+76 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_media_source_kind_impl(ptr, rust_vec_len, data_len),
+77 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_muted_impl(ptr, rust_vec_len, data_len),
+78 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_on_media_direction_changed_impl(ptr, rust_vec_len, data_len),
+79 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_on_muted_impl(ptr, rust_vec_len, data_len),
+80 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_on_stopped_impl(ptr, rust_vec_len, data_len),
+81 => wire__crate__api__dart__api__remote_media_track__RemoteMediaTrack_on_unmuted_impl(ptr, rust_vec_len, data_len),
+82 => wire__crate__api__dart__api__room__RoomHandle_disable_audio_impl(ptr, rust_vec_len, data_len),
+83 => wire__crate__api__dart__api__room__RoomHandle_disable_remote_audio_impl(ptr, rust_vec_len, data_len),
+84 => wire__crate__api__dart__api__room__RoomHandle_disable_remote_video_impl(ptr, rust_vec_len, data_len),
+85 => wire__crate__api__dart__api__room__RoomHandle_disable_video_impl(ptr, rust_vec_len, data_len),
+86 => wire__crate__api__dart__api__room__RoomHandle_enable_audio_impl(ptr, rust_vec_len, data_len),
+87 => wire__crate__api__dart__api__room__RoomHandle_enable_remote_audio_impl(ptr, rust_vec_len, data_len),
+88 => wire__crate__api__dart__api__room__RoomHandle_enable_remote_video_impl(ptr, rust_vec_len, data_len),
+89 => wire__crate__api__dart__api__room__RoomHandle_enable_video_impl(ptr, rust_vec_len, data_len),
+90 => wire__crate__api__dart__api__room__RoomHandle_join_impl(ptr, rust_vec_len, data_len),
+91 => wire__crate__api__dart__api__room__RoomHandle_join_with_credential_impl(ptr, rust_vec_len, data_len),
+92 => wire__crate__api__dart__api__room__RoomHandle_leave_impl(ptr, rust_vec_len, data_len),
+93 => wire__crate__api__dart__api__room__RoomHandle_members_impl(ptr, rust_vec_len, data_len),
+94 => wire__crate__api__dart__api__room__RoomHandle_mute_audio_impl(ptr, rust_vec_len, data_len),
+95 => wire__crate__api__dart__api__room__RoomHandle_mute_video_impl(ptr, rust_vec_len, data_len),
+96 => wire__crate__api__dart__api__room__RoomHandle_on_close_impl(ptr, rust_vec_len, data_len),
+97 => wire__crate__api__dart__api__room__RoomHandle_on_connection_loss_impl(ptr, rust_vec_len, data_len),
+98 => wire__crate__api__dart__api__room__RoomHandle_on_failed_local_media_impl(ptr, rust_vec_len, data_len),
+99 => wire__crate__api__dart__api__room__RoomHandle_on_local_track_impl(ptr, rust_vec_len, data_len),
+100 => wire__crate__api__dart__api__room__RoomHandle_on_member_joined_impl(ptr, rust_vec_len, data_len),
+101 => wire__crate__api__dart__api__room__RoomHandle_on_member_left_impl(ptr, rust_vec_len, data_len),
+102 => wire__crate__api__dart__api__room__RoomHandle_on_member_updated_impl(ptr, rust_vec_len, data_len),
+103 => wire__crate__api__dart__api__room__RoomHandle_on_new_connection_impl(ptr, rust_vec_len, data_len),
+104 => wire__crate__api__dart__api__room__RoomHandle_on_signalling_latency_impl(ptr, rust_vec_len, data_len),
+105 => wire__crate__api__dart__api__room__RoomHandle_on_unknown_event_impl(ptr, rust_vec_len, data_len),
+106 => wire__crate__api__dart__api__room__RoomHandle_rpc_transport_kind_impl(ptr, rust_vec_len, data_len),
+107 => wire__crate__api__dart__api__room__RoomHandle_set_local_media_settings_impl(ptr, rust_vec_len, data_len),
+108 => wire__crate__api__dart__api__room__RoomHandle_signalling_latency_impl(ptr, rust_vec_len, data_len),
+109 => wire__crate__api__dart__api__room__RoomHandle_unmute_audio_impl(ptr, rust_vec_len, data_len),
+110 => wire__crate__api__dart__api__room__RoomHandle_unmute_video_impl(ptr, rust_vec_len, data_len),
+112 => wire__crate__api__dart__api__device_capabilities_from_raw_impl(ptr, rust_vec_len, data_len),
+113 => wire__crate__api__dart__api__diagnostics__diagnostics_report_from_ptr_impl(ptr, rust_vec_len, data_len),
+114 => wire__crate__api__dart__api__log_dart_exception_impl(ptr, rust_vec_len, data_len),
+115 => wire__crate__api__dart__api__member_connection_state__member_connection_state_from_ptr_impl(ptr, rust_vec_len, data_len),
+116 => wire__crate__api__dart__api__on_panic_impl(ptr, rust_vec_len, data_len),
+117 => wire__crate__api__dart__api__room_close_reason__room_close_reason_from_ptr_impl(ptr, rust_vec_len, data_len),
+118 => wire__crate__api__dart__api__room_member__room_member_from_ptr_impl(ptr, rust_vec_len, data_len),
+119 => wire__crate__api__dart__api__set_dart_opaque_message_port_impl(ptr, rust_vec_len, data_len),
+120 => wire__crate__api__dart__api__set_log_level_impl(ptr, rust_vec_len, data_len),
+121 => wire__crate__api__dart__api__signalling_latency__signalling_latency_from_ptr_impl(ptr, rust_vec_len, data_len),
+122 => wire__crate__api__dart__api__local_media_track__vec_local_tracks_from_raw_impl(ptr, rust_vec_len, data_len),
+123 => wire__crate__api__dart__api__vec_media_device_details_from_raw_impl(ptr, rust_vec_len, data_len),
+124 => wire__crate__api__dart__api__vec_media_display_details_from_raw_impl(ptr, rust_vec_len, data_len),
                         _ => unreachable!(),
                     }
 }
@@ -3265,86 +4562,192 @@ impl
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart
-    for crate::api::dart::api::ApiDeviceVideoTrackConstraints
-{
+impl flutter_rust_bridge::IntoDart
+    for crate::api::dart::api::ApiDeviceCapabilities
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.frame_rate.into_into_dart().into_dart(),
+            self.facing_modes.into_into_dart().into_dart(),
+            self.channel_count.into_into_dart().into_dart(),
+            self.echo_cancellation.into_into_dart().into_dart(),
+            self.auto_gain_control.into_into_dart().into_dart(),
+            self.noise_suppression.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dart::api::ApiDeviceCapabilities
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::dart::api::ApiDeviceCapabilities,
+    > for crate::api::dart::api::ApiDeviceCapabilities
+{
+    fn into_into_dart(self) -> crate::api::dart::api::ApiDeviceCapabilities {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for crate::api::dart::api::ApiDeviceVideoTrackConstraints
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.device_id.into_into_dart().into_dart(),
+            self.facing_mode.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.frame_rate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dart::api::ApiDeviceVideoTrackConstraints
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::dart::api::ApiDeviceVideoTrackConstraints,
+    > for crate::api::dart::api::ApiDeviceVideoTrackConstraints
+{
+    fn into_into_dart(
+        self,
+    ) -> crate::api::dart::api::ApiDeviceVideoTrackConstraints {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for crate::api::dart::api::ApiDisplayAudioTrackConstraints
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        Vec::<u8>::new().into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dart::api::ApiDisplayAudioTrackConstraints
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::dart::api::ApiDisplayAudioTrackConstraints,
+    > for crate::api::dart::api::ApiDisplayAudioTrackConstraints
+{
+    fn into_into_dart(
+        self,
+    ) -> crate::api::dart::api::ApiDisplayAudioTrackConstraints {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for crate::api::dart::api::ApiDisplayVideoTrackConstraints
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.device_id.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.frame_rate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dart::api::ApiDisplayVideoTrackConstraints
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::dart::api::ApiDisplayVideoTrackConstraints,
+    > for crate::api::dart::api::ApiDisplayVideoTrackConstraints
+{
+    fn into_into_dart(
+        self,
+    ) -> crate::api::dart::api::ApiDisplayVideoTrackConstraints {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dart::api::ApiFloatRange {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.device_id.into_into_dart().into_dart(),
-            self.facing_mode.into_into_dart().into_dart(),
-            self.height.into_into_dart().into_dart(),
-            self.width.into_into_dart().into_dart(),
+            self.min.into_into_dart().into_dart(),
+            self.max.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::dart::api::ApiDeviceVideoTrackConstraints
+    for crate::api::dart::api::ApiFloatRange
 {
 }
-impl
-    flutter_rust_bridge::IntoIntoDart<
-        crate::api::dart::api::ApiDeviceVideoTrackConstraints,
-    > for crate::api::dart::api::ApiDeviceVideoTrackConstraints
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dart::api::ApiFloatRange>
+    for crate::api::dart::api::ApiFloatRange
 {
-    fn into_into_dart(
-        self,
-    ) -> crate::api::dart::api::ApiDeviceVideoTrackConstraints {
+    fn into_into_dart(self) -> crate::api::dart::api::ApiFloatRange {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart
-    for crate::api::dart::api::ApiDisplayAudioTrackConstraints
+    for crate::api::dart::api::diagnostics::ApiIceServer
 {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        Vec::<u8>::new().into_dart()
+        [
+            self.urls.into_into_dart().into_dart(),
+            self.username.into_into_dart().into_dart(),
+            self.credential.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::dart::api::ApiDisplayAudioTrackConstraints
+    for crate::api::dart::api::diagnostics::ApiIceServer
 {
 }
 impl
     flutter_rust_bridge::IntoIntoDart<
-        crate::api::dart::api::ApiDisplayAudioTrackConstraints,
-    > for crate::api::dart::api::ApiDisplayAudioTrackConstraints
+        crate::api::dart::api::diagnostics::ApiIceServer,
+    > for crate::api::dart::api::diagnostics::ApiIceServer
 {
     fn into_into_dart(
         self,
-    ) -> crate::api::dart::api::ApiDisplayAudioTrackConstraints {
+    ) -> crate::api::dart::api::diagnostics::ApiIceServer {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart
-    for crate::api::dart::api::ApiDisplayVideoTrackConstraints
-{
+impl flutter_rust_bridge::IntoDart for crate::api::dart::api::ApiIntRange {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.device_id.into_into_dart().into_dart(),
-            self.height.into_into_dart().into_dart(),
-            self.width.into_into_dart().into_dart(),
-            self.frame_rate.into_into_dart().into_dart(),
+            self.min.into_into_dart().into_dart(),
+            self.max.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::dart::api::ApiDisplayVideoTrackConstraints
+    for crate::api::dart::api::ApiIntRange
 {
 }
-impl
-    flutter_rust_bridge::IntoIntoDart<
-        crate::api::dart::api::ApiDisplayVideoTrackConstraints,
-    > for crate::api::dart::api::ApiDisplayVideoTrackConstraints
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dart::api::ApiIntRange>
+    for crate::api::dart::api::ApiIntRange
 {
-    fn into_into_dart(
-        self,
-    ) -> crate::api::dart::api::ApiDisplayVideoTrackConstraints {
+    fn into_into_dart(self) -> crate::api::dart::api::ApiIntRange {
         self
     }
 }
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart
     for crate::api::dart::api::ApiMediaDeviceDetails
@@ -3519,6 +4922,69 @@ impl flutter_rust_bridge::IntoIntoDart<crate::media::constraints::ConstrainU32>
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for crate::api::dart::api::diagnostics::DeviceDiagnostics
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.device_id.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.frame_rate.into_into_dart().into_dart(),
+            self.audio_level.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dart::api::diagnostics::DeviceDiagnostics
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::dart::api::diagnostics::DeviceDiagnostics,
+    > for crate::api::dart::api::diagnostics::DeviceDiagnostics
+{
+    fn into_into_dart(
+        self,
+    ) -> crate::api::dart::api::diagnostics::DeviceDiagnostics {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for crate::api::dart::api::diagnostics::DiagnosticsReport
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.devices.into_into_dart().into_dart(),
+            self.ice.into_into_dart().into_dart(),
+            self.throughput.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dart::api::diagnostics::DiagnosticsReport
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::dart::api::diagnostics::DiagnosticsReport,
+    > for crate::api::dart::api::diagnostics::DiagnosticsReport
+{
+    fn into_into_dart(
+        self,
+    ) -> crate::api::dart::api::diagnostics::DiagnosticsReport {
+        self
+    }
+}
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::media::constraints::FacingMode {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -3542,6 +5008,37 @@ impl flutter_rust_bridge::IntoIntoDart<crate::media::constraints::FacingMode>
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for crate::api::dart::api::diagnostics::IceDiagnostics
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.host_candidates.into_into_dart().into_dart(),
+            self.srflx_candidates.into_into_dart().into_dart(),
+            self.relay_candidates.into_into_dart().into_dart(),
+            self.connected.into_into_dart().into_dart(),
+            self.errors.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dart::api::diagnostics::IceDiagnostics
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::dart::api::diagnostics::IceDiagnostics,
+    > for crate::api::dart::api::diagnostics::IceDiagnostics
+{
+    fn into_into_dart(
+        self,
+    ) -> crate::api::dart::api::diagnostics::IceDiagnostics {
+        self
+    }
+}
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::shared::LogLevel {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -3683,6 +5180,30 @@ impl
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::media::constraints::MeteringMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::None => 0.into_dart(),
+            Self::Manual => 1.into_dart(),
+            Self::SingleShot => 2.into_dart(),
+            Self::Continuous => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::media::constraints::MeteringMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::media::constraints::MeteringMode>
+    for crate::media::constraints::MeteringMode
+{
+    fn into_into_dart(self) -> crate::media::constraints::MeteringMode {
+        self
+    }
+}
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart
     for crate::media::constraints::NoiseSuppressionLevel
@@ -3737,6 +5258,29 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::shared::PeerConnectionState>
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::media::PermissionKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Microphone => 0.into_dart(),
+            Self::Camera => 1.into_dart(),
+            Self::Screen => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::media::PermissionKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::media::PermissionKind>
+    for crate::media::PermissionKind
+{
+    fn into_into_dart(self) -> crate::media::PermissionKind {
+        self
+    }
+}
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::shared::RoomCloseKind {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -3747,6 +5291,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::shared::RoomCloseKind {
             Self::Idle => 3.into_dart(),
             Self::Rejected => 4.into_dart(),
             Self::Evicted => 5.into_dart(),
+            Self::IncompatibleProtocol => 6.into_dart(),
             _ => unreachable!(),
         }
     }
@@ -3790,6 +5335,91 @@ impl
     }
 }
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for crate::api::dart::api::room_member::RoomMember
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dart::api::room_member::RoomMember
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::dart::api::room_member::RoomMember,
+    > for crate::api::dart::api::room_member::RoomMember
+{
+    fn into_into_dart(self) -> crate::api::dart::api::room_member::RoomMember {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for crate::api::dart::api::signalling_latency::SignallingLatency
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rtt_ms.into_into_dart().into_dart(),
+            self.smoothed_rtt_ms.into_into_dart().into_dart(),
+            self.jitter_ms.into_into_dart().into_dart(),
+            self.samples.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dart::api::signalling_latency::SignallingLatency
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::dart::api::signalling_latency::SignallingLatency,
+    > for crate::api::dart::api::signalling_latency::SignallingLatency
+{
+    fn into_into_dart(
+        self,
+    ) -> crate::api::dart::api::signalling_latency::SignallingLatency {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for crate::api::dart::api::diagnostics::ThroughputDiagnostics
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.bytes_sent.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.bitrate_bps.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dart::api::diagnostics::ThroughputDiagnostics
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::dart::api::diagnostics::ThroughputDiagnostics,
+    > for crate::api::dart::api::diagnostics::ThroughputDiagnostics
+{
+    fn into_into_dart(
+        self,
+    ) -> crate::api::dart::api::diagnostics::ThroughputDiagnostics {
+        self
+    }
+}
+
 impl SseEncode for ConnectionHandle {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(
@@ -3936,6 +5566,19 @@ impl SseEncode for flutter_rust_bridge::DartOpaque {
     }
 }
 
+impl SseEncode for std::collections::HashMap<String, String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <Vec<(String, String)>>::sse_encode(
+            self.into_iter().collect(),
+            serializer,
+        );
+    }
+}
+
 impl SseEncode
     for RustOpaqueMoi<
         flutter_rust_bridge::for_generated::RustAutoOpaqueInner<
@@ -4124,6 +5767,37 @@ impl SseEncode for crate::api::dart::api::ApiDeviceAudioTrackConstraints {
     }
 }
 
+impl SseEncode for crate::api::dart::api::ApiDeviceCapabilities {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <Option<crate::api::dart::api::ApiIntRange>>::sse_encode(
+            self.width, serializer,
+        );
+        <Option<crate::api::dart::api::ApiIntRange>>::sse_encode(
+            self.height,
+            serializer,
+        );
+        <Option<crate::api::dart::api::ApiFloatRange>>::sse_encode(
+            self.frame_rate,
+            serializer,
+        );
+        <Vec<crate::media::constraints::FacingMode>>::sse_encode(
+            self.facing_modes,
+            serializer,
+        );
+        <Option<crate::api::dart::api::ApiIntRange>>::sse_encode(
+            self.channel_count,
+            serializer,
+        );
+        <Vec<bool>>::sse_encode(self.echo_cancellation, serializer);
+        <Vec<bool>>::sse_encode(self.auto_gain_control, serializer);
+        <Vec<bool>>::sse_encode(self.noise_suppression, serializer);
+    }
+}
+
 impl SseEncode for crate::api::dart::api::ApiDeviceVideoTrackConstraints {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(
@@ -4142,6 +5816,10 @@ impl SseEncode for crate::api::dart::api::ApiDeviceVideoTrackConstraints {
         <Option<crate::media::constraints::ConstrainU32>>::sse_encode(
             self.width, serializer,
         );
+        <Option<crate::media::constraints::ConstrainU32>>::sse_encode(
+            self.frame_rate,
+            serializer,
+        );
     }
 }
 
@@ -4175,6 +5853,40 @@ impl SseEncode for crate::api::dart::api::ApiDisplayVideoTrackConstraints {
     }
 }
 
+impl SseEncode for crate::api::dart::api::ApiFloatRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <f64>::sse_encode(self.min, serializer);
+        <f64>::sse_encode(self.max, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dart::api::ApiIntRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <u32>::sse_encode(self.min, serializer);
+        <u32>::sse_encode(self.max, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dart::api::diagnostics::ApiIceServer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <Vec<String>>::sse_encode(self.urls, serializer);
+        <Option<String>>::sse_encode(self.username, serializer);
+        <Option<String>>::sse_encode(self.credential, serializer);
+    }
+}
+
 impl SseEncode for crate::api::dart::api::ApiMediaDeviceDetails {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(
@@ -4313,6 +6025,37 @@ impl SseEncode for f64 {
     }
 }
 
+impl SseEncode for crate::api::dart::api::diagnostics::DeviceDiagnostics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <String>::sse_encode(self.device_id, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <crate::media::MediaKind>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+        <Option<u32>>::sse_encode(self.width, serializer);
+        <Option<u32>>::sse_encode(self.height, serializer);
+        <Option<f64>>::sse_encode(self.frame_rate, serializer);
+        <Option<i32>>::sse_encode(self.audio_level, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dart::api::diagnostics::DiagnosticsReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <Vec<crate::api::dart::api::diagnostics::DeviceDiagnostics>>::sse_encode(self.devices, serializer);
+        <crate::api::dart::api::diagnostics::IceDiagnostics>::sse_encode(
+            self.ice, serializer,
+        );
+        <Option<crate::api::dart::api::diagnostics::ThroughputDiagnostics>>::sse_encode(self.throughput, serializer);
+    }
+}
+
 impl SseEncode for crate::media::constraints::FacingMode {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(
@@ -4364,6 +6107,61 @@ impl SseEncode for isize {
     }
 }
 
+impl SseEncode for crate::api::dart::api::diagnostics::IceDiagnostics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <u32>::sse_encode(self.host_candidates, serializer);
+        <u32>::sse_encode(self.srflx_candidates, serializer);
+        <u32>::sse_encode(self.relay_candidates, serializer);
+        <bool>::sse_encode(self.connected, serializer);
+        <Vec<String>>::sse_encode(self.errors, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::dart::api::diagnostics::ApiIceServer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dart::api::diagnostics::ApiIceServer>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <bool>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<LocalMediaTrack> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(
@@ -4407,6 +6205,36 @@ impl SseEncode for Vec<crate::api::dart::api::ApiMediaDisplayDetails> {
     }
 }
 
+impl SseEncode for Vec<crate::media::constraints::FacingMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::media::constraints::FacingMode>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::dart::api::diagnostics::DeviceDiagnostics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dart::api::diagnostics::DeviceDiagnostics>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(
@@ -4420,6 +6248,34 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for Vec<(String, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, String)>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::dart::api::room_member::RoomMember> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dart::api::room_member::RoomMember>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
 impl SseEncode for crate::api::shared::LogLevel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(
@@ -4534,6 +6390,27 @@ impl SseEncode
     }
 }
 
+impl SseEncode for crate::media::constraints::MeteringMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <i32>::sse_encode(
+            match self {
+                crate::media::constraints::MeteringMode::None => 0,
+                crate::media::constraints::MeteringMode::Manual => 1,
+                crate::media::constraints::MeteringMode::SingleShot => 2,
+                crate::media::constraints::MeteringMode::Continuous => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::media::constraints::NoiseSuppressionLevel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(
@@ -4647,6 +6524,34 @@ impl SseEncode
     }
 }
 
+impl SseEncode for Option<crate::api::dart::api::ApiFloatRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::dart::api::ApiFloatRange>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::dart::api::ApiIntRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::dart::api::ApiIntRange>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::media::AudioDeviceKind> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(
@@ -4737,6 +6642,62 @@ impl SseEncode for Option<crate::media::constraints::NoiseSuppressionLevel> {
     }
 }
 
+impl SseEncode
+    for Option<crate::api::dart::api::signalling_latency::SignallingLatency>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::dart::api::signalling_latency::SignallingLatency>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode
+    for Option<crate::api::dart::api::diagnostics::ThroughputDiagnostics>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::dart::api::diagnostics::ThroughputDiagnostics>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(
@@ -4786,6 +6747,37 @@ impl SseEncode for crate::api::shared::PeerConnectionState {
     }
 }
 
+impl SseEncode for crate::media::PermissionKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <i32>::sse_encode(
+            match self {
+                crate::media::PermissionKind::Microphone => 0,
+                crate::media::PermissionKind::Camera => 1,
+                crate::media::PermissionKind::Screen => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for (String, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <String>::sse_encode(self.0, serializer);
+        <String>::sse_encode(self.1, serializer);
+    }
+}
+
 impl SseEncode for crate::api::shared::RoomCloseKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(
@@ -4800,6 +6792,7 @@ impl SseEncode for crate::api::shared::RoomCloseKind {
                 crate::api::shared::RoomCloseKind::Idle => 3,
                 crate::api::shared::RoomCloseKind::Rejected => 4,
                 crate::api::shared::RoomCloseKind::Evicted => 5,
+                crate::api::shared::RoomCloseKind::IncompatibleProtocol => 6,
                 _ => {
                     unimplemented!("");
                 }
@@ -4823,6 +6816,47 @@ impl SseEncode for crate::api::dart::api::room_close_reason::RoomCloseReason {
     }
 }
 
+impl SseEncode for crate::api::dart::api::room_member::RoomMember {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <String>::sse_encode(self.id, serializer);
+        <std::collections::HashMap<String, String>>::sse_encode(
+            self.metadata,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode
+    for crate::api::dart::api::signalling_latency::SignallingLatency
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <f64>::sse_encode(self.rtt_ms, serializer);
+        <f64>::sse_encode(self.smoothed_rtt_ms, serializer);
+        <f64>::sse_encode(self.jitter_ms, serializer);
+        <u32>::sse_encode(self.samples, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dart::api::diagnostics::ThroughputDiagnostics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(
+        self,
+        serializer: &mut flutter_rust_bridge::for_generated::SseSerializer,
+    ) {
+        <i64>::sse_encode(self.bytes_sent, serializer);
+        <i64>::sse_encode(self.duration_ms, serializer);
+        <i64>::sse_encode(self.bitrate_bps, serializer);
+    }
+}
+
 impl SseEncode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(
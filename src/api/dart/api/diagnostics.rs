@@ -0,0 +1,161 @@
+//! Pre-call diagnostics of media devices, network and connectivity.
+
+use medea_client_api_proto::{IcePassword, IceServer};
+
+use crate::{
+    api::dart::api::ForeignClass,
+    media::{self, MediaKind},
+};
+
+/// [RTCIceServer][1] to run pre-call diagnostics via.
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtciceserver
+#[derive(Debug)]
+pub struct ApiIceServer {
+    /// URLs of this [`ApiIceServer`].
+    pub urls: Vec<String>,
+
+    /// Username to authenticate on this [`ApiIceServer`] with.
+    pub username: Option<String>,
+
+    /// Secret to authenticate on this [`ApiIceServer`] with.
+    pub credential: Option<String>,
+}
+
+impl From<ApiIceServer> for IceServer {
+    fn from(server: ApiIceServer) -> Self {
+        Self {
+            urls: server.urls,
+            username: server.username,
+            credential: server
+                .credential
+                .map(|c| IcePassword::from(secrecy::SecretString::from(c))),
+        }
+    }
+}
+
+/// Report of pre-call diagnostics.
+#[derive(Debug)]
+pub struct DiagnosticsReport {
+    /// Results of checking every available media input device.
+    pub devices: Vec<DeviceDiagnostics>,
+
+    /// Results of the local ICE gathering and connectivity check.
+    pub ice: IceDiagnostics,
+
+    /// Throughput estimated over a loopback connection, if it has been
+    /// established.
+    pub throughput: Option<ThroughputDiagnostics>,
+}
+
+impl From<media::DiagnosticsReport> for DiagnosticsReport {
+    fn from(report: media::DiagnosticsReport) -> Self {
+        Self {
+            devices: report.devices.into_iter().map(Into::into).collect(),
+            ice: report.ice.into(),
+            throughput: report.throughput.map(Into::into),
+        }
+    }
+}
+
+impl ForeignClass for DiagnosticsReport {}
+
+/// Result of checking a single media input device.
+#[derive(Debug)]
+pub struct DeviceDiagnostics {
+    /// ID of the checked device.
+    pub device_id: String,
+
+    /// Label of the checked device.
+    pub label: String,
+
+    /// Kind of the media produced by the checked device.
+    pub kind: MediaKind,
+
+    /// Description of the error occurred while acquiring media from the
+    /// device, if any.
+    pub error: Option<String>,
+
+    /// Width (in pixels) of the video captured from the device.
+    pub width: Option<u32>,
+
+    /// Height (in pixels) of the video captured from the device.
+    pub height: Option<u32>,
+
+    /// Frame rate of the video captured from the device.
+    pub frame_rate: Option<f64>,
+
+    /// Peak audio level (in `0..=100` range) captured from the device.
+    pub audio_level: Option<i32>,
+}
+
+impl From<media::DeviceDiagnostics> for DeviceDiagnostics {
+    fn from(device: media::DeviceDiagnostics) -> Self {
+        Self {
+            device_id: device.device_id,
+            label: device.label,
+            kind: device.kind,
+            error: device.error,
+            width: device.width,
+            height: device.height,
+            frame_rate: device.frame_rate,
+            audio_level: device.audio_level,
+        }
+    }
+}
+
+/// Results of the local ICE gathering and connectivity check.
+#[derive(Debug)]
+pub struct IceDiagnostics {
+    /// Number of gathered host candidates.
+    pub host_candidates: u32,
+
+    /// Number of gathered server reflexive candidates.
+    pub srflx_candidates: u32,
+
+    /// Number of gathered relay candidates.
+    pub relay_candidates: u32,
+
+    /// Indicator whether the loopback connection has been established.
+    pub connected: bool,
+
+    /// Descriptions of the errors occurred while gathering candidates or
+    /// establishing the loopback connection.
+    pub errors: Vec<String>,
+}
+
+impl From<media::IceDiagnostics> for IceDiagnostics {
+    fn from(ice: media::IceDiagnostics) -> Self {
+        Self {
+            host_candidates: ice.host_candidates,
+            srflx_candidates: ice.srflx_candidates,
+            relay_candidates: ice.relay_candidates,
+            connected: ice.connected,
+            errors: ice.errors,
+        }
+    }
+}
+
+/// Throughput estimated over a loopback connection.
+#[derive(Clone, Copy, Debug)]
+pub struct ThroughputDiagnostics {
+    /// Number of bytes sent during the measurement.
+    pub bytes_sent: i64,
+
+    /// Duration of the measurement in milliseconds.
+    pub duration_ms: i64,
+
+    /// Estimated bitrate in bits per second.
+    pub bitrate_bps: i64,
+}
+
+impl From<media::ThroughputDiagnostics> for ThroughputDiagnostics {
+    fn from(throughput: media::ThroughputDiagnostics) -> Self {
+        let to_i64 = |v: u64| i64::try_from(v).unwrap_or(i64::MAX);
+        Self {
+            bytes_sent: to_i64(throughput.bytes_sent),
+            duration_ms: to_i64(throughput.duration_ms),
+            bitrate_bps: to_i64(throughput.bitrate_bps),
+        }
+    }
+}
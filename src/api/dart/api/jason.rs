@@ -2,7 +2,7 @@ use flutter_rust_bridge::{DartOpaque, frb};
 use send_wrapper::SendWrapper;
 
 use crate::{
-    api, api::Error as DartError, jason,
+    api, api::Error as DartError, jason, platform,
     platform::utils::dart_future::IntoDartFuture as _,
 };
 #[cfg(doc)]
@@ -54,6 +54,47 @@ impl Jason {
         self.0.close_room(&room_to_delete.0);
     }
 
+    /// Makes all the [`Room`]s initialized after this call share a single
+    /// connection with a media server.
+    ///
+    /// Each [`Room`] is still joined and left independently, while the shared
+    /// connection is closed once all of them are left.
+    #[frb(sync)]
+    pub fn jason_share_rpc_connection(&self) {
+        self.0.share_rpc_connection();
+    }
+
+    /// Makes all the connections with a media server created after this call
+    /// fall back to [Server-Sent Events] transport if a [WebSocket] connection
+    /// cannot be established.
+    ///
+    /// [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+    /// [WebSocket]: https://en.wikipedia.org/wiki/WebSocket
+    #[frb(sync)]
+    pub fn jason_enable_sse_fallback(&self) {
+        self.0.enable_sse_fallback();
+    }
+
+    /// Enables recording of RPC sessions of all the [`Room`]s initialized
+    /// after this call.
+    ///
+    /// Has no effect if this library is built without `rpc-recording` Cargo
+    /// feature.
+    #[frb(sync)]
+    pub fn jason_enable_rpc_recording(&self) {
+        self.0.enable_rpc_recording();
+    }
+
+    /// Exports the recorded RPC sessions as [JSON Lines], or returns [`None`]
+    /// if recording hasn't been enabled.
+    ///
+    /// [JSON Lines]: https://jsonlines.org
+    #[frb(sync)]
+    #[must_use]
+    pub fn jason_export_rpc_recording(&self) -> Option<String> {
+        self.0.export_rpc_recording()
+    }
+
     /// Notifies [`Jason`] about a network change event (interface switch or
     /// similar).
     ///
@@ -74,6 +115,28 @@ impl Jason {
         .into_dart_opaque()
     }
 
+    /// Sets callback, invoked with a [`NetworkState`] whenever it changes.
+    ///
+    /// [`NetworkState`]: crate::api::NetworkState
+    #[frb(sync)]
+    pub fn jason_on_network_state_change(&self, cb: DartOpaque) {
+        self.0.on_network_state_change(platform::Function::new(cb));
+    }
+
+    /// Notifies [`Jason`] whether the device is connected to a network, as
+    /// reported by a connectivity plugin.
+    ///
+    /// Calls [`Jason::jason_network_changed()`] automatically once the network
+    /// becomes available again.
+    #[frb(sync)]
+    pub fn jason_set_network_online(&self, online: bool) {
+        self.0.set_network_state(if online {
+            platform::NetworkState::Online
+        } else {
+            platform::NetworkState::Offline
+        });
+    }
+
     /// Closes this [`Jason`].
     #[frb(sync)]
     #[must_use]
@@ -10,9 +10,13 @@ use send_wrapper::SendWrapper;
 #[cfg(doc)]
 use crate::media::track::local;
 use crate::{
-    api::{DART_HANDLER_PORT, Error, dart::api::ForeignClass},
+    api::{
+        DART_HANDLER_PORT, Error,
+        dart::api::{ApiDeviceVideoTrackConstraints, ForeignClass},
+    },
     media::{
-        MediaKind, MediaSourceKind, NoiseSuppressionLevel, track::local as core,
+        MediaKind, MediaSourceKind, MeteringMode, NoiseSuppressionLevel,
+        VideoSource, track::local as core,
     },
     platform::{self, utils::dart_future::IntoDartFuture as _},
 };
@@ -132,6 +136,27 @@ impl LocalMediaTrack {
         self.0.is_audio_processing_available()
     }
 
+    /// Applies the provided [`ApiDeviceVideoTrackConstraints`] to this live
+    /// device video [`LocalMediaTrack`], without re-acquiring its media source.
+    ///
+    /// __NOTE__: Not supported by `medea_flutter_webrtc` yet, so always
+    /// completes with an error.
+    #[frb(sync)]
+    #[must_use]
+    pub fn apply_constraints(
+        &self,
+        constraints: ApiDeviceVideoTrackConstraints,
+    ) -> DartOpaque {
+        let this = self.0.clone();
+        async move {
+            this.apply_constraints(VideoSource::Device(constraints.into()))
+                .await
+                .map_err(Error::from)
+        }
+        .into_dart_future()
+        .into_dart_opaque()
+    }
+
     /// Toggles noise suppression for this [`LocalMediaTrack`].
     #[frb(sync)]
     #[must_use]
@@ -289,6 +314,134 @@ impl LocalMediaTrack {
         .into_dart_opaque()
     }
 
+    /// Indicates whether the camera producing this [`LocalMediaTrack`]
+    /// supports zoom control via
+    /// [`LocalMediaTrack::set_zoom()`].
+    ///
+    /// __NOTE__: Only supported on iOS.
+    #[frb(sync)]
+    #[must_use]
+    pub fn is_zoom_available(&self) -> bool {
+        self.0.is_zoom_available()
+    }
+
+    /// Sets the provided zoom level of the camera producing this
+    /// [`LocalMediaTrack`].
+    #[frb(sync)]
+    #[must_use]
+    pub fn set_zoom(&self, zoom: f64) -> DartOpaque {
+        let this = self.0.clone();
+        async move { this.set_zoom(zoom).await.map_err(Error::from) }
+            .into_dart_future()
+            .into_dart_opaque()
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrack`]
+    /// supports torch control via
+    /// [`LocalMediaTrack::set_torch_enabled()`].
+    ///
+    /// __NOTE__: Only supported on iOS.
+    #[frb(sync)]
+    #[must_use]
+    pub fn is_torch_available(&self) -> bool {
+        self.0.is_torch_available()
+    }
+
+    /// Toggles the torch (fill light) of the camera producing this
+    /// [`LocalMediaTrack`].
+    #[frb(sync)]
+    #[must_use]
+    pub fn set_torch_enabled(&self, enabled: bool) -> DartOpaque {
+        let this = self.0.clone();
+        async move { this.set_torch_enabled(enabled).await.map_err(Error::from) }
+            .into_dart_future()
+            .into_dart_opaque()
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrack`]
+    /// supports focus mode control via
+    /// [`LocalMediaTrack::set_focus_mode()`].
+    #[frb(sync)]
+    #[must_use]
+    pub fn is_focus_mode_available(&self) -> bool {
+        self.0.is_focus_mode_available()
+    }
+
+    /// Sets the provided focus [`MeteringMode`] of the camera producing this
+    /// [`LocalMediaTrack`].
+    #[frb(sync)]
+    #[must_use]
+    pub fn set_focus_mode(&self, mode: MeteringMode) -> DartOpaque {
+        let this = self.0.clone();
+        async move { this.set_focus_mode(mode).await.map_err(Error::from) }
+            .into_dart_future()
+            .into_dart_opaque()
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrack`]
+    /// supports focus distance control via
+    /// [`LocalMediaTrack::set_focus_distance()`].
+    #[frb(sync)]
+    #[must_use]
+    pub fn is_focus_distance_available(&self) -> bool {
+        self.0.is_focus_distance_available()
+    }
+
+    /// Sets the provided focus distance (in meters) of the camera producing
+    /// this [`LocalMediaTrack`].
+    #[frb(sync)]
+    #[must_use]
+    pub fn set_focus_distance(&self, distance: f64) -> DartOpaque {
+        let this = self.0.clone();
+        async move { this.set_focus_distance(distance).await.map_err(Error::from) }
+            .into_dart_future()
+            .into_dart_opaque()
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrack`]
+    /// supports exposure compensation control via
+    /// [`LocalMediaTrack::set_exposure_compensation()`].
+    #[frb(sync)]
+    #[must_use]
+    pub fn is_exposure_compensation_available(&self) -> bool {
+        self.0.is_exposure_compensation_available()
+    }
+
+    /// Sets the provided exposure compensation (in exposure value stops) of the
+    /// camera producing this [`LocalMediaTrack`].
+    #[frb(sync)]
+    #[must_use]
+    pub fn set_exposure_compensation(&self, compensation: f64) -> DartOpaque {
+        let this = self.0.clone();
+        async move {
+            this.set_exposure_compensation(compensation)
+                .await
+                .map_err(Error::from)
+        }
+        .into_dart_future()
+        .into_dart_opaque()
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrack`]
+    /// supports white balance mode control via
+    /// [`LocalMediaTrack::set_white_balance_mode()`].
+    #[frb(sync)]
+    #[must_use]
+    pub fn is_white_balance_mode_available(&self) -> bool {
+        self.0.is_white_balance_mode_available()
+    }
+
+    /// Sets the provided white balance [`MeteringMode`] of the camera producing
+    /// this [`LocalMediaTrack`].
+    #[frb(sync)]
+    #[must_use]
+    pub fn set_white_balance_mode(&self, mode: MeteringMode) -> DartOpaque {
+        let this = self.0.clone();
+        async move { this.set_white_balance_mode(mode).await.map_err(Error::from) }
+            .into_dart_future()
+            .into_dart_opaque()
+    }
+
     /// Frees the data behind the provided opaque local track.
     #[frb(sync)]
     #[must_use]
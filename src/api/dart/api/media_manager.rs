@@ -11,11 +11,12 @@ use crate::{
     api::{
         Error as DartError,
         api::{
-            ApiMediaDeviceDetails, ApiMediaDisplayDetails,
-            ApiMediaStreamSettings, LocalMediaTrack,
+            ApiDeviceCapabilities, ApiIceServer, ApiMediaDeviceDetails,
+            ApiMediaDisplayDetails, ApiMediaStreamSettings, DiagnosticsReport,
+            LocalMediaTrack,
         },
     },
-    media::{self as core},
+    media::{self as core, PermissionKind},
     platform::{self, utils::dart_future::IntoDartFuture as _},
 };
 
@@ -110,6 +111,24 @@ impl MediaManagerHandle {
         .into_dart_opaque()
     }
 
+    /// Returns [`ApiDeviceCapabilities`] of the media input device with the
+    /// provided `device_id`.
+    #[frb(sync)]
+    #[must_use]
+    pub fn device_capabilities(&self, device_id: String) -> DartOpaque {
+        let manager = self.0.clone();
+
+        async move {
+            Ok::<_, Traced<core::DeviceCapabilitiesError>>(
+                ApiDeviceCapabilities::from(
+                    manager.device_capabilities(device_id).await?,
+                ),
+            )
+        }
+        .into_dart_future()
+        .into_dart_opaque()
+    }
+
     /// Switches the current output audio device to the device with the provided
     /// `device_id`.
     #[frb(sync)]
@@ -184,4 +203,58 @@ impl MediaManagerHandle {
             .map_err(DartError::from)
             .map_err(Into::into)
     }
+
+    /// Returns the current [`PermissionState`] of the provided
+    /// [`PermissionKind`].
+    ///
+    /// [`PermissionState`]: core::PermissionState
+    #[frb(sync)]
+    #[must_use]
+    pub fn permission_state(&self, kind: PermissionKind) -> DartOpaque {
+        let manager = self.0.clone();
+
+        async move { manager.permission_state(kind).await }
+            .into_dart_future()
+            .into_dart_opaque()
+    }
+
+    /// Subscribes onto the [`PermissionState`] changes of the provided
+    /// [`PermissionKind`].
+    ///
+    /// [`PermissionState`]: core::PermissionState
+    #[frb(sync)]
+    #[must_use]
+    pub fn on_permission_change(
+        &self,
+        kind: PermissionKind,
+        cb: DartOpaque,
+    ) -> DartOpaque {
+        let manager = self.0.clone();
+        let cb = platform::Function::new(cb);
+
+        async move { manager.on_permission_change(kind, cb).await }
+            .into_dart_future()
+            .into_dart_opaque()
+    }
+
+    /// Runs pre-call diagnostics of media devices, network and connectivity
+    /// via the provided [`ApiIceServer`]s, resolving with a
+    /// [`DiagnosticsReport`].
+    #[frb(sync)]
+    #[must_use]
+    pub fn run_diagnostics(
+        &self,
+        ice_servers: Vec<ApiIceServer>,
+    ) -> DartOpaque {
+        let manager = self.0.clone();
+
+        async move {
+            let ice_servers = ice_servers.into_iter().map(Into::into).collect();
+            Ok::<_, Traced<core::DiagnosticsError>>(DiagnosticsReport::from(
+                manager.run_diagnostics(ice_servers).await?,
+            ))
+        }
+        .into_dart_future()
+        .into_dart_opaque()
+    }
 }
@@ -14,6 +14,7 @@
     clippy::missing_docs_in_private_items,
     clippy::redundant_else,
     clippy::significant_drop_tightening,
+    clippy::too_many_lines,
     clippy::undocumented_unsafe_blocks,
     clippy::unused_trait_names,
     clippy::use_self,
@@ -27,6 +28,7 @@
 mod api_bridge_generated;
 
 pub mod connection_handle;
+pub mod diagnostics;
 pub mod jason;
 pub mod local_media_track;
 pub mod media_manager;
@@ -35,6 +37,8 @@ pub mod reconnect_handle;
 pub mod remote_media_track;
 pub mod room;
 pub mod room_close_reason;
+pub mod room_member;
+pub mod signalling_latency;
 
 use std::{ptr, sync::LazyLock};
 
@@ -44,11 +48,18 @@ use flutter_rust_bridge::{
 };
 
 pub use self::{
-    connection_handle::ConnectionHandle, jason::Jason,
-    local_media_track::LocalMediaTrack, media_manager::MediaManagerHandle,
+    connection_handle::ConnectionHandle,
+    diagnostics::{ApiIceServer, DiagnosticsReport},
+    jason::Jason,
+    local_media_track::LocalMediaTrack,
+    media_manager::MediaManagerHandle,
     member_connection_state::MemberConnectionState,
-    reconnect_handle::ReconnectHandle, remote_media_track::RemoteMediaTrack,
-    room::RoomHandle, room_close_reason::RoomCloseReason,
+    reconnect_handle::ReconnectHandle,
+    remote_media_track::RemoteMediaTrack,
+    room::RoomHandle,
+    room_close_reason::RoomCloseReason,
+    room_member::RoomMember,
+    signalling_latency::SignallingLatency,
 };
 use crate::{
     api::{
@@ -183,6 +194,87 @@ impl From<platform::MediaDisplayInfo> for ApiMediaDisplayDetails {
     }
 }
 
+/// Inclusive range of integer values supported by a media input device.
+#[derive(Clone, Copy, Debug)]
+pub struct ApiIntRange {
+    /// Minimum supported value.
+    pub min: u32,
+
+    /// Maximum supported value.
+    pub max: u32,
+}
+
+impl From<media::ValueRange<u32>> for ApiIntRange {
+    fn from(range: media::ValueRange<u32>) -> Self {
+        Self { min: range.min, max: range.max }
+    }
+}
+
+/// Inclusive range of floating point values supported by a media input
+/// device.
+#[derive(Clone, Copy, Debug)]
+pub struct ApiFloatRange {
+    /// Minimum supported value.
+    pub min: f64,
+
+    /// Maximum supported value.
+    pub max: f64,
+}
+
+impl From<media::ValueRange<f64>> for ApiFloatRange {
+    fn from(range: media::ValueRange<f64>) -> Self {
+        Self { min: range.min, max: range.max }
+    }
+}
+
+/// Capabilities of a media input device, describing the values of
+/// [MediaTrackConstraints][1] it supports.
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#dom-mediatrackconstraints
+#[derive(Debug)]
+pub struct ApiDeviceCapabilities {
+    /// Supported width of the video in pixels.
+    pub width: Option<ApiIntRange>,
+
+    /// Supported height of the video in pixels.
+    pub height: Option<ApiIntRange>,
+
+    /// Supported frame rate of the video.
+    pub frame_rate: Option<ApiFloatRange>,
+
+    /// Supported facing modes of the camera.
+    pub facing_modes: Vec<media::FacingMode>,
+
+    /// Supported channel count of the audio.
+    pub channel_count: Option<ApiIntRange>,
+
+    /// Supported values of the echo cancellation toggle.
+    pub echo_cancellation: Vec<bool>,
+
+    /// Supported values of the auto gain control toggle.
+    pub auto_gain_control: Vec<bool>,
+
+    /// Supported values of the noise suppression toggle.
+    pub noise_suppression: Vec<bool>,
+}
+
+impl ForeignClass for ApiDeviceCapabilities {}
+
+impl From<media::DeviceCapabilities> for ApiDeviceCapabilities {
+    fn from(caps: media::DeviceCapabilities) -> Self {
+        Self {
+            width: caps.width.map(Into::into),
+            height: caps.height.map(Into::into),
+            frame_rate: caps.frame_rate.map(Into::into),
+            facing_modes: caps.facing_modes,
+            channel_count: caps.channel_count.map(Into::into),
+            echo_cancellation: caps.echo_cancellation,
+            auto_gain_control: caps.auto_gain_control,
+            noise_suppression: caps.noise_suppression,
+        }
+    }
+}
+
 /// Constraints applicable to audio tracks, sourced from a system audio
 /// recording device (usually a microphone).
 #[derive(Debug)]
@@ -288,6 +380,12 @@ pub struct ApiDeviceVideoTrackConstraints {
     /// Width of the video in pixels.
     #[frb(non_final)]
     pub width: Option<ConstrainU32>,
+
+    /// [Frame rate][1] of the video.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    #[frb(non_final)]
+    pub frame_rate: Option<ConstrainU32>,
 }
 
 impl From<ApiDeviceVideoTrackConstraints>
@@ -320,6 +418,8 @@ impl From<ApiDeviceVideoTrackConstraints>
                 ConstrainU32::Range(min, max) => res.width_in_range(min, max),
             }
         }
+
+        res.frame_rate = value.frame_rate;
         res
     }
 }
@@ -461,6 +561,14 @@ pub fn vec_media_display_details_from_raw(
     unsafe { Vec::<ApiMediaDisplayDetails>::from_ptr(ptr) }
 }
 
+/// Returns the [`ApiDeviceCapabilities`] from the provided [`ForeignClass`]
+/// address.
+#[frb(sync, type_64bit_int)]
+#[must_use]
+pub fn device_capabilities_from_raw(ptr: usize) -> ApiDeviceCapabilities {
+    unsafe { ApiDeviceCapabilities::from_ptr(ptr) }
+}
+
 /// Logs Dart exception.
 #[frb(sync)]
 #[must_use]
@@ -1,5 +1,7 @@
 //! External handle to a [`Room`].
 
+use std::collections::HashMap;
+
 use flutter_rust_bridge::{DartOpaque, frb};
 use send_wrapper::SendWrapper;
 use tracerr::Traced;
@@ -7,7 +9,10 @@ use tracerr::Traced;
 #[cfg(doc)]
 use crate::{api::LocalMediaTrack, room::Room};
 use crate::{
-    api::{Error as DartError, api::ApiMediaStreamSettings},
+    api::{
+        Error as DartError, RoomCloseReason, RoomMember, SignallingLatency,
+        api::ApiMediaStreamSettings,
+    },
     media::MediaSourceKind,
     platform::{self, utils::dart_future::IntoDartFuture as _},
     room as core,
@@ -44,6 +49,66 @@ impl RoomHandle {
         .into_dart_opaque()
     }
 
+    /// Connects to a media server and joins the [`Room`] with the provided
+    /// `credential`, so it never appears in the connection URL.
+    ///
+    /// The `url` has the following format:
+    /// `{{ Host URL }}/{{ Room ID }}/{{ Member ID }}`
+    /// (e.g. `wss://medea.com/MyConf1/Alice`).
+    ///
+    /// Additional HTTP `headers` are sent in a handshake request of the
+    /// [WebSocket] connection with a media server.
+    ///
+    /// [WebSocket]: https://en.wikipedia.org/wiki/WebSocket
+    #[frb(sync)]
+    #[must_use]
+    pub fn join_with_credential(
+        &self,
+        url: String,
+        credential: String,
+        headers: HashMap<String, String>,
+    ) -> DartOpaque {
+        let room_handle = self.0.clone();
+
+        async move {
+            room_handle
+                .join_with_credential(
+                    url,
+                    credential,
+                    headers.into_iter().collect(),
+                )
+                .await?;
+            Ok::<_, Traced<core::RoomJoinError>>(())
+        }
+        .into_dart_future()
+        .into_dart_opaque()
+    }
+
+    /// Leaves this [`Room`] gracefully, reporting the provided `reason` of
+    /// leaving to a media server.
+    ///
+    /// Waits for a media server to acknowledge the leaving (for 3 seconds at
+    /// most), stops all the local media tracks sent in this [`Room`] and
+    /// resolves with its final [`RoomCloseReason`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandleImpl::leave()`] method errors.
+    #[frb(sync)]
+    #[must_use]
+    pub fn leave(&self, reason: Option<String>) -> DartOpaque {
+        let room_handle = self.0.clone();
+
+        async move {
+            let reason = room_handle.leave(reason).await?;
+            Ok::<_, Traced<core::HandleDetachedError>>(RoomCloseReason::from(
+                reason,
+            ))
+        }
+        .into_dart_future()
+        .into_dart_opaque()
+    }
+
     /// Updates this [`Room`]'s [`ApiMediaStreamSettings`].
     ///
     /// This affects all the [`PeerConnection`]s in this [`Room`]. If
@@ -53,6 +118,10 @@ impl RoomHandle {
     /// sending peers, so that might cause a new [getUserMedia()][1] request to
     /// happen.
     ///
+    /// __NOTE__: Changing constraints of a live track is not supported by
+    /// `medea_flutter_webrtc` yet, so even resolution or frame rate changes
+    /// re-acquire the affected media tracks.
+    ///
     /// Media obtaining/injection errors are additionally fired to a
     /// [`on_failed_local_media`] callback.
     ///
@@ -404,6 +473,129 @@ impl RoomHandle {
             .map_err(Into::into)
     }
 
+    /// Returns all the `Member`s currently present in this [`Room`]
+    /// (including the local one).
+    ///
+    /// Doesn't depend on any media being exchanged with these `Member`s.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandleImpl::members()`] method errors.
+    #[frb(sync)]
+    pub fn members(&self) -> Result<Vec<RoomMember>, DartOpaque> {
+        self.0
+            .members()
+            .map(|members| members.into_iter().map(Into::into).collect())
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets a callback to be invoked once a new `Member` joins the provided
+    /// [`Room`], providing a [`RoomMember`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandleImpl::on_member_joined()`] method errors.
+    #[frb(sync)]
+    pub fn on_member_joined(&self, cb: DartOpaque) -> Result<(), DartOpaque> {
+        self.0
+            .on_member_joined(platform::Function::new(cb))
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets a callback to be invoked with an ID of a `Member` who left the
+    /// provided [`Room`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandleImpl::on_member_left()`] method errors.
+    #[frb(sync)]
+    pub fn on_member_left(&self, cb: DartOpaque) -> Result<(), DartOpaque> {
+        self.0
+            .on_member_left(platform::Function::new(cb))
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets a callback to be invoked once metadata of some `Member` of the
+    /// provided [`Room`] changes, providing an actual [`RoomMember`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandleImpl::on_member_updated()`] method errors.
+    #[frb(sync)]
+    pub fn on_member_updated(&self, cb: DartOpaque) -> Result<(), DartOpaque> {
+        self.0
+            .on_member_updated(platform::Function::new(cb))
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets a callback to be invoked with a name of an event received from a
+    /// media server, which this client doesn't recognize.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandleImpl::on_unknown_event()`] method errors.
+    #[frb(sync)]
+    pub fn on_unknown_event(&self, cb: DartOpaque) -> Result<(), DartOpaque> {
+        self.0
+            .on_unknown_event(platform::Function::new(cb))
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets a callback to be invoked with actual [`SignallingLatency`]
+    /// statistics each time a round-trip time of the signalling connection
+    /// with a media server is measured.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandleImpl::on_signalling_latency()`] method errors.
+    #[frb(sync)]
+    pub fn on_signalling_latency(
+        &self,
+        cb: DartOpaque,
+    ) -> Result<(), DartOpaque> {
+        self.0
+            .on_signalling_latency(platform::Function::new(cb))
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Returns the current [`SignallingLatency`] statistics of the signalling
+    /// connection with a media server, if any has been measured yet.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandleImpl::signalling_latency()`] method errors.
+    #[frb(sync)]
+    pub fn signalling_latency(
+        &self,
+    ) -> Result<Option<SignallingLatency>, DartOpaque> {
+        self.0
+            .signalling_latency()
+            .map(|l| l.map(Into::into))
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
+    /// Returns name of the transport used by the signalling connection with a
+    /// media server (`WebSocket` or `SSE`), if it has been established.
+    ///
+    /// # Errors
+    ///
+    /// If the [`core::RoomHandleImpl::rpc_transport_kind()`] method errors.
+    #[frb(sync)]
+    pub fn rpc_transport_kind(&self) -> Result<Option<String>, DartOpaque> {
+        self.0
+            .rpc_transport_kind()
+            .map(|k| k.map(|k| k.to_string()))
+            .map_err(DartError::from)
+            .map_err(Into::into)
+    }
+
     /// Sets a callback to be invoked once a connection with a media server is
     /// lost.
     ///
@@ -0,0 +1,27 @@
+//! `Member` present in a [`Room`].
+
+use std::collections::HashMap;
+
+use medea_client_api_proto as proto;
+
+use crate::api::dart::api::ForeignClass;
+#[cfg(doc)]
+use crate::room::Room;
+
+/// `Member` present in a [`Room`].
+#[derive(Clone, Debug)]
+pub struct RoomMember {
+    /// ID of this `Member`.
+    pub id: String,
+
+    /// Opaque metadata of this `Member` (display name, avatar URL, etc.).
+    pub metadata: HashMap<String, String>,
+}
+
+impl From<proto::Member> for RoomMember {
+    fn from(m: proto::Member) -> Self {
+        Self { id: m.id.0, metadata: m.metadata }
+    }
+}
+
+impl ForeignClass for RoomMember {}
@@ -0,0 +1,33 @@
+//! Round-trip latency statistics of a signalling connection.
+
+use crate::{api::dart::api::ForeignClass, rpc};
+
+/// Round-trip latency statistics of a signalling connection with a media
+/// server.
+#[derive(Clone, Copy, Debug)]
+pub struct SignallingLatency {
+    /// Round-trip time of the last measurement in milliseconds.
+    pub rtt_ms: f64,
+
+    /// Smoothed round-trip time in milliseconds.
+    pub smoothed_rtt_ms: f64,
+
+    /// Round-trip time variation (jitter) in milliseconds.
+    pub jitter_ms: f64,
+
+    /// Number of measurements these statistics are calculated from.
+    pub samples: u32,
+}
+
+impl From<rpc::SignallingLatency> for SignallingLatency {
+    fn from(l: rpc::SignallingLatency) -> Self {
+        Self {
+            rtt_ms: l.rtt.as_secs_f64() * 1000.0,
+            smoothed_rtt_ms: l.smoothed_rtt.as_secs_f64() * 1000.0,
+            jitter_ms: l.jitter.as_secs_f64() * 1000.0,
+            samples: l.samples,
+        }
+    }
+}
+
+impl ForeignClass for SignallingLatency {}
@@ -11,10 +11,12 @@ use crate::{
     api::{
         DART_HANDLER_PORT, box_dart_handle,
         err::{
-            EnumerateDevicesException, FormatException, InternalException,
+            DeviceNotFoundException, EnumerateDevicesException,
+            FormatException, InternalException,
             InvalidOutputAudioDeviceIdException, LocalMediaInitException,
-            MediaSettingsUpdateException, MediaStateTransitionException,
-            MicVolumeException, RpcClientException, StateError,
+            MediaSettingsUpdateException,
+            MediaStateTransitionException, MicVolumeException,
+            RpcClientException, StateError,
         },
     },
     platform::{self, utils::string_into_c_str},
@@ -108,6 +110,13 @@ mod exception {
             trace: ptr::NonNull<c_char>,
         ) -> Result<Dart_Handle, Error>;
 
+        /// Returns a new Dart [`DeviceNotFoundException`] with the provided
+        /// `device_id` and `trace` properties.
+        pub fn new_device_not_found_exception(
+            device_id: ptr::NonNull<c_char>,
+            trace: ptr::NonNull<c_char>,
+        ) -> Result<Dart_Handle, Error>;
+
         /// Returns a new Dart `NativePanicException`.
         pub fn new_panic_exception() -> Result<Dart_Handle, Error>;
     }
@@ -311,3 +320,19 @@ impl From<MediaSettingsUpdateException> for DartError {
         Self::new(exception)
     }
 }
+
+#[expect(clippy::fallible_impl_from, reason = "FFI error is unexpected")]
+impl From<DeviceNotFoundException> for DartError {
+    fn from(err: DeviceNotFoundException) -> Self {
+        #[expect(clippy::unwrap_used, reason = "FFI error is unexpected")]
+        let exception = unsafe {
+            exception::new_device_not_found_exception(
+                string_into_c_str(err.device_id()),
+                string_into_c_str(err.trace()),
+            )
+        }
+        .unwrap();
+
+        Self::new(exception)
+    }
+}
@@ -38,11 +38,10 @@ pub use self::{
     api::{
         ConnectionHandle, Jason, LocalMediaTrack, MediaManagerHandle,
         MemberConnectionState, ReconnectHandle, RemoteMediaTrack,
-        RoomCloseReason, RoomHandle,
+        RoomCloseReason, RoomHandle, RoomMember, SignallingLatency,
     },
     err::DartError as Error,
 };
-pub use crate::media::MediaDirection;
 use crate::{
     api::{api::ForeignClass, dart::err::new_panic_error},
     media::{
@@ -54,6 +53,10 @@ use crate::{
         handle::DartHandle, string_into_c_str,
     },
 };
+pub use crate::{
+    media::{MediaDirection, PermissionState},
+    platform::NetworkState,
+};
 
 thread_local! {
     /// Used to create [`DartOpaque`]s on the Rust side.
@@ -213,6 +216,18 @@ impl From<MediaDirection> for DartValue {
     }
 }
 
+impl From<PermissionState> for DartValue {
+    fn from(val: PermissionState) -> Self {
+        Self::from(val as u8)
+    }
+}
+
+impl From<NetworkState> for DartValue {
+    fn from(val: NetworkState) -> Self {
+        Self::from(val as u8)
+    }
+}
+
 impl From<bool> for DartValue {
     fn from(val: bool) -> Self {
         Self::Bool(val)
@@ -723,6 +738,19 @@ impl TryFrom<i64> for MediaDirection {
     }
 }
 
+impl TryFrom<i64> for PermissionState {
+    type Error = i64;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Granted),
+            1 => Ok(Self::Denied),
+            2 => Ok(Self::Prompt),
+            _ => Err(value),
+        }
+    }
+}
+
 /// Returns a [`Dart_Handle`] dereferenced from the provided pointer.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn unbox_dart_handle(
@@ -12,9 +12,11 @@ use crate::{
     api::Error,
     connection,
     media::{
-        self, AudioLevelError, AudioProcessingError, EnumerateDevicesError,
-        EnumerateDisplaysError, GetDisplayMediaError, GetUserMediaError,
-        InitLocalTracksError, InvalidOutputAudioDeviceIdError, MicVolumeError,
+        self, ApplyConstraintsError, AudioLevelError, AudioProcessingError,
+        CameraControlError, DeviceCapabilitiesError, DiagnosticsError,
+        EnumerateDevicesError, EnumerateDisplaysError,
+        GetDisplayMediaError, GetUserMediaError, InitLocalTracksError,
+        InvalidOutputAudioDeviceIdError, MicVolumeError, PermissionError,
     },
     peer::{
         InsertLocalTracksError, LocalMediaError, UpdateLocalStreamError,
@@ -94,6 +96,19 @@ pub enum LocalMediaInitExceptionKind {
     /// [2]: https://tinyurl.com/rnxcavf
     /// [3]: https://w3.org/TR/screen-capture#dom-mediadevices-getdisplaymedia
     LocalTrackIsEnded,
+
+    /// Occurs if the [getUserMedia()][1] request failed, because a user denied
+    /// the media permission.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediadevices-getusermedia
+    PermissionDeniedByUser,
+
+    /// Occurs if the [getUserMedia()][1] request failed, because a system (for
+    /// example, OS privacy settings or a browser policy) denied the media
+    /// permission.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediadevices-getusermedia
+    PermissionDeniedBySystem,
 }
 
 /// Exception thrown when accessing media devices.
@@ -219,6 +234,41 @@ impl InvalidOutputAudioDeviceIdException {
     }
 }
 
+/// Exception thrown when no media input device with the requested ID exists.
+#[cfg_attr(target_family = "wasm", wasm_bindgen)]
+#[derive(Debug)]
+pub struct DeviceNotFoundException {
+    /// ID of the media input device which wasn't found.
+    device_id: String,
+
+    /// Stacktrace of this [`DeviceNotFoundException`].
+    trace: Trace,
+}
+
+impl DeviceNotFoundException {
+    /// Creates a new [`DeviceNotFoundException`] from the provided
+    /// `device_id` and error [`Trace`].
+    #[must_use]
+    pub const fn new(device_id: String, trace: Trace) -> Self {
+        Self { device_id, trace }
+    }
+}
+
+#[cfg_attr(target_family = "wasm", wasm_bindgen)]
+impl DeviceNotFoundException {
+    /// Returns ID of the media input device which wasn't found.
+    #[must_use]
+    pub fn device_id(&self) -> String {
+        self.device_id.clone()
+    }
+
+    /// Returns stacktrace of this [`DeviceNotFoundException`].
+    #[must_use]
+    pub fn trace(&self) -> String {
+        self.trace.to_string()
+    }
+}
+
 /// Exception thrown when cannot interact with microphone volume.
 #[cfg_attr(target_family = "wasm", wasm_bindgen)]
 #[derive(Debug)]
@@ -539,6 +589,91 @@ impl MediaSettingsUpdateException {
     }
 }
 
+/// Possible error kinds of a [`MediaRecorderException`].
+#[cfg(target_family = "wasm")]
+#[cfg_attr(target_family = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum MediaRecorderExceptionKind {
+    /// Occurs if recording is started without any tracks to record.
+    NoTracks,
+
+    /// Occurs if the requested operation is not allowed in the current
+    /// recording state.
+    InvalidState,
+
+    /// Occurs if more than one video track is requested to be recorded.
+    TooManyVideoTracks,
+
+    /// Occurs if the underlying platform recorder fails.
+    RecordingFailed,
+}
+
+/// Exception thrown when recording of local and remote tracks fails.
+#[cfg(target_family = "wasm")]
+#[cfg_attr(target_family = "wasm", wasm_bindgen)]
+#[derive(Debug)]
+pub struct MediaRecorderException {
+    /// Concrete error kind of this [`MediaRecorderException`].
+    kind: MediaRecorderExceptionKind,
+
+    /// Error message describing the problem.
+    message: Cow<'static, str>,
+
+    /// [`platform::Error`] causing this [`MediaRecorderException`].
+    cause: Option<platform::Error>,
+
+    /// Stacktrace of this [`MediaRecorderException`].
+    trace: Trace,
+}
+
+#[cfg(target_family = "wasm")]
+impl MediaRecorderException {
+    /// Creates a new [`MediaRecorderException`] from the provided error
+    /// `kind`, `message`, optional `cause` and `trace`.
+    #[must_use]
+    pub fn new<M: Into<Cow<'static, str>>>(
+        kind: MediaRecorderExceptionKind,
+        message: M,
+        cause: Option<platform::Error>,
+        trace: Trace,
+    ) -> Self {
+        Self { kind, message: message.into(), cause, trace }
+    }
+}
+
+#[cfg(target_family = "wasm")]
+#[expect( // `wasm_bindgen` doesn't support `const fn`
+    clippy::missing_const_for_fn,
+    reason = "`wasm_bindgen` doesn't support `const fn`"
+)]
+#[cfg_attr(target_family = "wasm", wasm_bindgen)]
+impl MediaRecorderException {
+    /// Returns concrete error kind of this [`MediaRecorderException`].
+    #[must_use]
+    pub fn kind(&self) -> MediaRecorderExceptionKind {
+        self.kind
+    }
+
+    /// Returns an error message describing the problem.
+    #[must_use]
+    pub fn message(&self) -> String {
+        self.message.to_string()
+    }
+
+    /// Returns [`platform::Error`] causing this [`MediaRecorderException`].
+    #[must_use]
+    pub fn cause(&self) -> Option<platform::Error> {
+        self.cause.clone()
+    }
+
+    /// Returns stacktrace of this [`MediaRecorderException`].
+    #[must_use]
+    pub fn trace(&self) -> String {
+        self.trace.to_string()
+    }
+}
+
 impl From<Traced<media::HandleDetachedError>> for Error {
     fn from(err: Traced<media::HandleDetachedError>) -> Self {
         let (err, trace) = err.split();
@@ -589,6 +724,23 @@ impl From<Traced<EnumerateDisplaysError>> for Error {
     }
 }
 
+impl From<Traced<DeviceCapabilitiesError>> for Error {
+    fn from(err: Traced<DeviceCapabilitiesError>) -> Self {
+        let (err, stacktrace) = err.split();
+        match err {
+            DeviceCapabilitiesError::EnumerateDevicesFailed(err) => {
+                EnumerateDevicesException::new(err, stacktrace).into()
+            }
+            DeviceCapabilitiesError::DeviceNotFound(device_id) => {
+                DeviceNotFoundException::new(device_id, stacktrace).into()
+            }
+            DeviceCapabilitiesError::Detached => {
+                StateError::new(err.to_string(), stacktrace).into()
+            }
+        }
+    }
+}
+
 impl From<Traced<InvalidOutputAudioDeviceIdError>> for Error {
     fn from(err: Traced<InvalidOutputAudioDeviceIdError>) -> Self {
         let (_, trace) = err.split();
@@ -610,6 +762,71 @@ impl From<Traced<MicVolumeError>> for Error {
     }
 }
 
+#[cfg(target_family = "wasm")]
+impl From<Traced<media::FrameProcessorError>> for Error {
+    fn from(err: Traced<media::FrameProcessorError>) -> Self {
+        let (err, stacktrace) = err.split();
+        match err {
+            media::FrameProcessorError::Detached => {
+                StateError::new(err.to_string(), stacktrace).into()
+            }
+        }
+    }
+}
+
+impl From<Traced<PermissionError>> for Error {
+    fn from(err: Traced<PermissionError>) -> Self {
+        let (err, stacktrace) = err.split();
+        match err {
+            PermissionError::QueryFailed(_) => {
+                InternalException::new(err.to_string(), err.cause(), stacktrace)
+                    .into()
+            }
+            PermissionError::Detached => {
+                StateError::new(err.to_string(), stacktrace).into()
+            }
+        }
+    }
+}
+
+impl From<Traced<DiagnosticsError>> for Error {
+    fn from(err: Traced<DiagnosticsError>) -> Self {
+        let (err, stacktrace) = err.split();
+        match err {
+            DiagnosticsError::EnumerateDevicesFailed(err) => {
+                InternalException::new(err.to_string(), Some(err), stacktrace)
+                    .into()
+            }
+            DiagnosticsError::Detached => {
+                StateError::new(err.to_string(), stacktrace).into()
+            }
+        }
+    }
+}
+
+#[cfg(target_family = "wasm")]
+impl From<Traced<media::MediaRecorderError>> for Error {
+    fn from(err: Traced<media::MediaRecorderError>) -> Self {
+        use media::MediaRecorderError;
+        use MediaRecorderExceptionKind as Kind;
+
+        let (err, stacktrace) = err.split();
+        let message = err.to_string();
+        let (kind, cause) = match err {
+            MediaRecorderError::NoTracks => (Kind::NoTracks, None),
+            MediaRecorderError::InvalidState => (Kind::InvalidState, None),
+            MediaRecorderError::TooManyVideoTracks => {
+                (Kind::TooManyVideoTracks, None)
+            }
+            MediaRecorderError::RecordingFailed(err) => {
+                (Kind::RecordingFailed, Some(err))
+            }
+        };
+
+        MediaRecorderException::new(kind, message, cause, stacktrace).into()
+    }
+}
+
 impl From<Traced<AudioLevelError>> for Error {
     fn from(err: Traced<AudioLevelError>) -> Self {
         let (err, stacktrace) = err.split();
@@ -626,6 +843,20 @@ impl From<Traced<AudioProcessingError>> for Error {
     }
 }
 
+impl From<Traced<ApplyConstraintsError>> for Error {
+    fn from(err: Traced<ApplyConstraintsError>) -> Self {
+        let (err, stacktrace) = err.split();
+        InternalException::new(err.to_string(), err.cause(), stacktrace).into()
+    }
+}
+
+impl From<Traced<CameraControlError>> for Error {
+    fn from(err: Traced<CameraControlError>) -> Self {
+        let (err, stacktrace) = err.split();
+        InternalException::new(err.to_string(), err.cause(), stacktrace).into()
+    }
+}
+
 impl From<Traced<InitLocalTracksError>> for Error {
     fn from(err: Traced<InitLocalTracksError>) -> Self {
         use GetDisplayMediaError as Gdm;
@@ -646,6 +877,12 @@ impl From<Traced<InitLocalTracksError>> for Error {
             Err::GetUserMediaFailed(Gum::PlatformRequestFailed(
                 platform::GetUserMediaError::Video(cause),
             )) => (Kind::GetUserMediaVideoFailed, Some(cause)),
+            Err::GetUserMediaFailed(Gum::PlatformRequestFailed(
+                platform::GetUserMediaError::PermissionDeniedByUser(cause),
+            )) => (Kind::PermissionDeniedByUser, Some(cause)),
+            Err::GetUserMediaFailed(Gum::PlatformRequestFailed(
+                platform::GetUserMediaError::PermissionDeniedBySystem(cause),
+            )) => (Kind::PermissionDeniedBySystem, Some(cause)),
             Err::GetUserMediaFailed(Gum::PlatformRequestFailed(
                 platform::GetUserMediaError::Unknown(cause),
             )) => (Kind::GetUserMediaFailed, Some(cause)),
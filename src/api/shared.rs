@@ -39,6 +39,12 @@ pub enum RoomCloseKind {
     /// Usually this means that either `Member` or `Room` was deleted from the
     /// server.
     Evicted,
+
+    /// Client API protocol version of the client is incompatible with the
+    /// server one.
+    ///
+    /// Most likely either the client or the server should be upgraded.
+    IncompatibleProtocol,
 }
 
 impl From<proto::CloseReason> for RoomCloseKind {
@@ -52,6 +58,9 @@ impl From<proto::CloseReason> for RoomCloseKind {
             proto::CloseReason::Idle => Self::Idle,
             proto::CloseReason::Rejected => Self::Rejected,
             proto::CloseReason::Evicted => Self::Evicted,
+            proto::CloseReason::IncompatibleProtocol => {
+                Self::IncompatibleProtocol
+            }
             proto::CloseReason::Reconnected
             | proto::CloseReason::InternalError => Self::InternalServerError,
         }
@@ -69,6 +78,7 @@ impl From<ClientDisconnect> for RoomCloseKind {
                 Self::InternalClientError
             }
             ClientDisconnect::RoomClosed => Self::Finished,
+            ClientDisconnect::IncompatibleProtocol => Self::IncompatibleProtocol,
         }
     }
 }
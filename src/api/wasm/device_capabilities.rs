@@ -0,0 +1,120 @@
+//! Capabilities of a media input device.
+
+use derive_more::with_trait::From;
+use wasm_bindgen::prelude::*;
+
+use crate::{api::FacingMode, media};
+
+/// Inclusive range of integer values supported by a media input device.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub struct IntRange {
+    /// Minimum supported value.
+    pub min: u32,
+
+    /// Maximum supported value.
+    pub max: u32,
+}
+
+impl From<media::ValueRange<u32>> for IntRange {
+    fn from(range: media::ValueRange<u32>) -> Self {
+        Self { min: range.min, max: range.max }
+    }
+}
+
+/// Inclusive range of floating point values supported by a media input
+/// device.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub struct FloatRange {
+    /// Minimum supported value.
+    pub min: f64,
+
+    /// Maximum supported value.
+    pub max: f64,
+}
+
+impl From<media::ValueRange<f64>> for FloatRange {
+    fn from(range: media::ValueRange<f64>) -> Self {
+        Self { min: range.min, max: range.max }
+    }
+}
+
+/// Capabilities of a media input device, describing the values of
+/// [MediaTrackConstraints][1] it supports.
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#dom-mediatrackconstraints
+#[wasm_bindgen]
+#[derive(Clone, Debug, From)]
+pub struct DeviceCapabilities(pub(crate) media::DeviceCapabilities);
+
+#[wasm_bindgen]
+impl DeviceCapabilities {
+    /// Returns the supported [width][1] of the video in pixels.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-width
+    #[must_use]
+    pub fn width(&self) -> Option<IntRange> {
+        self.0.width.map(Into::into)
+    }
+
+    /// Returns the supported [height][1] of the video in pixels.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-height
+    #[must_use]
+    pub fn height(&self) -> Option<IntRange> {
+        self.0.height.map(Into::into)
+    }
+
+    /// Returns the supported [frame rate][1] of the video.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-frameRate
+    #[must_use]
+    pub fn frame_rate(&self) -> Option<FloatRange> {
+        self.0.frame_rate.map(Into::into)
+    }
+
+    /// Returns the supported [facing modes][1] of the camera.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-facingMode
+    #[must_use]
+    pub fn facing_modes(&self) -> Vec<JsValue> {
+        self.0
+            .facing_modes
+            .iter()
+            .map(|mode| JsValue::from(FacingMode::from(*mode)))
+            .collect()
+    }
+
+    /// Returns the supported [channel count][1] of the audio.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-channelCount
+    #[must_use]
+    pub fn channel_count(&self) -> Option<IntRange> {
+        self.0.channel_count.map(Into::into)
+    }
+
+    /// Returns the supported values of the [echo cancellation][1] toggle.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#def-constraint-echoCancellation
+    #[must_use]
+    pub fn echo_cancellation(&self) -> Vec<JsValue> {
+        self.0.echo_cancellation.iter().copied().map(JsValue::from).collect()
+    }
+
+    /// Returns the supported values of the [auto gain control][1] toggle.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#def-constraint-autoGainControl
+    #[must_use]
+    pub fn auto_gain_control(&self) -> Vec<JsValue> {
+        self.0.auto_gain_control.iter().copied().map(JsValue::from).collect()
+    }
+
+    /// Returns the supported values of the [noise suppression][1] toggle.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#def-constraint-noiseSuppression
+    #[must_use]
+    pub fn noise_suppression(&self) -> Vec<JsValue> {
+        self.0.noise_suppression.iter().copied().map(JsValue::from).collect()
+    }
+}
@@ -0,0 +1,208 @@
+//! Pre-call diagnostics of media devices, network and connectivity.
+
+use derive_more::with_trait::From;
+use medea_client_api_proto as proto;
+use wasm_bindgen::prelude::*;
+
+use crate::{api::MediaKind, media};
+
+/// [RTCIceServer][1] to run pre-call diagnostics via.
+///
+/// [1]: https://w3.org/TR/webrtc#dom-rtciceserver
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct IceServer(proto::IceServer);
+
+#[wasm_bindgen]
+impl IceServer {
+    /// Creates a new [`IceServer`] with the provided `urls` and no
+    /// credentials.
+    #[must_use]
+    #[wasm_bindgen(constructor)]
+    pub fn new(urls: Vec<String>) -> Self {
+        Self(proto::IceServer { urls, username: None, credential: None })
+    }
+
+    /// Sets the username to authenticate on this [`IceServer`] with.
+    pub fn username(&mut self, username: String) {
+        self.0.username = Some(username);
+    }
+
+    /// Sets the secret to authenticate on this [`IceServer`] with.
+    pub fn credential(&mut self, credential: String) {
+        self.0.credential =
+            Some(secrecy::SecretString::from(credential).into());
+    }
+}
+
+impl From<IceServer> for proto::IceServer {
+    fn from(server: IceServer) -> Self {
+        server.0
+    }
+}
+
+/// Report of pre-call diagnostics.
+///
+/// This struct is returned from the
+/// [`MediaManagerHandle::run_diagnostics()`][0] method.
+///
+/// [0]: crate::api::MediaManagerHandle::run_diagnostics
+#[wasm_bindgen]
+#[derive(Clone, Debug, From)]
+pub struct DiagnosticsReport(media::DiagnosticsReport);
+
+#[wasm_bindgen]
+impl DiagnosticsReport {
+    /// Returns [`DeviceDiagnostics`] of every available media input device.
+    #[must_use]
+    pub fn devices(&self) -> Vec<JsValue> {
+        self.0
+            .devices
+            .iter()
+            .cloned()
+            .map(|d| JsValue::from(DeviceDiagnostics(d)))
+            .collect()
+    }
+
+    /// Returns [`IceDiagnostics`] of the local ICE gathering and connectivity
+    /// check.
+    #[must_use]
+    pub fn ice(&self) -> IceDiagnostics {
+        IceDiagnostics(self.0.ice.clone())
+    }
+
+    /// Returns [`ThroughputDiagnostics`] estimated over a loopback
+    /// connection, if it has been established.
+    #[must_use]
+    pub fn throughput(&self) -> Option<ThroughputDiagnostics> {
+        self.0.throughput.map(ThroughputDiagnostics)
+    }
+}
+
+/// Result of checking a single media input device.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct DeviceDiagnostics(media::DeviceDiagnostics);
+
+#[wasm_bindgen]
+impl DeviceDiagnostics {
+    /// Returns ID of the checked device.
+    #[must_use]
+    pub fn device_id(&self) -> String {
+        self.0.device_id.clone()
+    }
+
+    /// Returns label of the checked device.
+    #[must_use]
+    pub fn label(&self) -> String {
+        self.0.label.clone()
+    }
+
+    /// Returns [`MediaKind`] of the media produced by the checked device.
+    #[must_use]
+    pub fn kind(&self) -> MediaKind {
+        self.0.kind.into()
+    }
+
+    /// Returns description of the error occurred while acquiring media from
+    /// the device, if any.
+    #[must_use]
+    pub fn error(&self) -> Option<String> {
+        self.0.error.clone()
+    }
+
+    /// Returns width (in pixels) of the video captured from the device.
+    #[must_use]
+    pub const fn width(&self) -> Option<u32> {
+        self.0.width
+    }
+
+    /// Returns height (in pixels) of the video captured from the device.
+    #[must_use]
+    pub const fn height(&self) -> Option<u32> {
+        self.0.height
+    }
+
+    /// Returns frame rate of the video captured from the device.
+    #[must_use]
+    pub const fn frame_rate(&self) -> Option<f64> {
+        self.0.frame_rate
+    }
+
+    /// Returns peak audio level (in `0..=100` range) captured from the
+    /// device.
+    #[must_use]
+    pub const fn audio_level(&self) -> Option<i32> {
+        self.0.audio_level
+    }
+}
+
+/// Results of the local ICE gathering and connectivity check.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct IceDiagnostics(media::IceDiagnostics);
+
+#[wasm_bindgen]
+impl IceDiagnostics {
+    /// Returns number of gathered host candidates.
+    #[must_use]
+    pub const fn host_candidates(&self) -> u32 {
+        self.0.host_candidates
+    }
+
+    /// Returns number of gathered server reflexive candidates.
+    #[must_use]
+    pub const fn srflx_candidates(&self) -> u32 {
+        self.0.srflx_candidates
+    }
+
+    /// Returns number of gathered relay candidates.
+    #[must_use]
+    pub const fn relay_candidates(&self) -> u32 {
+        self.0.relay_candidates
+    }
+
+    /// Indicates whether the loopback connection has been established.
+    #[must_use]
+    pub const fn connected(&self) -> bool {
+        self.0.connected
+    }
+
+    /// Returns descriptions of the errors occurred while gathering candidates
+    /// or establishing the loopback connection.
+    #[must_use]
+    pub fn errors(&self) -> Vec<String> {
+        self.0.errors.clone()
+    }
+}
+
+/// Throughput estimated over a loopback connection.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub struct ThroughputDiagnostics(media::ThroughputDiagnostics);
+
+#[wasm_bindgen]
+#[expect(
+    clippy::as_conversions,
+    clippy::cast_precision_loss,
+    reason = "JS numbers are `f64`"
+)]
+impl ThroughputDiagnostics {
+    /// Returns number of bytes sent during the measurement.
+    #[must_use]
+    pub const fn bytes_sent(&self) -> f64 {
+        self.0.bytes_sent as f64
+    }
+
+    /// Returns duration of the measurement in milliseconds.
+    #[must_use]
+    pub const fn duration_ms(&self) -> f64 {
+        self.0.duration_ms as f64
+    }
+
+    /// Returns estimated bitrate in bits per second.
+    #[must_use]
+    pub const fn bitrate_bps(&self) -> f64 {
+        self.0.bitrate_bps as f64
+    }
+}
@@ -9,8 +9,9 @@ use wasm_bindgen::{
 };
 
 use crate::api::err::{
-    EnumerateDevicesException, FormatException, InternalException,
-    InvalidOutputAudioDeviceIdException, LocalMediaInitException,
+    DeviceNotFoundException, EnumerateDevicesException, FormatException,
+    InternalException, InvalidOutputAudioDeviceIdException,
+    LocalMediaInitException, MediaRecorderException,
     MediaSettingsUpdateException, MediaStateTransitionException,
     MicVolumeException, RpcClientException, StateError,
 };
@@ -67,3 +68,5 @@ impl_from_into_jsval_for_error!(MediaStateTransitionException);
 impl_from_into_jsval_for_error!(MediaSettingsUpdateException);
 impl_from_into_jsval_for_error!(InvalidOutputAudioDeviceIdException);
 impl_from_into_jsval_for_error!(MicVolumeException);
+impl_from_into_jsval_for_error!(MediaRecorderException);
+impl_from_into_jsval_for_error!(DeviceNotFoundException);
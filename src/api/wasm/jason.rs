@@ -7,7 +7,7 @@ use wasm_bindgen_futures::future_to_promise;
 
 use crate::{
     api::{Error, MediaManagerHandle, RoomHandle},
-    jason,
+    jason, platform,
 };
 
 /// General JS side library interface.
@@ -44,6 +44,43 @@ impl Jason {
         self.0.close_room(&room_to_delete.into());
     }
 
+    /// Makes all the `Room`s initialized after this call share a single
+    /// connection with a media server.
+    ///
+    /// Each `Room` is still joined and left independently, while the shared
+    /// connection is closed once all of them are left.
+    pub fn share_rpc_connection(&self) {
+        self.0.share_rpc_connection();
+    }
+
+    /// Makes all the connections with a media server created after this call
+    /// fall back to [Server-Sent Events] transport if a [WebSocket] connection
+    /// cannot be established.
+    ///
+    /// [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+    /// [WebSocket]: https://en.wikipedia.org/wiki/WebSocket
+    pub fn enable_sse_fallback(&self) {
+        self.0.enable_sse_fallback();
+    }
+
+    /// Enables recording of RPC sessions of all the `Room`s initialized after
+    /// this call.
+    ///
+    /// Has no effect if this library is built without `rpc-recording` Cargo
+    /// feature.
+    pub fn enable_rpc_recording(&self) {
+        self.0.enable_rpc_recording();
+    }
+
+    /// Exports the recorded RPC sessions as [JSON Lines], or returns
+    /// `undefined` if recording hasn't been enabled.
+    ///
+    /// [JSON Lines]: https://jsonlines.org
+    #[must_use]
+    pub fn export_rpc_recording(&self) -> Option<String> {
+        self.0.export_rpc_recording()
+    }
+
     /// Notifies [`Jason`] about a network change event (interface switch or
     /// similar).
     ///
@@ -60,6 +97,32 @@ impl Jason {
         })
     }
 
+    /// Enables monitoring of the browser's network connectivity and page
+    /// lifecycle, so [`Jason::network_changed()`] is called automatically
+    /// once the network becomes available again.
+    pub fn enable_network_monitor(&self) {
+        self.0.enable_network_monitor();
+    }
+
+    /// Sets callback, invoked with a [`NetworkState`] whenever it changes.
+    ///
+    /// [`NetworkState`]: crate::api::NetworkState
+    pub fn on_network_state_change(&self, cb: js_sys::Function) {
+        self.0.on_network_state_change(cb.into());
+    }
+
+    /// Notifies [`Jason`] whether the device is connected to a network.
+    ///
+    /// Calls [`Jason::network_changed()`] automatically once the network
+    /// becomes available again.
+    pub fn set_network_online(&self, online: bool) {
+        self.0.set_network_state(if online {
+            platform::NetworkState::Online
+        } else {
+            platform::NetworkState::Offline
+        });
+    }
+
     /// Drops [`Jason`] API object, so all the related objects (rooms,
     /// connections, streams etc.) respectively. All objects related to this
     /// [`Jason`] API object will be detached (you will still hold them, but
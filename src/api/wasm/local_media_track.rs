@@ -8,8 +8,11 @@ use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::future_to_promise;
 
 use crate::{
-    api::{self, MediaKind, MediaSourceKind, MediaStreamTrackState},
-    media::track::local,
+    api::{
+        self, DeviceVideoTrackConstraints, MediaKind, MediaSourceKind,
+        MediaStreamTrackState, MeteringMode,
+    },
+    media::{VideoSource, track::local},
 };
 
 /// Wrapper around a local [MediaStreamTrack][1].
@@ -20,7 +23,7 @@ use crate::{
 /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack
 #[wasm_bindgen]
 #[derive(Debug, From)]
-pub struct LocalMediaTrack(local::LocalMediaTrackImpl);
+pub struct LocalMediaTrack(pub(crate) local::LocalMediaTrackImpl);
 
 #[wasm_bindgen]
 impl LocalMediaTrack {
@@ -99,6 +102,29 @@ impl LocalMediaTrack {
         self.0.is_audio_processing_available()
     }
 
+    /// Applies the provided [`DeviceVideoTrackConstraints`] to this live
+    /// device video [`LocalMediaTrack`] via [applyConstraints()][1], without
+    /// re-acquiring its media source.
+    ///
+    /// # Errors
+    ///
+    /// With an [`InternalException`] if the constraints cannot be applied.
+    ///
+    /// [`InternalException`]: crate::api::err::InternalException
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-applyconstraints
+    pub fn apply_constraints(
+        &self,
+        constraints: DeviceVideoTrackConstraints,
+    ) -> Promise {
+        let this = self.0.clone();
+        future_to_promise(async move {
+            this.apply_constraints(VideoSource::Device(constraints.into()))
+                .await
+                .map_err(api::Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
     /// Toggles noise suppression for this [`LocalMediaTrack`].
     pub fn set_noise_suppression_enabled(&self, enabled: bool) -> Promise {
         let this = self.0.clone();
@@ -170,4 +196,137 @@ impl LocalMediaTrack {
             Ok(JsValue::from(enabled))
         })
     }
+
+    /// Returns the microphone volume level (in percents) applied to this
+    /// [`LocalMediaTrack`].
+    ///
+    /// `undefined` if this [`LocalMediaTrack`] isn't a device audio one.
+    #[must_use]
+    pub fn microphone_volume(&self) -> Option<i64> {
+        self.0.microphone_volume()
+    }
+
+    /// Sets the microphone volume level (in percents) applied to this
+    /// [`LocalMediaTrack`], without affecting other [`LocalMediaTrack`]s.
+    ///
+    /// The provided `level` is clamped to the `[0;100]` range.
+    ///
+    /// Does nothing if this [`LocalMediaTrack`] isn't a device audio one.
+    pub fn set_microphone_volume(&self, level: i64) {
+        self.0.set_microphone_volume(level);
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrack`]
+    /// supports zoom control via
+    /// [`LocalMediaTrack::set_zoom()`].
+    #[must_use]
+    pub fn is_zoom_available(&self) -> bool {
+        self.0.is_zoom_available()
+    }
+
+    /// Sets the provided zoom level of the camera producing this
+    /// [`LocalMediaTrack`].
+    pub fn set_zoom(&self, zoom: f64) -> Promise {
+        let this = self.0.clone();
+        future_to_promise(async move {
+            this.set_zoom(zoom).await.map_err(api::Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrack`]
+    /// supports torch control via
+    /// [`LocalMediaTrack::set_torch_enabled()`].
+    #[must_use]
+    pub fn is_torch_available(&self) -> bool {
+        self.0.is_torch_available()
+    }
+
+    /// Toggles the torch (fill light) of the camera producing this
+    /// [`LocalMediaTrack`].
+    pub fn set_torch_enabled(&self, enabled: bool) -> Promise {
+        let this = self.0.clone();
+        future_to_promise(async move {
+            this.set_torch_enabled(enabled).await.map_err(api::Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrack`]
+    /// supports focus mode control via
+    /// [`LocalMediaTrack::set_focus_mode()`].
+    #[must_use]
+    pub fn is_focus_mode_available(&self) -> bool {
+        self.0.is_focus_mode_available()
+    }
+
+    /// Sets the provided focus [`MeteringMode`] of the camera producing this
+    /// [`LocalMediaTrack`].
+    pub fn set_focus_mode(&self, mode: MeteringMode) -> Promise {
+        let this = self.0.clone();
+        future_to_promise(async move {
+            this.set_focus_mode(mode.into()).await.map_err(api::Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrack`]
+    /// supports focus distance control via
+    /// [`LocalMediaTrack::set_focus_distance()`].
+    #[must_use]
+    pub fn is_focus_distance_available(&self) -> bool {
+        self.0.is_focus_distance_available()
+    }
+
+    /// Sets the provided focus distance (in meters) of the camera producing
+    /// this [`LocalMediaTrack`].
+    pub fn set_focus_distance(&self, distance: f64) -> Promise {
+        let this = self.0.clone();
+        future_to_promise(async move {
+            this.set_focus_distance(distance)
+                .await
+                .map_err(api::Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrack`]
+    /// supports exposure compensation control via
+    /// [`LocalMediaTrack::set_exposure_compensation()`].
+    #[must_use]
+    pub fn is_exposure_compensation_available(&self) -> bool {
+        self.0.is_exposure_compensation_available()
+    }
+
+    /// Sets the provided exposure compensation (in exposure value stops) of the
+    /// camera producing this [`LocalMediaTrack`].
+    pub fn set_exposure_compensation(&self, compensation: f64) -> Promise {
+        let this = self.0.clone();
+        future_to_promise(async move {
+            this.set_exposure_compensation(compensation)
+                .await
+                .map_err(api::Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrack`]
+    /// supports white balance mode control via
+    /// [`LocalMediaTrack::set_white_balance_mode()`].
+    #[must_use]
+    pub fn is_white_balance_mode_available(&self) -> bool {
+        self.0.is_white_balance_mode_available()
+    }
+
+    /// Sets the provided white balance [`MeteringMode`] of the camera producing
+    /// this [`LocalMediaTrack`].
+    pub fn set_white_balance_mode(&self, mode: MeteringMode) -> Promise {
+        let this = self.0.clone();
+        future_to_promise(async move {
+            this.set_white_balance_mode(mode.into())
+                .await
+                .map_err(api::Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
 }
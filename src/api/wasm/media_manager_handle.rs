@@ -9,7 +9,11 @@ use wasm_bindgen_futures::future_to_promise;
 
 use super::Error;
 use crate::{
-    api::{LocalMediaTrack, MediaDeviceDetails, MediaStreamSettings},
+    api::{
+        DeviceCapabilities, DiagnosticsReport, IceServer, LocalMediaTrack,
+        MediaDeviceDetails, MediaKind, MediaRecorder, MediaSourceKind,
+        MediaStreamSettings, PermissionKind, PermissionState,
+    },
     media,
 };
 
@@ -127,4 +131,405 @@ impl MediaManagerHandle {
             .map_err(Error::from)
             .map_err(Into::into)
     }
+
+    /// Returns the current [`PermissionState`] of the provided
+    /// [`PermissionKind`], allowing to check whether a user will be prompted
+    /// before acquiring media via [`MediaManagerHandle::init_local_tracks()`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// With an [`InternalException`] if the permission cannot be queried, for
+    /// example, if the provided [`PermissionKind`] is not supported by the
+    /// browser.
+    ///
+    /// [`InternalException`]: crate::api::err::InternalException
+    /// [`Jason`]: crate::api::Jason
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn permission_state(&self, kind: PermissionKind) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            this.permission_state(kind.into())
+                .await
+                .map(|state| PermissionState::from(state).into())
+                .map_err(Error::from)
+                .map_err(Into::into)
+        })
+    }
+
+    /// Subscribes onto the [`PermissionState`] changes of the provided
+    /// [`PermissionKind`].
+    ///
+    /// Replaces the callback previously set for the same [`PermissionKind`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// With an [`InternalException`] if the permission cannot be queried, for
+    /// example, if the provided [`PermissionKind`] is not supported by the
+    /// browser.
+    ///
+    /// [`InternalException`]: crate::api::err::InternalException
+    /// [`Jason`]: crate::api::Jason
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn on_permission_change(
+        &self,
+        kind: PermissionKind,
+        cb: js_sys::Function,
+    ) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            this.on_permission_change(kind.into(), cb.into())
+                .await
+                .map(|()| JsValue::UNDEFINED)
+                .map_err(Error::from)
+                .map_err(Into::into)
+        })
+    }
+
+    /// Runs pre-call diagnostics: checks every available media input device,
+    /// gathers local ICE candidates via the provided ICE servers and estimates
+    /// throughput over a loopback connection.
+    ///
+    /// Resolves with a [`DiagnosticsReport`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// With an [`InternalException`] if a request of platform media devices
+    /// access failed.
+    ///
+    /// [`InternalException`]: crate::api::err::InternalException
+    /// [`Jason`]: crate::api::Jason
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn run_diagnostics(&self, ice_servers: Vec<IceServer>) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            let ice_servers = ice_servers.into_iter().map(Into::into).collect();
+            this.run_diagnostics(ice_servers)
+                .await
+                .map(|report| DiagnosticsReport::from(report).into())
+                .map_err(Error::from)
+                .map_err(Into::into)
+        })
+    }
+
+    /// Creates a new [`MediaRecorder`] for recording a chosen set of
+    /// [`LocalMediaTrack`]s and [`RemoteMediaTrack`]s.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// [`Jason`]: crate::api::Jason
+    /// [`RemoteMediaTrack`]: crate::api::RemoteMediaTrack
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn new_recorder(&self) -> Result<MediaRecorder, JsValue> {
+        self.0
+            .new_recorder()
+            .map(MediaRecorder::from)
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Registers the provided `processor` function transforming media frames
+    /// ([VideoFrame][1]s or [AudioData][2]) of the [`LocalMediaTrack`]s of
+    /// the provided [`MediaKind`] and [`MediaSourceKind`], before they're
+    /// rendered and sent.
+    ///
+    /// The `processor` is called with each media frame, and should return the
+    /// processed one, or a `Promise` resolving into it. The original media
+    /// frame is closed once a new one is returned. If the `processor` throws
+    /// or rejects, the error is logged and the media frame is passed as is.
+    ///
+    /// Only applies to the [`LocalMediaTrack`]s obtained after this call.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// [`Jason`]: crate::api::Jason
+    /// [`StateError`]: crate::api::err::StateError
+    /// [1]: https://w3.org/TR/webcodecs#videoframe-interface
+    /// [2]: https://w3.org/TR/webcodecs#audiodata-interface
+    pub fn register_processor(
+        &self,
+        kind: MediaKind,
+        source_kind: MediaSourceKind,
+        processor: js_sys::Function,
+    ) -> Result<(), JsValue> {
+        self.0
+            .register_processor(
+                kind.into(),
+                source_kind.into(),
+                processor.into(),
+            )
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Enables or disables the processor registered via
+    /// [`MediaManagerHandle::register_processor()`] for the provided
+    /// [`MediaKind`] and [`MediaSourceKind`], in all the [`LocalMediaTrack`]s
+    /// it's applied to.
+    ///
+    /// Media frames are passed as is through a disabled processor.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// [`Jason`]: crate::api::Jason
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn set_processor_enabled(
+        &self,
+        kind: MediaKind,
+        source_kind: MediaSourceKind,
+        enabled: bool,
+    ) -> Result<(), JsValue> {
+        self.0
+            .set_processor_enabled(kind.into(), source_kind.into(), enabled)
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Unregisters the processor registered via
+    /// [`MediaManagerHandle::register_processor()`] for the provided
+    /// [`MediaKind`] and [`MediaSourceKind`], disabling it in all the
+    /// [`LocalMediaTrack`]s it's applied to.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// [`Jason`]: crate::api::Jason
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn unregister_processor(
+        &self,
+        kind: MediaKind,
+        source_kind: MediaSourceKind,
+    ) -> Result<(), JsValue> {
+        self.0
+            .unregister_processor(kind.into(), source_kind.into())
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Returns [`DeviceCapabilities`] of the media input device with the
+    /// provided `device_id`, describing the resolutions, frame rates, facing
+    /// modes, etc. it supports.
+    ///
+    /// Capabilities are read from an already captured track of this device,
+    /// if any, or from the device info otherwise, so the device is never
+    /// opened by this method.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// With a [`EnumerateDevicesException`][0] if a request of platform media
+    /// devices access failed.
+    ///
+    /// With a [`DeviceNotFoundException`] if there is no media input device
+    /// with the provided `device_id`.
+    ///
+    /// [`DeviceNotFoundException`]: crate::api::err::DeviceNotFoundException
+    /// [`Jason`]: crate::api::Jason
+    /// [`StateError`]: crate::api::err::StateError
+    /// [0]: crate::api::err::EnumerateDevicesException
+    pub fn device_capabilities(&self, device_id: String) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            this.device_capabilities(device_id)
+                .await
+                .map(|caps| DeviceCapabilities::from(caps).into())
+                .map_err(Error::from)
+                .map_err(Into::into)
+        })
+    }
+
+    /// Switches the current output audio device to the device with the
+    /// provided `device_id`.
+    ///
+    /// All the audio sinks registered via
+    /// [`MediaManagerHandle::register_audio_sink()`] (including the ones
+    /// registered afterwards) are switched to this device.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// With an [`InvalidOutputAudioDeviceIdException`][0] if the provided
+    /// `device_id` is not available.
+    ///
+    /// [`Jason`]: crate::api::Jason
+    /// [`StateError`]: crate::api::err::StateError
+    /// [0]: crate::api::err::InvalidOutputAudioDeviceIdException
+    pub fn set_output_audio_id(&self, device_id: String) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            this.set_output_audio_id(device_id)
+                .await
+                .map(|()| JsValue::UNDEFINED)
+                .map_err(Error::from)
+                .map_err(Into::into)
+        })
+    }
+
+    /// Registers the provided [HTMLMediaElement][1] playing a
+    /// [`RemoteMediaTrack`] audio, so it's switched to the output audio device
+    /// set via [`MediaManagerHandle::set_output_audio_id()`].
+    ///
+    /// # Ownership
+    ///
+    /// Jason never creates, registers or unregisters any [HTMLMediaElement][1]
+    /// on its own: it's up to the application to register every element it
+    /// renders a [`RemoteMediaTrack`] audio into.
+    ///
+    /// The registered [HTMLMediaElement][1] is strongly referenced until it's
+    /// unregistered, so the application __must__ call
+    /// [`MediaManagerHandle::unregister_audio_sink()`] once the element is
+    /// removed (e.g. when the [`RemoteMediaTrack`] is stopped), otherwise the
+    /// element is leaked.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// [`Jason`]: crate::api::Jason
+    /// [`RemoteMediaTrack`]: crate::api::RemoteMediaTrack
+    /// [`StateError`]: crate::api::err::StateError
+    /// [1]: https://html.spec.whatwg.org/#htmlmediaelement
+    pub fn register_audio_sink(
+        &self,
+        sink: web_sys::HtmlMediaElement,
+    ) -> Result<(), JsValue> {
+        self.0
+            .register_audio_sink(sink)
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Unregisters the provided [HTMLMediaElement][1], previously registered
+    /// via [`MediaManagerHandle::register_audio_sink()`], releasing the
+    /// reference to it.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// [`Jason`]: crate::api::Jason
+    /// [`StateError`]: crate::api::err::StateError
+    /// [1]: https://html.spec.whatwg.org/#htmlmediaelement
+    pub fn unregister_audio_sink(
+        &self,
+        sink: &web_sys::HtmlMediaElement,
+    ) -> Result<(), JsValue> {
+        self.0
+            .unregister_audio_sink(sink)
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Indicates whether it's possible to access microphone volume settings.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// [`Jason`]: crate::api::Jason
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn microphone_volume_is_available(&self) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            this.microphone_volume_is_available()
+                .await
+                .map(JsValue::from)
+                .map_err(Error::from)
+                .map_err(Into::into)
+        })
+    }
+
+    /// Returns the current microphone volume level in percents, applied to
+    /// all the [`LocalMediaTrack`]s captured from audio input devices.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// [`Jason`]: crate::api::Jason
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn microphone_volume(&self) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            this.microphone_volume()
+                .await
+                .map(JsValue::from)
+                .map_err(Error::from)
+                .map_err(Into::into)
+        })
+    }
+
+    /// Sets the microphone volume level in percents, applying it to all the
+    /// live [`LocalMediaTrack`]s captured from audio input devices, and to the
+    /// ones captured afterwards.
+    ///
+    /// The volume of a single [`LocalMediaTrack`] can be changed via
+    /// [`LocalMediaTrack::set_microphone_volume()`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying object has been disposed, e.g.
+    /// `free` was called on this [`MediaManagerHandle`], or on a [`Jason`] that
+    /// implicitly owns native object behind this [`MediaManagerHandle`].
+    ///
+    /// [`Jason`]: crate::api::Jason
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn set_microphone_volume(&self, level: i64) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            this.set_microphone_volume(level)
+                .await
+                .map(|()| JsValue::UNDEFINED)
+                .map_err(Error::from)
+                .map_err(Into::into)
+        })
+    }
 }
@@ -0,0 +1,145 @@
+//! Recorder of local and remote media tracks.
+
+use derive_more::with_trait::From;
+use js_sys::Promise;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+
+use crate::{
+    api::{Error, LocalMediaTrack, RecordingState, RemoteMediaTrack},
+    media,
+};
+
+/// Recorder of a chosen set of [`LocalMediaTrack`]s and [`RemoteMediaTrack`]s
+/// into a single media container via a [MediaRecorder][1].
+///
+/// Recorded media data is emitted in chunks to the [`MediaRecorder::on_data`]
+/// callback.
+///
+/// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+#[wasm_bindgen]
+#[derive(Debug, From)]
+pub struct MediaRecorder(media::MediaRecorderImpl);
+
+#[wasm_bindgen]
+impl MediaRecorder {
+    /// Adds the provided [`LocalMediaTrack`] to the recorded ones.
+    ///
+    /// # Errors
+    ///
+    /// With a [`MediaRecorderException`][0] if recording is already started.
+    ///
+    /// [0]: crate::api::err::MediaRecorderException
+    pub fn add_local_track(
+        &self,
+        track: &LocalMediaTrack,
+    ) -> Result<(), JsValue> {
+        self.0
+            .add_local_track(track.0.clone())
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Adds the provided [`RemoteMediaTrack`] to the recorded ones.
+    ///
+    /// # Errors
+    ///
+    /// With a [`MediaRecorderException`][0] if recording is already started.
+    ///
+    /// [0]: crate::api::err::MediaRecorderException
+    pub fn add_remote_track(
+        &self,
+        track: &RemoteMediaTrack,
+    ) -> Result<(), JsValue> {
+        self.0
+            .add_remote_track(track.0.clone())
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets callback to be invoked with each recorded media data chunk as a
+    /// [Blob][1].
+    ///
+    /// [1]: https://w3.org/TR/FileAPI#blob-section
+    pub fn on_data(&self, cb: js_sys::Function) {
+        self.0.on_data(cb.into());
+    }
+
+    /// Sets callback to be invoked with a [`MediaRecorderException`][0] once
+    /// recording fails.
+    ///
+    /// [0]: crate::api::err::MediaRecorderException
+    pub fn on_error(&self, cb: js_sys::Function) {
+        self.0.on_error(cb.into());
+    }
+
+    /// Returns the current [`RecordingState`] of this [`MediaRecorder`].
+    #[must_use]
+    pub fn state(&self) -> RecordingState {
+        self.0.state().into()
+    }
+
+    /// Starts recording of all the added tracks, emitting recorded data chunks
+    /// each `timeslice_ms` milliseconds (if provided), or only once recording
+    /// is stopped.
+    ///
+    /// # Errors
+    ///
+    /// With a [`MediaRecorderException`][0] if recording is already started,
+    /// no tracks or more than one video track were added, or the underlying
+    /// [MediaRecorder][1] fails to start.
+    ///
+    /// [0]: crate::api::err::MediaRecorderException
+    /// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    pub fn start(&self, timeslice_ms: Option<u32>) -> Result<(), JsValue> {
+        self.0.start(timeslice_ms).map_err(Error::from).map_err(Into::into)
+    }
+
+    /// Pauses recording.
+    ///
+    /// # Errors
+    ///
+    /// With a [`MediaRecorderException`][0] if recording is not in progress,
+    /// or the underlying [MediaRecorder][1] fails to pause.
+    ///
+    /// [0]: crate::api::err::MediaRecorderException
+    /// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    pub fn pause(&self) -> Result<(), JsValue> {
+        self.0.pause().map_err(Error::from).map_err(Into::into)
+    }
+
+    /// Resumes previously paused recording.
+    ///
+    /// # Errors
+    ///
+    /// With a [`MediaRecorderException`][0] if recording is not paused, or the
+    /// underlying [MediaRecorder][1] fails to resume.
+    ///
+    /// [0]: crate::api::err::MediaRecorderException
+    /// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    pub fn resume(&self) -> Result<(), JsValue> {
+        self.0.resume().map_err(Error::from).map_err(Into::into)
+    }
+
+    /// Stops recording, resolving once the last recorded data chunk is passed
+    /// to the [`MediaRecorder::on_data`] callback.
+    ///
+    /// # Errors
+    ///
+    /// With a [`MediaRecorderException`][0] if recording is not started, or
+    /// the underlying [MediaRecorder][1] fails to stop.
+    ///
+    /// [0]: crate::api::err::MediaRecorderException
+    /// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    pub fn stop(&self) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            this.stop()
+                .await
+                .map(|()| JsValue::UNDEFINED)
+                .map_err(Error::from)
+                .map_err(Into::into)
+        })
+    }
+}
@@ -10,7 +10,11 @@
 use derive_more::with_trait::{From, Into};
 use wasm_bindgen::prelude::*;
 
-use crate::{api::FacingMode, media, media::constraints::ConstrainBoolean};
+use crate::{
+    api::{DeviceCapabilities, FacingMode},
+    media,
+    media::constraints::ConstrainBoolean,
+};
 
 /// [MediaStreamConstraints][1] wrapper.
 ///
@@ -238,6 +242,30 @@ impl DeviceVideoTrackConstraints {
     pub fn width_in_range(&mut self, min: u32, max: u32) {
         self.0.width_in_range(min, max);
     }
+
+    /// Sets an exact [frameRate][1] constraint.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub fn exact_frame_rate(&mut self, frame_rate: u32) {
+        self.0.exact_frame_rate(frame_rate);
+    }
+
+    /// Sets an ideal [frameRate][1] constraint.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub fn ideal_frame_rate(&mut self, frame_rate: u32) {
+        self.0.ideal_frame_rate(frame_rate);
+    }
+
+    /// Adjusts these [`DeviceVideoTrackConstraints`] to the closest mode
+    /// supported by a device with the provided [`DeviceCapabilities`].
+    ///
+    /// Relaxes the exact and range width, height and frame rate constraints,
+    /// which cannot be satisfied by the device, into ideal ones, and drops the
+    /// facing mode constraint if the device doesn't support it.
+    pub fn fit_to(&mut self, caps: &DeviceCapabilities) {
+        self.0.fit_to(&caps.0);
+    }
 }
 
 /// Constraints applicable to video tracks sourced from a screen capturing.
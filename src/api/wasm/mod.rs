@@ -4,17 +4,22 @@
 //! [`Jason`]: crate::api::Jason
 
 pub mod connection_handle;
+pub mod device_capabilities;
+pub mod diagnostics;
 pub mod err;
 pub mod jason;
 pub mod local_media_track;
 pub mod media_device_details;
 pub mod media_manager_handle;
+pub mod media_recorder;
 pub mod media_stream_settings;
 pub mod member_connection_state;
 pub mod reconnect_handle;
 pub mod remote_media_track;
 pub mod room_close_reason;
 pub mod room_handle;
+pub mod room_member;
+pub mod signalling_latency;
 
 use derive_more::with_trait::Display;
 use js_sys::Promise;
@@ -23,11 +28,17 @@ use wasm_bindgen_futures::future_to_promise;
 
 pub use self::{
     connection_handle::ConnectionHandle,
+    device_capabilities::{DeviceCapabilities, FloatRange, IntRange},
+    diagnostics::{
+        DeviceDiagnostics, DiagnosticsReport, IceDiagnostics, IceServer,
+        ThroughputDiagnostics,
+    },
     err::Error,
     jason::Jason,
     local_media_track::LocalMediaTrack,
     media_device_details::MediaDeviceDetails,
     media_manager_handle::MediaManagerHandle,
+    media_recorder::MediaRecorder,
     media_stream_settings::{
         DeviceAudioTrackConstraints, DeviceVideoTrackConstraints,
         DisplayAudioTrackConstraints, DisplayVideoTrackConstraints,
@@ -40,6 +51,8 @@ pub use self::{
     remote_media_track::RemoteMediaTrack,
     room_close_reason::RoomCloseReason,
     room_handle::RoomHandle,
+    room_member::RoomMember,
+    signalling_latency::SignallingLatency,
 };
 use crate::{api, media, platform};
 
@@ -114,6 +127,87 @@ impl From<media::MediaDeviceKind> for MediaDeviceKind {
     }
 }
 
+/// Kind of a media permission, requested by a user agent before capturing
+/// media.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum PermissionKind {
+    /// Permission to capture audio from a microphone.
+    Microphone,
+
+    /// Permission to capture video from a camera.
+    Camera,
+
+    /// Permission to capture contents of a display or its portion (such as a
+    /// single window).
+    Screen,
+}
+
+impl From<PermissionKind> for media::PermissionKind {
+    fn from(val: PermissionKind) -> Self {
+        match val {
+            PermissionKind::Microphone => Self::Microphone,
+            PermissionKind::Camera => Self::Camera,
+            PermissionKind::Screen => Self::Screen,
+        }
+    }
+}
+
+/// [PermissionState][1] representation.
+///
+/// [1]: https://w3.org/TR/permissions#dom-permissionstate
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum PermissionState {
+    /// Permission is granted, so media can be captured without prompting a
+    /// user.
+    Granted,
+
+    /// Permission is denied, so media capturing will fail.
+    Denied,
+
+    /// User will be prompted for the permission on media capturing.
+    Prompt,
+}
+
+impl From<media::PermissionState> for PermissionState {
+    fn from(that: media::PermissionState) -> Self {
+        use media::PermissionState as S;
+
+        match that {
+            S::Granted => Self::Granted,
+            S::Denied => Self::Denied,
+            S::Prompt => Self::Prompt,
+        }
+    }
+}
+
+/// State of a [`MediaRecorder`].
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum RecordingState {
+    /// Recording is not started yet or has been stopped.
+    Inactive,
+
+    /// Recording is in progress.
+    Recording,
+
+    /// Recording is paused and may be resumed.
+    Paused,
+}
+
+impl From<media::RecordingState> for RecordingState {
+    fn from(that: media::RecordingState) -> Self {
+        use media::RecordingState as S;
+
+        match that {
+            S::Inactive => Self::Inactive,
+            S::Recording => Self::Recording,
+            S::Paused => Self::Paused,
+        }
+    }
+}
+
 /// Media source type.
 #[wasm_bindgen]
 #[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
@@ -222,6 +316,37 @@ impl From<FacingMode> for media::FacingMode {
     }
 }
 
+/// Mode of an automatic camera adjustment (focus, white balance, etc.).
+/// Representation of a [MeteringMode][1].
+///
+/// [1]: https://w3.org/TR/image-capture#meteringmode-section
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum MeteringMode {
+    /// No adjustment is available, or it's disabled.
+    None,
+
+    /// Adjustment is locked to a value set manually.
+    Manual,
+
+    /// Adjustment is performed once, and then locked.
+    SingleShot,
+
+    /// Adjustment is performed continuously.
+    Continuous,
+}
+
+impl From<MeteringMode> for media::MeteringMode {
+    fn from(val: MeteringMode) -> Self {
+        match val {
+            MeteringMode::None => Self::None,
+            MeteringMode::Manual => Self::Manual,
+            MeteringMode::SingleShot => Self::SingleShot,
+            MeteringMode::Continuous => Self::Continuous,
+        }
+    }
+}
+
 /// Media exchange direction of a `Track`.
 #[wasm_bindgen]
 #[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
@@ -262,3 +387,30 @@ impl From<media::MediaDirection> for MediaDirection {
         }
     }
 }
+
+/// State of the network connectivity of a device.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum NetworkState {
+    /// Device is connected to a network.
+    Online,
+
+    /// Device is disconnected from any network.
+    Offline,
+
+    /// Web page is frozen by a browser, so its timers and network activity
+    /// are suspended.
+    Frozen,
+}
+
+impl From<platform::NetworkState> for NetworkState {
+    fn from(val: platform::NetworkState) -> Self {
+        use platform::NetworkState as S;
+
+        match val {
+            S::Online => Self::Online,
+            S::Offline => Self::Offline,
+            S::Frozen => Self::Frozen,
+        }
+    }
+}
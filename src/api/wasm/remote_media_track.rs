@@ -15,7 +15,7 @@ use crate::{
 /// [1]: https://w3.org/TR/mediacapture-streams/#dom-mediastreamtrack
 #[wasm_bindgen]
 #[derive(Clone, Debug, From, Into)]
-pub struct RemoteMediaTrack(remote::Track);
+pub struct RemoteMediaTrack(pub(crate) remote::Track);
 
 #[wasm_bindgen]
 impl RemoteMediaTrack {
@@ -9,7 +9,10 @@ use wasm_bindgen_futures::future_to_promise;
 
 use super::Error;
 use crate::{
-    api::{MediaSourceKind, MediaStreamSettings},
+    api::{
+        MediaSourceKind, MediaStreamSettings, RoomCloseReason, RoomMember,
+        SignallingLatency,
+    },
     room,
 };
 
@@ -59,6 +62,35 @@ impl RoomHandle {
         })
     }
 
+    /// Connects to a media server and joins a [`Room`] with the provided
+    /// `credential`, so it never appears in the connection URL.
+    ///
+    /// The `url` has the following format:
+    /// `{{ Host URL }}/{{ Room ID }}/{{ Member ID }}`
+    /// (e.g. `wss://medea.com/MyConf1/Alice`).
+    ///
+    /// Establishes connection with media server (if it doesn't exist already).
+    ///
+    /// # Errors
+    ///
+    /// Same as for [`RoomHandle::join`].
+    ///
+    /// [`Room`]: room::Room
+    pub fn join_with_credential(
+        &self,
+        url: String,
+        credential: String,
+    ) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            this.join_with_credential(url, credential, Vec::new())
+                .await
+                .map_err(Error::from)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
     /// Sets callback, invoked when a new [`Connection`] with some remote
     /// `Member` is established.
     ///
@@ -150,6 +182,204 @@ impl RoomHandle {
             .map_err(Into::into)
     }
 
+    /// Returns all the `Member`s currently present in this [`Room`]
+    /// (including the local one) as an array of [`RoomMember`]s.
+    ///
+    /// Doesn't depend on any media being exchanged with these `Member`s.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Room`]: room::Room
+    /// [`RoomMember`]: crate::api::RoomMember
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn members(&self) -> Result<js_sys::Array, JsValue> {
+        self.0
+            .members()
+            .map(|members| {
+                members
+                    .into_iter()
+                    .map(|m| JsValue::from(RoomMember::from(m)))
+                    .collect()
+            })
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets `on_member_joined` callback, invoked when a new `Member` joins
+    /// this [`Room`], providing a [`RoomMember`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Room`]: room::Room
+    /// [`RoomMember`]: crate::api::RoomMember
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn on_member_joined(
+        &self,
+        cb: js_sys::Function,
+    ) -> Result<(), JsValue> {
+        self.0
+            .on_member_joined(cb.into())
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets `on_member_left` callback, invoked with an ID of a `Member` who
+    /// left this [`Room`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Room`]: room::Room
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn on_member_left(&self, cb: js_sys::Function) -> Result<(), JsValue> {
+        self.0
+            .on_member_left(cb.into())
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets `on_member_updated` callback, invoked when metadata of some
+    /// `Member` of this [`Room`] changes, providing an actual [`RoomMember`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Room`]: room::Room
+    /// [`RoomMember`]: crate::api::RoomMember
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn on_member_updated(
+        &self,
+        cb: js_sys::Function,
+    ) -> Result<(), JsValue> {
+        self.0
+            .on_member_updated(cb.into())
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets `on_unknown_event` callback, invoked with a name of an event
+    /// received from a media server, which this client doesn't recognize.
+    ///
+    /// Is a diagnostic hook only, since such events are ignored anyway.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn on_unknown_event(
+        &self,
+        cb: js_sys::Function,
+    ) -> Result<(), JsValue> {
+        self.0
+            .on_unknown_event(cb.into())
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Sets `on_signalling_latency` callback, invoked with actual
+    /// [`SignallingLatency`] statistics each time a round-trip time of the
+    /// signalling connection with a media server is measured.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`SignallingLatency`]: crate::api::SignallingLatency
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn on_signalling_latency(
+        &self,
+        cb: js_sys::Function,
+    ) -> Result<(), JsValue> {
+        self.0
+            .on_signalling_latency(cb.into())
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Returns the current [`SignallingLatency`] statistics of the signalling
+    /// connection with a media server, or `undefined` if no round-trip time
+    /// has been measured yet.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`SignallingLatency`]: crate::api::SignallingLatency
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn signalling_latency(
+        &self,
+    ) -> Result<Option<SignallingLatency>, JsValue> {
+        self.0
+            .signalling_latency()
+            .map(|l| l.map(SignallingLatency::from))
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Returns name of the transport used by the signalling connection with a
+    /// media server (`WebSocket` or `SSE`), or `undefined` if the connection
+    /// hasn't been established yet.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn rpc_transport_kind(&self) -> Result<Option<String>, JsValue> {
+        self.0
+            .rpc_transport_kind()
+            .map(|k| k.map(|k| k.to_string()))
+            .map_err(Error::from)
+            .map_err(Into::into)
+    }
+
+    /// Leaves this [`Room`] gracefully, reporting the provided `reason` of
+    /// leaving to a media server.
+    ///
+    /// Waits for a media server to acknowledge the leaving (for 3 seconds at
+    /// most), stops all the local media tracks sent in this [`Room`] and
+    /// resolves with its final [`RoomCloseReason`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Room`]: room::Room
+    /// [`StateError`]: crate::api::err::StateError
+    pub fn leave(&self, reason: Option<String>) -> Promise {
+        let this = self.0.clone();
+
+        future_to_promise(async move {
+            let reason = this.leave(reason).await.map_err(Error::from)?;
+            Ok(RoomCloseReason::from(reason).into())
+        })
+    }
+
+    /// Leaves this [`Room`] without waiting for anything, sending a leaving
+    /// request to a media server synchronously.
+    ///
+    /// Best-effort variant of the [`RoomHandle::leave`], safe to be called
+    /// from a [`beforeunload`][1] or [`pagehide`][2] event handler.
+    ///
+    /// # Errors
+    ///
+    /// With a [`StateError`] if the underlying pointer has been freed.
+    ///
+    /// [`Room`]: room::Room
+    /// [`StateError`]: crate::api::err::StateError
+    /// [1]: https://developer.mozilla.org/docs/Web/API/Window/beforeunload_event
+    /// [2]: https://developer.mozilla.org/docs/Web/API/Window/pagehide_event
+    pub fn leave_immediately(&self) -> Result<(), JsValue> {
+        self.0.leave_immediately().map_err(Error::from).map_err(Into::into)
+    }
+
     /// Updates this [`Room`]s [`MediaStreamSettings`]. This affects all
     /// [`PeerConnection`]s in this [`Room`]. If [`MediaStreamSettings`] is
     /// configured for some [`Room`], then this [`Room`] can only send media
@@ -0,0 +1,40 @@
+//! `Member` present in a [`Room`].
+//!
+//! [`Room`]: crate::room::Room
+
+use derive_more::with_trait::From;
+use medea_client_api_proto as proto;
+use wasm_bindgen::prelude::*;
+
+/// `Member` present in a [`Room`].
+///
+/// This struct is passed to [`RoomHandle::on_member_joined`] and
+/// [`RoomHandle::on_member_updated`] JS side callbacks.
+///
+/// [`Room`]: crate::room::Room
+/// [`RoomHandle::on_member_joined`]: crate::api::RoomHandle::on_member_joined
+/// [`RoomHandle::on_member_updated`]: crate::api::RoomHandle::on_member_updated
+#[wasm_bindgen]
+#[derive(Clone, Debug, From)]
+pub struct RoomMember(proto::Member);
+
+#[wasm_bindgen]
+impl RoomMember {
+    /// Returns ID of this `Member`.
+    #[must_use]
+    pub fn id(&self) -> String {
+        self.0.id.0.clone()
+    }
+
+    /// Returns opaque metadata of this `Member` (display name, avatar URL,
+    /// etc.) as a JS [Map] of strings.
+    ///
+    /// [Map]: https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Map
+    #[must_use]
+    pub fn metadata(&self) -> js_sys::Map {
+        self.0.metadata.iter().fold(js_sys::Map::new(), |map, (k, v)| {
+            _ = map.set(&k.into(), &v.into());
+            map
+        })
+    }
+}
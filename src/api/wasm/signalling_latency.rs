@@ -0,0 +1,44 @@
+//! Round-trip latency statistics of a signalling connection.
+
+use derive_more::with_trait::From;
+use wasm_bindgen::prelude::*;
+
+use crate::rpc;
+
+/// Round-trip latency statistics of a signalling connection with a media
+/// server.
+///
+/// This struct is passed to [`RoomHandle::on_signalling_latency`] JS side
+/// callback.
+///
+/// [`RoomHandle::on_signalling_latency`]: crate::api::RoomHandle::on_signalling_latency
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, From)]
+pub struct SignallingLatency(rpc::SignallingLatency);
+
+#[wasm_bindgen]
+impl SignallingLatency {
+    /// Returns round-trip time of the last measurement in milliseconds.
+    #[must_use]
+    pub fn rtt_ms(&self) -> f64 {
+        self.0.rtt.as_secs_f64() * 1000.0
+    }
+
+    /// Returns smoothed round-trip time in milliseconds.
+    #[must_use]
+    pub fn smoothed_rtt_ms(&self) -> f64 {
+        self.0.smoothed_rtt.as_secs_f64() * 1000.0
+    }
+
+    /// Returns round-trip time variation (jitter) in milliseconds.
+    #[must_use]
+    pub fn jitter_ms(&self) -> f64 {
+        self.0.jitter.as_secs_f64() * 1000.0
+    }
+
+    /// Returns number of measurements these statistics are calculated from.
+    #[must_use]
+    pub const fn samples(&self) -> u32 {
+        self.0.samples
+    }
+}
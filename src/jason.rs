@@ -5,7 +5,10 @@ use std::{cell::RefCell, rc::Rc, thread};
 use futures::{FutureExt as _, future::LocalBoxFuture};
 use tracerr::Traced;
 
+#[cfg(feature = "rpc-recording")]
+use crate::rpc::RpcRecorder;
 use crate::{
+    api,
     media::{MediaManager, MediaManagerHandleImpl},
     platform,
     room::{Room, RoomHandleImpl},
@@ -42,6 +45,26 @@ struct Inner {
     /// New [`WebSocketRpcClient`] will be created for each [`Room`] if it's
     /// [`None`].
     rpc: Option<Rc<WebSocketRpcClient>>,
+
+    /// [`RpcRecorder`] recording RPC sessions of all the [`Room`]s created
+    /// after [`JasonImpl::enable_rpc_recording()`] is called.
+    #[cfg(feature = "rpc-recording")]
+    rpc_recorder: Option<Rc<RpcRecorder>>,
+
+    /// Indicator whether connections with a media server created after
+    /// [`JasonImpl::enable_sse_fallback()`] is called fall back to
+    /// [`platform::RpcTransportKind::Sse`] transport.
+    sse_fallback: bool,
+
+    /// [`platform::NetworkMonitor`] reporting [`platform::NetworkState`]
+    /// changes, if enabled via [`JasonImpl::enable_network_monitor()`].
+    network_monitor: Option<platform::NetworkMonitor>,
+
+    /// Last known [`platform::NetworkState`].
+    network_state: platform::NetworkState,
+
+    /// Callback invoked on each [`platform::NetworkState`] change.
+    on_network_state_change: Rc<platform::Callback<api::NetworkState>>,
 }
 
 impl JasonImpl {
@@ -66,20 +89,98 @@ impl JasonImpl {
             rooms: Vec::new(),
             media_manager: Rc::new(MediaManager::default()),
             rpc,
+            #[cfg(feature = "rpc-recording")]
+            rpc_recorder: None,
+            sse_fallback: false,
+            network_monitor: None,
+            network_state: platform::NetworkState::Online,
+            on_network_state_change: Rc::default(),
         })))
     }
 
     /// Creates a new [`Room`] and returns its [`RoomHandleImpl`].
     #[must_use]
     pub fn init_room(&self) -> RoomHandleImpl {
-        let rpc = self.0.borrow().rpc.clone().unwrap_or_else(|| {
-            Rc::new(WebSocketRpcClient::new(Box::new(|| {
-                Rc::new(platform::WebSocketRpcTransport::new())
-            })))
-        });
+        let rpc = self
+            .0
+            .borrow()
+            .rpc
+            .clone()
+            .unwrap_or_else(|| self.new_rpc_client());
         self.inner_init_room(WebSocketRpcSession::new(rpc))
     }
 
+    /// Makes all the [`Room`]s created after this call share a single
+    /// connection with a media server, instead of creating a separate one for
+    /// each [`Room`].
+    ///
+    /// Each [`Room`] is still joined, left and reconnected independently,
+    /// while the shared connection is closed once all of them are left (and
+    /// established again by the next joining [`Room`]).
+    ///
+    /// Has no effect if the connection is already shared.
+    pub fn share_rpc_connection(&self) {
+        if self.0.borrow().rpc.is_none() {
+            let rpc = self.new_rpc_client();
+            self.0.borrow_mut().rpc = Some(rpc);
+        }
+    }
+
+    /// Makes all the connections with a media server created after this call
+    /// fall back to [`platform::RpcTransportKind::Sse`] transport if a
+    /// [WebSocket] connection cannot be established (e.g. because of a
+    /// restrictive proxy).
+    ///
+    /// Should be called before [`JasonImpl::share_rpc_connection()`] for
+    /// affecting the shared connection.
+    ///
+    /// [WebSocket]: https://en.wikipedia.org/wiki/WebSocket
+    pub fn enable_sse_fallback(&self) {
+        self.0.borrow_mut().sse_fallback = true;
+    }
+
+    /// Enables recording of RPC sessions of all the [`Room`]s created after
+    /// this call, so they can be exported via
+    /// [`JasonImpl::export_rpc_recording()`] for bugs reproduction.
+    ///
+    /// Has no effect if recording is already enabled, or if this library is
+    /// built without `rpc-recording` Cargo feature.
+    pub fn enable_rpc_recording(&self) {
+        #[cfg(feature = "rpc-recording")]
+        {
+            _ = self
+                .0
+                .borrow_mut()
+                .rpc_recorder
+                .get_or_insert_with(|| Rc::new(RpcRecorder::new()));
+        }
+        #[cfg(not(feature = "rpc-recording"))]
+        log::warn!(
+            "RPC recording is unavailable: `rpc-recording` feature is disabled",
+        );
+    }
+
+    /// Exports the RPC sessions recorded so far as [JSON Lines], or returns
+    /// [`None`] if recording hasn't been enabled via
+    /// [`JasonImpl::enable_rpc_recording()`].
+    ///
+    /// [JSON Lines]: https://jsonlines.org
+    #[cfg_attr(
+        not(feature = "rpc-recording"),
+        expect(clippy::missing_const_for_fn, reason = "feature-dependent")
+    )]
+    #[must_use]
+    pub fn export_rpc_recording(&self) -> Option<String> {
+        #[cfg(feature = "rpc-recording")]
+        {
+            self.0.borrow().rpc_recorder.as_ref().map(|r| r.export())
+        }
+        #[cfg(not(feature = "rpc-recording"))]
+        {
+            None
+        }
+    }
+
     /// Returns a [`MediaManagerHandleImpl`].
     #[must_use]
     pub fn media_manager(&self) -> MediaManagerHandleImpl {
@@ -136,6 +237,83 @@ impl JasonImpl {
         .boxed_local()
     }
 
+    /// Enables monitoring of the [`platform::NetworkState`], so
+    /// [`JasonImpl::network_changed()`] is called automatically once the
+    /// network becomes available again.
+    ///
+    /// Has no effect if monitoring is already enabled.
+    pub fn enable_network_monitor(&self) {
+        if self.0.borrow().network_monitor.is_some() {
+            return;
+        }
+
+        let weak_inner = Rc::downgrade(&self.0);
+        let on_change = Rc::new(move |state| {
+            if let Some(inner) = weak_inner.upgrade() {
+                Self(inner).set_network_state(state);
+            }
+        });
+        match platform::NetworkMonitor::start(on_change) {
+            Ok(monitor) => {
+                self.0.borrow_mut().network_monitor = Some(monitor);
+                self.set_network_state(
+                    platform::NetworkMonitor::current_state(),
+                );
+            }
+            Err(e) => log::error!("Failed to start network monitor: {e}"),
+        }
+    }
+
+    /// Sets `on_network_state_change` callback, invoked whenever the
+    /// [`platform::NetworkState`] changes.
+    pub fn on_network_state_change(
+        &self,
+        f: platform::Function<api::NetworkState>,
+    ) {
+        self.0.borrow().on_network_state_change.set_func(f);
+    }
+
+    /// Notifies this [`JasonImpl`] about the current
+    /// [`platform::NetworkState`].
+    ///
+    /// Pauses idle detection of RPC connections while the application is
+    /// [`Frozen`], and calls [`JasonImpl::network_changed()`] once the network
+    /// goes [`Online`] after being [`Offline`] (resuming a [`Frozen`]
+    /// application is not a network change, so is left to idle detection).
+    ///
+    /// [`Frozen`]: platform::NetworkState::Frozen
+    /// [`Offline`]: platform::NetworkState::Offline
+    /// [`Online`]: platform::NetworkState::Online
+    pub fn set_network_state(&self, state: platform::NetworkState) {
+        use platform::NetworkState as S;
+
+        let (prev, on_change) = {
+            let mut inner = self.0.borrow_mut();
+            let prev = inner.network_state;
+            if prev == state {
+                return;
+            }
+            inner.network_state = state;
+            (prev, Rc::clone(&inner.on_network_state_change))
+        };
+
+        if state == S::Frozen || prev == S::Frozen {
+            for room in &self.0.borrow().rooms {
+                room.set_idle_detection_paused(state == S::Frozen);
+            }
+        }
+        if prev == S::Offline && state == S::Online {
+            let fut = self.network_changed();
+            platform::spawn(async move {
+                if let Err(e) = fut.await {
+                    log::error!("Failed to handle network change: {e}");
+                }
+            });
+        }
+
+        on_change.call1(state);
+    }
+
     /// Drops this [`JasonImpl`] API object, so all the related objects (rooms,
     /// connections, streams, etc.) respectively. All objects related to this
     /// [`JasonImpl`] API object will be detached (you will still hold them, but
@@ -146,6 +324,26 @@ impl JasonImpl {
         });
     }
 
+    /// Creates a new [`WebSocketRpcClient`] falling back to
+    /// [`platform::RpcTransportKind::Sse`] transport and recording its
+    /// messages, if enabled.
+    fn new_rpc_client(&self) -> Rc<WebSocketRpcClient> {
+        let mut client = WebSocketRpcClient::new(Box::new(|| {
+            Rc::new(platform::WebSocketRpcTransport::new())
+        }));
+        if self.0.borrow().sse_fallback {
+            client = client.with_fallback(
+                platform::RpcTransportKind::Sse,
+                Box::new(|| Rc::new(platform::SseRpcTransport::new())),
+            );
+        }
+        #[cfg(feature = "rpc-recording")]
+        if let Some(recorder) = self.0.borrow().rpc_recorder.clone() {
+            client = client.with_recorder(recorder);
+        }
+        Rc::new(client)
+    }
+
     /// Returns a [`RoomHandleImpl`] for an initialized  [`Room`].
     fn inner_init_room(&self, rpc: Rc<dyn RpcSession>) -> RoomHandleImpl {
         let on_normal_close = rpc.on_normal_close();
@@ -166,6 +364,17 @@ impl JasonImpl {
                 Some(())
             })();
         }));
+        room.on_left({
+            let left_room = room.downgrade();
+            let jason = Rc::downgrade(&self.0);
+            Box::new(move || {
+                if let (Some(this_room), Some(inner)) =
+                    (left_room.upgrade(), jason.upgrade())
+                {
+                    inner.borrow_mut().rooms.retain(|r| !r.ptr_eq(&this_room));
+                }
+            })
+        });
 
         let handle = room.new_handle();
         self.0.borrow_mut().rooms.push(room);
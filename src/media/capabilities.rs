@@ -0,0 +1,89 @@
+//! Capabilities of media input devices.
+
+use crate::media::FacingMode;
+
+/// Inclusive range of values supported by a media input device.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ValueRange<T> {
+    /// Minimum supported value.
+    pub min: T,
+
+    /// Maximum supported value.
+    pub max: T,
+}
+
+impl<T: Copy + PartialOrd> ValueRange<T> {
+    /// Creates a new [`ValueRange`] with the provided bounds.
+    #[must_use]
+    pub const fn new(min: T, max: T) -> Self {
+        Self { min, max }
+    }
+
+    /// Indicates whether the provided `value` is within this [`ValueRange`].
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    /// Returns the value of this [`ValueRange`] closest to the provided one.
+    #[must_use]
+    pub fn closest(&self, value: T) -> T {
+        if value < self.min {
+            self.min
+        } else if value > self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+}
+
+/// Capabilities of a media input device, describing the values of
+/// [MediaTrackConstraints][1] it supports.
+///
+/// Properties not applicable to the device kind (or not reported by the
+/// platform) are [`None`] or empty.
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#dom-mediatrackconstraints
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeviceCapabilities {
+    /// Supported [width][1] of the video in pixels.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-width
+    pub width: Option<ValueRange<u32>>,
+
+    /// Supported [height][1] of the video in pixels.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-height
+    pub height: Option<ValueRange<u32>>,
+
+    /// Supported [frame rate][1] of the video in frames per second.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-frameRate
+    pub frame_rate: Option<ValueRange<f64>>,
+
+    /// Supported [facing modes][1] of the camera.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-facingMode
+    pub facing_modes: Vec<FacingMode>,
+
+    /// Supported [channel count][1] of the audio.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#def-constraint-channelCount
+    pub channel_count: Option<ValueRange<u32>>,
+
+    /// Supported values of the [echo cancellation][1] toggle.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#def-constraint-echoCancellation
+    pub echo_cancellation: Vec<bool>,
+
+    /// Supported values of the [auto gain control][1] toggle.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#def-constraint-autoGainControl
+    pub auto_gain_control: Vec<bool>,
+
+    /// Supported values of the [noise suppression][1] toggle.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#def-constraint-noiseSuppression
+    pub noise_suppression: Vec<bool>,
+}
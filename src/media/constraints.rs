@@ -8,7 +8,9 @@ use medea_client_api_proto as proto;
 use medea_reactive::ObservableCell;
 
 use crate::{
-    media::{MediaKind, track::MediaStreamTrackState},
+    media::{
+        DeviceCapabilities, MediaKind, ValueRange, track::MediaStreamTrackState,
+    },
     peer::{
         LocalStreamUpdateCriteria, MediaState, media_exchange_state, mute_state,
     },
@@ -58,6 +60,27 @@ pub enum NoiseSuppressionLevel {
     VeryHigh = 3,
 }
 
+/// Mode of an automatic camera adjustment (focus, white balance, etc.).
+///
+/// Representation of a [MeteringMode][1].
+///
+/// [1]: https://w3.org/TR/image-capture#meteringmode-section
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum MeteringMode {
+    /// No adjustment is available, or it's disabled.
+    None = 0,
+
+    /// Adjustment is locked to a value set manually.
+    Manual = 1,
+
+    /// Adjustment is performed once, and then locked.
+    SingleShot = 2,
+
+    /// Adjustment is performed continuously.
+    Continuous = 3,
+}
+
 /// Local media stream for injecting into new created [`PeerConnection`]s.
 ///
 /// [`PeerConnection`]: crate::peer::PeerConnection
@@ -382,6 +405,28 @@ impl<C> MediaTrackConstraints<C> {
         self.enabled &= other.enabled;
         self.constraints = other.constraints;
     }
+
+    /// Returns these [`MediaTrackConstraints::constraints`] if they differ
+    /// from the `current` ones, while still describing the same media source
+    /// according to the provided `same_source` predicate.
+    fn live_update_of(
+        &self,
+        current: &Self,
+        same_source: impl FnOnce(&C, &C) -> bool,
+    ) -> Option<&C>
+    where
+        C: PartialEq,
+    {
+        if self.enabled != current.enabled || self.muted != current.muted {
+            return None;
+        }
+        match (&self.constraints, &current.constraints) {
+            (Some(new), Some(old)) => {
+                (new != old && same_source(new, old)).then_some(new)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl MediaTrackConstraints<DeviceAudioTrackConstraints> {
@@ -613,6 +658,33 @@ impl MediaStreamSettings {
         kinds
     }
 
+    /// Returns [`VideoSource`]s of these [`MediaStreamSettings`] differing
+    /// from the `current` ones only in constraints which can be changed on a
+    /// live track via [applyConstraints()][1] (width, height and frame rate).
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-applyconstraints
+    #[must_use]
+    pub fn live_video_updates(&self, current: &Self) -> Vec<VideoSource> {
+        let device = self
+            .device_video
+            .live_update_of(
+                &current.device_video,
+                DeviceVideoTrackConstraints::is_same_source,
+            )
+            .cloned()
+            .map(VideoSource::Device);
+        let display = self
+            .display_video
+            .live_update_of(
+                &current.display_video,
+                DisplayVideoTrackConstraints::is_same_source,
+            )
+            .cloned()
+            .map(VideoSource::Display);
+
+        device.into_iter().chain(display).collect()
+    }
+
     /// Returns only [`DeviceAudioTrackConstraints`].
     #[must_use]
     pub const fn get_device_audio(
@@ -1103,6 +1175,7 @@ impl From<proto::VideoSettings> for VideoSource {
                     facing_mode: None,
                     width: None,
                     height: None,
+                    frame_rate: None,
                     required: settings.required,
                 })
             }
@@ -1533,6 +1606,160 @@ impl ConstrainU32 {
             }
         }
     }
+
+    /// Returns this [`ConstrainU32`] relaxed into an [`ConstrainU32::Ideal`]
+    /// one, if it cannot be satisfied by any value within the provided
+    /// [`ValueRange`], or intact otherwise.
+    ///
+    /// A relaxed [`ConstrainU32::Range`] prefers its bound closest to the
+    /// provided [`ValueRange`].
+    fn relaxed_to(self, range: ValueRange<f64>) -> Self {
+        match self {
+            Self::Exact(val) if !range.contains(f64::from(val)) => {
+                Self::Ideal(val)
+            }
+            Self::Range(_, end) if f64::from(end) < range.min => {
+                Self::Ideal(end)
+            }
+            Self::Range(start, _) if f64::from(start) > range.max => {
+                Self::Ideal(start)
+            }
+            Self::Exact(_) | Self::Ideal(_) | Self::Range(..) => self,
+        }
+    }
+}
+
+/// Members of a [ConstrainULongRange][1] dictionary a [`ConstrainU32`] is
+/// lowered into.
+///
+/// Serves as a common ground for all the platforms, so the same
+/// [`ConstrainU32`] results in the same media on all of them.
+///
+/// [1]: https://tinyurl.com/w3-streams#dom-constrainulongrange
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ConstrainU32Range {
+    /// Value the parameter must have.
+    pub exact: Option<u32>,
+
+    /// Value the parameter should have if possible.
+    pub ideal: Option<u32>,
+
+    /// Minimum value the parameter may have.
+    pub min: Option<u32>,
+
+    /// Maximum value the parameter may have.
+    pub max: Option<u32>,
+}
+
+impl ConstrainU32Range {
+    /// Selects a single value to be requested on platforms not supporting
+    /// range constraints.
+    ///
+    /// Prefers the [`ConstrainU32Range::exact`] value, then the
+    /// [`ConstrainU32Range::ideal`] one, falling back to the provided
+    /// `default`. The selected value is clamped into the
+    /// [`ConstrainU32Range::min`]..=[`ConstrainU32Range::max`] bounds.
+    #[must_use]
+    pub fn fallback_value(&self, default: u32) -> u32 {
+        if let Some(exact) = self.exact {
+            return exact;
+        }
+        let value = self.ideal.unwrap_or(default);
+        let value = self.min.map_or(value, |min| value.max(min));
+        self.max.map_or(value, |max| value.min(max))
+    }
+
+    /// Lowers this [`ConstrainU32Range`] into [`NativeConstraint`]s to be
+    /// requested one by one on platforms not supporting range constraints,
+    /// until a device fits to one of them.
+    ///
+    /// The [`ConstrainU32Range::exact`] value is lowered into a single
+    /// mandatory [`NativeConstraint`], and a sole [`ConstrainU32Range::ideal`]
+    /// one into a single optional [`NativeConstraint`]. A range is lowered into
+    /// the [`ConstrainU32Range::fallback_value()`] followed by the
+    /// [`ConstrainU32Range::min`] and [`ConstrainU32Range::max`] bounds, all of
+    /// them being mandatory, so a device supporting only a part of the range
+    /// still can be used.
+    #[must_use]
+    pub fn native_candidates(&self, default: u32) -> Vec<NativeConstraint<u32>> {
+        if let Some(value) = self.exact {
+            return vec![NativeConstraint { value, mandatory: true }];
+        }
+        if self.min.is_none() && self.max.is_none() {
+            let value = self.fallback_value(default);
+            return vec![NativeConstraint { value, mandatory: false }];
+        }
+
+        let mut values = vec![self.fallback_value(default)];
+        for bound in [self.min, self.max].into_iter().flatten() {
+            if !values.contains(&bound) {
+                values.push(bound);
+            }
+        }
+        values
+            .into_iter()
+            .map(|value| NativeConstraint { value, mandatory: true })
+            .collect()
+    }
+}
+
+/// Single value a constraint is lowered into on platforms accepting only a
+/// single value per constraint (`medea_flutter_webrtc`).
+///
+/// Mirrors the web representation of the constraint: the ones having `exact`,
+/// `min` or `max` members there are [`NativeConstraint::mandatory`], while the
+/// ones having only an `ideal` member are optional.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NativeConstraint<T> {
+    /// Value to be requested.
+    pub value: T,
+
+    /// Indicator whether a device not fitting to the
+    /// [`NativeConstraint::value`] cannot be used.
+    pub mandatory: bool,
+}
+
+impl<T> NativeConstraint<T> {
+    /// Maps the [`NativeConstraint::value`] with the provided function,
+    /// preserving its necessity.
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> NativeConstraint<U> {
+        NativeConstraint { value: f(self.value), mandatory: self.mandatory }
+    }
+}
+
+impl<T> From<ConstrainString<T>> for NativeConstraint<T> {
+    fn from(from: ConstrainString<T>) -> Self {
+        match from {
+            ConstrainString::Exact(value) => Self { value, mandatory: true },
+            ConstrainString::Ideal(value) => Self { value, mandatory: false },
+        }
+    }
+}
+
+impl From<ConstrainBoolean> for NativeConstraint<bool> {
+    fn from(from: ConstrainBoolean) -> Self {
+        match from {
+            ConstrainBoolean::Exact(value) => Self { value, mandatory: true },
+            ConstrainBoolean::Ideal(value) => Self { value, mandatory: false },
+        }
+    }
+}
+
+impl From<ConstrainU32> for ConstrainU32Range {
+    fn from(from: ConstrainU32) -> Self {
+        match from {
+            ConstrainU32::Exact(val) => {
+                Self { exact: Some(val), ..Self::default() }
+            }
+            ConstrainU32::Ideal(val) => {
+                Self { ideal: Some(val), ..Self::default() }
+            }
+            ConstrainU32::Range(min, max) => {
+                Self { min: Some(min), max: Some(max), ..Self::default() }
+            }
+        }
+    }
 }
 
 /// Representation of the [ConstrainDOMString][1].
@@ -1600,6 +1827,11 @@ pub struct DeviceVideoTrackConstraints {
 
     /// Width of the video in pixels.
     pub width: Option<ConstrainU32>,
+
+    /// [Frame rate][1] of the video.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub frame_rate: Option<ConstrainU32>,
 }
 
 /// Constraints applicable to video tracks that are sourced from screen-capture.
@@ -1674,6 +1906,20 @@ impl DeviceVideoTrackConstraints {
         self.width = Some(ConstrainU32::Range(min, max));
     }
 
+    /// Sets exact [frameRate][1] constraint.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub const fn exact_frame_rate(&mut self, frame_rate: u32) {
+        self.frame_rate = Some(ConstrainU32::Exact(frame_rate));
+    }
+
+    /// Sets ideal [frameRate][1] constraint.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dfn-framerate
+    pub const fn ideal_frame_rate(&mut self, frame_rate: u32) {
+        self.frame_rate = Some(ConstrainU32::Ideal(frame_rate));
+    }
+
     /// Checks whether the provided [`platform::MediaStreamTrack`] satisfies
     /// contained [`DeviceVideoTrackConstraints`].
     pub async fn satisfies<T: AsRef<platform::MediaStreamTrack>>(
@@ -1714,6 +1960,54 @@ impl DeviceVideoTrackConstraints {
         if self.width.is_none() && another.width.is_some() {
             self.width = another.width;
         }
+        if self.frame_rate.is_none() && another.frame_rate.is_some() {
+            self.frame_rate = another.frame_rate;
+        }
+    }
+
+    /// Indicates whether these [`DeviceVideoTrackConstraints`] and `other`
+    /// ones describe the same media source, differing only in constraints
+    /// which can be changed on a live track.
+    #[must_use]
+    pub fn is_same_source(&self, other: &Self) -> bool {
+        self.required == other.required
+            && self.device_id == other.device_id
+            && self.facing_mode == other.facing_mode
+    }
+
+    /// Adjusts these [`DeviceVideoTrackConstraints`] to the closest mode
+    /// supported by a device with the provided [`DeviceCapabilities`].
+    ///
+    /// Relaxes the exact and range width, height and frame rate constraints,
+    /// which cannot be satisfied by the device, into ideal ones, so the closest
+    /// supported mode is picked by the platform instead of failing, while the
+    /// satisfiable constraints are kept intact. Drops the facing mode
+    /// constraint if the device doesn't support it.
+    pub fn fit_to(&mut self, caps: &DeviceCapabilities) {
+        /// Converts the provided integer [`ValueRange`] into a float one.
+        fn to_f64(range: ValueRange<u32>) -> ValueRange<f64> {
+            ValueRange::new(f64::from(range.min), f64::from(range.max))
+        }
+
+        if let Some(range) = caps.width {
+            self.width = self.width.map(|w| w.relaxed_to(to_f64(range)));
+        }
+        if let Some(range) = caps.height {
+            self.height = self.height.map(|h| h.relaxed_to(to_f64(range)));
+        }
+        if let Some(range) = caps.frame_rate {
+            self.frame_rate = self.frame_rate.map(|f| f.relaxed_to(range));
+        }
+        if let Some(
+            ConstrainString::Exact(mode) | ConstrainString::Ideal(mode),
+        ) = &self.facing_mode
+        {
+            if !caps.facing_modes.is_empty()
+                && !caps.facing_modes.contains(mode)
+            {
+                self.facing_mode = None;
+            }
+        }
     }
 
     /// Returns an importance of these [`DeviceVideoTrackConstraints`].
@@ -1804,6 +2098,14 @@ impl DisplayVideoTrackConstraints {
         }
     }
 
+    /// Indicates whether these [`DisplayVideoTrackConstraints`] and `other`
+    /// ones describe the same media source, differing only in constraints
+    /// which can be changed on a live track.
+    #[must_use]
+    pub fn is_same_source(&self, other: &Self) -> bool {
+        self.required == other.required && self.device_id == other.device_id
+    }
+
     /// Sets an exact [height][1] constraint.
     ///
     /// [1]: https://tinyurl.com/w3-streams#def-constraint-height
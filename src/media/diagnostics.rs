@@ -0,0 +1,531 @@
+//! Pre-call diagnostics of media devices, network and connectivity.
+
+use std::{cell::Cell, collections::HashMap, rc::Rc, time::Duration};
+
+use derive_more::with_trait::{Display, From};
+use futures::{FutureExt as _, StreamExt as _, channel::mpsc};
+use medea_client_api_proto::{
+    IceServer, PeerConnectionState,
+    stats::{MediaSourceKind as StatsMediaSourceKind, RtcStatsType},
+};
+use tracerr::Traced;
+
+use super::track::local;
+use crate::{
+    media::MediaKind,
+    platform::{self, TransceiverDirection},
+    utils::Caused,
+};
+
+/// Duration of listening to audio levels of a checked microphone.
+const AUDIO_LEVEL_SAMPLING_PERIOD: Duration = Duration::from_secs(1);
+
+/// Maximum duration of gathering ICE candidates and establishing a loopback
+/// connection.
+const CONNECTIVITY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Duration of sending media over an established loopback connection to
+/// estimate the throughput.
+const THROUGHPUT_MEASUREMENT_PERIOD: Duration = Duration::from_secs(3);
+
+/// Errors returned from the
+/// [`MediaManagerHandleImpl::run_diagnostics()`][0] method.
+///
+/// [0]: super::MediaManagerHandleImpl::run_diagnostics
+#[derive(Caused, Clone, Debug, Display, From)]
+#[cause(error = platform::Error)]
+pub enum DiagnosticsError {
+    /// Occurs if the `enumerateDevices` request fails.
+    #[display("MediaDevices.enumerateDevices() failed: {_0}")]
+    EnumerateDevicesFailed(platform::Error),
+
+    /// [`MediaManagerHandleImpl`][0]'s inner [`Weak`] pointer cannot be
+    /// upgraded.
+    ///
+    /// [0]: super::MediaManagerHandleImpl
+    /// [`Weak`]: std::rc::Weak
+    #[display("MediaManagerHandle is in detached state")]
+    Detached,
+}
+
+/// Structured report of pre-call diagnostics.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiagnosticsReport {
+    /// Results of checking every available media input device.
+    pub devices: Vec<DeviceDiagnostics>,
+
+    /// Results of the local ICE gathering and connectivity check.
+    pub ice: IceDiagnostics,
+
+    /// Throughput estimated over a loopback connection.
+    ///
+    /// [`None`] if the loopback connection couldn't be established.
+    pub throughput: Option<ThroughputDiagnostics>,
+}
+
+/// Result of checking a single media input device.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeviceDiagnostics {
+    /// ID of the checked device.
+    pub device_id: String,
+
+    /// Label of the checked device.
+    pub label: String,
+
+    /// Kind of the media produced by the checked device.
+    pub kind: MediaKind,
+
+    /// Description of the error occurred while acquiring media from the
+    /// device, if any.
+    pub error: Option<String>,
+
+    /// Width (in pixels) of the video captured from the device.
+    pub width: Option<u32>,
+
+    /// Height (in pixels) of the video captured from the device.
+    pub height: Option<u32>,
+
+    /// Frame rate of the video captured from the device, as observed while
+    /// sending it over a loopback connection.
+    pub frame_rate: Option<f64>,
+
+    /// Peak audio level (in `0..=100` range) captured from the device.
+    pub audio_level: Option<i32>,
+}
+
+impl DeviceDiagnostics {
+    /// Creates a new [`DeviceDiagnostics`] of the device described by the
+    /// provided [`platform::MediaDeviceInfo`], which media couldn't be
+    /// acquired because of the provided `error`.
+    pub fn failed<E: ToString>(
+        info: &platform::MediaDeviceInfo,
+        kind: MediaKind,
+        error: &E,
+    ) -> Self {
+        let mut this = Self::new(info, kind);
+        this.error = Some(error.to_string());
+        this
+    }
+
+    /// Checks the media of the provided [`local::Track`] acquired from the
+    /// device described by the provided [`platform::MediaDeviceInfo`].
+    pub async fn check(
+        info: &platform::MediaDeviceInfo,
+        track: &local::Track,
+    ) -> Self {
+        let mut this = Self::new(info, track.kind());
+        let platform_track = track.platform_track();
+        match track.kind() {
+            MediaKind::Audio => {
+                if platform_track.is_on_audio_level_available() {
+                    this.audio_level = sample_audio_level(platform_track).await;
+                }
+            }
+            MediaKind::Video => {
+                this.width = platform_track.width();
+                this.height = platform_track.height();
+            }
+        }
+        this
+    }
+
+    /// Creates a new empty [`DeviceDiagnostics`] of the device described by
+    /// the provided [`platform::MediaDeviceInfo`].
+    fn new(info: &platform::MediaDeviceInfo, kind: MediaKind) -> Self {
+        Self {
+            device_id: info.device_id(),
+            label: info.label(),
+            kind,
+            error: None,
+            width: None,
+            height: None,
+            frame_rate: None,
+            audio_level: None,
+        }
+    }
+}
+
+/// Results of the local [ICE] gathering and connectivity check.
+///
+/// [ICE]: https://webrtcglossary.com/ice
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IceDiagnostics {
+    /// Number of gathered [host candidates][1].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcicecandidatetype-host
+    pub host_candidates: u32,
+
+    /// Number of gathered [server reflexive candidates][1].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcicecandidatetype-srflx
+    pub srflx_candidates: u32,
+
+    /// Number of gathered [relay candidates][1].
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcicecandidatetype-relay
+    pub relay_candidates: u32,
+
+    /// Indicator whether the loopback connection has been established.
+    pub connected: bool,
+
+    /// Descriptions of the errors occurred while gathering candidates or
+    /// establishing the loopback connection.
+    pub errors: Vec<String>,
+}
+
+impl IceDiagnostics {
+    /// Counts the provided [`platform::IceCandidate`] by its type.
+    fn record_candidate(&mut self, candidate: &platform::IceCandidate) {
+        let mut tokens = candidate.candidate.split_whitespace();
+        if tokens.any(|t| t == "typ") {
+            match tokens.next() {
+                Some("host") => self.host_candidates += 1,
+                Some("srflx") => self.srflx_candidates += 1,
+                Some("relay") => self.relay_candidates += 1,
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Throughput estimated over a loopback connection.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ThroughputDiagnostics {
+    /// Number of bytes sent during the measurement.
+    pub bytes_sent: u64,
+
+    /// Duration of the measurement in milliseconds.
+    pub duration_ms: u64,
+
+    /// Estimated bitrate in bits per second.
+    pub bitrate_bps: u64,
+}
+
+impl ThroughputDiagnostics {
+    /// Creates a new [`ThroughputDiagnostics`] out of the provided number of
+    /// `bytes_sent` during the provided `duration`.
+    fn new(bytes_sent: u64, duration: Duration) -> Self {
+        let duration_ms =
+            u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+        let bitrate_bps = bytes_sent
+            .saturating_mul(8 * 1000)
+            .checked_div(duration_ms)
+            .unwrap_or_default();
+        Self { bytes_sent, duration_ms, bitrate_bps }
+    }
+}
+
+/// Runs the connectivity and throughput checks over a loopback connection
+/// sending the provided [`local::Track`]s, and completes the provided
+/// [`DeviceDiagnostics`] of these [`local::Track`]s with the observed frame
+/// rates.
+pub async fn run(
+    ice_servers: Vec<IceServer>,
+    devices: Vec<(DeviceDiagnostics, Option<Rc<local::Track>>)>,
+) -> DiagnosticsReport {
+    let tracks: Vec<_> =
+        devices.iter().filter_map(|(_, t)| t.clone()).collect();
+
+    let mut ice = IceDiagnostics::default();
+    let (throughput, frame_rates) =
+        match check_loopback(ice_servers, &tracks, &mut ice).await {
+            Ok(Some((throughput, frame_rates))) => {
+                (Some(throughput), frame_rates)
+            }
+            Ok(None) => (None, HashMap::new()),
+            Err(e) => {
+                ice.errors.push(e.to_string());
+                (None, HashMap::new())
+            }
+        };
+
+    let devices = devices
+        .into_iter()
+        .map(|(mut device, track)| {
+            if let Some(track) = track {
+                device.frame_rate = frame_rates.get(&track.id()).copied();
+            }
+            device
+        })
+        .collect();
+
+    DiagnosticsReport { devices, ice, throughput }
+}
+
+/// Listens to audio levels of the provided [`platform::MediaStreamTrack`]
+/// during the [`AUDIO_LEVEL_SAMPLING_PERIOD`] and returns the peak one.
+async fn sample_audio_level(track: &platform::MediaStreamTrack) -> Option<i32> {
+    let peak = Rc::new(Cell::new(None));
+    let subscribed = track.on_audio_level_changed({
+        let peak = Rc::clone(&peak);
+        move |level: i32| {
+            peak.set(Some(peak.get().map_or(level, |p: i32| p.max(level))));
+        }
+    });
+    if let Err(e) = subscribed {
+        log::error!("Failed to subscribe to audio level changes: {e}");
+        return None;
+    }
+    platform::clock().delay_for(AUDIO_LEVEL_SAMPLING_PERIOD).await;
+    peak.get()
+}
+
+/// Events happening on a loopback connection.
+enum LoopbackEvent {
+    /// Sending [`platform::RtcPeerConnection`] discovered a new
+    /// [`platform::IceCandidate`].
+    SenderCandidate(platform::IceCandidate),
+
+    /// Receiving [`platform::RtcPeerConnection`] discovered a new
+    /// [`platform::IceCandidate`].
+    ReceiverCandidate(platform::IceCandidate),
+
+    /// Sending [`platform::RtcPeerConnection`] failed to gather an
+    /// [`platform::IceCandidate`].
+    CandidateError(platform::IceCandidateError),
+
+    /// Sending [`platform::RtcPeerConnection`] finished gathering
+    /// [`platform::IceCandidate`]s.
+    GatheringComplete,
+
+    /// [`PeerConnectionState`] of the sending
+    /// [`platform::RtcPeerConnection`] changed.
+    ConnectionStateChanged(PeerConnectionState),
+}
+
+/// Connects two local [`platform::RtcPeerConnection`]s to each other,
+/// recording the gathered candidates into the provided [`IceDiagnostics`].
+///
+/// Once connected, sends the provided [`local::Track`]s over this connection
+/// during the [`THROUGHPUT_MEASUREMENT_PERIOD`], and returns the estimated
+/// [`ThroughputDiagnostics`] along with the observed frame rates of the sent
+/// video [`local::Track`]s keyed by their IDs.
+///
+/// Returns [`None`] if the connection couldn't be established in the
+/// [`CONNECTIVITY_TIMEOUT`].
+///
+/// # Errors
+///
+/// With a [`platform::RtcPeerConnectionError`] if any of the
+/// [`platform::RtcPeerConnection`]s fails.
+async fn check_loopback(
+    ice_servers: Vec<IceServer>,
+    tracks: &[Rc<local::Track>],
+    ice: &mut IceDiagnostics,
+) -> Result<
+    Option<(ThroughputDiagnostics, HashMap<String, f64>)>,
+    Traced<platform::RtcPeerConnectionError>,
+> {
+    let sender =
+        platform::RtcPeerConnection::new(ice_servers.clone(), false).await?;
+    let receiver = platform::RtcPeerConnection::new(ice_servers, false).await?;
+
+    let (tx, events) = mpsc::unbounded();
+    sender.on_ice_candidate(Some({
+        let tx = tx.clone();
+        move |c| {
+            drop(tx.unbounded_send(LoopbackEvent::SenderCandidate(c)));
+        }
+    }));
+    receiver.on_ice_candidate(Some({
+        let tx = tx.clone();
+        move |c| {
+            drop(tx.unbounded_send(LoopbackEvent::ReceiverCandidate(c)));
+        }
+    }));
+    sender.on_ice_candidate_error(Some({
+        let tx = tx.clone();
+        move |e| {
+            drop(tx.unbounded_send(LoopbackEvent::CandidateError(e)));
+        }
+    }));
+    sender.on_ice_gathering_state_change(Some({
+        let tx = tx.clone();
+        move |state| {
+            if matches!(state, platform::IceGatheringState::Complete) {
+                drop(tx.unbounded_send(LoopbackEvent::GatheringComplete));
+            }
+        }
+    }));
+    sender.on_connection_state_change(Some(move |state| {
+        drop(tx.unbounded_send(LoopbackEvent::ConnectionStateChanged(state)));
+    }));
+
+    if tracks.is_empty() {
+        // At least one transceiver is required for ICE gathering to start.
+        drop(
+            sender
+                .add_transceiver(
+                    MediaKind::Video,
+                    platform::TransceiverInit::new(TransceiverDirection::SEND),
+                )
+                .await,
+        );
+    }
+    for track in tracks {
+        let transceiver = sender
+            .add_transceiver(
+                track.kind(),
+                platform::TransceiverInit::new(TransceiverDirection::SEND),
+            )
+            .await;
+        if let Err(e) = transceiver.set_send_track(Some(track)).await {
+            ice.errors
+                .push(format!("Failed to send {} track: {e}", track.kind()));
+        }
+    }
+
+    let offer = sender.create_offer().await?;
+    sender.set_offer(&offer).await?;
+    receiver.set_remote_description(platform::SdpType::Offer(offer)).await?;
+    let answer = receiver.create_answer().await?;
+    receiver.set_answer(&answer).await?;
+    sender.set_remote_description(platform::SdpType::Answer(answer)).await?;
+
+    await_connection(&sender, &receiver, events, ice).await;
+    if !ice.connected {
+        return Ok(None);
+    }
+
+    measure_throughput(&sender).await.map(Some)
+}
+
+/// Exchanges [`platform::IceCandidate`]s between the provided sending and
+/// receiving [`platform::RtcPeerConnection`]s until the sending one finishes
+/// gathering them and gets connected, or the [`CONNECTIVITY_TIMEOUT`] passes.
+async fn await_connection(
+    sender: &platform::RtcPeerConnection,
+    receiver: &platform::RtcPeerConnection,
+    mut events: mpsc::UnboundedReceiver<LoopbackEvent>,
+    ice: &mut IceDiagnostics,
+) {
+    let mut timeout = platform::clock().delay_for(CONNECTIVITY_TIMEOUT).fuse();
+    let mut gathered = false;
+    while !(gathered && ice.connected) {
+        let event = futures::select! {
+            event = events.select_next_some() => event,
+            () = timeout => break,
+        };
+        match event {
+            LoopbackEvent::SenderCandidate(c) => {
+                ice.record_candidate(&c);
+                if let Err(e) = add_ice_candidate(receiver, &c).await {
+                    ice.errors.push(e.to_string());
+                }
+            }
+            LoopbackEvent::ReceiverCandidate(c) => {
+                if let Err(e) = add_ice_candidate(sender, &c).await {
+                    ice.errors.push(e.to_string());
+                }
+            }
+            LoopbackEvent::CandidateError(e) => {
+                ice.errors.push(format!(
+                    "ICE candidate error from `{}`: {} {}",
+                    e.url, e.error_code, e.error_text,
+                ));
+            }
+            LoopbackEvent::GatheringComplete => gathered = true,
+            LoopbackEvent::ConnectionStateChanged(state) => match state {
+                PeerConnectionState::Connected => ice.connected = true,
+                PeerConnectionState::Failed => {
+                    ice.errors.push("Loopback connection failed".into());
+                    break;
+                }
+                PeerConnectionState::New
+                | PeerConnectionState::Connecting
+                | PeerConnectionState::Disconnected
+                | PeerConnectionState::Closed => (),
+            },
+        }
+    }
+}
+
+/// Adds the provided [`platform::IceCandidate`] to the provided
+/// [`platform::RtcPeerConnection`].
+async fn add_ice_candidate(
+    peer: &platform::RtcPeerConnection,
+    candidate: &platform::IceCandidate,
+) -> Result<(), Traced<platform::RtcPeerConnectionError>> {
+    peer.add_ice_candidate(
+        &candidate.candidate,
+        candidate.sdp_m_line_index,
+        &candidate.sdp_mid,
+    )
+    .await
+}
+
+/// Measures the number of bytes sent by the provided connected
+/// [`platform::RtcPeerConnection`] during the
+/// [`THROUGHPUT_MEASUREMENT_PERIOD`], and returns the estimated
+/// [`ThroughputDiagnostics`] along with the frame rates of the sent video
+/// tracks keyed by their IDs.
+///
+/// # Errors
+///
+/// With a [`platform::RtcPeerConnectionError`] if [`platform::RtcStats`]
+/// cannot be retrieved.
+async fn measure_throughput(
+    sender: &platform::RtcPeerConnection,
+) -> Result<
+    (ThroughputDiagnostics, HashMap<String, f64>),
+    Traced<platform::RtcPeerConnectionError>,
+> {
+    let clock = platform::clock();
+    let started_at = clock.now();
+    let bytes_before = bytes_sent(&sender.get_stats().await?);
+    clock.delay_for(THROUGHPUT_MEASUREMENT_PERIOD).await;
+    let stats = sender.get_stats().await?;
+    let throughput = ThroughputDiagnostics::new(
+        bytes_sent(&stats).saturating_sub(bytes_before),
+        started_at.elapsed(),
+    );
+
+    let frame_rates = stats
+        .0
+        .iter()
+        .filter_map(|stat| {
+            let RtcStatsType::MediaSource(source) = &stat.stats else {
+                return None;
+            };
+            let StatsMediaSourceKind::Video {
+                frames_per_second: Some(fps),
+                ..
+            } = source.kind
+            else {
+                return None;
+            };
+            Some((source.track_identifier.clone()?, fps.0))
+        })
+        .collect();
+
+    Ok((throughput, frame_rates))
+}
+
+/// Returns the total number of bytes sent according to the provided
+/// [`platform::RtcStats`].
+///
+/// Uses [transport stats][1] if there are any, falling back to the nominated
+/// [candidate pair stats][2] otherwise.
+///
+/// [1]: https://w3.org/TR/webrtc-stats#transportstats-dict%2A
+/// [2]: https://w3.org/TR/webrtc-stats#candidatepair-dict%2A
+fn bytes_sent(stats: &platform::RtcStats) -> u64 {
+    let mut transports = None;
+    let mut candidate_pairs = 0;
+    for stat in &stats.0 {
+        #[expect(clippy::wildcard_enum_match_arm, reason = "too many variants")]
+        match &stat.stats {
+            RtcStatsType::Transport(transport) => {
+                *transports.get_or_insert(0) +=
+                    transport.bytes_sent.unwrap_or_default();
+            }
+            RtcStatsType::CandidatePair(pair)
+                if pair.nominated == Some(true) =>
+            {
+                candidate_pairs += pair.bytes_sent.unwrap_or_default();
+            }
+            _ => (),
+        }
+    }
+    transports.unwrap_or(candidate_pairs)
+}
@@ -7,18 +7,27 @@ use std::{
 };
 
 use derive_more::with_trait::{Display, From};
-use medea_client_api_proto::MediaSourceKind;
+use medea_client_api_proto::{IceServer, MediaSourceKind};
 use tracerr::Traced;
 
-use super::track::local;
+use super::{
+    diagnostics::{self, DeviceDiagnostics},
+    track::local,
+};
 use crate::{
+    api,
     media::{
-        MediaKind, MediaStreamSettings, MultiSourceTracksConstraints,
+        DeviceAudioTrackConstraints, DeviceCapabilities,
+        DeviceVideoTrackConstraints, DiagnosticsError, DiagnosticsReport,
+        MediaDeviceKind, MediaKind, MediaStreamSettings,
+        MultiSourceTracksConstraints, PermissionKind, PermissionState,
         track::MediaStreamTrackState,
     },
     platform,
     utils::Caused,
 };
+#[cfg(target_family = "wasm")]
+use crate::media;
 
 /// Errors returned from the [`MediaManagerHandleImpl::enumerate_devices()`]
 /// method.
@@ -71,6 +80,25 @@ pub enum InitLocalTracksError {
     GetDisplayMediaFailed(#[cause] GetDisplayMediaError),
 }
 
+/// Errors returned from the [`MediaManagerHandleImpl::device_capabilities()`]
+/// method.
+#[derive(Caused, Clone, Debug, Display, From)]
+#[cause(error = platform::Error)]
+pub enum DeviceCapabilitiesError {
+    /// [`MediaManagerHandleImpl`]'s inner [`Weak`] pointer cannot be upgraded.
+    #[display("MediaManagerHandle is in detached state")]
+    Detached,
+
+    /// Occurs if the `enumerateDevices` request fails.
+    #[display("MediaDevices.enumerateDevices() failed: {_0}")]
+    EnumerateDevicesFailed(platform::Error),
+
+    /// Occurs if there is no media input device with the provided ID.
+    #[display("Media input device `{_0}` is not found")]
+    #[from(ignore)]
+    DeviceNotFound(String),
+}
+
 /// Error returned from the [`MediaManagerHandleImpl::set_output_audio_id`]
 /// method.
 ///
@@ -93,6 +121,31 @@ pub enum MicVolumeError {
     Detached,
 }
 
+/// Error returned from the [`MediaManagerHandleImpl::permission_state()`] or
+/// [`MediaManagerHandleImpl::on_permission_change()`] methods.
+#[derive(Caused, Clone, Debug, Display, From)]
+#[cause(error = platform::Error)]
+pub enum PermissionError {
+    /// Occurs if the media permission cannot be queried.
+    #[display("Failed to query media permission: {_0}")]
+    QueryFailed(platform::Error),
+
+    /// [`MediaManagerHandleImpl`]'s inner [`Weak`] pointer cannot be upgraded.
+    #[display("MediaManagerHandle is in detached state")]
+    Detached,
+}
+
+/// Error returned from the [`MediaManagerHandleImpl::register_processor()`]
+/// method.
+#[cfg(target_family = "wasm")]
+#[derive(Caused, Clone, Copy, Debug, Display)]
+#[cause(error = platform::Error)]
+pub enum FrameProcessorError {
+    /// [`MediaManagerHandleImpl`]'s inner [`Weak`] pointer cannot be upgraded.
+    #[display("MediaManagerHandle is in detached state")]
+    Detached,
+}
+
 /// Error indicating about a [`MediaManagerHandleImpl`] in detached state.
 #[derive(Clone, Copy, Debug, Display)]
 #[display("MediaManagerHandle is in detached state")]
@@ -184,6 +237,13 @@ struct InnerMediaManager {
     /// Obtained tracks storage.
     tracks: RefCell<HashMap<String, Weak<local::Track>>>,
 
+    /// [`platform::FrameProcessor`]s applied to the newly obtained tracks of
+    /// the respective [`MediaKind`] and [`MediaSourceKind`].
+    #[cfg(target_family = "wasm")]
+    processors: RefCell<
+        HashMap<(MediaKind, MediaSourceKind), platform::FrameProcessor>,
+    >,
+
     /// Media devices platform controller.
     media_devices: platform::MediaDevices,
 }
@@ -395,6 +455,18 @@ impl InnerMediaManager {
             }
         }
 
+        #[cfg(target_family = "wasm")]
+        let tracks = {
+            let processors = self.processors.borrow();
+            tracks
+                .into_iter()
+                .map(|tr| match processors.get(&(tr.kind(), kind)) {
+                    Some(processor) => tr.with_processor(processor.clone()),
+                    None => tr,
+                })
+                .collect::<Vec<_>>()
+        };
+
         let mut storage = self.tracks.borrow_mut();
         let tracks = tracks
             .into_iter()
@@ -407,6 +479,47 @@ impl InnerMediaManager {
         Ok(tracks)
     }
 
+    /// Returns [`DeviceCapabilities`] of the media input device with the
+    /// provided `device_id`, without opening it.
+    ///
+    /// Capabilities are read from a live [`local::Track`] of this device, if
+    /// there is any in the storage, or from its [`platform::MediaDeviceInfo`]
+    /// otherwise. Empty [`DeviceCapabilities`] are returned if neither of them
+    /// provides any.
+    ///
+    /// # Errors
+    ///
+    /// See [`DeviceCapabilitiesError`] for details.
+    async fn device_capabilities(
+        &self,
+        device_id: String,
+    ) -> Result<DeviceCapabilities, Traced<DeviceCapabilitiesError>> {
+        let info = self
+            .enumerate_devices()
+            .await
+            .map_err(tracerr::map_from_and_wrap!())?
+            .into_iter()
+            .find(|d| {
+                d.device_id() == device_id
+                    && d.kind() != MediaDeviceKind::AudioOutput
+            })
+            .ok_or_else(|| {
+                tracerr::new!(DeviceCapabilitiesError::DeviceNotFound(
+                    device_id.clone()
+                ))
+            })?;
+
+        let live_track =
+            self.tracks.borrow().values().filter_map(Weak::upgrade).find(|t| {
+                t.platform_track().device_id().as_ref() == Some(&device_id)
+            });
+        if let Some(track) = live_track {
+            return Ok(track.platform_track().capabilities().await);
+        }
+
+        Ok(info.capabilities().unwrap_or_default())
+    }
+
     /// Switches the current audio output device to the device with the provided
     /// `device_id`.
     ///
@@ -456,6 +569,86 @@ impl InnerMediaManager {
             .await
             .map_err(tracerr::map_from_and_wrap!())
     }
+
+    /// Returns the current [`PermissionState`] of the provided
+    /// [`PermissionKind`].
+    async fn permission_state(
+        &self,
+        kind: PermissionKind,
+    ) -> Result<PermissionState, Traced<PermissionError>> {
+        self.media_devices
+            .permission_state(kind)
+            .await
+            .map_err(tracerr::wrap!())
+    }
+
+    /// Subscribes onto the [`PermissionState`] changes of the provided
+    /// [`PermissionKind`].
+    async fn on_permission_change(
+        &self,
+        kind: PermissionKind,
+        cb: platform::Function<api::PermissionState>,
+    ) -> Result<(), Traced<PermissionError>> {
+        let on_change = platform::Callback::default();
+        on_change.set_func(cb);
+        self.media_devices
+            .on_permission_change(kind, move |state| on_change.call1(state))
+            .await
+            .map_err(tracerr::wrap!())
+    }
+
+    /// Runs pre-call diagnostics of all the available media input devices,
+    /// and of the connectivity via the provided [`IceServer`]s.
+    async fn run_diagnostics(
+        &self,
+        ice_servers: Vec<IceServer>,
+    ) -> Result<DiagnosticsReport, Traced<DiagnosticsError>> {
+        let devices = self
+            .enumerate_devices()
+            .await
+            .map_err(tracerr::map_from_and_wrap!())?;
+
+        let mut checked = Vec::new();
+        for info in devices {
+            let mut caps = MediaStreamSettings::new();
+            let kind = match info.kind() {
+                MediaDeviceKind::AudioInput => {
+                    let mut audio = DeviceAudioTrackConstraints::new();
+                    audio.device_id(info.device_id());
+                    caps.device_audio(audio);
+                    MediaKind::Audio
+                }
+                MediaDeviceKind::VideoInput => {
+                    let mut video = DeviceVideoTrackConstraints::new();
+                    video.device_id(info.device_id());
+                    caps.device_video(video);
+                    MediaKind::Video
+                }
+                MediaDeviceKind::AudioOutput => continue,
+            };
+
+            match self.get_tracks(caps).await {
+                Ok(tracks) => {
+                    if let Some((track, _)) = tracks.into_iter().next() {
+                        // Fork the track to not affect its other users.
+                        let track = Rc::new(track.fork().await);
+                        let device =
+                            DeviceDiagnostics::check(&info, &track).await;
+                        checked.push((device, Some(track)));
+                    }
+                }
+                Err(e) => {
+                    let (e, _) = e.split();
+                    checked.push((
+                        DeviceDiagnostics::failed(&info, kind, &e),
+                        None,
+                    ));
+                }
+            }
+        }
+
+        Ok(diagnostics::run(ice_servers, checked).await)
+    }
 }
 
 impl MediaManager {
@@ -568,6 +761,25 @@ impl MediaManagerHandleImpl {
             .map_err(tracerr::map_from_and_wrap!())
     }
 
+    /// Returns [`DeviceCapabilities`] of the media input device with the
+    /// provided `device_id`, describing the media it's able to produce.
+    ///
+    /// # Errors
+    ///
+    /// See [`DeviceCapabilitiesError`] for details.
+    pub async fn device_capabilities(
+        &self,
+        device_id: String,
+    ) -> Result<DeviceCapabilities, Traced<DeviceCapabilitiesError>> {
+        let this = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(DeviceCapabilitiesError::Detached))?;
+        this.device_capabilities(device_id)
+            .await
+            .map_err(tracerr::map_from_and_wrap!())
+    }
+
     /// Switches the current audio output device to the device with the provided
     /// `device_id`.
     ///
@@ -654,4 +866,206 @@ impl MediaManagerHandleImpl {
         this.on_device_change(cb);
         Ok(())
     }
+
+    /// Returns the current [`PermissionState`] of the provided
+    /// [`PermissionKind`], allowing to check whether a user will be prompted
+    /// before acquiring media via
+    /// [`MediaManagerHandleImpl::init_local_tracks()`].
+    ///
+    /// # Errors
+    ///
+    /// See [`PermissionError`] for details.
+    pub async fn permission_state(
+        &self,
+        kind: PermissionKind,
+    ) -> Result<PermissionState, Traced<PermissionError>> {
+        let this = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(PermissionError::Detached))?;
+        this.permission_state(kind).await
+    }
+
+    /// Subscribes onto the [`PermissionState`] changes of the provided
+    /// [`PermissionKind`].
+    ///
+    /// Replaces the callback previously set for the same [`PermissionKind`].
+    ///
+    /// # Errors
+    ///
+    /// See [`PermissionError`] for details.
+    pub async fn on_permission_change(
+        &self,
+        kind: PermissionKind,
+        cb: platform::Function<api::PermissionState>,
+    ) -> Result<(), Traced<PermissionError>> {
+        let this = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(PermissionError::Detached))?;
+        this.on_permission_change(kind, cb).await
+    }
+
+    /// Runs pre-call diagnostics: checks every available media input device,
+    /// gathers local ICE candidates via the provided [`IceServer`]s and
+    /// estimates throughput over a loopback connection.
+    ///
+    /// # Errors
+    ///
+    /// See [`DiagnosticsError`] for details.
+    pub async fn run_diagnostics(
+        &self,
+        ice_servers: Vec<IceServer>,
+    ) -> Result<DiagnosticsReport, Traced<DiagnosticsError>> {
+        let this = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(DiagnosticsError::Detached))?;
+        this.run_diagnostics(ice_servers).await
+    }
+
+    /// Creates a new [`media::MediaRecorderImpl`] for recording a chosen set of
+    /// local and remote tracks.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying [`MediaManagerHandleImpl`] is dropped.
+    #[cfg(target_family = "wasm")]
+    pub fn new_recorder(
+        &self,
+    ) -> Result<media::MediaRecorderImpl, Traced<HandleDetachedError>> {
+        self.0
+            .upgrade()
+            .map(|_| media::MediaRecorderImpl::new())
+            .ok_or_else(|| tracerr::new!(HandleDetachedError))
+    }
+
+    /// Registers the provided [`platform::FrameProcessor`] to process media
+    /// frames of the [`local::Track`]s of the provided [`MediaKind`] and
+    /// [`media::MediaSourceKind`].
+    ///
+    /// Only applies to the [`local::Track`]s obtained after this call, while
+    /// the already obtained ones keep using the previously registered
+    /// [`platform::FrameProcessor`] (if any).
+    ///
+    /// # Errors
+    ///
+    /// With a [`FrameProcessorError::Detached`] if the underlying
+    /// [`MediaManagerHandleImpl`] is dropped.
+    #[cfg(target_family = "wasm")]
+    pub fn register_processor(
+        &self,
+        kind: MediaKind,
+        source_kind: media::MediaSourceKind,
+        processor: platform::FrameProcessor,
+    ) -> Result<(), Traced<FrameProcessorError>> {
+        let this = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(FrameProcessorError::Detached))?;
+        _ = this
+            .processors
+            .borrow_mut()
+            .insert((kind, source_kind.into()), processor);
+        Ok(())
+    }
+
+    /// Enables or disables the [`platform::FrameProcessor`] registered for
+    /// the provided [`MediaKind`] and [`media::MediaSourceKind`], in all the
+    /// [`local::Track`]s it's applied to.
+    ///
+    /// Media frames are passed as is through a disabled
+    /// [`platform::FrameProcessor`]. No-op if there is no registered one.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying [`MediaManagerHandleImpl`] is dropped.
+    #[cfg(target_family = "wasm")]
+    pub fn set_processor_enabled(
+        &self,
+        kind: MediaKind,
+        source_kind: media::MediaSourceKind,
+        enabled: bool,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        let this = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(HandleDetachedError))?;
+        if let Some(processor) =
+            this.processors.borrow().get(&(kind, source_kind.into()))
+        {
+            processor.set_enabled(enabled);
+        }
+        Ok(())
+    }
+
+    /// Unregisters the [`platform::FrameProcessor`] registered for the
+    /// provided [`MediaKind`] and [`media::MediaSourceKind`], disabling it in
+    /// all the [`local::Track`]s it's applied to.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying [`MediaManagerHandleImpl`] is dropped.
+    #[cfg(target_family = "wasm")]
+    pub fn unregister_processor(
+        &self,
+        kind: MediaKind,
+        source_kind: media::MediaSourceKind,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        let this = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(HandleDetachedError))?;
+        if let Some(processor) =
+            this.processors.borrow_mut().remove(&(kind, source_kind.into()))
+        {
+            processor.set_enabled(false);
+        }
+        Ok(())
+    }
+
+    /// Registers the provided [HTMLMediaElement][1] playing remote audio, so
+    /// it's switched by the [`MediaManagerHandleImpl::set_output_audio_id()`].
+    ///
+    /// The [HTMLMediaElement][1] is held until it's unregistered via
+    /// [`MediaManagerHandleImpl::unregister_audio_sink()`].
+    ///
+    /// # Errors
+    ///
+    /// If the underlying [`MediaManagerHandleImpl`] is dropped.
+    ///
+    /// [1]: https://html.spec.whatwg.org/#htmlmediaelement
+    #[cfg(target_family = "wasm")]
+    pub fn register_audio_sink(
+        &self,
+        sink: web_sys::HtmlMediaElement,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        let this = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(HandleDetachedError))?;
+        this.media_devices.register_audio_sink(sink);
+        Ok(())
+    }
+
+    /// Unregisters the provided [HTMLMediaElement][1], previously registered
+    /// via [`MediaManagerHandleImpl::register_audio_sink()`].
+    ///
+    /// # Errors
+    ///
+    /// If the underlying [`MediaManagerHandleImpl`] is dropped.
+    ///
+    /// [1]: https://html.spec.whatwg.org/#htmlmediaelement
+    #[cfg(target_family = "wasm")]
+    pub fn unregister_audio_sink(
+        &self,
+        sink: &web_sys::HtmlMediaElement,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        let this = self
+            .0
+            .upgrade()
+            .ok_or_else(|| tracerr::new!(HandleDetachedError))?;
+        this.media_devices.unregister_audio_sink(sink);
+        Ok(())
+    }
 }
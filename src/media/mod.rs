@@ -2,8 +2,12 @@
 //!
 //! [1]: https://w3.org/TR/mediacapture-streams
 
+mod capabilities;
 pub mod constraints;
+mod diagnostics;
 mod manager;
+#[cfg(target_family = "wasm")]
+mod recorder;
 pub mod track;
 
 use derive_more::with_trait::Display;
@@ -11,29 +15,41 @@ use medea_client_api_proto::MediaType;
 
 #[doc(inline)]
 pub use self::{
+    capabilities::{DeviceCapabilities, ValueRange},
     constraints::{
         AudioSource, DeviceAudioTrackConstraints, DeviceVideoTrackConstraints,
         DisplayAudioTrackConstraints, DisplayVideoTrackConstraints, FacingMode,
         LocalTracksConstraints, MediaStreamSettings, MediaTrackConstraints,
-        MultiSourceTracksConstraints, NoiseSuppressionLevel, RecvConstraints,
-        TrackConstraints, VideoSource,
+        MeteringMode, MultiSourceTracksConstraints, NoiseSuppressionLevel,
+        RecvConstraints, TrackConstraints, VideoSource,
+    },
+    diagnostics::{
+        DeviceDiagnostics, DiagnosticsError, DiagnosticsReport, IceDiagnostics,
+        ThroughputDiagnostics,
     },
     manager::{
-        EnumerateDevicesError, EnumerateDisplaysError, GetDisplayMediaError,
-        GetUserMediaError, HandleDetachedError, InitLocalTracksError,
-        InvalidOutputAudioDeviceIdError, MediaManager, MediaManagerHandleImpl,
-        MicVolumeError,
+        DeviceCapabilitiesError, EnumerateDevicesError, EnumerateDisplaysError,
+        GetDisplayMediaError, GetUserMediaError, HandleDetachedError,
+        InitLocalTracksError, InvalidOutputAudioDeviceIdError, MediaManager,
+        MediaManagerHandleImpl, MicVolumeError, PermissionError,
     },
     track::{
-        AudioLevelError, AudioProcessingError, MediaSourceKind,
+        ApplyConstraintsError, AudioLevelError, AudioProcessingError,
+        CameraControl, CameraControlError, CameraSetting, MediaSourceKind,
         MediaStreamTrackState, remote::MediaDirection,
     },
 };
+#[cfg(target_family = "wasm")]
+#[doc(inline)]
+pub use self::{
+    manager::FrameProcessorError,
+    recorder::{MediaRecorderError, MediaRecorderImpl, RecordingState},
+};
 
 /// [MediaStreamTrack.kind][1] representation.
 ///
 /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-kind
-#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
 #[repr(u8)]
 pub enum MediaKind {
     /// Audio track.
@@ -90,6 +106,45 @@ pub enum MediaDeviceKind {
     AudioOutput = 2,
 }
 
+/// Kind of a media permission, requested by a user agent before capturing
+/// media.
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+#[repr(u8)]
+pub enum PermissionKind {
+    /// Permission to capture audio from a microphone.
+    #[display("microphone")]
+    Microphone = 0,
+
+    /// Permission to capture video from a camera.
+    #[display("camera")]
+    Camera = 1,
+
+    /// Permission to capture contents of a display or its portion (such as a
+    /// single window).
+    #[display("display-capture")]
+    Screen = 2,
+}
+
+/// [PermissionState][1] representation.
+///
+/// [1]: https://w3.org/TR/permissions#dom-permissionstate
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+#[repr(u8)]
+pub enum PermissionState {
+    /// Permission is granted, so media can be captured without prompting a
+    /// user.
+    #[display("granted")]
+    Granted = 0,
+
+    /// Permission is denied, so media capturing will fail.
+    #[display("denied")]
+    Denied = 1,
+
+    /// User will be prompted for the permission on media capturing.
+    #[display("prompt")]
+    Prompt = 2,
+}
+
 /// Possible kinds of audio devices.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
@@ -0,0 +1,332 @@
+//! Local recording of [`local::Track`]s and [`remote::Track`]s.
+//!
+//! [`local::Track`]: crate::media::track::local::Track
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use derive_more::with_trait::{Display, From};
+use tracerr::Traced;
+
+use crate::{
+    api,
+    media::track::{local::LocalMediaTrackImpl, remote},
+    platform,
+    utils::Caused,
+};
+
+/// State of a [`MediaRecorderImpl`].
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+#[repr(u8)]
+pub enum RecordingState {
+    /// Recording is not started yet or has been stopped.
+    #[display("inactive")]
+    Inactive = 0,
+
+    /// Recording is in progress.
+    #[display("recording")]
+    Recording = 1,
+
+    /// Recording is paused and may be resumed.
+    #[display("paused")]
+    Paused = 2,
+}
+
+/// Errors occurring in a [`MediaRecorderImpl`].
+#[derive(Caused, Clone, Debug, Display, From)]
+#[cause(error = platform::Error)]
+pub enum MediaRecorderError {
+    /// Occurs if recording is started without any tracks to record.
+    #[display("No tracks to record")]
+    NoTracks,
+
+    /// Occurs if the requested operation is not allowed in the current
+    /// [`RecordingState`].
+    #[display("Operation is not allowed in the current recording state")]
+    InvalidState,
+
+    /// Occurs if more than one video track is requested to be recorded.
+    #[display("Only a single video track can be recorded")]
+    TooManyVideoTracks,
+
+    /// Occurs if the underlying [`platform::MediaRecorder`] fails.
+    #[display("Recording failed: {_0}")]
+    RecordingFailed(platform::Error),
+}
+
+/// Track recorded by a [`MediaRecorderImpl`].
+#[derive(Debug)]
+enum RecordedTrack {
+    /// Local track received from a
+    /// [getUserMedia()][1]/[getDisplayMedia()][2] request.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediadevices-getusermedia
+    /// [2]: https://w3.org/TR/screen-capture/#dom-mediadevices-getdisplaymedia
+    Local(LocalMediaTrackImpl),
+
+    /// Track received from a remote member.
+    Remote(remote::Track),
+}
+
+impl RecordedTrack {
+    /// Returns the underlying [`platform::MediaStreamTrack`] of this
+    /// [`RecordedTrack`].
+    fn platform_track(&self) -> &platform::MediaStreamTrack {
+        match self {
+            Self::Local(track) => track.get_track(),
+            Self::Remote(track) => track.get_track(),
+        }
+    }
+}
+
+/// Inner data of a [`MediaRecorderImpl`].
+#[derive(Debug)]
+struct Inner {
+    /// Tracks to be recorded.
+    tracks: RefCell<Vec<RecordedTrack>>,
+
+    /// Underlying [`platform::MediaRecorder`], if recording is started.
+    recorder: RefCell<Option<platform::MediaRecorder>>,
+
+    /// Current [`RecordingState`].
+    state: Cell<RecordingState>,
+
+    /// Callback to be invoked with each recorded media data chunk.
+    on_data: platform::Callback<platform::RecordedData>,
+
+    /// Callback to be invoked once recording fails.
+    on_error: platform::Callback<api::Error>,
+}
+
+/// Recorder of a chosen set of [`local::Track`]s and [`remote::Track`]s into
+/// a single media container, emitting recorded data in chunks.
+///
+/// [`local::Track`]: crate::media::track::local::Track
+#[derive(Clone, Debug)]
+pub struct MediaRecorderImpl(Rc<Inner>);
+
+impl MediaRecorderImpl {
+    /// Creates a new [`MediaRecorderImpl`] without any tracks.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Rc::new(Inner {
+            tracks: RefCell::new(Vec::new()),
+            recorder: RefCell::new(None),
+            state: Cell::new(RecordingState::Inactive),
+            on_data: platform::Callback::default(),
+            on_error: platform::Callback::default(),
+        }))
+    }
+
+    /// Adds the provided [`LocalMediaTrackImpl`] to the recorded ones.
+    ///
+    /// # Errors
+    ///
+    /// With a [`MediaRecorderError::InvalidState`] if recording is already
+    /// started.
+    pub fn add_local_track(
+        &self,
+        track: LocalMediaTrackImpl,
+    ) -> Result<(), Traced<MediaRecorderError>> {
+        self.add_track(RecordedTrack::Local(track))
+    }
+
+    /// Adds the provided [`remote::Track`] to the recorded ones.
+    ///
+    /// # Errors
+    ///
+    /// With a [`MediaRecorderError::InvalidState`] if recording is already
+    /// started.
+    pub fn add_remote_track(
+        &self,
+        track: remote::Track,
+    ) -> Result<(), Traced<MediaRecorderError>> {
+        self.add_track(RecordedTrack::Remote(track))
+    }
+
+    /// Sets callback to be invoked with each recorded media data chunk.
+    pub fn on_data(&self, f: platform::Function<platform::RecordedData>) {
+        self.0.on_data.set_func(f);
+    }
+
+    /// Sets callback to be invoked once recording fails.
+    pub fn on_error(&self, f: platform::Function<api::Error>) {
+        self.0.on_error.set_func(f);
+    }
+
+    /// Returns the current [`RecordingState`] of this [`MediaRecorderImpl`].
+    #[must_use]
+    pub fn state(&self) -> RecordingState {
+        self.0.state.get()
+    }
+
+    /// Starts recording of all the added tracks, emitting recorded data chunks
+    /// each `timeslice_ms` milliseconds (if provided), or only once recording
+    /// is stopped.
+    ///
+    /// # Errors
+    ///
+    /// - With a [`MediaRecorderError::InvalidState`] if recording is already
+    ///   started.
+    /// - With a [`MediaRecorderError::NoTracks`] if no tracks were added.
+    /// - With a [`MediaRecorderError::TooManyVideoTracks`] if more than one
+    ///   video track was added.
+    /// - With a [`MediaRecorderError::RecordingFailed`] if the underlying
+    ///   [`platform::MediaRecorder`] fails to start.
+    pub fn start(
+        &self,
+        timeslice_ms: Option<u32>,
+    ) -> Result<(), Traced<MediaRecorderError>> {
+        self.ensure_state(RecordingState::Inactive)?;
+
+        let recorder = {
+            let tracks = self.0.tracks.borrow();
+            if tracks.is_empty() {
+                return Err(tracerr::new!(MediaRecorderError::NoTracks));
+            }
+            platform::MediaRecorder::new(
+                tracks.iter().map(RecordedTrack::platform_track),
+            )
+            .map_err(tracerr::wrap!())?
+        };
+
+        recorder.on_data(Some({
+            let weak_inner = Rc::downgrade(&self.0);
+            move |data| {
+                if let Some(inner) = weak_inner.upgrade() {
+                    inner.on_data.call1(data);
+                }
+            }
+        }));
+        recorder.on_error(Some({
+            let weak_inner = Rc::downgrade(&self.0);
+            move |err| {
+                if let Some(inner) = weak_inner.upgrade() {
+                    inner.state.set(RecordingState::Inactive);
+                    inner.on_error.call1(api::Error::from(tracerr::new!(
+                        MediaRecorderError::RecordingFailed(err)
+                    )));
+                }
+            }
+        }));
+
+        recorder
+            .start(timeslice_ms)
+            .map_err(MediaRecorderError::RecordingFailed)
+            .map_err(tracerr::wrap!())?;
+        *self.0.recorder.borrow_mut() = Some(recorder);
+        self.0.state.set(RecordingState::Recording);
+
+        Ok(())
+    }
+
+    /// Pauses recording.
+    ///
+    /// # Errors
+    ///
+    /// - With a [`MediaRecorderError::InvalidState`] if recording is not in
+    ///   progress.
+    /// - With a [`MediaRecorderError::RecordingFailed`] if the underlying
+    ///   [`platform::MediaRecorder`] fails to pause.
+    pub fn pause(&self) -> Result<(), Traced<MediaRecorderError>> {
+        self.ensure_state(RecordingState::Recording)?;
+        if let Some(recorder) = self.0.recorder.borrow().as_ref() {
+            recorder
+                .pause()
+                .map_err(MediaRecorderError::RecordingFailed)
+                .map_err(tracerr::wrap!())?;
+        }
+        self.0.state.set(RecordingState::Paused);
+
+        Ok(())
+    }
+
+    /// Resumes previously paused recording.
+    ///
+    /// # Errors
+    ///
+    /// - With a [`MediaRecorderError::InvalidState`] if recording is not
+    ///   paused.
+    /// - With a [`MediaRecorderError::RecordingFailed`] if the underlying
+    ///   [`platform::MediaRecorder`] fails to resume.
+    pub fn resume(&self) -> Result<(), Traced<MediaRecorderError>> {
+        self.ensure_state(RecordingState::Paused)?;
+        if let Some(recorder) = self.0.recorder.borrow().as_ref() {
+            recorder
+                .resume()
+                .map_err(MediaRecorderError::RecordingFailed)
+                .map_err(tracerr::wrap!())?;
+        }
+        self.0.state.set(RecordingState::Recording);
+
+        Ok(())
+    }
+
+    /// Stops recording, resolving once the last recorded data chunk is
+    /// emitted.
+    ///
+    /// # Errors
+    ///
+    /// - With a [`MediaRecorderError::InvalidState`] if recording is not
+    ///   started.
+    /// - With a [`MediaRecorderError::RecordingFailed`] if the underlying
+    ///   [`platform::MediaRecorder`] fails to stop.
+    pub async fn stop(&self) -> Result<(), Traced<MediaRecorderError>> {
+        if self.0.state.get() == RecordingState::Inactive {
+            return Err(tracerr::new!(MediaRecorderError::InvalidState));
+        }
+        self.0.state.set(RecordingState::Inactive);
+
+        let recorder = self.0.recorder.borrow_mut().take();
+        if let Some(recorder) = recorder {
+            recorder
+                .stop()
+                .await
+                .map_err(MediaRecorderError::RecordingFailed)
+                .map_err(tracerr::wrap!())?;
+        }
+
+        Ok(())
+    }
+
+    /// Adds the provided [`RecordedTrack`] to the recorded ones.
+    ///
+    /// # Errors
+    ///
+    /// With a [`MediaRecorderError::InvalidState`] if recording is already
+    /// started.
+    fn add_track(
+        &self,
+        track: RecordedTrack,
+    ) -> Result<(), Traced<MediaRecorderError>> {
+        self.ensure_state(RecordingState::Inactive)?;
+        self.0.tracks.borrow_mut().push(track);
+
+        Ok(())
+    }
+
+    /// Ensures that this [`MediaRecorderImpl`] is in the provided
+    /// [`RecordingState`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`MediaRecorderError::InvalidState`] otherwise.
+    fn ensure_state(
+        &self,
+        expected: RecordingState,
+    ) -> Result<(), Traced<MediaRecorderError>> {
+        if self.0.state.get() == expected {
+            Ok(())
+        } else {
+            Err(tracerr::new!(MediaRecorderError::InvalidState))
+        }
+    }
+}
+
+impl Default for MediaRecorderImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -12,8 +12,10 @@ use tracerr::Traced;
 
 use crate::{
     media::{
-        AudioLevelError, AudioProcessingError, MediaKind, MediaSourceKind,
-        MediaStreamTrackState, NoiseSuppressionLevel,
+        ApplyConstraintsError, AudioLevelError, AudioProcessingError,
+        CameraControl, CameraControlError, CameraSetting, MediaKind,
+        MediaSourceKind, MediaStreamTrackState, MeteringMode,
+        NoiseSuppressionLevel, VideoSource,
     },
     platform,
 };
@@ -40,8 +42,9 @@ pub struct Track {
     /// Parent will be [`None`] if this [`Track`] wasn't forked from another
     /// [`Track`].
     ///
-    /// This field is used only for holding strong reference to the parent.
-    _parent: Option<Rc<Self>>,
+    /// This field is used for holding strong reference to the parent, and for
+    /// propagating constraints changes to it.
+    parent: Option<Rc<Self>>,
 }
 
 impl Track {
@@ -52,7 +55,7 @@ impl Track {
         track: platform::MediaStreamTrack,
         source_kind: proto::MediaSourceKind,
     ) -> Self {
-        Self { inner: track, source_kind, _parent: None }
+        Self { inner: track, source_kind, parent: None }
     }
 
     /// Returns the underlying [`platform::MediaStreamTrack`] of this [`Track`].
@@ -106,6 +109,33 @@ impl Track {
         self.inner.ready_state().await
     }
 
+    /// Applies the provided [`VideoSource`] constraints to this live [`Track`]
+    /// via [applyConstraints()][1], without re-acquiring its media source.
+    ///
+    /// Constraints are applied to all the parents of this [`Track`] as well,
+    /// so the ones stored in a [`MediaManager`] keep satisfying them.
+    ///
+    /// # Errors
+    ///
+    /// With an [`ApplyConstraintsError`] if platform call errors.
+    ///
+    /// [`MediaManager`]: crate::media::MediaManager
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-applyconstraints
+    pub async fn apply_constraints(
+        &self,
+        constraints: VideoSource,
+    ) -> Result<(), Traced<ApplyConstraintsError>> {
+        let mut track = Some(self);
+        while let Some(t) = track {
+            t.inner
+                .apply_constraints(constraints.clone())
+                .await
+                .map_err(tracerr::wrap!())?;
+            track = t.parent.as_deref();
+        }
+        Ok(())
+    }
+
     /// Forks this [`Track`].
     ///
     /// Creates a new [`Track`] from this [`Track`]'s
@@ -120,7 +150,7 @@ impl Track {
         Self {
             inner: track,
             source_kind: self.source_kind,
-            _parent: Some(parent),
+            parent: Some(parent),
         }
     }
 
@@ -207,6 +237,26 @@ impl LocalMediaTrackImpl {
             .map_err(tracerr::wrap!())
     }
 
+    /// Returns the microphone volume level (in percents) applied to this
+    /// [`LocalMediaTrackImpl`].
+    ///
+    /// [`None`] if this [`LocalMediaTrackImpl`] isn't a device audio one.
+    #[cfg(target_family = "wasm")]
+    #[must_use]
+    pub fn microphone_volume(&self) -> Option<i64> {
+        self.0.inner.microphone_volume()
+    }
+
+    /// Sets the microphone volume level (in percents) applied to this
+    /// [`LocalMediaTrackImpl`], without affecting other
+    /// [`LocalMediaTrackImpl`]s.
+    ///
+    /// Does nothing if this [`LocalMediaTrackImpl`] isn't a device audio one.
+    #[cfg(target_family = "wasm")]
+    pub fn set_microphone_volume(&self, level: i64) {
+        self.0.inner.set_microphone_volume(level);
+    }
+
     /// Indicates whether this [`LocalMediaTrackImpl`] supports audio processing
     /// functions:
     /// - [`LocalMediaTrackImpl::is_noise_suppression_enabled()`]
@@ -224,6 +274,19 @@ impl LocalMediaTrackImpl {
         self.0.inner.is_audio_processing_available()
     }
 
+    /// Applies the provided [`VideoSource`] constraints to this
+    /// [`LocalMediaTrackImpl`] without re-acquiring its media source.
+    ///
+    /// # Errors
+    ///
+    /// With an [`ApplyConstraintsError`] if platform call errors.
+    pub async fn apply_constraints(
+        &self,
+        constraints: VideoSource,
+    ) -> Result<(), Traced<ApplyConstraintsError>> {
+        self.0.apply_constraints(constraints).await
+    }
+
     /// Toggles noise suppression for this [`LocalMediaTrackImpl`].
     ///
     /// # Errors
@@ -394,6 +457,148 @@ impl LocalMediaTrackImpl {
             .map_err(tracerr::wrap!())
     }
 
+    /// Indicates whether the camera producing this [`LocalMediaTrackImpl`]
+    /// supports zoom control via
+    /// [`LocalMediaTrackImpl::set_zoom()`].
+    #[must_use]
+    pub fn is_zoom_available(&self) -> bool {
+        self.0.inner.is_camera_control_available(CameraControl::Zoom)
+    }
+
+    /// Sets the provided zoom level of the camera producing this
+    /// [`LocalMediaTrackImpl`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`CameraControlError`] if platform call errors.
+    pub async fn set_zoom(
+        &self,
+        zoom: f64,
+    ) -> Result<(), Traced<CameraControlError>> {
+        self.set_camera_control(CameraSetting::Zoom(zoom)).await
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrackImpl`]
+    /// supports torch control via
+    /// [`LocalMediaTrackImpl::set_torch_enabled()`].
+    #[must_use]
+    pub fn is_torch_available(&self) -> bool {
+        self.0.inner.is_camera_control_available(CameraControl::Torch)
+    }
+
+    /// Toggles the torch (fill light) of the camera producing this
+    /// [`LocalMediaTrackImpl`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`CameraControlError`] if platform call errors.
+    pub async fn set_torch_enabled(
+        &self,
+        enabled: bool,
+    ) -> Result<(), Traced<CameraControlError>> {
+        self.set_camera_control(CameraSetting::Torch(enabled)).await
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrackImpl`]
+    /// supports focus mode control via
+    /// [`LocalMediaTrackImpl::set_focus_mode()`].
+    #[must_use]
+    pub fn is_focus_mode_available(&self) -> bool {
+        self.0.inner.is_camera_control_available(CameraControl::FocusMode)
+    }
+
+    /// Sets the provided focus [`MeteringMode`] of the camera producing this
+    /// [`LocalMediaTrackImpl`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`CameraControlError`] if platform call errors.
+    pub async fn set_focus_mode(
+        &self,
+        mode: MeteringMode,
+    ) -> Result<(), Traced<CameraControlError>> {
+        self.set_camera_control(CameraSetting::FocusMode(mode)).await
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrackImpl`]
+    /// supports focus distance control via
+    /// [`LocalMediaTrackImpl::set_focus_distance()`].
+    #[must_use]
+    pub fn is_focus_distance_available(&self) -> bool {
+        self.0.inner.is_camera_control_available(CameraControl::FocusDistance)
+    }
+
+    /// Sets the provided focus distance (in meters) of the camera producing
+    /// this [`LocalMediaTrackImpl`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`CameraControlError`] if platform call errors.
+    pub async fn set_focus_distance(
+        &self,
+        distance: f64,
+    ) -> Result<(), Traced<CameraControlError>> {
+        self.set_camera_control(CameraSetting::FocusDistance(distance)).await
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrackImpl`]
+    /// supports exposure compensation control via
+    /// [`LocalMediaTrackImpl::set_exposure_compensation()`].
+    #[must_use]
+    pub fn is_exposure_compensation_available(&self) -> bool {
+        self.0
+            .inner
+            .is_camera_control_available(CameraControl::ExposureCompensation)
+    }
+
+    /// Sets the provided exposure compensation (in exposure value stops) of the
+    /// camera producing this [`LocalMediaTrackImpl`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`CameraControlError`] if platform call errors.
+    pub async fn set_exposure_compensation(
+        &self,
+        compensation: f64,
+    ) -> Result<(), Traced<CameraControlError>> {
+        self.set_camera_control(CameraSetting::ExposureCompensation(
+            compensation,
+        ))
+        .await
+    }
+
+    /// Indicates whether the camera producing this [`LocalMediaTrackImpl`]
+    /// supports white balance mode control via
+    /// [`LocalMediaTrackImpl::set_white_balance_mode()`].
+    #[must_use]
+    pub fn is_white_balance_mode_available(&self) -> bool {
+        self.0
+            .inner
+            .is_camera_control_available(CameraControl::WhiteBalanceMode)
+    }
+
+    /// Sets the provided white balance [`MeteringMode`] of the camera producing
+    /// this [`LocalMediaTrackImpl`].
+    ///
+    /// # Errors
+    ///
+    /// With a [`CameraControlError`] if platform call errors.
+    pub async fn set_white_balance_mode(
+        &self,
+        mode: MeteringMode,
+    ) -> Result<(), Traced<CameraControlError>> {
+        self.set_camera_control(CameraSetting::WhiteBalanceMode(mode)).await
+    }
+
+    /// Applies the provided [`CameraSetting`] to the camera producing this
+    /// [`LocalMediaTrackImpl`].
+    async fn set_camera_control(
+        &self,
+        setting: CameraSetting,
+    ) -> Result<(), Traced<CameraControlError>> {
+        self.0.inner.set_camera_control(setting).await.map_err(tracerr::wrap!())
+    }
+
     /// Returns a [`MediaSourceKind::Device`] if this [`LocalMediaTrackImpl`] is
     /// sourced from some device (webcam/microphone), or
     /// a [`MediaSourceKind::Display`] if it's captured via
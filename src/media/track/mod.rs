@@ -8,7 +8,7 @@ pub mod remote;
 use derive_more::{Display, From, Into};
 use medea_client_api_proto as proto;
 
-use crate::{platform, utils::Caused};
+use crate::{media::MeteringMode, platform, utils::Caused};
 
 /// Error returned when trying to bind to local audio track level changes.
 #[derive(Caused, Clone, Debug, Display, From, Into)]
@@ -22,6 +22,101 @@ pub struct AudioLevelError(platform::Error);
 #[display("Failed to access audio processing of a track")]
 pub struct AudioProcessingError(platform::Error);
 
+/// Error returned when applying constraints to a live media track fails.
+#[derive(Caused, Clone, Debug, Display, From)]
+#[cause(error = platform::Error)]
+pub enum ApplyConstraintsError {
+    /// Platform doesn't support changing constraints of a live media track,
+    /// so its media source should be re-acquired instead.
+    #[display("Changing constraints of a live track is not supported")]
+    NotSupported,
+
+    /// Occurs if the [applyConstraints()][1] platform call fails.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-applyconstraints
+    #[display("Failed to apply constraints to a track: {_0}")]
+    Failed(platform::Error),
+}
+
+/// Error returned from camera controls manipulation.
+#[derive(Caused, Clone, Debug, Display, From)]
+#[cause(error = platform::Error)]
+pub enum CameraControlError {
+    /// Platform doesn't support [`CameraControl`]s.
+    #[display("Camera controls are not supported")]
+    NotSupported,
+
+    /// Occurs if the platform call setting the [`CameraControl`] fails.
+    #[display("Failed to set a camera control of a track: {_0}")]
+    Failed(platform::Error),
+}
+
+/// Camera control of a local video track.
+///
+/// See the [MediaStream Image Capture][1] spec for details.
+///
+/// [1]: https://w3.org/TR/image-capture#constrainable-properties
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum CameraControl {
+    /// Zoom level of a camera.
+    Zoom = 0,
+
+    /// Torch (fill light) of a camera.
+    Torch = 1,
+
+    /// Focus mode of a camera.
+    FocusMode = 2,
+
+    /// Focus distance of a camera, in meters.
+    FocusDistance = 3,
+
+    /// Exposure compensation of a camera, in f-stops.
+    ExposureCompensation = 4,
+
+    /// White balance mode of a camera.
+    WhiteBalanceMode = 5,
+}
+
+/// Value of a [`CameraControl`] to be set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CameraSetting {
+    /// [`CameraControl::Zoom`] level.
+    Zoom(f64),
+
+    /// Indicator whether [`CameraControl::Torch`] is enabled.
+    Torch(bool),
+
+    /// [`CameraControl::FocusMode`] value.
+    FocusMode(MeteringMode),
+
+    /// [`CameraControl::FocusDistance`] value.
+    FocusDistance(f64),
+
+    /// [`CameraControl::ExposureCompensation`] value.
+    ExposureCompensation(f64),
+
+    /// [`CameraControl::WhiteBalanceMode`] value.
+    WhiteBalanceMode(MeteringMode),
+}
+
+impl CameraSetting {
+    /// Returns the [`CameraControl`] this [`CameraSetting`] is for.
+    #[must_use]
+    pub const fn control(self) -> CameraControl {
+        match self {
+            Self::Zoom(_) => CameraControl::Zoom,
+            Self::Torch(_) => CameraControl::Torch,
+            Self::FocusMode(_) => CameraControl::FocusMode,
+            Self::FocusDistance(_) => CameraControl::FocusDistance,
+            Self::ExposureCompensation(_) => {
+                CameraControl::ExposureCompensation
+            }
+            Self::WhiteBalanceMode(_) => CameraControl::WhiteBalanceMode,
+        }
+    }
+}
+
 /// Liveness state of a [MediaStreamTrack][1] .
 ///
 /// [1]: crate::platform::MediaStreamTrack
@@ -116,7 +116,7 @@ pub struct State {
     force_relay: bool,
 
     /// List of [`IceServer`]s which this [`Component`] should use.
-    ice_servers: Vec<IceServer>,
+    ice_servers: ObservableCell<Vec<IceServer>>,
 
     /// Current [`NegotiationRole`] of this [`Component`].
     negotiation_role: ProgressableCell<Option<NegotiationRole>>,
@@ -178,7 +178,7 @@ impl State {
             connection_mode,
             senders: TracksRepository::new(),
             receivers: TracksRepository::new(),
-            ice_servers,
+            ice_servers: ObservableCell::new(ice_servers),
             force_relay,
             remote_sdp: ProgressableCell::new(None),
             local_sdp: LocalSdp::new(),
@@ -217,8 +217,16 @@ impl State {
 
     /// Returns all [`IceServer`]s of this [`State`].
     #[must_use]
-    pub const fn ice_servers(&self) -> &Vec<IceServer> {
-        &self.ice_servers
+    pub fn ice_servers(&self) -> Vec<IceServer> {
+        self.ice_servers.get()
+    }
+
+    /// Replaces [`IceServer`]s of this [`State`] with the provided ones.
+    ///
+    /// New [`IceServer`]s are applied to the [`PeerConnection`] without any
+    /// renegotiation.
+    pub fn set_ice_servers(&self, ice_servers: Vec<IceServer>) {
+        self.ice_servers.set(ice_servers);
     }
 
     /// Indicates whether [`PeerConnection`] should be relayed forcibly.
@@ -494,7 +502,7 @@ impl AsProtoState for State {
             receivers: self.receivers.as_proto(),
             ice_candidates: self.ice_candidates.as_proto(),
             force_relay: self.force_relay,
-            ice_servers: self.ice_servers.clone(),
+            ice_servers: self.ice_servers.get(),
             negotiation_role: self.negotiation_role.get(),
             local_sdp: self.local_sdp.current(),
             remote_sdp: self.remote_sdp.get(),
@@ -565,6 +573,7 @@ impl SynchronizableState for State {
             self.negotiation_phase.set(NegotiationPhase::WaitLocalSdp);
         }
         self.remote_sdp.set(input.remote_sdp);
+        self.ice_servers.set(input.ice_servers);
         self.ice_candidates.apply(input.ice_candidates, send_cons);
         self.senders.apply(input.senders, send_cons);
         self.receivers.apply(input.receivers, send_cons);
@@ -5,7 +5,8 @@ use std::{collections::HashSet, rc::Rc};
 use derive_more::with_trait::{Display, From};
 use futures::{StreamExt as _, future};
 use medea_client_api_proto::{
-    IceCandidate, MemberId, NegotiationRole, PeerConnectionState, TrackId,
+    IceCandidate, IceServer, MemberId, NegotiationRole, PeerConnectionState,
+    TrackId,
 };
 use medea_macro::watchers;
 use medea_reactive::Guarded;
@@ -60,6 +61,26 @@ impl Component {
         .map_err(tracerr::map_from_and_wrap!())
     }
 
+    /// Watcher for the [`State::ice_servers`] update.
+    ///
+    /// Applies new [`IceServer`]s to the underlying [RTCPeerConnection][1]
+    /// via [setConfiguration()][2], so they are used on the next ICE
+    /// gathering (ICE restart, for example).
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcpeerconnection
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcpeerconnection-setconfiguration
+    #[watch(self.ice_servers.subscribe().skip(1))]
+    async fn ice_servers_changed(
+        peer: Rc<PeerConnection>,
+        state: Rc<State>,
+        ice_servers: Vec<IceServer>,
+    ) -> Result<(), Traced<RtcPeerConnectionError>> {
+        peer.peer
+            .set_ice_servers(ice_servers, state.force_relay())
+            .await
+            .map_err(tracerr::map_from_and_wrap!())
+    }
+
     /// Watcher for the [`State::remote_sdp`] update.
     ///
     /// Calls [`PeerConnection::set_remote_answer()`] with a new value if the
@@ -555,6 +555,11 @@ impl MediaConnections {
         );
     }
 
+    /// Returns all underlying [`Sender`]'s.
+    pub fn get_senders(&self) -> Vec<Rc<Sender>> {
+        self.0.borrow().senders.values().map(Component::obj).collect()
+    }
+
     /// Returns [`TracksRequest`] based on [`Sender`]s in this
     /// [`MediaConnections`]. [`Sender`]s are chosen based on provided
     /// [`LocalStreamUpdateCriteria`].
@@ -981,11 +986,6 @@ impl MediaConnections {
         Ok(())
     }
 
-    /// Returns all underlying [`Sender`]'s.
-    pub fn get_senders(&self) -> Vec<Rc<Sender>> {
-        self.0.borrow().senders.values().map(Component::obj).collect()
-    }
-
     /// Returns [`sender::State`] with the provided [`TrackId`].
     #[must_use]
     pub fn get_sender_state_by_id(
@@ -374,7 +374,7 @@ impl PeerConnection {
     ) -> Result<Rc<Self>, Traced<RtcPeerConnectionError>> {
         let peer = Rc::new(
             platform::RtcPeerConnection::new(
-                state.ice_servers().clone(),
+                state.ice_servers(),
                 state.force_relay(),
             )
             .await
@@ -1053,6 +1053,17 @@ impl PeerConnection {
     pub fn connection_state(&self) -> PeerConnectionState {
         self.peer.connection_state()
     }
+
+    /// Returns all [`local::Track`]s from [`PeerConnection`]'s
+    /// [`Transceiver`]s.
+    #[must_use]
+    pub fn get_send_tracks(&self) -> Vec<Rc<local::Track>> {
+        self.media_connections
+            .get_senders()
+            .into_iter()
+            .filter_map(|sndr| sndr.get_send_track())
+            .collect()
+    }
 }
 
 #[cfg(feature = "mockable")]
@@ -1133,17 +1144,6 @@ impl PeerConnection {
         self.media_connections.is_send_audio_unmuted()
     }
 
-    /// Returns all [`local::Track`]s from [`PeerConnection`]'s
-    /// [`Transceiver`]s.
-    #[must_use]
-    pub fn get_send_tracks(&self) -> Vec<Rc<local::Track>> {
-        self.media_connections
-            .get_senders()
-            .into_iter()
-            .filter_map(|sndr| sndr.get_send_track())
-            .collect()
-    }
-
     /// Returns [`Rc`] to the [`Receiver`] with the provided [`TrackId`].
     #[must_use]
     pub fn get_receiver_by_id(
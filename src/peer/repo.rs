@@ -159,16 +159,13 @@ impl Repository {
         static LOOP_STEP: Duration = Duration::from_millis(333);
 
         let (fut, abort) = future::abortable(async move {
-            // TODO: Add `platform::Instant` abstraction.
-            // `Instant`/`SystemTime` from `std` are not available on
-            // `wasm32-unknown-unknown` target, so this loop will drift over
-            // time. However, high precision isn't really important here.
-            let mut peers_next_scrape: HashMap<PeerId, Duration> =
+            let mut peers_next_scrape: HashMap<PeerId, platform::Instant> =
                 HashMap::new();
 
             loop {
-                platform::delay_for(LOOP_STEP).await;
+                platform::clock().delay_for(LOOP_STEP).await;
 
+                let now = platform::Instant::now();
                 let mut tasks = Vec::new();
                 let peers = peers.borrow();
 
@@ -183,19 +180,19 @@ impl Repository {
                         continue;
                     };
 
-                    let remaining =
-                        peers_next_scrape.entry(*id).or_insert(Duration::ZERO);
+                    let next_scrape =
+                        peers_next_scrape.entry(*id).or_insert(now);
 
-                    if *remaining == Duration::ZERO {
+                    if *next_scrape <= now {
                         let peer = peer_component.obj();
                         tasks.push(async move {
                             peer.scrape_and_send_peer_stats().await;
                         });
 
-                        *remaining = interval;
+                        // Scheduled from the previous deadline, so the
+                        // schedule doesn't drift by `LOOP_STEP` granularity.
+                        *next_scrape = (*next_scrape + interval).max(now);
                     }
-
-                    *remaining = remaining.saturating_sub(LOOP_STEP);
                 }
 
                 if peers_next_scrape.len() != peers.len() {
@@ -253,6 +250,8 @@ impl AsProtoState for State {
                 .iter()
                 .map(|(id, p)| (*id, p.as_proto()))
                 .collect(),
+            // `Member`s are tracked and filled by a `Room` itself.
+            members: HashMap::new(),
         }
     }
 }
@@ -86,6 +86,16 @@ impl LocalStreamUpdateCriteria {
         self.0.bitor_assign(Self::from_kinds(media_kind, Some(source_kind)).0);
     }
 
+    /// Removes the given [`MediaKind`] + [`MediaSourceKind`] pair from this
+    /// [`LocalStreamUpdateCriteria`].
+    pub fn remove(
+        &mut self,
+        media_kind: MediaKind,
+        source_kind: MediaSourceKind,
+    ) {
+        self.0.remove(Self::from_kinds(media_kind, Some(source_kind)).0);
+    }
+
     /// Checks whether this [`LocalStreamUpdateCriteria`] contains the provided
     /// [`MediaKind`] + [`MediaSourceKind`] pair.
     #[must_use]
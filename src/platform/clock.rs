@@ -0,0 +1,157 @@
+//! Platform-agnostic abstraction over time.
+//!
+//! All the time-dependent logic (heartbeats, backoffs, timeouts, stats
+//! scraping) should measure and await time via the current [`Clock`], so it can
+//! be replaced with a [`ManualClock`] in tests.
+
+use std::{cell::RefCell, ops, rc::Rc, time::Duration};
+
+#[cfg(feature = "mockable")]
+use std::cell::Cell;
+
+use derive_more::with_trait::Debug;
+#[cfg(feature = "mockable")]
+use futures::channel::oneshot;
+use futures::future::LocalBoxFuture;
+
+use crate::platform;
+
+/// Monotonic moment in time, measured by a [`Clock`].
+///
+/// [`std::time::Instant`] is not available on `wasm32-unknown-unknown`
+/// target, so this one should be used instead.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Instant(Duration);
+
+impl Instant {
+    /// Returns an [`Instant`] of the current moment, as measured by the
+    /// current [`Clock`].
+    #[must_use]
+    pub fn now() -> Self {
+        clock().now()
+    }
+
+    /// Returns the amount of time elapsed from the `earlier` [`Instant`] to
+    /// this one, or [`Duration::ZERO`] if the `earlier` one is later.
+    #[must_use]
+    pub const fn saturating_duration_since(self, earlier: Self) -> Duration {
+        self.0.saturating_sub(earlier.0)
+    }
+
+    /// Returns the amount of time elapsed since this [`Instant`], as measured
+    /// by the current [`Clock`].
+    #[must_use]
+    pub fn elapsed(self) -> Duration {
+        Self::now().saturating_duration_since(self)
+    }
+}
+
+impl ops::Add<Duration> for Instant {
+    type Output = Self;
+
+    fn add(self, rhs: Duration) -> Self {
+        Self(self.0.saturating_add(rhs))
+    }
+}
+
+/// Source of time.
+pub trait Clock {
+    /// Returns an [`Instant`] of the current moment.
+    fn now(&self) -> Instant;
+
+    /// Returns a [`Future`] resolving once the provided [`Duration`] elapses.
+    fn delay_for(&self, delay: Duration) -> LocalBoxFuture<'static, ()>;
+}
+
+/// [`Clock`] measuring the real time via [`platform::now()`] and
+/// [`platform::delay_for()`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RealClock;
+
+impl Clock for RealClock {
+    fn now(&self) -> Instant {
+        Instant(platform::now())
+    }
+
+    fn delay_for(&self, delay: Duration) -> LocalBoxFuture<'static, ()> {
+        Box::pin(platform::delay_for(delay))
+    }
+}
+
+/// [`Clock`] standing still until it's manually advanced via
+/// [`ManualClock::advance()`].
+///
+/// Allows to test time-dependent behavior instantly and deterministically.
+#[cfg(feature = "mockable")]
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    /// Time elapsed since this [`ManualClock`] has been created.
+    elapsed: Cell<Duration>,
+
+    /// Pending [`Clock::delay_for()`] calls, along with their deadlines.
+    #[debug("{}", timers.borrow().len())]
+    timers: RefCell<Vec<(Instant, oneshot::Sender<()>)>>,
+}
+
+#[cfg(feature = "mockable")]
+impl ManualClock {
+    /// Creates a new [`ManualClock`] starting at zero.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advances this [`ManualClock`] by the provided [`Duration`], resolving
+    /// all the [`Clock::delay_for()`] [`Future`]s whose deadlines are reached.
+    pub fn advance(&self, by: Duration) {
+        self.elapsed.set(self.elapsed.get().saturating_add(by));
+        let now = self.now();
+        let due = {
+            let mut timers = self.timers.borrow_mut();
+            let (due, pending) =
+                timers.drain(..).partition(|(deadline, _)| *deadline <= now);
+            *timers = pending;
+            due
+        };
+        for (_, tx) in due {
+            _ = tx.send(());
+        }
+    }
+}
+
+#[cfg(feature = "mockable")]
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        Instant(self.elapsed.get())
+    }
+
+    fn delay_for(&self, delay: Duration) -> LocalBoxFuture<'static, ()> {
+        if delay.is_zero() {
+            return Box::pin(async {});
+        }
+        let (tx, rx) = oneshot::channel();
+        self.timers.borrow_mut().push((self.now() + delay, tx));
+        Box::pin(async move {
+            _ = rx.await;
+        })
+    }
+}
+
+thread_local! {
+    /// [`Clock`] currently used by this library.
+    static CLOCK: RefCell<Rc<dyn Clock>> = RefCell::new(Rc::new(RealClock));
+}
+
+/// Returns the [`Clock`] currently used by this library.
+#[must_use]
+pub fn clock() -> Rc<dyn Clock> {
+    CLOCK.with_borrow(Rc::clone)
+}
+
+/// Replaces the [`Clock`] used by this library with the provided one.
+///
+/// Affects only the time measured and awaited after this call.
+#[cfg(feature = "mockable")]
+pub fn set_clock(clock: Rc<dyn Clock>) {
+    CLOCK.set(clock);
+}
@@ -5,7 +5,6 @@
 //! [1]: https://w3.org/TR/mediacapture-streams#mediastreamconstraints
 
 use dart_sys::Dart_Handle;
-use derive_more::with_trait::From;
 use medea_macro::dart_bridge;
 
 use crate::{
@@ -13,9 +12,9 @@ use crate::{
     media::{
         DeviceAudioTrackConstraints, DeviceVideoTrackConstraints,
         DisplayAudioTrackConstraints, DisplayVideoTrackConstraints,
-        constraints::{ConstrainBoolean, ConstrainString, ConstrainU32},
+        constraints::{ConstrainU32, ConstrainU32Range, NativeConstraint},
     },
-    platform::dart::utils::handle::DartHandle,
+    platform::{Error, dart::utils::handle::DartHandle},
 };
 
 #[dart_bridge("flutter/lib/src/native/platform/constraints.g.dart")]
@@ -122,6 +121,22 @@ enum VideoConstraintKind {
     FrameRate = 4,
 }
 
+/// Width requested for a device video width range, mirroring the default
+/// camera mode of browsers.
+const DEFAULT_DEVICE_WIDTH: u32 = 640;
+
+/// Height requested for a device video height range, mirroring the default
+/// camera mode of browsers.
+const DEFAULT_DEVICE_HEIGHT: u32 = 480;
+
+/// Frame rate requested for a device video frame rate range, mirroring the
+/// default camera mode of browsers.
+const DEFAULT_DEVICE_FRAME_RATE: u32 = 30;
+
+/// Value requested for a display video range, meaning its maximum, as
+/// browsers capture a display in its full resolution.
+const DEFAULT_DISPLAY_VALUE: u32 = u32::MAX;
+
 /// Kind of a [MediaStreamConstraints.audio][0] setting.
 ///
 /// [0]: https://tinyurl.com/5bmrr4w5
@@ -163,29 +178,29 @@ pub struct MediaTrackConstraints {
 
 /// Dart side representation of [MediaStreamConstraints][0].
 ///
+/// Lowered into the Dart side constraints only when requested, as a single
+/// request may require several attempts (see
+/// [`MediaStreamConstraints::attempts()`]).
+///
 /// [0]: https://w3.org/TR/mediacapture-streams#dom-mediastreamconstraints
-#[derive(Clone, Debug, From)]
-pub struct MediaStreamConstraints(DartHandle);
-
-impl From<MediaStreamConstraints> for Dart_Handle {
-    fn from(from: MediaStreamConstraints) -> Self {
-        from.0.get()
-    }
-}
+#[derive(Clone, Debug, Default)]
+pub struct MediaStreamConstraints {
+    /// Constraints of an `audio` [MediaStreamTrack][1].
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    audio: Option<DeviceAudioTrackConstraints>,
 
-impl Default for MediaStreamConstraints {
-    fn default() -> Self {
-        Self::new()
-    }
+    /// Constraints of a `video` [MediaStreamTrack][1].
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    video: Option<DeviceVideoTrackConstraints>,
 }
 
 impl MediaStreamConstraints {
     /// Creates new empty [`MediaStreamConstraints`].
     #[must_use]
     pub fn new() -> Self {
-        let constraints =
-            unsafe { constraints::init_device_constraints() }.unwrap();
-        unsafe { Self(DartHandle::new(constraints)) }
+        Self::default()
     }
 
     /// Specifies the provided nature and settings of an `audio`
@@ -193,23 +208,7 @@ impl MediaStreamConstraints {
     ///
     /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
     pub fn audio(&mut self, audio: DeviceAudioTrackConstraints) {
-        let audio = MediaTrackConstraints::from(audio);
-        unsafe {
-            constraints::set_device_audio_constraint(
-                self.0.get(),
-                ConstraintType::Mandatory as i64,
-                audio.mandatory.get(),
-            )
-        }
-        .unwrap();
-        unsafe {
-            constraints::set_device_audio_constraint(
-                self.0.get(),
-                ConstraintType::Optional as i64,
-                audio.optional.get(),
-            )
-        }
-        .unwrap();
+        self.audio = Some(audio);
     }
 
     /// Specifies the provided nature and settings of a `video`
@@ -217,51 +216,93 @@ impl MediaStreamConstraints {
     ///
     /// [1]: https://w3.org/TR/mediacapture-streams/#mediastreamtrack
     pub fn video(&mut self, video: DeviceVideoTrackConstraints) {
-        let video = MediaTrackConstraints::from(video);
-        unsafe {
-            constraints::set_video_constraint(
-                self.0.get(),
-                ConstraintType::Mandatory as i64,
-                video.mandatory.get(),
-            )
+        self.video = Some(video);
+    }
+
+    /// Returns the number of attempts to be made for requesting media with
+    /// these [`MediaStreamConstraints`].
+    ///
+    /// `medea_flutter_webrtc` accepts only a single value per constraint, so
+    /// each range constraint is requested as several
+    /// [`ConstrainU32Range::native_candidates()`] one by one (see
+    /// [`MediaStreamConstraints::attempt()`]).
+    #[must_use]
+    pub fn attempts(&self) -> usize {
+        self.video.as_ref().map_or(1, |video| {
+            attempts([
+                (video.width, DEFAULT_DEVICE_WIDTH),
+                (video.height, DEFAULT_DEVICE_HEIGHT),
+                (video.frame_rate, DEFAULT_DEVICE_FRAME_RATE),
+            ])
+        })
+    }
+
+    /// Lowers these [`MediaStreamConstraints`] into the Dart side ones for the
+    /// provided attempt, requesting the `attempt`th
+    /// [`ConstrainU32Range::native_candidates()`] of each range constraint
+    /// (or the last one, if there are less of them).
+    #[must_use]
+    pub fn attempt(&self, attempt: usize) -> DartHandle {
+        let constraints =
+            unsafe { constraints::init_device_constraints() }.unwrap();
+        let constraints = unsafe { DartHandle::new(constraints) };
+
+        if let Some(audio) = &self.audio {
+            let audio = MediaTrackConstraints::device_audio(audio);
+            for (ty, c) in audio.by_type() {
+                unsafe {
+                    constraints::set_device_audio_constraint(
+                        constraints.get(),
+                        ty as i64,
+                        c.get(),
+                    )
+                }
+                .unwrap();
+            }
         }
-        .unwrap();
-        unsafe {
-            constraints::set_video_constraint(
-                self.0.get(),
-                ConstraintType::Optional as i64,
-                video.optional.get(),
-            )
+        if let Some(video) = &self.video {
+            let video = MediaTrackConstraints::device_video(video, attempt);
+            for (ty, c) in video.by_type() {
+                unsafe {
+                    constraints::set_video_constraint(
+                        constraints.get(),
+                        ty as i64,
+                        c.get(),
+                    )
+                }
+                .unwrap();
+            }
         }
-        .unwrap();
+
+        constraints
     }
 }
 
 /// Dart side representation of [DisplayMediaStreamConstraints][0].
 ///
+/// Lowered into the Dart side constraints only when requested, as a single
+/// request may require several attempts (see
+/// [`DisplayMediaStreamConstraints::attempts()`]).
+///
 /// [0]: https://w3.org/TR/screen-capture#dom-displaymediastreamconstraints
-#[derive(Clone, Debug, From)]
-pub struct DisplayMediaStreamConstraints(DartHandle);
-
-impl From<DisplayMediaStreamConstraints> for Dart_Handle {
-    fn from(from: DisplayMediaStreamConstraints) -> Self {
-        from.0.get()
-    }
-}
+#[derive(Clone, Debug, Default)]
+pub struct DisplayMediaStreamConstraints {
+    /// Constraints of an `audio` [MediaStreamTrack][1].
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    audio: Option<DisplayAudioTrackConstraints>,
 
-impl Default for DisplayMediaStreamConstraints {
-    fn default() -> Self {
-        Self::new()
-    }
+    /// Constraints of a `video` [MediaStreamTrack][1].
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    video: Option<DisplayVideoTrackConstraints>,
 }
 
 impl DisplayMediaStreamConstraints {
     /// Creates new empty [`DisplayMediaStreamConstraints`] .
     #[must_use]
     pub fn new() -> Self {
-        let constraints =
-            unsafe { constraints::init_display_constraints() }.unwrap();
-        unsafe { Self(DartHandle::new(constraints)) }
+        Self::default()
     }
 
     /// Specifies the provided nature and settings of a `video`
@@ -269,362 +310,278 @@ impl DisplayMediaStreamConstraints {
     ///
     /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
     pub fn video(&mut self, video: DisplayVideoTrackConstraints) {
-        let video = MediaTrackConstraints::from(video);
-        unsafe {
-            constraints::set_display_video_constraint(
-                self.0.get(),
-                ConstraintType::Mandatory as i64,
-                video.mandatory.get(),
-            )
-        }
-        .unwrap();
-        unsafe {
-            constraints::set_display_video_constraint(
-                self.0.get(),
-                ConstraintType::Optional as i64,
-                video.optional.get(),
-            )
-        }
-        .unwrap();
+        self.video = Some(video);
     }
 
     /// Specifies the provided nature and settings of an `audio`
     /// [MediaStreamTrack][1] to these [`DisplayMediaStreamConstraints`].
     ///
     /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
-    pub fn audio(&mut self, audio: DisplayAudioTrackConstraints) {
-        let audio = MediaTrackConstraints::from(audio);
-        unsafe {
-            constraints::set_display_audio_constraint(
-                self.0.get(),
-                ConstraintType::Mandatory as i64,
-                audio.mandatory.get(),
-            )
-        }
-        .unwrap();
-        unsafe {
-            constraints::set_display_audio_constraint(
-                self.0.get(),
-                ConstraintType::Optional as i64,
-                audio.optional.get(),
-            )
-        }
-        .unwrap();
+    pub const fn audio(&mut self, audio: DisplayAudioTrackConstraints) {
+        self.audio = Some(audio);
     }
-}
 
-#[expect(clippy::fallible_impl_from, reason = "FFI error is unexpected")]
-impl From<DeviceAudioTrackConstraints> for MediaTrackConstraints {
-    fn from(from: DeviceAudioTrackConstraints) -> Self {
-        let optional = {
-            let audio =
-                unsafe { constraints::new_audio_constraints() }.unwrap();
-            unsafe { DartHandle::new(audio) }
-        };
-        let mandatory = {
-            let audio =
-                unsafe { constraints::new_audio_constraints() }.unwrap();
-            unsafe { DartHandle::new(audio) }
-        };
-        let parse_bool_const = |c: ConstrainBoolean| -> (&DartHandle, bool) {
-            match c {
-                ConstrainBoolean::Exact(val) => (&mandatory, val),
-                ConstrainBoolean::Ideal(val) => (&optional, val),
-            }
-        };
+    /// Returns the number of attempts to be made for requesting media with
+    /// these [`DisplayMediaStreamConstraints`].
+    ///
+    /// See [`MediaStreamConstraints::attempts()`] for details.
+    #[must_use]
+    pub fn attempts(&self) -> usize {
+        self.video.as_ref().map_or(1, |video| {
+            attempts([
+                (video.width, DEFAULT_DISPLAY_VALUE),
+                (video.height, DEFAULT_DISPLAY_VALUE),
+                (video.frame_rate, DEFAULT_DISPLAY_VALUE),
+            ])
+        })
+    }
 
-        if let Some(device_id) = from.device_id {
-            match device_id {
-                ConstrainString::Exact(device_id) => unsafe {
-                    constraints::set_audio_constraint_value(
-                        mandatory.get(),
-                        AudioConstraintKind::DeviceId as i64,
-                        DartValue::from(device_id),
-                    )
-                }
-                .unwrap(),
-                ConstrainString::Ideal(device_id) => unsafe {
-                    constraints::set_audio_constraint_value(
-                        optional.get(),
-                        AudioConstraintKind::DeviceId as i64,
-                        DartValue::from(device_id),
+    /// Lowers these [`DisplayMediaStreamConstraints`] into the Dart side ones
+    /// for the provided attempt.
+    ///
+    /// See [`MediaStreamConstraints::attempt()`] for details.
+    #[must_use]
+    pub fn attempt(&self, attempt: usize) -> DartHandle {
+        let constraints =
+            unsafe { constraints::init_display_constraints() }.unwrap();
+        let constraints = unsafe { DartHandle::new(constraints) };
+
+        if let Some(video) = &self.video {
+            let video = MediaTrackConstraints::display_video(video, attempt);
+            for (ty, c) in video.by_type() {
+                unsafe {
+                    constraints::set_display_video_constraint(
+                        constraints.get(),
+                        ty as i64,
+                        c.get(),
                     )
                 }
-                .unwrap(),
-            }
-        }
-        if let Some(agc) = from.auto_gain_control {
-            let (kind, val) = parse_bool_const(agc);
-
-            unsafe {
-                constraints::set_audio_constraint_value(
-                    kind.get(),
-                    AudioConstraintKind::AutoGainControl as i64,
-                    DartValue::from(val),
-                )
-                .unwrap();
-            };
-        }
-        if let Some(aec) = from.echo_cancellation {
-            let (kind, val) = parse_bool_const(aec);
-
-            unsafe {
-                constraints::set_audio_constraint_value(
-                    kind.get(),
-                    AudioConstraintKind::EchoCancellation as i64,
-                    DartValue::from(val),
-                )
-                .unwrap();
-            }
-        }
-        if let Some(hpf) = from.high_pass_filter {
-            let (kind, val) = parse_bool_const(hpf);
-
-            unsafe {
-                constraints::set_audio_constraint_value(
-                    kind.get(),
-                    AudioConstraintKind::HighPassFilter as i64,
-                    DartValue::from(val),
-                )
-                .unwrap();
-            }
-        }
-        if let Some(ns) = from.noise_suppression {
-            let (kind, val) = parse_bool_const(ns);
-
-            unsafe {
-                constraints::set_audio_constraint_value(
-                    kind.get(),
-                    AudioConstraintKind::NoiseSuppression as i64,
-                    DartValue::from(val),
-                )
                 .unwrap();
             }
         }
-        if let Some(nsl) = from.noise_suppression_level {
-            unsafe {
-                constraints::set_audio_constraint_value(
-                    optional.get(),
-                    AudioConstraintKind::NoiseSuppressionLevel as i64,
-                    DartValue::from(nsl as i64),
-                )
+        if self.audio.is_some() {
+            let audio = MediaTrackConstraints::new_audio();
+            for (ty, c) in audio.by_type() {
+                unsafe {
+                    constraints::set_display_audio_constraint(
+                        constraints.get(),
+                        ty as i64,
+                        c.get(),
+                    )
+                }
                 .unwrap();
             }
         }
 
-        Self { optional, mandatory }
+        constraints
     }
 }
 
-#[expect(clippy::fallible_impl_from, reason = "FFI error is unexpected")]
-impl From<DisplayAudioTrackConstraints> for MediaTrackConstraints {
-    fn from(_: DisplayAudioTrackConstraints) -> Self {
-        let optional = {
-            let audio =
-                unsafe { constraints::new_audio_constraints() }.unwrap();
-            unsafe { DartHandle::new(audio) }
-        };
-        let mandatory = {
+impl MediaTrackConstraints {
+    /// Creates new empty [`MediaTrackConstraints`] of an `audio`
+    /// [MediaStreamTrack][1].
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    fn new_audio() -> Self {
+        let new = || {
             let audio =
                 unsafe { constraints::new_audio_constraints() }.unwrap();
             unsafe { DartHandle::new(audio) }
         };
-
-        Self { optional, mandatory }
+        Self { optional: new(), mandatory: new() }
     }
-}
 
-#[expect(clippy::fallible_impl_from, reason = "FFI error is unexpected")]
-impl From<DeviceVideoTrackConstraints> for MediaTrackConstraints {
-    fn from(from: DeviceVideoTrackConstraints) -> Self {
-        let optional = {
-            let video =
-                unsafe { constraints::new_video_constraints() }.unwrap();
-            unsafe { DartHandle::new(video) }
-        };
-        let mandatory = {
+    /// Creates new empty [`MediaTrackConstraints`] of a `video`
+    /// [MediaStreamTrack][1].
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    fn new_video() -> Self {
+        let new = || {
             let video =
                 unsafe { constraints::new_video_constraints() }.unwrap();
             unsafe { DartHandle::new(video) }
         };
+        Self { optional: new(), mandatory: new() }
+    }
 
-        if let Some(device_id) = from.device_id {
-            unsafe {
-                set_constrain_string(
-                    device_id,
-                    VideoConstraintKind::DeviceId,
-                    &optional,
-                    &mandatory,
-                );
-            }
+    /// Returns [`MediaTrackConstraints::mandatory`] and
+    /// [`MediaTrackConstraints::optional`] settings along with their
+    /// [`ConstraintType`]s.
+    const fn by_type(&self) -> [(ConstraintType, &DartHandle); 2] {
+        [
+            (ConstraintType::Mandatory, &self.mandatory),
+            (ConstraintType::Optional, &self.optional),
+        ]
+    }
+
+    /// Applies the provided [`NativeConstraint`] to the `audio` setting of the
+    /// specified [`AudioConstraintKind`].
+    fn set_audio<T>(&self, kind: AudioConstraintKind, c: NativeConstraint<T>)
+    where
+        DartValue: From<T>,
+    {
+        self.set(constraints::set_audio_constraint_value, kind as i64, c);
+    }
+
+    /// Applies the provided [`NativeConstraint`] to the `video` setting of the
+    /// specified [`VideoConstraintKind`].
+    fn set_video<T>(&self, kind: VideoConstraintKind, c: NativeConstraint<T>)
+    where
+        DartValue: From<T>,
+    {
+        self.set(constraints::set_video_constraint_value, kind as i64, c);
+    }
+
+    /// Applies the provided [`NativeConstraint`] via the provided Dart side
+    /// `setter` to either [`MediaTrackConstraints::mandatory`] or
+    /// [`MediaTrackConstraints::optional`] settings, according to its
+    /// necessity.
+    ///
+    /// `medea_flutter_webrtc` accepts only a single value per constraint, so
+    /// all the constraints are lowered into [`NativeConstraint`]s the same
+    /// way, mirroring their web representation.
+    fn set<T>(
+        &self,
+        setter: unsafe fn(Dart_Handle, i64, DartValue) -> Result<(), Error>,
+        kind: i64,
+        c: NativeConstraint<T>,
+    ) where
+        DartValue: From<T>,
+    {
+        let constraints =
+            if c.mandatory { &self.mandatory } else { &self.optional };
+        unsafe { setter(constraints.get(), kind, DartValue::from(c.value)) }
+            .unwrap();
+    }
+
+    /// Lowers the provided [`DeviceAudioTrackConstraints`] into
+    /// [`MediaTrackConstraints`].
+    fn device_audio(from: &DeviceAudioTrackConstraints) -> Self {
+        use AudioConstraintKind as Kind;
+
+        let this = Self::new_audio();
+        if let Some(device_id) = from.device_id.clone() {
+            this.set_audio(Kind::DeviceId, device_id.into());
         }
-        if let Some(facing_mode) = from.facing_mode {
-            match facing_mode {
-                ConstrainString::Exact(facing_mode) => unsafe {
-                    constraints::set_video_constraint_value(
-                        mandatory.get(),
-                        VideoConstraintKind::FacingMode as i64,
-                        DartValue::from(facing_mode as i64),
-                    )
-                }
-                .unwrap(),
-                ConstrainString::Ideal(facing_mode) => unsafe {
-                    constraints::set_video_constraint_value(
-                        optional.get(),
-                        VideoConstraintKind::FacingMode as i64,
-                        DartValue::from(facing_mode as i64),
-                    )
-                }
-                .unwrap(),
-            }
+        if let Some(agc) = from.auto_gain_control {
+            this.set_audio(Kind::AutoGainControl, agc.into());
         }
-        if let Some(width) = from.width {
-            unsafe {
-                set_video_constrain_u32(
-                    width,
-                    VideoConstraintKind::Width,
-                    &optional,
-                    &mandatory,
-                );
-            }
+        if let Some(aec) = from.echo_cancellation {
+            this.set_audio(Kind::EchoCancellation, aec.into());
         }
-        if let Some(height) = from.height {
-            unsafe {
-                set_video_constrain_u32(
-                    height,
-                    VideoConstraintKind::Height,
-                    &optional,
-                    &mandatory,
-                );
-            }
+        if let Some(hpf) = from.high_pass_filter {
+            this.set_audio(Kind::HighPassFilter, hpf.into());
         }
-
-        Self { optional, mandatory }
+        if let Some(ns) = from.noise_suppression {
+            this.set_audio(Kind::NoiseSuppression, ns.into());
+        }
+        if let Some(nsl) = from.noise_suppression_level {
+            // Noise suppression level has no web representation, so is never
+            // mandatory.
+            this.set_audio(
+                Kind::NoiseSuppressionLevel,
+                NativeConstraint { value: nsl as i64, mandatory: false },
+            );
+        }
+        this
     }
-}
 
-#[expect(clippy::fallible_impl_from, reason = "FFI error is unexpected")]
-impl From<DisplayVideoTrackConstraints> for MediaTrackConstraints {
-    fn from(from: DisplayVideoTrackConstraints) -> Self {
-        let optional = {
-            let video =
-                unsafe { constraints::new_video_constraints() }.unwrap();
-            unsafe { DartHandle::new(video) }
-        };
-        let mandatory = {
-            let video =
-                unsafe { constraints::new_video_constraints() }.unwrap();
-            unsafe { DartHandle::new(video) }
-        };
+    /// Lowers the provided [`DeviceVideoTrackConstraints`] into
+    /// [`MediaTrackConstraints`] for the provided attempt.
+    ///
+    /// See [`MediaStreamConstraints::attempt()`] for details.
+    fn device_video(from: &DeviceVideoTrackConstraints, attempt: usize) -> Self {
+        use VideoConstraintKind as Kind;
 
-        if let Some(device_id) = from.device_id {
-            unsafe {
-                set_constrain_string(
-                    device_id,
-                    VideoConstraintKind::DeviceId,
-                    &optional,
-                    &mandatory,
-                );
-            }
+        let this = Self::new_video();
+        if let Some(device_id) = from.device_id.clone() {
+            this.set_video(Kind::DeviceId, device_id.into());
+        }
+        if let Some(facing_mode) = from.facing_mode {
+            this.set_video(
+                Kind::FacingMode,
+                NativeConstraint::from(facing_mode).map(|m| m as i64),
+            );
         }
         if let Some(width) = from.width {
-            unsafe {
-                set_video_constrain_u32(
-                    width,
-                    VideoConstraintKind::Width,
-                    &optional,
-                    &mandatory,
-                );
-            }
+            this.set_video(
+                Kind::Width,
+                native_u32(width, DEFAULT_DEVICE_WIDTH, attempt),
+            );
         }
         if let Some(height) = from.height {
-            unsafe {
-                set_video_constrain_u32(
-                    height,
-                    VideoConstraintKind::Height,
-                    &optional,
-                    &mandatory,
-                );
-            }
+            this.set_video(
+                Kind::Height,
+                native_u32(height, DEFAULT_DEVICE_HEIGHT, attempt),
+            );
         }
         if let Some(frame_rate) = from.frame_rate {
-            unsafe {
-                set_video_constrain_u32(
-                    frame_rate,
-                    VideoConstraintKind::FrameRate,
-                    &optional,
-                    &mandatory,
-                );
-            }
+            this.set_video(
+                Kind::FrameRate,
+                native_u32(frame_rate, DEFAULT_DEVICE_FRAME_RATE, attempt),
+            );
         }
-
-        Self { optional, mandatory }
+        this
     }
-}
 
-/// Applies the specified [`ConstrainString`] to the provided  `optional` and
-/// `mandatory` [`DartHandle`]s representing the Dart side constraints.
-unsafe fn set_constrain_string<T>(
-    constrain: ConstrainString<T>,
-    kind: VideoConstraintKind,
-    optional: &DartHandle,
-    mandatory: &DartHandle,
-) where
-    DartValue: From<T>,
-{
-    match constrain {
-        ConstrainString::Exact(val) => unsafe {
-            constraints::set_video_constraint_value(
-                mandatory.get(),
-                kind as i64,
-                DartValue::from(val),
-            )
+    /// Lowers the provided [`DisplayVideoTrackConstraints`] into
+    /// [`MediaTrackConstraints`] for the provided attempt.
+    ///
+    /// See [`MediaStreamConstraints::attempt()`] for details.
+    fn display_video(
+        from: &DisplayVideoTrackConstraints,
+        attempt: usize,
+    ) -> Self {
+        use VideoConstraintKind as Kind;
+
+        let this = Self::new_video();
+        if let Some(device_id) = from.device_id.clone() {
+            this.set_video(Kind::DeviceId, device_id.into());
+        }
+        if let Some(width) = from.width {
+            this.set_video(
+                Kind::Width,
+                native_u32(width, DEFAULT_DISPLAY_VALUE, attempt),
+            );
         }
-        .unwrap(),
-        ConstrainString::Ideal(val) => unsafe {
-            constraints::set_video_constraint_value(
-                optional.get(),
-                kind as i64,
-                DartValue::from(val),
-            )
+        if let Some(height) = from.height {
+            this.set_video(
+                Kind::Height,
+                native_u32(height, DEFAULT_DISPLAY_VALUE, attempt),
+            );
         }
-        .unwrap(),
+        if let Some(frame_rate) = from.frame_rate {
+            this.set_video(
+                Kind::FrameRate,
+                native_u32(frame_rate, DEFAULT_DISPLAY_VALUE, attempt),
+            );
+        }
+        this
     }
 }
 
-/// Applies the specified [`ConstrainU32`] to the provided  `optional` and
-/// `mandatory` [`DartHandle`]s representing the Dart side constraints.
-unsafe fn set_video_constrain_u32(
+/// Returns the number of attempts required for requesting all the
+/// [`ConstrainU32Range::native_candidates()`] of the provided [`ConstrainU32`]s
+/// along with their `default` values.
+fn attempts<const N: usize>(constrains: [(Option<ConstrainU32>, u32); N]) -> usize {
+    constrains
+        .into_iter()
+        .filter_map(|(constrain, default)| {
+            let range = ConstrainU32Range::from(constrain?);
+            Some(range.native_candidates(default).len())
+        })
+        .max()
+        .unwrap_or(1)
+}
+
+/// Lowers the provided [`ConstrainU32`] into a [`NativeConstraint`] to be
+/// requested on the provided attempt, falling back to the last of its
+/// [`ConstrainU32Range::native_candidates()`] if there are less of them.
+fn native_u32(
     constrain: ConstrainU32,
-    kind: VideoConstraintKind,
-    optional: &DartHandle,
-    mandatory: &DartHandle,
-) {
-    match constrain {
-        ConstrainU32::Ideal(val) => unsafe {
-            constraints::set_video_constraint_value(
-                optional.get(),
-                kind as i64,
-                DartValue::from(val),
-            )
-        }
-        .unwrap(),
-        ConstrainU32::Exact(val) => unsafe {
-            constraints::set_video_constraint_value(
-                mandatory.get(),
-                kind as i64,
-                DartValue::from(val),
-            )
-        }
-        .unwrap(),
-        ConstrainU32::Range(min, _) => unsafe {
-            // TODO: Implement range constraints in `medea_flutter_webrtc`.
-            constraints::set_video_constraint_value(
-                mandatory.get(),
-                kind as i64,
-                DartValue::from(min),
-            )
-        }
-        .unwrap(),
-    }
+    default: u32,
+    attempt: usize,
+) -> NativeConstraint<u32> {
+    let candidates = ConstrainU32Range::from(constrain).native_candidates(default);
+    candidates[attempt.min(candidates.len() - 1)]
 }
@@ -5,7 +5,7 @@
 use medea_macro::dart_bridge;
 
 use crate::{
-    media::{AudioDeviceKind, MediaDeviceKind},
+    media::{AudioDeviceKind, DeviceCapabilities, MediaDeviceKind},
     platform::dart::utils::{
         NonNullDartValueArgExt as _, dart_string_into_rust, handle::DartHandle,
     },
@@ -150,6 +150,16 @@ impl MediaDeviceInfo {
                 .unwrap();
         AudioDeviceKind::try_from(v).ok()
     }
+
+    /// Returns [`DeviceCapabilities`] of the represented input device, without
+    /// opening it.
+    ///
+    /// Always [`None`], as `medea_flutter_webrtc` doesn't expose capabilities
+    /// of media devices.
+    #[must_use]
+    pub const fn capabilities(&self) -> Option<DeviceCapabilities> {
+        None
+    }
 }
 
 impl TryFrom<DartHandle> for MediaDeviceInfo {
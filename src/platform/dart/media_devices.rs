@@ -12,7 +12,9 @@ use super::{
     media_track::MediaStreamTrack,
 };
 use crate::{
-    media::MediaSourceKind,
+    media::{
+        MediaSourceKind, PermissionError, PermissionKind, PermissionState,
+    },
     platform::{
         Error, GetUserMediaError,
         dart::utils::{
@@ -80,12 +82,40 @@ mod media_devices {
         pub fn get_media_exception_kind(
             exception: Dart_Handle,
         ) -> Result<i64, Error>;
+
+        /// Returns the current state of the provided media permission kind.
+        pub fn permission_state(kind: i64) -> Result<Dart_Handle, Error>;
+
+        /// Subscribes onto the state changes of the provided media permission
+        /// kind, replacing the previous subscription.
+        pub fn on_permission_change(
+            kind: i64,
+            cb: Dart_Handle,
+        ) -> Result<(), Error>;
     }
 }
 
 #[expect(clippy::fallible_impl_from, reason = "FFI error is unexpected")]
 impl From<Error> for GetUserMediaError {
     fn from(err: Error) -> Self {
+        // `medea_flutter_webrtc` has no dedicated exception kinds for
+        // permission denials, so they can be only recognized by the message
+        // reported by the OS.
+        let message = err.message().to_lowercase();
+        let is_permission_denial = ["permission", "not authorized", "denied"]
+            .iter()
+            .any(|m| message.contains(m));
+        if is_permission_denial {
+            return if ["restricted", "system", "policy"]
+                .iter()
+                .any(|m| message.contains(m))
+            {
+                Self::PermissionDeniedBySystem(err)
+            } else {
+                Self::PermissionDeniedByUser(err)
+            };
+        }
+
         let kind = unsafe {
             media_devices::get_media_exception_kind(err.get_handle())
         }
@@ -171,11 +201,29 @@ impl MediaDevices {
         &self,
         caps: MediaStreamConstraints,
     ) -> Result<Vec<MediaStreamTrack>, Traced<GetUserMediaError>> {
-        let fut =
-            unsafe { media_devices::get_user_media(caps.into()) }.unwrap();
-        let tracks = unsafe { FutureFromDart::execute::<DartHandle>(fut) }
-            .await
-            .map_err(tracerr::from_and_wrap!())?;
+        let last = caps.attempts() - 1;
+        let mut attempt = 0;
+        let tracks = loop {
+            let fut = unsafe {
+                media_devices::get_user_media(caps.attempt(attempt).get())
+            }
+            .unwrap();
+            match unsafe { FutureFromDart::execute::<DartHandle>(fut) }.await {
+                Ok(tracks) => break tracks,
+                Err(e) => {
+                    let e = GetUserMediaError::from(e);
+                    // Range constraints are requested value by value, so the
+                    // next one is tried if the device doesn't fit.
+                    if attempt < last
+                        && matches!(e, GetUserMediaError::Video(_))
+                    {
+                        attempt += 1;
+                        continue;
+                    }
+                    return Err(tracerr::new!(e));
+                }
+            }
+        };
 
         let tracks = Vec::from(DartList::from(tracks))
             .into_iter()
@@ -204,11 +252,21 @@ impl MediaDevices {
         &self,
         caps: DisplayMediaStreamConstraints,
     ) -> Result<Vec<MediaStreamTrack>, Traced<Error>> {
-        let fut =
-            unsafe { media_devices::get_display_media(caps.into()) }.unwrap();
-        let tracks = unsafe { FutureFromDart::execute::<DartHandle>(fut) }
-            .await
-            .map_err(tracerr::wrap!())?;
+        let last = caps.attempts() - 1;
+        let mut attempt = 0;
+        let tracks = loop {
+            let fut = unsafe {
+                media_devices::get_display_media(caps.attempt(attempt).get())
+            }
+            .unwrap();
+            match unsafe { FutureFromDart::execute::<DartHandle>(fut) }.await {
+                Ok(tracks) => break tracks,
+                // Range constraints are requested value by value, so the next
+                // one is tried if the display doesn't fit.
+                Err(_) if attempt < last => attempt += 1,
+                Err(e) => return Err(tracerr::new!(e)),
+            }
+        };
 
         let tracks = Vec::from(DartList::from(tracks))
             .into_iter()
@@ -294,4 +352,59 @@ impl MediaDevices {
             .unwrap();
         }
     }
+
+    /// Returns the current [`PermissionState`] of the provided
+    /// [`PermissionKind`].
+    ///
+    /// `medea_flutter_webrtc` lacks a permissions API, so media permissions
+    /// are queried via the `medea_jason` Flutter plugin.
+    ///
+    /// # Errors
+    ///
+    /// With a [`PermissionError::QueryFailed`] if platform call errors.
+    pub async fn permission_state(
+        &self,
+        kind: PermissionKind,
+    ) -> Result<PermissionState, Traced<PermissionError>> {
+        let fut =
+            unsafe { media_devices::permission_state(kind as i64) }.unwrap();
+        let state = unsafe { FutureFromDart::execute::<i64>(fut) }
+            .await
+            .map_err(tracerr::from_and_wrap!())?;
+
+        Ok(PermissionState::try_from(state).unwrap())
+    }
+
+    /// Subscribes onto the [`PermissionState`] changes of the provided
+    /// [`PermissionKind`].
+    ///
+    /// Native platforms don't notify about media permissions changes, so the
+    /// `medea_jason` Flutter plugin re-queries them whenever the application
+    /// is resumed, as they can be changed in the system settings only.
+    ///
+    /// # Errors
+    ///
+    /// Never errors, but returns a [`Result`] for `cfg` code uniformity.
+    #[expect(clippy::unused_async, reason = "`cfg` code uniformity")]
+    pub async fn on_permission_change<F>(
+        &self,
+        kind: PermissionKind,
+        mut f: F,
+    ) -> Result<(), Traced<PermissionError>>
+    where
+        F: 'static + FnMut(PermissionState),
+    {
+        unsafe {
+            media_devices::on_permission_change(
+                kind as i64,
+                Callback::from_fn_mut(move |state: i64| {
+                    f(PermissionState::try_from(state).unwrap());
+                })
+                .into_dart(),
+            )
+        }
+        .unwrap();
+
+        Ok(())
+    }
 }
@@ -6,15 +6,20 @@ use dart_sys::Dart_Handle;
 use medea_macro::dart_bridge;
 
 use crate::{
+    api::DartValue,
     media::{
-        FacingMode, MediaKind, MediaSourceKind, NoiseSuppressionLevel,
+        ApplyConstraintsError, CameraControl, CameraControlError,
+        CameraSetting, DeviceCapabilities, FacingMode, MediaKind,
+        MediaSourceKind, NoiseSuppressionLevel, VideoSource,
         track::MediaStreamTrackState,
     },
     platform::{
         self,
-        dart::utils::{
-            NonNullDartValueArgExt as _, callback::Callback,
-            dart_string_into_rust, handle::DartHandle,
+        dart::{
+            utils::{
+                NonNullDartValueArgExt as _, callback::Callback,
+                dart_string_into_rust, handle::DartHandle,
+            },
         },
         utils::dart_future::FutureFromDart,
     },
@@ -26,7 +31,10 @@ mod media_stream_track {
 
     use dart_sys::Dart_Handle;
 
-    use crate::{api::DartValueArg, platform::Error};
+    use crate::{
+        api::{DartValue, DartValueArg},
+        platform::Error,
+    };
 
     extern "C" {
         /// Returns [ID][1] of the provided [MediaStreamTrack][0].
@@ -237,6 +245,25 @@ mod media_stream_track {
         pub fn is_high_pass_filter_enabled(
             track: Dart_Handle,
         ) -> Result<Dart_Handle, Error>;
+
+        /// Indicates whether the camera producing the provided
+        /// [MediaStreamTrack][0] supports the provided `CameraControl`.
+        ///
+        /// [0]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+        pub fn is_camera_control_available(
+            track: Dart_Handle,
+            control: i64,
+        ) -> Result<bool, Error>;
+
+        /// Sets the provided value of the provided `CameraControl` for the
+        /// camera producing the provided [MediaStreamTrack][0].
+        ///
+        /// [0]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+        pub fn set_camera_control(
+            track: Dart_Handle,
+            control: i64,
+            value: DartValue,
+        ) -> Result<Dart_Handle, Error>;
     }
 }
 
@@ -499,6 +526,26 @@ impl MediaStreamTrack {
         .unwrap()
     }
 
+    /// Applies the provided [`VideoSource`] constraints to this live
+    /// [`MediaStreamTrack`].
+    ///
+    /// `medea_flutter_webrtc` lacks [applyConstraints()][1], so constraints of
+    /// a live [`MediaStreamTrack`] cannot be changed, and its media source
+    /// should be re-acquired instead.
+    ///
+    /// # Errors
+    ///
+    /// Always errors with an [`ApplyConstraintsError::NotSupported`].
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-applyconstraints
+    #[expect(clippy::unused_async, reason = "`cfg` code uniformity")]
+    pub async fn apply_constraints(
+        &self,
+        _: VideoSource,
+    ) -> Result<(), ApplyConstraintsError> {
+        Err(ApplyConstraintsError::NotSupported)
+    }
+
     /// Toggles noise suppression for this [`MediaStreamTrack`].
     ///
     /// # Errors
@@ -673,6 +720,94 @@ impl MediaStreamTrack {
         }?;
         unsafe { FutureFromDart::execute::<bool>(fut) }.await
     }
+
+    /// Indicates whether the provided [`CameraControl`] is supported by the
+    /// camera producing this [`MediaStreamTrack`].
+    ///
+    /// `medea_flutter_webrtc` lacks camera controls, so they're provided by the
+    /// `medea_jason` Flutter plugin, supporting only [`CameraControl::Zoom`]
+    /// and [`CameraControl::Torch`] on iOS at the moment.
+    #[must_use]
+    pub fn is_camera_control_available(&self, control: CameraControl) -> bool {
+        unsafe {
+            media_stream_track::is_camera_control_available(
+                self.inner.get(),
+                control as i64,
+            )
+        }
+        .unwrap()
+    }
+
+    /// Sets the provided [`CameraSetting`] for the camera producing this
+    /// [`MediaStreamTrack`].
+    ///
+    /// # Errors
+    ///
+    /// - With a [`CameraControlError::NotSupported`] if the camera doesn't
+    ///   support the [`CameraControl`].
+    /// - With a [`CameraControlError::Failed`] if platform call errors.
+    pub async fn set_camera_control(
+        &self,
+        setting: CameraSetting,
+    ) -> Result<(), CameraControlError> {
+        let control = setting.control();
+        if !self.is_camera_control_available(control) {
+            return Err(CameraControlError::NotSupported);
+        }
+
+        let value = match setting {
+            CameraSetting::Zoom(val)
+            | CameraSetting::FocusDistance(val)
+            | CameraSetting::ExposureCompensation(val) => DartValue::from(val),
+            CameraSetting::Torch(enabled) => DartValue::from(enabled),
+            CameraSetting::FocusMode(mode)
+            | CameraSetting::WhiteBalanceMode(mode) => {
+                DartValue::from(mode as i64)
+            }
+        };
+        let fut = unsafe {
+            media_stream_track::set_camera_control(
+                self.inner.get(),
+                control as i64,
+                value,
+            )
+        }?;
+        unsafe { FutureFromDart::execute::<()>(fut) }.await?;
+
+        Ok(())
+    }
+
+    /// Returns [`DeviceCapabilities`] of the device producing this
+    /// [`MediaStreamTrack`].
+    ///
+    /// `medea_flutter_webrtc` lacks [getCapabilities()][1], so the supported
+    /// video ranges are not reported, and only the current facing mode of this
+    /// [`MediaStreamTrack`] is, while audio processing toggles are reported as
+    /// supported if audio processing is available for it.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-getcapabilities
+    #[expect(clippy::unused_async, reason = "`cfg` code uniformity")]
+    pub async fn capabilities(&self) -> DeviceCapabilities {
+        match self.kind() {
+            MediaKind::Video => DeviceCapabilities {
+                facing_modes: self.facing_mode().into_iter().collect(),
+                ..DeviceCapabilities::default()
+            },
+            MediaKind::Audio => {
+                let toggles = if self.is_audio_processing_available() {
+                    vec![true, false]
+                } else {
+                    Vec::new()
+                };
+                DeviceCapabilities {
+                    echo_cancellation: toggles.clone(),
+                    auto_gain_control: toggles.clone(),
+                    noise_suppression: toggles,
+                    ..DeviceCapabilities::default()
+                }
+            }
+        }
+    }
 }
 
 impl Drop for MediaStreamTrack {
@@ -21,15 +21,23 @@ pub mod media_device_info;
 pub mod media_devices;
 pub mod media_display_info;
 pub mod media_track;
+pub mod network_monitor;
 pub mod peer_connection;
 pub mod rtc_stats;
 pub mod send_encoding_parameters;
 pub mod send_parameters;
+pub mod sse_transport;
 pub mod transceiver;
 pub mod transport;
 pub mod utils;
 
-use std::{cell::RefCell, mem::ManuallyDrop, panic, sync::Once};
+use std::{
+    cell::RefCell,
+    mem::ManuallyDrop,
+    panic,
+    sync::{LazyLock, Once},
+    time::{Duration, Instant},
+};
 
 use libc::c_void;
 use log::LevelFilter;
@@ -43,8 +51,10 @@ pub use self::{
     media_devices::MediaDevices,
     media_display_info::MediaDisplayInfo,
     media_track::MediaStreamTrack,
+    network_monitor::NetworkMonitor,
     peer_connection::RtcPeerConnection,
     rtc_stats::RtcStats,
+    sse_transport::SseRpcTransport,
     transceiver::{Transceiver, TransceiverInit},
     transport::WebSocketRpcTransport,
     utils::{Function, completer::delay_for},
@@ -133,3 +143,13 @@ where
     log::set_max_level(level);
     logging::set_log_level(level).await;
 }
+
+/// Returns a monotonic timestamp of the current moment, measured from an
+/// arbitrary fixed point in time.
+#[must_use]
+pub fn now() -> Duration {
+    /// Fixed point in time the returned timestamps are measured from.
+    static EPOCH: LazyLock<Instant> = LazyLock::new(Instant::now);
+
+    EPOCH.elapsed()
+}
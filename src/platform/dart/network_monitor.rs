@@ -0,0 +1,39 @@
+//! Monitoring of the network connectivity of a device.
+
+use std::rc::Rc;
+
+use tracerr::Traced;
+
+use crate::platform::{Error, NetworkState};
+
+/// Monitor of the [`NetworkState`] of a device.
+///
+/// Flutter has no built-in connectivity events, so this monitor doesn't
+/// observe anything by itself. Instead, an application is expected to report
+/// [`NetworkState`] changes, detected by a connectivity plugin of its choice,
+/// via the `Jason.setNetworkOnline()` method.
+#[derive(Clone, Copy, Debug)]
+pub struct NetworkMonitor;
+
+impl NetworkMonitor {
+    /// Starts a new [`NetworkMonitor`].
+    ///
+    /// The provided callback is never called, since [`NetworkState`] changes
+    /// are reported by an application directly.
+    ///
+    /// # Errors
+    ///
+    /// Never errors, returns [`Result`] for `cfg` code uniformity.
+    pub fn start(_: Rc<dyn Fn(NetworkState)>) -> Result<Self, Traced<Error>> {
+        Ok(Self)
+    }
+
+    /// Returns the last known [`NetworkState`] of the device.
+    ///
+    /// Always returns [`NetworkState::Online`], since no connectivity
+    /// information is available until it's reported by an application.
+    #[must_use]
+    pub const fn current_state() -> NetworkState {
+        NetworkState::Online
+    }
+}
@@ -129,6 +129,14 @@ mod peer_connection {
             is_force_relayed: bool,
         ) -> Result<Dart_Handle, Error>;
 
+        /// Replaces `iceServers` of the provided [`PeerConnection`]'s
+        /// configuration with the provided ones.
+        pub fn set_configuration(
+            peer: Dart_Handle,
+            ice_servers: Dart_Handle,
+            is_force_relayed: bool,
+        ) -> Result<Dart_Handle, Error>;
+
         /// Creates a new [`Transceiver`] in the provided [`PeerConnection`].
         pub fn add_transceiver(
             peer: Dart_Handle,
@@ -423,6 +431,39 @@ impl RtcPeerConnection {
         Ok(())
     }
 
+    /// Replaces [`IceServer`]s of this [`RtcPeerConnection`] with the provided
+    /// ones, preserving its [ICE transport policy][2].
+    ///
+    /// # Errors
+    ///
+    /// With [`RtcPeerConnectionError::SetConfigurationFailed`] if
+    /// [RtcPeerConnection.setConfiguration()][1] fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcpeerconnection-setconfiguration
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcconfiguration-icetransportpolicy
+    pub async fn set_ice_servers<I>(
+        &self,
+        ice_servers: I,
+        is_force_relayed: bool,
+    ) -> RtcPeerConnectionResult<()>
+    where
+        I: IntoIterator<Item = IceServer>,
+    {
+        let ice_servers = RtcIceServers::from(ice_servers);
+        let fut = unsafe {
+            peer_connection::set_configuration(
+                self.handle.get(),
+                ice_servers.get_handle(),
+                is_force_relayed,
+            )
+        }
+        .unwrap();
+        unsafe { FutureFromDart::execute::<()>(fut) }.await.map_err(|e| {
+            tracerr::new!(RtcPeerConnectionError::SetConfigurationFailed(e))
+        })?;
+        Ok(())
+    }
+
     /// Marks [`RtcPeerConnection`] to trigger ICE restart.
     ///
     /// After this function returns, the offer returned by the next call to
@@ -0,0 +1,261 @@
+//! [Server-Sent Events] fallback [`RpcTransport`] implementation.
+//!
+//! [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::{Rc, Weak},
+};
+
+use async_trait::async_trait;
+use futures::{channel::mpsc, prelude::stream::LocalBoxStream};
+use medea_client_api_proto::{ClientMsg, ServerMsg, WireFormat};
+use medea_macro::dart_bridge;
+use medea_reactive::ObservableCell;
+use tracerr::Traced;
+
+use crate::{
+    platform::{
+        RpcTransport, TransportError, TransportState,
+        dart::utils::{
+            callback::Callback, dart_future::FutureFromDart,
+            handle::DartHandle, string_into_c_str,
+        },
+        transport::Frame,
+    },
+    rpc::{ApiUrl, ClientDisconnect, CloseMsg},
+};
+
+type TransportResult<T> = Result<T, Traced<TransportError>>;
+
+/// Close code reported when the [Server-Sent Events] connection is lost
+/// abnormally.
+///
+/// [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+const ABNORMAL_CLOSE_CODE: u16 = 1006;
+
+#[dart_bridge("flutter/lib/src/native/platform/sse_transport.g.dart")]
+mod sse_transport {
+    use std::{os::raw::c_char, ptr};
+
+    use dart_sys::Dart_Handle;
+
+    use crate::platform::Error;
+
+    extern "C" {
+        /// Opens a [Server-Sent Events] stream from the provided `url` sending
+        /// the provided `headers` (newline-separated `Name: value` pairs), and
+        /// returns the created `SseConnection` once a session ID is received.
+        ///
+        /// Passes data of all the received unnamed events to the provided
+        /// `on_message` callback, and data of a `close` event (or an empty
+        /// string, if the stream is lost abnormally) to the provided
+        /// `on_close` callback.
+        ///
+        /// [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+        pub fn connect(
+            url: ptr::NonNull<c_char>,
+            headers: ptr::NonNull<c_char>,
+            on_message: Dart_Handle,
+            on_close: Dart_Handle,
+        ) -> Result<Dart_Handle, Error>;
+
+        /// Sends the provided `message` via an HTTP `POST` request of the
+        /// provided `SseConnection`, preserving the order of sent messages.
+        pub fn send(
+            connection: Dart_Handle,
+            message: ptr::NonNull<c_char>,
+        ) -> Result<(), Error>;
+
+        /// Closes the provided `SseConnection`, notifying a server with the
+        /// provided `close_msg`.
+        pub fn close(
+            connection: Dart_Handle,
+            close_msg: ptr::NonNull<c_char>,
+        ) -> Result<(), Error>;
+    }
+}
+
+/// [`RpcTransport`] implementation over [Server-Sent Events] and HTTP `POST`
+/// requests, performed by a Dart side `SseConnection`.
+///
+/// Used as a fallback in networks blocking [WebSocket] connections.
+///
+/// [Server-Sent Events]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+/// [WebSocket]: https://api.dart.dev/stable/dart-io/WebSocket-class.html
+#[derive(Clone, Debug)]
+pub struct SseRpcTransport {
+    /// Handle to the Dart side `SseConnection`.
+    ///
+    /// If [`DartHandle`] is [`None`], then connection hasn't been instantiated
+    /// yet.
+    handle: RefCell<Option<DartHandle>>,
+
+    /// Subscribers to the messages received by this transport.
+    on_message_subs: Rc<RefCell<Vec<mpsc::UnboundedSender<ServerMsg>>>>,
+
+    /// Reason of [`SseRpcTransport`] closing.
+    ///
+    /// Is sent to a server in a separate HTTP `POST` request.
+    close_reason: Cell<ClientDisconnect>,
+
+    /// State of this [`SseRpcTransport`] connection.
+    state: Rc<ObservableCell<TransportState>>,
+}
+
+impl SseRpcTransport {
+    /// Creates a new [`SseRpcTransport`] which can be connected to the server
+    /// with the [`RpcTransport::connect()`] method call.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            handle: RefCell::new(None),
+            on_message_subs: Rc::new(RefCell::new(Vec::new())),
+            state: Rc::new(ObservableCell::new(TransportState::Connecting)),
+            close_reason: Cell::new(
+                ClientDisconnect::RpcTransportUnexpectedlyDropped,
+            ),
+        }
+    }
+}
+
+/// Decodes the provided [JSON] message and sends the decoded [`ServerMsg`] to
+/// the provided subscribers, if they are still alive.
+///
+/// [JSON]: https://json.org
+fn dispatch_message(
+    subs: &Weak<RefCell<Vec<mpsc::UnboundedSender<ServerMsg>>>>,
+    msg: String,
+) {
+    let Some(subs) = subs.upgrade() else {
+        return;
+    };
+    let msg = match Frame::Text(msg).decode() {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            log::error!("{}", tracerr::new!(e));
+            return;
+        }
+    };
+
+    subs.borrow_mut().retain(|sub: &mpsc::UnboundedSender<ServerMsg>| {
+        sub.unbounded_send(msg.clone()).is_ok()
+    });
+}
+
+impl Default for SseRpcTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait(?Send)]
+impl RpcTransport for SseRpcTransport {
+    async fn connect(&self, url: ApiUrl) -> TransportResult<()> {
+        let url = url.to_http();
+        // TODO: Propagate execution error.
+        #[expect(clippy::map_err_ignore, reason = "needs refactoring")]
+        let handle = {
+            let on_message = Callback::from_fn_mut({
+                let weak_subs = Rc::downgrade(&self.on_message_subs);
+                move |msg: String| dispatch_message(&weak_subs, msg)
+            })
+            .into_dart();
+            let on_close = Callback::from_fn_mut({
+                let state = Rc::clone(&self.state);
+                move |reason: String| {
+                    let msg = if reason.is_empty() {
+                        CloseMsg::Abnormal(ABNORMAL_CLOSE_CODE)
+                    } else {
+                        CloseMsg::from((1000, reason))
+                    };
+                    state.set(TransportState::Closed(msg));
+                }
+            })
+            .into_dart();
+
+            let headers = url
+                .headers()
+                .iter()
+                .map(|(name, value)| format!("{name}: {value}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let fut = unsafe {
+                sse_transport::connect(
+                    string_into_c_str(url.as_ref().to_owned()),
+                    string_into_c_str(headers),
+                    on_message,
+                    on_close,
+                )
+            }
+            .unwrap();
+            unsafe { FutureFromDart::execute::<DartHandle>(fut) }
+        }
+        .await
+        .map_err(|_| tracerr::new!(TransportError::InitSocket))?;
+
+        *self.handle.borrow_mut() = Some(handle);
+        self.state.set(TransportState::Open);
+
+        Ok(())
+    }
+
+    fn on_message(&self) -> LocalBoxStream<'static, ServerMsg> {
+        let (tx, rx) = mpsc::unbounded();
+        self.on_message_subs.borrow_mut().push(tx);
+        Box::pin(rx)
+    }
+
+    fn set_close_reason(&self, reason: ClientDisconnect) {
+        self.close_reason.set(reason);
+    }
+
+    fn send(&self, msg: &ClientMsg) -> TransportResult<()> {
+        let state = self.state.get();
+        let handle = self
+            .handle
+            .borrow()
+            .as_ref()
+            .cloned()
+            .ok_or_else(|| tracerr::new!(TransportError::ClosedSocket))?;
+        match state {
+            TransportState::Open => {
+                let Frame::Text(text) = Frame::encode(msg, WireFormat::Json)
+                    .map_err(tracerr::wrap!())?
+                else {
+                    unreachable!("`WireFormat::Json` is always encoded as text")
+                };
+                unsafe {
+                    sse_transport::send(handle.get(), string_into_c_str(text))
+                }
+                .map_err(TransportError::SendMessage)
+                .map_err(tracerr::wrap!())
+            }
+            TransportState::Connecting
+            | TransportState::Closing
+            | TransportState::Closed(_) => {
+                Err(tracerr::new!(TransportError::ClosedSocket))
+            }
+        }
+    }
+
+    fn on_state_change(&self) -> LocalBoxStream<'static, TransportState> {
+        self.state.subscribe()
+    }
+}
+
+impl Drop for SseRpcTransport {
+    fn drop(&mut self) {
+        if !self.state.get().can_close() {
+            return;
+        }
+        let rsn = serde_json::to_string(&self.close_reason.get())
+            .unwrap_or_else(|e| panic!("cannot serialize close message: {e}"));
+        if let Some(handle) = self.handle.borrow().as_ref() {
+            unsafe {
+                sse_transport::close(handle.get(), string_into_c_str(rsn))
+            }
+            .unwrap();
+        }
+    }
+}
@@ -1,11 +1,14 @@
 use std::{
     cell::{Cell, RefCell},
-    rc::Rc,
+    ptr,
+    rc::{Rc, Weak},
 };
 
 use async_trait::async_trait;
 use futures::{channel::mpsc, prelude::stream::LocalBoxStream};
-use medea_client_api_proto::{ClientMsg, ServerMsg};
+use medea_client_api_proto::{
+    ClientMsg, CloseReason as CloseByServerReason, ServerMsg, WireFormat,
+};
 use medea_macro::dart_bridge;
 use medea_reactive::ObservableCell;
 use tracerr::Traced;
@@ -17,6 +20,7 @@ use crate::{
             callback::Callback, dart_future::FutureFromDart,
             dart_string_into_rust, handle::DartHandle, string_into_c_str,
         },
+        transport::Frame,
     },
     rpc::{ApiUrl, ClientDisconnect, CloseMsg},
 };
@@ -32,21 +36,35 @@ mod transport {
     use crate::platform::Error;
 
     extern "C" {
-        /// [Connects][1] to the provided `url` and returns the created
+        /// [Connects][1] to the provided `url` offering the provided
+        /// comma-separated `protocols` and sending the provided `headers`
+        /// (newline-separated `Name: value` pairs), and returns the created
         /// [`WebSocket`][0].
         ///
         /// [Subscribes][2] to the created [`WebSocket`][0] passing the given
-        /// `on_message` and `on_close` callbacks.
+        /// `on_message`, `on_binary_message` and `on_close` callbacks.
         ///
         /// [0]: https://api.dart.dev/stable/dart-io/WebSocket-class.html
         /// [1]: https://api.dart.dev/stable/dart-io/WebSocket/connect.html
         /// [2]: https://api.dart.dev/stable/dart-async/Stream/listen.html
         pub fn connect(
             url: ptr::NonNull<c_char>,
+            protocols: ptr::NonNull<c_char>,
+            headers: ptr::NonNull<c_char>,
             on_message: Dart_Handle,
+            on_binary_message: Dart_Handle,
             on_close: Dart_Handle,
         ) -> Result<Dart_Handle, Error>;
 
+        /// Returns the [protocol][1] selected by the server for the provided
+        /// [`WebSocket`][0], or an empty string if none was selected.
+        ///
+        /// [0]: https://api.dart.dev/stable/dart-io/WebSocket-class.html
+        /// [1]: https://api.dart.dev/stable/dart-io/WebSocket/protocol.html
+        pub fn protocol(
+            transport: Dart_Handle,
+        ) -> Result<ptr::NonNull<c_char>, Error>;
+
         /// [Sends][1] the provided `message` via the provided [`WebSocket`][0].
         ///
         /// [0]: https://api.dart.dev/stable/dart-io/WebSocket-class.html
@@ -56,6 +74,34 @@ mod transport {
             message: ptr::NonNull<c_char>,
         ) -> Result<(), Error>;
 
+        /// [Sends][1] the provided binary `message` of the provided `len` via
+        /// the provided [`WebSocket`][0].
+        ///
+        /// [0]: https://api.dart.dev/stable/dart-io/WebSocket-class.html
+        /// [1]: https://api.dart.dev/stable/dart-io/WebSocket/add.html
+        pub fn send_binary(
+            transport: Dart_Handle,
+            message: ptr::NonNull<u8>,
+            len: u32,
+        ) -> Result<(), Error>;
+
+        /// Returns the length of the provided binary message received via a
+        /// [`WebSocket`][0].
+        ///
+        /// [0]: https://api.dart.dev/stable/dart-io/WebSocket-class.html
+        pub fn binary_message_len(message: Dart_Handle) -> Result<u32, Error>;
+
+        /// Copies the provided binary message received via a
+        /// [`WebSocket`][0] into the provided `dst` buffer of the provided
+        /// `len`.
+        ///
+        /// [0]: https://api.dart.dev/stable/dart-io/WebSocket-class.html
+        pub fn copy_binary_message(
+            message: Dart_Handle,
+            dst: ptr::NonNull<u8>,
+            len: u32,
+        ) -> Result<(), Error>;
+
         /// [Closes][1] the provided [`WebSocket`][0] connection.
         ///
         /// [0]: https://api.dart.dev/stable/dart-io/WebSocket-class.html
@@ -103,10 +149,13 @@ pub struct WebSocketRpcTransport {
     /// Is sent in a [WebSocket close frame][1].
     ///
     /// [1]: https://tools.ietf.org/html/rfc6455#section-5.5.1
-    close_reason: Cell<ClientDisconnect>,
+    close_reason: Rc<Cell<ClientDisconnect>>,
 
     /// State of this [`WebSocketRpcTransport`] connection.
     socket_state: Rc<ObservableCell<TransportState>>,
+
+    /// [`WireFormat`] negotiated with the server.
+    wire_format: Cell<WireFormat>,
 }
 
 impl WebSocketRpcTransport {
@@ -120,13 +169,77 @@ impl WebSocketRpcTransport {
             socket_state: Rc::new(ObservableCell::new(
                 TransportState::Connecting,
             )),
-            close_reason: Cell::new(
+            close_reason: Rc::new(Cell::new(
                 ClientDisconnect::RpcTransportUnexpectedlyDropped,
-            ),
+            )),
+            wire_format: Cell::new(WireFormat::Json),
         }
     }
 }
 
+/// Receiver of [`Frame`]s of a [`WebSocketRpcTransport`].
+#[derive(Debug)]
+struct FrameDispatcher {
+    /// Subscribers to the messages received by the [`WebSocketRpcTransport`].
+    subs: Weak<RefCell<Vec<mpsc::UnboundedSender<ServerMsg>>>>,
+
+    /// State of the [`WebSocketRpcTransport`] connection.
+    socket_state: Rc<ObservableCell<TransportState>>,
+
+    /// Reason of the [`WebSocketRpcTransport`] closing.
+    close_reason: Rc<Cell<ClientDisconnect>>,
+}
+
+impl FrameDispatcher {
+    /// Creates a new [`FrameDispatcher`] of the provided
+    /// [`WebSocketRpcTransport`].
+    fn new(transport: &WebSocketRpcTransport) -> Self {
+        Self {
+            subs: Rc::downgrade(&transport.on_message_subs),
+            socket_state: Rc::clone(&transport.socket_state),
+            close_reason: Rc::clone(&transport.close_reason),
+        }
+    }
+
+    /// Decodes the provided [`Frame`] and sends the decoded [`ServerMsg`] to
+    /// the subscribers, if they are still alive.
+    ///
+    /// If the [`Frame`] cannot be decoded, then moves the
+    /// [`WebSocketRpcTransport`] to the [`TransportState::Closed`] with the
+    /// [`CloseByServerReason::IncompatibleProtocol`], so the `Room` is closed
+    /// the same way as if the server rejected the client protocol, and the
+    /// underlying [`WebSocket`][0] is closed with the
+    /// [`ClientDisconnect::IncompatibleProtocol`] once the
+    /// [`WebSocketRpcTransport`] is dropped.
+    ///
+    /// [0]: https://api.dart.dev/stable/dart-io/WebSocket-class.html
+    fn dispatch(&self, frame: &Frame) {
+        let Some(subs) = self.subs.upgrade() else {
+            return;
+        };
+        let msg = match frame.decode() {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                log::error!("{}", tracerr::new!(e));
+                if self.socket_state.get().can_close() {
+                    self.close_reason.set(ClientDisconnect::IncompatibleProtocol);
+                    self.socket_state.set(TransportState::Closed(
+                        CloseMsg::Normal(
+                            ClientDisconnect::IncompatibleProtocol.code(),
+                            CloseByServerReason::IncompatibleProtocol,
+                        ),
+                    ));
+                }
+                return;
+            }
+        };
+
+        subs.borrow_mut().retain(|sub: &mpsc::UnboundedSender<ServerMsg>| {
+            sub.unbounded_send(msg.clone()).is_ok()
+        });
+    }
+}
+
 impl Default for WebSocketRpcTransport {
     fn default() -> Self {
         Self::new()
@@ -140,32 +253,39 @@ impl RpcTransport for WebSocketRpcTransport {
         #[expect(clippy::map_err_ignore, reason = "needs refactoring")]
         let handle = {
             let on_message = Callback::from_fn_mut({
-                let weak_subs = Rc::downgrade(&self.on_message_subs);
+                let dispatcher = FrameDispatcher::new(self);
                 move |msg: String| {
-                    if let Some(subs) = weak_subs.upgrade() {
-                        let msg = match serde_json::from_str::<ServerMsg>(&msg)
-                        {
-                            Ok(parsed) => parsed,
-                            Err(e) => {
-                                // TODO: Protocol versions mismatch?
-                                //       Should drop connection if so.
-                                log::error!("{}", tracerr::new!(e));
-                                return;
-                            }
-                        };
-
-                        subs.borrow_mut().retain(
-                            |sub: &mpsc::UnboundedSender<ServerMsg>| {
-                                sub.unbounded_send(msg.clone()).is_ok()
-                            },
-                        );
+                    dispatcher.dispatch(&Frame::Text(msg));
+                }
+            })
+            .into_dart();
+            let on_binary_message = Callback::from_fn_mut({
+                let dispatcher = FrameDispatcher::new(self);
+                move |msg: DartHandle| {
+                    let len =
+                        unsafe { transport::binary_message_len(msg.get()) }
+                            .unwrap();
+                    let mut bytes = vec![0; len as usize];
+                    unsafe {
+                        transport::copy_binary_message(
+                            msg.get(),
+                            ptr::NonNull::from(bytes.as_mut_slice()).cast(),
+                            len,
+                        )
                     }
+                    .unwrap();
+                    dispatcher.dispatch(&Frame::Binary(bytes));
                 }
             })
             .into_dart();
             let on_close = Callback::from_fn_mut({
                 let socket_state = Rc::clone(&self.socket_state);
                 move |close_frame: DartHandle| {
+                    if !socket_state.get().can_close() {
+                        // Already closed, for example, because of an
+                        // incompatible protocol.
+                        return;
+                    }
                     let code =
                         unsafe { transport::close_code(close_frame.get()) }
                             .unwrap()
@@ -183,10 +303,24 @@ impl RpcTransport for WebSocketRpcTransport {
             })
             .into_dart();
 
+            let protocols = WireFormat::ALL
+                .iter()
+                .map(|f| f.subprotocol())
+                .collect::<Vec<_>>()
+                .join(",");
+            let headers = url
+                .headers()
+                .iter()
+                .map(|(name, value)| format!("{name}: {value}"))
+                .collect::<Vec<_>>()
+                .join("\n");
             let fut = unsafe {
                 transport::connect(
                     string_into_c_str(url.as_ref().to_owned()),
+                    string_into_c_str(protocols),
+                    string_into_c_str(headers),
                     on_message,
+                    on_binary_message,
                     on_close,
                 )
             }
@@ -196,6 +330,9 @@ impl RpcTransport for WebSocketRpcTransport {
         .await
         .map_err(|_| tracerr::new!(TransportError::InitSocket))?;
 
+        let protocol = unsafe { transport::protocol(handle.get()) }.unwrap();
+        let protocol = unsafe { dart_string_into_rust(protocol) };
+        self.wire_format.set(WireFormat::from_subprotocol(&protocol));
         *self.handle.borrow_mut() = Some(handle);
         self.socket_state.set(TransportState::Open);
 
@@ -212,7 +349,6 @@ impl RpcTransport for WebSocketRpcTransport {
         self.close_reason.set(reason);
     }
 
-    #[expect(clippy::unwrap_in_result, reason = "unrelated")]
     fn send(&self, msg: &ClientMsg) -> TransportResult<()> {
         let state = self.socket_state.get();
         let handle = self
@@ -222,13 +358,32 @@ impl RpcTransport for WebSocketRpcTransport {
             .cloned()
             .ok_or_else(|| tracerr::new!(TransportError::ClosedSocket))?;
         match state {
-            TransportState::Open => unsafe {
-                let msg = serde_json::to_string(msg).unwrap();
-                transport::send(handle.get(), string_into_c_str(msg))
-                    .map_err(TransportError::SendMessage)
-                    .map_err(tracerr::wrap!())?;
+            TransportState::Open => {
+                match Frame::encode(msg, self.wire_format.get())
+                    .map_err(tracerr::wrap!())?
+                {
+                    Frame::Text(text) => unsafe {
+                        transport::send(handle.get(), string_into_c_str(text))
+                    },
+                    Frame::Binary(mut bytes) => {
+                        let Ok(len) = u32::try_from(bytes.len()) else {
+                            return Err(tracerr::new!(
+                                TransportError::MessageTooLarge(bytes.len())
+                            ));
+                        };
+                        unsafe {
+                            transport::send_binary(
+                                handle.get(),
+                                ptr::NonNull::from(bytes.as_mut_slice()).cast(),
+                                len,
+                            )
+                        }
+                    }
+                }
+                .map_err(TransportError::SendMessage)
+                .map_err(tracerr::wrap!())?;
                 Ok(())
-            },
+            }
             TransportState::Connecting
             | TransportState::Closing
             | TransportState::Closed(_) => {
@@ -1,7 +1,9 @@
 //! Platform-specific functionality.
 
 pub mod callback;
+pub mod clock;
 pub mod codec_capability;
+pub mod network;
 pub mod peer_connection;
 pub mod rtc_stats;
 pub mod transceiver;
@@ -16,13 +18,13 @@ pub use self::dart::*;
 mod wasm;
 use derive_more::with_trait::Display;
 
-#[cfg(feature = "mockable")]
-pub use self::transport::MockRpcTransport;
 #[cfg(target_family = "wasm")]
 pub use self::wasm::*;
 pub use self::{
     callback::Callback,
+    clock::{Clock, Instant, RealClock, clock},
     codec_capability::get_capabilities,
+    network::NetworkState,
     peer_connection::{
         IceCandidate, IceCandidateError, IceGatheringState,
         RtcPeerConnectionError, SdpType,
@@ -30,7 +32,14 @@ pub use self::{
     rtc_stats::RtcStatsError,
     send_encoding_parameters::SendEncodingParameters,
     transceiver::Direction as TransceiverDirection,
-    transport::{RpcTransport, TransportError, TransportState},
+    transport::{
+        RpcTransport, RpcTransportKind, TransportError, TransportState,
+    },
+};
+#[cfg(feature = "mockable")]
+pub use self::{
+    clock::{ManualClock, set_clock},
+    transport::MockRpcTransport,
 };
 use crate::utils::Caused;
 
@@ -50,6 +59,14 @@ pub enum GetUserMediaError {
     /// [`Error`] has been caused by getting video.
     Video(Error),
 
+    /// [`Error`] has been caused by a user denying the media permission.
+    PermissionDeniedByUser(Error),
+
+    /// [`Error`] has been caused by a system denying the media permission (for
+    /// example, by an OS privacy settings or a browser policy), so a user
+    /// wasn't even prompted.
+    PermissionDeniedBySystem(Error),
+
     /// Cause cannot be identified.
     Unknown(Error),
 }
@@ -0,0 +1,18 @@
+//! Platform-agnostic functionality of network state monitoring.
+
+use derive_more::with_trait::Display;
+
+/// State of the network connectivity of a device, as seen by the library.
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+#[repr(u8)]
+pub enum NetworkState {
+    /// Device is connected to a network.
+    Online = 0,
+
+    /// Device is disconnected from any network.
+    Offline = 1,
+
+    /// Application is frozen (for example, a web page is frozen by a browser
+    /// in background), so its timers and network activity are suspended.
+    Frozen = 2,
+}
@@ -163,6 +163,12 @@ pub enum RtcPeerConnectionError {
     #[from(ignore)]
     GetStatsException(platform::Error),
 
+    /// Occurs if a new configuration cannot be applied to the
+    /// [`platform::RtcPeerConnection`].
+    #[display("Failed to set PeerConnection configuration: {_0}")]
+    #[from(ignore)]
+    SetConfigurationFailed(platform::Error),
+
     /// Occurs if the local description associated with the
     /// [`platform::RtcPeerConnection`] cannot be changed.
     #[display("Failed to set local SDP description: {_0}")]
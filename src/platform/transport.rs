@@ -2,16 +2,22 @@
 
 #![cfg_attr(feature = "mockable", expect(unused_lifetimes, reason = "codegen"))]
 
+use std::{error::Error as StdError, fmt, iter};
+
 use async_trait::async_trait;
 use derive_more::with_trait::Display;
 use futures::stream::LocalBoxStream;
-use medea_client_api_proto::{ClientMsg, ServerMsg};
+use medea_client_api_proto::{ClientMsg, Event, RoomId, ServerMsg, WireFormat};
+use serde::{
+    Deserialize,
+    de::{self, value::MapDeserializer},
+};
 use tracerr::Traced;
 
 use crate::{
     platform,
     rpc::{ApiUrl, ClientDisconnect, CloseMsg},
-    utils::{Caused, JsonParseError},
+    utils::{Caused, CborParseError, JsonParseError},
 };
 
 /// Possible states of a [`RpcTransport`].
@@ -41,6 +47,26 @@ impl TransportState {
     }
 }
 
+/// Kinds of [`RpcTransport`]s.
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum RpcTransportKind {
+    /// [WebSocket] transport.
+    ///
+    /// [WebSocket]: https://developer.mozilla.org/docs/Web/API/WebSocket
+    #[display("WebSocket")]
+    WebSocket,
+
+    /// [Server-Sent Events] transport, sending [`ClientMsg`]s via separate
+    /// HTTP `POST` requests.
+    ///
+    /// Used as a fallback in networks blocking [WebSocket] connections.
+    ///
+    /// [Server-Sent Events]: https://developer.mozilla.org/docs/Web/API/Server-sent_events
+    /// [WebSocket]: https://developer.mozilla.org/docs/Web/API/WebSocket
+    #[display("SSE")]
+    Sse,
+}
+
 /// RPC transport between a client and a server.
 #[async_trait(?Send)]
 #[cfg_attr(feature = "mockable", mockall::automock)]
@@ -87,6 +113,161 @@ pub trait RpcTransport {
     fn on_state_change(&self) -> LocalBoxStream<'static, TransportState>;
 }
 
+/// Single WebSocket [data frame][1] of a [`RpcTransport`].
+///
+/// [1]: https://tools.ietf.org/html/rfc6455#section-5.6
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Frame {
+    /// Text frame.
+    Text(String),
+
+    /// Binary frame.
+    Binary(Vec<u8>),
+}
+
+impl Frame {
+    /// Encodes the provided [`ClientMsg`] into a [`Frame`] of the provided
+    /// [`WireFormat`].
+    ///
+    /// # Errors
+    ///
+    /// With [`TransportError::SerializeClientMessage`] or
+    /// [`TransportError::SerializeBinaryClientMessage`] if the [`ClientMsg`]
+    /// cannot be serialized.
+    pub fn encode(
+        msg: &ClientMsg,
+        format: WireFormat,
+    ) -> Result<Self, TransportError> {
+        match format {
+            WireFormat::Json => serde_json::to_string(msg)
+                .map(Self::Text)
+                .map_err(|e| TransportError::SerializeClientMessage(e.into())),
+            WireFormat::Cbor => {
+                let mut buf = Vec::new();
+                ciborium::into_writer(msg, &mut buf).map_err(|e| {
+                    TransportError::SerializeBinaryClientMessage(e.into())
+                })?;
+                Ok(Self::Binary(buf))
+            }
+        }
+    }
+
+    /// Decodes a [`ServerMsg`] from this [`Frame`].
+    ///
+    /// [`Frame::Text`] is always decoded as [JSON], and [`Frame::Binary`] is
+    /// always decoded as [CBOR], regardless of the negotiated [`WireFormat`].
+    ///
+    /// [`ServerMsg::Event`]s which [`Event`] name cannot be recognized are
+    /// decoded as an [`Event::Unknown`] rather than failing.
+    ///
+    /// # Errors
+    ///
+    /// With [`TransportError::ParseServerMessage`] or
+    /// [`TransportError::ParseBinaryServerMessage`] if the [`ServerMsg`]
+    /// cannot be parsed, including malformed known [`Event`]s.
+    ///
+    /// [CBOR]: https://cbor.io
+    /// [JSON]: https://json.org
+    pub fn decode(&self) -> Result<ServerMsg, TransportError> {
+        match self {
+            Self::Text(text) => serde_json::from_str(text).or_else(|e| {
+                serde_json::from_str::<UnknownEventMsg>(text)
+                    .ok()
+                    .filter(UnknownEventMsg::is_unknown)
+                    .map(ServerMsg::from)
+                    .ok_or_else(|| TransportError::ParseServerMessage(e.into()))
+            }),
+            Self::Binary(bytes) => ciborium::from_reader(bytes.as_slice())
+                .or_else(|e| {
+                    ciborium::from_reader::<UnknownEventMsg, _>(
+                        bytes.as_slice(),
+                    )
+                    .ok()
+                    .filter(UnknownEventMsg::is_unknown)
+                    .map(ServerMsg::from)
+                    .ok_or_else(|| {
+                        TransportError::ParseBinaryServerMessage(e.into())
+                    })
+                }),
+        }
+    }
+}
+
+/// [`ServerMsg::Event`] carrying an [`Event`] which cannot be recognized.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "msg", content = "data")]
+enum UnknownEventMsg {
+    /// [`ServerMsg::Event`] with an unrecognized [`Event`].
+    Event {
+        /// ID of the `Room` the unrecognized [`Event`] is associated with.
+        room_id: RoomId,
+
+        /// Unrecognized [`Event`].
+        event: UnknownEvent,
+    },
+}
+
+impl UnknownEventMsg {
+    /// Indicates whether the name of the carried [`Event`] is not known to
+    /// this client.
+    ///
+    /// Known [`Event`]s failed to be parsed are malformed rather than unknown.
+    fn is_unknown(&self) -> bool {
+        let Self::Event { event, .. } = self;
+        let tag = MapDeserializer::<_, ProbeError>::new(iter::once((
+            "event",
+            event.event.as_str(),
+        )));
+        matches!(Event::deserialize(tag), Err(ProbeError::UnknownVariant))
+    }
+}
+
+/// [`Event`] which cannot be recognized, with its name only.
+#[derive(Debug, Deserialize)]
+struct UnknownEvent {
+    /// Name of the unrecognized [`Event`].
+    event: String,
+}
+
+/// [`de::Error`] distinguishing an unknown [`Event`] name from any other
+/// deserialization failure.
+#[derive(Debug)]
+enum ProbeError {
+    /// [`Event`] name is unknown.
+    UnknownVariant,
+
+    /// Any other deserialization failure.
+    Other,
+}
+
+impl fmt::Display for ProbeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownVariant => write!(f, "unknown variant"),
+            Self::Other => write!(f, "deserialization failed"),
+        }
+    }
+}
+
+impl StdError for ProbeError {}
+
+impl de::Error for ProbeError {
+    fn custom<T: fmt::Display>(_: T) -> Self {
+        Self::Other
+    }
+
+    fn unknown_variant(_: &str, _: &'static [&'static str]) -> Self {
+        Self::UnknownVariant
+    }
+}
+
+impl From<UnknownEventMsg> for ServerMsg {
+    fn from(msg: UnknownEventMsg) -> Self {
+        let UnknownEventMsg::Event { room_id, event } = msg;
+        Self::Event { room_id, event: Event::Unknown { event: event.event } }
+    }
+}
+
 /// Errors that may occur when working with a [`RpcTransport`].
 #[derive(Caused, Clone, Debug, Display, PartialEq)]
 #[cause(error = platform::Error)]
@@ -103,11 +284,21 @@ pub enum TransportError {
     #[display("Failed to parse client message: {_0}")]
     SerializeClientMessage(JsonParseError),
 
+    /// Occurs when [`ClientMsg`] cannot be serialized into a binary
+    /// [`WireFormat`].
+    #[display("Failed to serialize client message: {_0}")]
+    SerializeBinaryClientMessage(CborParseError),
+
     /// Occurs when [`ServerMsg`] cannot be parsed.
     #[display("Failed to parse server message: {_0}")]
     ParseServerMessage(JsonParseError),
 
-    /// Occurs if the parsed message is not string.
+    /// Occurs when [`ServerMsg`] cannot be parsed from a binary
+    /// [`WireFormat`].
+    #[display("Failed to parse binary server message: {_0}")]
+    ParseBinaryServerMessage(CborParseError),
+
+    /// Occurs if the received message is neither a string nor a binary one.
     #[display("Message is not a string")]
     MessageNotString,
 
@@ -115,6 +306,10 @@ pub enum TransportError {
     #[display("Failed to send message: {_0}")]
     SendMessage(platform::Error),
 
+    /// Occurs when a binary message is too large to be sent to server.
+    #[display("Message of {_0} bytes is too large to be sent")]
+    MessageTooLarge(usize),
+
     /// Occurs when message is sent to a closed socket.
     #[display("Underlying socket is closed")]
     ClosedSocket,
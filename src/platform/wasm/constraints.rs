@@ -10,7 +10,9 @@ use web_sys::{
 use crate::media::{
     DeviceAudioTrackConstraints, DeviceVideoTrackConstraints,
     DisplayAudioTrackConstraints, DisplayVideoTrackConstraints,
-    constraints::{ConstrainBoolean, ConstrainString, ConstrainU32},
+    constraints::{
+        ConstrainBoolean, ConstrainString, ConstrainU32, ConstrainU32Range,
+    },
 };
 
 /// [MediaStreamConstraints][1] wrapper.
@@ -95,6 +97,9 @@ impl From<DeviceVideoTrackConstraints> for MediaTrackConstraints {
         if let Some(height) = track_constraints.height {
             constraints.set_height(&ConstrainDoubleRange::from(height));
         }
+        if let Some(frame_rate) = track_constraints.frame_rate {
+            constraints.set_frame_rate(&ConstrainDoubleRange::from(frame_rate));
+        }
 
         constraints
     }
@@ -102,14 +107,19 @@ impl From<DeviceVideoTrackConstraints> for MediaTrackConstraints {
 
 impl From<ConstrainU32> for ConstrainDoubleRange {
     fn from(from: ConstrainU32) -> Self {
+        let range = ConstrainU32Range::from(from);
         let constraint = Self::new();
-        match from {
-            ConstrainU32::Exact(val) => constraint.set_exact(f64::from(val)),
-            ConstrainU32::Ideal(val) => constraint.set_ideal(f64::from(val)),
-            ConstrainU32::Range(min, max) => {
-                constraint.set_min(f64::from(min));
-                constraint.set_max(f64::from(max));
-            }
+        if let Some(exact) = range.exact {
+            constraint.set_exact(f64::from(exact));
+        }
+        if let Some(ideal) = range.ideal {
+            constraint.set_ideal(f64::from(ideal));
+        }
+        if let Some(min) = range.min {
+            constraint.set_min(f64::from(min));
+        }
+        if let Some(max) = range.max {
+            constraint.set_max(f64::from(max));
         }
         constraint
     }
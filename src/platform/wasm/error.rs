@@ -19,6 +19,12 @@ impl Error {
     pub fn message(&self) -> String {
         self.0.message().into()
     }
+
+    /// Returns a name of the error's type (for example, `NotAllowedError`).
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.0.name().into()
+    }
 }
 
 impl From<JsValue> for Error {
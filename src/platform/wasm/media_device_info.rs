@@ -3,8 +3,13 @@
 //! [1]: https://w3.org/TR/mediacapture-streams#device-info
 
 use derive_more::with_trait::From;
+use js_sys::{Function, Reflect};
+use wasm_bindgen::{JsCast as _, JsValue};
 
-use crate::media::MediaDeviceKind;
+use crate::{
+    media::{DeviceCapabilities, MediaDeviceKind},
+    platform::wasm::media_track::parse_capabilities,
+};
 
 /// Representation of a [MediaDeviceInfo][1].
 ///
@@ -63,4 +68,22 @@ impl MediaDeviceInfo {
     pub fn group_id(&self) -> Option<String> {
         Some(self.0.group_id())
     }
+
+    /// Returns [`DeviceCapabilities`] of the represented input device, without
+    /// opening it.
+    ///
+    /// Adapter for the [InputDeviceInfo.getCapabilities()][1] function.
+    /// Returns [`None`] if it's not supported by the browser.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-inputdeviceinfo-getcapabilities
+    #[must_use]
+    pub fn capabilities(&self) -> Option<DeviceCapabilities> {
+        let get_capabilities =
+            Reflect::get(&self.0, &JsValue::from_str("getCapabilities"))
+                .ok()?
+                .dyn_into::<Function>()
+                .ok()?;
+        let caps = get_capabilities.call0(&self.0).ok()?;
+        Some(parse_capabilities(&caps))
+    }
 }
@@ -4,17 +4,30 @@
 
 #![expect(clippy::unwrap_used, reason = "JS interop error is unexpected")]
 
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::{Rc, Weak},
+};
 
+use futures::future;
+use js_sys::Reflect;
+use medea_reactive::ObservableCell;
 use tracerr::Traced;
+use wasm_bindgen::{JsCast as _, JsValue};
 use wasm_bindgen_futures::JsFuture;
-use web_sys::{Event, MediaDevices as SysMediaDevices};
+use web_sys::{
+    Event, HtmlMediaElement, MediaDevices as SysMediaDevices, PermissionStatus,
+};
 
 use super::window;
 use crate::{
-    media::{InvalidOutputAudioDeviceIdError, MediaSourceKind, MicVolumeError},
+    media::{
+        InvalidOutputAudioDeviceIdError, MediaDeviceKind, MediaSourceKind,
+        MicVolumeError, PermissionError, PermissionKind, PermissionState,
+    },
     platform::{
-        DisplayMediaStreamConstraints, Error, GetUserMediaError,
+        self, DisplayMediaStreamConstraints, Error, GetUserMediaError,
         MediaDeviceInfo, MediaDisplayInfo, MediaStreamConstraints,
         MediaStreamTrack, utils::EventListener,
     },
@@ -23,8 +36,18 @@ use crate::{
 impl From<Error> for GetUserMediaError {
     fn from(err: Error) -> Self {
         let message = err.message().to_lowercase();
+        let name = err.name();
 
-        if message.contains("audio") {
+        // Browsers report OS-level denials as `NotAllowedError` with
+        // a "Permission denied by system" message, while the legacy
+        // `SecurityError` is reported when the media is disabled by policy.
+        if name == "SecurityError"
+            || (name == "NotAllowedError" && message.contains("system"))
+        {
+            Self::PermissionDeniedBySystem(err)
+        } else if name == "NotAllowedError" {
+            Self::PermissionDeniedByUser(err)
+        } else if message.contains("audio") {
             Self::Audio(err)
         } else if message.contains("video") {
             Self::Video(err)
@@ -44,6 +67,40 @@ pub struct MediaDevices {
     /// [`SysMediaDevices`].
     on_device_change_listener:
         RefCell<Option<EventListener<SysMediaDevices, Event>>>,
+
+    /// [`EventListener`]s for the `change` events of the [PermissionStatus][1]
+    /// of every observed [`PermissionKind`].
+    ///
+    /// [1]: https://w3.org/TR/permissions#dom-permissionstatus
+    on_permission_change_listeners: RefCell<
+        HashMap<PermissionKind, EventListener<PermissionStatus, Event>>,
+    >,
+
+    /// [HTMLMediaElement][1]s playing remote audio, registered via
+    /// [`MediaDevices::register_audio_sink()`].
+    ///
+    /// All of them are switched by the
+    /// [`MediaDevices::set_output_audio_id()`] to the same audio output
+    /// device.
+    ///
+    /// Held strongly until unregistered via
+    /// [`MediaDevices::unregister_audio_sink()`].
+    ///
+    /// [1]: https://html.spec.whatwg.org/#htmlmediaelement
+    audio_sinks: RefCell<Vec<HtmlMediaElement>>,
+
+    /// ID of the audio output device set via
+    /// [`MediaDevices::set_output_audio_id()`], if any.
+    output_audio_id: RefCell<Option<String>>,
+
+    /// Microphone volume level in percents, applied to the local audio
+    /// [`MediaStreamTrack`]s captured by these [`MediaDevices`] afterwards.
+    mic_volume: Cell<i64>,
+
+    /// Microphone volume levels of the live local audio
+    /// [`MediaStreamTrack`]s captured by these [`MediaDevices`], each one
+    /// shared by a [`MediaStreamTrack`] with its forks.
+    track_mic_volumes: RefCell<Vec<Weak<ObservableCell<i64>>>>,
 }
 
 impl Default for MediaDevices {
@@ -69,6 +126,11 @@ impl MediaDevices {
         Self {
             devices: Rc::new(devices),
             on_device_change_listener: RefCell::new(None),
+            on_permission_change_listeners: RefCell::new(HashMap::new()),
+            audio_sinks: RefCell::new(Vec::new()),
+            output_audio_id: RefCell::new(None),
+            mic_volume: Cell::new(100),
+            track_mic_volumes: RefCell::new(Vec::new()),
         }
     }
 
@@ -156,7 +218,15 @@ impl MediaDevices {
         Ok(stream
             .get_tracks()
             .into_iter()
-            .map(|tr| MediaStreamTrack::new(tr, Some(MediaSourceKind::Device)))
+            .map(|tr| {
+                let volume =
+                    Rc::new(ObservableCell::new(self.mic_volume.get()));
+                self.track_mic_volumes
+                    .borrow_mut()
+                    .push(Rc::downgrade(&volume));
+                MediaStreamTrack::new(tr, Some(MediaSourceKind::Device))
+                    .with_mic_volume(volume)
+            })
             .collect())
     }
 
@@ -206,25 +276,88 @@ impl MediaDevices {
             .collect())
     }
 
-    /// This method should be unreachable, because this functional is
-    /// implemented on the Dart side of Jason only.
+    /// Switches all the registered audio sinks to the audio output device
+    /// with the provided `device_id`.
     ///
-    /// # Errors
+    /// Audio sinks registered afterwards via
+    /// [`MediaDevices::register_audio_sink()`] are switched to this device as
+    /// well.
     ///
-    /// Never.
+    /// Adapter for the [HTMLMediaElement.setSinkId()][1] function.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Always.
-    #[expect(clippy::unused_async, reason = "`cfg` code uniformity")]
+    /// With [`InvalidOutputAudioDeviceIdError`] if there is no audio output
+    /// device with the provided `device_id`, or [setSinkId()][1] fails.
+    ///
+    /// [1]: https://w3.org/TR/audio-output#dom-htmlmediaelement-setsinkid
     pub async fn set_output_audio_id(
         &self,
-        _: String,
+        device_id: String,
     ) -> Result<(), Traced<InvalidOutputAudioDeviceIdError>> {
-        unreachable!(
-            "`set_output_audio_id()` is implemented on the Dart side, \
-             so this method call is unreachable",
+        #[expect(clippy::map_err_ignore, reason = "not useful")]
+        let is_known = self
+            .enumerate_devices()
+            .await
+            .map_err(|_| tracerr::new!(InvalidOutputAudioDeviceIdError))?
+            .into_iter()
+            .any(|d| {
+                d.kind() == MediaDeviceKind::AudioOutput
+                    && d.device_id() == device_id
+            });
+        if !is_known {
+            return Err(tracerr::new!(InvalidOutputAudioDeviceIdError));
+        }
+
+        let sinks = self.audio_sinks.borrow().clone();
+        #[expect(clippy::map_err_ignore, reason = "not useful")]
+        future::try_join_all(
+            sinks.iter().map(|s| JsFuture::from(s.set_sink_id(&device_id))),
         )
+        .await
+        .map_err(|_| tracerr::new!(InvalidOutputAudioDeviceIdError))?;
+
+        drop(self.output_audio_id.borrow_mut().replace(device_id));
+
+        Ok(())
+    }
+
+    /// Registers the provided [HTMLMediaElement][1] playing remote audio, so
+    /// it's switched by the [`MediaDevices::set_output_audio_id()`].
+    ///
+    /// If an audio output device has been already set, then the provided
+    /// [HTMLMediaElement][1] is switched to it right away.
+    ///
+    /// Remote audio sinks are never registered by Jason itself, only by the
+    /// application, and are strongly referenced until
+    /// [`MediaDevices::unregister_audio_sink()`] is called.
+    ///
+    /// [1]: https://html.spec.whatwg.org/#htmlmediaelement
+    pub fn register_audio_sink(&self, sink: HtmlMediaElement) {
+        let mut sinks = self.audio_sinks.borrow_mut();
+        if sinks.contains(&sink) {
+            return;
+        }
+        if let Some(id) = self.output_audio_id.borrow().as_deref() {
+            let set_sink_id = JsFuture::from(sink.set_sink_id(id));
+            platform::spawn(async move {
+                if let Err(e) = set_sink_id.await {
+                    log::error!(
+                        "Failed to set audio output device: {}",
+                        Error::from(e),
+                    );
+                }
+            });
+        }
+        sinks.push(sink);
+    }
+
+    /// Unregisters the provided [HTMLMediaElement][1], previously registered
+    /// via [`MediaDevices::register_audio_sink()`].
+    ///
+    /// [1]: https://html.spec.whatwg.org/#htmlmediaelement
+    pub fn unregister_audio_sink(&self, sink: &HtmlMediaElement) {
+        self.audio_sinks.borrow_mut().retain(|s| s != sink);
     }
 
     /// Subscribes onto the [`MediaDevices`]'s `devicechange` event.
@@ -250,49 +383,171 @@ impl MediaDevices {
         }
     }
 
-    /// Always returns `false` since accessing microphone cannot be implemented
-    /// on web platform.
+    /// Always returns `true`, since microphone volume is applied to the local
+    /// audio [`MediaStreamTrack`]s via a [GainNode][1].
+    ///
+    /// [1]: https://w3.org/TR/webaudio#GainNode
     #[expect(clippy::unused_async, reason = "`cfg` code uniformity")]
     pub async fn microphone_volume_is_available(&self) -> bool {
-        false
+        true
     }
 
-    /// This method should be unreachable, because cannot be implemented on web
-    /// platform.
+    /// Returns the microphone volume level in percents, applied to the local
+    /// audio [`MediaStreamTrack`]s captured by these [`MediaDevices`].
     ///
     /// # Errors
     ///
     /// Never.
-    ///
-    /// # Panics
-    ///
-    /// Always.
     #[expect(clippy::unused_async, reason = "`cfg` code uniformity")]
     pub async fn microphone_volume(
         &self,
     ) -> Result<i64, Traced<MicVolumeError>> {
-        unreachable!(
-            "`microphone_volume()` cannot be implemented on web platform",
-        )
+        Ok(self.mic_volume.get())
     }
 
-    /// This method should be unreachable, because cannot be implemented on web
-    /// platform.
+    /// Sets the microphone volume level in percents, applying it to all the
+    /// live local audio [`MediaStreamTrack`]s captured by these
+    /// [`MediaDevices`], and to the ones captured afterwards.
     ///
-    /// # Errors
+    /// The volume of a single [`MediaStreamTrack`] can be changed via
+    /// [`MediaStreamTrack::set_microphone_volume()`] afterwards.
     ///
-    /// Never.
+    /// The provided `level` is clamped to the `[0;100]` range.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Always.
+    /// Never.
     #[expect(clippy::unused_async, reason = "`cfg` code uniformity")]
     pub async fn set_microphone_volume(
         &self,
-        _: i64,
+        level: i64,
     ) -> Result<(), Traced<MicVolumeError>> {
-        unreachable!(
-            "`set_microphone_volume()` cannot be implemented on web platform",
-        )
+        let level = level.clamp(0, 100);
+        self.mic_volume.set(level);
+        self.track_mic_volumes.borrow_mut().retain(|volume| {
+            let Some(volume) = volume.upgrade() else {
+                return false;
+            };
+            volume.set(level);
+            true
+        });
+        Ok(())
+    }
+
+    /// Returns the current [`PermissionState`] of the provided
+    /// [`PermissionKind`].
+    ///
+    /// Adapter for the [Permissions.query()][1] function.
+    ///
+    /// # Errors
+    ///
+    /// With [`PermissionError::QueryFailed`] if [Permissions.query()][1]
+    /// returns error, for example, if the provided [`PermissionKind`] is not
+    /// supported by the browser.
+    ///
+    /// [1]: https://w3.org/TR/permissions#dom-permissions-query
+    pub async fn permission_state(
+        &self,
+        kind: PermissionKind,
+    ) -> Result<PermissionState, Traced<PermissionError>> {
+        let status = query_permission(kind)
+            .await
+            .map_err(tracerr::map_from_and_wrap!())?;
+        Ok(parse_permission_state(status.state()))
+    }
+
+    /// Subscribes onto the `change` event of the [PermissionStatus][1] of the
+    /// provided [`PermissionKind`].
+    ///
+    /// # Errors
+    ///
+    /// With [`PermissionError::QueryFailed`] if [Permissions.query()][2]
+    /// returns error, for example, if the provided [`PermissionKind`] is not
+    /// supported by the browser.
+    ///
+    /// # Panics
+    ///
+    /// If `change` event listener binding fails.
+    ///
+    /// [1]: https://w3.org/TR/permissions#dom-permissionstatus
+    /// [2]: https://w3.org/TR/permissions#dom-permissions-query
+    pub async fn on_permission_change<F>(
+        &self,
+        kind: PermissionKind,
+        mut f: F,
+    ) -> Result<(), Traced<PermissionError>>
+    where
+        F: 'static + FnMut(PermissionState),
+    {
+        let status = Rc::new(
+            query_permission(kind)
+                .await
+                .map_err(tracerr::map_from_and_wrap!())?,
+        );
+        let listener =
+            EventListener::new_mut(Rc::clone(&status), "change", move |_| {
+                f(parse_permission_state(status.state()))
+            })
+            .unwrap();
+        drop(
+            self.on_permission_change_listeners
+                .borrow_mut()
+                .insert(kind, listener),
+        );
+
+        Ok(())
+    }
+}
+
+/// Queries the [PermissionStatus][1] of the provided [`PermissionKind`].
+///
+/// # Errors
+///
+/// With [`Error`] if [Permissions API][2] is not available, or
+/// [Permissions.query()][3] returns error.
+///
+/// [1]: https://w3.org/TR/permissions#dom-permissionstatus
+/// [2]: https://w3.org/TR/permissions
+/// [3]: https://w3.org/TR/permissions#dom-permissions-query
+async fn query_permission(
+    kind: PermissionKind,
+) -> Result<PermissionStatus, Traced<Error>> {
+    let permissions = window()
+        .navigator()
+        .permissions()
+        .map_err(Error::from)
+        .map_err(tracerr::wrap!())?;
+
+    let descriptor = js_sys::Object::new();
+    Reflect::set(
+        &descriptor,
+        &JsValue::from_str("name"),
+        &JsValue::from_str(&kind.to_string()),
+    )
+    .map_err(Error::from)
+    .map_err(tracerr::wrap!())?;
+
+    let status = JsFuture::from(
+        permissions
+            .query(&descriptor)
+            .map_err(Error::from)
+            .map_err(tracerr::wrap!())?,
+    )
+    .await
+    .map_err(Error::from)
+    .map_err(tracerr::wrap!())?;
+
+    Ok(status.unchecked_into())
+}
+
+/// Converts the provided [`web_sys::PermissionState`] into a
+/// [`PermissionState`].
+const fn parse_permission_state(
+    state: web_sys::PermissionState,
+) -> PermissionState {
+    match state {
+        web_sys::PermissionState::Granted => PermissionState::Granted,
+        web_sys::PermissionState::Denied => PermissionState::Denied,
+        _ => PermissionState::Prompt,
     }
 }
@@ -0,0 +1,257 @@
+//! Insertable processing of [MediaStreamTrack][1]s' media frames via
+//! [MediaStreamTrackProcessor][2] and [MediaStreamTrackGenerator][3].
+//!
+//! [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+//! [2]: https://w3.org/TR/mediacapture-transform#track-processor
+//! [3]: https://w3.org/TR/mediacapture-transform#track-generator
+
+use std::rc::Rc;
+
+use js_sys::{Promise, Reflect};
+use medea_reactive::ObservableCell;
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use wasm_bindgen_futures::{JsFuture, future_to_promise};
+use web_sys::TransformStreamDefaultController;
+
+use crate::platform::{self, wasm::utils::EventListener};
+
+/// Application provided processor of media frames ([VideoFrame][1]s or
+/// [AudioData][2]) of local [`platform::MediaStreamTrack`]s.
+///
+/// [1]: https://w3.org/TR/webcodecs#videoframe-interface
+/// [2]: https://w3.org/TR/webcodecs#audiodata-interface
+#[derive(Clone, Debug)]
+pub struct FrameProcessor {
+    /// JS function transforming the provided media frame into a processed
+    /// one, or into a [Promise][1] resolving into it.
+    ///
+    /// [1]: https://tc39.es/ecma262/#sec-promise-objects
+    transform: js_sys::Function,
+
+    /// Indicator whether media frames are passed through the `transform`, or
+    /// passed as is.
+    enabled: Rc<ObservableCell<bool>>,
+}
+
+impl FrameProcessor {
+    /// Creates a new enabled [`FrameProcessor`] applying the provided
+    /// `transform` JS function to media frames.
+    #[must_use]
+    pub fn new(transform: js_sys::Function) -> Self {
+        Self { transform, enabled: Rc::new(ObservableCell::new(true)) }
+    }
+
+    /// Enables or disables this [`FrameProcessor`] in all the
+    /// [`platform::MediaStreamTrack`]s it's applied to.
+    ///
+    /// Disabled [`FrameProcessor`] passes media frames as is.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.set(enabled);
+    }
+
+    /// Indicates whether this [`FrameProcessor`] is enabled.
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        self.enabled.get()
+    }
+
+    /// Passes the provided media `frame` through the `transform` function (if
+    /// this [`FrameProcessor`] is enabled), and enqueues the result into the
+    /// provided [TransformStreamDefaultController][1].
+    ///
+    /// The provided `frame` is [closed][2] if the `transform` function
+    /// produces a new one. If the `transform` function throws or rejects, the
+    /// error is logged and the provided `frame` is enqueued as is, so the
+    /// pipeline keeps running instead of ending the processed track.
+    ///
+    /// [1]: https://streams.spec.whatwg.org/#ts-default-controller-class
+    /// [2]: https://w3.org/TR/webcodecs#dom-videoframe-close
+    fn process(
+        &self,
+        frame: JsValue,
+        controller: TransformStreamDefaultController,
+    ) -> Promise {
+        if !self.enabled() {
+            return match controller.enqueue_with_chunk(&frame) {
+                Ok(()) => Promise::resolve(&JsValue::UNDEFINED),
+                Err(e) => Promise::reject(&e),
+            };
+        }
+
+        let transform = self.transform.clone();
+        future_to_promise(async move {
+            let processed = match transform.call1(&JsValue::NULL, &frame) {
+                Ok(processed) => {
+                    JsFuture::from(Promise::resolve(&processed)).await
+                }
+                Err(e) => Err(e),
+            };
+            let processed = processed.unwrap_or_else(|e| {
+                log::error!(
+                    "Frame processor failed, passing frame as is: {}",
+                    platform::Error::from(e),
+                );
+                frame.clone()
+            });
+            if processed != frame {
+                if let Ok(close) = Reflect::get(&frame, &"close".into())
+                    .and_then(JsCast::dyn_into::<js_sys::Function>)
+                {
+                    drop(close.call0(&frame));
+                }
+            }
+            controller.enqueue_with_chunk(&processed)?;
+
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+}
+
+impl From<js_sys::Function> for FrameProcessor {
+    fn from(transform: js_sys::Function) -> Self {
+        Self::new(transform)
+    }
+}
+
+/// Pipeline passing media frames of a [MediaStreamTrack][1] through a
+/// [`FrameProcessor`] into a [MediaStreamTrackGenerator][2].
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+/// [2]: https://w3.org/TR/mediacapture-transform#track-generator
+#[derive(Debug)]
+pub(super) struct TrackProcessor {
+    /// [`FrameProcessor`] applied to media frames.
+    pub(super) processor: FrameProcessor,
+
+    /// [MediaStreamTrackGenerator][1] producing the processed media.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-transform#track-generator
+    pub(super) output: web_sys::MediaStreamTrack,
+
+    /// Listener for an [ended][1] event of the captured
+    /// [MediaStreamTrack][2], stopping the `output` once it's fired.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#event-mediastreamtrack-ended
+    /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    _on_source_ended: EventListener<web_sys::MediaStreamTrack, web_sys::Event>,
+}
+
+impl TrackProcessor {
+    /// Creates a new [`TrackProcessor`] passing media frames of the provided
+    /// `input` [MediaStreamTrack][1] through the provided [`FrameProcessor`].
+    ///
+    /// The processed media is stopped once the provided `source`
+    /// [MediaStreamTrack][1] is [ended][2].
+    ///
+    /// If the pipeline fails while both the `source` and the processed
+    /// [MediaStreamTrack][1]s are live, the error is logged and media frames
+    /// of the `input` are passed to the processed [MediaStreamTrack][1] as is.
+    ///
+    /// # Errors
+    ///
+    /// With a [`platform::Error`] if [MediaStreamTrackProcessor][3] or
+    /// [MediaStreamTrackGenerator][4] cannot be created, which happens if
+    /// they are not supported by the browser.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    /// [2]: https://tinyurl.com/w3-streams#event-mediastreamtrack-ended
+    /// [3]: https://w3.org/TR/mediacapture-transform#track-processor
+    /// [4]: https://w3.org/TR/mediacapture-transform#track-generator
+    pub(super) fn new(
+        processor: FrameProcessor,
+        source: Rc<web_sys::MediaStreamTrack>,
+        input: &web_sys::MediaStreamTrack,
+    ) -> Result<Self, platform::Error> {
+        let track_processor = web_sys::MediaStreamTrackProcessor::new(
+            &web_sys::MediaStreamTrackProcessorInit::new(input),
+        )?;
+        let generator = web_sys::MediaStreamTrackGenerator::new(
+            &web_sys::MediaStreamTrackGeneratorInit::new(&input.kind()),
+        )?;
+
+        let transformer = js_sys::Object::new();
+        let transform = Closure::<
+            dyn FnMut(JsValue, TransformStreamDefaultController) -> Promise,
+        >::new({
+            let processor = processor.clone();
+            move |frame, controller| processor.process(frame, controller)
+        });
+        // Ownership of the closure is passed to JS, so it lives as long as
+        // the pipeline does.
+        Reflect::set(
+            &transformer,
+            &"transform".into(),
+            &transform.into_js_value(),
+        )
+        .map_err(platform::Error::from)?;
+        let transform =
+            web_sys::TransformStream::new_with_transformer(&transformer)?;
+
+        // Generator is kept writable on a pipeline failure, so the source
+        // media frames can be passed to it as is.
+        let writable = generator.writable();
+        let options = web_sys::StreamPipeOptions::new();
+        options.set_prevent_abort(true);
+        let pipe = track_processor
+            .readable()
+            .pipe_through(&web_sys::ReadableWritablePair::new(
+                &transform.readable(),
+                &transform.writable(),
+            ))
+            .pipe_to_with_options(&writable, &options);
+
+        let output = web_sys::MediaStreamTrack::from(generator);
+        platform::spawn({
+            let source = Rc::clone(&source);
+            let input = input.clone();
+            let output = output.clone();
+            async move {
+                let Err(e) = JsFuture::from(pipe).await else {
+                    return;
+                };
+                // Pipe is aborted once its source or output track is stopped,
+                // so only a failure of a still live pipeline is unexpected.
+                let live = web_sys::MediaStreamTrackState::Live;
+                if source.ready_state() != live || output.ready_state() != live
+                {
+                    return;
+                }
+                log::error!(
+                    "Frame processing pipeline failed, falling back to the \
+                     source track: {}",
+                    platform::Error::from(e),
+                );
+                let fallback = web_sys::MediaStreamTrackProcessor::new(
+                    &web_sys::MediaStreamTrackProcessorInit::new(&input),
+                )
+                .map(|p| JsFuture::from(p.readable().pipe_to(&writable)));
+                match fallback {
+                    Ok(pipe) => drop(pipe.await),
+                    Err(e) => {
+                        log::error!(
+                            "Failed to fall back to the source track: {}",
+                            platform::Error::from(e),
+                        );
+                        output.stop();
+                    }
+                }
+            }
+        });
+        #[expect(clippy::unwrap_used, reason = "shouldn't error ever")]
+        let on_source_ended = EventListener::new_once(source, "ended", {
+            let output = output.clone();
+            move |_: web_sys::Event| {
+                output.stop();
+            }
+        })
+        .unwrap();
+
+        Ok(Self { processor, output, _on_source_ended: on_source_ended })
+    }
+}
+
+impl Drop for TrackProcessor {
+    fn drop(&mut self) {
+        self.output.stop();
+    }
+}
@@ -0,0 +1,301 @@
+//! Wrapper around [MediaRecorder][1].
+//!
+//! [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+
+#![expect(clippy::unwrap_used, reason = "JS interop error is unexpected")]
+
+use std::{cell::RefCell, rc::Rc};
+
+use futures::channel::oneshot;
+use js_sys::Reflect;
+use tracerr::Traced;
+use wasm_bindgen::{JsCast as _, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+use crate::{
+    media::{MediaKind, MediaRecorderError},
+    platform::{self, wasm::utils::EventListener},
+};
+
+/// Chunk of media data recorded by a [`MediaRecorder`].
+pub type RecordedData = web_sys::Blob;
+
+/// Wrapper around [MediaRecorder][1] recording a set of
+/// [`platform::MediaStreamTrack`]s into a single media container.
+///
+/// A single video track is recorded as is, while all the audio tracks are
+/// mixed into a single one via [WebAudio].
+///
+/// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+/// [WebAudio]: https://w3.org/TR/webaudio
+#[derive(Debug)]
+pub struct MediaRecorder {
+    /// Underlying [MediaRecorder][1].
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    recorder: Rc<web_sys::MediaRecorder>,
+
+    /// [`AudioMix`] of the recorded audio tracks, if there are more than one.
+    _audio_mix: Option<AudioMix>,
+
+    /// Listener for a [dataavailable][1] event.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#eventdef-mediarecorder-dataavailable
+    on_data: RefCell<
+        Option<EventListener<web_sys::MediaRecorder, web_sys::BlobEvent>>,
+    >,
+
+    /// Listener for an [error][1] event.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#eventdef-mediarecorder-error
+    on_error:
+        RefCell<Option<EventListener<web_sys::MediaRecorder, web_sys::Event>>>,
+}
+
+impl MediaRecorder {
+    /// Creates a new [`MediaRecorder`] recording the provided
+    /// [`platform::MediaStreamTrack`]s.
+    ///
+    /// # Errors
+    ///
+    /// - With a [`MediaRecorderError::TooManyVideoTracks`] if more than one
+    ///   video [`platform::MediaStreamTrack`] is provided.
+    /// - With a [`MediaRecorderError::RecordingFailed`] if the
+    ///   [MediaRecorder][1] cannot be created for the provided
+    ///   [`platform::MediaStreamTrack`]s.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#mediarecorder-api
+    pub fn new<'a, I>(tracks: I) -> Result<Self, Traced<MediaRecorderError>>
+    where
+        I: IntoIterator<Item = &'a platform::MediaStreamTrack>,
+    {
+        let (audio, video): (Vec<_>, Vec<_>) = tracks
+            .into_iter()
+            .partition(|track| track.kind() == MediaKind::Audio);
+        if video.len() > 1 {
+            return Err(tracerr::new!(MediaRecorderError::TooManyVideoTracks));
+        }
+
+        let stream = web_sys::MediaStream::new()
+            .map_err(platform::Error::from)
+            .map_err(tracerr::map_from_and_wrap!())?;
+        if let [video] = video.as_slice() {
+            stream.add_track(video.as_ref());
+        }
+        let audio_mix = if let [track] = audio.as_slice() {
+            stream.add_track(track.as_ref());
+            None
+        } else if audio.is_empty() {
+            None
+        } else {
+            let mix =
+                AudioMix::new(&audio).map_err(tracerr::map_from_and_wrap!())?;
+            stream.add_track(&mix.output);
+            Some(mix)
+        };
+
+        let recorder = web_sys::MediaRecorder::new_with_media_stream(&stream)
+            .map_err(platform::Error::from)
+            .map_err(tracerr::map_from_and_wrap!())?;
+
+        Ok(Self {
+            recorder: Rc::new(recorder),
+            _audio_mix: audio_mix,
+            on_data: RefCell::new(None),
+            on_error: RefCell::new(None),
+        })
+    }
+
+    /// Sets handler for a [dataavailable][1] event, receiving recorded media
+    /// data chunks.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#eventdef-mediarecorder-dataavailable
+    pub fn on_data<F>(&self, f: Option<F>)
+    where
+        F: 'static + FnMut(RecordedData),
+    {
+        let mut on_data = self.on_data.borrow_mut();
+        drop(match f {
+            None => on_data.take(),
+            Some(mut f) => on_data.replace(
+                EventListener::new_mut(
+                    Rc::clone(&self.recorder),
+                    "dataavailable",
+                    move |e: web_sys::BlobEvent| {
+                        if let Some(data) = e.data().filter(|d| d.size() > 0.0)
+                        {
+                            f(data);
+                        }
+                    },
+                )
+                .unwrap(),
+            ),
+        });
+    }
+
+    /// Sets handler for an [error][1] event, fired once recording fails.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#eventdef-mediarecorder-error
+    pub fn on_error<F>(&self, f: Option<F>)
+    where
+        F: 'static + FnMut(platform::Error),
+    {
+        let mut on_error = self.on_error.borrow_mut();
+        drop(match f {
+            None => on_error.take(),
+            Some(mut f) => on_error.replace(
+                EventListener::new_mut(
+                    Rc::clone(&self.recorder),
+                    "error",
+                    move |e: web_sys::Event| {
+                        let err = Reflect::get(&e, &JsValue::from_str("error"))
+                            .ok()
+                            .filter(|err| !err.is_undefined())
+                            .unwrap_or_else(|| e.into());
+                        f(platform::Error::from(err));
+                    },
+                )
+                .unwrap(),
+            ),
+        });
+    }
+
+    /// Starts recording, emitting recorded data chunks each `timeslice_ms`
+    /// milliseconds (if provided), or only once recording is stopped.
+    ///
+    /// # Errors
+    ///
+    /// With a [`platform::Error`] if [MediaRecorder.start()][1] fails.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#dom-mediarecorder-start
+    pub fn start(
+        &self,
+        timeslice_ms: Option<u32>,
+    ) -> Result<(), platform::Error> {
+        match timeslice_ms {
+            Some(ms) => self
+                .recorder
+                .start_with_time_slice(i32::try_from(ms).unwrap_or(i32::MAX)),
+            None => self.recorder.start(),
+        }
+        .map_err(platform::Error::from)
+    }
+
+    /// Pauses recording.
+    ///
+    /// # Errors
+    ///
+    /// With a [`platform::Error`] if [MediaRecorder.pause()][1] fails.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#dom-mediarecorder-pause
+    pub fn pause(&self) -> Result<(), platform::Error> {
+        self.recorder.pause().map_err(platform::Error::from)
+    }
+
+    /// Resumes previously paused recording.
+    ///
+    /// # Errors
+    ///
+    /// With a [`platform::Error`] if [MediaRecorder.resume()][1] fails.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#dom-mediarecorder-resume
+    pub fn resume(&self) -> Result<(), platform::Error> {
+        self.recorder.resume().map_err(platform::Error::from)
+    }
+
+    /// Stops recording, resolving once the last recorded data chunk is
+    /// emitted.
+    ///
+    /// # Errors
+    ///
+    /// With a [`platform::Error`] if [MediaRecorder.stop()][1] fails.
+    ///
+    /// [1]: https://w3.org/TR/mediastream-recording#dom-mediarecorder-stop
+    pub async fn stop(&self) -> Result<(), platform::Error> {
+        let (tx, rx) = oneshot::channel();
+        let _on_stop = EventListener::new_once(
+            Rc::clone(&self.recorder),
+            "stop",
+            move |_: web_sys::Event| {
+                drop(tx.send(()));
+            },
+        )
+        .unwrap();
+        self.recorder.stop()?;
+        drop(rx.await);
+
+        Ok(())
+    }
+}
+
+impl Drop for MediaRecorder {
+    fn drop(&mut self) {
+        drop(self.recorder.stop());
+    }
+}
+
+/// [WebAudio] pipeline mixing several audio [MediaStreamTrack][1]s into a
+/// single one.
+///
+/// [WebAudio]: https://w3.org/TR/webaudio
+/// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+#[derive(Debug)]
+struct AudioMix {
+    /// [`web_sys::AudioContext`] holding this [`AudioMix`] audio processing
+    /// pipeline.
+    audio_ctx: web_sys::AudioContext,
+
+    /// [`web_sys::MediaStreamAudioSourceNode`]s wrapping the mixed
+    /// [MediaStreamTrack][1]s.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    sources: Vec<web_sys::MediaStreamAudioSourceNode>,
+
+    /// [MediaStreamTrack][1] producing the mixed audio.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    output: web_sys::MediaStreamTrack,
+}
+
+impl AudioMix {
+    /// Creates a new [`AudioMix`] of the provided
+    /// [`platform::MediaStreamTrack`]s.
+    fn new(
+        tracks: &[&platform::MediaStreamTrack],
+    ) -> Result<Self, platform::Error> {
+        let audio_ctx = web_sys::AudioContext::new()?;
+        let dst = audio_ctx.create_media_stream_destination()?;
+        let sources = tracks
+            .iter()
+            .map(|track| {
+                let stream = web_sys::MediaStream::new()?;
+                stream.add_track(track.as_ref());
+                // TODO: Use `createMediaStreamTrackSource` once available.
+                let src = audio_ctx.create_media_stream_source(&stream)?;
+                src.connect_with_audio_node(&dst)?;
+                Ok(src)
+            })
+            .collect::<Result<Vec<_>, JsValue>>()?;
+        let output = dst
+            .stream()
+            .get_audio_tracks()
+            .get(0)
+            .dyn_into::<web_sys::MediaStreamTrack>()
+            .map_err(platform::Error::from)?;
+
+        Ok(Self { audio_ctx, sources, output })
+    }
+}
+
+impl Drop for AudioMix {
+    fn drop(&mut self) {
+        for src in &self.sources {
+            drop(src.disconnect());
+        }
+        if let Ok(close) = self.audio_ctx.close() {
+            platform::spawn(async {
+                drop(JsFuture::from(close).await);
+            });
+        }
+    }
+}
@@ -4,19 +4,30 @@
 
 use std::{cell::RefCell, rc::Rc, time::Duration};
 
-use derive_more::{Debug, with_trait::AsRef};
-use futures::{StreamExt as _, future, stream::LocalBoxStream};
+use derive_more::Debug;
+use futures::{FutureExt as _, StreamExt as _, future, stream::LocalBoxStream};
 use js_sys::{Error as JsError, Reflect};
 use medea_reactive::ObservableCell;
-use wasm_bindgen::JsValue;
+use wasm_bindgen::{JsCast as _, JsValue};
 use wasm_bindgen_futures::JsFuture;
 
 use crate::{
     media::{
-        FacingMode, MediaKind, MediaSourceKind, NoiseSuppressionLevel,
-        track::MediaStreamTrackState,
+        CameraControl, CameraSetting, DeviceCapabilities, FacingMode,
+        MediaKind, MediaSourceKind, MeteringMode, NoiseSuppressionLevel,
+        ValueRange, VideoSource,
+        track::{
+            ApplyConstraintsError, CameraControlError, MediaStreamTrackState,
+        },
     },
-    platform::{self, wasm::utils::EventListener},
+    platform::{
+        self,
+        wasm::{
+            media_processor::{FrameProcessor, TrackProcessor},
+            utils::EventListener,
+        },
+    },
+    utils::TaskHandle,
 };
 
 /// Wrapper around [MediaStreamTrack][1] received from a
@@ -25,12 +36,11 @@ use crate::{
 /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
 /// [2]: https://w3.org/TR/mediacapture-streams#dom-mediadevices-getusermedia
 /// [3]: https://w3.org/TR/screen-capture/#dom-mediadevices-getdisplaymedia
-#[derive(AsRef, Debug)]
+#[derive(Debug)]
 pub struct MediaStreamTrack {
     /// Underlying [MediaStreamTrack][1].
     ///
     /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
-    #[as_ref(forward)]
     sys_track: Rc<web_sys::MediaStreamTrack>,
 
     /// Kind of the underlying [MediaStreamTrack][1].
@@ -64,6 +74,31 @@ pub struct MediaStreamTrack {
     ///
     /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
     audio_level_watcher: Rc<RefCell<Option<AudioLevelWatcher>>>,
+
+    /// [`MicGain`] pipeline applying the microphone volume to the underlying
+    /// [MediaStreamTrack][1] (if it's a local audio one).
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    mic_gain: Option<MicGain>,
+
+    /// [`TrackProcessor`] passing media frames of this [`MediaStreamTrack`]
+    /// through an application provided [`FrameProcessor`] (if it's a local
+    /// one).
+    processor: Option<TrackProcessor>,
+}
+
+impl AsRef<web_sys::MediaStreamTrack> for MediaStreamTrack {
+    /// Returns the [MediaStreamTrack][1] to be rendered and sent, which is the
+    /// [`TrackProcessor`] or [`MicGain`] output if there is any, or the
+    /// underlying [MediaStreamTrack][1] otherwise.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    fn as_ref(&self) -> &web_sys::MediaStreamTrack {
+        if let Some(processor) = &self.processor {
+            return &processor.output;
+        }
+        self.mic_gain.as_ref().map_or(&*self.sys_track, |g| &g.output)
+    }
 }
 
 impl MediaStreamTrack {
@@ -86,16 +121,80 @@ impl MediaStreamTrack {
             on_ended: RefCell::new(None),
             on_audio_level: Rc::new(RefCell::new(None)),
             audio_level_watcher: Rc::new(RefCell::new(None)),
+            mic_gain: None,
+            processor: None,
         }
     }
 
-    /// Returns [`id`] of the underlying [MediaStreamTrack][2].
+    /// Routes audio of this [`MediaStreamTrack`] through a [GainNode][1]
+    /// controlled by the provided microphone `volume` (in percents).
+    ///
+    /// Keeps this [`MediaStreamTrack`] intact if it's not an audio one, or the
+    /// [GainNode][1] pipeline cannot be created.
+    ///
+    /// [1]: https://w3.org/TR/webaudio#GainNode
+    #[must_use]
+    pub(super) fn with_mic_volume(
+        mut self,
+        volume: Rc<ObservableCell<i64>>,
+    ) -> Self {
+        if self.kind == MediaKind::Audio {
+            match MicGain::new(&self.sys_track, volume) {
+                Ok(gain) => self.mic_gain = Some(gain),
+                Err(e) => log::error!("Failed to create `GainNode`: {e}"),
+            }
+        }
+        self
+    }
+
+    /// Returns the microphone volume level (in percents) applied to this
+    /// [`MediaStreamTrack`].
+    ///
+    /// [`None`] if this [`MediaStreamTrack`] isn't routed through a
+    /// [GainNode][1] (e.g. it's not a local device audio one).
+    ///
+    /// [1]: https://w3.org/TR/webaudio#GainNode
+    #[must_use]
+    pub fn microphone_volume(&self) -> Option<i64> {
+        self.mic_gain.as_ref().map(|g| g.volume.get())
+    }
+
+    /// Sets the microphone volume level (in percents) applied to this
+    /// [`MediaStreamTrack`] and all its forks, clamped to the `[0;100]` range.
+    ///
+    /// Does nothing if this [`MediaStreamTrack`] isn't routed through a
+    /// [GainNode][1] (e.g. it's not a local device audio one).
+    ///
+    /// [1]: https://w3.org/TR/webaudio#GainNode
+    pub fn set_microphone_volume(&self, level: i64) {
+        if let Some(gain) = &self.mic_gain {
+            gain.volume.set(level.clamp(0, 100));
+        }
+    }
+
+    /// Passes media frames of this [`MediaStreamTrack`] through the provided
+    /// [`FrameProcessor`], so the processed ones are rendered and sent.
+    ///
+    /// Keeps this [`MediaStreamTrack`] intact if the processing pipeline
+    /// cannot be created.
+    #[must_use]
+    pub fn with_processor(mut self, processor: FrameProcessor) -> Self {
+        let source = Rc::clone(&self.sys_track);
+        match TrackProcessor::new(processor, source, self.as_ref()) {
+            Ok(processor) => self.processor = Some(processor),
+            Err(e) => log::error!("Failed to process `MediaStreamTrack`: {e}"),
+        }
+        self
+    }
+
+    /// Returns [`id`] of the [MediaStreamTrack][2] being rendered and sent
+    /// (see [`AsRef`] implementation for details).
     ///
     /// [`id`]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-id
     /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
     #[must_use]
     pub fn id(&self) -> String {
-        self.sys_track.id()
+        self.as_ref().id()
     }
 
     /// Returns this [`MediaStreamTrack`]'s kind (audio/video).
@@ -148,17 +247,7 @@ impl MediaStreamTrack {
     /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
     #[must_use]
     pub fn facing_mode(&self) -> Option<FacingMode> {
-        let facing_mode = self.sys_track.get_settings().get_facing_mode()?;
-        match facing_mode.as_ref() {
-            "user" => Some(FacingMode::User),
-            "environment" => Some(FacingMode::Environment),
-            "left" => Some(FacingMode::Left),
-            "right" => Some(FacingMode::Right),
-            _ => {
-                log::error!("Unknown `FacingMode`: {facing_mode}");
-                None
-            }
-        }
+        parse_facing_mode(&self.sys_track.get_settings().get_facing_mode()?)
     }
 
     /// Returns a [`height`][1] of the underlying [MediaStreamTrack][2].
@@ -182,12 +271,19 @@ impl MediaStreamTrack {
     }
 
     /// Changes an [`enabled`][1] attribute in the underlying
-    /// [MediaStreamTrack][2].
+    /// [MediaStreamTrack][2] and the one being rendered and sent (see
+    /// [`AsRef`] implementation for details).
     ///
     /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-enabled
     /// [2]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
     pub fn set_enabled(&self, enabled: bool) {
         self.sys_track.set_enabled(enabled);
+        if let Some(gain) = &self.mic_gain {
+            gain.output.set_enabled(enabled);
+        }
+        if let Some(processor) = &self.processor {
+            processor.output.set_enabled(enabled);
+        }
     }
 
     /// Changes a [`readyState`][1] attribute in the underlying
@@ -198,6 +294,12 @@ impl MediaStreamTrack {
     /// [3]: https://tinyurl.com/w3-streams#idl-def-MediaStreamTrackState.ended
     pub fn stop(&self) -> impl Future<Output = ()> + 'static + use<> {
         self.sys_track.stop();
+        if let Some(gain) = &self.mic_gain {
+            gain.output.stop();
+        }
+        if let Some(processor) = &self.processor {
+            processor.output.stop();
+        }
         // For platform code uniformity.
         future::ready(())
     }
@@ -240,7 +342,7 @@ impl MediaStreamTrack {
     /// [1]: https://w3.org/TR/mediacapture-streams#dom-mediastreamtrack-clone
     #[expect(clippy::unused_async, reason = "`cfg` code uniformity")]
     pub async fn fork(&self) -> Self {
-        Self {
+        let forked = Self {
             sys_track: Rc::new(web_sys::MediaStreamTrack::clone(
                 &self.sys_track,
             )),
@@ -249,6 +351,16 @@ impl MediaStreamTrack {
             on_ended: RefCell::new(None),
             on_audio_level: Rc::new(RefCell::new(None)),
             audio_level_watcher: Rc::clone(&self.audio_level_watcher),
+            mic_gain: None,
+            processor: None,
+        };
+        let forked = match &self.mic_gain {
+            Some(gain) => forked.with_mic_volume(Rc::clone(&gain.volume)),
+            None => forked,
+        };
+        match &self.processor {
+            Some(p) => forked.with_processor(p.processor.clone()),
+            None => forked,
         }
     }
 
@@ -378,6 +490,36 @@ impl MediaStreamTrack {
             && caps.get_auto_gain_control().is_some()
     }
 
+    /// Applies the provided [`VideoSource`] constraints to this live
+    /// [`MediaStreamTrack`] via [applyConstraints()][1].
+    ///
+    /// # Errors
+    ///
+    /// With an [`ApplyConstraintsError::Failed`] if platform call errors.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-applyconstraints
+    pub async fn apply_constraints(
+        &self,
+        constraints: VideoSource,
+    ) -> Result<(), ApplyConstraintsError> {
+        let constraints = match constraints {
+            VideoSource::Device(device) => {
+                web_sys::MediaTrackConstraints::from(device)
+            }
+            VideoSource::Display(display) => {
+                web_sys::MediaTrackConstraints::from(display)
+            }
+        };
+
+        let fut = self
+            .sys_track
+            .apply_constraints_with_constraints(&constraints)
+            .map_err(platform::Error::from)?;
+        JsFuture::from(fut).await.map_err(platform::Error::from)?;
+
+        Ok(())
+    }
+
     /// Toggles noise suppression for this [`MediaStreamTrack`].
     ///
     /// # Errors
@@ -573,6 +715,219 @@ impl MediaStreamTrack {
     ) -> Result<bool, platform::Error> {
         unimplemented!("getting high-pass filter is not available on web")
     }
+
+    /// Returns [`DeviceCapabilities`] of the device producing this
+    /// [`MediaStreamTrack`].
+    ///
+    /// Adapter for the [MediaStreamTrack.getCapabilities()][1] function.
+    /// Returns empty [`DeviceCapabilities`] if it's not supported by the
+    /// browser.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-getcapabilities
+    #[expect(clippy::unused_async, reason = "`cfg` code uniformity")]
+    pub async fn capabilities(&self) -> DeviceCapabilities {
+        let is_supported = Reflect::get(
+            &self.sys_track,
+            &JsValue::from_str("getCapabilities"),
+        )
+        .is_ok_and(|f| f.is_function());
+        if !is_supported {
+            return DeviceCapabilities::default();
+        }
+        parse_capabilities(&self.sys_track.get_capabilities())
+    }
+
+    /// Indicates whether the provided [`CameraControl`] is supported by the
+    /// camera producing this [`MediaStreamTrack`].
+    ///
+    /// Checks whether the [MediaStreamTrack.getCapabilities()][1] result
+    /// contains the corresponding [constrainable property][2].
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediastreamtrack-getcapabilities
+    /// [2]: https://w3.org/TR/image-capture#constrainable-properties
+    pub fn is_camera_control_available(&self, control: CameraControl) -> bool {
+        for func in ["getCapabilities", "applyConstraints"] {
+            if Reflect::get(&self.sys_track, &JsValue::from_str(func))
+                .ok()
+                .filter(|val| !val.is_undefined())
+                .is_none()
+            {
+                return false;
+            }
+        }
+
+        let caps = JsValue::from(self.sys_track.get_capabilities());
+        Reflect::get(&caps, &JsValue::from_str(camera_control_name(control)))
+            .is_ok_and(|val| !val.is_undefined())
+    }
+
+    /// Sets the provided [`CameraSetting`] for the camera producing this
+    /// [`MediaStreamTrack`].
+    ///
+    /// The [`CameraSetting`] is applied as an [advanced constraint][1],
+    /// preserving the previously set ones.
+    ///
+    /// # Errors
+    ///
+    /// - With a [`CameraControlError::NotSupported`] if the camera doesn't
+    ///   support the [`CameraControl`], as browsers silently ignore
+    ///   unsatisfiable advanced constraints.
+    /// - With a [`CameraControlError::Failed`] if platform call errors.
+    ///
+    /// [1]: https://tinyurl.com/w3-streams#dom-mediatrackconstraints-advanced
+    pub async fn set_camera_control(
+        &self,
+        setting: CameraSetting,
+    ) -> Result<(), CameraControlError> {
+        if !self.is_camera_control_available(setting.control()) {
+            return Err(CameraControlError::NotSupported);
+        }
+
+        let value = match setting {
+            CameraSetting::Zoom(val)
+            | CameraSetting::FocusDistance(val)
+            | CameraSetting::ExposureCompensation(val) => JsValue::from(val),
+            CameraSetting::Torch(enabled) => JsValue::from(enabled),
+            CameraSetting::FocusMode(mode)
+            | CameraSetting::WhiteBalanceMode(mode) => {
+                JsValue::from_str(metering_mode_name(mode))
+            }
+        };
+
+        let constraints = self.sys_track.get_constraints();
+        let advanced = Reflect::get(&constraints, &"advanced".into())
+            .ok()
+            .filter(js_sys::Array::is_array)
+            .map(|val| js_sys::Array::from(&val).get(0))
+            .filter(JsValue::is_object)
+            .unwrap_or_else(|| js_sys::Object::new().into());
+        Reflect::set(
+            &advanced,
+            &JsValue::from_str(camera_control_name(setting.control())),
+            &value,
+        )
+        .map_err(platform::Error::from)?;
+        Reflect::set(
+            &constraints,
+            &"advanced".into(),
+            &js_sys::Array::of1(&advanced),
+        )
+        .map_err(platform::Error::from)?;
+
+        let fut = self
+            .sys_track
+            .apply_constraints_with_constraints(&constraints)
+            .map_err(platform::Error::from)?;
+        JsFuture::from(fut).await.map_err(platform::Error::from)?;
+
+        Ok(())
+    }
+}
+
+/// Returns the name of the [constrainable property][1] controlling the
+/// provided [`CameraControl`].
+///
+/// [1]: https://w3.org/TR/image-capture#constrainable-properties
+const fn camera_control_name(control: CameraControl) -> &'static str {
+    match control {
+        CameraControl::Zoom => "zoom",
+        CameraControl::Torch => "torch",
+        CameraControl::FocusMode => "focusMode",
+        CameraControl::FocusDistance => "focusDistance",
+        CameraControl::ExposureCompensation => "exposureCompensation",
+        CameraControl::WhiteBalanceMode => "whiteBalanceMode",
+    }
+}
+
+/// Returns the [MeteringMode][1] value of the provided [`MeteringMode`].
+///
+/// [1]: https://w3.org/TR/image-capture#meteringmode-section
+const fn metering_mode_name(mode: MeteringMode) -> &'static str {
+    match mode {
+        MeteringMode::None => "none",
+        MeteringMode::Manual => "manual",
+        MeteringMode::SingleShot => "single-shot",
+        MeteringMode::Continuous => "continuous",
+    }
+}
+
+/// Parses the provided [VideoFacingModeEnum][1] value.
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#dom-videofacingmodeenum
+fn parse_facing_mode(facing_mode: &str) -> Option<FacingMode> {
+    match facing_mode {
+        "user" => Some(FacingMode::User),
+        "environment" => Some(FacingMode::Environment),
+        "left" => Some(FacingMode::Left),
+        "right" => Some(FacingMode::Right),
+        _ => {
+            log::error!("Unknown `FacingMode`: {facing_mode}");
+            None
+        }
+    }
+}
+
+/// Parses [`DeviceCapabilities`] from the provided [MediaTrackCapabilities][1]
+/// dictionary.
+///
+/// [1]: https://w3.org/TR/mediacapture-streams#dom-mediatrackcapabilities
+pub(super) fn parse_capabilities(caps: &JsValue) -> DeviceCapabilities {
+    /// Returns the defined `name` property of the provided `obj`.
+    fn prop(obj: &JsValue, name: &str) -> Option<JsValue> {
+        Reflect::get(obj, &JsValue::from_str(name))
+            .ok()
+            .filter(|val| !val.is_undefined())
+    }
+
+    /// Returns the `name` range property of the provided `caps`.
+    fn range(caps: &JsValue, name: &str) -> Option<ValueRange<f64>> {
+        let range = prop(caps, name)?;
+        Some(ValueRange::new(
+            prop(&range, "min")?.as_f64()?,
+            prop(&range, "max")?.as_f64()?,
+        ))
+    }
+
+    /// Returns the `name` integer range property of the provided `caps`.
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "no better way"
+    )]
+    fn u32_range(caps: &JsValue, name: &str) -> Option<ValueRange<u32>> {
+        range(caps, name).map(|r| ValueRange::new(r.min as u32, r.max as u32))
+    }
+
+    /// Returns the `name` sequence property of the provided `caps`.
+    fn list(caps: &JsValue, name: &str) -> Vec<JsValue> {
+        prop(caps, name)
+            .map(|val| js_sys::Array::from(&val).iter().collect())
+            .unwrap_or_default()
+    }
+
+    DeviceCapabilities {
+        width: u32_range(caps, "width"),
+        height: u32_range(caps, "height"),
+        frame_rate: range(caps, "frameRate"),
+        facing_modes: list(caps, "facingMode")
+            .into_iter()
+            .filter_map(|mode| parse_facing_mode(&mode.as_string()?))
+            .collect(),
+        channel_count: u32_range(caps, "channelCount"),
+        echo_cancellation: list(caps, "echoCancellation")
+            .iter()
+            .filter_map(JsValue::as_bool)
+            .collect(),
+        auto_gain_control: list(caps, "autoGainControl")
+            .iter()
+            .filter_map(JsValue::as_bool)
+            .collect(),
+        noise_suppression: list(caps, "noiseSuppression")
+            .iter()
+            .filter_map(JsValue::as_bool)
+            .collect(),
+    }
 }
 
 /// Analyzer of audio track raw data producing audio level ([RMS] loudness).
@@ -580,10 +935,6 @@ impl MediaStreamTrack {
 /// [RMS]: https://en.wikipedia.org/wiki/Root_mean_square
 #[derive(Debug)]
 struct AudioLevelWatcher {
-    /// [`web_sys::AudioContext`] holding this [`AudioLevelWatcher`] audio
-    /// processing pipeline.
-    audio_ctx: web_sys::AudioContext,
-
     /// Latest audio level value in the `[0;100]` range.
     level: Rc<ObservableCell<i32>>,
 
@@ -593,6 +944,9 @@ struct AudioLevelWatcher {
 
     /// [`web_sys::AnalyserNode`] processing audio data.
     analyzer: web_sys::AnalyserNode,
+
+    /// [`TaskHandle`] of measuring the audio level.
+    _measure_task: TaskHandle,
 }
 
 impl AudioLevelWatcher {
@@ -605,7 +959,7 @@ impl AudioLevelWatcher {
         /// Must be a power of two in the `[32..32768]` range.
         const FFT_SIZE: u32 = 256;
 
-        let audio_ctx = web_sys::AudioContext::new()?;
+        let audio_ctx = audio_context()?;
         let level = Rc::new(ObservableCell::new(0));
 
         let stream = {
@@ -621,16 +975,11 @@ impl AudioLevelWatcher {
         let mut audio_buf = vec![0.0f32; usize::try_from(FFT_SIZE).unwrap()];
 
         src.connect_with_audio_node(&analyzer)?;
-        platform::spawn({
+        let (measure_fut, measure_task) = future::abortable({
             let level = Rc::clone(&level);
             let analyzer = analyzer.clone();
-            let audio_ctx = audio_ctx.clone();
             async move {
                 loop {
-                    if audio_ctx.state() == web_sys::AudioContextState::Closed {
-                        break;
-                    }
-
                     analyzer.get_float_time_domain_data(&mut audio_buf);
                     let mut sum = 0.0;
                     for b in &audio_buf {
@@ -655,8 +1004,9 @@ impl AudioLevelWatcher {
                 }
             }
         });
+        platform::spawn(measure_fut.map(drop));
 
-        Ok(Self { audio_ctx, level, src, analyzer })
+        Ok(Self { level, src, analyzer, _measure_task: measure_task.into() })
     }
 
     /// Subscribes to audio level changes of this [`AudioLevelWatcher`].
@@ -669,10 +1019,136 @@ impl Drop for AudioLevelWatcher {
     fn drop(&mut self) {
         drop(self.src.disconnect());
         drop(self.analyzer.disconnect());
-        if let Ok(close) = self.audio_ctx.close() {
+    }
+}
+
+/// [WebAudio] pipeline applying the microphone volume to a local audio
+/// [MediaStreamTrack][1] via a [GainNode][2].
+///
+/// [WebAudio]: https://w3.org/TR/webaudio
+/// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+/// [2]: https://w3.org/TR/webaudio#GainNode
+#[derive(Debug)]
+struct MicGain {
+    /// [`web_sys::MediaStreamAudioSourceNode`] wrapping the captured
+    /// [MediaStreamTrack][1].
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    src: web_sys::MediaStreamAudioSourceNode,
+
+    /// [`web_sys::GainNode`] applying the microphone volume.
+    gain: web_sys::GainNode,
+
+    /// [MediaStreamTrack][1] producing the processed audio.
+    ///
+    /// [1]: https://w3.org/TR/mediacapture-streams#mediastreamtrack
+    output: web_sys::MediaStreamTrack,
+
+    /// Microphone volume level in the `[0;100]` range.
+    volume: Rc<ObservableCell<i64>>,
+
+    /// [`TaskHandle`] of applying the `volume` changes to the `gain`.
+    _volume_task: TaskHandle,
+}
+
+impl MicGain {
+    /// Creates a new [`MicGain`] for the provided
+    /// [`web_sys::MediaStreamTrack`].
+    fn new(
+        track: &web_sys::MediaStreamTrack,
+        volume: Rc<ObservableCell<i64>>,
+    ) -> Result<Self, platform::Error> {
+        /// Converts the provided volume level in percents into a gain value.
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_precision_loss,
+            reason = "no better way"
+        )]
+        fn to_gain(level: i64) -> f32 {
+            level as f32 / 100.0
+        }
+
+        let audio_ctx = audio_context()?;
+        let stream = {
+            let stream = web_sys::MediaStream::new()?;
+            stream.add_track(track);
+            stream
+        };
+        // TODO: Use `createMediaStreamTrackSource` once available.
+        let src = audio_ctx.create_media_stream_source(&stream)?;
+        let gain = audio_ctx.create_gain()?;
+        let dst = audio_ctx.create_media_stream_destination()?;
+        gain.gain().set_value(to_gain(volume.get()));
+        src.connect_with_audio_node(&gain)?;
+        gain.connect_with_audio_node(&dst)?;
+        let output = dst
+            .stream()
+            .get_audio_tracks()
+            .get(0)
+            .dyn_into::<web_sys::MediaStreamTrack>()
+            .map_err(platform::Error::from)?;
+
+        let (volume_fut, volume_task) = future::abortable({
+            let gain = gain.clone();
+            let mut levels = volume.subscribe();
+            async move {
+                while let Some(level) = levels.next().await {
+                    gain.gain().set_value(to_gain(level));
+                }
+            }
+        });
+        platform::spawn(volume_fut.map(drop));
+
+        Ok(Self { src, gain, output, volume, _volume_task: volume_task.into() })
+    }
+}
+
+impl Drop for MicGain {
+    fn drop(&mut self) {
+        drop(self.src.disconnect());
+        drop(self.gain.disconnect());
+        self.output.stop();
+    }
+}
+
+thread_local! {
+    /// [`web_sys::AudioContext`] shared by all the [WebAudio] pipelines, as
+    /// browsers limit the number of simultaneously running ones.
+    ///
+    /// [WebAudio]: https://w3.org/TR/webaudio
+    static AUDIO_CTX: RefCell<Option<web_sys::AudioContext>> =
+        const { RefCell::new(None) };
+}
+
+/// Returns the shared [`web_sys::AudioContext`], creating it if it doesn't
+/// exist yet, and [resuming][1] it if it has been suspended (for example,
+/// because it was created without a user gesture).
+///
+/// # Errors
+///
+/// If a [`web_sys::AudioContext`] cannot be created.
+///
+/// [1]: https://w3.org/TR/webaudio#dom-audiocontext-resume
+fn audio_context() -> Result<web_sys::AudioContext, platform::Error> {
+    let audio_ctx = AUDIO_CTX.with_borrow_mut(|shared| {
+        if let Some(ctx) = shared.as_ref() {
+            return Ok(ctx.clone());
+        }
+        let ctx = web_sys::AudioContext::new()?;
+        *shared = Some(ctx.clone());
+        Ok::<_, platform::Error>(ctx)
+    })?;
+    if audio_ctx.state() == web_sys::AudioContextState::Suspended {
+        if let Ok(resume) = audio_ctx.resume() {
             platform::spawn(async {
-                drop(JsFuture::from(close).await);
+                if let Err(e) = JsFuture::from(resume).await {
+                    log::error!(
+                        "Failed to resume `AudioContext`: {}",
+                        platform::Error::from(e),
+                    );
+                }
             });
         }
     }
+    Ok(audio_ctx)
 }
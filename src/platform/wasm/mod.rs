@@ -6,11 +6,15 @@ pub mod error;
 pub mod ice_server;
 pub mod media_device_info;
 pub mod media_devices;
+pub mod media_processor;
+pub mod media_recorder;
 pub mod media_track;
+pub mod network_monitor;
 pub mod peer_connection;
 pub mod rtc_stats;
 pub mod send_encoding_parameters;
 pub mod send_parameters;
+pub mod sse_transport;
 pub mod transceiver;
 pub mod transport;
 pub mod utils;
@@ -27,9 +31,13 @@ pub use self::{
     error::Error,
     media_device_info::MediaDeviceInfo,
     media_devices::MediaDevices,
+    media_processor::FrameProcessor,
+    media_recorder::{MediaRecorder, RecordedData},
     media_track::MediaStreamTrack,
+    network_monitor::NetworkMonitor,
     peer_connection::RtcPeerConnection,
     rtc_stats::RtcStats,
+    sse_transport::SseRpcTransport,
     transceiver::{Transceiver, TransceiverInit},
     transport::WebSocketRpcTransport,
     utils::Function,
@@ -106,6 +114,21 @@ pub async fn delay_for(delay: Duration) {
     .unwrap();
 }
 
+/// Returns a monotonic timestamp of the current moment, measured from an
+/// arbitrary fixed point in time.
+///
+/// Uses [Performance.now()][1], falling back to [Date.now()][2] if it's not
+/// available.
+///
+/// [1]: https://w3.org/TR/hr-time-3#dom-performance-now
+/// [2]: https://tc39.es/ecma262/#sec-date.now
+#[must_use]
+pub fn now() -> Duration {
+    let millis =
+        window().performance().map_or_else(js_sys::Date::now, |p| p.now());
+    Duration::from_secs_f64(millis.max(0.0) / 1000.0)
+}
+
 /// Returns [`Window`] object.
 ///
 /// # Panics
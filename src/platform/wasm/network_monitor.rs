@@ -0,0 +1,126 @@
+//! Monitoring of the network connectivity and the page lifecycle of a
+//! browser.
+
+use std::rc::Rc;
+
+use derive_more::with_trait::Debug;
+use tracerr::Traced;
+use web_sys::{Event, Node, Window};
+
+use crate::platform::{
+    NetworkState,
+    wasm::{
+        utils::{EventListener, EventListenerBindError},
+        window,
+    },
+};
+
+/// Monitor of the [`NetworkState`] of a browser.
+///
+/// Listens to [`online`][1] and [`offline`][2] events of a [`Window`], and to
+/// [`freeze`][3], [`resume`][4] and [`visibilitychange`][5] events of a
+/// [`Document`].
+///
+/// [`Document`]: web_sys::Document
+/// [1]: https://developer.mozilla.org/docs/Web/API/Window/online_event
+/// [2]: https://developer.mozilla.org/docs/Web/API/Window/offline_event
+/// [3]: https://developer.mozilla.org/docs/Web/API/Document/freeze_event
+/// [4]: https://developer.mozilla.org/docs/Web/API/Document/resume_event
+/// [5]: https://developer.mozilla.org/docs/Web/API/Document/visibilitychange_event
+#[derive(Debug)]
+pub struct NetworkMonitor {
+    /// [`EventListener`]s of the [`Window`] events.
+    #[debug(skip)]
+    _window_listeners: Vec<EventListener<Window, Event>>,
+
+    /// [`EventListener`]s of the [`Document`] events.
+    ///
+    /// [`Document`]: web_sys::Document
+    #[debug(skip)]
+    _document_listeners: Vec<EventListener<Node, Event>>,
+}
+
+impl NetworkMonitor {
+    /// Starts a new [`NetworkMonitor`] calling the provided `on_change`
+    /// callback with the [`NetworkState`] whenever it may have changed.
+    ///
+    /// Stops monitoring on [`Drop`].
+    ///
+    /// # Errors
+    ///
+    /// If fails to subscribe to browser events.
+    pub fn start(
+        on_change: Rc<dyn Fn(NetworkState)>,
+    ) -> Result<Self, Traced<EventListenerBindError>> {
+        let window = Rc::new(window());
+        let mut window_listeners = Vec::new();
+        for (event, state) in [
+            ("online", NetworkState::Online),
+            ("offline", NetworkState::Offline),
+        ] {
+            let on_change = Rc::clone(&on_change);
+            window_listeners.push(
+                EventListener::new_mut(Rc::clone(&window), event, move |_| {
+                    on_change(state)
+                })
+                .map_err(tracerr::wrap!())?,
+            );
+        }
+
+        let mut document_listeners = Vec::new();
+        if let Some(document) = window.document() {
+            let document = Rc::new(Node::from(document));
+
+            let on_freeze = Rc::clone(&on_change);
+            document_listeners.push(
+                EventListener::new_mut(Rc::clone(&document), "freeze", {
+                    move |_| on_freeze(NetworkState::Frozen)
+                })
+                .map_err(tracerr::wrap!())?,
+            );
+
+            let on_resume = Rc::clone(&on_change);
+            document_listeners.push(
+                EventListener::new_mut(Rc::clone(&document), "resume", {
+                    move |_| on_resume(Self::current_state())
+                })
+                .map_err(tracerr::wrap!())?,
+            );
+
+            // Not every browser supports `freeze` and `resume` events, so
+            // the state is also rechecked whenever the page becomes visible.
+            document_listeners.push(
+                EventListener::new_mut(
+                    Rc::clone(&document),
+                    "visibilitychange",
+                    move |_| {
+                        let hidden =
+                            window().document().is_none_or(|d| d.hidden());
+                        if !hidden {
+                            on_change(Self::current_state());
+                        }
+                    },
+                )
+                .map_err(tracerr::wrap!())?,
+            );
+        }
+
+        Ok(Self {
+            _window_listeners: window_listeners,
+            _document_listeners: document_listeners,
+        })
+    }
+
+    /// Returns the current [`NetworkState`] of the browser, as reported by
+    /// [`Navigator.onLine`][1].
+    ///
+    /// [1]: https://developer.mozilla.org/docs/Web/API/Navigator/onLine
+    #[must_use]
+    pub fn current_state() -> NetworkState {
+        if window().navigator().on_line() {
+            NetworkState::Online
+        } else {
+            NetworkState::Offline
+        }
+    }
+}
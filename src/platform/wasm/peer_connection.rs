@@ -474,6 +474,41 @@ impl RtcPeerConnection {
         Ok(())
     }
 
+    /// Replaces [`IceServer`]s of this [`RtcPeerConnection`] with the provided
+    /// ones, preserving its [ICE transport policy][2].
+    ///
+    /// # Errors
+    ///
+    /// With [`RtcPeerConnectionError::SetConfigurationFailed`] if
+    /// [RtcPeerConnection.setConfiguration()][1] fails.
+    ///
+    /// [1]: https://w3.org/TR/webrtc#dom-rtcpeerconnection-setconfiguration
+    /// [2]: https://w3.org/TR/webrtc#dom-rtcconfiguration-icetransportpolicy
+    #[expect(clippy::unused_async, reason = "`cfg` code uniformity")]
+    pub async fn set_ice_servers<I>(
+        &self,
+        ice_servers: I,
+        is_force_relayed: bool,
+    ) -> RtcPeerConnectionResult<()>
+    where
+        I: IntoIterator<Item = IceServer>,
+    {
+        let peer_conf = RtcConfiguration::new();
+        let policy = if is_force_relayed {
+            RtcIceTransportPolicy::Relay
+        } else {
+            RtcIceTransportPolicy::All
+        };
+        peer_conf.set_bundle_policy(RtcBundlePolicy::MaxBundle);
+        peer_conf.set_ice_transport_policy(policy);
+        peer_conf.set_ice_servers(&RtcIceServers::from(ice_servers));
+        self.peer
+            .set_configuration(&peer_conf)
+            .map_err(Into::into)
+            .map_err(RtcPeerConnectionError::SetConfigurationFailed)
+            .map_err(tracerr::wrap!())
+    }
+
     /// Marks [`RtcPeerConnection`] to trigger ICE restart.
     ///
     /// After this function returns, the offer returned by the next call to
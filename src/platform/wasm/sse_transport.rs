@@ -0,0 +1,370 @@
+//! [Server-Sent Events] transport wrapper.
+//!
+//! [Server-Sent Events]: https://developer.mozilla.org/docs/Web/API/Server-sent_events
+
+use std::{cell::RefCell, rc::Rc};
+
+use async_trait::async_trait;
+use futures::{StreamExt as _, channel::mpsc, stream::LocalBoxStream};
+use medea_client_api_proto::{ClientMsg, ServerMsg, WireFormat};
+use medea_reactive::ObservableCell;
+use tracerr::Traced;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Event, EventSource, MessageEvent, RequestInit};
+
+use crate::{
+    platform::{
+        self,
+        transport::{Frame, RpcTransport, TransportError, TransportState},
+        wasm::utils::EventListener,
+    },
+    rpc::{ApiUrl, CloseMsg, websocket::ClientDisconnect},
+};
+
+/// Shortcut for a [`Result`] containing a [`Traced`] [`TransportError`].
+type TransportResult<T> = Result<T, Traced<TransportError>>;
+
+/// Close code reported when the [EventSource] connection is lost abnormally.
+///
+/// [EventSource]: https://developer.mozilla.org/docs/Web/API/EventSource
+const ABNORMAL_CLOSE_CODE: u16 = 1006;
+
+/// Inner data of a [`SseRpcTransport`].
+#[derive(Debug)]
+struct Inner {
+    /// JS side [EventSource].
+    ///
+    /// If [`EventSource`] is [`None`], then connection hasn't been
+    /// instantiated yet.
+    ///
+    /// [EventSource]: https://developer.mozilla.org/docs/Web/API/EventSource
+    source: Option<EventSource>,
+
+    /// HTTP URL of the [EventSource] connection, used as a base for sending
+    /// [`ClientMsg`]s.
+    ///
+    /// [EventSource]: https://developer.mozilla.org/docs/Web/API/EventSource
+    url: String,
+
+    /// ID of the session assigned by a server, used for sending
+    /// [`ClientMsg`]s.
+    session_id: Option<String>,
+
+    /// State of [`SseRpcTransport`] connection.
+    state: ObservableCell<TransportState>,
+
+    /// Sender of serialized [`ClientMsg`]s to be `POST`ed to a server in
+    /// order.
+    outbox: Option<mpsc::UnboundedSender<String>>,
+
+    /// Listener for [EventSource] `session` event.
+    ///
+    /// [EventSource]: https://developer.mozilla.org/docs/Web/API/EventSource
+    on_session_listener: Option<EventListener<EventSource, MessageEvent>>,
+
+    /// Listener for [EventSource] [message event][1].
+    ///
+    /// [EventSource]: https://developer.mozilla.org/docs/Web/API/EventSource
+    /// [1]: https://developer.mozilla.org/docs/Web/API/EventSource/message_event
+    on_message_listener: Option<EventListener<EventSource, MessageEvent>>,
+
+    /// Listener for [EventSource] `close` event.
+    ///
+    /// [EventSource]: https://developer.mozilla.org/docs/Web/API/EventSource
+    on_close_listener: Option<EventListener<EventSource, MessageEvent>>,
+
+    /// Listener for [EventSource] [error event][1].
+    ///
+    /// [EventSource]: https://developer.mozilla.org/docs/Web/API/EventSource
+    /// [1]: https://developer.mozilla.org/docs/Web/API/EventSource/error_event
+    on_error_listener: Option<EventListener<EventSource, Event>>,
+
+    /// Subscribers for [`RpcTransport::on_message`] events.
+    on_message_subs: Vec<mpsc::UnboundedSender<ServerMsg>>,
+
+    /// Reason of [`SseRpcTransport`] closing.
+    ///
+    /// Will be sent to a server in a separate HTTP `POST` request.
+    close_reason: ClientDisconnect,
+}
+
+impl Inner {
+    /// Closes the [EventSource] (if any) and sets [`TransportState::Closed`]
+    /// with the provided [`CloseMsg`].
+    ///
+    /// [EventSource]: https://developer.mozilla.org/docs/Web/API/EventSource
+    fn close(&mut self, msg: CloseMsg) {
+        if let Some(source) = &self.source {
+            source.close();
+        }
+        drop(self.outbox.take());
+        self.state.set(TransportState::Closed(msg));
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        if !self.state.borrow().can_close() {
+            return;
+        }
+        if let Some(source) = self.source.take() {
+            source.close();
+        }
+        if let Some(id) = &self.session_id {
+            let reason = serde_json::to_string(&self.close_reason)
+                .unwrap_or_else(|e| {
+                    panic!("cannot serialize close message: {e}")
+                });
+            drop(post(&format!("{}/{id}/close", self.url), &reason));
+        }
+    }
+}
+
+/// Sends an HTTP `POST` request with the provided `body` to the provided
+/// `url`, returning the [`JsFuture`] of its response.
+fn post(url: &str, body: &str) -> JsFuture {
+    let init = RequestInit::new();
+    init.set_method("POST");
+    init.set_body(&JsValue::from_str(body));
+    JsFuture::from(platform::window().fetch_with_str_and_init(url, &init))
+}
+
+/// [Server-Sent Events] [`RpcTransport`] between a client and a server,
+/// sending [`ClientMsg`]s via separate HTTP `POST` requests.
+///
+/// Used as a fallback in networks blocking [WebSocket] connections.
+///
+/// # Drop
+///
+/// This structure has __cyclic references__, which are freed in its [`Drop`]
+/// implementation.
+///
+/// If you're adding new cyclic dependencies, then don't forget to drop them in
+/// the [`Drop`].
+///
+/// [Server-Sent Events]: https://developer.mozilla.org/docs/Web/API/Server-sent_events
+/// [WebSocket]: https://developer.mozilla.org/docs/Web/API/WebSocket
+#[derive(Debug)]
+pub struct SseRpcTransport(Rc<RefCell<Inner>>);
+
+impl SseRpcTransport {
+    /// Returns a new [`SseRpcTransport`] which can be connected to the server
+    /// with the [`RpcTransport::connect()`] method call.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Rc::new(RefCell::new(Inner {
+            source: None,
+            url: String::new(),
+            session_id: None,
+            state: ObservableCell::new(TransportState::Connecting),
+            outbox: None,
+            on_session_listener: None,
+            on_message_listener: None,
+            on_close_listener: None,
+            on_error_listener: None,
+            on_message_subs: Vec::new(),
+            close_reason: ClientDisconnect::RpcTransportUnexpectedlyDropped,
+        })))
+    }
+
+    /// Spawns a task `POST`ing serialized [`ClientMsg`]s one by one, so they
+    /// are delivered to a server in order.
+    fn spawn_outbox(&self) {
+        let (tx, mut rx) = mpsc::unbounded::<String>();
+        let url = {
+            let inner = self.0.borrow();
+            format!(
+                "{}/{}",
+                inner.url,
+                inner.session_id.as_deref().unwrap_or_default(),
+            )
+        };
+        self.0.borrow_mut().outbox = Some(tx);
+
+        platform::spawn(async move {
+            while let Some(body) = rx.next().await {
+                if let Err(e) = post(&url, &body).await {
+                    log::error!("Failed to send message via SSE: {e:?}");
+                }
+            }
+        });
+    }
+
+    /// Sets [`Inner::on_message_listener`] and [`Inner::on_close_listener`]
+    /// once the connection is opened.
+    ///
+    /// # Panics
+    ///
+    /// If the binding to [EventSource] events fails. Not supposed to ever
+    /// happen.
+    ///
+    /// [EventSource]: https://developer.mozilla.org/docs/Web/API/EventSource
+    #[expect(clippy::unwrap_used, reason = "not supposed to ever happen")]
+    fn set_open_listeners(&self, source: &Rc<EventSource>) {
+        let inner = Rc::clone(&self.0);
+        let on_message = EventListener::new_mut(
+            Rc::clone(source),
+            "message",
+            move |msg: MessageEvent| {
+                let Some(text) = msg.data().as_string() else {
+                    log::error!(
+                        "{}",
+                        tracerr::new!(TransportError::MessageNotString)
+                    );
+                    return;
+                };
+                let msg = match Frame::Text(text).decode() {
+                    Ok(msg) => msg,
+                    Err(e) => {
+                        log::error!("{}", tracerr::new!(e));
+                        return;
+                    }
+                };
+                inner.borrow_mut().on_message_subs.retain(|on_message| {
+                    on_message.unbounded_send(msg.clone()).is_ok()
+                });
+            },
+        )
+        .unwrap();
+
+        let inner = Rc::clone(&self.0);
+        let on_close = EventListener::new_once(
+            Rc::clone(source),
+            "close",
+            move |msg: MessageEvent| {
+                let reason = msg.data().as_string().unwrap_or_default();
+                inner.borrow_mut().close(CloseMsg::from((1000, reason)));
+            },
+        )
+        .unwrap();
+
+        let mut inner = self.0.borrow_mut();
+        inner.on_message_listener = Some(on_message);
+        inner.on_close_listener = Some(on_close);
+    }
+}
+
+impl Default for SseRpcTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait(?Send)]
+impl RpcTransport for SseRpcTransport {
+    #[expect(clippy::unwrap_used, reason = "not supposed to ever happen")]
+    async fn connect(&self, url: ApiUrl) -> TransportResult<()> {
+        let url = url.to_http();
+        let source = EventSource::new(url.as_ref())
+            .map_err(Into::into)
+            .map_err(TransportError::CreateSocket)
+            .map_err(tracerr::wrap!())?;
+        let source = Rc::new(source);
+        {
+            let mut inner = self.0.borrow_mut();
+            inner.url = url.as_ref().trim_end_matches('/').to_owned();
+            inner.source = Some((*source).clone());
+        }
+
+        {
+            let inner = Rc::clone(&self.0);
+            self.0.borrow_mut().on_session_listener = Some(
+                EventListener::new_once(
+                    Rc::clone(&source),
+                    "session",
+                    move |msg: MessageEvent| {
+                        let mut inner = inner.borrow_mut();
+                        inner.session_id = msg.data().as_string();
+                        inner.state.set(TransportState::Open);
+                    },
+                )
+                .unwrap(),
+            );
+        }
+
+        {
+            // `EventSource` reconnects automatically on errors, which would
+            // start a new session on a server, so it's closed instead.
+            let inner = Rc::clone(&self.0);
+            self.0.borrow_mut().on_error_listener = Some(
+                EventListener::new_mut(
+                    Rc::clone(&source),
+                    "error",
+                    move |_: Event| {
+                        if inner.borrow().state.borrow().can_close() {
+                            inner.borrow_mut().close(CloseMsg::from((
+                                ABNORMAL_CLOSE_CODE,
+                                String::new(),
+                            )));
+                        }
+                    },
+                )
+                .unwrap(),
+            );
+        }
+
+        let state_updates_rx = self.0.borrow().state.subscribe();
+        let state = state_updates_rx.skip(1).next().await;
+
+        if state == Some(TransportState::Open) {
+            self.set_open_listeners(&source);
+            self.spawn_outbox();
+            Ok(())
+        } else {
+            Err(tracerr::new!(TransportError::InitSocket))
+        }
+    }
+
+    fn on_message(&self) -> LocalBoxStream<'static, ServerMsg> {
+        let (tx, rx) = mpsc::unbounded();
+        self.0.borrow_mut().on_message_subs.push(tx);
+
+        Box::pin(rx)
+    }
+
+    fn set_close_reason(&self, reason: ClientDisconnect) {
+        self.0.borrow_mut().close_reason = reason;
+    }
+
+    fn send(&self, msg: &ClientMsg) -> TransportResult<()> {
+        let inner = self.0.borrow();
+        let Frame::Text(text) =
+            Frame::encode(msg, WireFormat::Json).map_err(tracerr::wrap!())?
+        else {
+            unreachable!("`WireFormat::Json` is always encoded as text");
+        };
+
+        match *inner.state.borrow() {
+            TransportState::Open => inner.outbox.as_ref().map_or_else(
+                || Err(tracerr::new!(TransportError::ClosedSocket)),
+                |outbox| {
+                    outbox.unbounded_send(text).map_err(|_| {
+                        tracerr::new!(TransportError::ClosedSocket)
+                    })
+                },
+            ),
+            TransportState::Connecting
+            | TransportState::Closing
+            | TransportState::Closed(_) => {
+                Err(tracerr::new!(TransportError::ClosedSocket))
+            }
+        }
+    }
+
+    fn on_state_change(&self) -> LocalBoxStream<'static, TransportState> {
+        self.0.borrow().state.subscribe()
+    }
+}
+
+impl Drop for SseRpcTransport {
+    /// Don't forget that [`SseRpcTransport`] is a [`Rc`] and this [`Drop`]
+    /// implementation will be called on each drop of its references.
+    fn drop(&mut self) {
+        let mut inner = self.0.borrow_mut();
+        drop(inner.on_session_listener.take());
+        drop(inner.on_message_listener.take());
+        drop(inner.on_close_listener.take());
+        drop(inner.on_error_listener.take());
+    }
+}
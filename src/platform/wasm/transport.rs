@@ -10,14 +10,20 @@ use std::{cell::RefCell, rc::Rc};
 use async_trait::async_trait;
 use derive_more::with_trait::{From, Into};
 use futures::{StreamExt as _, channel::mpsc, stream::LocalBoxStream};
-use medea_client_api_proto::{ClientMsg, ServerMsg};
+use js_sys::{Array, ArrayBuffer, Uint8Array};
+use medea_client_api_proto::{
+    ClientMsg, CloseReason as CloseByServerReason, ServerMsg, WireFormat,
+};
 use medea_reactive::ObservableCell;
 use tracerr::Traced;
-use web_sys::{CloseEvent, Event, MessageEvent, WebSocket as SysWebSocket};
+use wasm_bindgen::{JsCast as _, JsValue};
+use web_sys::{
+    BinaryType, CloseEvent, Event, MessageEvent, WebSocket as SysWebSocket,
+};
 
 use crate::{
     platform::{
-        transport::{RpcTransport, TransportError, TransportState},
+        transport::{Frame, RpcTransport, TransportError, TransportState},
         wasm::utils::EventListener,
     },
     rpc::{ApiUrl, CloseMsg, websocket::ClientDisconnect},
@@ -33,13 +39,18 @@ impl TryFrom<&MessageEvent> for ServerMessage {
     type Error = TransportError;
 
     fn try_from(msg: &MessageEvent) -> Result<Self, Self::Error> {
-        use TransportError::{MessageNotString, ParseServerMessage};
-
-        let payload = msg.data().as_string().ok_or(MessageNotString)?;
-
-        serde_json::from_str::<ServerMsg>(&payload)
-            .map_err(|e| ParseServerMessage(e.into()))
-            .map(Self::from)
+        let data = msg.data();
+        let frame = if let Some(text) = data.as_string() {
+            Frame::Text(text)
+        } else {
+            let buf = data
+                .dyn_into::<ArrayBuffer>()
+                .ok()
+                .ok_or(TransportError::MessageNotString)?;
+            Frame::Binary(Uint8Array::new(&buf).to_vec())
+        };
+
+        frame.decode().map(Self::from)
     }
 }
 
@@ -60,6 +71,9 @@ struct InnerSocket {
     /// State of [`WebSocketRpcTransport`] connection.
     socket_state: ObservableCell<TransportState>,
 
+    /// [`WireFormat`] negotiated with the server.
+    wire_format: WireFormat,
+
     /// Listener for [WebSocket] [open event][1].
     ///
     /// [WebSocket]: https://developer.mozilla.org/docs/Web/API/WebSocket
@@ -95,6 +109,7 @@ impl InnerSocket {
         Self {
             socket_state: ObservableCell::new(TransportState::Connecting),
             socket: RefCell::new(None),
+            wire_format: WireFormat::Json,
             on_open_listener: None,
             on_message_listener: None,
             on_close_listener: None,
@@ -104,8 +119,11 @@ impl InnerSocket {
     }
 }
 
-impl Drop for InnerSocket {
-    fn drop(&mut self) {
+    /// Closes the underlying [WebSocket] with the current
+    /// [`InnerSocket::close_reason`], if it can be closed.
+    ///
+    /// [WebSocket]: https://developer.mozilla.org/docs/Web/API/WebSocket
+    fn close(&self) {
         if self.socket_state.borrow().can_close() {
             let code = self.close_reason.code();
             let rsn =
@@ -119,6 +137,29 @@ impl Drop for InnerSocket {
             }
         }
     }
+
+    /// Closes this [`InnerSocket`] with the
+    /// [`ClientDisconnect::IncompatibleProtocol`] reason, as the server sent a
+    /// [`ServerMsg`] which cannot be parsed.
+    ///
+    /// Moves it to the [`TransportState::Closed`] with the
+    /// [`CloseByServerReason::IncompatibleProtocol`], so the `Room` is closed
+    /// the same way as if the server rejected the client protocol.
+    fn close_incompatible(&mut self) {
+        self.close_reason = ClientDisconnect::IncompatibleProtocol;
+        drop(self.on_close_listener.take());
+        self.close();
+        self.socket_state.set(TransportState::Closed(CloseMsg::Normal(
+            self.close_reason.code(),
+            CloseByServerReason::IncompatibleProtocol,
+        )));
+    }
+}
+
+impl Drop for InnerSocket {
+    fn drop(&mut self) {
+        self.close();
+    }
 }
 
 /// WebSocket [`RpcTransport`] between a client and a server.
@@ -164,18 +205,17 @@ impl WebSocketRpcTransport {
         let this = Rc::clone(&self.0);
         let on_message =
             EventListener::new_mut(Rc::new(socket), "message", move |msg| {
+                let mut this_mut = this.borrow_mut();
                 let msg =
                     match ServerMessage::try_from(&msg).map(ServerMsg::from) {
                         Ok(parsed) => parsed,
                         Err(e) => {
-                            // TODO: Protocol versions mismatch? Should drop
-                            //       connection if so.
                             log::error!("{}", tracerr::new!(e));
+                            this_mut.close_incompatible();
                             return;
                         }
                     };
 
-                let mut this_mut = this.borrow_mut();
                 this_mut.on_message_subs.retain(|on_message| {
                     on_message.unbounded_send(msg.clone()).is_ok()
                 });
@@ -195,10 +235,16 @@ impl Default for WebSocketRpcTransport {
 #[async_trait(?Send)]
 impl RpcTransport for WebSocketRpcTransport {
     async fn connect(&self, url: ApiUrl) -> TransportResult<()> {
-        let socket = SysWebSocket::new(url.as_ref())
-            .map_err(Into::into)
-            .map_err(TransportError::CreateSocket)
-            .map_err(tracerr::wrap!())?;
+        let subprotocols = WireFormat::ALL
+            .into_iter()
+            .map(|f| JsValue::from_str(f.subprotocol()))
+            .collect::<Array>();
+        let socket =
+            SysWebSocket::new_with_str_sequence(url.as_ref(), &subprotocols)
+                .map_err(Into::into)
+                .map_err(TransportError::CreateSocket)
+                .map_err(tracerr::wrap!())?;
+        socket.set_binary_type(BinaryType::Arraybuffer);
         *self.0.borrow_mut().socket.borrow_mut() = Some(socket.clone());
         {
             {
@@ -242,6 +288,8 @@ impl RpcTransport for WebSocketRpcTransport {
         let state = state_updates_rx.skip(1).next().await;
 
         if state == Some(TransportState::Open) {
+            self.0.borrow_mut().wire_format =
+                WireFormat::from_subprotocol(&socket.protocol());
             self.set_on_close_listener(socket.clone());
             self.set_on_message_listener(socket);
             Ok(())
@@ -263,20 +311,23 @@ impl RpcTransport for WebSocketRpcTransport {
 
     fn send(&self, msg: &ClientMsg) -> TransportResult<()> {
         let inner = self.0.borrow();
-        let message = serde_json::to_string(msg)
-            .map_err(|e| TransportError::SerializeClientMessage(e.into()))
-            .map_err(tracerr::wrap!())?;
+        let frame =
+            Frame::encode(msg, inner.wire_format).map_err(tracerr::wrap!())?;
 
         let state = &*inner.socket_state.borrow();
         match state {
             TransportState::Open => inner.socket.borrow().as_ref().map_or_else(
                 || Err(tracerr::new!(TransportError::ClosedSocket)),
                 |socket| {
-                    socket
-                        .send_with_str(&message)
-                        .map_err(Into::into)
-                        .map_err(TransportError::SendMessage)
-                        .map_err(tracerr::wrap!())
+                    match &frame {
+                        Frame::Text(text) => socket.send_with_str(text),
+                        Frame::Binary(bytes) => {
+                            socket.send_with_u8_array(bytes)
+                        }
+                    }
+                    .map_err(Into::into)
+                    .map_err(TransportError::SendMessage)
+                    .map_err(tracerr::wrap!())
                 },
             ),
             TransportState::Connecting
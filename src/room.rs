@@ -4,6 +4,7 @@ use std::{
     cell::RefCell,
     collections::{HashMap, HashSet},
     rc::{Rc, Weak},
+    time::Duration,
 };
 
 use async_recursion::async_recursion;
@@ -26,8 +27,9 @@ use crate::{
     api,
     connection::Connections,
     media::{
-        InitLocalTracksError, LocalTracksConstraints, MediaKind, MediaManager,
-        MediaSourceKind, MediaStreamSettings, RecvConstraints,
+        InitLocalTracksError, LocalTracksConstraints,
+        MediaKind, MediaManager, MediaSourceKind, MediaStreamSettings,
+        RecvConstraints, VideoSource,
         track::{local, remote},
     },
     peer::{
@@ -41,11 +43,15 @@ use crate::{
     rpc::{
         ClientDisconnect, CloseReason, ConnectionInfo,
         ConnectionInfoParseError, ReconnectError, ReconnectHandleImpl,
-        RpcSession, SessionError,
+        RpcSession, SessionError, SignallingLatency,
     },
     utils::{AsProtoState as _, Caused},
 };
 
+/// Maximum time [`RoomHandleImpl::leave()`] waits for a media server to
+/// acknowledge the leaving.
+const LEAVE_ACK_TIMEOUT: Duration = Duration::from_secs(3);
+
 /// Alias of [`Result`]s related to [`MediaState`] update functions.
 type ChangeMediaStateResult = Result<(), Traced<ChangeMediaStateError>>;
 
@@ -221,14 +227,54 @@ impl RoomHandleImpl {
     ///
     /// See [`RoomJoinError`] for details.
     pub async fn join(&self, url: String) -> Result<(), Traced<RoomJoinError>> {
+        let connection_info: ConnectionInfo =
+            url.parse().map_err(tracerr::map_from_and_wrap!())?;
+
+        self.join_with_info(connection_info).await
+    }
+
+    /// Connects to a media server and joins the [`Room`] with the provided
+    /// `credential`, which is sent inside [`Command::JoinRoom`] only, so it
+    /// never appears in the connection URL.
+    ///
+    /// The `url` has the following format:
+    /// `{{ Host URL }}/{{ Room ID }}/{{ Member ID }}`
+    /// (e.g. `wss://medea.com/MyConf1/Alice`). A `token` query parameter is
+    /// ignored, if any.
+    ///
+    /// Additional HTTP `headers` are sent in a handshake request of the
+    /// connection with a media server (if the platform allows it).
+    ///
+    /// Establishes connection with a media server (if it doesn't exist
+    /// already).
+    ///
+    /// # Errors
+    ///
+    /// See [`RoomJoinError`] for details.
+    pub async fn join_with_credential(
+        &self,
+        url: String,
+        credential: String,
+        headers: Vec<(String, String)>,
+    ) -> Result<(), Traced<RoomJoinError>> {
+        let connection_info =
+            ConnectionInfo::with_credential(&url, credential.into(), headers)
+                .map_err(tracerr::map_from_and_wrap!())?;
+
+        self.join_with_info(connection_info).await
+    }
+
+    /// Connects to a media server and joins the [`Room`] using the provided
+    /// [`ConnectionInfo`].
+    async fn join_with_info(
+        &self,
+        connection_info: ConnectionInfo,
+    ) -> Result<(), Traced<RoomJoinError>> {
         let inner = self
             .0
             .upgrade()
             .ok_or_else(|| tracerr::new!(RoomJoinError::Detached))?;
 
-        let connection_info: ConnectionInfo =
-            url.parse().map_err(tracerr::map_from_and_wrap!())?;
-
         if !inner.on_failed_local_media.is_set() {
             return Err(tracerr::new!(RoomJoinError::CallbackNotSet(
                 "Room.on_failed_local_media()"
@@ -310,6 +356,75 @@ impl RoomHandleImpl {
             .map(|inner| inner.on_failed_local_media.set_func(f))
     }
 
+    /// Returns all the `Member`s currently present in this [`Room`]
+    /// (including the local one), regardless of any media being exchanged
+    /// with them.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn members(
+        &self,
+    ) -> Result<Vec<proto::Member>, Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0)
+            .map(|inner| inner.members.borrow().values().cloned().collect())
+    }
+
+    /// Sets `on_member_joined` callback, invoked when a new `Member` joins
+    /// this [`Room`].
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn on_member_joined(
+        &self,
+        f: platform::Function<api::RoomMember>,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0).map(|inner| inner.on_member_joined.set_func(f))
+    }
+
+    /// Sets `on_member_left` callback, invoked with an ID of a `Member` who
+    /// left this [`Room`].
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn on_member_left(
+        &self,
+        f: platform::Function<String>,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0).map(|inner| inner.on_member_left.set_func(f))
+    }
+
+    /// Sets `on_member_updated` callback, invoked when metadata of some
+    /// `Member` of this [`Room`] changes.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn on_member_updated(
+        &self,
+        f: platform::Function<api::RoomMember>,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0).map(|inner| inner.on_member_updated.set_func(f))
+    }
+
+    /// Sets `on_unknown_event` callback, invoked with a name of an event
+    /// received from a media server, which this client doesn't recognize.
+    ///
+    /// Is a diagnostic hook, since such events are ignored anyway. Most likely
+    /// indicates that the media server speaks a newer protocol version.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn on_unknown_event(
+        &self,
+        f: platform::Function<String>,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0).map(|inner| inner.on_unknown_event.set_func(f))
+    }
+
     /// Sets `on_connection_loss` callback, invoked when a connection with
     /// server is lost.
     ///
@@ -323,6 +438,102 @@ impl RoomHandleImpl {
         upgrade_inner!(self.0).map(|inner| inner.on_connection_loss.set_func(f))
     }
 
+    /// Sets `on_signalling_latency` callback, invoked with actual
+    /// [`SignallingLatency`] statistics each time a round-trip time of the
+    /// signalling connection with a media server is measured.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn on_signalling_latency(
+        &self,
+        f: platform::Function<api::SignallingLatency>,
+    ) -> Result<(), Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0)
+            .map(|inner| inner.on_signalling_latency.set_func(f))
+    }
+
+    /// Returns the current [`SignallingLatency`] statistics of the signalling
+    /// connection with a media server.
+    ///
+    /// Returns [`None`] if no round-trip time has been measured yet.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn signalling_latency(
+        &self,
+    ) -> Result<Option<SignallingLatency>, Traced<HandleDetachedError>> {
+        upgrade_inner!(self.0).map(|inner| inner.rpc.signalling_latency())
+    }
+
+    /// Returns [`platform::RpcTransportKind`] of the transport used by the
+    /// signalling connection with a media server.
+    ///
+    /// Returns [`None`] if the connection hasn't been established yet.
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn rpc_transport_kind(
+        &self,
+    ) -> Result<Option<platform::RpcTransportKind>, Traced<HandleDetachedError>>
+    {
+        upgrade_inner!(self.0).map(|inner| inner.rpc.transport_kind())
+    }
+
+    /// Leaves this [`Room`] gracefully, reporting the provided `reason` of
+    /// leaving to a media server.
+    ///
+    /// Waits for a media server to acknowledge the leaving for 3 seconds at
+    /// most, then stops all the [`local::Track`]s
+    /// sent in this [`Room`] and resolves with its final
+    /// [`RoomCloseReasonImpl`] (also passed to the `on_close` callback).
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub async fn leave(
+        &self,
+        reason: Option<String>,
+    ) -> Result<RoomCloseReasonImpl, Traced<HandleDetachedError>> {
+        let inner = upgrade_inner!(self.0)?;
+
+        let close_reason =
+            Rc::clone(&inner.rpc).leave(reason, LEAVE_ACK_TIMEOUT).await;
+        inner.set_close_reason(close_reason);
+        if let Some(on_left) = inner.on_left.take() {
+            on_left();
+        }
+
+        let tracks = inner
+            .peers
+            .get_all()
+            .iter()
+            .flat_map(|peer| peer.get_send_tracks())
+            .collect::<Vec<_>>();
+        future::join_all(tracks.iter().map(|track| track.stop())).await;
+
+        Ok(RoomCloseReasonImpl::new(close_reason))
+    }
+
+    /// Leaves this [`Room`] without waiting for anything, sending a
+    /// [`Command::LeaveRoom`] synchronously.
+    ///
+    /// Best-effort variant of the [`RoomHandleImpl::leave()`], intended to be
+    /// used when no asynchronous work can be awaited anymore (e.g. while a
+    /// web page is being unloaded).
+    ///
+    /// # Errors
+    ///
+    /// See [`HandleDetachedError`] for details.
+    pub fn leave_immediately(&self) -> Result<(), Traced<HandleDetachedError>> {
+        let inner = upgrade_inner!(self.0)?;
+        inner.set_close_reason(ClientDisconnect::RoomClosed.into());
+        inner.rpc.close_with_reason(ClientDisconnect::RoomClosed);
+        Ok(())
+    }
+
     /// Updates this [`Room`]s [`MediaStreamSettings`]. This affects all
     /// [`PeerConnection`]s in this [`Room`]. If [`MediaStreamSettings`] is
     /// configured for some [`Room`], then this [`Room`] can only send media
@@ -830,6 +1041,10 @@ impl Room {
             ///
             /// [`rpc::Client`]: crate::rpc::Client
             RpcClientReconnected,
+
+            /// Round-trip time of the signalling connection with the Media
+            /// Server has been measured.
+            SignallingLatency(SignallingLatency),
         }
 
         let (tx, peer_events_rx) = mpsc::unbounded();
@@ -846,6 +1061,10 @@ impl Room {
             .on_reconnected()
             .map(|()| RoomEvent::RpcClientReconnected)
             .fuse();
+        let mut rpc_signalling_latency = rpc
+            .on_signalling_latency()
+            .map(RoomEvent::SignallingLatency)
+            .fuse();
 
         let room = Rc::new(InnerRoom::new(rpc, media_manager, tx));
         let weak_room = Rc::downgrade(&room);
@@ -857,6 +1076,7 @@ impl Room {
                     event = peer_events_stream.select_next_some() => event,
                     event = rpc_connection_lost.select_next_some() => event,
                     event = rpc_client_reconnected.select_next_some() => event,
+                    event = rpc_signalling_latency.select_next_some() => event,
                     complete => break,
                 };
 
@@ -886,6 +1106,9 @@ impl Room {
                         RoomEvent::RpcClientReconnected => {
                             this_room.handle_rpc_connection_recovered();
                         }
+                        RoomEvent::SignallingLatency(latency) => {
+                            this_room.on_signalling_latency.call1(latency);
+                        }
                     }
                 } else {
                     log::error!("Inner Room dropped unexpectedly");
@@ -910,6 +1133,12 @@ impl Room {
         self.0.set_close_reason(reason);
     }
 
+    /// Sets the hook invoked once this [`Room`] is left via the
+    /// [`RoomHandleImpl::leave()`], replacing the previous one, if any.
+    pub fn on_left(&self, f: Box<dyn FnOnce()>) {
+        drop(self.0.on_left.replace(Some(f)));
+    }
+
     /// Creates a new external handle to [`Room`]. You can create them as many
     /// as you need.
     #[must_use]
@@ -939,6 +1168,12 @@ impl Room {
             .map_err(tracerr::map_from_and_wrap!())
     }
 
+    /// Pauses or resumes idle detection of the RPC connection of this
+    /// [`Room`].
+    pub fn set_idle_detection_paused(&self, paused: bool) {
+        self.0.rpc.set_idle_detection_paused(paused);
+    }
+
     /// Indicates whether this [`Room`] reference is the same as the given
     /// [`Room`] reference. Compares pointers, not values.
     #[must_use]
@@ -1001,9 +1236,33 @@ struct InnerRoom {
     /// Callback invoked when a [`RpcSession`] loses connection.
     on_connection_loss: platform::Callback<api::ReconnectHandle>,
 
+    /// Callback invoked when a round-trip time of the signalling connection
+    /// with a media server is measured.
+    on_signalling_latency: platform::Callback<api::SignallingLatency>,
+
     /// Callback invoked when this [`Room`] is closed.
     on_close: Rc<platform::Callback<api::RoomCloseReason>>,
 
+    /// All the `Member`s currently present in this [`Room`].
+    members: RefCell<HashMap<MemberId, proto::Member>>,
+
+    /// Callback invoked when a new `Member` joins this [`Room`].
+    on_member_joined: platform::Callback<api::RoomMember>,
+
+    /// Callback invoked when a `Member` leaves this [`Room`].
+    on_member_left: platform::Callback<String>,
+
+    /// Callback invoked when metadata of a `Member` of this [`Room`] changes.
+    on_member_updated: platform::Callback<api::RoomMember>,
+
+    /// Callback invoked when an unrecognized [`RpcEvent`] is received.
+    on_unknown_event: platform::Callback<String>,
+
+    /// Hook invoked once this [`Room`] is left via the
+    /// [`RoomHandleImpl::leave()`].
+    #[debug(skip)]
+    on_left: RefCell<Option<Box<dyn FnOnce()>>>,
+
     /// Reason of [`Room`] closing.
     ///
     /// This [`CloseReason`] will be provided into [`RoomHandleImpl::on_close`]
@@ -1138,15 +1397,46 @@ impl InnerRoom {
             recv_constraints,
             connections,
             on_connection_loss: platform::Callback::default(),
+            on_signalling_latency: platform::Callback::default(),
             on_failed_local_media: Rc::new(platform::Callback::default()),
             on_local_track: platform::Callback::default(),
             on_close: Rc::new(platform::Callback::default()),
+            members: RefCell::default(),
+            on_member_joined: platform::Callback::default(),
+            on_member_left: platform::Callback::default(),
+            on_member_updated: platform::Callback::default(),
+            on_unknown_event: platform::Callback::default(),
+            on_left: RefCell::default(),
             close_reason: RefCell::new(CloseReason::ByClient {
                 reason: ClientDisconnect::RoomUnexpectedlyDropped,
             }),
         }
     }
 
+    /// Replaces the [`InnerRoom::members`] roster with the provided one,
+    /// invoking `on_member_joined`, `on_member_left` and `on_member_updated`
+    /// callbacks for the detected changes.
+    fn sync_members(&self, members: impl IntoIterator<Item = proto::Member>) {
+        let mut new: HashMap<_, _> =
+            members.into_iter().map(|m| (m.id.clone(), m)).collect();
+        let old = self.members.replace(new.clone());
+
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for (id, member) in old {
+            match new.remove(&id) {
+                Some(actual) => {
+                    if actual != member {
+                        self.on_member_updated.call1(actual);
+                    }
+                }
+                None => self.on_member_left.call1(id.0),
+            }
+        }
+        for member in new.into_values() {
+            self.on_member_joined.call1(member);
+        }
+    }
+
     /// Toggles [`InnerRoom::recv_constraints`] or
     /// [`InnerRoom::send_constraints`] media exchange status based on the
     /// provided [`TrackDirection`], [`MediaKind`] and
@@ -1434,10 +1724,32 @@ impl InnerRoom {
 
         let current_settings = self.send_constraints.inner();
         self.send_constraints.constrain(new_settings);
-        let criteria_kinds_diff =
+        let mut criteria_kinds_diff =
             self.send_constraints.calculate_kinds_diff(&current_settings);
         let peers = self.peers.get_all();
 
+        // Changes of non-identity constraints are applied to live tracks in
+        // place, so they don't need to be re-acquired.
+        //
+        // `medea_flutter_webrtc` cannot change constraints of a live track, so
+        // on native platforms the tracks are always re-acquired instead.
+        let live_updates = if cfg!(target_family = "wasm") {
+            self.send_constraints.inner().live_video_updates(&current_settings)
+        } else {
+            Vec::new()
+        };
+        for source in live_updates {
+            let source_kind = match source {
+                VideoSource::Device(_) => proto::MediaSourceKind::Device,
+                VideoSource::Display(_) => proto::MediaSourceKind::Display,
+            };
+            if Self::apply_live_video_constraints(&peers, source, source_kind)
+                .await
+            {
+                criteria_kinds_diff.remove(MediaKind::Video, source_kind);
+            }
+        }
+
         if stop_first {
             for peer in &peers {
                 peer.drop_send_tracks(criteria_kinds_diff).await;
@@ -1512,6 +1824,45 @@ impl InnerRoom {
             .map_err(|e| E::errored(tracerr::map_from_and_new!(e)))
     }
 
+    /// Applies the provided [`VideoSource`] constraints to the live video
+    /// [`local::Track`]s of the provided `source_kind` being sent by the
+    /// provided [`PeerConnection`]s, without re-acquiring them.
+    ///
+    /// Returns `false` if there are no such [`local::Track`]s, or the
+    /// constraints cannot be applied to some of them, so they should be
+    /// re-acquired instead.
+    async fn apply_live_video_constraints(
+        peers: &[Rc<PeerConnection>],
+        source: VideoSource,
+        source_kind: proto::MediaSourceKind,
+    ) -> bool {
+        let mut tracks: Vec<Rc<local::Track>> = Vec::new();
+        for track in peers.iter().flat_map(|p| p.get_send_tracks()) {
+            if track.kind() == MediaKind::Video
+                && track.media_source_kind() == source_kind
+                && !tracks.iter().any(|t| Rc::ptr_eq(t, &track))
+            {
+                tracks.push(track);
+            }
+        }
+        if tracks.is_empty() {
+            return false;
+        }
+
+        let results = future::join_all(
+            tracks.iter().map(|t| t.apply_constraints(source.clone())),
+        )
+        .await;
+        let mut applied = true;
+        for res in results {
+            if let Err(e) = res {
+                log::warn!("Failed to apply constraints in place: {e}");
+                applied = false;
+            }
+        }
+        applied
+    }
+
     /// Stops state transition timers in all [`PeerConnection`]'s in this
     /// [`Room`].
     fn handle_rpc_connection_lost(&self) {
@@ -1527,9 +1878,9 @@ impl InnerRoom {
     /// [`Room`].
     fn handle_rpc_connection_recovered(&self) {
         self.peers.connection_recovered();
-        self.rpc.send_command(Command::SynchronizeMe {
-            state: self.peers.state().as_proto(),
-        });
+        let mut state = self.peers.state().as_proto();
+        state.members.clone_from(&self.members.borrow());
+        self.rpc.send_command(Command::SynchronizeMe { state });
     }
 }
 
@@ -1676,6 +2027,23 @@ impl EventHandler for InnerRoom {
         Ok(())
     }
 
+    /// Replaces [`IceServer`]s of the [`peer::State`] with the provided
+    /// [`PeerId`].
+    async fn on_ice_servers_updated(
+        &self,
+        peer_id: PeerId,
+        ice_servers: Vec<IceServer>,
+    ) -> Self::Output {
+        let peer_state = self
+            .peers
+            .state()
+            .get(peer_id)
+            .ok_or_else(|| tracerr::new!(UnknownPeerIdError(peer_id)))?;
+        peer_state.set_ice_servers(ice_servers);
+
+        Ok(())
+    }
+
     /// Updates [`Connection`]'s [`ConnectionQualityScore`] by calling
     /// [`Connection::update_quality_score()`][1].
     ///
@@ -1692,8 +2060,15 @@ impl EventHandler for InnerRoom {
         Ok(())
     }
 
-    async fn on_room_joined(&self, _: MemberId, _: bool) -> Self::Output {
-        unreachable!("Room can't receive Event::RoomJoined")
+    /// Replaces the [`InnerRoom::members`] roster with the provided one.
+    async fn on_room_joined(
+        &self,
+        _: MemberId,
+        _: bool,
+        members: Vec<proto::Member>,
+    ) -> Self::Output {
+        self.sync_members(members);
+        Ok(())
     }
 
     async fn on_room_left(
@@ -1710,9 +2085,47 @@ impl EventHandler for InnerRoom {
         state: proto::state::Room,
     ) -> Self::Output {
         self.connections.apply(&state);
+        self.sync_members(state.members.values().cloned());
         self.peers.apply(state);
         Ok(())
     }
+
+    /// Adds the provided [`proto::Member`] to the [`InnerRoom::members`] and
+    /// invokes `on_member_joined` callback.
+    async fn on_member_joined(&self, member: proto::Member) -> Self::Output {
+        drop(
+            self.members.borrow_mut().insert(member.id.clone(), member.clone()),
+        );
+        self.on_member_joined.call1(member);
+        Ok(())
+    }
+
+    /// Removes the [`proto::Member`] with the provided [`MemberId`] from the
+    /// [`InnerRoom::members`] and invokes `on_member_left` callback.
+    async fn on_member_left(&self, member_id: MemberId) -> Self::Output {
+        if self.members.borrow_mut().remove(&member_id).is_some() {
+            self.on_member_left.call1(member_id.0);
+        }
+        Ok(())
+    }
+
+    /// Updates the provided [`proto::Member`] in the [`InnerRoom::members`]
+    /// and invokes `on_member_updated` callback.
+    async fn on_member_updated(&self, member: proto::Member) -> Self::Output {
+        drop(
+            self.members.borrow_mut().insert(member.id.clone(), member.clone()),
+        );
+        self.on_member_updated.call1(member);
+        Ok(())
+    }
+
+    /// Logs the unrecognized [`RpcEvent`] and invokes `on_unknown_event`
+    /// callback.
+    async fn on_unknown(&self, event: String) -> Self::Output {
+        log::warn!("Unknown `Event` received: {event}");
+        self.on_unknown_event.call1(event);
+        Ok(())
+    }
 }
 
 /// Error of a [`PeerEvent::NewRemoteTrack`] containing an unknown remote
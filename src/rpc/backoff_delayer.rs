@@ -53,7 +53,8 @@ impl BackoffDelayer {
     }
 }
 
-/// [`backoff::future::Sleeper`] implementation using [`platform::delay_for()`].
+/// [`backoff::future::Sleeper`] implementation using the current
+/// [`platform::Clock`].
 struct Sleeper;
 
 impl backoff::future::Sleeper for Sleeper {
@@ -62,7 +63,7 @@ impl backoff::future::Sleeper for Sleeper {
     fn sleep(&self, dur: Duration) -> Self::Sleep {
         let (tx, rx) = oneshot::channel();
         platform::spawn(async move {
-            platform::delay_for(dur).await;
+            platform::clock().delay_for(dur).await;
             _ = tx.send(());
         });
         Box::pin(rx.map(drop))
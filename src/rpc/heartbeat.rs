@@ -1,4 +1,4 @@
-//! Connection loss detection via ping/pong mechanism.
+//! Connection loss detection and latency measurement via ping/pong mechanism.
 
 use std::{cell::RefCell, rc::Rc, time::Duration};
 
@@ -20,6 +20,45 @@ pub struct IdleTimeout(pub Duration);
 #[derive(Clone, Copy, Debug, Mul)]
 pub struct PingInterval(pub Duration);
 
+/// Round-trip latency statistics of a signalling connection with a media
+/// server, measured via [`ClientMsg::Ping`]s.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SignallingLatency {
+    /// Round-trip time of the last [`ClientMsg::Ping`].
+    pub rtt: Duration,
+
+    /// Smoothed round-trip time, as defined in [RFC 6298].
+    ///
+    /// [RFC 6298]: https://tools.ietf.org/html/rfc6298#section-2
+    pub smoothed_rtt: Duration,
+
+    /// Round-trip time variation, calculated in the same way as interarrival
+    /// jitter defined in [RFC 3550].
+    ///
+    /// [RFC 3550]: https://tools.ietf.org/html/rfc3550#section-6.4.1
+    pub jitter: Duration,
+
+    /// Number of round-trip time samples these statistics are calculated
+    /// from.
+    pub samples: u32,
+}
+
+impl SignallingLatency {
+    /// Accounts the provided round-trip time sample in these
+    /// [`SignallingLatency`] statistics.
+    fn add_sample(&mut self, rtt: Duration) {
+        if self.samples == 0 {
+            self.smoothed_rtt = rtt;
+        } else {
+            self.smoothed_rtt = (self.smoothed_rtt * 7 + rtt) / 8;
+            let diff = rtt.abs_diff(self.rtt);
+            self.jitter = (self.jitter * 15 + diff) / 16;
+        }
+        self.rtt = rtt;
+        self.samples = self.samples.saturating_add(1);
+    }
+}
+
 /// Inner data of [`Heartbeat`].
 #[derive(Debug)]
 struct Inner {
@@ -40,11 +79,34 @@ struct Inner {
     /// [`TaskHandle`] for idle watchdog.
     idle_watchdog_task: Option<TaskHandle>,
 
+    /// Indicator whether idle detection is paused, so no idle watchdog is
+    /// running.
+    idle_detection_paused: bool,
+
     /// Number of last received [`ServerMsg::Ping`].
     last_ping_num: u32,
 
+    /// Number and sending timestamp of the last sent [`ClientMsg::Ping`],
+    /// which hasn't been answered yet.
+    pending_probe: Option<(u32, platform::Instant)>,
+
+    /// Number of last sent [`ClientMsg::Ping`].
+    last_probe_num: u32,
+
+    /// Indicator whether a server answers [`ClientMsg::Ping`]s, so they're
+    /// sent for measuring [`SignallingLatency`].
+    latency_probes: bool,
+
+    /// Current [`SignallingLatency`] statistics.
+    ///
+    /// [`None`] if no [`ServerMsg::Pong`] has been received yet.
+    latency: Option<SignallingLatency>,
+
     /// [`mpsc::UnboundedSender`]s for a [`Heartbeat::on_idle`].
     on_idle_subs: Vec<mpsc::UnboundedSender<()>>,
+
+    /// [`mpsc::UnboundedSender`]s for a [`Heartbeat::on_latency`].
+    on_latency_subs: Vec<mpsc::UnboundedSender<SignallingLatency>>,
 }
 
 impl Inner {
@@ -58,6 +120,39 @@ impl Inner {
             .map_err(tracerr::wrap!(=> platform::TransportError))
             .map_err(|e| log::error!("Failed to send pong: {e}"));
     }
+
+    /// Sends a new [`ClientMsg::Ping`] to a server for measuring round-trip
+    /// time, superseding the previous one if it hasn't been answered yet.
+    ///
+    /// If some error happen then it will be printed with [`log::error`].
+    fn send_probe(&mut self) {
+        self.last_probe_num = self.last_probe_num.wrapping_add(1);
+        let num = self.last_probe_num;
+        self.pending_probe = Some((num, platform::Instant::now()));
+        _ = self
+            .transport
+            .send(&ClientMsg::Ping(num))
+            .map_err(tracerr::wrap!(=> platform::TransportError))
+            .map_err(|e| log::error!("Failed to send ping: {e}"));
+    }
+
+    /// Accounts the received [`ServerMsg::Pong`] in [`SignallingLatency`]
+    /// statistics, if it answers the pending [`ClientMsg::Ping`].
+    fn handle_probe_answer(&mut self, num: u32) {
+        let Some((pending_num, sent_at)) = self.pending_probe else {
+            return;
+        };
+        if pending_num != num {
+            return;
+        }
+        self.pending_probe = None;
+
+        let rtt = sent_at.elapsed();
+        let latency = self.latency.get_or_insert_default();
+        latency.add_sample(rtt);
+        let latency = *latency;
+        self.on_latency_subs.retain(|sub| sub.unbounded_send(latency).is_ok());
+    }
 }
 
 /// Detector of connection loss via ping/pong mechanism.
@@ -79,8 +174,14 @@ impl Heartbeat {
             transport,
             handle_ping_task: None,
             idle_watchdog_task: None,
+            idle_detection_paused: false,
             on_idle_subs: Vec::new(),
+            on_latency_subs: Vec::new(),
             last_ping_num: 0,
+            pending_probe: None,
+            last_probe_num: 0,
+            latency_probes: false,
+            latency: None,
         }));
 
         let handle_ping_task = spawn_ping_handle_task(Rc::clone(&inner));
@@ -102,6 +203,44 @@ impl Heartbeat {
         self.0.borrow_mut().ping_interval = ping_interval;
     }
 
+    /// Enables or disables [`SignallingLatency`] measurement of this
+    /// [`Heartbeat`].
+    ///
+    /// Should be enabled only if a server answers [`ClientMsg::Ping`]s, as
+    /// indicated by [`RpcSettings::latency_probes`].
+    ///
+    /// [`RpcSettings::latency_probes`]: medea_client_api_proto::RpcSettings::latency_probes
+    pub fn set_latency_probes(&self, enabled: bool) {
+        let mut inner = self.0.borrow_mut();
+        inner.latency_probes = enabled;
+        if !enabled {
+            inner.pending_probe = None;
+        }
+    }
+
+    /// Pauses idle detection of this [`Heartbeat`] until
+    /// [`Heartbeat::resume_idle_detection()`] is called.
+    ///
+    /// Should be used when timers are not reliable, for example, while a web
+    /// page is frozen, so the connection is not considered idle falsely.
+    pub fn pause_idle_detection(&self) {
+        let mut inner = self.0.borrow_mut();
+        inner.idle_detection_paused = true;
+        drop(inner.idle_watchdog_task.take());
+    }
+
+    /// Resumes idle detection of this [`Heartbeat`] paused by
+    /// [`Heartbeat::pause_idle_detection()`], starting its countdown from the
+    /// beginning.
+    pub fn resume_idle_detection(&self) {
+        if !self.0.borrow().idle_detection_paused {
+            return;
+        }
+        self.0.borrow_mut().idle_detection_paused = false;
+        let idle_task = spawn_idle_watchdog_task(Rc::clone(&self.0));
+        self.0.borrow_mut().idle_watchdog_task = Some(idle_task);
+    }
+
     /// Returns [`LocalBoxStream`] to which will sent `()` when [`Heartbeat`]
     /// considers that [`platform::RpcTransport`] is idle.
     #[must_use]
@@ -111,6 +250,23 @@ impl Heartbeat {
 
         Box::pin(on_idle_rx)
     }
+
+    /// Returns [`LocalBoxStream`] to which actual [`SignallingLatency`]
+    /// statistics will be sent on each [`ServerMsg::Pong`] received.
+    #[must_use]
+    pub fn on_latency(&self) -> LocalBoxStream<'static, SignallingLatency> {
+        let (tx, rx) = mpsc::unbounded();
+        self.0.borrow_mut().on_latency_subs.push(tx);
+
+        Box::pin(rx)
+    }
+
+    /// Returns the current [`SignallingLatency`] statistics, if any has been
+    /// measured yet.
+    #[must_use]
+    pub fn latency(&self) -> Option<SignallingLatency> {
+        self.0.borrow().latency
+    }
 }
 
 /// Spawns idle watchdog task returning its handle.
@@ -124,7 +280,7 @@ fn spawn_idle_watchdog_task(this: Rc<RefCell<Inner>>) -> TaskHandle {
     let (idle_watchdog_fut, idle_watchdog_handle) =
         future::abortable(async move {
             let wait_for_ping = this.borrow().ping_interval * 2;
-            platform::delay_for(wait_for_ping.0).await;
+            platform::clock().delay_for(wait_for_ping.0).await;
 
             let last_ping_num = this.borrow().last_ping_num;
             this.borrow().send_pong(last_ping_num + 1);
@@ -135,7 +291,7 @@ fn spawn_idle_watchdog_task(this: Rc<RefCell<Inner>>) -> TaskHandle {
                 .0
                 .checked_sub(wait_for_ping.0)
                 .expect("`wait_for_ping` > `idle_timeout`");
-            platform::delay_for(delay).await;
+            platform::clock().delay_for(delay).await;
             this.borrow_mut()
                 .on_idle_subs
                 .retain(|sub| sub.unbounded_send(()).is_ok());
@@ -151,18 +307,31 @@ fn spawn_idle_watchdog_task(this: Rc<RefCell<Inner>>) -> TaskHandle {
 /// Spawns ping handle task returning its handle.
 ///
 /// This task is responsible for answering [`ServerMsg::Ping`] with
-/// [`ClientMsg::Pong`] and renewing idle watchdog task.
+/// [`ClientMsg::Pong`], renewing idle watchdog task, and measuring
+/// [`SignallingLatency`] by sending [`ClientMsg::Ping`] along with each
+/// [`ClientMsg::Pong`] (if enabled).
 fn spawn_ping_handle_task(this: Rc<RefCell<Inner>>) -> TaskHandle {
     let mut on_message_stream = this.borrow().transport.on_message();
 
     let (handle_ping_fut, handle_ping_task) = future::abortable(async move {
         while let Some(msg) = on_message_stream.next().await {
-            let idle_task = spawn_idle_watchdog_task(Rc::clone(&this));
-            this.borrow_mut().idle_watchdog_task = Some(idle_task);
+            if !this.borrow().idle_detection_paused {
+                let idle_task = spawn_idle_watchdog_task(Rc::clone(&this));
+                this.borrow_mut().idle_watchdog_task = Some(idle_task);
+            }
 
-            if let ServerMsg::Ping(num) = msg {
-                this.borrow_mut().last_ping_num = num;
-                this.borrow().send_pong(num);
+            match msg {
+                ServerMsg::Ping(num) => {
+                    this.borrow_mut().last_ping_num = num;
+                    this.borrow().send_pong(num);
+                    if this.borrow().latency_probes {
+                        this.borrow_mut().send_probe();
+                    }
+                }
+                ServerMsg::Pong(num) => {
+                    this.borrow_mut().handle_probe_answer(num);
+                }
+                ServerMsg::Event { .. } | ServerMsg::RpcSettings(_) => {}
             }
         }
     });
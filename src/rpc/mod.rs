@@ -3,6 +3,8 @@
 mod backoff_delayer;
 mod heartbeat;
 mod reconnect_handle;
+#[cfg(feature = "rpc-recording")]
+pub mod recording;
 pub mod rpc_session;
 pub mod websocket;
 
@@ -16,12 +18,18 @@ use medea_client_api_proto::{
 use tracerr::Traced;
 use url::Url;
 
+#[cfg(feature = "rpc-recording")]
+#[doc(inline)]
+pub use self::recording::{
+    PeerActivity, PeerAnnotation, RecordedMsg, RecordingParseError,
+    ReplayRpcTransport, RpcRecorder, parse_recording,
+};
 #[cfg(feature = "mockable")]
 pub use self::rpc_session::MockRpcSession;
 #[doc(inline)]
 pub use self::{
     backoff_delayer::BackoffDelayer,
-    heartbeat::{Heartbeat, IdleTimeout, PingInterval},
+    heartbeat::{Heartbeat, IdleTimeout, PingInterval, SignallingLatency},
     reconnect_handle::{ReconnectError, ReconnectHandleImpl},
     rpc_session::{
         RpcSession, SessionError, SessionState, WebSocketRpcSession,
@@ -30,10 +38,78 @@ pub use self::{
 };
 use crate::{platform, utils::Caused};
 
-/// [`Url`] to which transport layer will connect.
-#[derive(AsRef, Clone, Debug, Eq, From, PartialEq)]
-#[as_ref(forward)]
-pub struct ApiUrl(Url);
+/// [`Url`] to which transport layer will connect, along with additional
+/// headers of its handshake request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApiUrl {
+    /// [`Url`] to which transport layer will connect.
+    url: Url,
+
+    /// Additional HTTP headers to be sent in a handshake request of transport
+    /// layer.
+    ///
+    /// Ignored by platforms not allowing to specify them (web browsers).
+    headers: Vec<(String, String)>,
+}
+
+impl ApiUrl {
+    /// Sets additional HTTP `headers` to be sent in a handshake request of
+    /// transport layer.
+    #[must_use]
+    pub fn with_headers(mut self, headers: Vec<(String, String)>) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Returns additional HTTP headers to be sent in a handshake request of
+    /// transport layer.
+    #[must_use]
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Indicates whether this [`ApiUrl`] points to the same server endpoint as
+    /// the `other` one.
+    ///
+    /// Only scheme, host, port and path are compared, so [`ApiUrl`]s differing
+    /// in their query, fragment or handshake headers are considered the same.
+    #[must_use]
+    pub fn is_same_server(&self, other: &Self) -> bool {
+        let (this, other) = (&self.url, &other.url);
+        this.scheme() == other.scheme()
+            && this.host_str() == other.host_str()
+            && this.port_or_known_default() == other.port_or_known_default()
+            && this.path() == other.path()
+    }
+
+    /// Returns this [`ApiUrl`] with its `ws` or `wss` scheme replaced with
+    /// `http` or `https` accordingly.
+    ///
+    /// Used by [`platform::RpcTransport`]s working over plain HTTP.
+    #[must_use]
+    pub fn to_http(&self) -> Self {
+        let mut url = self.url.clone();
+        let scheme = match url.scheme() {
+            "ws" => "http",
+            "wss" => "https",
+            _ => return self.clone(),
+        };
+        _ = url.set_scheme(scheme);
+        Self { url, headers: self.headers.clone() }
+    }
+}
+
+impl From<Url> for ApiUrl {
+    fn from(url: Url) -> Self {
+        Self { url, headers: Vec::new() }
+    }
+}
+
+impl AsRef<str> for ApiUrl {
+    fn as_ref(&self) -> &str {
+        self.url.as_ref()
+    }
+}
 
 /// Information about [`RpcSession`] connection.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -76,29 +152,42 @@ impl ConnectionInfo {
     pub const fn credential(&self) -> &Credential {
         &self.credential
     }
-}
-
-/// Errors which can occur while [`ConnectionInfo`] parsing from the [`str`].
-#[derive(Caused, Clone, Copy, Debug, Display)]
-#[cause(error = platform::Error)]
-pub enum ConnectionInfoParseError {
-    /// [`Url::parse`] returned error.
-    #[display("Failed to parse provided URL: {_0}")]
-    UrlParse(url::ParseError),
-
-    /// Provided URL doesn't have important segments.
-    #[display("Provided URL doesn't have important segments")]
-    NotEnoughSegments,
 
-    /// Provided URL doesn't contain auth token.
-    #[display("Provided URL does not contain auth token")]
-    NoToken,
-}
-
-impl FromStr for ConnectionInfo {
-    type Err = Traced<ConnectionInfoParseError>;
+    /// Parses [`ConnectionInfo`] from the provided `url` using the provided
+    /// [`Credential`] instead of the `token` query parameter, so the
+    /// [`Credential`] doesn't need to be a part of the `url`.
+    ///
+    /// Additional HTTP `headers` will be sent in a handshake request of
+    /// transport layer (if the platform allows it).
+    ///
+    /// # Errors
+    ///
+    /// See [`ConnectionInfoParseError`] for details.
+    pub fn with_credential(
+        url: &str,
+        credential: Credential,
+        headers: Vec<(String, String)>,
+    ) -> Result<Self, Traced<ConnectionInfoParseError>> {
+        let (url, room_id, member_id, _) =
+            Self::parse_url(url).map_err(tracerr::wrap!())?;
+
+        Ok(Self {
+            url: url.with_headers(headers),
+            room_id,
+            member_id,
+            credential,
+        })
+    }
 
-    fn from_str(string: &str) -> Result<Self, Self::Err> {
+    /// Parses the provided `url` into its [`ApiUrl`], [`RoomId`],
+    /// [`MemberId`] and [`Credential`] (if present as the `token` query
+    /// parameter).
+    fn parse_url(
+        string: &str,
+    ) -> Result<
+        (ApiUrl, RoomId, MemberId, Option<Credential>),
+        Traced<ConnectionInfoParseError>,
+    > {
         use ConnectionInfoParseError as E;
 
         let mut url = Url::parse(string)
@@ -107,10 +196,7 @@ impl FromStr for ConnectionInfo {
         let credential = url
             .query_pairs()
             .find(|(key, _)| key.as_ref() == "token")
-            .ok_or_else(|| tracerr::new!(E::NoToken))?
-            .1
-            .clone()
-            .into();
+            .map(|(_, token)| token.into_owned().into());
 
         url.set_fragment(None);
         url.set_query(None);
@@ -135,7 +221,37 @@ impl FromStr for ConnectionInfo {
             _ = s.pop().pop();
         }
 
-        Ok(Self { url: url.into(), room_id, member_id, credential })
+        Ok((url.into(), room_id, member_id, credential))
+    }
+}
+
+/// Errors which can occur while [`ConnectionInfo`] parsing from the [`str`].
+#[derive(Caused, Clone, Copy, Debug, Display)]
+#[cause(error = platform::Error)]
+pub enum ConnectionInfoParseError {
+    /// [`Url::parse`] returned error.
+    #[display("Failed to parse provided URL: {_0}")]
+    UrlParse(url::ParseError),
+
+    /// Provided URL doesn't have important segments.
+    #[display("Provided URL doesn't have important segments")]
+    NotEnoughSegments,
+
+    /// Provided URL doesn't contain auth token.
+    #[display("Provided URL does not contain auth token")]
+    NoToken,
+}
+
+impl FromStr for ConnectionInfo {
+    type Err = Traced<ConnectionInfoParseError>;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (url, room_id, member_id, credential) =
+            Self::parse_url(string).map_err(tracerr::wrap!())?;
+        let credential = credential
+            .ok_or_else(|| tracerr::new!(ConnectionInfoParseError::NoToken))?;
+
+        Ok(Self { url, room_id, member_id, credential })
     }
 }
 
@@ -175,6 +291,9 @@ pub enum ClosedStateReason {
 
     /// Client wants to recreate a new connection, so the current was closed.
     ClosedForReconnection,
+
+    /// All the `Room`s sharing the connection have been left, so it was closed.
+    AllRoomsLeft,
 }
 
 /// Reason of why [`WebSocketRpcClient`]/[`platform::RpcTransport`] lost
@@ -208,6 +327,12 @@ pub enum RpcClientError {
     /// Occurs if [`WebSocketRpcClient::connect`] fails.
     #[display("Connection failed: {_0:?}")]
     ConnectionFailed(ClosedStateReason),
+
+    /// Occurs if [`WebSocketRpcClient::connect_room`] is called with a URL of
+    /// another server, while the current connection is still used by some
+    /// `Room`s.
+    #[display("Connection is used by `Room`s of another server")]
+    UsedByAnotherServer,
 }
 
 /// Connection with remote was closed.
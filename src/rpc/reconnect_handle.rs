@@ -50,7 +50,9 @@ impl ReconnectHandleImpl {
         &self,
         delay_ms: u32,
     ) -> Result<(), Traced<ReconnectError>> {
-        platform::delay_for(Duration::from_millis(u64::from(delay_ms))).await;
+        platform::clock()
+            .delay_for(Duration::from_millis(u64::from(delay_ms)))
+            .await;
 
         let rpc = self
             .0
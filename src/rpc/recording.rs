@@ -0,0 +1,440 @@
+//! Recording and replaying of RPC sessions for bugs reproduction.
+
+use std::{cell::RefCell, mem, rc::Rc};
+
+use async_trait::async_trait;
+use derive_more::with_trait::Display;
+use futures::{channel::mpsc, stream::LocalBoxStream};
+use medea_client_api_proto::{
+    ClientMsg, Command, Credential, Event, PeerId, ServerMsg,
+};
+use medea_reactive::ObservableCell;
+use serde::{Deserialize, Serialize};
+use tracerr::Traced;
+
+use crate::{
+    platform,
+    rpc::{ApiUrl, ClientDisconnect},
+    utils::{Caused, JsonParseError},
+};
+
+/// Placeholder replacing [`Credential`]s in the recorded messages.
+const REDACTED_CREDENTIAL: &str = "<redacted>";
+
+/// Activity of a `Peer` a [`RecordedMsg`] is related to.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PeerActivity {
+    /// `Peer` is created.
+    Created,
+
+    /// SDP offer of a `Peer` is sent or applied by a server.
+    SdpOffer,
+
+    /// SDP answer of a `Peer` is sent or received.
+    SdpAnswer,
+
+    /// ICE candidate of a `Peer` is sent or received.
+    IceCandidate,
+
+    /// `Peer` is updated with `PeerUpdate`s, or its tracks update is
+    /// requested.
+    Updated,
+
+    /// ICE servers of a `Peer` are updated.
+    IceServersUpdated,
+
+    /// Metrics of a `Peer` are reported.
+    Metrics,
+
+    /// `Peer`s are removed.
+    Removed,
+}
+
+/// Annotation of a [`RecordedMsg`] with the `Peer`s it's related to.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PeerAnnotation {
+    /// IDs of the `Peer`s the [`RecordedMsg`] is related to.
+    pub peer_ids: Vec<PeerId>,
+
+    /// [`PeerActivity`] the [`RecordedMsg`] represents.
+    pub activity: PeerActivity,
+}
+
+impl PeerAnnotation {
+    /// Creates a new [`PeerAnnotation`] for a single `Peer`.
+    fn single(peer_id: PeerId, activity: PeerActivity) -> Self {
+        Self { peer_ids: vec![peer_id], activity }
+    }
+
+    /// Returns a [`PeerAnnotation`] of the provided [`ServerMsg`], if it's
+    /// related to any `Peer`s.
+    fn of_server_msg(msg: &ServerMsg) -> Option<Self> {
+        let ServerMsg::Event { event, .. } = msg else {
+            return None;
+        };
+        match event {
+            Event::PeerCreated { peer_id, .. } => {
+                Some(Self::single(*peer_id, PeerActivity::Created))
+            }
+            Event::SdpAnswerMade { peer_id, .. } => {
+                Some(Self::single(*peer_id, PeerActivity::SdpAnswer))
+            }
+            Event::LocalDescriptionApplied { peer_id, .. } => {
+                Some(Self::single(*peer_id, PeerActivity::SdpOffer))
+            }
+            Event::IceCandidateDiscovered { peer_id, .. } => {
+                Some(Self::single(*peer_id, PeerActivity::IceCandidate))
+            }
+            Event::PeerUpdated { peer_id, .. } => {
+                Some(Self::single(*peer_id, PeerActivity::Updated))
+            }
+            Event::IceServersUpdated { peer_id, .. } => {
+                Some(Self::single(*peer_id, PeerActivity::IceServersUpdated))
+            }
+            Event::PeersRemoved { peer_ids } => Some(Self {
+                peer_ids: peer_ids.clone(),
+                activity: PeerActivity::Removed,
+            }),
+            Event::RoomJoined { .. }
+            | Event::RoomLeft { .. }
+            | Event::ConnectionQualityUpdated { .. }
+            | Event::StateSynchronized { .. }
+            | Event::MemberJoined { .. }
+            | Event::MemberLeft { .. }
+            | Event::MemberUpdated { .. }
+            | Event::Unknown { .. } => None,
+        }
+    }
+
+    /// Returns a [`PeerAnnotation`] of the provided [`ClientMsg`], if it's
+    /// related to any `Peer`s.
+    fn of_client_msg(msg: &ClientMsg) -> Option<Self> {
+        let ClientMsg::Command { command, .. } = msg else {
+            return None;
+        };
+        match command {
+            Command::MakeSdpOffer { peer_id, .. } => {
+                Some(Self::single(*peer_id, PeerActivity::SdpOffer))
+            }
+            Command::MakeSdpAnswer { peer_id, .. } => {
+                Some(Self::single(*peer_id, PeerActivity::SdpAnswer))
+            }
+            Command::SetIceCandidate { peer_id, .. } => {
+                Some(Self::single(*peer_id, PeerActivity::IceCandidate))
+            }
+            Command::UpdateTracks { peer_id, .. } => {
+                Some(Self::single(*peer_id, PeerActivity::Updated))
+            }
+            Command::AddPeerConnectionMetrics { peer_id, .. } => {
+                Some(Self::single(*peer_id, PeerActivity::Metrics))
+            }
+            Command::JoinRoom { .. }
+            | Command::LeaveRoom { .. }
+            | Command::SynchronizeMe { .. } => None,
+        }
+    }
+}
+
+/// Single message of a recorded RPC session.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "direction", rename_all = "snake_case")]
+pub enum RecordedMsg {
+    /// [`ServerMsg`] received from a media server.
+    Received {
+        /// Milliseconds passed since the recording has been started.
+        at_ms: u64,
+
+        /// Received [`ServerMsg`].
+        msg: ServerMsg,
+
+        /// `Peer`s the received [`ServerMsg`] is related to.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        peer: Option<PeerAnnotation>,
+    },
+
+    /// [`ClientMsg`] sent to a media server.
+    Sent {
+        /// Milliseconds passed since the recording has been started.
+        at_ms: u64,
+
+        /// Sent [`ClientMsg`].
+        ///
+        /// [`Credential`]s are redacted.
+        msg: ClientMsg,
+
+        /// `Peer`s the sent [`ClientMsg`] is related to.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        peer: Option<PeerAnnotation>,
+    },
+}
+
+/// Recorder of all the [`ServerMsg`]s and [`ClientMsg`]s passing through a
+/// [`WebSocketRpcClient`].
+///
+/// [`WebSocketRpcClient`]: crate::rpc::WebSocketRpcClient
+#[derive(Debug)]
+pub struct RpcRecorder {
+    /// [`platform::Instant`] when this [`RpcRecorder`] has been started.
+    started_at: platform::Instant,
+
+    /// All the [`RecordedMsg`]s in order of their recording.
+    records: RefCell<Vec<RecordedMsg>>,
+}
+
+impl RpcRecorder {
+    /// Starts a new empty [`RpcRecorder`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            started_at: platform::Instant::now(),
+            records: RefCell::default(),
+        }
+    }
+
+    /// Returns milliseconds passed since this [`RpcRecorder`] has been
+    /// started.
+    fn elapsed_ms(&self) -> u64 {
+        u64::try_from(self.started_at.elapsed().as_millis()).unwrap_or(u64::MAX)
+    }
+
+    /// Records the provided [`ServerMsg`] received from a media server.
+    ///
+    /// [`Event::Unknown`]s are skipped, since they cannot be serialized and,
+    /// so, replayed.
+    pub fn record_received(&self, msg: &ServerMsg) {
+        if matches!(msg, ServerMsg::Event { event: Event::Unknown { .. }, .. })
+        {
+            return;
+        }
+        let record = RecordedMsg::Received {
+            at_ms: self.elapsed_ms(),
+            peer: PeerAnnotation::of_server_msg(msg),
+            msg: msg.clone(),
+        };
+        self.records.borrow_mut().push(record);
+    }
+
+    /// Records the provided [`ClientMsg`] sent to a media server, redacting
+    /// its [`Credential`], if any.
+    pub fn record_sent(&self, msg: &ClientMsg) {
+        let mut msg = msg.clone();
+        if let ClientMsg::Command {
+            command: Command::JoinRoom { credential, .. },
+            ..
+        } = &mut msg
+        {
+            *credential = Credential::from(REDACTED_CREDENTIAL);
+        }
+        let record = RecordedMsg::Sent {
+            at_ms: self.elapsed_ms(),
+            peer: PeerAnnotation::of_client_msg(&msg),
+            msg,
+        };
+        self.records.borrow_mut().push(record);
+    }
+
+    /// Returns all the [`RecordedMsg`]s recorded so far.
+    #[must_use]
+    pub fn records(&self) -> Vec<RecordedMsg> {
+        self.records.borrow().clone()
+    }
+
+    /// Exports all the [`RecordedMsg`]s recorded so far as [JSON Lines].
+    ///
+    /// [JSON Lines]: https://jsonlines.org
+    #[must_use]
+    pub fn export(&self) -> String {
+        let mut out = String::new();
+        for record in self.records.borrow().iter() {
+            match serde_json::to_string(record) {
+                Ok(line) => {
+                    out.push_str(&line);
+                    out.push('\n');
+                }
+                Err(e) => log::error!("Failed to export RPC record: {e}"),
+            }
+        }
+        out
+    }
+}
+
+impl Default for RpcRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parses [`RecordedMsg`]s from the provided [JSON Lines], as exported by
+/// [`RpcRecorder::export()`].
+///
+/// # Errors
+///
+/// With a [`RecordingParseError`] if any non-empty line is not a valid
+/// [`RecordedMsg`].
+///
+/// [JSON Lines]: https://jsonlines.org
+pub fn parse_recording(
+    jsonl: &str,
+) -> Result<Vec<RecordedMsg>, Traced<RecordingParseError>> {
+    jsonl
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|e| {
+                tracerr::new!(RecordingParseError::MalformedRecord(
+                    i + 1,
+                    e.into(),
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Errors occurring when parsing a recorded RPC session.
+#[derive(Caused, Clone, Debug, Display, PartialEq)]
+#[cause(error = platform::Error)]
+pub enum RecordingParseError {
+    /// Line with the provided number is not a valid [`RecordedMsg`].
+    #[display("Malformed record at line {_0}: {_1}")]
+    MalformedRecord(usize, JsonParseError),
+}
+
+/// [`platform::RpcTransport`] replaying a recorded RPC session.
+///
+/// Received [`ServerMsg`]s are emitted in the recorded order, each one only
+/// after all the [`Command`]s preceding it in the recording have been sent,
+/// regardless of the recorded timings. This way, the sequence of [`Event`]s
+/// seen by a [`Room`] is reproduced deterministically.
+///
+/// Heartbeat messages are not replayed.
+///
+/// [`Room`]: crate::room::Room
+#[derive(Debug)]
+pub struct ReplayRpcTransport(Rc<RefCell<ReplayInner>>);
+
+/// Inner state of a [`ReplayRpcTransport`].
+#[derive(Debug)]
+struct ReplayInner {
+    /// [`RecordedMsg`]s left to be replayed.
+    records: Vec<RecordedMsg>,
+
+    /// Index of the next [`RecordedMsg`] to be replayed.
+    cursor: usize,
+
+    /// Subscribers to the [`ServerMsg`]s emitted by this transport.
+    on_message_subs: Vec<mpsc::UnboundedSender<ServerMsg>>,
+
+    /// State of this [`ReplayRpcTransport`].
+    state: ObservableCell<platform::TransportState>,
+}
+
+impl ReplayInner {
+    /// Emits all the received [`ServerMsg`]s up to the next sent [`Command`]
+    /// in the recording.
+    fn emit_until_sent(&mut self) {
+        while let Some(record) = self.records.get(self.cursor) {
+            match record {
+                RecordedMsg::Received { msg, .. } => {
+                    if !matches!(msg, ServerMsg::Ping(_) | ServerMsg::Pong(_)) {
+                        let msg = msg.clone();
+                        self.on_message_subs
+                            .retain(|s| s.unbounded_send(msg.clone()).is_ok());
+                    }
+                }
+                RecordedMsg::Sent { msg, .. } => {
+                    if matches!(msg, ClientMsg::Command { .. }) {
+                        break;
+                    }
+                }
+            }
+            self.cursor += 1;
+        }
+    }
+
+    /// Advances over the next recorded sent [`Command`], checking it to match
+    /// the actually sent one.
+    fn advance_sent(&mut self, command: &Command) {
+        if let Some(RecordedMsg::Sent {
+            msg: ClientMsg::Command { command: recorded, .. },
+            ..
+        }) = self.records.get(self.cursor)
+        {
+            if mem::discriminant(recorded) != mem::discriminant(command) {
+                log::warn!(
+                    "Replay diverged: expected `{recorded:?}`, \
+                     but `{command:?}` was sent",
+                );
+            }
+            self.cursor += 1;
+        }
+        self.emit_until_sent();
+    }
+}
+
+impl ReplayRpcTransport {
+    /// Creates a new [`ReplayRpcTransport`] replaying the provided
+    /// [`RecordedMsg`]s.
+    #[must_use]
+    pub fn new(records: Vec<RecordedMsg>) -> Self {
+        Self(Rc::new(RefCell::new(ReplayInner {
+            records,
+            cursor: 0,
+            on_message_subs: Vec::new(),
+            state: ObservableCell::new(platform::TransportState::Connecting),
+        })))
+    }
+
+    /// Indicates whether all the recorded [`ServerMsg`]s have been replayed.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        let inner = self.0.borrow();
+        inner.cursor >= inner.records.len()
+    }
+}
+
+#[async_trait(?Send)]
+impl platform::RpcTransport for ReplayRpcTransport {
+    async fn connect(
+        &self,
+        _: ApiUrl,
+    ) -> Result<(), Traced<platform::TransportError>> {
+        self.0.borrow().state.set(platform::TransportState::Open);
+
+        // Messages are emitted asynchronously, so all the subscriptions made
+        // right after connecting receive them.
+        let inner = Rc::downgrade(&self.0);
+        platform::spawn(async move {
+            if let Some(inner) = inner.upgrade() {
+                inner.borrow_mut().emit_until_sent();
+            }
+        });
+
+        Ok(())
+    }
+
+    fn on_message(&self) -> LocalBoxStream<'static, ServerMsg> {
+        let (tx, rx) = mpsc::unbounded();
+        self.0.borrow_mut().on_message_subs.push(tx);
+        Box::pin(rx)
+    }
+
+    fn set_close_reason(&self, _: ClientDisconnect) {}
+
+    fn send(
+        &self,
+        msg: &ClientMsg,
+    ) -> Result<(), Traced<platform::TransportError>> {
+        if let ClientMsg::Command { command, .. } = msg {
+            self.0.borrow_mut().advance_sent(command);
+        }
+        Ok(())
+    }
+
+    fn on_state_change(
+        &self,
+    ) -> LocalBoxStream<'static, platform::TransportState> {
+        self.0.borrow().state.subscribe()
+    }
+}
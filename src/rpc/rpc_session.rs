@@ -6,6 +6,7 @@
 use std::{
     cell::{Cell, RefCell},
     rc::Rc,
+    time::Duration,
 };
 
 use async_trait::async_trait;
@@ -14,10 +15,13 @@ use derive_more::with_trait::{Display, From};
 use futures::{
     StreamExt as _,
     channel::mpsc,
-    future::{self, LocalBoxFuture},
+    future::{self, Either, LocalBoxFuture},
     stream::LocalBoxStream,
 };
-use medea_client_api_proto::{Command, Event, MemberId, RoomId};
+use medea_client_api_proto::{
+    CloseReason as CloseByServerReason, Command, Event, Member, MemberId,
+    RoomId,
+};
 use medea_reactive::ObservableCell;
 use tracerr::Traced;
 
@@ -25,7 +29,7 @@ use crate::{
     platform,
     rpc::{
         ClientDisconnect, CloseReason, ConnectionInfo, RpcClientError,
-        WebSocketRpcClient, websocket::RpcEventHandler,
+        SignallingLatency, WebSocketRpcClient, websocket::RpcEventHandler,
     },
     utils::Caused,
 };
@@ -134,6 +138,21 @@ pub trait RpcSession {
     /// client will be dropped.
     fn close_with_reason(&self, close_reason: ClientDisconnect);
 
+    /// Leaves the `Room` gracefully, reporting the provided `reason` of
+    /// leaving to a server.
+    ///
+    /// Waits for a server to acknowledge the leaving with an
+    /// [`Event::RoomLeft`] (or to close the session on its own) for the
+    /// provided `timeout` at most, and closes this [`RpcSession`] with a
+    /// [`ClientDisconnect::RoomClosed`] if it doesn't.
+    ///
+    /// Returns the final [`CloseReason`] of this [`RpcSession`].
+    async fn leave(
+        self: Rc<Self>,
+        reason: Option<String>,
+        timeout: Duration,
+    ) -> CloseReason;
+
     /// Subscribe to connection loss events.
     ///
     /// Connection loss is any unexpected [`platform::RpcTransport`] close. In
@@ -158,6 +177,25 @@ pub trait RpcSession {
     async fn network_changed(
         self: Rc<Self>,
     ) -> Result<(), Traced<SessionError>>;
+
+    /// Pauses or resumes idle detection of the underlying connection with a
+    /// media server, for example, while timers are unreliable because of a web
+    /// page being frozen.
+    fn set_idle_detection_paused(&self, paused: bool);
+
+    /// Subscribes to updates of [`SignallingLatency`] statistics of the
+    /// underlying connection with a media server.
+    fn on_signalling_latency(
+        &self,
+    ) -> LocalBoxStream<'static, SignallingLatency>;
+
+    /// Returns the current [`SignallingLatency`] statistics of the underlying
+    /// connection with a media server, if any has been measured yet.
+    fn signalling_latency(&self) -> Option<SignallingLatency>;
+
+    /// Returns [`platform::RpcTransportKind`] of the transport used by the
+    /// underlying connection with a media server, if it has been established.
+    fn transport_kind(&self) -> Option<platform::RpcTransportKind>;
 }
 
 /// Client to talk with server via Client API RPC.
@@ -181,6 +219,10 @@ pub struct WebSocketRpcSession {
 
     /// Subscribers of the [`RpcSession::subscribe`].
     event_txs: RefCell<Vec<mpsc::UnboundedSender<Event>>>,
+
+    /// Indicator whether [`RpcSession::leave()`] has been requested, so an
+    /// [`Event::RoomLeft`] acknowledges it.
+    leaving: Cell<bool>,
 }
 
 impl WebSocketRpcSession {
@@ -194,6 +236,7 @@ impl WebSocketRpcSession {
             state: ObservableCell::new(SessionState::Uninitialized),
             was_connected: Rc::new(Cell::new(false)),
             event_txs: RefCell::default(),
+            leaving: Cell::new(false),
         });
 
         this.spawn_state_watcher();
@@ -283,7 +326,7 @@ impl WebSocketRpcSession {
                 let this = upgrade_or_break!(weak_this);
                 match state {
                     S::Connecting(info) => match Rc::clone(&this.client)
-                        .connect(info.url.clone())
+                        .connect_room(info.room_id.clone(), info.url.clone())
                         .await
                     {
                         Ok(()) => {
@@ -359,11 +402,32 @@ impl WebSocketRpcSession {
                 ClientDisconnect::RpcClientUnexpectedlyDropped.into()
             });
             if let Some(this) = weak_this.upgrade() {
-                this.state.set(SessionState::Finished(reason));
+                this.finish(reason);
             }
         });
     }
 
+    /// Transits this [`WebSocketRpcSession`] into the
+    /// [`SessionState::Finished`] with the provided [`CloseReason`], releasing
+    /// its `Room` in the underlying [`WebSocketRpcClient`].
+    ///
+    /// A [`CloseByServerReason::Finished`] is considered as an acknowledgement
+    /// of the requested [`RpcSession::leave()`], so is replaced with a
+    /// [`ClientDisconnect::RoomClosed`] in such case.
+    fn finish(&self, reason: CloseReason) {
+        if let Some(info) = self.state.get().connection_info() {
+            self.client.release_room(&info.room_id);
+        }
+        let reason = if self.leaving.get()
+            && reason == CloseReason::ByServer(CloseByServerReason::Finished)
+        {
+            ClientDisconnect::RoomClosed.into()
+        } else {
+            reason
+        };
+        self.state.set(SessionState::Finished(reason));
+    }
+
     /// Spawns [`WebSocketRpcClient::subscribe`] listener.
     fn spawn_server_msg_listener(self: &Rc<Self>) {
         let mut server_msg_rx = self.client.subscribe();
@@ -474,12 +538,60 @@ impl RpcSession for WebSocketRpcSession {
     /// [`WebSocketRpcClient`] with [`WebSocketRpcClient::set_close_reason`].
     fn close_with_reason(&self, close_reason: ClientDisconnect) {
         if let SessionState::Opened { info, .. } = self.state.get() {
-            self.client
-                .leave_room(info.room_id.clone(), info.member_id.clone());
+            self.client.leave_room(
+                info.room_id.clone(),
+                info.member_id.clone(),
+                None,
+            );
         }
 
         self.client.set_close_reason(close_reason);
-        self.state.set(SessionState::Finished(close_reason.into()));
+        self.finish(close_reason.into());
+    }
+
+    /// Sends a [`Command::LeaveRoom`] if the current [`SessionState`] is
+    /// [`SessionState::Opened`] and waits for the [`SessionState::Finished`]
+    /// for the provided `timeout` at most.
+    async fn leave(
+        self: Rc<Self>,
+        reason: Option<String>,
+        timeout: Duration,
+    ) -> CloseReason {
+        let info = match self.state.get() {
+            SessionState::Opened { info, .. } => info,
+            SessionState::Finished(close_reason) => return close_reason,
+            SessionState::Uninitialized
+            | SessionState::Initialized(_)
+            | SessionState::Connecting(_)
+            | SessionState::Authorizing(_)
+            | SessionState::Lost(..) => {
+                self.close_with_reason(ClientDisconnect::RoomClosed);
+                return ClientDisconnect::RoomClosed.into();
+            }
+        };
+
+        let closed = self.on_normal_close();
+        self.leaving.set(true);
+        self.client.set_close_reason(ClientDisconnect::RoomClosed);
+        self.client.leave_room(
+            info.room_id.clone(),
+            info.member_id.clone(),
+            reason,
+        );
+
+        let timeout = platform::clock().delay_for(timeout);
+        match future::select(closed, timeout).await {
+            Either::Left((close_reason, _)) => close_reason,
+            Either::Right(_) => {
+                log::warn!(
+                    "Leaving `Room({})` wasn't acknowledged in time",
+                    info.room_id,
+                );
+                let close_reason = ClientDisconnect::RoomClosed.into();
+                self.finish(close_reason);
+                close_reason
+            }
+        }
     }
 
     /// Returns [`Stream`] which will provided `Some(())` every time when
@@ -548,6 +660,26 @@ impl RpcSession for WebSocketRpcSession {
         }
         self.inner_connect().await.map_err(tracerr::map_from_and_wrap!())
     }
+
+    fn set_idle_detection_paused(&self, paused: bool) {
+        if let Some(info) = self.state.get().connection_info() {
+            self.client.set_idle_detection_paused(info.room_id.clone(), paused);
+        }
+    }
+
+    fn on_signalling_latency(
+        &self,
+    ) -> LocalBoxStream<'static, SignallingLatency> {
+        self.client.on_signalling_latency()
+    }
+
+    fn signalling_latency(&self) -> Option<SignallingLatency> {
+        self.client.signalling_latency()
+    }
+
+    fn transport_kind(&self) -> Option<platform::RpcTransportKind> {
+        self.client.transport_kind()
+    }
 }
 
 impl RpcEventHandler for WebSocketRpcSession {
@@ -557,16 +689,24 @@ impl RpcEventHandler for WebSocketRpcSession {
     /// [`RoomId`] from [`ConnectionInfo`] is equal to the provided
     /// [`RoomId`], then [`SessionState`] will be transited to the
     /// [`SessionState::Opened`].
+    ///
+    /// The provided `members` roster is forwarded to the
+    /// [`RpcSession::subscribe`] subscribers as an [`Event::RoomJoined`].
     fn on_joined_room(
         &self,
         room_id: RoomId,
         member_id: MemberId,
         is_reconnect: bool,
+        members: Vec<Member>,
     ) {
         let state = self.state.get();
         if let SessionState::Authorizing(info) = state {
             if info.room_id == room_id && info.member_id == member_id {
                 self.state.set(SessionState::Opened { info, is_reconnect });
+                self.on_event(
+                    room_id,
+                    Event::RoomJoined { member_id, is_reconnect, members },
+                );
             }
         }
     }
@@ -597,7 +737,7 @@ impl RpcEventHandler for WebSocketRpcSession {
 
         match state {
             SessionState::Opened { .. } => {
-                self.state.set(SessionState::Finished(close_reason));
+                self.finish(close_reason);
             }
             SessionState::Authorizing(_) => {
                 self.state.set(SessionState::Uninitialized);
@@ -610,6 +750,19 @@ impl RpcEventHandler for WebSocketRpcSession {
         }
     }
 
+    /// If current [`SessionState`] is [`SessionState::Opened`], then
+    /// [`SessionState`] will be transited to the [`SessionState::Connecting`],
+    /// so the `Room` is joined again once a new connection is established.
+    ///
+    /// Happens when another [`RpcSession`] sharing the same
+    /// [`WebSocketRpcClient`] recreates its connection.
+    fn on_closed_for_reconnection(&self) {
+        if let SessionState::Opened { info, .. } = self.state.get() {
+            self.was_connected.set(true);
+            self.state.set(SessionState::Connecting(info));
+        }
+    }
+
     /// Sends received [`Event`] to the all [`RpcSession::subscribe`]
     /// subscribers if current [`SessionState`] is [`SessionState::Opened`]
     /// and provided [`RoomId`] is equal to the [`RoomId`] from the
@@ -697,3 +850,18 @@ pub enum SessionState {
     /// Terminal state: transport is closed and can not be reopened.
     Finished(CloseReason),
 }
+
+impl SessionState {
+    /// Returns [`ConnectionInfo`] of this [`SessionState`], if any.
+    #[must_use]
+    pub const fn connection_info(&self) -> Option<&Rc<ConnectionInfo>> {
+        match self {
+            Self::Initialized(info)
+            | Self::Connecting(info)
+            | Self::Authorizing(info)
+            | Self::Lost(_, info)
+            | Self::Opened { info, .. } => Some(info),
+            Self::Uninitialized | Self::Finished(_) => None,
+        }
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! [WebSocket]: https://developer.mozilla.org/ru/docs/WebSockets
 
-use std::{cell::RefCell, rc::Rc, time::Duration};
+use std::{cell::RefCell, collections::HashSet, rc::Rc, time::Duration};
 
 use derive_more::with_trait::{Debug, Display};
 use futures::{
@@ -12,18 +12,22 @@ use futures::{
 };
 use medea_client_api_proto::{
     Capabilities, ClientMsg, CloseReason as CloseByServerReason, Command,
-    Credential, Event, MemberId, RoomId, RpcSettings, ServerMsg,
+    Credential, Event, Member, MemberId, ProtocolVersion, RoomId, RpcSettings,
+    ServerMsg,
 };
 use medea_macro::dispatchable;
 use medea_reactive::ObservableCell;
 use serde::Serialize;
 use tracerr::Traced;
 
+#[cfg(feature = "rpc-recording")]
+use crate::rpc::RpcRecorder;
 use crate::{
     platform,
     rpc::{
         ApiUrl, CloseMsg, CloseReason, ClosedStateReason, ConnectionLostReason,
         Heartbeat, IdleTimeout, PingInterval, RpcClientError,
+        SignallingLatency,
     },
 };
 
@@ -53,6 +57,10 @@ pub enum ClientDisconnect {
 
     /// Client initiated reconnection for whatever reason.
     CloseForReconnection,
+
+    /// [`ServerMsg`] received from a server cannot be parsed, so the client
+    /// and the server speak incompatible Client API protocols.
+    IncompatibleProtocol,
 }
 
 impl ClientDisconnect {
@@ -64,7 +72,8 @@ impl ClientDisconnect {
             | Self::RoomUnexpectedlyDropped
             | Self::RpcClientUnexpectedlyDropped
             | Self::RpcTransportUnexpectedlyDropped
-            | Self::SessionUnexpectedlyDropped => 1000,
+            | Self::SessionUnexpectedlyDropped
+            | Self::IncompatibleProtocol => 1000,
             Self::CloseForReconnection => {
                 // Only 1000 or [3000; 4999] can be used, with 1000 as a normal
                 // close, and everything else is protocol-defined, which is
@@ -122,11 +131,27 @@ struct Inner {
     /// is lost.
     on_connection_loss_subs: Vec<mpsc::UnboundedSender<ConnectionLostReason>>,
 
-    /// Closure which will create new [`platform::RpcTransport`]s for this
+    /// Subscribers that will be notified with actual [`SignallingLatency`]
+    /// statistics each time they're updated.
+    on_latency_subs: Vec<mpsc::UnboundedSender<SignallingLatency>>,
+
+    /// Closures which will create new [`platform::RpcTransport`]s of the
+    /// respective [`platform::RpcTransportKind`]s for this
     /// [`WebSocketRpcClient`] on each
-    /// [`WebSocketRpcClient:: establish_connection`] call.
-    #[debug("{rpc_transport_factory:p}")]
-    rpc_transport_factory: RpcTransportFactory,
+    /// [`WebSocketRpcClient::establish_connection`] call.
+    ///
+    /// Tried in order until one of the created [`platform::RpcTransport`]s
+    /// connects successfully.
+    #[debug(skip)]
+    rpc_transport_factories:
+        Vec<(platform::RpcTransportKind, RpcTransportFactory)>,
+
+    /// [`platform::RpcTransportKind`] of the currently used
+    /// [`platform::RpcTransport`].
+    ///
+    /// [`None`] if this [`WebSocketRpcClient`] has never been connected to
+    /// a server.
+    transport_kind: Option<platform::RpcTransportKind>,
 
     /// URL that [`platform::RpcTransport`] will connect to.
     ///
@@ -134,8 +159,24 @@ struct Inner {
     /// a sever.
     url: Option<ApiUrl>,
 
+    /// [`RpcRecorder`] recording all the [`ServerMsg`]s and [`Command`]s
+    /// passing through this [`WebSocketRpcClient`], if recording is enabled.
+    #[cfg(feature = "rpc-recording")]
+    recorder: Option<Rc<RpcRecorder>>,
+
     /// Current [`ClientState`] of this [`WebSocketRpcClient`].
     state: ObservableCell<ClientState>,
+
+    /// IDs of the `Room`s currently joined via this [`WebSocketRpcClient`].
+    ///
+    /// The connection is closed once the last of them is left.
+    rooms: HashSet<RoomId>,
+
+    /// IDs of the `Room`s which have requested to pause idle detection of the
+    /// connection.
+    ///
+    /// Idle detection is paused while there is at least one of them.
+    idle_paused_rooms: HashSet<RoomId>,
 }
 
 /// Factory closure producing a [`platform::RpcTransport`].
@@ -151,11 +192,20 @@ impl Inner {
             heartbeat: None,
             close_reason: ClientDisconnect::RpcClientUnexpectedlyDropped,
             on_connection_loss_subs: Vec::new(),
-            rpc_transport_factory,
+            on_latency_subs: Vec::new(),
+            rpc_transport_factories: vec![(
+                platform::RpcTransportKind::WebSocket,
+                rpc_transport_factory,
+            )],
+            transport_kind: None,
             url: None,
+            #[cfg(feature = "rpc-recording")]
+            recorder: None,
             state: ObservableCell::new(ClientState::Closed(
                 ClosedStateReason::NeverConnected,
             )),
+            rooms: HashSet::new(),
+            idle_paused_rooms: HashSet::new(),
         })
     }
 }
@@ -179,6 +229,11 @@ pub enum RpcEvent {
 
         /// Indicator whether this join is a reconnect.
         is_reconnect: bool,
+
+        /// Roster of all the `Member`s present in the joined [`Room`].
+        ///
+        /// [`Room`]: crate::room::Room
+        members: Vec<Member>,
     },
 
     /// Notification of the subscribers that [`WebSocketRpcClient`] left
@@ -197,6 +252,13 @@ pub enum RpcEvent {
         close_reason: CloseReason,
     },
 
+    /// Notification of the subscribers that [`WebSocketRpcClient`] has closed
+    /// its connection to recreate a new one, so all the joined [`Room`]s
+    /// should be joined again.
+    ///
+    /// [`Room`]: crate::room::Room
+    ClosedForReconnection,
+
     /// [`WebSocketRpcClient`] received [`Event`] from Media Server.
     Event {
         /// ID of the [`Room`] for that this [`Event`] has been received for.
@@ -223,6 +285,38 @@ impl WebSocketRpcClient {
         Self(Inner::new(rpc_transport_factory))
     }
 
+    /// Adds a fallback [`RpcTransportFactory`] of the provided
+    /// [`platform::RpcTransportKind`], tried if none of the previously added
+    /// ones is able to establish a connection.
+    #[must_use]
+    pub fn with_fallback(
+        self,
+        kind: platform::RpcTransportKind,
+        rpc_transport_factory: RpcTransportFactory,
+    ) -> Self {
+        self.0
+            .borrow_mut()
+            .rpc_transport_factories
+            .push((kind, rpc_transport_factory));
+        self
+    }
+
+    /// Records all the [`ServerMsg`]s and [`Command`]s passing through this
+    /// [`WebSocketRpcClient`] into the provided [`RpcRecorder`].
+    #[cfg(feature = "rpc-recording")]
+    #[must_use]
+    pub fn with_recorder(self, recorder: Rc<RpcRecorder>) -> Self {
+        self.0.borrow_mut().recorder = Some(recorder);
+        self
+    }
+
+    /// Returns [`platform::RpcTransportKind`] of the [`platform::RpcTransport`]
+    /// used by the last established connection, if any.
+    #[must_use]
+    pub fn transport_kind(&self) -> Option<platform::RpcTransportKind> {
+        self.0.borrow().transport_kind
+    }
+
     /// Authorizes [`WebSocketRpcClient`] on the Media Server.
     pub fn join_room(
         &self,
@@ -233,13 +327,51 @@ impl WebSocketRpcClient {
     ) {
         self.send_command(
             room_id,
-            Command::JoinRoom { member_id, credential, capabilities },
+            Command::JoinRoom {
+                member_id,
+                credential,
+                capabilities,
+                protocol_version: ProtocolVersion::CURRENT,
+            },
         );
     }
 
-    /// Leaves `Room` with a provided [`RoomId`].
-    pub fn leave_room(&self, room_id: RoomId, member_id: MemberId) {
-        self.send_command(room_id, Command::LeaveRoom { member_id });
+    /// Leaves `Room` with a provided [`RoomId`], optionally reporting the
+    /// provided `reason` of leaving to a server.
+    pub fn leave_room(
+        &self,
+        room_id: RoomId,
+        member_id: MemberId,
+        reason: Option<String>,
+    ) {
+        self.send_command(room_id, Command::LeaveRoom { member_id, reason });
+    }
+
+    /// Stops counting the `Room` with the provided [`RoomId`] as joined via
+    /// this [`WebSocketRpcClient`], closing the connection if it was the last
+    /// one.
+    pub fn release_room(&self, room_id: &RoomId) {
+        let is_last = {
+            let mut inner = self.0.borrow_mut();
+            _ = inner.idle_paused_rooms.remove(room_id);
+            inner.rooms.remove(room_id) && inner.rooms.is_empty()
+        };
+        if is_last {
+            self.close_unused();
+        } else {
+            self.update_idle_detection();
+        }
+    }
+
+    /// Closes the connection, as it isn't used by any `Room` anymore.
+    fn close_unused(&self) {
+        let mut inner = self.0.borrow_mut();
+        drop(inner.heartbeat.take());
+        inner.close_reason = ClientDisconnect::RoomClosed;
+        if let Some(sock) = inner.sock.take() {
+            sock.set_close_reason(ClientDisconnect::RoomClosed);
+        }
+        inner.state.set(ClientState::Closed(ClosedStateReason::AllRoomsLeft));
     }
 
     /// Stops [`Heartbeat`] and notifies all
@@ -261,6 +393,12 @@ impl WebSocketRpcClient {
     /// This function will be called on every WebSocket close (normal and
     /// abnormal) regardless of the [`CloseReason`].
     fn handle_close_message(&self, close_msg: CloseMsg) {
+        if *self.0.borrow().state.borrow()
+            == ClientState::Closed(ClosedStateReason::AllRoomsLeft)
+        {
+            // Connection has been closed intentionally, as it's not used.
+            return;
+        }
         drop(self.0.borrow_mut().heartbeat.take());
 
         match close_msg {
@@ -272,7 +410,8 @@ impl WebSocketRpcClient {
                 CloseByServerReason::Finished
                 | CloseByServerReason::Rejected
                 | CloseByServerReason::InternalError
-                | CloseByServerReason::Evicted => {
+                | CloseByServerReason::Evicted
+                | CloseByServerReason::IncompatibleProtocol => {
                     self.0.borrow().state.set(ClientState::Closed(
                         ClosedStateReason::ConnectionLost(
                             ConnectionLostReason::WithMessage(close_msg),
@@ -307,27 +446,40 @@ impl WebSocketRpcClient {
 
     /// Handles [`ServerMsg`]s from a remote server.
     fn on_transport_message(&self, msg: ServerMsg) {
+        #[cfg(feature = "rpc-recording")]
+        if let Some(recorder) = self.0.borrow().recorder.as_ref() {
+            recorder.record_received(&msg);
+        }
         let msg = match msg {
             ServerMsg::Event { room_id, event } => match event {
-                Event::RoomJoined { member_id, is_reconnect } => {
+                Event::RoomJoined { member_id, is_reconnect, members } => {
                     Some(RpcEvent::JoinedRoom {
                         room_id,
                         member_id,
                         is_reconnect,
+                        members,
+                    })
+                }
+                Event::RoomLeft { close_reason } => {
+                    self.release_room(&room_id);
+                    Some(RpcEvent::LeftRoom {
+                        room_id,
+                        close_reason: CloseReason::ByServer(close_reason),
                     })
                 }
-                Event::RoomLeft { close_reason } => Some(RpcEvent::LeftRoom {
-                    room_id,
-                    close_reason: CloseReason::ByServer(close_reason),
-                }),
                 Event::PeerCreated { .. }
                 | Event::SdpAnswerMade { .. }
                 | Event::LocalDescriptionApplied { .. }
                 | Event::IceCandidateDiscovered { .. }
                 | Event::PeersRemoved { .. }
                 | Event::PeerUpdated { .. }
+                | Event::IceServersUpdated { .. }
                 | Event::ConnectionQualityUpdated { .. }
-                | Event::StateSynchronized { .. } => {
+                | Event::StateSynchronized { .. }
+                | Event::MemberJoined { .. }
+                | Event::MemberLeft { .. }
+                | Event::MemberUpdated { .. }
+                | Event::Unknown { .. } => {
                     Some(RpcEvent::Event { room_id, event })
                 }
             },
@@ -348,11 +500,12 @@ impl WebSocketRpcClient {
                                 settings.ping_interval_ms.into(),
                             )),
                         );
+                        heartbeat.set_latency_probes(settings.latency_probes);
                     },
                 );
                 None
             }
-            ServerMsg::Ping(_) => None,
+            ServerMsg::Ping(_) | ServerMsg::Pong(_) => None,
         };
         if let Some(m) = msg {
             self.0
@@ -378,6 +531,7 @@ impl WebSocketRpcClient {
 
         let heartbeat =
             Heartbeat::start(transport, ping_interval, idle_timeout);
+        heartbeat.set_latency_probes(rpc_settings.latency_probes);
 
         let mut on_idle = heartbeat.on_idle();
         let weak_this = Rc::downgrade(&self);
@@ -388,7 +542,62 @@ impl WebSocketRpcClient {
                 }
             }
         });
+
+        let mut on_latency = heartbeat.on_latency();
+        let weak_self = Rc::downgrade(&self);
+        platform::spawn(async move {
+            while let Some(latency) = on_latency.next().await {
+                if let Some(this) = weak_self.upgrade() {
+                    this.0
+                        .borrow_mut()
+                        .on_latency_subs
+                        .retain(|sub| sub.unbounded_send(latency).is_ok());
+                }
+            }
+        });
         self.0.borrow_mut().heartbeat = Some(heartbeat);
+        self.update_idle_detection();
+    }
+
+    /// Connects the first [`platform::RpcTransport`] able to establish a
+    /// connection, trying all the [`RpcTransportFactory`]s in order.
+    ///
+    /// # Errors
+    ///
+    /// With an error of the primary [`platform::RpcTransport`] if none of them
+    /// is able to establish a connection, since the fallback ones are expected
+    /// to fail for the same reason.
+    async fn connect_transport(
+        &self,
+        url: &ApiUrl,
+    ) -> Result<
+        (Rc<dyn platform::RpcTransport>, LocalBoxStream<'static, ServerMsg>),
+        platform::TransportError,
+    > {
+        let mut primary_err = None;
+        let count = self.0.borrow().rpc_transport_factories.len();
+        for i in 0..count {
+            let (kind, transport) = {
+                let inner = self.0.borrow();
+                let (kind, factory) = &inner.rpc_transport_factories[i];
+                (*kind, factory())
+            };
+            let on_message = transport.on_message();
+            match transport.connect(url.clone()).await {
+                Ok(()) => {
+                    if primary_err.is_some() {
+                        log::warn!("Falling back to {kind} RPC transport");
+                    }
+                    self.0.borrow_mut().transport_kind = Some(kind);
+                    return Ok((transport, on_message));
+                }
+                Err(e) => {
+                    log::warn!("Failed to connect via {kind}: {e}");
+                    _ = primary_err.get_or_insert_with(|| e.into_inner());
+                }
+            }
+        }
+        Err(primary_err.unwrap_or(platform::TransportError::InitSocket))
     }
 
     /// Tries to establish [`WebSocketRpcClient`] connection.
@@ -400,20 +609,22 @@ impl WebSocketRpcClient {
         self.0.borrow().state.set(ClientState::Connecting);
 
         // Wait for transport opening.
-        let transport = (self.0.borrow().rpc_transport_factory)();
-        let mut on_message = transport.on_message();
-        transport.connect(url).await.map_err(|e| {
-            let transport_err = e.into_inner();
-            self.0.borrow().state.set(ClientState::Closed(
-                ClosedStateReason::CouldNotEstablish(transport_err.clone()),
-            ));
-            tracerr::new!(RpcClientError::from(
-                ClosedStateReason::CouldNotEstablish(transport_err)
-            ))
-        })?;
+        let (transport, mut on_message) =
+            self.connect_transport(&url).await.map_err(|e| {
+                self.0.borrow().state.set(ClientState::Closed(
+                    ClosedStateReason::CouldNotEstablish(e.clone()),
+                ));
+                tracerr::new!(RpcClientError::from(
+                    ClosedStateReason::CouldNotEstablish(e)
+                ))
+            })?;
 
         // Wait for `ServerMsg::RpcSettings`.
         if let Some(msg) = on_message.next().await {
+            #[cfg(feature = "rpc-recording")]
+            if let Some(recorder) = self.0.borrow().recorder.as_ref() {
+                recorder.record_received(&msg);
+            }
             if let ServerMsg::RpcSettings(rpc_settings) = msg {
                 Rc::clone(&self)
                     .start_heartbeat(Rc::clone(&transport), rpc_settings);
@@ -521,7 +732,7 @@ impl WebSocketRpcClient {
         url: ApiUrl,
     ) -> Result<(), Traced<RpcClientError>> {
         let current_url = self.0.borrow().url.clone();
-        if current_url.as_ref() == Some(&url) {
+        if current_url.is_some_and(|current| current.is_same_server(&url)) {
             let state = self.0.borrow().state.borrow().clone();
             match state {
                 ClientState::Open => Ok(()),
@@ -533,6 +744,42 @@ impl WebSocketRpcClient {
         }
     }
 
+    /// Counts the `Room` with the provided [`RoomId`] as joined via this
+    /// [`WebSocketRpcClient`] and [connects][1] it to the provided [`ApiUrl`].
+    ///
+    /// The `Room` stays counted until it's [released][2], so the connection is
+    /// kept while it's used by at least one `Room`.
+    ///
+    /// # Errors
+    ///
+    /// With [`RpcClientError::UsedByAnotherServer`] if this
+    /// [`WebSocketRpcClient`] is used by other `Room`s connected to another
+    /// server.
+    ///
+    /// See [`WebSocketRpcClient::connect()`] for other errors.
+    ///
+    /// [1]: WebSocketRpcClient::connect
+    /// [2]: WebSocketRpcClient::release_room
+    pub async fn connect_room(
+        self: Rc<Self>,
+        room_id: RoomId,
+        url: ApiUrl,
+    ) -> Result<(), Traced<RpcClientError>> {
+        {
+            let mut inner = self.0.borrow_mut();
+            let is_used_by_another_server = inner
+                .url
+                .as_ref()
+                .is_some_and(|current| !current.is_same_server(&url))
+                && inner.rooms.iter().any(|id| *id != room_id);
+            if is_used_by_another_server {
+                return Err(tracerr::new!(RpcClientError::UsedByAnotherServer));
+            }
+            _ = inner.rooms.insert(room_id);
+        }
+        self.connect(url).await
+    }
+
     /// Subscribes on this [`WebSocketRpcClient`]'s [`RpcEvent`]s.
     pub fn subscribe(&self) -> LocalBoxStream<'static, RpcEvent> {
         let (tx, rx) = mpsc::unbounded();
@@ -546,8 +793,13 @@ impl WebSocketRpcClient {
         let socket_borrow = &self.0.borrow().sock;
 
         if let Some(socket) = socket_borrow.as_ref() {
+            let msg = ClientMsg::Command { room_id, command };
+            #[cfg(feature = "rpc-recording")]
+            if let Some(recorder) = self.0.borrow().recorder.as_ref() {
+                recorder.record_sent(&msg);
+            }
             if let Err(e) = socket
-                .send(&ClientMsg::Command { room_id, command })
+                .send(&msg)
                 .map_err(tracerr::map_from_and_wrap!(=> RpcClientError))
             {
                 log::error!("{e}");
@@ -585,6 +837,56 @@ impl WebSocketRpcClient {
         Box::pin(rx)
     }
 
+    /// Subscribes to updates of [`SignallingLatency`] statistics of this
+    /// [`WebSocketRpcClient`].
+    ///
+    /// Statistics are updated each time a [`ServerMsg::Pong`] is received
+    /// from a media server in answer to a [`ClientMsg::Ping`].
+    pub fn on_signalling_latency(
+        &self,
+    ) -> LocalBoxStream<'static, SignallingLatency> {
+        let (tx, rx) = mpsc::unbounded();
+        self.0.borrow_mut().on_latency_subs.push(tx);
+        Box::pin(rx)
+    }
+
+    /// Returns the current [`SignallingLatency`] statistics of the active
+    /// connection, if any has been measured yet.
+    #[must_use]
+    pub fn signalling_latency(&self) -> Option<SignallingLatency> {
+        self.0.borrow().heartbeat.as_ref().and_then(Heartbeat::latency)
+    }
+
+    /// Pauses or resumes idle detection of the active connection on behalf of
+    /// the `Room` with the provided [`RoomId`].
+    ///
+    /// Idle detection stays paused while at least one `Room` requires so. See
+    /// [`Heartbeat::pause_idle_detection()`] for details.
+    pub fn set_idle_detection_paused(&self, room_id: RoomId, paused: bool) {
+        {
+            let mut inner = self.0.borrow_mut();
+            if paused {
+                _ = inner.idle_paused_rooms.insert(room_id);
+            } else {
+                _ = inner.idle_paused_rooms.remove(&room_id);
+            }
+        }
+        self.update_idle_detection();
+    }
+
+    /// Pauses or resumes idle detection of the active connection, depending on
+    /// whether any `Room` requires it to be paused.
+    fn update_idle_detection(&self) {
+        let inner = self.0.borrow();
+        if let Some(heartbeat) = inner.heartbeat.as_ref() {
+            if inner.idle_paused_rooms.is_empty() {
+                heartbeat.resume_idle_detection();
+            } else {
+                heartbeat.pause_idle_detection();
+            }
+        }
+    }
+
     /// Forces the underlying transport to close immediately.
     ///
     /// This triggers normal or abnormal close handling and propagates to
@@ -603,6 +905,9 @@ impl WebSocketRpcClient {
                 )),
             ),
         ));
+        self.0.borrow_mut().subs.retain(|sub| {
+            sub.unbounded_send(RpcEvent::ClosedForReconnection).is_ok()
+        });
     }
 
     /// Sets reason being passed to the underlying transport when this client is
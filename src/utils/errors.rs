@@ -1,7 +1,8 @@
 //! Helpers for application errors.
 
-use std::rc::Rc;
+use std::{fmt, rc::Rc};
 
+use ciborium::{de, ser};
 use derive_more::with_trait::{Display, From};
 pub use medea_macro::Caused;
 
@@ -30,3 +31,24 @@ impl PartialEq for JsonParseError {
             && self.0.classify() == other.0.classify()
     }
 }
+
+/// Wrapper for [CBOR] (de)serialization errors that provides [`Clone`],
+/// [`Debug`], [`Display`] implementations.
+///
+/// [CBOR]: https://cbor.io
+/// [`Debug`]: std::fmt::Debug
+/// [`Display`]: std::fmt::Display
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+pub struct CborParseError(Rc<str>);
+
+impl<T: fmt::Debug> From<de::Error<T>> for CborParseError {
+    fn from(err: de::Error<T>) -> Self {
+        Self(err.to_string().into())
+    }
+}
+
+impl<T: fmt::Debug> From<ser::Error<T>> for CborParseError {
+    fn from(err: ser::Error<T>) -> Self {
+        Self(err.to_string().into())
+    }
+}
@@ -13,7 +13,7 @@ use medea_reactive::Guarded;
 #[doc(inline)]
 pub use self::{
     component::{AsProtoState, Component, SynchronizableState, Updatable},
-    errors::{Caused, JsonParseError},
+    errors::{Caused, CborParseError, JsonParseError},
     resettable_delay::{ResettableDelayHandle, resettable_delay_for},
 };
 
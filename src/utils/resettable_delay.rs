@@ -85,7 +85,7 @@ impl ResettableDelayHandle {
         let future_resolver = Rc::clone(&self.future_resolver);
         let timeout = self.timeout;
         let (fut, abort) = future::abortable(async move {
-            platform::delay_for(timeout).await;
+            platform::clock().delay_for(timeout).await;
             if let Some(rsvr) = future_resolver.borrow_mut().take() {
                 _ = rsvr.send(());
             }
@@ -17,11 +17,12 @@ use medea_jason::{
     api,
     jason::JasonImpl,
     platform::{
-        MockRpcTransport, RpcTransport, TransportError, TransportState,
+        MockRpcTransport, NetworkState, RpcTransport, TransportError,
+        TransportState,
     },
     rpc::WebSocketRpcClient,
 };
-use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast as _, JsValue, closure::Closure};
 use wasm_bindgen_futures::JsFuture;
 use wasm_bindgen_test::*;
 
@@ -72,6 +73,7 @@ async fn jason_network_changed() {
                             event: Event::RoomJoined {
                                 member_id: "member_id".into(),
                                 is_reconnect: false,
+                                members: Vec::new(),
                             },
                         },
                         ServerMsg::Event {
@@ -134,6 +136,7 @@ async fn jason_network_changed() {
                         event: Event::RoomJoined {
                             member_id: "member_id".into(),
                             is_reconnect: true,
+                            members: Vec::new(),
                         },
                     })
                     .unwrap();
@@ -287,6 +290,7 @@ async fn jason_network_changed_errors_on_failed_reconnect() {
                             event: Event::RoomJoined {
                                 member_id: "member_id".into(),
                                 is_reconnect: false,
+                                members: Vec::new(),
                             },
                         },
                     ])
@@ -343,3 +347,161 @@ async fn jason_network_changed_errors_on_failed_reconnect() {
         "reconnection should have failed, but it hasn't",
     );
 }
+
+/// Tests that [`JasonImpl::on_network_state_change()`] callback is invoked
+/// only when [`NetworkState`] actually changes.
+///
+/// [`NetworkState`]: medea_jason::platform::NetworkState
+#[wasm_bindgen_test]
+async fn reports_network_state_changes() {
+    let jason = JasonImpl::new(None);
+    let states = Rc::new(RefCell::new(Vec::new()));
+    let cb = Closure::<dyn FnMut(api::NetworkState)>::new({
+        let states = Rc::clone(&states);
+        move |s| states.borrow_mut().push(s)
+    });
+    jason.on_network_state_change(
+        cb.into_js_value().unchecked_into::<js_sys::Function>().into(),
+    );
+
+    jason.set_network_state(NetworkState::Offline);
+    jason.set_network_state(NetworkState::Offline);
+    jason.set_network_state(NetworkState::Frozen);
+    jason.set_network_state(NetworkState::Online);
+
+    assert_eq!(
+        *states.borrow(),
+        [
+            api::NetworkState::Offline,
+            api::NetworkState::Frozen,
+            api::NetworkState::Online,
+        ],
+    );
+}
+
+/// Tests that [`JasonImpl::set_network_state()`] reconnects only once the
+/// network goes [`NetworkState::Online`] after being
+/// [`NetworkState::Offline`], and not on resuming from
+/// [`NetworkState::Frozen`].
+#[wasm_bindgen_test]
+async fn reconnects_only_after_network_goes_offline() {
+    let created_count = Rc::new(RefCell::new(0));
+    let ws = Rc::new(WebSocketRpcClient::new({
+        let created_count = Rc::clone(&created_count);
+        Box::new(move || {
+            *created_count.borrow_mut() += 1;
+
+            let mut t = MockRpcTransport::new();
+            t.expect_connect()
+                .return_once(|_| Box::pin(futures::future::ok(())));
+            t.expect_on_state_change().return_once(|| {
+                Box::pin(stream::once(async { TransportState::Open }))
+            });
+            t.expect_on_message().returning(|| {
+                Box::pin(
+                    stream::iter(vec![
+                        RPC_SETTINGS,
+                        ServerMsg::Event {
+                            room_id: "room_id".into(),
+                            event: Event::RoomJoined {
+                                member_id: "member_id".into(),
+                                is_reconnect: false,
+                                members: Vec::new(),
+                            },
+                        },
+                    ])
+                    .chain(stream::pending()),
+                )
+            });
+            t.expect_send().returning(|_| Ok(()));
+            t.expect_set_close_reason().return_const(());
+            Rc::new(t) as Rc<dyn RpcTransport>
+        })
+    }));
+
+    let jason_impl = JasonImpl::new(Some(ws));
+    let jason = api::Jason::from(jason_impl.clone());
+    let room = jason.init_room();
+    room.on_failed_local_media(Closure::once_into_js(|| {}).into()).unwrap();
+    room.on_connection_loss(Closure::once_into_js(|| {}).into()).unwrap();
+    JsFuture::from(room.join(TEST_ROOM_URL.to_string())).await.unwrap();
+    assert_eq!(*created_count.borrow(), 1);
+
+    jason_impl.set_network_state(NetworkState::Frozen);
+    jason_impl.set_network_state(NetworkState::Online);
+    delay_for(100).await;
+    assert_eq!(*created_count.borrow(), 1, "reconnected on resume");
+
+    jason_impl.set_network_state(NetworkState::Offline);
+    jason_impl.set_network_state(NetworkState::Online);
+    delay_for(100).await;
+    assert_eq!(*created_count.borrow(), 2, "not reconnected on network change");
+}
+
+/// Tests that [`RoomHandle::leave()`] removes the left `Room` from the
+/// [`JasonImpl`], so its [`RoomHandle`] becomes detached.
+///
+/// [`RoomHandle`]: api::RoomHandle
+/// [`RoomHandle::leave()`]: api::RoomHandle::leave
+#[wasm_bindgen_test]
+async fn left_room_is_removed() {
+    let ws = Rc::new(WebSocketRpcClient::new(Box::new(|| {
+        let subs =
+            Rc::new(RefCell::new(Vec::<mpsc::UnboundedSender<_>>::new()));
+        let mut t = MockRpcTransport::new();
+        t.expect_connect().return_once(|_| Box::pin(futures::future::ok(())));
+        t.expect_on_state_change().return_once(|| {
+            Box::pin(stream::once(async { TransportState::Open }))
+        });
+        t.expect_on_message().returning_st({
+            let subs = Rc::clone(&subs);
+            move || {
+                let (server_tx, server_rx) = mpsc::unbounded();
+                subs.borrow_mut().push(server_tx);
+                Box::pin(stream::once(async { RPC_SETTINGS }).chain(server_rx))
+            }
+        });
+        t.expect_send().returning_st(move |msg| {
+            let event = match msg {
+                ClientMsg::Command {
+                    command: Command::JoinRoom { .. },
+                    ..
+                } => Event::RoomJoined {
+                    member_id: "member_id".into(),
+                    is_reconnect: false,
+                    members: Vec::new(),
+                },
+                ClientMsg::Command {
+                    command: Command::LeaveRoom { .. },
+                    ..
+                } => Event::RoomLeft {
+                    close_reason: medea_client_api_proto::CloseReason::Finished,
+                },
+                _ => return Ok(()),
+            };
+            for server_tx in subs.borrow().iter() {
+                _ = server_tx.unbounded_send(ServerMsg::Event {
+                    room_id: "room_id".into(),
+                    event: event.clone(),
+                });
+            }
+            Ok(())
+        });
+        t.expect_set_close_reason().return_const(());
+        Rc::new(t) as Rc<dyn RpcTransport>
+    })));
+
+    let jason = api::Jason::from(JasonImpl::new(Some(ws)));
+    let room = jason.init_room();
+    room.on_failed_local_media(Closure::once_into_js(|| {}).into()).unwrap();
+    room.on_connection_loss(Closure::once_into_js(|| {}).into()).unwrap();
+    JsFuture::from(room.join(TEST_ROOM_URL.to_string())).await.unwrap();
+    room.on_close(Closure::once_into_js(|_: JsValue| {}).into()).unwrap();
+
+    JsFuture::from(room.leave(None)).await.unwrap();
+
+    assert!(
+        room.on_close(Closure::once_into_js(|_: JsValue| {}).into()).is_err(),
+        "left `Room` is still held by `Jason`",
+    );
+}
@@ -1,10 +1,20 @@
 #![cfg(target_arch = "wasm32")]
 
+use js_sys::Reflect;
 use medea_client_api_proto::MediaSourceKind;
-use medea_jason::media::{
-    DeviceAudioTrackConstraints, DeviceVideoTrackConstraints,
-    DisplayAudioTrackConstraints, DisplayVideoTrackConstraints, MediaKind,
-    MediaManager, MediaStreamSettings, MultiSourceTracksConstraints,
+use medea_jason::{
+    media::{
+        DeviceAudioTrackConstraints, DeviceCapabilities,
+        DeviceVideoTrackConstraints, DisplayAudioTrackConstraints,
+        DisplayVideoTrackConstraints, FacingMode, MediaKind, MediaManager,
+        MediaStreamSettings, MultiSourceTracksConstraints, ValueRange,
+        VideoSource,
+        constraints::{
+            ConstrainBoolean, ConstrainString, ConstrainU32, ConstrainU32Range,
+            NativeConstraint,
+        },
+    },
+    platform,
 };
 use wasm_bindgen::JsValue;
 use wasm_bindgen_futures::JsFuture;
@@ -472,7 +482,207 @@ fn js_val_to_option(val: JsValue) -> Option<JsValue> {
     if val.is_undefined() { None } else { Some(val) }
 }
 
-/// Returns all registered media devices.
+/// Tests that [`DeviceVideoTrackConstraints::fit_to()`] relaxes constraints
+/// unsatisfiable by a device into ideal ones.
+#[wasm_bindgen_test]
+async fn video_constraints_fit_to_capabilities() {
+    let caps = DeviceCapabilities {
+        width: Some(ValueRange::new(320, 1920)),
+        height: Some(ValueRange::new(240, 1080)),
+        frame_rate: Some(ValueRange::new(1.0, 30.0)),
+        facing_modes: vec![FacingMode::User],
+        ..DeviceCapabilities::default()
+    };
+
+    let mut constraints = DeviceVideoTrackConstraints::new();
+    constraints.exact_width(3840);
+    constraints.height_in_range(100, 720);
+    constraints.exact_frame_rate(60);
+    constraints.exact_facing_mode(FacingMode::Environment);
+    constraints.fit_to(&caps);
+
+    assert_eq!(constraints.width, Some(ConstrainU32::Ideal(3840)));
+    assert_eq!(constraints.height, Some(ConstrainU32::Range(100, 720)));
+    assert_eq!(constraints.frame_rate, Some(ConstrainU32::Ideal(60)));
+    assert!(constraints.facing_mode.is_none());
+
+    let mut constraints = DeviceVideoTrackConstraints::new();
+    constraints.exact_width(1280);
+    constraints.height_in_range(1200, 1440);
+    constraints.fit_to(&caps);
+
+    assert_eq!(constraints.width, Some(ConstrainU32::Exact(1280)));
+    assert_eq!(constraints.height, Some(ConstrainU32::Ideal(1200)));
+
+    let mut constraints = DeviceVideoTrackConstraints::new();
+    constraints.ideal_width(640);
+    constraints.ideal_facing_mode(FacingMode::User);
+    constraints.fit_to(&caps);
+
+    assert_eq!(constraints.width, Some(ConstrainU32::Ideal(640)));
+    assert!(matches!(
+        constraints.facing_mode,
+        Some(ConstrainString::Ideal(FacingMode::User)),
+    ));
+}
+
+/// Tests that [`MediaStreamSettings::live_video_updates()`] reports only
+/// changes which can be applied to live tracks.
+#[wasm_bindgen_test]
+async fn live_video_updates_ignore_source_changes() {
+    let mut video = DeviceVideoTrackConstraints::new();
+    video.device_id("foo".into());
+    video.ideal_width(640);
+    let mut current = MediaStreamSettings::new();
+    current.device_video(video.clone());
+
+    assert!(current.live_video_updates(&current).is_empty());
+
+    let mut resized = video.clone();
+    resized.exact_width(1280);
+    let mut settings = MediaStreamSettings::new();
+    settings.device_video(resized);
+    let updates = settings.live_video_updates(&current);
+    assert!(matches!(
+        updates.as_slice(),
+        [VideoSource::Device(c)] if c.width == Some(ConstrainU32::Exact(1280)),
+    ));
+
+    let mut switched = video;
+    switched.device_id("bar".into());
+    let mut settings = MediaStreamSettings::new();
+    settings.device_video(switched);
+    assert!(settings.live_video_updates(&current).is_empty());
+}
+
+/// Tests that the web serialization of all the constraints conforms to their
+/// [`NativeConstraint`] lowering used on the Dart platform, so the same
+/// constraints request the same media on both platforms.
+#[wasm_bindgen_test]
+async fn native_constraints_conform_to_web_serialization() {
+    let string = |c: ConstrainString<String>| {
+        NativeConstraint::from(c).map(|v| JsValue::from_str(&v))
+    };
+    let boolean =
+        |c: ConstrainBoolean| NativeConstraint::from(c).map(JsValue::from_bool);
+    let range = |c: ConstrainU32| {
+        ConstrainU32Range::from(c)
+            .native_candidates(640)
+            .into_iter()
+            .map(|c| c.map(JsValue::from))
+            .collect::<Vec<_>>()
+    };
+
+    for c in [
+        ConstrainU32::Exact(720),
+        ConstrainU32::Ideal(720),
+        ConstrainU32::Range(320, 1080),
+        ConstrainU32::Range(720, 1080),
+        ConstrainU32::Range(120, 240),
+    ] {
+        let mut video = DeviceVideoTrackConstraints::new();
+        video.width = Some(c);
+        video.height = Some(c);
+        video.frame_rate = Some(c);
+        let video = web_video(video);
+        for native in range(c) {
+            assert_conforms(&member(&video, "width"), native.clone());
+            assert_conforms(&member(&video, "height"), native.clone());
+            assert_conforms(&member(&video, "frameRate"), native);
+        }
+        if let ConstrainU32::Range(min, max) = c {
+            let values: Vec<_> =
+                range(c).into_iter().map(|c| c.value).collect();
+            assert!(values.contains(&JsValue::from(min)));
+            assert!(values.contains(&JsValue::from(max)));
+        }
+    }
+
+    for c in [
+        ConstrainString::Exact(FacingMode::User),
+        ConstrainString::Ideal(FacingMode::Environment),
+    ] {
+        let mut video = DeviceVideoTrackConstraints::new();
+        video.facing_mode = Some(c);
+        let native = NativeConstraint::from(c)
+            .map(|v: FacingMode| JsValue::from_str(v.as_ref()));
+        assert_conforms(&member(&web_video(video), "facingMode"), native);
+    }
+
+    for c in [
+        ConstrainString::Exact(String::from("device")),
+        ConstrainString::Ideal(String::from("device")),
+    ] {
+        let mut video = DeviceVideoTrackConstraints::new();
+        video.device_id = Some(c.clone());
+        assert_conforms(
+            &member(&web_video(video), "deviceId"),
+            string(c.clone()),
+        );
+
+        let mut audio = DeviceAudioTrackConstraints::new();
+        audio.device_id = Some(c.clone());
+        assert_conforms(&member(&web_audio(audio), "deviceId"), string(c));
+    }
+
+    for c in [ConstrainBoolean::Exact(true), ConstrainBoolean::Ideal(false)] {
+        let mut audio = DeviceAudioTrackConstraints::new();
+        audio.auto_gain_control = Some(c);
+        audio.echo_cancellation = Some(c);
+        audio.noise_suppression = Some(c);
+        let audio = web_audio(audio);
+        assert_conforms(&member(&audio, "autoGainControl"), boolean(c));
+        assert_conforms(&member(&audio, "echoCancellation"), boolean(c));
+        assert_conforms(&member(&audio, "noiseSuppression"), boolean(c));
+    }
+}
+
+/// Returns the web serialization of the provided
+/// [`DeviceVideoTrackConstraints`].
+fn web_video(video: DeviceVideoTrackConstraints) -> JsValue {
+    let constraints = platform::MediaStreamConstraints::new();
+    constraints.video(video);
+    let sys: &web_sys::MediaStreamConstraints = constraints.as_ref();
+    member(sys, "video")
+}
+
+/// Returns the web serialization of the provided
+/// [`DeviceAudioTrackConstraints`].
+fn web_audio(audio: DeviceAudioTrackConstraints) -> JsValue {
+    let constraints = platform::MediaStreamConstraints::new();
+    constraints.audio(audio);
+    let sys: &web_sys::MediaStreamConstraints = constraints.as_ref();
+    member(sys, "audio")
+}
+
+/// Returns the `name` member of the provided JS object.
+fn member(obj: &JsValue, name: &str) -> JsValue {
+    Reflect::get(obj, &name.into()).unwrap()
+}
+
+/// Asserts that the provided web serialization of a constraint requests the
+/// same media as the provided [`NativeConstraint`].
+fn assert_conforms(web: &JsValue, native: NativeConstraint<JsValue>) {
+    let exact = member(web, "exact");
+    let ideal = member(web, "ideal");
+    let min = member(web, "min").as_f64();
+    let max = member(web, "max").as_f64();
+
+    assert_eq!(
+        native.mandatory,
+        !exact.is_undefined() || min.is_some() || max.is_some(),
+    );
+    if !exact.is_undefined() {
+        assert_eq!(native.value, exact);
+    } else if min.is_some() || max.is_some() {
+        let value = native.value.as_f64().unwrap();
+        assert!(min.is_none_or(|min| value >= min));
+        assert!(max.is_none_or(|max| value <= max));
+    } else {
+        assert_eq!(native.value, ideal);
+    }
+}
+
 async fn get_media_devices() -> Result<Vec<MediaDeviceInfo>, JsValue> {
     let media_devices = window().navigator().media_devices()?;
     let media_devices =
@@ -1,24 +1,28 @@
 #![cfg(target_arch = "wasm32")]
 
+use std::{cell::Cell, rc::Rc};
+
 use js_sys::Array as JsArray;
 use medea_jason::{
     api,
     api::err::{
-        EnumerateDevicesException, LocalMediaInitException,
+        DeviceNotFoundException, EnumerateDevicesException,
+        InvalidOutputAudioDeviceIdException, LocalMediaInitException,
         LocalMediaInitExceptionKind,
     },
     media::{
         DeviceAudioTrackConstraints, DeviceVideoTrackConstraints,
         DisplayAudioTrackConstraints, DisplayVideoTrackConstraints,
-        GetUserMediaError, InitLocalTracksError, MediaKind, MediaManager,
-        MediaStreamSettings,
+        GetUserMediaError, InitLocalTracksError, MediaDeviceKind, MediaKind,
+        MediaManager, MediaStreamSettings, track::local::LocalMediaTrackImpl,
     },
 };
+use wasm_bindgen::{JsCast as _, JsValue, closure::Closure};
 use wasm_bindgen_futures::JsFuture;
 use wasm_bindgen_test::*;
 use web_sys as sys;
 
-use crate::{MockNavigator, is_firefox, jsval_cast};
+use crate::{MockNavigator, delay_for, is_firefox, jsval_cast};
 
 wasm_bindgen_test_configure!(run_in_browser);
 
@@ -154,6 +158,107 @@ async fn failed_get_user_media2() {
     }
 }
 
+#[wasm_bindgen_test]
+async fn get_user_media_permission_denied() {
+    for (message, kind) in [
+        (
+            "Permission denied",
+            LocalMediaInitExceptionKind::PermissionDeniedByUser,
+        ),
+        (
+            "Permission denied by system",
+            LocalMediaInitExceptionKind::PermissionDeniedBySystem,
+        ),
+    ] {
+        let mock_navigator = MockNavigator::new();
+        let error = js_sys::Error::new(message);
+        error.set_name("NotAllowedError");
+        mock_navigator.error_get_user_media(error.into());
+
+        let media_manager = MediaManager::default();
+        let constraints = {
+            let mut constraints = api::MediaStreamSettings::new();
+            constraints.device_audio(api::DeviceAudioTrackConstraints::new());
+            constraints
+        };
+        let err = JsFuture::from(
+            api::MediaManagerHandle::from(media_manager.new_handle())
+                .init_local_tracks(&constraints),
+        )
+        .await
+        .unwrap_err();
+        mock_navigator.stop();
+
+        let err = jsval_cast::<LocalMediaInitException>(
+            err,
+            "LocalMediaInitException",
+        )
+        .unwrap();
+        assert_eq!(err.kind(), kind);
+    }
+}
+
+#[wasm_bindgen_test]
+async fn permission_state_works() {
+    if is_firefox() {
+        // Firefox doesn't support querying `camera` and `microphone`
+        // permissions in all the versions we run tests on.
+        return;
+    }
+
+    let media_manager = MediaManager::default();
+    let handle = api::MediaManagerHandle::from(media_manager.new_handle());
+
+    for kind in [api::PermissionKind::Microphone, api::PermissionKind::Camera] {
+        let state = JsFuture::from(handle.permission_state(kind)).await;
+
+        assert!(state.unwrap().as_f64().is_some());
+    }
+}
+
+#[wasm_bindgen_test]
+async fn run_diagnostics_works() {
+    let media_manager = MediaManager::default();
+
+    let report =
+        media_manager.new_handle().run_diagnostics(Vec::new()).await.unwrap();
+
+    assert!(
+        report
+            .devices
+            .iter()
+            .any(|d| d.kind == MediaKind::Audio && d.error.is_none())
+    );
+    assert!(
+        report
+            .devices
+            .iter()
+            .any(|d| d.kind == MediaKind::Video && d.width.is_some())
+    );
+    assert!(report.ice.host_candidates > 0);
+    assert!(report.ice.connected);
+    assert!(report.throughput.is_some());
+}
+
+#[wasm_bindgen_test]
+async fn run_diagnostics_resolves_with_report() {
+    let media_manager = MediaManager::default();
+    let handle = api::MediaManagerHandle::from(media_manager.new_handle());
+    let mut ice_server =
+        api::IceServer::new(vec!["stun:127.0.0.1:3478".into()]);
+    ice_server.username("username".into());
+    ice_server.credential("credential".into());
+
+    let report =
+        JsFuture::from(handle.run_diagnostics(vec![ice_server])).await.unwrap();
+
+    let report =
+        jsval_cast::<api::DiagnosticsReport>(report, "DiagnosticsReport")
+            .unwrap();
+    assert!(!report.devices().is_empty());
+    assert!(report.ice().host_candidates() > 0);
+}
+
 /// 1. Do `media_manager.get_stream(caps)`
 /// 2. Only one `getUserMedia` request
 /// 3. Do `media_manager.get_stream(caps)`
@@ -458,3 +563,198 @@ async fn new_tracks_should_be_live() {
 
     mock_navigator.stop();
 }
+
+/// Tests that [`api::MediaManagerHandle::set_output_audio_id()`] rejects IDs of
+/// unknown audio output devices.
+#[wasm_bindgen_test]
+async fn set_output_audio_id_rejects_unknown_device() {
+    let media_manager = MediaManager::default();
+    let result = JsFuture::from(
+        api::MediaManagerHandle::from(media_manager.new_handle())
+            .set_output_audio_id("unknown_device_id".into()),
+    )
+    .await;
+
+    let err = jsval_cast::<InvalidOutputAudioDeviceIdException>(
+        result.unwrap_err(),
+        "InvalidOutputAudioDeviceIdException",
+    )
+    .unwrap();
+    assert!(err.trace().contains("at src"));
+}
+
+/// Tests that microphone volume set via
+/// [`api::MediaManagerHandle::set_microphone_volume()`] is clamped to the
+/// `[0;100]` range, returned back by the
+/// [`api::MediaManagerHandle::microphone_volume()`] and applied to the live
+/// local audio tracks captured at the default volume level, while
+/// [`api::LocalMediaTrack::set_microphone_volume()`] affects a single track
+/// only.
+#[wasm_bindgen_test]
+async fn set_microphone_volume_works() {
+    let media_manager = MediaManager::default();
+    let handle = api::MediaManagerHandle::from(media_manager.new_handle());
+    let mut caps = MediaStreamSettings::new();
+    caps.device_audio(DeviceAudioTrackConstraints::new());
+    let (track, _) =
+        media_manager.get_tracks(caps).await.unwrap().pop().unwrap();
+    let track = api::LocalMediaTrack::from(LocalMediaTrackImpl::new(track));
+    assert_eq!(track.microphone_volume(), Some(100));
+
+    assert_eq!(
+        JsFuture::from(handle.microphone_volume_is_available()).await.unwrap(),
+        JsValue::TRUE,
+    );
+
+    JsFuture::from(handle.set_microphone_volume(40)).await.unwrap();
+    assert_eq!(
+        JsFuture::from(handle.microphone_volume()).await.unwrap(),
+        JsValue::from(40_i64),
+    );
+    assert_eq!(track.microphone_volume(), Some(40));
+
+    track.set_microphone_volume(70);
+    assert_eq!(track.microphone_volume(), Some(70));
+    assert_eq!(
+        JsFuture::from(handle.microphone_volume()).await.unwrap(),
+        JsValue::from(40_i64),
+    );
+
+    JsFuture::from(handle.set_microphone_volume(150)).await.unwrap();
+    assert_eq!(
+        JsFuture::from(handle.microphone_volume()).await.unwrap(),
+        JsValue::from(100_i64),
+    );
+    assert_eq!(track.microphone_volume(), Some(100));
+}
+
+/// Tests that [`api::MediaManagerHandle::device_capabilities()`] reports
+/// capabilities of a video input device and rejects unknown devices.
+#[wasm_bindgen_test]
+async fn device_capabilities_works() {
+    let media_manager = MediaManager::default();
+    let handle = api::MediaManagerHandle::from(media_manager.new_handle());
+    let video_device = media_manager
+        .new_handle()
+        .enumerate_devices()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|d| d.kind() == MediaDeviceKind::VideoInput)
+        .unwrap();
+
+    let caps =
+        JsFuture::from(handle.device_capabilities(video_device.device_id()))
+            .await
+            .unwrap();
+    let caps =
+        jsval_cast::<api::DeviceCapabilities>(caps, "DeviceCapabilities")
+            .unwrap();
+    if !is_firefox() {
+        assert!(caps.width().is_some());
+        assert!(caps.height().is_some());
+    }
+
+    let err = JsFuture::from(handle.device_capabilities("unknown".into()))
+        .await
+        .unwrap_err();
+    let err =
+        jsval_cast::<DeviceNotFoundException>(err, "DeviceNotFoundException")
+            .unwrap();
+    assert_eq!(err.device_id(), "unknown");
+}
+
+/// Tests that media frames of the local video tracks are passed through the
+/// processor registered via [`api::MediaManagerHandle::register_processor()`]
+/// only while it's enabled.
+#[wasm_bindgen_test]
+async fn register_processor_works() {
+    if is_firefox() {
+        // `MediaStreamTrackProcessor` is not supported in Firefox.
+        return;
+    }
+    let media_manager = MediaManager::default();
+    let handle = api::MediaManagerHandle::from(media_manager.new_handle());
+    let frames = Rc::new(Cell::new(0));
+    handle
+        .register_processor(
+            api::MediaKind::Video,
+            api::MediaSourceKind::Device,
+            Closure::<dyn FnMut(JsValue) -> JsValue>::new({
+                let frames = Rc::clone(&frames);
+                move |frame| {
+                    frames.set(frames.get() + 1);
+                    frame
+                }
+            })
+            .into_js_value()
+            .unchecked_into(),
+        )
+        .unwrap();
+
+    let mut caps = MediaStreamSettings::new();
+    caps.device_video(DeviceVideoTrackConstraints::new());
+    let tracks = media_manager.get_tracks(caps).await.unwrap();
+    assert_eq!(tracks.len(), 1);
+    delay_for(500).await;
+    assert!(frames.get() > 0);
+
+    handle
+        .set_processor_enabled(
+            api::MediaKind::Video,
+            api::MediaSourceKind::Device,
+            false,
+        )
+        .unwrap();
+    delay_for(100).await;
+    let processed = frames.get();
+    delay_for(300).await;
+    assert_eq!(frames.get(), processed);
+
+    handle
+        .unregister_processor(
+            api::MediaKind::Video,
+            api::MediaSourceKind::Device,
+        )
+        .unwrap();
+}
+
+/// Tests that media frames are passed as is, keeping the local track alive,
+/// if the processor registered via
+/// [`api::MediaManagerHandle::register_processor()`] throws.
+#[wasm_bindgen_test]
+async fn failing_processor_falls_back_to_source_frames() {
+    if is_firefox() {
+        // `MediaStreamTrackProcessor` is not supported in Firefox.
+        return;
+    }
+    let media_manager = MediaManager::default();
+    let handle = api::MediaManagerHandle::from(media_manager.new_handle());
+    let calls = Rc::new(Cell::new(0));
+    handle
+        .register_processor(
+            api::MediaKind::Video,
+            api::MediaSourceKind::Device,
+            Closure::<dyn FnMut(JsValue) -> Result<JsValue, JsValue>>::new({
+                let calls = Rc::clone(&calls);
+                move |_| {
+                    calls.set(calls.get() + 1);
+                    Err(js_sys::Error::new("processor failure").into())
+                }
+            })
+            .into_js_value()
+            .unchecked_into(),
+        )
+        .unwrap();
+
+    let mut caps = MediaStreamSettings::new();
+    caps.device_video(DeviceVideoTrackConstraints::new());
+    let mut tracks = media_manager.get_tracks(caps).await.unwrap();
+    assert_eq!(tracks.len(), 1);
+    let (track, _) = tracks.pop().unwrap();
+    delay_for(500).await;
+
+    assert!(calls.get() > 1);
+    let sys_track: &sys::MediaStreamTrack = track.as_ref().as_ref().as_ref();
+    assert_eq!(sys_track.ready_state(), sys::MediaStreamTrackState::Live);
+}
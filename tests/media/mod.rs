@@ -1,5 +1,6 @@
 mod constraints;
 mod manager;
+mod recorder;
 mod track;
 
 use std::rc::Rc;
@@ -0,0 +1,150 @@
+#![cfg(target_arch = "wasm32")]
+
+use std::{cell::RefCell, rc::Rc};
+
+use medea_jason::{
+    api::{
+        self, RecordingState,
+        err::{MediaRecorderException, MediaRecorderExceptionKind},
+    },
+    media::{
+        DeviceAudioTrackConstraints, DeviceVideoTrackConstraints,
+        DisplayVideoTrackConstraints, MediaManager, MediaStreamSettings,
+        track::local::LocalMediaTrackImpl,
+    },
+};
+use wasm_bindgen::{JsCast as _, closure::Closure};
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+
+use crate::{delay_for, jsval_cast};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Returns a new [`api::MediaRecorder`] along with local audio and video
+/// [`api::LocalMediaTrack`]s to be recorded.
+async fn recorder_with_tracks()
+-> (api::MediaRecorder, Vec<api::LocalMediaTrack>) {
+    let media_manager = MediaManager::default();
+    let mut caps = MediaStreamSettings::new();
+    caps.device_audio(DeviceAudioTrackConstraints::new());
+    caps.device_video(DeviceVideoTrackConstraints::new());
+
+    let tracks = media_manager
+        .get_tracks(caps)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(track, _)| {
+            api::LocalMediaTrack::from(LocalMediaTrackImpl::new(track))
+        })
+        .collect();
+    let recorder = api::MediaManagerHandle::from(media_manager.new_handle())
+        .new_recorder()
+        .unwrap();
+
+    (recorder, tracks)
+}
+
+#[wasm_bindgen_test]
+async fn recording_local_tracks_works() {
+    let (recorder, tracks) = recorder_with_tracks().await;
+    for track in &tracks {
+        recorder.add_local_track(track).unwrap();
+    }
+    let chunks = Rc::new(RefCell::new(Vec::new()));
+    recorder.on_data(
+        Closure::<dyn FnMut(web_sys::Blob)>::new({
+            let chunks = Rc::clone(&chunks);
+            move |data| chunks.borrow_mut().push(data)
+        })
+        .into_js_value()
+        .unchecked_into(),
+    );
+    assert_eq!(recorder.state(), RecordingState::Inactive);
+
+    recorder.start(Some(100)).unwrap();
+    assert_eq!(recorder.state(), RecordingState::Recording);
+    delay_for(300).await;
+
+    recorder.pause().unwrap();
+    assert_eq!(recorder.state(), RecordingState::Paused);
+    recorder.resume().unwrap();
+    assert_eq!(recorder.state(), RecordingState::Recording);
+    delay_for(300).await;
+
+    JsFuture::from(recorder.stop()).await.unwrap();
+    assert_eq!(recorder.state(), RecordingState::Inactive);
+    assert!(!chunks.borrow().is_empty());
+    assert!(chunks.borrow().iter().all(|chunk| chunk.size() > 0.0));
+}
+
+#[wasm_bindgen_test]
+async fn start_without_tracks_fails() {
+    let (recorder, _) = recorder_with_tracks().await;
+
+    let err = recorder.start(None).unwrap_err();
+
+    let err =
+        jsval_cast::<MediaRecorderException>(err, "MediaRecorderException")
+            .unwrap();
+    assert_eq!(err.kind(), MediaRecorderExceptionKind::NoTracks);
+    assert_eq!(recorder.state(), RecordingState::Inactive);
+}
+
+#[wasm_bindgen_test]
+async fn invalid_state_transitions_fail() {
+    let (recorder, tracks) = recorder_with_tracks().await;
+    recorder.add_local_track(&tracks[0]).unwrap();
+
+    let err = recorder.pause().unwrap_err();
+    let err =
+        jsval_cast::<MediaRecorderException>(err, "MediaRecorderException")
+            .unwrap();
+    assert_eq!(err.kind(), MediaRecorderExceptionKind::InvalidState);
+
+    recorder.start(None).unwrap();
+    let err = recorder.add_local_track(&tracks[1]).unwrap_err();
+    let err =
+        jsval_cast::<MediaRecorderException>(err, "MediaRecorderException")
+            .unwrap();
+    assert_eq!(err.kind(), MediaRecorderExceptionKind::InvalidState);
+
+    JsFuture::from(recorder.stop()).await.unwrap();
+    let err = JsFuture::from(recorder.stop()).await.unwrap_err();
+    let err =
+        jsval_cast::<MediaRecorderException>(err, "MediaRecorderException")
+            .unwrap();
+    assert_eq!(err.kind(), MediaRecorderExceptionKind::InvalidState);
+}
+
+#[wasm_bindgen_test]
+async fn start_with_several_video_tracks_fails() {
+    let media_manager = MediaManager::default();
+    let mut caps = MediaStreamSettings::new();
+    caps.device_video(DeviceVideoTrackConstraints::new());
+    caps.display_video(DisplayVideoTrackConstraints::new());
+    let tracks: Vec<_> = media_manager
+        .get_tracks(caps)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(track, _)| {
+            api::LocalMediaTrack::from(LocalMediaTrackImpl::new(track))
+        })
+        .collect();
+    let recorder = api::MediaManagerHandle::from(media_manager.new_handle())
+        .new_recorder()
+        .unwrap();
+    for track in &tracks {
+        recorder.add_local_track(track).unwrap();
+    }
+
+    let err = recorder.start(None).unwrap_err();
+
+    let err =
+        jsval_cast::<MediaRecorderException>(err, "MediaRecorderException")
+            .unwrap();
+    assert_eq!(err.kind(), MediaRecorderExceptionKind::TooManyVideoTracks);
+    assert_eq!(recorder.state(), RecordingState::Inactive);
+}
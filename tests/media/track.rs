@@ -9,8 +9,9 @@ use futures::{
 use medea_jason::{
     api::MediaDirection,
     media::{
+        CameraControlError, DeviceAudioTrackConstraints,
         DeviceVideoTrackConstraints, MediaManager, MediaStreamSettings,
-        track::remote,
+        track::{local::LocalMediaTrackImpl, remote},
     },
 };
 use wasm_bindgen::closure::Closure;
@@ -39,6 +40,48 @@ async fn track_autostop() {
     assert_eq!(Weak::strong_count(&weak_track), 0);
 }
 
+/// Assert that audio track processed by a microphone gain is stopped when all
+/// strong refs are dropped.
+#[wasm_bindgen_test]
+async fn audio_track_autostop() {
+    let media_manager = MediaManager::default();
+    let mut caps = MediaStreamSettings::new();
+    caps.device_audio(DeviceAudioTrackConstraints::new());
+
+    let mut tracks = media_manager.get_tracks(caps).await.unwrap();
+
+    assert_eq!(1, tracks.len());
+    let (strong_track, _) = tracks.pop().unwrap();
+    let sys_track = Clone::clone(strong_track.as_ref().as_ref().as_ref());
+
+    assert!(sys_track.ready_state() == web_sys::MediaStreamTrackState::Live);
+    drop(strong_track);
+    assert!(sys_track.ready_state() == web_sys::MediaStreamTrackState::Ended);
+}
+
+/// Assert that camera controls are reported unavailable for audio tracks, and
+/// cannot be set.
+#[wasm_bindgen_test]
+async fn camera_controls_unavailable_for_audio_track() {
+    let media_manager = MediaManager::default();
+    let mut caps = MediaStreamSettings::new();
+    caps.device_audio(DeviceAudioTrackConstraints::new());
+
+    let mut tracks = media_manager.get_tracks(caps).await.unwrap();
+    let track = LocalMediaTrackImpl::new(tracks.pop().unwrap().0);
+
+    assert!(!track.is_zoom_available());
+    assert!(!track.is_torch_available());
+    assert!(!track.is_focus_mode_available());
+    assert!(!track.is_focus_distance_available());
+    assert!(!track.is_exposure_compensation_available());
+    assert!(!track.is_white_balance_mode_available());
+    assert!(matches!(
+        track.set_torch_enabled(true).await.unwrap_err().as_ref(),
+        CameraControlError::NotSupported,
+    ));
+}
+
 #[wasm_bindgen_test]
 async fn on_track_unmuted_works() {
     let api_track = get_audio_track().await;
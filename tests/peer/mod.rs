@@ -10,7 +10,7 @@ use futures::{
     future::{self, FutureExt as _},
 };
 use medea_client_api_proto::{
-    AudioSettings, ConnectionMode, Direction, IceConnectionState,
+    AudioSettings, ConnectionMode, Direction, IceConnectionState, IceServer,
     MediaDirection, MediaSourceKind, MediaType, MemberId, NegotiationRole,
     PeerId, Track, TrackId, TrackPatchEvent, VideoSettings,
     stats::{
@@ -1548,6 +1548,49 @@ mod ice_restart {
             .zip(ice_ufrags_after.into_iter())
             .for_each(|(before, after)| assert_ne!(before, after));
     }
+
+    /// Checks that [`IceServer`]s updated on a live `Peer` are applied to it
+    /// and used by a subsequent ICE restart.
+    #[wasm_bindgen_test]
+    async fn ice_servers_update_before_ice_restart() {
+        let peers = InterconnectedPeers::new().await;
+        peers
+            .first_peer
+            .state()
+            .set_negotiation_role(NegotiationRole::Offerer)
+            .await;
+        let sdp_offer_before =
+            peers.first_peer.state().when_local_sdp_updated().await.unwrap();
+        let ice_ufrags_before = get_ice_ufrags(&sdp_offer_before);
+        peers.first_peer.state().reset_negotiation_role();
+
+        let ice_servers = vec![IceServer {
+            urls: vec!["stun:127.0.0.1:3478".into()],
+            username: None,
+            credential: None,
+        }];
+        peers.first_peer.state().set_ice_servers(ice_servers.clone());
+        delay_for(100).await;
+        assert_eq!(
+            peers.first_peer.state().as_proto().ice_servers,
+            ice_servers
+        );
+
+        peers.first_peer.state().restart_ice();
+        peers
+            .first_peer
+            .state()
+            .set_negotiation_role(NegotiationRole::Offerer)
+            .await;
+        let sdp_offer_after =
+            peers.first_peer.state().when_local_sdp_updated().await.unwrap();
+        let ice_ufrags_after = get_ice_ufrags(&sdp_offer_after);
+
+        ice_ufrags_before
+            .into_iter()
+            .zip(ice_ufrags_after.into_iter())
+            .for_each(|(before, after)| assert_ne!(before, after));
+    }
 }
 
 /// Tests [`peer::State::patch_track`] method.
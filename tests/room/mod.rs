@@ -43,6 +43,7 @@ async fn only_one_strong_rpc_rc_exists() {
                         event: Event::RoomJoined {
                             member_id: "member_id".into(),
                             is_reconnect: false,
+                            members: Vec::new(),
                         },
                     },
                 ]))
@@ -88,6 +89,7 @@ async fn rpc_dropped_on_jason_dispose() {
                         event: Event::RoomJoined {
                             member_id: "member_id".into(),
                             is_reconnect: false,
+                            members: Vec::new(),
                         },
                     },
                 ]))
@@ -168,6 +170,7 @@ async fn room_dispose_works() {
                     event: Event::RoomJoined {
                         member_id: "member_id".into(),
                         is_reconnect: false,
+                        members: Vec::new(),
                     },
                 })
                 .ok();
@@ -193,6 +196,7 @@ async fn room_dispose_works() {
                     event: Event::RoomJoined {
                         member_id: "member_id".into(),
                         is_reconnect: false,
+                        members: Vec::new(),
                     },
                 })
                 .ok();
@@ -213,6 +217,7 @@ async fn room_dispose_works() {
                 member_id: _,
                 credential: _,
                 capabilities: _,
+                protocol_version: _,
             },
         },
     ));
@@ -224,6 +229,7 @@ async fn room_dispose_works() {
                 member_id: _,
                 credential: _,
                 capabilities: _,
+                protocol_version: _,
             },
         },
     ));
@@ -233,7 +239,7 @@ async fn room_dispose_works() {
         cmd_rx.next().await.unwrap(),
         ClientMsg::Command {
             room_id: _,
-            command: Command::LeaveRoom { member_id: _ },
+            command: Command::LeaveRoom { member_id: _, reason: None },
         },
     ));
 
@@ -242,7 +248,7 @@ async fn room_dispose_works() {
         cmd_rx.next().await.unwrap(),
         ClientMsg::Command {
             room_id: _,
-            command: Command::LeaveRoom { member_id: _ },
+            command: Command::LeaveRoom { member_id: _, reason: None },
         },
     ));
 
@@ -274,6 +280,7 @@ async fn room_closes_on_rpc_transport_close() {
                             event: Event::RoomJoined {
                                 member_id: "member_id".into(),
                                 is_reconnect: false,
+                                members: Vec::new(),
                             },
                         },
                     ]))
@@ -3,6 +3,7 @@
 use std::{
     collections::{HashMap, HashSet},
     rc::Rc,
+    time::Duration,
 };
 
 use futures::{
@@ -39,10 +40,10 @@ use wasm_bindgen_futures::{JsFuture, spawn_local};
 use wasm_bindgen_test::*;
 
 use crate::{
-    MockNavigator, TEST_ROOM_URL, delay_for, get_test_recv_tracks,
-    get_test_required_tracks, get_test_tracks, get_test_unrequired_tracks,
-    jsval_cast, media_stream_settings, timeout, wait_and_check_test_result,
-    yield_now,
+    ManualClockGuard, MockNavigator, TEST_ROOM_URL, delay_for,
+    get_test_recv_tracks, get_test_required_tracks, get_test_tracks,
+    get_test_unrequired_tracks, jsval_cast, media_stream_settings, timeout,
+    wait_and_check_test_result, yield_now,
 };
 
 wasm_bindgen_test_configure!(run_in_browser);
@@ -61,6 +62,8 @@ fn get_test_room(
     rpc.expect_on_connection_loss()
         .return_once(|| stream::pending().boxed_local());
     rpc.expect_on_reconnected().return_once(|| stream::pending().boxed_local());
+    rpc.expect_on_signalling_latency()
+        .return_once(|| stream::pending().boxed_local());
     rpc.expect_send_command().returning(move |command| {
         let _ = tx.unbounded_send(command);
     });
@@ -86,6 +89,8 @@ async fn get_test_room_and_exist_peer(
     rpc.expect_on_connection_loss()
         .return_once(|| stream::pending().boxed_local());
     rpc.expect_on_reconnected().return_once(|| stream::pending().boxed_local());
+    rpc.expect_on_signalling_latency()
+        .return_once(|| stream::pending().boxed_local());
     rpc.expect_close_with_reason().return_const(());
     let event_tx_clone = event_tx.clone();
     rpc.expect_send_command().returning(move |cmd| {
@@ -1405,6 +1410,176 @@ mod on_close_callback {
     }
 }
 
+mod members_roster {
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    /// Returns sorted IDs of all the `Member`s present in the provided
+    /// [`Room`].
+    fn member_ids(room: &Room) -> Vec<String> {
+        let mut ids: Vec<_> = room
+            .new_handle()
+            .members()
+            .unwrap()
+            .into_iter()
+            .map(|m| m.id.0)
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Tests that [`RoomHandle::members`] reflects the roster provided in
+    /// [`Event::RoomJoined`] and updated with [`Event::MemberJoined`],
+    /// [`Event::MemberUpdated`] and [`Event::MemberLeft`].
+    #[wasm_bindgen_test]
+    async fn roster_is_updated() {
+        let (event_tx, event_rx) = mpsc::unbounded();
+        let (room, _) = get_test_room(Box::pin(event_rx));
+
+        event_tx
+            .unbounded_send(Event::RoomJoined {
+                member_id: "alice".into(),
+                is_reconnect: false,
+                members: vec![proto::Member {
+                    id: "alice".into(),
+                    metadata: HashMap::new(),
+                }],
+            })
+            .unwrap();
+        event_tx
+            .unbounded_send(Event::MemberJoined {
+                member: proto::Member {
+                    id: "bob".into(),
+                    metadata: HashMap::new(),
+                },
+            })
+            .unwrap();
+        yield_now().await;
+        assert_eq!(member_ids(&room), ["alice", "bob"]);
+
+        let bob = proto::Member {
+            id: "bob".into(),
+            metadata: [("display_name".into(), "Bob".into())]
+                .into_iter()
+                .collect(),
+        };
+        event_tx
+            .unbounded_send(Event::MemberUpdated { member: bob.clone() })
+            .unwrap();
+        yield_now().await;
+        assert!(room.new_handle().members().unwrap().contains(&bob));
+
+        event_tx
+            .unbounded_send(Event::MemberLeft { member_id: "alice".into() })
+            .unwrap();
+        yield_now().await;
+        assert_eq!(member_ids(&room), ["bob"]);
+    }
+
+    /// Tests that [`RoomHandle::on_member_left`] is called with an ID of the
+    /// `Member` missing in the synchronized [`proto::state::Room`].
+    #[wasm_bindgen_test]
+    async fn on_member_left_fires_on_state_sync() {
+        let (event_tx, event_rx) = mpsc::unbounded();
+        let (room, _) = get_test_room(Box::pin(event_rx));
+        let room_handle = api::RoomHandle::from(room.new_handle());
+
+        event_tx
+            .unbounded_send(Event::MemberJoined {
+                member: proto::Member {
+                    id: "bob".into(),
+                    metadata: HashMap::new(),
+                },
+            })
+            .unwrap();
+        yield_now().await;
+
+        let (cb, test_result) = js_callback!(|member_id: JsValue| {
+            cb_assert_eq!(member_id.as_string().unwrap(), "bob");
+        });
+        room_handle.on_member_left(cb.into()).unwrap();
+
+        event_tx
+            .unbounded_send(Event::StateSynchronized {
+                state: proto::state::Room {
+                    peers: HashMap::new(),
+                    members: HashMap::new(),
+                },
+            })
+            .unwrap();
+        wait_and_check_test_result(test_result, || {}).await;
+        assert!(member_ids(&room).is_empty());
+    }
+
+    /// Tests that [`Command::SynchronizeMe`] sent on RPC reconnect carries all
+    /// the `Member`s known by the [`Room`].
+    #[wasm_bindgen_test]
+    async fn synchronize_me_carries_members() {
+        let (event_tx, event_rx) = mpsc::unbounded();
+        let (command_tx, mut command_rx) = mpsc::unbounded();
+        let (reconnect_tx, reconnect_rx) = mpsc::unbounded();
+
+        let mut rpc = MockRpcSession::new();
+        rpc.expect_subscribe().return_once(move || Box::pin(event_rx));
+        rpc.expect_close_with_reason().return_const(());
+        rpc.expect_on_connection_loss()
+            .return_once(|| stream::pending().boxed_local());
+        rpc.expect_on_reconnected().return_once(move || Box::pin(reconnect_rx));
+        rpc.expect_on_signalling_latency()
+            .return_once(|| stream::pending().boxed_local());
+        rpc.expect_send_command().returning(move |cmd| {
+            let _ = command_tx.unbounded_send(cmd);
+        });
+        let _room = Room::new(Rc::new(rpc), Rc::default());
+
+        let bob = proto::Member {
+            id: "bob".into(),
+            metadata: [("display_name".into(), "Bob".into())]
+                .into_iter()
+                .collect(),
+        };
+        event_tx
+            .unbounded_send(Event::MemberJoined { member: bob.clone() })
+            .unwrap();
+        yield_now().await;
+
+        reconnect_tx.unbounded_send(()).unwrap();
+        let state = timeout(1000, async {
+            loop {
+                if let Command::SynchronizeMe { state } =
+                    command_rx.next().await.unwrap()
+                {
+                    break state;
+                }
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(state.members, HashMap::from([(bob.id.clone(), bob)]));
+    }
+}
+
+/// Tests that [`RoomHandle::on_unknown_event`] is called with a name of an
+/// unrecognized [`Event`].
+#[wasm_bindgen_test]
+async fn on_unknown_event_fires() {
+    let (event_tx, event_rx) = mpsc::unbounded();
+    let (room, _) = get_test_room(Box::pin(event_rx));
+    let room_handle = api::RoomHandle::from(room.new_handle());
+
+    let (cb, test_result) = js_callback!(|event: JsValue| {
+        cb_assert_eq!(event.as_string().unwrap(), "SomethingNew");
+    });
+    room_handle.on_unknown_event(cb.into()).unwrap();
+
+    event_tx
+        .unbounded_send(Event::Unknown { event: "SomethingNew".into() })
+        .unwrap();
+    wait_and_check_test_result(test_result, || {}).await;
+}
+
 mod rpc_close_reason_on_room_drop {
     //! Tests which checks that when [`Room`] is dropped, the right close reason
     //! is provided to [`RpcClient`].
@@ -1426,6 +1601,8 @@ mod rpc_close_reason_on_room_drop {
             .return_once(|| stream::pending().boxed_local());
         rpc.expect_on_reconnected()
             .return_once(|| stream::pending().boxed_local());
+        rpc.expect_on_signalling_latency()
+            .return_once(|| stream::pending().boxed_local());
         let (test_tx, test_rx) = oneshot::channel();
         rpc.expect_close_with_reason().return_once(move |reason| {
             test_tx.send(reason).unwrap();
@@ -1545,6 +1722,8 @@ mod patches_generation {
             .return_once(|| stream::pending().boxed_local());
         rpc.expect_on_reconnected()
             .return_once(|| stream::pending().boxed_local());
+        rpc.expect_on_signalling_latency()
+            .return_once(|| stream::pending().boxed_local());
 
         let room = Room::new(Rc::new(rpc), Rc::default());
 
@@ -2406,6 +2585,8 @@ async fn send_enabling_holds_local_tracks() {
     rpc.expect_on_connection_loss()
         .return_once(|| stream::pending().boxed_local());
     rpc.expect_on_reconnected().return_once(|| stream::pending().boxed_local());
+    rpc.expect_on_signalling_latency()
+        .return_once(|| stream::pending().boxed_local());
     rpc.expect_close_with_reason().return_const(());
     rpc.expect_send_command().returning_st(|c| {
         if matches!(c, Command::UpdateTracks { .. }) {
@@ -3061,6 +3242,9 @@ mod state_synchronization {
         rpc_session
             .expect_on_reconnected()
             .return_once(|| Box::pin(stream::pending()));
+        rpc_session
+            .expect_on_signalling_latency()
+            .return_once(|| Box::pin(stream::pending()));
         rpc_session.expect_close_with_reason().returning(drop);
         rpc_session.expect_send_command().returning(move |cmd| {
             let _ = command_tx.unbounded_send(cmd);
@@ -3149,6 +3333,9 @@ mod state_synchronization {
             rpc_session
                 .expect_on_reconnected()
                 .return_once(move || Box::pin(reconnect_rx));
+            rpc_session
+                .expect_on_signalling_latency()
+                .return_once(|| Box::pin(stream::pending()));
             rpc_session
                 .expect_reconnect()
                 .returning(|| Box::pin(async { Ok(()) }));
@@ -3441,3 +3628,75 @@ async fn sender_answerer() {
         }
     }
 }
+
+/// Tests that [`Room`] scrapes [`PeerMetrics::RtcStats`] by the current
+/// [`platform::Clock`] according to the `stats_scrape_interval_ms` provided by
+/// a server.
+///
+/// # Algorithm
+///
+/// 1. Replace the current [`platform::Clock`] with a
+///    [`platform::ManualClock`].
+///
+/// 2. Create a `Peer` with `1s` stats scrape interval.
+///
+/// 3. Check that [`PeerMetrics::RtcStats`] are sent on the first stats loop
+///    step, and then only once `1s` elapses since the first scrape.
+#[wasm_bindgen_test]
+async fn stats_are_scraped_by_schedule() {
+    /// Advances the provided [`platform::ManualClock`] by the stats loop step
+    /// and returns the number of [`PeerMetrics::RtcStats`] sent since then.
+    async fn step(
+        clock: &platform::ManualClock,
+        commands_rx: &mut UnboundedReceiver<Command>,
+    ) -> usize {
+        clock.advance(Duration::from_millis(333));
+        delay_for(100).await;
+
+        let mut count = 0;
+        while let Ok(Some(command)) = commands_rx.try_next() {
+            if matches!(
+                command,
+                Command::AddPeerConnectionMetrics {
+                    metrics: PeerMetrics::RtcStats(_),
+                    ..
+                }
+            ) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    let clock = ManualClockGuard::install();
+    let (event_tx, event_rx) = mpsc::unbounded();
+    let (_room, mut commands_rx) = get_test_room(Box::pin(event_rx));
+    let (audio_track, video_track) = get_test_tracks(false, false);
+    event_tx
+        .unbounded_send(Event::PeerCreated {
+            peer_id: PeerId(1),
+            negotiation_role: NegotiationRole::Offerer,
+            tracks: vec![audio_track, video_track],
+            ice_servers: Vec::new(),
+            force_relay: false,
+            connection_mode: ConnectionMode::Mesh,
+            stats_scrape_interval_ms: 1000,
+        })
+        .unwrap();
+    loop {
+        let command = timeout(400, commands_rx.next()).await.unwrap().unwrap();
+        if matches!(command, Command::MakeSdpOffer { .. }) {
+            break;
+        }
+    }
+
+    assert_eq!(step(&clock, &mut commands_rx).await, 1, "no first scrape");
+    for _ in 0..3 {
+        assert_eq!(
+            step(&clock, &mut commands_rx).await,
+            0,
+            "scraped before interval elapsed",
+        );
+    }
+    assert_eq!(step(&clock, &mut commands_rx).await, 1, "no second scrape");
+}
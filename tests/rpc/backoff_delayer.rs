@@ -0,0 +1,71 @@
+//! Tests for [`medea_jason::rpc::BackoffDelayer`].
+
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+use futures::FutureExt as _;
+use medea_jason::rpc::BackoffDelayer;
+use wasm_bindgen_test::*;
+
+use crate::{ManualClockGuard, timeout};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Tests that [`BackoffDelayer`] awaits the current [`Clock`] between retries
+/// and increases the delay by the provided multiplier.
+///
+/// # Algorithm
+///
+/// 1. Replace the current [`Clock`] with a [`ManualClock`].
+///
+/// 2. Retry an operation failing twice with `1s` initial interval and `2.0`
+///    multiplier.
+///
+/// 3. Check that the second attempt is made only once `1s` elapses, and the
+///    third one only once `2s` more elapse.
+///
+/// [`Clock`]: medea_jason::platform::Clock
+/// [`ManualClock`]: medea_jason::platform::ManualClock
+#[wasm_bindgen_test]
+async fn retries_on_manual_clock() {
+    let clock = ManualClockGuard::install();
+    let attempts = Rc::new(Cell::new(0));
+    let mut retry = BackoffDelayer::new(
+        Duration::from_secs(1),
+        2.0,
+        Duration::from_secs(10),
+        None,
+    )
+    .retry({
+        let attempts = Rc::clone(&attempts);
+        move || {
+            attempts.set(attempts.get() + 1);
+            let attempt = attempts.get();
+            async move {
+                if attempt < 3 {
+                    Err(backoff::Error::transient(()))
+                } else {
+                    Ok(attempt)
+                }
+            }
+        }
+    })
+    .boxed_local();
+
+    timeout(50, &mut retry).await.unwrap_err();
+    assert_eq!(attempts.get(), 1, "retried before the clock advanced");
+
+    clock.advance(Duration::from_millis(999));
+    timeout(50, &mut retry).await.unwrap_err();
+    assert_eq!(attempts.get(), 1, "retried before the initial interval");
+
+    clock.advance(Duration::from_millis(1));
+    timeout(50, &mut retry).await.unwrap_err();
+    assert_eq!(attempts.get(), 2, "not retried after the initial interval");
+
+    clock.advance(Duration::from_millis(1999));
+    timeout(50, &mut retry).await.unwrap_err();
+    assert_eq!(attempts.get(), 2, "delay is not multiplied");
+
+    clock.advance(Duration::from_millis(1));
+    assert_eq!(timeout(100, retry).await.unwrap(), Ok(3));
+}
@@ -14,7 +14,7 @@ use medea_jason::{
 };
 use wasm_bindgen_test::*;
 
-use crate::{delay_for, timeout};
+use crate::{ManualClockGuard, delay_for, timeout, yield_now};
 
 wasm_bindgen_test_configure!(run_in_browser);
 
@@ -57,6 +57,101 @@ async fn sends_pong_on_received_ping() {
     .unwrap();
 }
 
+/// Tests that [`Heartbeat`] measures [`SignallingLatency`] by sending
+/// [`ClientMsg::Ping`] along with each [`ClientMsg::Pong`] and awaiting the
+/// matching [`ServerMsg::Pong`].
+///
+/// # Algorithm
+///
+/// 1. Enable latency probes, send [`ServerMsg::Ping`] and wait for
+///    [`ClientMsg::Ping`] probe.
+///
+/// 2. Answer the probe with [`ServerMsg::Pong`] and check that
+///    [`Heartbeat::on_latency`] fires with a single sample.
+///
+/// 3. Check that a [`ServerMsg::Pong`] with unknown number is ignored.
+#[wasm_bindgen_test]
+async fn measures_latency_on_pong() {
+    let mut transport = MockRpcTransport::new();
+    let (on_message_tx, on_message_rx) = mpsc::unbounded();
+    transport.expect_connect().return_once(|_| Box::pin(future::ok(())));
+    transport.expect_on_message().return_once(|| Box::pin(on_message_rx));
+    let (sent_tx, mut sent_rx) = mpsc::unbounded();
+    transport.expect_send().returning(move |msg| {
+        sent_tx.unbounded_send(msg.clone()).unwrap();
+        Ok(())
+    });
+
+    let hb = Heartbeat::start(
+        Rc::new(transport),
+        PingInterval(Duration::from_secs(10).into()),
+        IdleTimeout(Duration::from_secs(10).into()),
+    );
+    hb.set_latency_probes(true);
+    let mut on_latency = hb.on_latency();
+    assert_eq!(hb.latency(), None);
+
+    on_message_tx.unbounded_send(ServerMsg::Ping(1)).unwrap();
+    let probe = timeout(100, async {
+        loop {
+            if let ClientMsg::Ping(n) = sent_rx.next().await.unwrap() {
+                break n;
+            }
+        }
+    })
+    .await
+    .unwrap();
+
+    on_message_tx.unbounded_send(ServerMsg::Pong(probe + 1)).unwrap();
+    on_message_tx.unbounded_send(ServerMsg::Pong(probe)).unwrap();
+    let latency = timeout(100, on_latency.next()).await.unwrap().unwrap();
+    assert_eq!(latency.samples, 1);
+    assert_eq!(latency.smoothed_rtt, latency.rtt);
+    assert_eq!(latency.jitter, Duration::ZERO);
+    assert_eq!(hb.latency(), Some(latency));
+}
+
+/// Tests that [`Heartbeat`] doesn't send [`ClientMsg::Ping`]s unless latency
+/// probes are enabled.
+///
+/// # Algorithm
+///
+/// 1. Send [`ServerMsg::Ping`] and check that only [`ClientMsg::Pong`] is
+///    sent.
+///
+/// 2. Enable latency probes, send [`ServerMsg::Ping`] and check that
+///    [`ClientMsg::Ping`] probe is sent along with [`ClientMsg::Pong`].
+#[wasm_bindgen_test]
+async fn latency_probes_are_opt_in() {
+    let mut transport = MockRpcTransport::new();
+    let (on_message_tx, on_message_rx) = mpsc::unbounded();
+    transport.expect_connect().return_once(|_| Box::pin(future::ok(())));
+    transport.expect_on_message().return_once(|| Box::pin(on_message_rx));
+    let (sent_tx, mut sent_rx) = mpsc::unbounded();
+    transport.expect_send().returning(move |msg| {
+        sent_tx.unbounded_send(msg.clone()).unwrap();
+        Ok(())
+    });
+
+    let hb = Heartbeat::start(
+        Rc::new(transport),
+        PingInterval(Duration::from_secs(10).into()),
+        IdleTimeout(Duration::from_secs(10).into()),
+    );
+
+    on_message_tx.unbounded_send(ServerMsg::Ping(1)).unwrap();
+    let msg = timeout(100, sent_rx.next()).await.unwrap().unwrap();
+    assert_eq!(msg, ClientMsg::Pong(1));
+    timeout(50, sent_rx.next()).await.unwrap_err();
+
+    hb.set_latency_probes(true);
+    on_message_tx.unbounded_send(ServerMsg::Ping(2)).unwrap();
+    let pong = timeout(100, sent_rx.next()).await.unwrap().unwrap();
+    assert_eq!(pong, ClientMsg::Pong(2));
+    let probe = timeout(100, sent_rx.next()).await.unwrap().unwrap();
+    assert!(matches!(probe, ClientMsg::Ping(_)), "{probe:?}");
+}
+
 /// Tests that idle timeout works.
 ///
 /// # Algorithm
@@ -80,6 +175,91 @@ async fn on_idle_works() {
     timeout(120, hb.on_idle().next()).await.unwrap().unwrap();
 }
 
+/// Tests that idle timeout is measured by the current [`Clock`].
+///
+/// # Algorithm
+///
+/// 1. Replace the current [`Clock`] with a [`ManualClock`].
+///
+/// 2. Let [`Heartbeat`] schedule its timers, advance [`ManualClock`] by
+///    `ping_interval * 2` and wait for [`ClientMsg::Pong`].
+///
+/// 3. Check that [`Heartbeat::on_idle`] fires only once [`ManualClock`] is
+///    advanced up to `idle_timeout`.
+#[wasm_bindgen_test]
+async fn on_idle_works_with_manual_clock() {
+    let clock = ManualClockGuard::install();
+
+    let mut transport = MockRpcTransport::new();
+    transport.expect_connect().return_once(|_| Box::pin(future::ok(())));
+    transport.expect_on_message().return_once(|| stream::pending().boxed());
+    let (sent_tx, mut sent_rx) = mpsc::unbounded();
+    transport.expect_send().returning(move |msg| {
+        sent_tx.unbounded_send(msg.clone()).unwrap();
+        Ok(())
+    });
+
+    let hb = Heartbeat::start(
+        Rc::new(transport),
+        PingInterval(Duration::from_secs(10).into()),
+        IdleTimeout(Duration::from_secs(30).into()),
+    );
+    let mut on_idle = hb.on_idle();
+    yield_now().await;
+
+    clock.advance(Duration::from_secs(20));
+    let msg = timeout(100, sent_rx.next()).await.unwrap().unwrap();
+    assert_eq!(msg, ClientMsg::Pong(1));
+
+    yield_now().await;
+    clock.advance(Duration::from_secs(9));
+    timeout(50, on_idle.next()).await.unwrap_err();
+
+    clock.advance(Duration::from_secs(1));
+    timeout(100, on_idle.next()).await.unwrap().unwrap();
+}
+
+/// Tests that idle detection of [`Heartbeat`] can be paused.
+///
+/// # Algorithm
+///
+/// 1. Replace the current [`Clock`] with a [`ManualClock`].
+///
+/// 2. Pause idle detection and advance [`ManualClock`] past `idle_timeout`.
+///
+/// 3. Check that [`Heartbeat::on_idle`] doesn't fire.
+///
+/// 4. Resume idle detection and check that [`Heartbeat::on_idle`] fires once
+///    `idle_timeout` elapses again.
+#[wasm_bindgen_test]
+async fn idle_detection_can_be_paused() {
+    let clock = ManualClockGuard::install();
+
+    let mut transport = MockRpcTransport::new();
+    transport.expect_connect().return_once(|_| Box::pin(future::ok(())));
+    transport.expect_on_message().return_once(|| stream::pending().boxed());
+    transport.expect_send().returning(|_| Ok(()));
+
+    let hb = Heartbeat::start(
+        Rc::new(transport),
+        PingInterval(Duration::from_secs(10).into()),
+        IdleTimeout(Duration::from_secs(30).into()),
+    );
+    let mut on_idle = hb.on_idle();
+    yield_now().await;
+
+    hb.pause_idle_detection();
+    clock.advance(Duration::from_secs(60));
+    timeout(50, on_idle.next()).await.unwrap_err();
+
+    hb.resume_idle_detection();
+    yield_now().await;
+    clock.advance(Duration::from_secs(20));
+    delay_for(10).await;
+    clock.advance(Duration::from_secs(10));
+    timeout(100, on_idle.next()).await.unwrap().unwrap();
+}
+
 /// Tests that [`Heartbeat`] will try send [`ClientMsg::Pong`] if
 /// no [`ServerMsg::Ping`]s received within `ping_interval * 2`.
 ///
@@ -1,7 +1,10 @@
 //! Tests for [`medea_jason::rpc::RpcClient`].
 
+mod backoff_delayer;
 mod heartbeat;
 mod reconnect_handle;
+#[cfg(feature = "rpc-recording")]
+mod recording;
 mod rpc_session;
 mod websocket;
 
@@ -17,8 +20,14 @@ use medea_client_api_proto::{
     ClientMsg, CloseReason, Command, Event, PeerId, RpcSettings, ServerMsg,
 };
 use medea_jason::{
-    platform::{MockRpcTransport, RpcTransport, TransportState},
-    rpc::{ClientDisconnect, CloseMsg, RpcEvent, WebSocketRpcClient},
+    platform::{
+        MockRpcTransport, RpcTransport, RpcTransportKind, TransportError,
+        TransportState,
+    },
+    rpc::{
+        ClientDisconnect, CloseMsg, ClosedStateReason, RpcClientError,
+        RpcEvent, WebSocketRpcClient,
+    },
 };
 use wasm_bindgen_futures::spawn_local;
 use wasm_bindgen_test::*;
@@ -31,6 +40,7 @@ wasm_bindgen_test_configure!(run_in_browser);
 pub const RPC_SETTINGS: ServerMsg = ServerMsg::RpcSettings(RpcSettings {
     idle_timeout_ms: 5_000,
     ping_interval_ms: 2_000,
+    latency_probes: false,
 });
 
 /// Creates [`WebSocketRpcClient`] with the provided [`MockRpcTransport`].
@@ -77,6 +87,7 @@ async fn message_received_from_transport_is_transmitted_to_sub() {
                 ServerMsg::RpcSettings(RpcSettings {
                     idle_timeout_ms: 10_000,
                     ping_interval_ms: 10_000,
+                    latency_probes: false,
                 }),
                 ServerMsg::Event { room_id: "".into(), event: SRV_EVENT },
             ])
@@ -121,6 +132,7 @@ async fn transport_is_dropped_when_client_is_dropped() {
         on_message_mock(RpcSettings {
             idle_timeout_ms: 10_000,
             ping_interval_ms: 500,
+            latency_probes: false,
         })
     });
     let rpc_transport = Rc::new(transport);
@@ -156,6 +168,7 @@ async fn send_goes_to_transport() {
         on_message_mock(RpcSettings {
             idle_timeout_ms: 10_000,
             ping_interval_ms: 500,
+            latency_probes: false,
         })
     });
     transport.expect_send().returning(move |e| {
@@ -203,6 +216,107 @@ async fn send_goes_to_transport() {
     timeout(1000, test_rx).await.unwrap().unwrap();
 }
 
+/// Tests that [`WebSocketRpcClient`] falls back to the next
+/// [`RpcTransportKind`] if the previous one fails to connect, and reports the
+/// one being in use.
+///
+/// # Algorithm
+///
+/// 1. Create [`WebSocketRpcClient`] with a failing
+///    [`RpcTransportKind::WebSocket`] [`MockRpcTransport`] and a working
+///    [`RpcTransportKind::Sse`] one.
+///
+/// 2. Connect [`WebSocketRpcClient`].
+///
+/// 3. Check that [`RpcTransportKind::Sse`] is used.
+#[wasm_bindgen_test]
+async fn falls_back_to_next_transport() {
+    let ws = Rc::new(
+        WebSocketRpcClient::new(Box::new(|| {
+            let mut transport = MockRpcTransport::new();
+            transport.expect_connect().return_once(|_| {
+                Box::pin(future::err(tracerr::new!(TransportError::InitSocket)))
+            });
+            transport
+                .expect_on_message()
+                .returning(|| stream::pending().boxed());
+            Rc::new(transport) as Rc<dyn RpcTransport>
+        }))
+        .with_fallback(
+            RpcTransportKind::Sse,
+            Box::new(|| {
+                let mut transport = MockRpcTransport::new();
+                transport
+                    .expect_connect()
+                    .return_once(|_| Box::pin(future::ok(())));
+                transport.expect_on_state_change().return_once(|| {
+                    stream::once(async { TransportState::Open }).boxed()
+                });
+                transport.expect_on_message().returning(|| {
+                    on_message_mock(RpcSettings {
+                        idle_timeout_ms: 10_000,
+                        ping_interval_ms: 10_000,
+                        latency_probes: false,
+                    })
+                });
+                transport.expect_send().returning(|_| Ok(()));
+                transport.expect_set_close_reason().return_const(());
+                Rc::new(transport) as Rc<dyn RpcTransport>
+            }),
+        ),
+    );
+    assert_eq!(ws.transport_kind(), None);
+
+    ws.clone().connect(join_room_url()).await.unwrap();
+
+    assert_eq!(ws.transport_kind(), Some(RpcTransportKind::Sse));
+}
+
+/// Tests that [`WebSocketRpcClient`] reports an error of the primary
+/// [`RpcTransportKind`] if all the transports fail to connect.
+///
+/// # Algorithm
+///
+/// 1. Create [`WebSocketRpcClient`] with [`RpcTransportKind::WebSocket`] and
+///    [`RpcTransportKind::Sse`] [`MockRpcTransport`]s failing with different
+///    [`TransportError`]s.
+///
+/// 2. Connect [`WebSocketRpcClient`].
+///
+/// 3. Check that [`TransportError`] of the [`RpcTransportKind::WebSocket`] is
+///    returned.
+#[wasm_bindgen_test]
+async fn reports_primary_transport_error() {
+    fn failing(err: TransportError) -> Rc<dyn RpcTransport> {
+        let mut transport = MockRpcTransport::new();
+        transport
+            .expect_connect()
+            .return_once(move |_| Box::pin(future::err(tracerr::new!(err))));
+        transport.expect_on_message().returning(|| stream::pending().boxed());
+        Rc::new(transport)
+    }
+
+    let ws = Rc::new(
+        WebSocketRpcClient::new(Box::new(|| {
+            failing(TransportError::InitSocket)
+        }))
+        .with_fallback(
+            RpcTransportKind::Sse,
+            Box::new(|| failing(TransportError::MessageNotString)),
+        ),
+    );
+
+    let err = ws.clone().connect(join_room_url()).await.unwrap_err();
+
+    assert!(matches!(
+        err.into_inner(),
+        RpcClientError::ConnectionFailed(ClosedStateReason::CouldNotEstablish(
+            TransportError::InitSocket
+        )),
+    ));
+    assert_eq!(ws.transport_kind(), None);
+}
+
 /// Tests for [`WebSocketRpcClient::on_close`].
 mod on_close {
     use super::*;
@@ -224,6 +338,7 @@ mod on_close {
             on_message_mock(RpcSettings {
                 idle_timeout_ms: 10_000,
                 ping_interval_ms: 500,
+                latency_probes: false,
             })
         });
         transport.expect_send().returning(|_| Ok(()));
@@ -316,6 +431,7 @@ mod transport_close_reason_on_drop {
             on_message_mock(RpcSettings {
                 idle_timeout_ms: 10000,
                 ping_interval_ms: 500,
+                latency_probes: false,
             })
         });
         transport.expect_send().return_once(|_| Ok(()));
@@ -415,6 +531,7 @@ mod connect {
                 on_message_mock(RpcSettings {
                     idle_timeout_ms: 3_000,
                     ping_interval_ms: 3_000,
+                    latency_probes: false,
                 })
             });
             transport.expect_send().return_once(|_| Ok(()));
@@ -455,6 +572,7 @@ mod connect {
                 on_message_mock(RpcSettings {
                     idle_timeout_ms: 3_000,
                     ping_interval_ms: 3_000,
+                    latency_probes: false,
                 })
             });
             transport.expect_send().return_once(|_| Ok(()));
@@ -504,6 +622,7 @@ mod connect {
                 on_message_mock(RpcSettings {
                     idle_timeout_ms: 3_000,
                     ping_interval_ms: 3_000,
+                    latency_probes: false,
                 })
             });
             transport.expect_send().return_once(|_| Ok(()));
@@ -541,6 +660,7 @@ mod on_connection_loss {
                 on_message_mock(RpcSettings {
                     idle_timeout_ms: idle_timeout_ms.unwrap_or(u32::MAX),
                     ping_interval_ms: ping_interval_ms.unwrap_or(u32::MAX),
+                    latency_probes: false,
                 })
             });
             transport.expect_set_close_reason().return_once(drop);
@@ -706,6 +826,7 @@ mod on_reconnected {
                 on_message_mock(RpcSettings {
                     idle_timeout_ms: 5_000,
                     ping_interval_ms: 2_000,
+                    latency_probes: false,
                 })
             });
             transport.expect_send().return_once(|_| Ok(()));
@@ -730,6 +851,7 @@ mod on_reconnected {
             Rc::new(ObservableCell::new(ServerMsg::RpcSettings(RpcSettings {
                 idle_timeout_ms: 5_000,
                 ping_interval_ms: 2_000,
+                latency_probes: false,
             })));
         let on_state_change_mock =
             Rc::new(ObservableCell::new(TransportState::Open));
@@ -769,6 +891,7 @@ mod on_reconnected {
         on_message_mock.set(ServerMsg::RpcSettings(RpcSettings {
             idle_timeout_ms: 5_000,
             ping_interval_ms: 2_000,
+            latency_probes: false,
         }));
 
         ws.connect(join_room_url()).await.unwrap();
@@ -43,6 +43,7 @@ async fn reconnect_with_backoff() {
                         event: Event::RoomJoined {
                             member_id: "member_id".into(),
                             is_reconnect: false,
+                            members: Vec::new(),
                         },
                     },
                 ]))
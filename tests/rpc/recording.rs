@@ -0,0 +1,145 @@
+//! Tests for [`medea_jason::rpc::RpcRecorder`] and
+//! [`medea_jason::rpc::ReplayRpcTransport`].
+
+use std::rc::Rc;
+
+use futures::{StreamExt as _, future, stream};
+use medea_client_api_proto::{
+    Capabilities, ClientMsg, Command, Event, RpcSettings, ServerMsg,
+};
+use medea_jason::{
+    platform::{MockRpcTransport, RpcTransport, TransportState},
+    rpc::{
+        PeerActivity, RecordedMsg, ReplayRpcTransport, RpcEvent, RpcRecorder,
+        WebSocketRpcClient, parse_recording,
+    },
+};
+use wasm_bindgen_test::*;
+
+use crate::{join_room_url, timeout};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// [`Event`] sent by a server in tests.
+const SRV_EVENT: Event = Event::PeersRemoved { peer_ids: Vec::new() };
+
+/// Tests that a session recorded by [`RpcRecorder`] is replayed by
+/// [`ReplayRpcTransport`] in the same order.
+///
+/// # Algorithm
+///
+/// 1. Connect [`WebSocketRpcClient`] with a [`RpcRecorder`] to a
+///    [`MockRpcTransport`] sending [`SRV_EVENT`].
+///
+/// 2. Receive [`SRV_EVENT`], join a `Room` and export the recording.
+///
+/// 3. Check that the `Credential` is redacted in the exported recording.
+///
+/// 4. Connect a new [`WebSocketRpcClient`] to a [`ReplayRpcTransport`] with
+///    the exported recording.
+///
+/// 5. Check that [`SRV_EVENT`], moved after the `Command::JoinRoom` in the
+///    recording, is replayed only after the `Room` is joined.
+#[wasm_bindgen_test]
+async fn replays_recorded_session() {
+    let recorder = Rc::new(RpcRecorder::new());
+    let ws = Rc::new(
+        WebSocketRpcClient::new(Box::new(|| {
+            let mut transport = MockRpcTransport::new();
+            transport
+                .expect_connect()
+                .return_once(|_| Box::pin(future::ok(())));
+            transport.expect_on_state_change().return_once(|| {
+                stream::once(async { TransportState::Open }).boxed()
+            });
+            transport.expect_on_message().returning(|| {
+                stream::iter(vec![
+                    ServerMsg::RpcSettings(RpcSettings {
+                        idle_timeout_ms: 10_000,
+                        ping_interval_ms: 10_000,
+                        latency_probes: false,
+                    }),
+                    ServerMsg::Event { room_id: "".into(), event: SRV_EVENT },
+                ])
+                .chain(stream::pending())
+                .boxed()
+            });
+            transport.expect_send().returning(|_| Ok(()));
+            transport.expect_set_close_reason().return_const(());
+            Rc::new(transport) as Rc<dyn RpcTransport>
+        }))
+        .with_recorder(Rc::clone(&recorder)),
+    );
+
+    let mut events = ws.subscribe();
+    ws.clone().connect(join_room_url()).await.unwrap();
+    timeout(1000, events.next()).await.unwrap();
+    ws.join_room(
+        "".into(),
+        "member".into(),
+        "secret".into(),
+        Capabilities::default(),
+    );
+
+    let exported = recorder.export();
+    assert!(!exported.contains("secret"));
+    let mut records = parse_recording(&exported).unwrap();
+    assert_eq!(records.len(), 3);
+    assert!(matches!(
+        &records[1],
+        RecordedMsg::Received { peer: Some(p), .. }
+            if p.activity == PeerActivity::Removed,
+    ));
+    assert!(matches!(
+        &records[2],
+        RecordedMsg::Sent { msg: ClientMsg::Command { command, .. }, .. }
+            if matches!(command, Command::JoinRoom { .. }),
+    ));
+
+    // Make the event being expected only after joining the `Room`.
+    records.swap(1, 2);
+    let replay = Rc::new(ReplayRpcTransport::new(records));
+    let ws = Rc::new(WebSocketRpcClient::new(Box::new({
+        let replay = Rc::clone(&replay);
+        move || Rc::clone(&replay) as Rc<dyn RpcTransport>
+    })));
+    let mut events = ws.subscribe();
+    ws.clone().connect(join_room_url()).await.unwrap();
+    timeout(100, events.next()).await.unwrap_err();
+    assert!(!replay.is_finished());
+
+    ws.join_room(
+        "".into(),
+        "member".into(),
+        "secret".into(),
+        Capabilities::default(),
+    );
+    assert_eq!(
+        timeout(1000, events.next()).await.unwrap().unwrap(),
+        RpcEvent::Event { room_id: "".into(), event: SRV_EVENT },
+    );
+    assert!(replay.is_finished());
+}
+
+/// Tests that [`RpcRecorder`] skips [`Event::Unknown`]s, so the recording
+/// remains exportable and parseable.
+#[wasm_bindgen_test]
+async fn skips_unknown_events() {
+    let recorder = RpcRecorder::new();
+    recorder.record_received(&ServerMsg::Event {
+        room_id: "".into(),
+        event: Event::Unknown { event: "NewFancyEvent".into() },
+    });
+    recorder.record_received(&ServerMsg::Event {
+        room_id: "".into(),
+        event: SRV_EVENT,
+    });
+
+    let records = parse_recording(&recorder.export()).unwrap();
+    assert_eq!(records.len(), 1);
+    assert!(matches!(
+        &records[0],
+        RecordedMsg::Received { msg: ServerMsg::Event { event, .. }, .. }
+            if *event == SRV_EVENT,
+    ));
+}
@@ -6,11 +6,13 @@ use std::{
     rc::Rc,
     str::FromStr,
     sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
 };
 
-use futures::{FutureExt as _, StreamExt as _, future, stream};
+use futures::{FutureExt as _, StreamExt as _, channel::mpsc, future, stream};
 use medea_client_api_proto::{
-    ClientMsg, CloseReason, Command, Event, ServerMsg,
+    ClientMsg, CloseReason as ServerCloseReason, Command, Event,
+    ProtocolVersion, ServerMsg,
 };
 use medea_jason::{
     platform::{
@@ -18,13 +20,15 @@ use medea_jason::{
         WebSocketRpcTransport,
     },
     rpc::{
-        CloseMsg, ConnectionInfo, RpcSession, SessionError, WebSocketRpcClient,
-        WebSocketRpcSession,
+        ClientDisconnect, CloseMsg, CloseReason, ConnectionInfo, RpcSession,
+        SessionError, WebSocketRpcClient, WebSocketRpcSession,
     },
 };
 use wasm_bindgen_test::*;
 
-use crate::{TEST_ROOM_URL, delay_for, rpc::RPC_SETTINGS, timeout};
+use crate::{
+    ManualClockGuard, TEST_ROOM_URL, delay_for, rpc::RPC_SETTINGS, timeout,
+};
 
 wasm_bindgen_test_configure!(run_in_browser);
 
@@ -44,7 +48,7 @@ async fn could_not_auth_err() {
                     ServerMsg::Event {
                         room_id: "room_id".into(),
                         event: Event::RoomLeft {
-                            close_reason: CloseReason::InternalError,
+                            close_reason: ServerCloseReason::InternalError,
                         },
                     },
                 ]))
@@ -100,6 +104,7 @@ async fn concurrent_connect_requests() {
                         event: Event::RoomJoined {
                             member_id: "member_id".into(),
                             is_reconnect: false,
+                            members: Vec::new(),
                         },
                     },
                 ]))
@@ -141,6 +146,78 @@ async fn concurrent_connect_requests() {
     assert!(join_room_sent.load(Ordering::Relaxed));
 }
 
+/// Makes sure that a credential provided separately from a URL is sent inside
+/// `JoinRoom` only, while the URL the transport connects to doesn't contain
+/// it, but carries the provided handshake headers.
+#[wasm_bindgen_test]
+async fn credential_is_not_sent_in_url() {
+    let sent_credential = Rc::new(RefCell::new(None));
+
+    let sent_credential_clone = Rc::clone(&sent_credential);
+    let session = WebSocketRpcSession::new(Rc::new(WebSocketRpcClient::new({
+        Box::new(move || {
+            let sent_credential = Rc::clone(&sent_credential_clone);
+            let mut transport = MockRpcTransport::new();
+            transport.expect_connect().return_once(|url| {
+                assert_eq!(url.as_ref(), "ws://example.com/");
+                assert_eq!(
+                    url.headers(),
+                    [("X-Custom".to_owned(), "value".to_owned())],
+                );
+                Box::pin(future::ok(()))
+            });
+            transport.expect_on_message().returning_st(|| {
+                Box::pin(stream::iter(vec![
+                    RPC_SETTINGS,
+                    ServerMsg::Event {
+                        room_id: "room_id".into(),
+                        event: Event::RoomJoined {
+                            member_id: "member_id".into(),
+                            is_reconnect: false,
+                            members: Vec::new(),
+                        },
+                    },
+                ]))
+            });
+            transport.expect_send().returning_st(move |msg| {
+                if let ClientMsg::Command {
+                    command: Command::JoinRoom { credential, .. },
+                    ..
+                } = msg
+                {
+                    drop(sent_credential.replace(Some(credential.clone())));
+                }
+                Ok(())
+            });
+            transport.expect_set_close_reason().return_once(drop);
+            transport.expect_on_state_change().return_once_st(move || {
+                Box::pin(stream::once(async { TransportState::Open }))
+            });
+            let transport = Rc::new(transport);
+            transport as Rc<dyn RpcTransport>
+        })
+    })));
+
+    assert!(
+        ConnectionInfo::from_str("ws://example.com/room_id/member_id").is_err()
+    );
+    let connection_info = ConnectionInfo::with_credential(
+        "ws://example.com/room_id/member_id",
+        "secret".into(),
+        vec![("X-Custom".to_owned(), "value".to_owned())],
+    )
+    .unwrap();
+
+    timeout(100, Rc::clone(&session).connect(connection_info))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        sent_credential.borrow().as_ref().map(|c| c.expose_str().to_owned()),
+        Some("secret".to_owned()),
+    );
+}
+
 /// Makes sure that `connect` fails immediately if transport establishment
 /// failed.
 #[wasm_bindgen_test]
@@ -202,6 +279,7 @@ async fn reconnect_after_transport_abnormal_close() {
                                 member_id: "member_id".into(),
                                 is_reconnect: is_reconnect
                                     .load(Ordering::Relaxed),
+                                members: Vec::new(),
                             },
                         },
                     ]))
@@ -260,6 +338,7 @@ async fn reconnect_after_transport_abnormal_close() {
                     member_id: "member_id".into(),
                     credential: "token".into(),
                     capabilities: capabilities.clone(),
+                    protocol_version: ProtocolVersion::CURRENT,
                 },
             },
             // reconnect
@@ -269,6 +348,7 @@ async fn reconnect_after_transport_abnormal_close() {
                     member_id: "member_id".into(),
                     credential: "token".into(),
                     capabilities,
+                    protocol_version: ProtocolVersion::CURRENT,
                 },
             },
         ],
@@ -303,6 +383,7 @@ async fn on_reconnected_emits_only_on_true() {
                             event: Event::RoomJoined {
                                 member_id: "member_id".into(),
                                 is_reconnect,
+                                members: Vec::new(),
                             },
                         },
                     ]))
@@ -341,6 +422,7 @@ async fn on_reconnected_emits_only_on_true() {
                 session_for_sync.send_command(Command::SynchronizeMe {
                     state: medea_client_api_proto::state::Room {
                         peers: HashMap::new(),
+                        members: HashMap::new(),
                     },
                 });
             }
@@ -427,3 +509,377 @@ async fn on_reconnected_emits_only_on_true() {
         1
     );
 }
+
+/// Creates a [`WebSocketRpcSession`] joining a `Room` and acknowledging a
+/// [`Command::LeaveRoom`] with an [`Event::RoomLeft`] if `ack_leave` is
+/// `true`.
+///
+/// Returns the created [`WebSocketRpcSession`] along with all the
+/// [`ClientMsg`]s sent by it.
+fn leaving_session(
+    ack_leave: bool,
+) -> (Rc<WebSocketRpcSession>, Rc<RefCell<Vec<ClientMsg>>>) {
+    let sent = Rc::new(RefCell::new(Vec::new()));
+    let sent_clone = Rc::clone(&sent);
+    let session = WebSocketRpcSession::new(Rc::new(WebSocketRpcClient::new(
+        Box::new(move || {
+            let sent = Rc::clone(&sent_clone);
+            let (server_tx, server_rx) = mpsc::unbounded();
+            let mut transport = MockRpcTransport::new();
+            transport
+                .expect_connect()
+                .return_once(|_| Box::pin(future::ok(())));
+            transport
+                .expect_on_message()
+                .return_once_st(move || Box::pin(server_rx));
+            transport.expect_send().returning_st(move |msg| {
+                sent.borrow_mut().push(msg.clone());
+                let event = match msg {
+                    ClientMsg::Command {
+                        command: Command::JoinRoom { .. },
+                        ..
+                    } => Event::RoomJoined {
+                        member_id: "member_id".into(),
+                        is_reconnect: false,
+                        members: Vec::new(),
+                    },
+                    ClientMsg::Command {
+                        command: Command::LeaveRoom { .. },
+                        ..
+                    } if ack_leave => Event::RoomLeft {
+                        close_reason: ServerCloseReason::Finished,
+                    },
+                    _ => return Ok(()),
+                };
+                _ = server_tx.unbounded_send(ServerMsg::Event {
+                    room_id: "room_id".into(),
+                    event,
+                });
+                Ok(())
+            });
+            transport.expect_set_close_reason().returning(drop);
+            transport.expect_on_state_change().return_once_st(move || {
+                Box::pin(stream::once(async { TransportState::Open }))
+            });
+            let transport = Rc::new(transport);
+            transport as Rc<dyn RpcTransport>
+        }),
+    )));
+    (session, sent)
+}
+
+/// Tests that [`RpcSession::leave()`] sends a [`Command::LeaveRoom`] with the
+/// provided reason and resolves as closed by client once a server
+/// acknowledges it.
+#[wasm_bindgen_test]
+async fn leave_resolves_on_server_acknowledgement() {
+    let (session, sent) = leaving_session(true);
+    let connect_fut = Rc::clone(&session)
+        .connect(ConnectionInfo::from_str(TEST_ROOM_URL).unwrap());
+    timeout(100, connect_fut).await.unwrap().unwrap();
+
+    let close_reason = timeout(
+        100,
+        Rc::clone(&session)
+            .leave(Some("bye".to_owned()), Duration::from_secs(60)),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        close_reason,
+        CloseReason::ByClient { reason: ClientDisconnect::RoomClosed },
+    );
+    assert!(sent.borrow().iter().any(|msg| matches!(
+        msg,
+        ClientMsg::Command {
+            command: Command::LeaveRoom { reason: Some(reason), .. },
+            ..
+        } if reason == "bye",
+    )));
+    assert_eq!(
+        timeout(100, session.on_normal_close()).await.unwrap(),
+        close_reason,
+    );
+}
+
+/// Tests that [`RpcSession::leave()`] finishes the session once the provided
+/// timeout elapses without any acknowledgement from a server.
+#[wasm_bindgen_test]
+async fn leave_finishes_on_acknowledgement_timeout() {
+    let clock = ManualClockGuard::install();
+
+    let (session, _) = leaving_session(false);
+    let connect_fut = Rc::clone(&session)
+        .connect(ConnectionInfo::from_str(TEST_ROOM_URL).unwrap());
+    timeout(100, connect_fut).await.unwrap().unwrap();
+
+    let mut leave = Rc::clone(&session)
+        .leave(None, Duration::from_secs(3))
+        .boxed_local()
+        .fuse();
+    futures::select! {
+        _ = delay_for(50).fuse() => (),
+        _ = leave => panic!("`leave()` resolved before timeout"),
+    };
+
+    clock.advance(Duration::from_secs(3));
+    let close_reason = timeout(100, leave).await.unwrap();
+    assert_eq!(
+        close_reason,
+        CloseReason::ByClient { reason: ClientDisconnect::RoomClosed },
+    );
+}
+
+/// Creates a [`WebSocketRpcClient`] answering each [`Command::JoinRoom`] with
+/// an [`Event::RoomJoined`] for the same `Room`.
+///
+/// Returns the created [`WebSocketRpcClient`] along with all the
+/// [`ClientMsg`]s sent by it and all the [`ClientDisconnect`] reasons its
+/// transports were closed with.
+fn shared_client() -> (
+    Rc<WebSocketRpcClient>,
+    Rc<RefCell<Vec<ClientMsg>>>,
+    Rc<RefCell<Vec<ClientDisconnect>>>,
+) {
+    let sent = Rc::new(RefCell::new(Vec::new()));
+    let closed = Rc::new(RefCell::new(Vec::new()));
+    let client = Rc::new(WebSocketRpcClient::new(Box::new({
+        let (sent, closed) = (Rc::clone(&sent), Rc::clone(&closed));
+        move || {
+            let (sent, closed) = (Rc::clone(&sent), Rc::clone(&closed));
+            let (server_tx, server_rx) = mpsc::unbounded();
+            server_tx.unbounded_send(RPC_SETTINGS).unwrap();
+            let mut transport = MockRpcTransport::new();
+            transport
+                .expect_connect()
+                .return_once(|_| Box::pin(future::ok(())));
+            transport
+                .expect_on_message()
+                .return_once_st(move || Box::pin(server_rx));
+            transport.expect_send().returning_st(move |msg| {
+                sent.borrow_mut().push(msg.clone());
+                if let ClientMsg::Command {
+                    room_id,
+                    command: Command::JoinRoom { member_id, .. },
+                } = msg
+                {
+                    _ = server_tx.unbounded_send(ServerMsg::Event {
+                        room_id: room_id.clone(),
+                        event: Event::RoomJoined {
+                            member_id: member_id.clone(),
+                            is_reconnect: false,
+                            members: Vec::new(),
+                        },
+                    });
+                }
+                Ok(())
+            });
+            transport
+                .expect_set_close_reason()
+                .returning_st(move |reason| closed.borrow_mut().push(reason));
+            transport.expect_on_state_change().return_once_st(move || {
+                Box::pin(stream::once(async { TransportState::Open }))
+            });
+            let transport = Rc::new(transport);
+            transport as Rc<dyn RpcTransport>
+        }
+    })));
+    (client, sent, closed)
+}
+
+/// Counts [`Command::JoinRoom`]s sent for the `Room` with the provided ID.
+fn joins_count(sent: &RefCell<Vec<ClientMsg>>, room: &str) -> usize {
+    sent.borrow()
+        .iter()
+        .filter(|msg| {
+            matches!(
+                msg,
+                ClientMsg::Command {
+                    room_id,
+                    command: Command::JoinRoom { .. },
+                } if room_id.0 == room,
+            )
+        })
+        .count()
+}
+
+/// Tests that several [`WebSocketRpcSession`]s share a single connection of
+/// the same [`WebSocketRpcClient`], and closing one of them leaves only its
+/// `Room`, while the connection is closed once all of them are closed.
+#[wasm_bindgen_test]
+async fn shared_connection_is_closed_after_last_room() {
+    let (client, sent, closed) = shared_client();
+    let lobby = WebSocketRpcSession::new(Rc::clone(&client));
+    let breakout = WebSocketRpcSession::new(Rc::clone(&client));
+
+    let (lobby_res, breakout_res) = future::join(
+        Rc::clone(&lobby).connect(
+            ConnectionInfo::from_str("ws://example.com/lobby/member?token=t")
+                .unwrap(),
+        ),
+        Rc::clone(&breakout).connect(
+            ConnectionInfo::from_str(
+                "ws://example.com/breakout/member?token=t",
+            )
+            .unwrap(),
+        ),
+    )
+    .await;
+    lobby_res.unwrap();
+    breakout_res.unwrap();
+    assert_eq!(joins_count(&sent, "lobby"), 1);
+    assert_eq!(joins_count(&sent, "breakout"), 1);
+
+    lobby.close_with_reason(ClientDisconnect::RoomClosed);
+    assert!(sent.borrow().iter().any(|msg| matches!(
+        msg,
+        ClientMsg::Command { room_id, command: Command::LeaveRoom { .. } }
+            if room_id.0 == "lobby",
+    )));
+    assert!(closed.borrow().is_empty());
+
+    breakout.send_command(Command::SynchronizeMe {
+        state: medea_client_api_proto::state::Room {
+            peers: HashMap::new(),
+            members: HashMap::new(),
+        },
+    });
+    assert!(matches!(
+        sent.borrow().last().unwrap(),
+        ClientMsg::Command { room_id, command: Command::SynchronizeMe { .. } }
+            if room_id.0 == "breakout",
+    ));
+
+    breakout.close_with_reason(ClientDisconnect::RoomClosed);
+    assert_eq!(*closed.borrow(), [ClientDisconnect::RoomClosed]);
+}
+
+/// Tests that recreating a shared connection by one [`WebSocketRpcSession`]
+/// makes all the other ones to join their `Room`s again.
+#[wasm_bindgen_test]
+async fn shared_connection_reconnection_rejoins_all_rooms() {
+    let (client, sent, _) = shared_client();
+    let lobby = WebSocketRpcSession::new(Rc::clone(&client));
+    let breakout = WebSocketRpcSession::new(Rc::clone(&client));
+
+    Rc::clone(&lobby)
+        .connect(
+            ConnectionInfo::from_str("ws://example.com/lobby/member?token=t")
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    Rc::clone(&breakout)
+        .connect(
+            ConnectionInfo::from_str(
+                "ws://example.com/breakout/member?token=t",
+            )
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    timeout(100, Rc::clone(&lobby).network_changed())
+        .await
+        .unwrap()
+        .unwrap();
+    // Wait for `breakout` to join its `Room` again.
+    delay_for(100).await;
+
+    assert_eq!(joins_count(&sent, "lobby"), 2);
+    assert_eq!(joins_count(&sent, "breakout"), 2);
+}
+
+/// Tests that a [`WebSocketRpcClient`] shared by some joined `Room`s refuses
+/// to connect to another server.
+#[wasm_bindgen_test]
+async fn shared_connection_refuses_another_server() {
+    let (client, _, closed) = shared_client();
+    let lobby = WebSocketRpcSession::new(Rc::clone(&client));
+    let other = WebSocketRpcSession::new(Rc::clone(&client));
+
+    Rc::clone(&lobby)
+        .connect(
+            ConnectionInfo::from_str("ws://example.com/lobby/member?token=t")
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let err = Rc::clone(&other)
+        .connect(
+            ConnectionInfo::from_str("ws://another.com/room/member?token=t")
+                .unwrap(),
+        )
+        .await
+        .unwrap_err()
+        .into_inner();
+    assert!(matches!(err, SessionError::ConnectionLost(_)));
+    assert!(closed.borrow().is_empty());
+
+    lobby.close_with_reason(ClientDisconnect::RoomClosed);
+    assert_eq!(*closed.borrow(), [ClientDisconnect::RoomClosed]);
+}
+
+/// Tests that a [`WebSocketRpcClient`] refuses to connect to another server
+/// while the `Room` it's shared with is still joining.
+#[wasm_bindgen_test]
+async fn shared_connection_refuses_another_server_while_joining() {
+    let (client, sent, _) = shared_client();
+    let lobby = WebSocketRpcSession::new(Rc::clone(&client));
+    let other = WebSocketRpcSession::new(Rc::clone(&client));
+
+    let (lobby_res, other_res) = future::join(
+        Rc::clone(&lobby).connect(
+            ConnectionInfo::from_str("ws://example.com/lobby/member?token=t")
+                .unwrap(),
+        ),
+        Rc::clone(&other).connect(
+            ConnectionInfo::from_str("ws://another.com/room/member?token=t")
+                .unwrap(),
+        ),
+    )
+    .await;
+
+    lobby_res.unwrap();
+    assert!(matches!(
+        other_res.unwrap_err().into_inner(),
+        SessionError::ConnectionLost(_),
+    ));
+    assert_eq!(joins_count(&sent, "lobby"), 1);
+    assert_eq!(joins_count(&sent, "room"), 0);
+}
+
+/// Tests that [`ConnectionInfo`]s differing only in their query or handshake
+/// headers reuse the shared connection without recreating it.
+#[wasm_bindgen_test]
+async fn shared_connection_ignores_query_and_headers() {
+    let (client, sent, closed) = shared_client();
+    let lobby = WebSocketRpcSession::new(Rc::clone(&client));
+    let breakout = WebSocketRpcSession::new(Rc::clone(&client));
+
+    Rc::clone(&lobby)
+        .connect(
+            ConnectionInfo::from_str("ws://example.com/lobby/member?token=t")
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    Rc::clone(&breakout)
+        .connect(
+            ConnectionInfo::with_credential(
+                "ws://example.com:80/breakout/member?foo=bar",
+                "t".into(),
+                vec![("X-Foo".to_owned(), "bar".to_owned())],
+            )
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    delay_for(100).await;
+
+    assert_eq!(joins_count(&sent, "lobby"), 1);
+    assert_eq!(joins_count(&sent, "breakout"), 1);
+    assert!(closed.borrow().is_empty());
+}
@@ -1,7 +1,10 @@
 #![cfg(target_arch = "wasm32")]
 
+use medea_client_api_proto::{
+    ClientMsg, Event, RpcSettings, ServerMsg, WireFormat,
+};
 use medea_jason::platform::{
-    RpcTransport, TransportError, WebSocketRpcTransport,
+    RpcTransport, TransportError, WebSocketRpcTransport, transport::Frame,
 };
 use url::Url;
 use wasm_bindgen_test::*;
@@ -21,3 +24,79 @@ async fn could_not_init_socket_err() {
         },
     }
 }
+
+#[wasm_bindgen_test]
+async fn frame_is_encoded_according_to_wire_format() {
+    let msg = ClientMsg::Pong(3);
+
+    let json = Frame::encode(&msg, WireFormat::Json).unwrap();
+    assert_eq!(json, Frame::Text(serde_json::to_string(&msg).unwrap()));
+
+    let cbor = Frame::encode(&msg, WireFormat::Cbor).unwrap();
+    assert!(matches!(cbor, Frame::Binary(_)));
+}
+
+#[wasm_bindgen_test]
+async fn binary_frame_is_decoded_as_cbor() {
+    let msg = ServerMsg::Ping(7);
+    let mut bytes = Vec::new();
+    ciborium::into_writer(&msg, &mut bytes).unwrap();
+
+    assert_eq!(Frame::Binary(bytes).decode().unwrap(), msg);
+    assert!(matches!(
+        Frame::Binary(vec![0xff]).decode().unwrap_err(),
+        TransportError::ParseBinaryServerMessage(_),
+    ));
+}
+
+#[wasm_bindgen_test]
+async fn unknown_event_is_decoded_as_unknown() {
+    let frame = Frame::Text(
+        r#"{"msg":"Event","data":{"room_id":"room","event":{
+            "event":"SomethingNew","data":{"foo":1}
+        }}}"#
+            .into(),
+    );
+
+    assert_eq!(
+        frame.decode().unwrap(),
+        ServerMsg::Event {
+            room_id: "room".into(),
+            event: Event::Unknown { event: "SomethingNew".into() },
+        },
+    );
+}
+
+#[wasm_bindgen_test]
+async fn malformed_known_event_is_an_error() {
+    let frame = Frame::Text(
+        r#"{"msg":"Event","data":{"room_id":"room","event":{
+            "event":"PeersRemoved","data":{"foo":1}
+        }}}"#
+            .into(),
+    );
+
+    assert!(matches!(
+        frame.decode().unwrap_err(),
+        TransportError::ParseServerMessage(_),
+    ));
+}
+
+#[wasm_bindgen_test]
+async fn unknown_fields_are_ignored() {
+    let frame = Frame::Text(
+        r#"{"msg":"RpcSettings","data":{
+            "idle_timeout_ms":1,"ping_interval_ms":2,"something_new":true
+        }}"#
+        .into(),
+    );
+
+    assert_eq!(
+        frame.decode().unwrap(),
+        ServerMsg::RpcSettings(RpcSettings {
+            idle_timeout_ms: 1,
+            ping_interval_ms: 2,
+            latency_probes: false,
+        }),
+    );
+}
@@ -84,6 +84,8 @@ mod room;
 mod rpc;
 mod utils;
 
+use std::{ops::Deref, rc::Rc};
+
 use futures::{channel::oneshot, future::Either};
 use js_sys::Promise;
 use medea_client_api_proto::{
@@ -98,6 +100,7 @@ use medea_jason::{
         MediaStreamSettings, track::remote,
     },
     peer::media_exchange_state,
+    platform::{Clock, ManualClock, RealClock, set_clock},
     rpc::ApiUrl,
 };
 use url::Url;
@@ -391,6 +394,33 @@ pub async fn yield_now() {
     delay_for(0).await;
 }
 
+/// Guard replacing the current [`Clock`] with a [`ManualClock`] and restoring
+/// the [`RealClock`] on [`Drop`], so a failed test doesn't affect the others.
+pub struct ManualClockGuard(Rc<ManualClock>);
+
+impl ManualClockGuard {
+    /// Replaces the current [`Clock`] with a new [`ManualClock`].
+    pub fn install() -> Self {
+        let clock = Rc::new(ManualClock::new());
+        set_clock(Rc::clone(&clock) as Rc<dyn Clock>);
+        Self(clock)
+    }
+}
+
+impl Deref for ManualClockGuard {
+    type Target = ManualClock;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Drop for ManualClockGuard {
+    fn drop(&mut self) {
+        set_clock(Rc::new(RealClock));
+    }
+}
+
 /// Returns [`Window`] object.
 ///
 /// # Panics